@@ -0,0 +1,91 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Count heap allocations made while parsing JSON into typed trees.
+//!
+//! Compare the default storage of repetitions with the `smallvec` one by running
+//!
+//! ```shell
+//! cargo bench -p pest_typed_derive --bench allocations
+//! cargo bench -p pest_typed_derive --bench allocations --features pest_typed/smallvec
+//! ```
+
+use pest_typed::ParsableTypedNode;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+pub mod json_typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar = "benches/json.pest"]
+    #[emit_rule_reference]
+    pub struct JsonParser;
+}
+
+struct Counting;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(layout.size(), Ordering::Relaxed);
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        BYTES.fetch_add(new_size, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Parse `input` and print allocations made by parsing, excluding dropping the tree.
+fn count(name: &str, input: &str) {
+    let (allocations, bytes) = (
+        ALLOCATIONS.load(Ordering::Relaxed),
+        BYTES.load(Ordering::Relaxed),
+    );
+    let tree = json_typed::pairs::json::try_parse(input).unwrap();
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed) - allocations;
+    let bytes = BYTES.load(Ordering::Relaxed) - bytes;
+    drop(tree);
+    println!(
+        "{name}: {} bytes of input, {allocations} allocations, {bytes} bytes allocated",
+        input.len(),
+    );
+}
+
+fn main() {
+    let canada =
+        std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/benches/Canada.json"))
+            .unwrap();
+    // Most repetitions in typical JSON documents hold only a few elements.
+    let records = (0..10_000)
+        .map(|i| {
+            format!(r#"{{"id": {i}, "tags": ["a", "b"], "point": [{i}.5, -{i}], "meta": {{}}}}"#)
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let records = format!("[{records}]");
+
+    let storage = std::any::type_name::<pest_typed::predefined_node::RepStorage<u8>>();
+    println!("Repetitions are stored in `{storage}`.");
+    count("Canada.json", &canada);
+    count("records", &records);
+}
@@ -9,6 +9,7 @@ use pest_typed_derive::TypedParser;
 #[derive(TypedParser)]
 #[grammar = "examples/csv.pest"]
 #[emit_rule_reference]
+#[allow(dead_code)]
 struct Parser;
 
 fn main() -> Result<(), Error> {
@@ -0,0 +1,68 @@
+use anyhow::Error;
+use core::ops::Deref;
+use pest_typed::{
+    pratt::{Assoc, PrattParser},
+    ParsableTypedNode,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar = "examples/calculator.pest"]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Calculator;
+
+type Pratt<'i> = PrattParser<pairs::prefix<'i>, pairs::infix<'i>, pairs::postfix<'i>>;
+
+fn pratt<'i>() -> Pratt<'i> {
+    use generics::Choice5;
+    Pratt::new()
+        .prefix(|_neg| 3)
+        .infix(|op| match op.deref() {
+            Choice5::_0(_) | Choice5::_1(_) => (1, Assoc::Left),
+            Choice5::_2(_) | Choice5::_3(_) => (2, Assoc::Left),
+            Choice5::_4(_) => (4, Assoc::Right),
+        })
+        .postfix(|_fac| 5)
+}
+
+fn eval<'i>(expr: &pairs::expr<'i>, pratt: &Pratt<'i>) -> f64 {
+    use generics::{Choice2, Choice5};
+    pratt
+        .map_primary(|primary: &pairs::primary<'i>| match primary.deref() {
+            Choice2::_0(num) => num.span.as_str().parse().unwrap(),
+            Choice2::_1(parenthesized) => {
+                let (_, expr, _) = parenthesized.as_ref();
+                eval(expr, pratt)
+            }
+        })
+        .map_prefix(|_neg, rhs| -rhs)
+        .map_postfix(|lhs, _fac| (1..=lhs as u64).product::<u64>() as f64)
+        .map_infix(|lhs, op, rhs| match op.deref() {
+            Choice5::_0(_add) => lhs + rhs,
+            Choice5::_1(_sub) => lhs - rhs,
+            Choice5::_2(_mul) => lhs * rhs,
+            Choice5::_3(_div) => lhs / rhs,
+            Choice5::_4(_pow) => lhs.powf(rhs),
+        })
+        .parse(expr.deref())
+}
+
+fn calculate(input: &str) -> Result<f64, Error> {
+    let line = pairs::line::try_parse(input)?;
+    Ok(eval(line.expr(), &pratt()))
+}
+
+fn main() -> Result<(), Error> {
+    assert_eq!(calculate("1 + 2 * 3")?, 7.0);
+    assert_eq!(calculate("(1 + 2) * 3")?, 9.0);
+    assert_eq!(calculate("10 - 4 - 3")?, 3.0);
+    assert_eq!(calculate("2 ^ 3 ^ 2")?, 512.0);
+    assert_eq!(calculate("-2 ^ 2")?, -4.0);
+    assert_eq!(calculate("3! + 1")?, 7.0);
+    assert_eq!(calculate("1 - -(2.5 * 2)")?, 6.0);
+    for input in std::env::args().skip(1) {
+        println!("{} = {}", input, calculate(&input)?);
+    }
+    Ok(())
+}
@@ -6,6 +6,7 @@ use std::iter::once;
 #[derive(TypedParser)]
 #[grammar = "examples/csv.pest"]
 #[emit_rule_reference]
+#[allow(dead_code)]
 struct Csv;
 
 fn main() -> Result<(), Error> {
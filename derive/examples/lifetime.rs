@@ -9,6 +9,7 @@ b1 = { "bbb" }
 b2 = { "cc" }
 "#]
 #[emit_rule_reference]
+#[allow(dead_code)]
 struct Parser;
 
 fn parse(input: &'_ str) -> Result<&'_ str, Error> {
@@ -6,6 +6,7 @@ use pest_typed_derive::TypedParser;
 #[grammar_inline = r#"a = { "a" ~ #b = (b1 ~ #c = (b2 ~ b3)) } b1 = { "b" } b2 = { "bb" } b3 = { "bbb" }"#]
 #[emit_rule_reference]
 #[emit_tagged_node_reference]
+#[allow(dead_code)]
 struct Parser;
 
 fn main() -> Result<(), Error> {
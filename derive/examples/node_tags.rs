@@ -12,6 +12,7 @@ c  = { #a = item ~ ("," ~ #a = item)* }
 "#]
 #[emit_rule_reference]
 #[emit_tagged_node_reference]
+#[allow(dead_code)]
 struct Parser;
 
 fn main() -> Result<(), Error> {
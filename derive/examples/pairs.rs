@@ -5,6 +5,7 @@ use pest_typed_derive::TypedParser;
 
 #[derive(TypedParser)]
 #[grammar = "examples/csv.pest"]
+#[allow(dead_code)]
 struct Csv;
 
 fn main() -> Result<()> {
@@ -10,6 +10,7 @@ c = @{ "c" }
 d = ${ "d" }
 "#]
 #[emit_rule_reference]
+#[allow(dead_code)]
 struct Parser;
 
 fn parse(input: &'static str) -> Result<(), Error> {
@@ -10,6 +10,7 @@ b2 = { "cc" }
 b3 = { "d" }
 "#]
 #[emit_rule_reference]
+#[allow(dead_code)]
 struct Parser;
 
 fn parse(input: &str) -> Result<(), Error> {
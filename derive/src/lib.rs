@@ -24,6 +24,54 @@
 //!
 //! Note that skipped items are taken into consideration when using [core::hash::Hash], [PartialEq] or [Eq].
 //!
+//! Skipped `COMMENT`s and `WHITESPACE`s are kept in the typed tree,
+//! and `skipped()` on sequences and repetitions returns them in positional order, which is useful for formatters.
+//!
+//! If all rules are atomic and neither `COMMENT` nor `WHITESPACE` is defined, nothing can be skipped,
+//! so the generated types use `pest_typed::predefined_node::Empty` directly,
+//! and module `generics` contains no `Skipped`, `Rep` or `RepOnce`.
+//!
+//! Attribute `skip_rules` makes some rules skip another rule instead, such as a string-literal sublanguage that skips nothing:
+//!
+//! ```rust
+//! # use pest_typed::ParsableTypedNode as _;
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! WHITESPACE = _{ " " }
+//! call       =  { name ~ "(" ~ template? ~ ")" }
+//! template   =  { "`" ~ text* ~ "`" }
+//! text       =  { (!"`" ~ ANY)+ }
+//! name       = @{ ASCII_ALPHA+ }
+//! "#]
+//! #[skip_rules(template = "")]
+//! #[emit_rule_reference]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let call = pairs::call::try_parse("f( ` a b ` )").unwrap();
+//! assert_eq!(call.template().unwrap().text()[0].span.as_str(), " a b ");
+//! # }
+//! ```
+//!
+//! Non-atomic rules referenced by `template`, such as `text`, skip nothing as well.
+//! A rule can't inherit different skip rules, unless it's listed in `skip_rules` itself.
+//!
+//! ### Built-in Rules
+//!
+//! Rules defined in the grammar take precedence over built-in rules with the same names,
+//! such as `NEWLINE`, `ASCII_DIGIT` or unicode properties,
+//! and all references to them, including those in accessers, point to the defined rules.
+//!
+//! Pest keywords `ANY`, `DROP`, `EOI`, `PEEK`, `PEEK_ALL`, `POP`, `POP_ALL`, `PUSH` and `SOI` can't be redefined,
+//! as both pest and the generated codes rely on their semantics, such as the `Rule::EOI` variant.
+//! Defining one of them is a compile error.
+//!
+//! Rules and tags are emitted as raw identifiers in modules of their own,
+//! so names such as `type`, `Rule`, `pairs` or `generics` can be used freely.
+//! Only `_`, `crate`, `self`, `Self` and `super` are rejected with a compile error,
+//! as they can't be raw identifiers.
+//!
 //! ## Generation
 //!
 //! We generate documents for automatically generated types, just hover on those types or view them in documents of your project to see them!
@@ -34,14 +82,304 @@
 //!
 //! It implements `Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd`.
 //!
+//! `Rule::is_atomic` tells whether a rule is atomic (`@`) or compound-atomic (`$`),
+//! `Rule::is_silent` whether it's silent (`_`),
+//! and `Rule::doc` returns its doc comment in the grammar, if any.
+//! `EOI` is neither atomic nor silent, and has no doc comment.
+//!
+//! ### Ordering of Generated Items
+//!
+//! Variants of the rule enumeration follow the order of rules in the grammar.
+//!
+//! Other generated items, including rule structs, tag modules and accesser functions,
+//! are sorted alphabetically by their names in the grammar,
+//! so the order stays the same even if some names are escaped as raw identifiers.
+//!
 //! ## APIs
 //!
 //! Note: to use **pest_typed_derive** as a dependency, **pest_typed** is also needed.
 //!
+//! ### Parsing Methods
+//!
+//! The parser struct has an inherent method `parse_<rule>` for each non-silent rule,
+//! which parses the whole input with that rule and doesn't need any trait to be imported:
+//!
+//! ```rust
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! file   = { SOI ~ number ~ ("," ~ number)* ~ EOI }
+//! number = { ASCII_DIGIT+ }
+//! "#]
+//! #[entry_rules(file)]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let file = Parser::parse_file("1,2,3").unwrap();
+//! assert_eq!(file.span.as_str(), "1,2,3");
+//! # }
+//! ```
+//!
+//! Attribute `entry_rules` limits the methods to the listed rules.
+//!
+//! Silent rules have no methods by default, but they can be listed in `entry_rules` to be parsed as a whole input.
+//! Their structs have no span, so their methods return a `pest_typed::Root`,
+//! which dereferences to the rule struct and has the `span` of the whole input.
+//! Accessers are generated for silent rules as for other rules,
+//! and the pairs of a silent rule are its inner pairs, as in **pest**:
+//!
+//! ```rust
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! WHITESPACE = _{ " " }
+//! file       = _{ SOI ~ number ~ ("," ~ number)* ~ EOI }
+//! number     = @{ ASCII_DIGIT+ }
+//! "#]
+//! #[entry_rules(file)]
+//! #[emit_rule_reference]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let file = Parser::parse_file(" 1, 2, 3 ").unwrap();
+//! assert_eq!(file.span.as_str(), " 1, 2, 3 ");
+//! let (first, rest) = file.number();
+//! assert_eq!(first.span.as_str(), "1");
+//! assert_eq!(rest.len(), 2);
+//! # }
+//! ```
+//!
+//! When the rule is only known at runtime, the generated function `parse_rule`
+//! parses the whole input with the rule selected by a `Rule` value.
+//! It returns a boxed `pest_typed::iterators::AnyPair`, which provides the rule, the span and inner pairs,
+//! or an error if the rule is silent:
+//!
+//! ```rust
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! file   = { SOI ~ number ~ ("," ~ number)* ~ EOI }
+//! number = { ASCII_DIGIT+ }
+//! "#]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let pair = parse_rule(Rule::number, "42").unwrap();
+//! assert_eq!(pair.rule(), Rule::number);
+//! assert_eq!(pair.as_str(), "42");
+//! assert_eq!(parse_rule(Rule::file, "1,2").unwrap().inner_pairs().count(), 3);
+//! # }
+//! ```
+//!
+//! Rules can also be selected by their names in the grammar with `Rule::from_name`.
+//! `Rule::name` converts back, and `Rule::ALL` and `Rule::NAMES` list all rules and their names,
+//! starting with `EOI`.
+//! With `rule_repr(u16)`, `Rule` is represented as `u16`, numbered from `EOI = 0` in the order of the grammar,
+//! and `rule_non_exhaustive` marks it `#[non_exhaustive]`.
+//!
+//! The generated function `rule_dependencies` lists rules directly referenced by each rule,
+//! so that tools can render the grammar as a graph:
+//!
+//! ```rust
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! file   = { SOI ~ number ~ ("," ~ number)* ~ EOI }
+//! number = { ASCII_DIGIT+ }
+//! "#]
+//! struct Parser;
+//!
+//! # fn main() {
+//! assert_eq!(
+//!     rule_dependencies(),
+//!     &[(Rule::file, &[Rule::number, Rule::EOI][..]), (Rule::number, &[][..])],
+//! );
+//! # }
+//! ```
+//!
+//! Rules that can't be reached from non-silent rules, or from rules listed in `roots(rule, ...)`,
+//! are reported as a warning when deriving the parser, or as an error with `deny_unused_rules`.
+//!
+//! With `emit_generator`, the generated function `generate_random` writes a random input matching a rule,
+//! such as for differential testing against other parsers of the grammar.
+//! Random numbers come from a [`RandomSource`](pest_typed::random::RandomSource),
+//! and rules nested deeper than `max_depth` take their shallowest choices.
+//! Predicates, skips and unicode properties are reported as errors when deriving the parser:
+//!
+//! ```rust
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! WHITESPACE = _{ " " }
+//! list       =  { "[" ~ (number ~ ("," ~ number)*)? ~ "]" }
+//! number     = @{ ASCII_DIGIT+ }
+//! "#]
+//! #[emit_generator]
+//! struct Parser;
+//!
+//! # fn main() {
+//! use pest_typed::random::SplitMix64;
+//! let mut rng = SplitMix64::new(0);
+//! for _ in 0..16 {
+//!     let input = generate_random(Rule::list, &mut rng, 4);
+//!     assert!(parse_rule(Rule::list, &input).is_ok(), "{input:?}");
+//! }
+//! # }
+//! ```
+//!
+//! With feature `differential` and attribute `emit_pest_check`, the generated function `check_against_pest`
+//! parses a prefix of the input with a rule, both with the typed parser and with a `pest::Parser`
+//! generated by **pest_generator** from the same grammar, which requires **pest** as a dependency.
+//! It returns a [`Mismatch`](pest_typed::testing::Mismatch) if they don't agree on
+//! whether the rule matches, how many bytes it consumes, or the non-silent pairs in pre-order.
+//! Note that compound atomic rules have no inner pairs in pest-typed, unlike in pest.
+//!
+//! Case-insensitive strings such as `^"straße"` are matched like pest, ignoring case of ASCII letters only.
+//! With `unicode_case_insensitive`, they're matched by [`InsensUnicode`](pest_typed::predefined_node::InsensUnicode)
+//! with Unicode simple case folding instead, so each character matches a single character:
+//!
+//! ```rust
+//! # use pest_typed::ParsableTypedNode as _;
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! street = { ^"straße" }
+//! "#]
+//! #[unicode_case_insensitive]
+//! struct Parser;
+//!
+//! # fn main() {
+//! assert_eq!(pairs::street::try_parse("STRAẞE").unwrap().span.as_str(), "STRAẞE");
+//! assert!(pairs::street::try_parse("STRASSE").is_err());
+//! # }
+//! ```
+//!
+//! ### Error Recovery
+//!
+//! With attribute `recover_at(rule, ...)`, each element of a repetition of a sequence
+//! ending with one of the listed rules is wrapped in [`Recover`](pest_typed::predefined_node::Recover).
+//! [`ParsableTypedNode::parse_with_recovery`](pest_typed::ParsableTypedNode::parse_with_recovery)
+//! records the error of a failed element, skips to the next match of the rule and continues,
+//! so that multiple errors are reported in one parse:
+//!
+//! ```rust
+//! # use pest_typed::ParsableTypedNode as _;
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! program = { SOI ~ (stmt ~ sep)* ~ EOI }
+//! stmt    = { ASCII_ALPHA ~ "=" ~ number }
+//! number  = { ASCII_DIGIT+ }
+//! sep     = { ";" }
+//! "#]
+//! #[emit_rule_reference]
+//! #[recover_at(sep)]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let (program, errors) = pairs::program::parse_with_recovery("a=1;b=;c=;d=4;");
+//! assert_eq!(errors.len(), 2);
+//! let program = program.unwrap();
+//! let stmts = program.stmt();
+//! assert_eq!(stmts.iter().filter(|stmt| stmt.is_none()).count(), 2);
+//! # }
+//! ```
+//!
+//! Accessers return [`None`] for recovered elements. Other parsing methods fail at the first error as usual.
+//!
+//! ### Error Labels
+//!
+//! Rules are named by their names in the grammar in error messages.
+//! Attribute `error_label(rule = "label", ...)` names them by labels instead,
+//! which are also returned by generated `Rule::error_label`:
+//!
+//! ```rust
+//! # use pest_typed::ParsableTypedNode as _;
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! assign = { ASCII_ALPHA ~ "=" ~ number }
+//! number = @{ ASCII_DIGIT+ }
+//! "#]
+//! #[error_label(number = "a numeric literal")]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let error = pairs::assign::try_parse("a=b").unwrap_err();
+//! assert!(error.to_string().contains("Expected [a numeric literal]"));
+//! assert_eq!(Rule::number.error_label(), Some("a numeric literal"));
+//! assert_eq!(Rule::assign.error_label(), None);
+//! # }
+//! ```
+//!
+//! ### Incremental Reparsing
+//!
+//! A rule in the form of `item*` or `SOI ~ item* ~ EOI`, where `item` is a non-silent rule,
+//! has a method `reparse(edit, input)`.
+//! It takes the input of the tree with range `edit` replaced,
+//! reparses only the item covering the edit and moves spans after it,
+//! or parses the whole input again if the edit is not inside a single item:
+//!
+//! ```rust
+//! # use pest_typed::ParsableTypedNode as _;
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! WHITESPACE = _{ " " }
+//! item = { ASCII_ALPHA+ ~ ";" }
+//! file = { SOI ~ item* ~ EOI }
+//! "#]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let old = pairs::file::try_parse("a; bc; d;").unwrap();
+//! let new = old.reparse(4..5, "a; bxyz; d;").unwrap();
+//! assert_eq!(new, pairs::file::try_parse("a; bxyz; d;").unwrap());
+//! # }
+//! ```
+//!
+//! See [`pest_typed::incremental`] for reparsing other repetitions.
+//!
+//! ### Streaming Parsing
+//!
+//! A non-atomic rule in the form of `item*`, `SOI ~ item*`, `item* ~ EOI` or `SOI ~ item* ~ EOI`,
+//! where `item` is a rule in the grammar, has an associated function `parse_iter(input, f)`.
+//! It parses the whole input with the rule, but passes each item to `f` and drops it
+//! before parsing the next one, so memory doesn't grow with the number of items.
+//! Errors are the same as those of `try_parse`,
+//! and items before the failure have already been passed to `f` by then.
+//! Returning [`ControlFlow::Break`](core::ops::ControlFlow::Break) from `f` stops parsing without error:
+//!
+//! ```rust
+//! # use core::ops::ControlFlow;
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! WHITESPACE = _{ " " | NEWLINE }
+//! line = { ASCII_ALPHA+ ~ ";" }
+//! log  = { SOI ~ line* ~ EOI }
+//! "#]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let mut count = 0;
+//! pairs::log::parse_iter("a;\nbc;\nd;", |line| {
+//!     count += 1;
+//!     ControlFlow::Continue(())
+//! })
+//! .unwrap();
+//! assert_eq!(count, 3);
+//! assert!(pairs::log::parse_iter("a;\n1;", |_| ControlFlow::Continue(())).is_err());
+//! # }
+//! ```
+//!
 //! ### Pairs API
 //!
 //! Note: the simulated [Pairs](#https://docs.rs/pest/latest/pest/iterators/struct.Pairs.html) API behaves a bit different from original version. An atomic rule will not contain inner pairs.
 //!
+//! `display_tree` on a rule struct displays the pairs under it with their rules, spans and truncated matched text,
+//! which is more stable and readable than [`Debug`] for snapshot tests, as generic types inside rules are not shown.
+//!
 //! ### Accesser API
 //!
 //! See [`fn@derive_typed_parser`] for how to enable Accesser API.
@@ -58,6 +396,12 @@
 //!
 //! you can access `bar` from an instance `f` of `foo` by calling `f.bar()`.
 //!
+//! If `bar` is optional in `foo`, such as in `foo = { bar? }`, `f.bar()` returns an [`Option`],
+//! and `f.is_present_bar()` tells whether it's matched.
+//!
+//! If `bar` is not silent, `f.bar_span()` returns its [`Span`](pest_typed::Span) in the same shape,
+//! such as `Option<Span>` for an optional `bar`, so building diagnostics doesn't need to import [`Spanned`](pest_typed::Spanned).
+//!
 //! Given the pest grammar being:
 //!
 //! ```pest
@@ -70,6 +414,32 @@
 #![doc = include_str!("../examples/accesser_api.rs")]
 //! ```
 //!
+//! Stack operations have accesser functions as well, which return `Span`s rather than references:
+//! `pop()` for spans popped by `POP`, `peek()` for spans matched by `PEEK`,
+//! and `pushed()` for spans pushed by `PUSH`.
+//! If a rule has the same name, `_` is appended to the accesser until it no longer collides, such as `peek_()`.
+//!
+//! ```rust
+//! # use pest_typed::ParsableTypedNode as _;
+//! # use pest_typed_derive::TypedParser;
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! block = ${ PUSH("`"{3,}) ~ (!PEEK ~ ANY)* ~ POP }
+//! "#]
+//! #[emit_rule_reference]
+//! struct Parser;
+//!
+//! # fn main() {
+//! let block = pairs::block::try_parse("```` ``` ````").unwrap();
+//! assert_eq!(block.pushed().as_str(), "````");
+//! assert_eq!(block.pop().as_str(), "````");
+//! # }
+//! ```
+//!
+//! Accesser functions of a large grammar take a lot of code and compile time.
+//! To emit them for a few rules only, list those rules instead, such as `#[emit_rule_reference(expr, stmt)]`.
+//! Other rules still have their fields, but no accesser functions.
+//!
 //! ### Rule Structs
 //!
 //! We generate a **Rule Struct** for each rule.
@@ -77,13 +447,59 @@
 //!
 //! And the [pest](https://pest.rs) grammar is displayed in short in doc comments using [core::fmt::Display] so that you can view the structure without switching to `.pest` files.
 //!
+//! To keep generated code small, most rule structs are aliases of generic structs in `rules_impl::bodies`,
+//! each shared by rules with the same fields, atomicity and skipped contents,
+//! and told apart by the rule as a generic argument.
+//! Rules whose types may contain themselves, that is, heads of recursion cycles and skipped rules
+//! such as `WHITESPACE` and `COMMENT`, are still structs of their own.
+//! Both are used in the same way, though [`core::any::type_name`] shows the expanded type of an alias.
+//!
 //! #### Emitted Fields for Rule Structs
 //!
 //! There are three cases related to fields of a generated `struct`:
 //!
 //! - Emit inner nodes and a span (normal rule, non-atomic rule and compound atomic rule in **pest**).
-//! - Emit a span (atomic rule in **pest**).
-//! - Emit inner expression (silent rule in **pest**).
+//! - Emit a span (atomic rule in **pest**). With attribute `atomic_content`, atomic rules also emit inner nodes like compound atomic rules.
+//!   Rules listed in attribute `span_only` also emit only a span, though their inner expressions are parsed as usual.
+//! - Emit inner expression (silent rule in **pest**). With attribute `silent_spans`, silent rules also emit a span,
+//!   while they are still skipped by pair iteration as in **pest**.
+//!
+//! Rule structs with a span implement `pest_typed::Spanned`,
+//! which provides `as_str()` for the matched text, `input()` for the whole input,
+//! and `parse_to::<F>()` to parse it with [`core::str::FromStr`].
+//!
+//! Rule structs implement [`Clone`], [`Hash`](core::hash::Hash), [`PartialEq`] and [`Eq`].
+//! Those with a span also implement [`PartialOrd`] and [`Ord`], ordered by their spans,
+//! so nodes parsed from the same input can be sorted by position.
+//!
+//! Rules that are repetitions, such as `args = { arg* }`, implement `pest_typed::BoundWrapper`,
+//! whose `MIN` and `MAX` are the least and most numbers of repeated items.
+//! `MAX` is `usize::MAX` if unbounded. For example, `pairs::args::MIN` is `0`.
+//!
+//! Rule structs can also be created without parsing, by `new(content, span)`, `new(span)` or `new(content)`
+//! according to their fields, and from their inner nodes with [`From`], which gives an empty span.
+//! Rules that are repetitions or optional implement [`Default`] as well.
+//! As with stack accessers, `new` is suffixed with `_` if a rule has the same name.
+//!
+//! #### Serialization
+//!
+//! With feature `serde` of **pest_typed** enabled, rule structs and all generics implement `serde::Serialize`:
+//!
+//! - Normal rules are serialized as a struct with its `content` and `span`.
+//! - Atomic rules are serialized as the matched string.
+//! - Silent rules are serialized as their content.
+//! - A span is serialized as `{str, start, end}`.
+//! - Sequences are serialized as tuples, and choices are serialized as a map with the matched branch, such as `{"_0": ...}`.
+//! - Skipped `COMMENT` and `WHITESPACE` are not serialized.
+//!
+//! Feature `serde` of this crate additionally derives `serde::Serialize` for the `Rule` enumeration.
+//!
+//! #### Displaying Matched Text
+//!
+//! Rule structs and generics implement [core::fmt::Display] by writing exactly the text they matched,
+//! including skipped `COMMENT` and `WHITESPACE`, so `format!("{}", parsed)` reconstructs the input.
+//!
+//! Silent rules concatenate their inner nodes, while other rules write their spans.
 //!
 //! #### Example for Rule Structs
 //!
@@ -96,6 +512,22 @@
 //! We generate a **Rule Struct** for each tag.
 //! The inner structure is generated from the grammar structure inside the tag.
 //!
+//! With `emit_tagged_node_reference`, a tag `item` inside rule `list` is aliased as `tags::list::item`,
+//! which is the type of the node inside the tag,
+//! and its accesser is wrapped the same way as an accesser to a rule at that position:
+//!
+//! - `list = { (#item = expr)* }`: `list.item()` returns `Vec<&tags::list::item>`.
+//! - `list = { (#item = expr)? }`: `list.item()` returns `Option<&tags::list::item>`.
+//! - `list = { #item = expr }`: `list.item()` returns `&tags::list::item`.
+//!
+//! If a tag appears more than once in a rule with different types, it's not aliased,
+//! and its accesser returns references to the nodes of all occurrences.
+//!
+//! Inside rules that only keep their spans, such as atomic rules,
+//! a tag only keeps its span as well, and only tags are accessible from the rule.
+//! For example, with `number = @{ #int = (ASCII_DIGIT ~ ASCII_DIGIT*) ~ "." ~ #frac = (ASCII_DIGIT ~ ASCII_DIGIT*) }`,
+//! `number.int().span` is the integral part.
+//!
 //! #### Emitted Fields for Tag Structs
 //!
 //! Fields:
@@ -125,20 +557,20 @@
 //!
 //! Maybe we can use [`pest_meta::ast::Expr`](https://docs.rs/pest_meta/latest/pest_meta/ast/enum.Expr.html) by default in the future.
 //!
-//! |              Node Type              |                                                  Fields                                                   |                                             Functions                                             |
-//! | :---------------------------------: | :-------------------------------------------------------------------------------------------------------: | :-----------------------------------------------------------------------------------------------: |
-//! |           Non-silent rule           | Matched `content` (wrapped in a [`Box`]), which can be used to access matched expression; matched `span`. |                                See [Accesser API](#accesser-api).                                 |
-//! |    Exact string (case-sensitive)    |                                                                                                           | `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`. |
-//! |   Exact string (case-insensitive)   |                                     Matched `content` (an `&'i str`).                                     | `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`. |
-//! |        Sequence `T, Res...`         |                                       Matched `content` as a tuple.                                       |          `get_matched(&self)`, which returns referencs of all elemnets `(&elemnts...)`.           |
-//! |         Choices `T, Res...`         |                                   An enum, whose variants are choices.                                    |                       `if_then(&self)`, several functions `_0`, `_1`, etc.                        |
-//! | Optional (wrapped in an [`Option`]) |                                                                                                           |                                                                                                   |
-//! |          Repetition of `T`          |                                Matched `content` wrapped in a [`Vec<T>`].                                 | `iter_matched` and `iter_all` (by reference); `into_iter_matched` and `into_iter_all` (by value). |
-//! |         Positive predicate          |                                     Matched `content` (not consumed).                                     |                                                                                                   |
-//! |         Negative predicate          |                                                                                                           |                                                                                                   |
-//! |          `PUSH` and `PEEK`          |                                            Matched `content`.                                             |                                                                                                   |
-//! |         `POP` and `POP_ALL`         |                                              Popped `span`.                                               |                                                                                                   |
-//! |               `DROP`                |                                                                                                           |                                                                                                   |
+//! |              Node Type              |                                                  Fields                                                   |                                                         Functions                                                         |
+//! | :---------------------------------: | :-------------------------------------------------------------------------------------------------------: | :-----------------------------------------------------------------------------------------------------------------------: |
+//! |           Non-silent rule           | Matched `content` (wrapped in a [`Box`] if the rule is recursive), which can be used to access matched expression; matched `span`.|                                            See [Accesser API](#accesser-api).                                             |
+//! |    Exact string (case-sensitive)    |                                                                                                           |             `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`.             |
+//! |   Exact string (case-insensitive)   |                                     Matched `content` (an `&'i str`).                                     |             `const fn get_content(&self)` to get original string, which requires trait `pest_typed::Storage`.             |
+//! |        Sequence `T, Res...`         |                                       Matched `content` as a tuple.                                       |                      `get_matched(&self)`, which returns referencs of all elemnets `(&elemnts...)`.                       |
+//! |         Choices `T, Res...`         |                                   An enum, whose variants are choices.                                    |                                   `if_then(&self)`, several functions `_0`, `_1`, etc.                                    |
+//! | Optional (wrapped in an [`Option`]) |                                                                                                           |                                                                                                                           |
+//! |          Repetition of `T`          |          Matched `content` wrapped in a [`Vec<T>`], and `skipped` contents before each of them.           | `as_slice`, `iter_matched` and `iter_all` (by reference); `into_vec`, `into_iter_matched` and `into_iter_all` (by value). |
+//! |         Positive predicate          |                                     Matched `content` (not consumed).                                     |                                                                                                                           |
+//! |         Negative predicate          |                                                                                                           |                                                                                                                           |
+//! |          `PUSH` and `PEEK`          |                                            Matched `content`.                                             |                                                                                                                           |
+//! |         `POP` and `POP_ALL`         |                                              Popped `span`.                                               |                                                                                                                           |
+//! |               `DROP`                |                                                                                                           |                                                                                                                           |
 //!
 //! #### Sequence
 //!
@@ -146,6 +578,13 @@
 //!
 //! Both functions return a tuple.
 //!
+//! #### Stack
+//!
+//! `PUSH` pushes exactly the bytes consumed by its inner expression, the same as **pest**.
+//! Trivia skipped inside the expression, such as between two elements of a sequence, is included,
+//! while trivia skipped before the next element of the enclosing sequence is not.
+//! So `PEEK` in an atomic rule must match the embedded trivia literally.
+//!
 //! #### Choices
 //!
 //! Choices can be matched using `match`, as long as you find where its type is defined.
@@ -156,6 +595,9 @@
 //! with a bit simpler syntax.
 //! Note that you need to import module `generics` to use the macro.
 //!
+//! Choices with two branches can also be converted into a `pest_typed::choices::Either`
+//! with `into_either` (by value) or `as_either` (by reference).
+//!
 //! What's more, we provide several functions that simulate control structure
 //! like `if` (`if_then(f)`), `else-if` (`else_if(f)`) and `else` (`else_then(f)`).
 //!
@@ -171,12 +613,65 @@
 //!
 //! Using these functions, one can handle those cases one by one in order.
 //!
+//! With attribute `emit_match_macros`, a macro `match_<rule>!` is generated for each rule
+//! whose expression is a choice of distinct rules, such as `value = { object | array | null }`.
+//! Its arms are named after the rules, and either all of them or a wildcard arm `_` are required:
+//!
+//! ```rust,ignore
+//! let kind = match_value!(value, {
+//!     object(o) => o.span.as_str(),
+//!     _ => "not an object",
+//! });
+//! ```
+//!
+//! The macros are declared next to the derived parser,
+//! so they can only be used after it in the same module or its child modules.
+//! A missing arm without a wildcard fails to compile:
+//!
+//! ```compile_fail,E0004
+//! use pest_typed::ParsableTypedNode as _;
+//! use pest_typed_derive::TypedParser;
+//!
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! value  = { object | array | null }
+//! object = { "{}" }
+//! array  = { "[]" }
+//! null   = { "null" }
+//! "#]
+//! #[emit_match_macros]
+//! struct Parser;
+//!
+//! let value = pairs::value::try_parse("null").unwrap();
+//! let _ = match_value!(value, { object(_) => 0, array(_) => 1 });
+//! ```
+//!
 //! #### Example
 //!
 //! ```rust
 #![doc = include_str!("../examples/structural_api.rs")]
 //! ```
 //!
+//! ### Visitor API
+//!
+//! With attribute `emit_visitor`, a module `visitor` is generated, containing:
+//!
+//! - A trait `Visit<'i>` with a method `visit_<rule>` for each rule.
+//! - A function `walk_<rule>` for each rule, which visits rule structs inside a node of that rule in order.
+//!
+//! Each `visit_<rule>` calls `walk_<rule>` by default, so only methods for rules you care about need to be overridden.
+//! An overridden method can call `walk_<rule>` to keep walking into the node, or skip its children by not doing so.
+//!
+//! The methods are generic over `INHERITED` of rule structs, as a rule struct may be used in both atomic and non-atomic contexts:
+//!
+//! ```rust,ignore
+//! impl<'i> visitor::Visit<'i> for Counter {
+//!     fn visit_ident<const INHERITED: usize>(&mut self, node: &pairs::ident<'i, INHERITED>) {
+//!         self.count += 1;
+//!     }
+//! }
+//! ```
+//!
 //! ### Lifetime
 //!
 //! Structs have fields that contains references borrowed from the input, so each of them has a lifetime argument `'i`.
@@ -211,7 +706,31 @@ use proc_macro::TokenStream;
         truncate_accesser_at_node_tag,
         simulate_pair_api,
         box_only_if_needed,
+        box_all_rules,
         no_warnings,
+        inline_grammar_docs,
+        typed_vis,
+        skip_rules,
+        emit_visitor,
+        atomic_content,
+        span_only,
+        entry_rules,
+        recover_at,
+        error_label,
+        rule_recursion_limit,
+        roots,
+        deny_unused_rules,
+        lower_str_choices,
+        rule_non_exhaustive,
+        rule_repr,
+        silent_spans,
+        tokenize_literals,
+        emit_match_macros,
+        mod_name,
+        lower_rep_until,
+        emit_generator,
+        emit_pest_check,
+        unicode_case_insensitive,
     )
 )]
 pub fn derive_typed_parser(input: TokenStream) -> TokenStream {
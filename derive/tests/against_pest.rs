@@ -0,0 +1,121 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `check_against_pest` with `emit_pest_check` finds no difference from **pest**
+//! on a grammar with predicates, stack operations and atomic rules,
+//! and reports the known difference of pairs in compound atomic rules.
+
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r##"
+WHITESPACE = _{ " " | "\t" }
+COMMENT    = _{ "//" ~ (!NEWLINE ~ ANY)* }
+file       =  { SOI ~ (statement ~ NEWLINE)* ~ statement? ~ EOI }
+statement  = _{ binding | item }
+binding    =  { "let" ~ word ~ "=" ~ item }
+item       =  { heredoc | raw | list | number | quoted | word }
+heredoc    = @{ "<<" ~ PUSH(tag) ~ NEWLINE ~ body ~ POP }
+tag        = @{ ASCII_ALPHA_UPPER+ }
+body       = @{ (!PEEK ~ ANY)* }
+raw        = ${ "r" ~ PUSH("#"*) ~ "\"" ~ raw_inner ~ "\"" ~ POP }
+raw_inner  = _{ (!("\"" ~ PEEK) ~ ANY)* }
+list       =  { "[" ~ (item ~ ("," ~ item)*)? ~ &"]" ~ "]" }
+number     = @{ "-"? ~ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
+quoted     = ${ "\"" ~ inner ~ "\"" }
+inner      = _{ (escape | (!("\"" | "\\") ~ ANY))* }
+escape     = _{ "\\" ~ ANY }
+word       = @{ !keyword ~ ASCII_ALPHA ~ ASCII_ALPHANUMERIC* }
+keyword    =  { "let" ~ !ASCII_ALPHANUMERIC }
+"##]
+#[emit_pest_check]
+#[allow(dead_code)]
+struct Parser;
+
+const INPUTS: &[&str] = &[
+    "",
+    "let x = 1",
+    "let let = 1",
+    "letter",
+    "let x = [1, -2.5, \"a\\\"b\", [], [y]]",
+    "<<EOF\nline EO\nEOF",
+    "<<EOF\nno end",
+    "r##\"a\"#b\"##",
+    "r#\"a\"",
+    "[1 // comment\n]",
+    "let x = <<AB\nx\nAB\nlet y = r\"z\"\n[ 1 , 2 ]",
+    "x\n\ny\n",
+    "[1,]",
+    "\"unterminated",
+    "-",
+    "3.",
+];
+
+#[test]
+fn no_mismatch() {
+    for input in INPUTS {
+        // Pest panics if `PEEK` is called on an empty stack,
+        // so rules peeking what their callers pushed are only checked through them.
+        for rule in Rule::ALL
+            .iter()
+            .filter(|rule| !matches!(rule, Rule::body | Rule::raw_inner))
+        {
+            if let Err(mismatch) = check_against_pest(*rule, input) {
+                panic!("{rule:?} on {input:?}: {mismatch}");
+            }
+            for (end, _) in input.char_indices() {
+                if let Err(mismatch) = check_against_pest(*rule, &input[..end]) {
+                    panic!("{rule:?} on {:?}: {mismatch}", &input[..end]);
+                }
+            }
+        }
+    }
+}
+
+mod compound {
+    use pest_typed::testing::{FlatPair, Mismatch};
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+quoted = ${ "'" ~ inner ~ "'" }
+inner  =  { ASCII_ALPHA* }
+"#]
+    #[emit_pest_check]
+    #[allow(dead_code)]
+    struct Parser;
+
+    /// Compound atomic rules have no inner pairs in pest-typed.
+    #[test]
+    fn inner_pairs() {
+        let mismatch = check_against_pest(Rule::quoted, "'ab'").unwrap_err();
+        assert_eq!(
+            mismatch,
+            Mismatch::Pairs {
+                index: 1,
+                typed: None,
+                pest: Some(FlatPair {
+                    rule: String::from("inner"),
+                    start: 1,
+                    end: 3,
+                }),
+            }
+        );
+        assert_eq!(
+            mismatch.to_string(),
+            "Pair 1 is nothing in pest-typed, but inner 1..3 in pest."
+        );
+        assert_eq!(check_against_pest(Rule::inner, "ab"), Ok(()));
+        assert_eq!(
+            check_against_pest(Rule::quoted, "'ab"),
+            Ok(()),
+            "Both fail."
+        );
+    }
+}
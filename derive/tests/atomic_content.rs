@@ -0,0 +1,51 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{ParsableTypedNode as _, Spanned};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+number     = @{ "-"? ~ ASCII_DIGIT+ }
+fraction   = @{ number ~ ("." ~ ASCII_DIGIT+)? }
+"#]
+#[emit_rule_reference]
+#[atomic_content]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn signed_number() -> anyhow::Result<()> {
+    let number = pairs::number::try_parse("-12")?;
+    // Digits are split into `ASCII_DIGIT ~ ASCII_DIGIT*` unless `grammar-extras` keeps `ASCII_DIGIT+`,
+    // so only the sign is destructured.
+    let (sign, ..) = number.get_matched();
+    assert!(sign.present());
+    assert_eq!(number.span().as_str(), "-12");
+
+    let number = pairs::number::try_parse("7")?;
+    let (sign, ..) = number.get_matched();
+    assert!(!sign.present());
+    Ok(())
+}
+
+#[test]
+fn no_implicit_whitespace() {
+    pairs::number::try_parse("- 12").unwrap_err();
+    pairs::fraction::try_parse("1 .5").unwrap_err();
+}
+
+#[test]
+fn accesser() -> anyhow::Result<()> {
+    let fraction = pairs::fraction::try_parse("-3.25")?;
+    assert_eq!(fraction.number().span.as_str(), "-3");
+    assert!(fraction.number().get_matched().0.present());
+    Ok(())
+}
@@ -0,0 +1,119 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Grammars without any non-atomic rule use no skipping machinery.
+
+mod typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+ident  = @{ ASCII_ALPHA ~ (ASCII_ALPHANUMERIC | "_")* }
+number = @{ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
+string = ${ "\"" ~ inner ~ "\"" }
+inner  = @{ (!"\"" ~ ANY)* }
+token  = ${ ident | number | string }
+tokens = ${ (token ~ " "*)* }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+
+    /// The same rules, with a normal rule in it.
+    pub mod mixed {
+        use pest_typed_derive::TypedParser;
+
+        #[derive(TypedParser)]
+        #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident  = @{ ASCII_ALPHA ~ (ASCII_ALPHANUMERIC | "_")* }
+number = @{ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
+string = ${ "\"" ~ inner ~ "\"" }
+inner  = @{ (!"\"" ~ ANY)* }
+token  = ${ ident | number | string }
+tokens =  { token* }
+"#]
+        #[emit_rule_reference]
+        #[allow(dead_code)]
+        pub struct Parser;
+    }
+}
+
+mod untyped {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r#"
+ident  = @{ ASCII_ALPHA ~ (ASCII_ALPHANUMERIC | "_")* }
+number = @{ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
+string = ${ "\"" ~ inner ~ "\"" }
+inner  = @{ (!"\"" ~ ANY)* }
+token  = ${ ident | number | string }
+tokens = ${ (token ~ " "*)* }
+"#]
+    pub struct Parser;
+}
+
+use pest::Parser as _;
+use pest_typed::{ParsableTypedNode as _, Spanned};
+
+const INPUTS: &[&str] = &[
+    "",
+    "abc",
+    "a_1 23 4.5 \"x y\"",
+    "1.",
+    "\"unterminated",
+    "_a",
+    "a  b",
+    " a",
+    "a\tb",
+];
+
+/// End of the longest match of `tokens`, if it matches the whole input.
+fn typed(input: &str) -> Option<usize> {
+    typed::pairs::tokens::try_parse(input)
+        .ok()
+        .map(|tokens| tokens.span().end())
+}
+
+fn untyped(input: &str) -> Option<usize> {
+    let mut pairs = untyped::Parser::parse(untyped::Rule::tokens, input).ok()?;
+    let end = pairs.next()?.as_span().end();
+    (end == input.len()).then_some(end)
+}
+
+#[test]
+fn parity() {
+    for input in INPUTS {
+        assert_eq!(typed(input), untyped(input), "{:?}", input);
+    }
+}
+
+#[test]
+fn nodes() -> anyhow::Result<()> {
+    let tokens = typed::pairs::tokens::try_parse("a_1 23 \"x y\"")?;
+    let spans = tokens.token().into_iter().map(|t| t.span.as_str());
+    assert_eq!(spans.collect::<Vec<_>>(), ["a_1", "23", "\"x y\""]);
+    let string = typed::pairs::string::try_parse("\"x y\"")?;
+    assert_eq!(string.inner().span.as_str(), "x y");
+    Ok(())
+}
+
+#[test]
+fn mixed() -> anyhow::Result<()> {
+    let tokens = typed::mixed::pairs::tokens::try_parse("a_1 23  \"x y\" ")?;
+    let spans = tokens.token().into_iter().map(|t| t.span.as_str());
+    assert_eq!(spans.collect::<Vec<_>>(), ["a_1", "23", "\"x y\""]);
+    // A rule that is just a repetition can be indexed directly.
+    assert_eq!(tokens.len(), 3);
+    assert_eq!(tokens[1].span.as_str(), "23");
+    assert_eq!(tokens.get(1), Some(&tokens[1]));
+    assert_eq!(tokens.last().unwrap().span.as_str(), "\"x y\"");
+    Ok(())
+}
@@ -0,0 +1,57 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Tags inside atomic rules, which only keep the spans of tagged sub-expressions.
+
+#![cfg(feature = "grammar-extras")]
+
+use pest_typed::{ParsableTypedNode as _, Span};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+sign   = { "-" }
+number = @{ (#sign = sign)? ~ #int = (ASCII_DIGIT ~ ASCII_DIGIT*) ~ ("." ~ #frac = (ASCII_DIGIT ~ ASCII_DIGIT*))? }
+list   = { number* }
+"#]
+#[emit_rule_reference]
+#[emit_tagged_node_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn spans() {
+    let number = pairs::number::try_parse("-12.50").unwrap();
+    let int: &tags::number::int<'_, 1> = number.int();
+    let span: Span<'_> = int.span;
+    assert_eq!(span.as_str(), "12");
+    assert_eq!(number.frac().unwrap().span.as_str(), "50");
+    assert_eq!(number.sign().unwrap().span.as_str(), "-");
+    assert_eq!(number.span.as_str(), "-12.50");
+
+    let number = pairs::number::try_parse("7").unwrap();
+    assert_eq!(number.int().span.as_str(), "7");
+    assert!(number.frac().is_none());
+    assert!(number.sign().is_none());
+}
+
+#[test]
+fn no_skipping() {
+    assert!(pairs::number::try_parse("12 .5").is_err());
+    assert!(pairs::number::try_parse("- 12").is_err());
+
+    let list = pairs::list::try_parse("1.5 -2 30").unwrap();
+    let ints = list
+        .number()
+        .iter()
+        .map(|n| n.int().span.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(ints, ["1", "2", "30"]);
+}
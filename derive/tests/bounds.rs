@@ -0,0 +1,56 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rule structs wrapping a repetition forward [`BoundWrapper`] to it.
+
+use pest_typed::{BoundWrapper, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+arg    = @{ ASCII_DIGIT+ }
+args   = { arg* }
+digits = @{ ASCII_DIGIT* }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn rep() {
+    assert_eq!(pairs::args::MIN, 0);
+    assert_eq!(pairs::args::MAX, usize::MAX);
+    assert_eq!(pairs::digits::MIN, 0);
+    assert_eq!(pairs::digits::MAX, usize::MAX);
+
+    let args = pairs::args::try_parse("1 22").unwrap();
+    assert_eq!(args.get_min_len(), 0);
+    assert_eq!(args.get_max_len(), usize::MAX);
+}
+
+#[cfg(feature = "grammar-extras")]
+mod rep_once {
+    use pest_typed::BoundWrapper;
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+arg  = @{ ASCII_DIGIT+ }
+args = { arg+ }
+"#]
+    #[allow(dead_code)]
+    struct Parser;
+
+    #[test]
+    fn rep_once() {
+        assert_eq!(pairs::args::MIN, 1);
+        assert_eq!(pairs::args::MAX, usize::MAX);
+    }
+}
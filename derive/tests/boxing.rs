@@ -0,0 +1,96 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Only rules in recursion cycles are boxed, unless `box_all_rules` is given.
+
+mod inline {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+kw_let     =  { "let" }
+kw_if      =  { "if" }
+kw_then    =  { "then" }
+kw_else    =  { "else" }
+kw_in      =  { "in" }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+keyword    =  { kw_let | kw_if | kw_then | kw_else | kw_in }
+atom       =  { number | ident | "(" ~ expr ~ ")" }
+expr       =  { kw_let ~ ident ~ "=" ~ expr ~ kw_in ~ expr | kw_if ~ expr ~ kw_then ~ expr ~ kw_else ~ expr | atom ~ ("+" ~ atom)* }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod boxed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+kw_let     =  { "let" }
+kw_if      =  { "if" }
+kw_then    =  { "then" }
+kw_else    =  { "else" }
+kw_in      =  { "in" }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+keyword    =  { kw_let | kw_if | kw_then | kw_else | kw_in }
+atom       =  { number | ident | "(" ~ expr ~ ")" }
+expr       =  { kw_let ~ ident ~ "=" ~ expr ~ kw_in ~ expr | kw_if ~ expr ~ kw_then ~ expr ~ kw_else ~ expr | atom ~ ("+" ~ atom)* }
+"#]
+    #[emit_rule_reference]
+    #[box_all_rules]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+use core::mem::size_of;
+use pest_typed::{ParsableTypedNode as _, Span};
+
+const INPUT: &str = "let x = if a then (1 + b) else 2 in x + (let y = 3 in y)";
+
+#[test]
+fn parse() {
+    let expr = inline::pairs::expr::try_parse(INPUT).unwrap();
+    assert_eq!(expr.span.as_str(), INPUT);
+    let expr = boxed::pairs::expr::try_parse(INPUT).unwrap();
+    assert_eq!(expr.span.as_str(), INPUT);
+
+    for input in ["let", "if", "then", "else", "in"] {
+        inline::pairs::keyword::try_parse(input).unwrap();
+        boxed::pairs::keyword::try_parse(input).unwrap();
+    }
+}
+
+#[test]
+fn size() {
+    // Keywords only keep their spans.
+    assert_eq!(
+        size_of::<inline::pairs::kw_let<'_>>(),
+        size_of::<Span<'_>>()
+    );
+    assert_eq!(
+        size_of::<boxed::pairs::kw_let<'_>>(),
+        size_of::<Span<'_>>() + size_of::<Box<()>>()
+    );
+    assert!(size_of::<inline::pairs::keyword<'_>>() > size_of::<boxed::pairs::keyword<'_>>());
+    // Rules in the cycle are always boxed.
+    assert_eq!(
+        size_of::<inline::pairs::expr<'_>>(),
+        size_of::<boxed::pairs::expr<'_>>()
+    );
+    assert_eq!(
+        size_of::<inline::pairs::atom<'_>>(),
+        size_of::<boxed::pairs::atom<'_>>()
+    );
+}
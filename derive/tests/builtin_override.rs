@@ -0,0 +1,60 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rules defined in the grammar take precedence over built-in rules with the same names.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+NEWLINE     = { ";" }
+ASCII_DIGIT = { "x" }
+line        = { ASCII_DIGIT+ ~ NEWLINE }
+lines       = { SOI ~ line* ~ EOI }
+atomic      = @{ ASCII_DIGIT ~ NEWLINE }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+/// Accessers to `x+` return `(first, rest)` as the optimizer splits it into `x ~ x*`,
+/// or a single `Vec` with `grammar-extras`, which keeps the repetition.
+#[cfg(not(feature = "grammar-extras"))]
+fn one_or_more<T>((first, rest): (T, Vec<T>)) -> Vec<T> {
+    core::iter::once(first).chain(rest).collect()
+}
+#[cfg(feature = "grammar-extras")]
+fn one_or_more<T>(all: Vec<T>) -> Vec<T> {
+    all
+}
+
+#[test]
+fn newline() {
+    let line = pairs::line::try_parse("xx;").unwrap();
+    assert_eq!(line.NEWLINE().span.as_str(), ";");
+    assert!(pairs::line::try_parse("xx\n").is_err());
+    assert!(pairs::NEWLINE::try_parse("\n").is_err());
+}
+
+#[test]
+fn ascii_digit() {
+    let line = pairs::line::try_parse("xx;").unwrap();
+    let digits = one_or_more(line.ASCII_DIGIT());
+    assert_eq!(digits.len(), 2);
+    assert_eq!(digits[0].span.as_str(), "x");
+    assert!(pairs::line::try_parse("12;").is_err());
+
+    let atomic = pairs::atomic::try_parse("x;").unwrap();
+    assert_eq!(atomic.span.as_str(), "x;");
+    assert!(pairs::atomic::try_parse("1\n").is_err());
+
+    let lines = pairs::lines::try_parse("x;xx;").unwrap();
+    assert_eq!(lines.line().len(), 2);
+}
@@ -0,0 +1,109 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `!("a" | "b" | ...) ~ ANY` is lowered to `CharExcept` where nothing is skipped in between.
+
+mod typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+string     = @{ "\"" ~ ("\\" ~ ANY | !("\"" | "\\") ~ ANY)* ~ "\"" }
+not_end    = ${ !("end" | "e") ~ ANY }
+spaced     =  { !"x" ~ ANY }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod untyped {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+string     = @{ "\"" ~ ("\\" ~ ANY | !("\"" | "\\") ~ ANY)* ~ "\"" }
+not_end    = ${ !("end" | "e") ~ ANY }
+spaced     =  { !"x" ~ ANY }
+"#]
+    pub struct Parser;
+}
+
+use pest::Parser as _;
+use pest_typed::{ParsableTypedNode, Spanned};
+
+fn typed<'i, N: ParsableTypedNode<'i, typed::Rule>>(input: &'i str) -> Option<usize> {
+    N::try_parse_partial(input).ok().map(|(pos, _)| pos.pos())
+}
+
+fn untyped(rule: untyped::Rule, input: &str) -> Option<usize> {
+    let mut pairs = untyped::Parser::parse(rule, input).ok()?;
+    Some(pairs.next()?.as_span().end())
+}
+
+#[test]
+fn string() {
+    let long = format!("\"{}\"", "ab\\\"cd ".repeat(4096));
+    let inputs = [
+        "\"\"",
+        "\"abc\"",
+        "\"a\\\"b\"",
+        "\"a\\\\\"",
+        "\"héllo 世界 🎉\"",
+        "\"\\é\"",
+        "\"unterminated",
+        "\"a\\\"",
+        "abc",
+        long.as_str(),
+    ];
+    for input in inputs {
+        assert_eq!(
+            typed::<typed::pairs::string<'_>>(input),
+            untyped(untyped::Rule::string, input),
+            "{:?}",
+            input
+        );
+    }
+    let string = typed::pairs::string::try_parse(&long).unwrap();
+    assert_eq!(string.span().as_str(), long);
+}
+
+#[test]
+fn node() -> anyhow::Result<()> {
+    let not_end = typed::pairs::not_end::try_parse("世")?;
+    assert_eq!(not_end.content.content, '世');
+    assert_eq!(not_end.to_string(), "世");
+    Ok(())
+}
+
+#[test]
+fn multiple_chars() {
+    for input in ["", "a", "é", "🎉", "e", "en", "end", "x"] {
+        assert_eq!(
+            typed::<typed::pairs::not_end<'_>>(input),
+            untyped(untyped::Rule::not_end, input),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn skipped_in_between() {
+    for input in ["a", "x", " x", "  y", " "] {
+        assert_eq!(
+            typed::<typed::pairs::spaced<'_>>(input),
+            untyped(untyped::Rule::spaced, input),
+            "{:?}",
+            input
+        );
+    }
+}
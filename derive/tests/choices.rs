@@ -24,7 +24,14 @@ c9  = { ("a" | "b" | "c" | "d" | "e" | "f" | "g" | "h" | "i"){9} }
 c10 = { ("a" | "b" | "c" | "d" | "e" | "f" | "g" | "h" | "i" | "j"){10} }
 c11 = { ("a" | "b" | "c" | "d" | "e" | "f" | "g" | "h" | "i" | "j" | "k"){11} }
 c12 = { ("a" | "b" | "c" | "d" | "e" | "f" | "g" | "h" | "i" | "j" | "k" | "l"){12} }
+
+number  = @{ ASCII_DIGIT+ }
+string  = @{ "'" ~ (!"'" ~ ANY)* ~ "'" }
+boolean =  { "true" | "false" }
+null    =  { "null" }
+value   =  { number | string | boolean | null }
 "#]
+#[allow(dead_code)]
 struct Parser;
 
 macro_rules! matching {
@@ -88,6 +95,7 @@ test!(c11, "a""b""c""d""e""f""g""h""i""j""k");
 test!(c12, "a""b""c""d""e""f""g""h""i""j""k""l");
 
 #[test]
+#[allow(clippy::just_underscores_and_digits)]
 fn choices() {
     let c4 = pairs::c4::try_parse("abcd").unwrap();
     let (_0, _1, _2, _3) = c4.as_ref();
@@ -130,3 +138,41 @@ fn choices() {
     t!(_2);
     t!(_3);
 }
+
+#[test]
+fn exhaustive_match() -> Result<(), anyhow::Error> {
+    use pest_typed::choices::{Choice4, Either};
+
+    fn describe(input: &str) -> Result<String, anyhow::Error> {
+        let value = pairs::value::try_parse(input)?;
+        let res = match &value.content {
+            Choice4::_0(number) => format!("number {}", number.span.as_str()),
+            Choice4::_1(string) => format!("string {}", string.span.as_str()),
+            Choice4::_2(boolean) => match boolean.content.as_either() {
+                Either::Left(_) => String::from("true"),
+                Either::Right(_) => String::from("false"),
+            },
+            Choice4::_3(_) => String::from("null"),
+        };
+        Ok(res)
+    }
+
+    assert_eq!(describe("42")?, "number 42");
+    assert_eq!(describe("'a b'")?, "string 'a b'");
+    assert_eq!(describe("true")?, "true");
+    assert_eq!(describe("false")?, "false");
+    assert_eq!(describe("null")?, "null");
+    Ok(())
+}
+
+#[test]
+fn either() -> Result<(), anyhow::Error> {
+    use pest_typed::choices::{Choice2, Either};
+
+    let boolean = pairs::boolean::try_parse("false")?;
+    let choice = boolean.content.clone();
+    assert!(matches!(choice.as_either(), Either::Right(_)));
+    let either = choice.clone().into_either();
+    assert_eq!(Choice2::from(either), choice);
+    Ok(())
+}
@@ -0,0 +1,191 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Parse with the same generated types on multiple threads.
+
+use pest_typed::{error::Error, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+use std::{
+    collections::hash_map::DefaultHasher,
+    hash::{Hash, Hasher},
+    thread,
+};
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+fence      = @{ "`"+ }
+quoted     = ${ PUSH(fence) ~ (!PEEK ~ ANY)* ~ POP }
+value      =  { number | ident | quoted }
+pair       =  { ident ~ "=" ~ value }
+file       =  { SOI ~ (pair ~ ";")* ~ EOI }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+/// A grammar covering most kinds of nodes.
+mod grammar {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar = "tests/grammar.pest"]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+const INPUTS: &[&str] = &[
+    "a = 1; b = c;",
+    "a = `x` ; b = ``y`z``;",
+    "",
+    "a = ;",
+    "a = `x``;",
+    "a = 1 b = 2;",
+    "= 1;",
+];
+const THREADS: usize = 8;
+const ROUNDS: usize = 250;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn send_sync() {
+    assert_send_sync::<Parser>();
+    assert_send_sync::<Rule>();
+    assert_send_sync::<pairs::file<'static>>();
+    assert_send_sync::<pairs::quoted<'static>>();
+    assert_send_sync::<Error<Rule>>();
+}
+
+macro_rules! assert_send_sync {
+    ($($rule:ident,)*) => {
+        $(assert_send_sync::<grammar::pairs::$rule<'static>>();)*
+    };
+}
+
+#[test]
+fn send_sync_all_rules() {
+    assert_send_sync!(
+        string,
+        insensitive,
+        range,
+        ident,
+        pos_pred,
+        neg_pred,
+        double_neg_pred,
+        sequence,
+        sequence_compound,
+        sequence_atomic,
+        sequence_non_atomic,
+        sequence_atomic_compound,
+        sequence_nested,
+        sequence_compound_nested,
+        node_tag,
+        choice,
+        choice_prefix,
+        optional,
+        repeat,
+        repeat_atomic,
+        repeat_once,
+        repeat_once_atomic,
+        repeat_min_max,
+        repeat_min_max_atomic,
+        repeat_exact,
+        repeat_min,
+        repeat_min_atomic,
+        repeat_max,
+        repeat_max_atomic,
+        soi_at_start,
+        repeat_mutate_stack,
+        repeat_mutate_stack_pop_all,
+        will_fail,
+        stack_resume_after_fail,
+        peek_,
+        peek_all,
+        peek_slice_23,
+        pop_,
+        pop_all,
+        pop_fail,
+        checkpoint_restore,
+        ascii_digits,
+        ascii_nonzero_digits,
+        ascii_bin_digits,
+        ascii_oct_digits,
+        ascii_hex_digits,
+        ascii_alpha_lowers,
+        ascii_alpha_uppers,
+        ascii_alphas,
+        ascii_alphanumerics,
+        asciis,
+        newline,
+        unicode,
+        SYMBOL,
+        han,
+        hangul,
+        hiragana,
+        arabic,
+        emoji,
+        WHITESPACE,
+        COMMENT,
+    );
+}
+
+#[test]
+fn move_across_threads() {
+    let input = String::from("a = `x` ; b = 12;");
+    let file = pairs::file::try_parse(&input).unwrap();
+    let expected = format!("{:?}", file);
+    let actual = thread::scope(|scope| {
+        scope
+            .spawn(move || {
+                assert_eq!(file.pair().len(), 2);
+                format!("{:?}", file)
+            })
+            .join()
+            .unwrap()
+    });
+    assert_eq!(actual, expected);
+
+    let file = pairs::file::try_parse("a = 1;").unwrap();
+    let pairs = thread::spawn(move || file.pair().len()).join().unwrap();
+    assert_eq!(pairs, 1);
+}
+
+/// Hash of the output of parsing all inputs.
+fn digest() -> u64 {
+    let mut hasher = DefaultHasher::new();
+    for input in INPUTS {
+        match pairs::file::try_parse(input) {
+            Ok(file) => format!("{:?}", file).hash(&mut hasher),
+            Err(error) => error.to_string().hash(&mut hasher),
+        }
+        let (res, farthest) = pairs::file::try_parse_partial_with_farthest(input);
+        res.map(|(pos, _)| pos.pos()).ok().hash(&mut hasher);
+        farthest.pos().hash(&mut hasher);
+    }
+    hasher.finish()
+}
+
+#[test]
+fn parallel() {
+    let expected = digest();
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..THREADS)
+            .map(|_| scope.spawn(|| (0..ROUNDS).map(|_| digest()).collect::<Vec<_>>()))
+            .collect();
+        for handle in handles {
+            for actual in handle.join().unwrap() {
+                assert_eq!(actual, expected);
+            }
+        }
+    });
+}
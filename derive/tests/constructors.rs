@@ -0,0 +1,69 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Creating nodes without parsing.
+
+use pest_typed::{ContentEq, ParsableTypedNode as _, Span};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+number     = @{ ASCII_DIGIT+ }
+list       =  { number* }
+numbers    = _{ number* }
+signed     =  { "-"? }
+maybe      =  { number? }
+new        =  { "new" ~ number }
+"#]
+#[no_warnings]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+/// The constructor is `new_`, as `new` is the accesser to rule `new`.
+fn number(digits: &str) -> pairs::number<'_> {
+    pairs::number::new_(Span::new(digits, 0, digits.len()).unwrap())
+}
+
+#[test]
+fn repetition() {
+    let parsed = pairs::list::try_parse("1  23").unwrap();
+
+    let mut list = pairs::list::default();
+    assert!(!list.content_eq(&parsed));
+    for digits in ["1", "23"] {
+        list.content.content.push(number(digits));
+        list.content.skipped.push(Default::default());
+    }
+    assert!(list.content_eq(&parsed));
+    assert_ne!(list, parsed);
+    assert_eq!(list.span, Span::default());
+
+    let rebuilt = pairs::list::new_(parsed.content.clone(), parsed.span);
+    assert_eq!(rebuilt, parsed);
+
+    let numbers = pairs::numbers::from(list.content.clone());
+    assert!(numbers.content_eq(&pairs::numbers::try_parse("1 23").unwrap()));
+}
+
+#[test]
+fn optional() {
+    let parsed = pairs::maybe::try_parse("7").unwrap();
+    let maybe = pairs::maybe::from(Some(number("7")));
+    assert!(maybe.content_eq(&parsed));
+    assert_eq!(maybe.number().unwrap().span.as_str(), "7");
+
+    // `"-"?` is matched by `Presence`, so `signed` has no `Default`.
+    assert!(pairs::signed::try_parse("-").unwrap().content.present());
+
+    let empty = pairs::maybe::try_parse("").unwrap();
+    assert!(pairs::maybe::default().content_eq(&empty));
+    assert!(!maybe.content_eq(&empty));
+}
@@ -0,0 +1,52 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Comparing generated trees by content, ignoring spans and skipped contents.
+
+use pest_typed::{ContentEq, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\t" }
+foo        =  { "foo" }
+foos       =  { foo* }
+ident      = @{ ASCII_ALPHA+ }
+select     =  { ^"select" ~ ident ~ (^"from" ~ ident)? }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn spans() {
+    let lhs = pairs::foos::try_parse("foo  foo").unwrap();
+    let rhs = pairs::foos::try_parse("foo foo").unwrap();
+    assert_ne!(lhs, rhs);
+    assert!(lhs.content_eq(&rhs));
+    assert!(lhs.content_eq_ignore_case(&rhs));
+
+    let three = pairs::foos::try_parse("foo foo\tfoo").unwrap();
+    assert!(!lhs.content_eq(&three));
+    assert!(!lhs.content_eq_ignore_case(&three));
+}
+
+#[test]
+fn ignore_case() {
+    let lhs = pairs::select::try_parse("select a from b").unwrap();
+    let rhs = pairs::select::try_parse("SELECT  a  From b").unwrap();
+    assert!(!lhs.content_eq(&rhs));
+    assert!(lhs.content_eq_ignore_case(&rhs));
+
+    // Matched texts of atomic rules are still compared exactly.
+    let upper = pairs::select::try_parse("SELECT A FROM B").unwrap();
+    assert!(!lhs.content_eq_ignore_case(&upper));
+
+    let short = pairs::select::try_parse("Select a").unwrap();
+    assert!(!lhs.content_eq_ignore_case(&short));
+}
@@ -0,0 +1,100 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use anyhow::Error as AnyError;
+use pest_typed::{
+    error::{CustomError as _, Error, ErrorVariant, InputLocation, LineColLocation},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+use std::collections::BTreeSet;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | NEWLINE }
+object     =  { "{" ~ (entry ~ ("," ~ entry)*)? ~ "}" }
+entry      =  { key ~ ":" ~ value }
+key        = @{ ASCII_ALPHA+ }
+value      = @{ ASCII_DIGIT+ }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn check(object: &pairs::object<'_>) -> Result<(), Box<Error<Rule>>> {
+    let mut keys = BTreeSet::new();
+    if let Some((first, rest)) = object.entry() {
+        for entry in Some(first).into_iter().chain(rest) {
+            let key = entry.key();
+            if !keys.insert(key.span.as_str()) {
+                return Err(Box::new(Error::custom_at_span(
+                    key.span,
+                    format!("duplicate key `{}`", key.span.as_str()),
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[test]
+fn at_span() -> Result<(), AnyError> {
+    let object = pairs::object::try_parse("{\n  a: 1,\n  bc: 2,\n  bc: 3\n}")?;
+    let error = check(&object).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 4:3
+  |
+4 |   bc: 3
+  |   ^^
+  |
+  = duplicate key `bc`"
+    );
+    assert_eq!(error.location, InputLocation::Span((21, 23)));
+    assert_eq!(error.line_col, LineColLocation::Span((4, 3), (4, 5)));
+    assert_eq!(
+        error.variant,
+        ErrorVariant::CustomError {
+            message: "duplicate key `bc`".to_owned()
+        }
+    );
+    Ok(())
+}
+
+#[test]
+fn at_pos() -> Result<(), AnyError> {
+    let object = pairs::object::try_parse("{ a: 1 }")?;
+    let error = Error::<Rule>::custom_at_pos(object.span.end_pos(), "expected `;`");
+    assert_eq!(
+        error.to_string(),
+        " --> 1:9
+  |
+1 | { a: 1 }
+  |         ^---
+  |
+  = expected `;`"
+    );
+    assert_eq!(error.location, InputLocation::Pos(8));
+    Ok(())
+}
+
+#[test]
+fn same_format_as_parse_errors() {
+    let input = "{ a: 1, }";
+    let parsed = pairs::object::try_parse(input).unwrap_err();
+    let InputLocation::Pos(pos) = parsed.location else {
+        unreachable!()
+    };
+    let pos = pest_typed::Position::new(input, pos).unwrap();
+    let ErrorVariant::CustomError { message } = &parsed.variant else {
+        unreachable!()
+    };
+    let custom = Error::<Rule>::custom_at_pos(pos, message.clone());
+    assert_eq!(custom.to_string(), parsed.to_string());
+}
@@ -0,0 +1,79 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use anyhow::Error;
+use pest_typed::{iterators::Pairs as _, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+list       =  { "[" ~ (item ~ ("," ~ item)*)? ~ "]" }
+item       = _{ list | number }
+number     = @{ ASCII_DIGIT+ }
+digits     =  { ASCII_DIGIT* }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn limited() -> Result<(), Error> {
+    let list = pairs::list::try_parse("[1, [2, [3, [4]]], 5, 6, 7]")?;
+    assert_eq!(
+        format!("{:#?}", list.debug_limited(3, 2)),
+        r#"[
+    list {
+        span: 0..27,
+        inner: [
+            number {
+                span: 1..2,
+                str: "1",
+            },
+            list {
+                span: 4..17,
+                inner: [
+                    number {
+                        span: 5..6,
+                        str: "2",
+                    },
+                    list {
+                        span: 8..16,
+                        inner: … depth limit,
+                    },
+                ],
+            },
+            … +3 children,
+        ],
+    },
+]"#
+    );
+    assert_eq!(
+        format!("{:?}", list.debug_limited(1, 1)),
+        "[list { span: 0..27, inner: … depth limit }]"
+    );
+    assert_eq!(
+        format!("{:?}", list.debug_limited(2, 1)),
+        r#"[list { span: 0..27, inner: [number { span: 1..2, str: "1" }, … +4 children] }]"#
+    );
+    assert_eq!(format!("{:?}", list.debug_limited(0, 1)), "… depth limit");
+    Ok(())
+}
+
+#[test]
+fn full_debug_unchanged() -> Result<(), Error> {
+    let digits = pairs::digits::try_parse("123")?;
+    assert_eq!(
+        format!("{:?}", digits),
+        "r#digits { content: RepMin { \
+content: [CharRange { content: '1' }, CharRange { content: '2' }, CharRange { content: '3' }], \
+skipped: [[AtomicRep { content: [] }], [AtomicRep { content: [] }], [AtomicRep { content: [] }]] \
+}, span: Span { str: \"123\", start: 0, end: 3 } }"
+    );
+    Ok(())
+}
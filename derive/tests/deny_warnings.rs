@@ -0,0 +1,44 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Generated codes compile without warnings, without any `allow` from the consumer.
+
+#![deny(warnings)]
+
+use pest_typed_derive::TypedParser;
+
+/// Grammar covering most expressions.
+#[derive(TypedParser)]
+#[grammar = "tests/grammar.pest"]
+#[emit_rule_reference]
+pub struct GrammarParser;
+
+/// Rules in camel case and a single-element sequence.
+pub mod camel {
+    use pest_typed_derive::TypedParser;
+
+    /// Parser with rules named in camel case.
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+Ident      = @{ ASCII_ALPHA+ }
+KeyValue   =  { Ident ~ "=" ~ Ident }
+Items      =  { (KeyValue ~ ";")* }
+Single     =  { (Ident) }
+"#]
+    #[emit_rule_reference]
+    pub struct Parser;
+}
+
+#[test]
+fn compiles() {
+    use pest_typed::ParsableTypedNode as _;
+    let items = camel::pairs::Items::try_parse("a = b; c = d;").unwrap();
+    assert_eq!(items.KeyValue().len(), 2);
+}
@@ -0,0 +1,68 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\t" | NEWLINE }
+COMMENT    = _{ "//" ~ (!NEWLINE ~ ANY)* | "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+program    =  { SOI ~ item* ~ EOI }
+item       = _{ assignment | call }
+assignment =  { ^"let" ~ name ~ "=" ~ value ~ ";" }
+call       =  { name ~ "(" ~ args? ~ ")" ~ ";" }
+args       = _{ value ~ ("," ~ value)* ~ ","? }
+value      = _{ number | name | string }
+number     = @{ "-"? ~ ASCII_DIGIT+ }
+name       = @{ XID_START ~ XID_CONTINUE* }
+string     = ${ "\"" ~ (!"\"" ~ ANY)* ~ "\"" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+const INPUT: &str = r#"// Comments are kept.
+LET x = -12; /* So are
+multi-line ones. */
+print(x ,	"a /* b */ c",
+    αβ /* trailing comma */ , );
+"#;
+
+#[test]
+fn round_trip() -> Result<(), Error> {
+    let program = pairs::program::try_parse(INPUT)?;
+    assert_eq!(format!("{}", program), INPUT);
+    Ok(())
+}
+
+#[test]
+fn non_silent() -> Result<(), Error> {
+    let program = pairs::program::try_parse(INPUT)?;
+    let (_, items, _) = program.get_matched();
+    let items = items.iter_matched().map(|item| item.to_string());
+    assert_eq!(
+        items.collect::<Vec<_>>(),
+        [
+            "LET x = -12;",
+            "print(x ,\t\"a /* b */ c\",\n    αβ /* trailing comma */ , );",
+        ],
+    );
+    Ok(())
+}
+
+#[test]
+fn silent() -> Result<(), Error> {
+    let input = "x /* y */ , -1 ,\"z\" ,";
+    let args = pairs::args::try_parse(input)?;
+    assert_eq!(args.to_string(), input);
+    let args = pairs::args::try_parse("x")?;
+    assert_eq!(args.to_string(), "x");
+    Ok(())
+}
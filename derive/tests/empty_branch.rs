@@ -0,0 +1,99 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Branches after one that matched nothing are still reported in errors on the same position.
+//!
+//! pest rejects choices after a branch that can't fail, such as `a? | b`,
+//! so branches below match nothing unless followed by `c`.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+stmt       =  { (maybe_a | b) ~ semi }
+nested     =  { "(" ~ (maybe_a | b ~ c) ~ semi }
+pushed     =  { (maybe_push | PUSH(b)) ~ PEEK_ALL }
+maybe_a    = _{ a? ~ !c }
+maybe_push = _{ PUSH(a)? ~ !c }
+a          =  { "a" }
+b          =  { "b" }
+c          =  { "c" }
+semi       =  { ";" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn committed() {
+    let stmt = pairs::stmt::try_parse(";").unwrap();
+    assert!(stmt.content.0.matched._0().is_some());
+    // Committed to the first branch, as in pest.
+    let error = pairs::stmt::try_parse("b;").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:1
+  |
+1 | b;
+  | ^---
+  |
+  = ^---
+    Expected [a, b, semi]."
+    );
+
+    let error = pairs::stmt::try_parse("x").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:1
+  |
+1 | x
+  | ^---
+  |
+  = ^---
+    Expected [a, b, semi]."
+    );
+}
+
+#[test]
+fn progress() {
+    // Attempts inside probed branches are only recorded on the committed position,
+    // so `c` is not expected.
+    let error = pairs::nested::try_parse("(b").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:2
+  |
+1 | (b
+  |  ^---
+  |
+  = (^---
+    Expected [a, b, semi], by nested."
+    );
+    // Records on the committed position are dropped once parsing goes further.
+    let error = pairs::nested::try_parse("(a").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:3
+  |
+1 | (a
+  |   ^---
+  |
+  = (a^---
+    Expected [semi], by nested."
+    );
+}
+
+#[test]
+fn stack() {
+    // Probed branches don't push.
+    let pushed = pairs::pushed::try_parse("").unwrap();
+    assert!(pushed.content.0.matched._0().is_some());
+    assert!(pairs::pushed::try_parse("aa").is_ok());
+}
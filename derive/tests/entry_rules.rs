@@ -0,0 +1,74 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Inherent `parse_<rule>` methods on the parser struct.
+
+mod all {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+file       =  { SOI ~ expr ~ (";" ~ expr)* ~ EOI }
+expr       =  { term ~ ("+" ~ term)* }
+term       = _{ number | type }
+number     = @{ ASCII_DIGIT+ }
+type       = @{ "int" }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod limited {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+file       =  { SOI ~ expr ~ (";" ~ expr)* ~ EOI }
+expr       =  { number ~ ("+" ~ number)* }
+number     = @{ ASCII_DIGIT+ }
+"#]
+    #[entry_rules(file, expr)]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+
+    // A method with the same name as a non-entry rule doesn't collide.
+    impl Parser {
+        pub fn parse_number(input: &str) -> Option<u32> {
+            input.parse().ok()
+        }
+    }
+}
+
+#[test]
+fn all() -> anyhow::Result<()> {
+    use all::{pairs, Parser};
+
+    let file: pairs::file<'_> = Parser::parse_file("1 + 2; int")?;
+    assert_eq!(file.expr().1.len(), 1);
+    assert_eq!(Parser::parse_expr("1 + int")?.span.as_str(), "1 + int");
+    assert_eq!(Parser::parse_number("12")?.span.as_str(), "12");
+    assert_eq!(Parser::parse_type("int")?.span.as_str(), "int");
+    assert!(Parser::parse_number("12 ").is_err());
+    assert!(Parser::parse_file("1 +").is_err());
+    Ok(())
+}
+
+#[test]
+fn limited() -> anyhow::Result<()> {
+    use limited::Parser;
+
+    assert_eq!(Parser::parse_file("1; 2 + 3")?.expr().1.len(), 1);
+    assert_eq!(Parser::parse_expr("2 + 3")?.number().1.len(), 1);
+    assert_eq!(Parser::parse_number("3"), Some(3));
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Structured details of parsing failures, and their `miette` diagnostics.
+
+use miette::Diagnostic;
+use pest_typed::{error::ErrorDetails, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+keyword    =  { "let" }
+ident      = @{ ASCII_ALPHA+ }
+name       =  { !keyword ~ ident }
+number     = @{ ASCII_DIGIT+ }
+string     = @{ "\"" ~ (!"\"" ~ ANY)* ~ "\"" }
+value      =  { number | string }
+assign     =  { name ~ "=" ~ value ~ ";" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn expected() {
+    let failure = pairs::assign::try_parse("x = y;").unwrap_err();
+    assert_eq!(failure.range(), 4..4);
+    assert_eq!(failure.snippet(), "x = y;");
+    assert_eq!(failure.expected(), &[Rule::number, Rule::string]);
+    assert!(failure.unexpected().is_empty());
+}
+
+#[test]
+fn unexpected() {
+    let failure = pairs::assign::try_parse("let = 1;").unwrap_err();
+    assert_eq!(failure.range(), 0..3);
+    assert!(failure.expected().is_empty());
+    assert_eq!(failure.unexpected(), &[Rule::keyword]);
+}
+
+#[test]
+fn converted() {
+    fn parse(input: &str) -> Result<pairs::assign<'_>, Box<pest_typed::error::Error<Rule>>> {
+        Ok(pairs::assign::try_parse(input)?)
+    }
+    let failure = pairs::assign::try_parse("x = y;").unwrap_err();
+    assert_eq!(
+        parse("x = y;").unwrap_err().to_string(),
+        failure.to_string()
+    );
+}
+
+#[test]
+fn miette_labels() {
+    let failure = pairs::assign::try_parse("x  =  ;").unwrap_err();
+    let labels: Vec<_> = failure.labels().unwrap().collect();
+    assert_eq!(labels.len(), 1);
+    assert_eq!(labels[0].offset(), 6);
+    assert_eq!(labels[0].len(), 0);
+    assert!(labels[0].primary());
+    assert_eq!(labels[0].label(), Some("expected number, string"));
+
+    let failure = pairs::assign::try_parse("let = 1;").unwrap_err();
+    let report = miette::Report::new(*failure).with_source_code("let = 1;");
+    let labels: Vec<_> = report.labels().unwrap().collect();
+    assert_eq!((labels[0].offset(), labels[0].len()), (0, 3));
+    assert_eq!(labels[0].label(), Some("unexpected keyword"));
+}
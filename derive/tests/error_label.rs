@@ -0,0 +1,81 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rules named by `error_label` in error messages.
+
+use pest_typed::{tracker::Tracker, ParsableTypedNode as _, Position, RuleWrapper, Stack};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+assign     =  { ident ~ "=" ~ number ~ ";" }
+list       =  { "[" ~ (number ~ ("," ~ number)*)? ~ "]" }
+"#]
+#[error_label(number = "a numeric literal", assign = "an assignment")]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn expected() {
+    let error = pairs::assign::try_parse("x = y;").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:5
+  |
+1 | x = y;
+  |     ^---
+  |
+  = x = ^---
+    Expected [a numeric literal], by an assignment."
+    );
+    assert!(!error.to_string().contains("number"));
+}
+
+#[test]
+fn unlabelled() {
+    let error = pairs::assign::try_parse("1 = 2;").unwrap_err();
+    assert!(error.to_string().contains("Expected [ident]."), "{error}");
+
+    let error = pairs::list::try_parse("[1, x]").unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .contains("Expected [a numeric literal], by list."),
+        "{error}"
+    );
+}
+
+#[test]
+fn lookup() {
+    assert_eq!(Rule::number.error_label(), Some("a numeric literal"));
+    assert_eq!(Rule::assign.error_label(), Some("an assignment"));
+    assert_eq!(Rule::ident.error_label(), None);
+    assert_eq!(Rule::EOI.error_label(), None);
+    assert_eq!(
+        <pairs::number<'_> as RuleWrapper<Rule>>::ERROR_LABEL,
+        Some("a numeric literal")
+    );
+    assert_eq!(<pairs::ident<'_> as RuleWrapper<Rule>>::ERROR_LABEL, None);
+}
+
+#[test]
+fn shared_tracker() {
+    let input = "x";
+    let mut tracker = Tracker::new(Position::from_start(input));
+    assert!(pairs::number::try_parse_with_state(input, &mut Stack::new(), &mut tracker).is_none());
+    assert!(pairs::list::try_parse_with_state(input, &mut Stack::new(), &mut tracker).is_none());
+    let message = tracker.collect().to_string();
+    assert!(
+        message.contains("Expected [a numeric literal, list]."),
+        "{message}"
+    );
+}
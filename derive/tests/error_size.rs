@@ -0,0 +1,54 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! All fallible entry points return errors in a `Box`.
+
+use core::mem::size_of;
+use pest_typed::{
+    error::{Error, ParseResult},
+    iterators::AnyPair,
+    ParsableTypedNode, Position, Root, TypedParser,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+file       = _{ SOI ~ number ~ ("," ~ number)* ~ EOI }
+number     = @{ ASCII_DIGIT+ }
+"#]
+#[entry_rules(file, number)]
+#[allow(dead_code)]
+struct Parser;
+
+type Boxed<T> = ParseResult<T, Rule>;
+
+#[test]
+fn signatures() {
+    type Input = &'static str;
+    let _: fn(Input) -> Boxed<pairs::number<'static>> = Parser::parse_number;
+    let _: fn(Input) -> Boxed<Root<'static, pairs::file<'static>>> = Parser::parse_file;
+    type Dyn = Box<dyn AnyPair<'static, Rule>>;
+    let _: fn(Rule, Input) -> Boxed<Dyn> = parse_rule;
+    let _: fn(Input) -> Boxed<pairs::number<'static>> = pairs::number::try_parse;
+    let _: fn(Input) -> Boxed<(Position<'static>, pairs::number<'static>)> =
+        pairs::number::try_parse_partial;
+    let _: fn(Input) -> Boxed<Root<'static, pairs::number<'static>>> =
+        pairs::number::try_parse_root;
+    let _: fn(Input) -> Boxed<pairs::number<'static>> =
+        <Parser as TypedParser<Rule>>::try_parse::<pairs::number<'static>>;
+}
+
+#[test]
+fn size() {
+    let boxed = size_of::<Boxed<pairs::number<'static>>>();
+    let inline = size_of::<Result<pairs::number<'static>, Error<Rule>>>();
+    assert!(boxed <= size_of::<pairs::number<'static>>() + size_of::<usize>());
+    assert!(boxed < inline, "{boxed} < {inline}");
+}
@@ -0,0 +1,68 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! The farthest position reached by any match, including abandoned branches.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\n" }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+long       =  { "let" ~ ident ~ "=" ~ number ~ ";" }
+short      =  { "let" }
+stmt       =  { long | short }
+chars      =  { "a" ~ "b" ~ "c" | "a" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn abandoned() {
+    let (res, farthest) = pairs::stmt::try_parse_partial_with_farthest("let x = 1 ?");
+    let (end, _) = res.unwrap();
+    assert_eq!(end.pos(), 3);
+    assert_eq!(farthest.pos(), 9);
+    assert_eq!(farthest.line_col(), (1, 10));
+
+    let (res, farthest) = pairs::chars::try_parse_partial_with_farthest("abx");
+    assert_eq!(res.unwrap().0.pos(), 1);
+    assert_eq!(farthest.pos(), 2);
+}
+
+#[test]
+fn committed() {
+    let (res, farthest) = pairs::stmt::try_parse_partial_with_farthest("let x = 1;");
+    assert_eq!(res.unwrap().0.pos(), 10);
+    assert_eq!(farthest.pos(), 10);
+}
+
+#[test]
+fn failed() {
+    let (res, farthest) = pairs::long::try_parse_partial_with_farthest("let x = ?");
+    res.unwrap_err();
+    assert_eq!(farthest.pos(), 7);
+
+    let (res, farthest) = pairs::stmt::try_parse_partial_with_farthest("?");
+    res.unwrap_err();
+    assert_eq!(farthest.pos(), 0);
+}
+
+#[test]
+fn failure() {
+    let input = "let x =\n  12 ?";
+    let failure = pairs::long::try_parse_partial(input).unwrap_err();
+    assert_eq!(failure.farthest(), 12);
+    assert_eq!(failure.farthest_line_col(), (2, 5));
+    let (_, farthest) = pairs::long::try_parse_partial_with_farthest(input);
+    assert_eq!(farthest.pos(), failure.farthest());
+    assert_eq!(failure.to_string(), failure.error().to_string());
+}
@@ -0,0 +1,68 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Accessers to rules inside nested choices and optionals return a single `Option`.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+a        = { "a" }
+b        = { "b" }
+choice   = { (a | b)? }
+nested   = { ("c" | (b | a)?)? }
+sequence = { ("x" ~ a? | b)? }
+deep     = { ((a ~ "q")? ~ "w")? }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn choice() {
+    let choice = pairs::choice::try_parse("a").unwrap();
+    let a: Option<&pairs::a<'_>> = choice.a();
+    assert_eq!(a.unwrap().span.as_str(), "a");
+    assert!(choice.b().is_none());
+
+    let choice = pairs::choice::try_parse("").unwrap();
+    assert!(choice.a().is_none());
+    assert!(choice.b().is_none());
+}
+
+#[test]
+fn nested() {
+    let nested = pairs::nested::try_parse("a").unwrap();
+    let a: Option<&pairs::a<'_>> = nested.a();
+    assert!(a.is_some());
+
+    for input in ["c", "b", ""] {
+        let nested = pairs::nested::try_parse(input).unwrap();
+        assert!(nested.a().is_none(), "{input:?}");
+    }
+}
+
+#[test]
+fn sequence() {
+    let sequence = pairs::sequence::try_parse("xa").unwrap();
+    let a: Option<&pairs::a<'_>> = sequence.a();
+    assert!(a.is_some());
+    assert!(pairs::sequence::try_parse("x").unwrap().a().is_none());
+    assert!(pairs::sequence::try_parse("b").unwrap().a().is_none());
+}
+
+#[test]
+fn deep() {
+    let deep = pairs::deep::try_parse("aqw").unwrap();
+    let a: Option<&pairs::a<'_>> = deep.a();
+    assert!(a.is_some());
+    assert!(pairs::deep::try_parse("w").unwrap().a().is_none());
+    assert!(pairs::deep::try_parse("").unwrap().a().is_none());
+}
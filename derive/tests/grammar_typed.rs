@@ -26,6 +26,7 @@ use pest_typed_derive::TypedParser;
 
 #[derive(TypedParser)]
 #[grammar = "tests/grammar.pest"]
+#[allow(dead_code)]
 struct GrammarParser;
 
 macro_rules! tokens {
@@ -0,0 +1,84 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Reparsing only the item that covers an edit.
+
+use core::ops::Range;
+use pest_typed::{incremental::try_reparse, ParsableTypedNode as _, Spanned};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | NEWLINE }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+item       =  { "let" ~ ident ~ "=" ~ number ~ ";" }
+file       =  { SOI ~ item* ~ EOI }
+items      =  { item* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+const INPUT: &str = "let a = 1;\nlet b = 2;\nlet c = 3;\n";
+
+fn edit(range: Range<usize>, replacement: &str) -> String {
+    let mut input = INPUT.to_owned();
+    input.replace_range(range, replacement);
+    input
+}
+
+#[test]
+fn inside_one_item() {
+    let input = edit(15..16, "bee");
+    let old = pairs::file::try_parse(INPUT).unwrap();
+    let expected = pairs::file::try_parse(&input).unwrap();
+
+    let new = try_reparse(&old, 15..16, &input, |file| {
+        &mut file.content.content.1.matched.content
+    })
+    .unwrap();
+    assert_eq!(new, expected);
+    assert_eq!(new.input(), input);
+
+    let new = old.reparse(15..16, &input).unwrap();
+    assert_eq!(new, expected);
+    let (_, items, _) = new.get_matched();
+    assert_eq!(items[1].ident().span.as_str(), "bee");
+    assert_eq!(items[2].span.start(), 24);
+    assert_eq!(items[2].ident().span.as_str(), "c");
+
+    let old = pairs::items::try_parse(INPUT).unwrap();
+    let new = old.reparse(15..16, &input).unwrap();
+    assert_eq!(new, pairs::items::try_parse(&input).unwrap());
+}
+
+#[test]
+fn spanning_two_items() {
+    let input = edit(8..19, "10;\nlet d = 2");
+    let old = pairs::file::try_parse(INPUT).unwrap();
+    let expected = pairs::file::try_parse(&input).unwrap();
+
+    let reparsed = try_reparse(&old, 8..19, &input, |file| {
+        &mut file.content.content.1.matched.content
+    });
+    assert!(reparsed.is_none());
+
+    let new = old.reparse(8..19, &input).unwrap();
+    assert_eq!(new, expected);
+}
+
+#[test]
+fn broken_item() {
+    let input = edit(19..20, "");
+    let old = pairs::file::try_parse(INPUT).unwrap();
+    let expected = pairs::file::try_parse(&input).unwrap_err();
+    let error = old.reparse(19..20, &input).unwrap_err();
+    assert_eq!(error.to_string(), expected.to_string());
+}
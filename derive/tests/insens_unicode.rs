@@ -0,0 +1,91 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! With `unicode_case_insensitive`, `^"..."` is matched with Unicode simple case folding.
+
+use pest_typed::ParsableTypedNode;
+
+mod unicode {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+street  = { ^"straße" ~ EOI }
+kelvin  = { ^"k" ~ EOI }
+upper   = { ^"SS" ~ EOI }
+keyword = { ^"größe" ~ !ASCII_ALPHA ~ ANY }
+"#]
+    #[unicode_case_insensitive]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    struct Parser;
+}
+
+mod ascii {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+street  = { ^"straße" ~ EOI }
+kelvin  = { ^"k" ~ EOI }
+upper   = { ^"SS" ~ EOI }
+keyword = { ^"größe" ~ !ASCII_ALPHA ~ ANY }
+"#]
+    #[allow(dead_code)]
+    struct Parser;
+}
+
+#[test]
+fn simple_folding() {
+    use unicode::pairs::{kelvin, street, upper};
+    // Case of non-ASCII letters is ignored.
+    assert!(street::try_parse("STRAẞE").is_ok());
+    assert!(street::try_parse("Straße").is_ok());
+    // `ß` is a single character, which is never folded to `ss` as in full case folding.
+    assert!(street::try_parse("STRASSE").is_err());
+    assert!(street::try_parse("strasse").is_err());
+    assert!(upper::try_parse("ß").is_err());
+    assert!(upper::try_parse("ẞ").is_err());
+    assert!(upper::try_parse("sS").is_ok());
+    // Kelvin sign is lowercased to `k`, with a different length in bytes.
+    assert!(kelvin::try_parse("\u{212A}").is_ok());
+    assert!(kelvin::try_parse("K").is_ok());
+}
+
+#[test]
+fn exact_content() {
+    let street = unicode::pairs::street::try_parse("STRAẞE").unwrap();
+    let (insens, _) = street.as_ref();
+    assert_eq!(insens.content, "STRAẞE");
+    assert_eq!(street.span.as_str(), "STRAẞE");
+
+    let kelvin = unicode::pairs::kelvin::try_parse("\u{212A}").unwrap();
+    let (insens, _) = kelvin.as_ref();
+    assert_eq!(insens.content, "\u{212A}");
+}
+
+#[test]
+fn keyword() {
+    use unicode::pairs::keyword;
+    assert!(keyword::try_parse("GRÖSSE!").is_err());
+    assert!(keyword::try_parse("GRÖẞE!").is_ok());
+    assert!(keyword::try_parse("GRÖẞEN").is_err());
+}
+
+#[test]
+fn ascii_only() {
+    use ascii::pairs::{kelvin, street, upper};
+    // Like pest, only ASCII letters are compared ignoring case.
+    assert!(street::try_parse("STRAße").is_ok());
+    assert!(street::try_parse("STRAẞE").is_err());
+    assert!(upper::try_parse("ß").is_err());
+    assert!(kelvin::try_parse("\u{212A}").is_err());
+    assert!(ascii::pairs::keyword::try_parse("GRöße!").is_ok());
+    assert!(ascii::pairs::keyword::try_parse("GRÖßE!").is_err());
+}
@@ -16,6 +16,7 @@ x = _{ "x" ~ y }
 y = _{ "y" | x }
 z =  { x }
 "#]
+#[allow(dead_code)]
 struct Parser;
 
 #[test]
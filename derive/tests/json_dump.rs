@@ -0,0 +1,57 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Dump trees of rule structs as JSON.
+
+use anyhow::Error;
+use pest_typed::{iterators::PairTree as _, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+assign     =  { name ~ "=" ~ string }
+name       = @{ ASCII_ALPHA+ }
+string     = @{ "'" ~ (!"'" ~ ANY)* ~ "'" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn nested() -> Result<(), Error> {
+    let assign = pairs::assign::try_parse("a = 'b'")?;
+    assert_eq!(
+        assign.to_json(),
+        concat!(
+            r#"{"rule":"assign","start":0,"end":7,"text":"a = 'b'","children":["#,
+            r#"{"rule":"name","start":0,"end":1,"text":"a","children":[]},"#,
+            r#"{"rule":"string","start":4,"end":7,"text":"'b'","children":[]}"#,
+            r#"]}"#,
+        )
+    );
+    Ok(())
+}
+
+#[test]
+fn escaped() -> Result<(), Error> {
+    let assign = pairs::assign::try_parse("a='\"\\\t\u{1}α'")?;
+    let json = assign.to_json();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"rule":"assign","start":0,"end":10,"text":"a='\"\\\t\u0001α'","children":["#,
+            r#"{"rule":"name","start":0,"end":1,"text":"a","children":[]},"#,
+            r#"{"rule":"string","start":2,"end":10,"text":"'\"\\\t\u0001α'","children":[]}"#,
+            r#"]}"#,
+        )
+    );
+    let value: serde_json::Value = serde_json::from_str(&json)?;
+    assert_eq!(value["children"][1]["text"], "'\"\\\t\u{1}α'");
+    Ok(())
+}
@@ -0,0 +1,115 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `^"keyword" ~ !boundary` is lowered to `Keyword` where nothing is skipped in between.
+
+mod typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+kw_if      = @{ ^"if" ~ !(ASCII_ALPHANUMERIC | "_") }
+kw_else    = ${ ^"else" ~ !ASCII_ALPHA }
+spaced     =  { ^"do" ~ !ASCII_ALPHA }
+"#]
+    #[emit_rule_reference]
+    #[atomic_content]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod untyped {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+kw_if      = @{ ^"if" ~ !(ASCII_ALPHANUMERIC | "_") }
+kw_else    = ${ ^"else" ~ !ASCII_ALPHA }
+spaced     =  { ^"do" ~ !ASCII_ALPHA }
+"#]
+    pub struct Parser;
+}
+
+use pest::Parser as _;
+use pest_typed::{
+    predefined_node::{Keyword, KeywordBoundary},
+    tracker::Tracker,
+    ParsableTypedNode, Position, Stack, StringWrapper, TypedNode,
+};
+
+fn typed<'i, N: ParsableTypedNode<'i, typed::Rule>>(input: &'i str) -> Option<usize> {
+    N::try_parse_partial(input).ok().map(|(pos, _)| pos.pos())
+}
+
+fn untyped(rule: untyped::Rule, input: &str) -> Option<usize> {
+    let mut pairs = untyped::Parser::parse(rule, input).ok()?;
+    Some(pairs.next()?.as_span().end())
+}
+
+#[test]
+fn boundary() {
+    let check = |rule: untyped::Rule, typed: fn(&str) -> Option<usize>, inputs: &[&str]| {
+        for input in inputs {
+            assert_eq!(
+                typed(input),
+                untyped(rule, input),
+                "{:?}: {:?}",
+                rule,
+                input
+            );
+        }
+    };
+    check(
+        untyped::Rule::kw_if,
+        |input| typed::<typed::pairs::kw_if<'_>>(input),
+        &["if", "iffy", "if_", "if1", "if ", "if(", "IF", "If x", "i"],
+    );
+    check(
+        untyped::Rule::kw_else,
+        |input| typed::<typed::pairs::kw_else<'_>>(input),
+        &["else", "elsewhere", "else1", "ELSE", "eLsE ", "els"],
+    );
+    check(
+        untyped::Rule::spaced,
+        |input| typed::<typed::pairs::spaced<'_>>(input),
+        &["do", "DO x", "do x", "dox", "do1", "d"],
+    );
+}
+
+#[test]
+fn node() -> anyhow::Result<()> {
+    let kw_if = typed::pairs::kw_if::try_parse("iF")?;
+    assert_eq!(kw_if.content.content, "iF");
+    assert_eq!(kw_if.to_string(), "iF");
+    Ok(())
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+struct While;
+impl StringWrapper for While {
+    const CONTENT: &'static str = "while";
+}
+
+#[test]
+fn hand_written() {
+    type KwWhile<'i> = Keyword<'i, While, KeywordBoundary>;
+    let parse = |input| {
+        let input = Position::from_start(input);
+        let mut tracker = Tracker::<typed::Rule>::new(input);
+        KwWhile::try_parse_with(input, &mut Stack::new(), &mut tracker)
+            .map(|(pos, node)| (pos.pos(), node.content))
+    };
+    assert_eq!(parse("WHILE"), Some((5, "WHILE")));
+    assert_eq!(parse("while (x)"), Some((5, "while")));
+    assert_eq!(parse("while_"), None);
+    assert_eq!(parse("whiles"), None);
+    assert_eq!(parse("whil"), None);
+}
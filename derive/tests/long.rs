@@ -15,6 +15,7 @@ use pest_typed_derive::TypedParser;
 x = { "a" ~ "b" ~ "c" ~ "d" ~ "e" ~ "f" ~ "g" ~ "h" ~ "i" ~ "j" ~ "k" ~ "l" ~ "m" ~ "n" ~ "o" ~ "p" }
 y = { ("a" | "b" | "c" | "d" | "e" | "f" | "g" | "h" | "i" | "j" | "k" | "l" | "m" | "n" | "o" | "p"){16} }
 "#]
+#[allow(dead_code)]
 struct Parser;
 
 #[test]
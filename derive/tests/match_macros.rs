@@ -0,0 +1,67 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Generated `match_<rule>!` macros for choices of rules.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+value      =  { object | array | string | number | null }
+object     =  { "{" ~ "}" }
+array      =  { "[" ~ value* ~ "]" }
+string     = @{ "\"" ~ (!"\"" ~ ANY)* ~ "\"" }
+number     = @{ ASCII_DIGIT+ }
+null       =  { "null" }
+"#]
+#[emit_match_macros]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+fn describe(value: &pairs::value<'_>) -> String {
+    match_value!(value, {
+        object(_) => "object".to_owned(),
+        array(a) => format!("array of {}", a.value().len()),
+        string(s) => format!("string {}", s.span.as_str()),
+        number(n) => format!("number {}", n.span.as_str()),
+        null(_) => "null".to_owned(),
+    })
+}
+
+#[test]
+fn all_branches() -> Result<(), Error> {
+    let cases = [
+        ("{ }", "object"),
+        ("[1 null]", "array of 2"),
+        ("\"a\"", "string \"a\""),
+        ("42", "number 42"),
+        ("null", "null"),
+    ];
+    for (input, expected) in cases {
+        let value = pairs::value::try_parse(input)?;
+        assert_eq!(describe(&value), expected);
+    }
+    Ok(())
+}
+
+#[test]
+fn wildcard() -> Result<(), Error> {
+    let is_scalar = |input| -> Result<bool, Error> {
+        let value = pairs::value::try_parse(input)?;
+        Ok(match_value!(value, { object(_) => false, array(_) => false, _ => true }))
+    };
+    assert!(!is_scalar("[]")?);
+    assert!(is_scalar("1")?);
+    assert!(is_scalar("null")?);
+    Ok(())
+}
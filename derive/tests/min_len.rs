@@ -0,0 +1,73 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `MIN_LEN` of rules, and pruning of choice branches that can't fit in the remaining input.
+
+use pest_typed::{ParsableTypedNode as _, RuleWrapper};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+stmt       =  { long | short }
+long       =  { "let" ~ ident ~ "=" ~ ident }
+short      =  { ident }
+ident      = @{ ASCII_ALPHA+ }
+paren      =  { "(" ~ bracket ~ ")" | number }
+bracket    =  { "[" ~ paren ~ "]" }
+number     = @{ ASCII_DIGIT+ }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn min_len() {
+    assert_eq!(<pairs::stmt<'_> as RuleWrapper<Rule>>::MIN_LEN, 1);
+    assert_eq!(<pairs::long<'_> as RuleWrapper<Rule>>::MIN_LEN, 6);
+    assert_eq!(<pairs::ident<'_> as RuleWrapper<Rule>>::MIN_LEN, 1);
+    assert_eq!(<pairs::paren<'_> as RuleWrapper<Rule>>::MIN_LEN, 1);
+    assert_eq!(<pairs::bracket<'_> as RuleWrapper<Rule>>::MIN_LEN, 3);
+    assert_eq!(<pairs::EOI<'_> as RuleWrapper<Rule>>::MIN_LEN, 0);
+}
+
+#[test]
+fn pruned() {
+    let stmt = pairs::stmt::try_parse("le").unwrap();
+    assert!(stmt.content._1().is_some());
+    let stmt = pairs::stmt::try_parse("let a = b").unwrap();
+    assert!(stmt.content._0().is_some());
+}
+
+#[test]
+fn errors() {
+    // The pruned branch is reported as if it matched nothing.
+    let error = pairs::stmt::try_parse("1").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:1
+  |
+1 | 1
+  | ^---
+  |
+  = ^---
+    Expected [long, ident]."
+    );
+    // Errors of the last branch are kept.
+    let error = pairs::paren::try_parse("(x").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:2
+  |
+1 | (x
+  |  ^---
+  |
+  = (^---
+    Expected [bracket], by paren."
+    );
+}
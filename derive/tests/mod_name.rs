@@ -0,0 +1,43 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rule structs re-exported under a configured module name.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+/// A user module that would collide with the default `pairs`.
+#[allow(dead_code)]
+mod pairs {}
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+file       =  { SOI ~ item* ~ EOI }
+item       = @{ ASCII_ALPHA+ }
+"#]
+#[mod_name = "nodes"]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn parse() -> Result<(), Error> {
+    let file: nodes::file<'_> = nodes::file::try_parse("a bc")?;
+    let items = file.item().into_iter().map(|i| i.span.as_str());
+    assert_eq!(items.collect::<Vec<_>>(), ["a", "bc"]);
+
+    let file: nodes::file<'_> = Parser::parse_file("d")?;
+    assert_eq!(file.span.as_str(), "d");
+
+    let item = parse_rule(Rule::item, "ef")?;
+    assert_eq!(item.span().as_str(), "ef");
+    Ok(())
+}
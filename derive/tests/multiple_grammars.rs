@@ -0,0 +1,36 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{ParsableTypedNode as _, Spanned};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar = "tests/multi/base.pest"]
+#[grammar = "tests/multi/lang.pest"]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn shared_rule() -> anyhow::Result<()> {
+    let stmt = pairs::stmt::try_parse("let a = b")?;
+    let (lhs, rhs): (&pairs::ident<'_>, &pairs::ident<'_>) = stmt.ident();
+    assert_eq!(lhs.as_str(), "a");
+    assert_eq!(rhs.as_str(), "b");
+
+    let ident = pairs::ident::try_parse("abc")?;
+    assert_eq!(ident.as_str(), "abc");
+    Ok(())
+}
+
+#[test]
+fn rules() {
+    assert_eq!(_PEST_GRAMMAR_Parser.len(), 2);
+    let _ = [Rule::WHITESPACE, Rule::ident, Rule::stmt];
+}
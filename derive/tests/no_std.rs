@@ -0,0 +1,76 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Generated codes only depend on [`core`] and [`alloc`].
+//!
+//! The runtime is built with std here, as dev-dependencies enable it.
+//! It's checked without std on a target that has no std, see `.github/workflows/no_std.yml`.
+
+#![no_std]
+
+extern crate alloc;
+
+use alloc::{string::ToString, vec::Vec};
+use pest_typed::{iterators::PairTree as _, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+mod from_file {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar = "tests/grammar.pest"]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+COMMENT    = _{ "//" ~ (!NEWLINE ~ ANY)* }
+key        = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+quoted     = ${ PUSH("'" | "\"") ~ (!PEEK ~ ANY)* ~ POP }
+value      =  { number | quoted | list }
+list       =  { "[" ~ (value ~ ("," ~ value)*)? ~ "]" }
+pair       =  { key ~ "=" ~ value? ~ ";" }
+file       =  { SOI ~ pair* ~ EOI }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn parse() {
+    let file = Parser::parse_file("a = 1; b = ['x', 2]; // end").unwrap();
+    let pairs = file.pair();
+    assert_eq!(pairs.len(), 2);
+    let keys: Vec<_> = pairs.iter().map(|pair| pair.key().span.as_str()).collect();
+    assert_eq!(keys, ["a", "b"]);
+    let list = pairs[1].value().unwrap().list().unwrap();
+    let quoted = list.value().unwrap().0.quoted().unwrap();
+    assert_eq!(quoted.pushed().as_str(), "'");
+    assert_eq!(quoted.pop().as_str(), "'");
+    assert!(file.display_tree(8).to_string().starts_with("file"));
+}
+
+#[test]
+fn errors() {
+    let error = pairs::file::try_parse("a = [1; b = 2;").unwrap_err();
+    assert!(error.to_string().contains("^---"));
+    let (file, errors) = pairs::file::parse_with_recovery("a = 'x;");
+    assert!(file.is_none());
+    assert_eq!(errors.len(), 1);
+}
+
+#[test]
+fn from_file() {
+    let sequence = from_file::pairs::sequence::try_parse("abc abc").unwrap();
+    assert_eq!(sequence.string().0.span.as_str(), "abc");
+}
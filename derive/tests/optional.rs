@@ -0,0 +1,52 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Reading optional parts of a rule.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+digits     = @{ ASCII_DIGIT+ }
+minus      =  { "-" }
+sign       =  { "-"? ~ digits }
+number     =  { minus? ~ digits ~ ("." ~ digits)? }
+list       =  { number ~ ("," ~ number)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn presence() {
+    let sign = pairs::sign::try_parse("-12").unwrap();
+    let (minus, digits) = sign.get_matched();
+    assert!(minus.present());
+    assert_eq!(digits.span.as_str(), "12");
+
+    let sign = pairs::sign::try_parse("12").unwrap();
+    assert!(!sign.get_matched().0.present());
+}
+
+#[test]
+fn is_present() {
+    let number = pairs::number::try_parse("- 1.5").unwrap();
+    assert!(number.is_present_minus());
+    assert_eq!(number.minus().map(|minus| minus.span.as_str()), Some("-"));
+    let (int, frac) = number.digits();
+    assert_eq!(int.span.as_str(), "1");
+    assert_eq!(frac.map(|frac| frac.span.as_str()), Some("5"));
+
+    let number = pairs::number::try_parse("1").unwrap();
+    assert!(!number.is_present_minus());
+    assert!(number.minus().is_none());
+    assert!(number.digits().1.is_none());
+}
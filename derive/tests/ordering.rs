@@ -0,0 +1,55 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{ParsableTypedNode as _, Spanned};
+use pest_typed_derive::TypedParser;
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+list       =  { item ~ ("," ~ item)* }
+item       =  { word | number }
+word       = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn hash_set() -> anyhow::Result<()> {
+    let list = pairs::list::try_parse("a, 1, b")?;
+    let (first, rest) = list.item();
+    let items: HashSet<_> = std::iter::once(first)
+        .chain(rest)
+        .chain(std::iter::once(first))
+        .collect();
+    assert_eq!(items.len(), 3);
+    assert!(items.contains(first));
+    Ok(())
+}
+
+#[test]
+fn sort_by_position() -> anyhow::Result<()> {
+    let list = pairs::list::try_parse("c, 2, a, 1")?;
+    let (first, rest) = list.item();
+    let mut items: Vec<_> = rest
+        .into_iter()
+        .rev()
+        .chain(std::iter::once(first))
+        .collect();
+    items.sort();
+    let texts: Vec<_> = items.iter().map(|item| item.as_str()).collect();
+    assert_eq!(texts, ["c", "2", "a", "1"]);
+
+    let words: BTreeSet<_> = items.iter().filter_map(|item| item.word()).collect();
+    assert_eq!(words.first().map(|word| word.as_str()), Some("c"));
+    Ok(())
+}
@@ -0,0 +1,170 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rules matching repetitions of items can be parsed item by item with `parse_iter`.
+
+use core::ops::ControlFlow;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+};
+
+#[derive(TypedParser)]
+#[grammar_inline = r##"
+WHITESPACE = _{ " " | NEWLINE }
+COMMENT    = _{ "#" ~ (!NEWLINE ~ ANY)* }
+log        =  { SOI ~ line* ~ EOI }
+lines      =  { line* }
+line       = ${ level ~ " " ~ message ~ NEWLINE }
+level      =  { "INFO" | "WARN" | "ERROR" }
+message    = @{ (!NEWLINE ~ ANY)* }
+"##]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+/// Counts bytes allocated and not yet freed on the current thread,
+/// so that tests running in parallel don't affect each other.
+struct Counting;
+
+thread_local! {
+    static LIVE: Cell<usize> = const { Cell::new(0) };
+    static PEAK: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = LIVE.try_with(|live| {
+            live.set(live.get() + layout.size());
+            let _ = PEAK.try_with(|peak| peak.set(peak.get().max(live.get())));
+        });
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let _ = LIVE.try_with(|live| live.set(live.get().saturating_sub(layout.size())));
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// Peak of bytes allocated by `f` on the current thread and still alive at the same time.
+fn peak_during(f: impl FnOnce()) -> usize {
+    let base = LIVE.with(Cell::get);
+    PEAK.with(|peak| peak.set(base));
+    f();
+    PEAK.with(Cell::get) - base
+}
+
+fn synthetic(lines: usize) -> String {
+    (0..lines)
+        .map(|i| match i % 3 {
+            0 => format!("INFO request {i} served\n"),
+            1 => format!(" WARN slow response #{i}\n"),
+            _ => format!("ERROR connection {i} reset\n"),
+        })
+        .collect()
+}
+
+#[test]
+fn streaming() {
+    let input = synthetic(100_000);
+    let mut count = 0;
+    let mut errors = 0;
+    let peak = peak_during(|| {
+        pairs::log::parse_iter(&input, |line| {
+            count += 1;
+            if line.level().span.as_str() == "ERROR" {
+                errors += 1;
+            }
+            ControlFlow::Continue(())
+        })
+        .unwrap();
+    });
+    assert_eq!(count, 100_000);
+    assert_eq!(errors, 33_333);
+    // Only a single line is alive at a time.
+    assert!(peak < 16 * 1024, "{peak} bytes");
+
+    let collected = peak_during(|| {
+        let log = pairs::log::try_parse(&input).unwrap();
+        assert_eq!(log.line().len(), 100_000);
+    });
+    assert!(collected > 100 * peak, "{collected} bytes");
+}
+
+#[test]
+fn same_items() {
+    let input = "INFO a\n  # comment\n\nWARN b c\n ERROR \n";
+    let mut streamed = vec![];
+    pairs::log::parse_iter(input, |line| {
+        streamed.push(line);
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    let log = Parser::parse_log(input).unwrap();
+    assert_eq!(streamed.iter().collect::<Vec<_>>(), log.line());
+    let messages: Vec<_> = streamed.iter().map(|l| l.message().span.as_str()).collect();
+    assert_eq!(messages, ["a", "b c", ""]);
+
+    let mut count = 0;
+    pairs::log::parse_iter("  ", |_| {
+        count += 1;
+        ControlFlow::Continue(())
+    })
+    .unwrap();
+    assert_eq!(count, 0);
+}
+
+#[test]
+fn errors() {
+    let input = synthetic(500) + "DEBUG hidden\n" + &synthetic(500);
+    for rule in ["log", "lines"] {
+        let mut count = 0;
+        let f = |_| {
+            count += 1;
+            ControlFlow::Continue(())
+        };
+        let (streamed, collected) = match rule {
+            "log" => (
+                pairs::log::parse_iter(&input, f),
+                pairs::log::try_parse(&input).map(drop),
+            ),
+            _ => (
+                pairs::lines::parse_iter(&input, f),
+                pairs::lines::try_parse(&input).map(drop),
+            ),
+        };
+        // Items before the failure have been passed.
+        assert_eq!(count, 500, "{rule}");
+        assert_eq!(
+            streamed.unwrap_err().to_string(),
+            collected.unwrap_err().to_string(),
+            "{rule}"
+        );
+    }
+}
+
+#[test]
+fn stop_early() {
+    let input = "INFO a\nWARN b\nnot a line";
+    let mut levels = vec![];
+    let res = pairs::log::parse_iter(input, |line| {
+        levels.push(line.level().span.as_str());
+        match levels.len() {
+            2 => ControlFlow::Break(()),
+            _ => ControlFlow::Continue(()),
+        }
+    });
+    assert!(res.is_ok());
+    assert_eq!(levels, ["INFO", "WARN"]);
+}
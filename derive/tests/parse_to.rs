@@ -0,0 +1,61 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{ParsableTypedNode as _, Spanned};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+int        = @{ "-"? ~ ASCII_DIGIT+ }
+float      = @{ int ~ ("." ~ ASCII_DIGIT+)? }
+pair       =  { int ~ "," ~ float }
+table      =  { pair ~ (NEWLINE ~ pair)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn numbers() -> anyhow::Result<()> {
+    let pair = pairs::pair::try_parse("-12 , 3.5")?;
+    assert_eq!(pair.as_str(), "-12 , 3.5");
+    assert_eq!(pair.int().as_str(), "-12");
+    assert_eq!(pair.int().parse_to::<i64>()?, -12);
+    assert_eq!(pair.float().parse_to::<f64>()?, 3.5);
+    assert!(pair.int().parse_to::<u8>().is_err());
+    Ok(())
+}
+
+#[test]
+fn object_safe() -> anyhow::Result<()> {
+    let pair = pairs::pair::try_parse("1, 2")?;
+    let nodes: [&dyn Spanned<'_, Rule>; 3] = [&pair, pair.int(), pair.float()];
+    let texts: Vec<_> = nodes.iter().map(|node| node.as_str()).collect();
+    assert_eq!(texts, ["1, 2", "1", "2"]);
+    Ok(())
+}
+
+#[test]
+fn context_line() -> anyhow::Result<()> {
+    let input = "1, 2\n3 , -4.25\n5, 6";
+    let table = pairs::table::try_parse(input)?;
+    let (_, rest) = table.pair();
+    let float = rest[0].float();
+    assert_eq!(float.as_str(), "-4.25");
+    assert_eq!(float.input(), input);
+
+    let start = float.span().start();
+    let line_start = float.input()[..start].rfind('\n').map_or(0, |i| i + 1);
+    let line_end = float.input()[start..]
+        .find('\n')
+        .map_or(input.len(), |i| start + i);
+    assert_eq!(&float.input()[line_start..line_end], "3 , -4.25");
+    Ok(())
+}
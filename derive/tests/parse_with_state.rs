@@ -0,0 +1,67 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Parsing with a caller-supplied stack, such as one seeded with the indentation of a fragment.
+
+use pest_typed::{tracker::Tracker, ParsableTypedNode as _, Position, Span, Stack};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+line  = @{ ASCII_ALPHA+ }
+block =  { PEEK ~ line ~ (NEWLINE ~ PEEK ~ line)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+const INDENT: &str = "    ";
+
+#[test]
+fn seeded() {
+    let input = "    a\n    bc";
+    let mut stack = Stack::new();
+    stack.push(Span::new(INDENT, 0, 4).unwrap());
+    let mut tracker = Tracker::new(Position::from_start(input));
+    let block = pairs::block::try_parse_with_state(input, &mut stack, &mut tracker).unwrap();
+    let (first, rest) = block.line();
+    assert_eq!(first.span.as_str(), "a");
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].span.as_str(), "bc");
+    assert_eq!(stack.len(), 1);
+
+    // A shallower seed matches a prefix of the indentation only.
+    let mut stack = Stack::new();
+    stack.push(Span::new(INDENT, 0, 2).unwrap());
+    let mut tracker = Tracker::new(Position::from_start(input));
+    assert!(pairs::block::try_parse_with_state(input, &mut stack, &mut tracker).is_none());
+    let error = tracker.collect();
+    assert_eq!(
+        error.line_col,
+        pest_typed::error::LineColLocation::Pos((1, 3))
+    );
+}
+
+#[test]
+fn unseeded() {
+    let error = pairs::block::try_parse("    a").unwrap_err();
+    assert!(error.to_string().contains("Nothing to pop or drop."));
+}
+
+#[test]
+fn partial() {
+    let input = "  a\n  b\nc";
+    let mut stack = Stack::new();
+    stack.push(Span::new(INDENT, 0, 2).unwrap());
+    let mut tracker = Tracker::new(Position::from_start(input));
+    let (end, block) =
+        pairs::block::try_parse_partial_with_state(input, &mut stack, &mut tracker).unwrap();
+    assert_eq!(end.pos(), 7);
+    assert_eq!(block.span.as_str(), "  a\n  b");
+}
@@ -14,6 +14,7 @@ use pest_typed_derive::TypedParser;
 #[grammar_inline = r#"
 main = { PUSH("foo") ~ PUSH("Foo") ~ PUSH("FOO") ~ PEEK[1..] ~ PEEK[1..2] }
 "#]
+#[allow(dead_code)]
 struct Parser;
 
 #[test]
@@ -42,3 +43,13 @@ fn tree() {
     Expected [main]."
     );
 }
+
+#[test]
+fn round_trip() {
+    let input = "fooFooFOOFooFOOFoo";
+    let main = rules::main::try_parse(input).unwrap();
+    assert_eq!(main.to_string(), input);
+    let (.., peek1, peek2) = main.get_matched();
+    assert_eq!(peek1.to_string(), "FooFOO");
+    assert_eq!(peek2.span.as_str(), "Foo");
+}
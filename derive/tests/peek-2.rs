@@ -16,6 +16,7 @@ use pest_typed_derive::TypedParser;
 #[grammar_inline = r#"
 main = { PUSH(^"foo")* ~ " " ~ PEEK[1..] }
 "#]
+#[allow(dead_code)]
 struct Parser;
 
 #[test]
@@ -39,7 +40,7 @@ fn tree() {
     assert_eq!(strings.len(), 6);
 
     let mut x = rules::main::try_parse("FOO ").unwrap();
-    x.deref_mut().0.matched.content.clear();
+    x.deref_mut().0.matched.clear();
     assert_eq!(x.get_matched().0, &RepMin::default());
 
     let error = rules::main::try_parse(" ").unwrap_err();
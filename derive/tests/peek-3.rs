@@ -0,0 +1,32 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `PEEK_INSENS` and `POP_INSENS` match the stack case-insensitively.
+
+use pest_typed::ParsableTypedNode;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+heredoc    = { "<<" ~ PUSH(ASCII_ALPHA+) ~ NEWLINE ~ body ~ POP_INSENS }
+body       = { (!(PEEK_INSENS ~ EOI) ~ ANY)* }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn heredoc() {
+    let heredoc = rules::heredoc::try_parse("<<End\nline\nEND").unwrap();
+    let (_, _, _, body, end) = heredoc.as_ref();
+    assert_eq!(body.span.as_str(), "line\n");
+    assert_eq!(end.span.as_str(), "END");
+    assert_eq!((end.span.start(), end.span.end()), (11, 14));
+
+    rules::heredoc::try_parse("<<End\nline\nEDN").unwrap_err();
+}
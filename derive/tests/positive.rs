@@ -0,0 +1,84 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rules referenced under positive predicates are reachable by accessers.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+inner      =  { "a" }
+other      =  { "b" }
+x          =  { &inner ~ ANY+ }
+seq        =  { &(inner ~ other) ~ ANY+ }
+choice     =  { &(inner | other) ~ ANY+ }
+optional   =  { (&inner)? ~ ANY+ }
+repeated   =  { (&inner ~ ANY)+ }
+nested     =  { &(&inner ~ ANY) ~ ANY+ }
+guarded    =  { &(!other ~ inner) ~ ANY+ }
+atomic     = ${ &inner ~ ANY+ }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+/// Accessers to `x+` return `(first, rest)` as the optimizer splits it into `x ~ x*`,
+/// or a single `Vec` with `grammar-extras`, which keeps the repetition.
+#[cfg(not(feature = "grammar-extras"))]
+fn one_or_more<T>((first, rest): (T, Vec<T>)) -> Vec<T> {
+    core::iter::once(first).chain(rest).collect()
+}
+#[cfg(feature = "grammar-extras")]
+fn one_or_more<T>(all: Vec<T>) -> Vec<T> {
+    all
+}
+
+#[test]
+fn direct() {
+    let x = pairs::x::try_parse("abc").unwrap();
+    let inner = x.inner();
+    assert_eq!(inner.span.as_str(), "a");
+    // The peeked node doesn't consume anything.
+    assert_eq!(x.span.as_str(), "abc");
+
+    let atomic = pairs::atomic::try_parse("abc").unwrap();
+    assert_eq!(atomic.inner().span.as_str(), "a");
+}
+
+#[test]
+fn nested() {
+    let seq = pairs::seq::try_parse("a b").unwrap();
+    assert_eq!(seq.inner().span.as_str(), "a");
+    assert_eq!(seq.other().span.start(), 2);
+
+    let choice = pairs::choice::try_parse("b").unwrap();
+    assert!(choice.inner().is_none());
+    assert_eq!(choice.other().unwrap().span.as_str(), "b");
+
+    let optional = pairs::optional::try_parse("a").unwrap();
+    assert_eq!(optional.inner().unwrap().span.as_str(), "a");
+    let optional = pairs::optional::try_parse("b").unwrap();
+    assert!(optional.inner().is_none());
+
+    let repeated = pairs::repeated::try_parse("a a").unwrap();
+    let all = one_or_more(repeated.inner());
+    assert_eq!(all.len(), 2);
+    assert_eq!(all[0].span.start(), 0);
+    assert_eq!(all[1].span.start(), 2);
+
+    let nested = pairs::nested::try_parse("ab").unwrap();
+    assert_eq!(nested.inner().span.as_str(), "a");
+
+    // Negative predicates are still inaccessible, but don't hide their siblings.
+    let guarded = pairs::guarded::try_parse("a").unwrap();
+    assert_eq!(guarded.inner().span.as_str(), "a");
+    pairs::guarded::try_parse("b").unwrap_err();
+}
@@ -0,0 +1,141 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Fold typed expressions with [`pest_typed::pratt::PrattParser`].
+
+use core::ops::Deref;
+use pest_typed::{
+    pratt::{Assoc, PrattParser},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+num        = @{ ASCII_DIGIT+ }
+neg        =  { "-" }
+not        =  { "~" }
+fac        =  { "!" }
+try        =  { "?" }
+add        =  { "+" }
+sub        =  { "-" }
+mul        =  { "*" }
+pow        =  { "^" }
+prefix     = _{ neg | not }
+postfix    = _{ fac | try }
+infix      = _{ add | sub | mul | pow }
+primary    = _{ num | "(" ~ expr ~ ")" }
+expr       =  { prefix* ~ primary ~ postfix* ~ (infix ~ prefix* ~ primary ~ postfix*)* }
+line       =  { SOI ~ expr ~ EOI }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+use generics::{Choice2, Choice4};
+
+type Pratt<'i> = PrattParser<pairs::prefix<'i>, pairs::infix<'i>, pairs::postfix<'i>>;
+
+fn pratt<'i>() -> Pratt<'i> {
+    Pratt::new()
+        .prefix(|op| match op.deref() {
+            Choice2::_0(_neg) => 3,
+            Choice2::_1(_not) => 6,
+        })
+        .infix(|op| match op.deref() {
+            Choice4::_0(_) | Choice4::_1(_) => (1, Assoc::Left),
+            Choice4::_2(_) => (2, Assoc::Left),
+            Choice4::_3(_) => (4, Assoc::Right),
+        })
+        .postfix(|op| match op.deref() {
+            Choice2::_0(_fac) => 5,
+            Choice2::_1(_try) => 2,
+        })
+}
+
+/// Fully parenthesize an expression.
+fn fold<'i>(expr: &pairs::expr<'i>, pratt: &Pratt<'i>) -> String {
+    pratt
+        .map_primary(|primary: &pairs::primary<'i>| match primary.deref() {
+            Choice2::_0(num) => num.span.as_str().to_owned(),
+            Choice2::_1(parenthesized) => {
+                let (_, expr, _) = parenthesized.as_ref();
+                fold(expr, pratt)
+            }
+        })
+        .map_prefix(|op, rhs| match op.deref() {
+            Choice2::_0(neg) => format!("({}{})", neg.span.as_str(), rhs),
+            Choice2::_1(not) => format!("({}{})", not.span.as_str(), rhs),
+        })
+        .map_postfix(|lhs, op| match op.deref() {
+            Choice2::_0(fac) => format!("({}{})", lhs, fac.span.as_str()),
+            Choice2::_1(try_) => format!("({}{})", lhs, try_.span.as_str()),
+        })
+        .map_infix(|lhs, op, rhs| {
+            let op = match op.deref() {
+                Choice4::_0(add) => add.span,
+                Choice4::_1(sub) => sub.span,
+                Choice4::_2(mul) => mul.span,
+                Choice4::_3(pow) => pow.span,
+            };
+            format!("({}{}{})", lhs, op.as_str(), rhs)
+        })
+        .parse(expr.deref())
+}
+
+fn parse(input: &str) -> String {
+    let line = pairs::line::try_parse(input).unwrap();
+    fold(line.expr(), &pratt())
+}
+
+#[test]
+fn precedence() {
+    assert_eq!(parse("1"), "1");
+    assert_eq!(parse("1 + 2 * 3"), "(1+(2*3))");
+    assert_eq!(parse("1 * 2 + 3"), "((1*2)+3)");
+    assert_eq!(parse("1 * 2 ^ 3 + 4"), "((1*(2^3))+4)");
+}
+
+#[test]
+fn associativity() {
+    assert_eq!(parse("1 - 2 - 3"), "((1-2)-3)");
+    assert_eq!(parse("1 - 2 + 3"), "((1-2)+3)");
+    assert_eq!(parse("1 ^ 2 ^ 3"), "(1^(2^3))");
+}
+
+#[test]
+fn prefix_postfix() {
+    assert_eq!(parse("-1 ^ 2"), "(-(1^2))");
+    assert_eq!(parse("-1 * 2"), "((-1)*2)");
+    assert_eq!(parse("~1 ^ 2"), "((~1)^2)");
+    assert_eq!(parse("- ~1"), "(-(~1))");
+    assert_eq!(parse("1 - -2"), "(1-(-2))");
+    assert_eq!(parse("-1!"), "(-(1!))");
+    assert_eq!(parse("-1?"), "((-1)?)");
+    assert_eq!(parse("1 + 2?"), "(1+(2?))");
+    assert_eq!(parse("1 * 2!?"), "((1*(2!))?)");
+}
+
+#[test]
+fn parenthesized() {
+    assert_eq!(parse("(1 + 2) * 3"), "((1+2)*3)");
+    assert_eq!(parse("2 ^ (1 - 2 - 3)!"), "(2^(((1-2)-3)!))");
+    assert_eq!(parse("((1))"), "1");
+}
+
+#[test]
+#[should_panic(expected = "Postfix mapping is not set.")]
+fn missing_mapping() {
+    let line = pairs::line::try_parse("1!").unwrap();
+    pratt()
+        .map_primary(|_: &pairs::primary<'_>| ())
+        .map_infix(|_, _, _| ())
+        .parse(line.expr().deref());
+}
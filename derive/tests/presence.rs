@@ -0,0 +1,112 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Optional strings are generated as `Presence`, which only stores whether they matched.
+
+mod typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+decl       =  { "pub"? ~ "fn" ~ ident ~ ";"? }
+insens     =  { ^"pub"? ~ "fn" ~ ident ~ ";"? }
+"#]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod untyped {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+decl       =  { "pub"? ~ "fn" ~ ident ~ ";"? }
+"#]
+    pub struct Parser;
+}
+
+use core::mem::size_of;
+use pest::Parser as _;
+use pest_typed::{
+    predefined_node::{Insens, Presence, Str},
+    ParsableTypedNode as _, StringWrapper,
+};
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+struct Pub;
+impl StringWrapper for Pub {
+    const CONTENT: &'static str = "pub";
+}
+
+#[test]
+fn present() {
+    let decl = typed::pairs::decl::try_parse("pub fn f;").unwrap();
+    let (public, _, ident, semicolon) = decl.get_matched();
+    let public: bool = public.present();
+    let semicolon: bool = semicolon.present();
+    assert!(public);
+    assert!(semicolon);
+    assert_eq!(ident.span.as_str(), "f");
+    assert_eq!(decl.to_string(), "pub fn f;");
+
+    let decl = typed::pairs::decl::try_parse("fn f").unwrap();
+    let (public, _, _, semicolon) = decl.get_matched();
+    assert!(!public.present());
+    assert!(!semicolon.present());
+    assert_eq!(decl.to_string(), "fn f");
+
+    // Case-insensitive strings keep what they matched.
+    let insens = typed::pairs::insens::try_parse("PUB fn f").unwrap();
+    let (public, _, _, _) = insens.get_matched();
+    assert_eq!(public.as_ref().unwrap().content, "PUB");
+}
+
+#[test]
+fn size() {
+    assert_eq!(size_of::<Presence<Pub>>(), 1);
+    assert_eq!(size_of::<Presence<Pub>>(), size_of::<Option<Str<Pub>>>());
+    assert!(size_of::<Presence<Pub>>() < size_of::<Option<Insens<'static, Pub>>>());
+}
+
+#[test]
+fn parity() {
+    let inputs = [
+        "pub fn f;",
+        "pub fn f",
+        "fn f;",
+        "fn f",
+        "pubfn f",
+        "pub  fn   f ;",
+        "pu fn f",
+        "pub pub fn f",
+        "fn ;",
+        "",
+    ];
+    for input in inputs {
+        let typed = typed::pairs::decl::try_parse_partial(input);
+        let untyped = untyped::Parser::parse(untyped::Rule::decl, input);
+        match (typed, untyped) {
+            (Ok((end, _)), Ok(mut pairs)) => {
+                assert_eq!(
+                    end.pos(),
+                    pairs.next().unwrap().as_span().end(),
+                    "{input:?}"
+                );
+            }
+            (Err(typed), Err(untyped)) => {
+                assert_eq!(typed.location, untyped.location, "{input:?}");
+            }
+            (typed, untyped) => panic!("{input:?}: {typed:?} and {untyped:?}"),
+        }
+    }
+}
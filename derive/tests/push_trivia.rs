@@ -0,0 +1,137 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Spans pushed by `PUSH` are exactly what the inner expression consumed,
+//! which is checked against **pest** by matching them with `PEEK` in an atomic rule.
+
+mod typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r##"
+WHITESPACE = _{ " " }
+COMMENT    = _{ "#" }
+word       = @{ ASCII_ALPHA+ }
+pair       =  { word ~ word }
+closing    = @{ PEEK }
+seq        =  { PUSH(word ~ word) ~ "|" ~ closing }
+rep        =  { PUSH(word*) ~ "|" ~ closing }
+opt        =  { PUSH(word ~ ("," ~ word)?) ~ "|" ~ closing }
+nested     =  { PUSH(pair) ~ "|" ~ closing }
+inner      =  { PUSH(pair ~ pair*) ~ "|" ~ closing }
+"##]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod untyped {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r##"
+WHITESPACE = _{ " " }
+COMMENT    = _{ "#" }
+word       = @{ ASCII_ALPHA+ }
+pair       =  { word ~ word }
+closing    = @{ PEEK }
+seq        =  { PUSH(word ~ word) ~ "|" ~ closing }
+rep        =  { PUSH(word*) ~ "|" ~ closing }
+opt        =  { PUSH(word ~ ("," ~ word)?) ~ "|" ~ closing }
+nested     =  { PUSH(pair) ~ "|" ~ closing }
+inner      =  { PUSH(pair ~ pair*) ~ "|" ~ closing }
+"##]
+    pub struct Parser;
+}
+
+use pest::Parser as _;
+use pest_typed::ParsableTypedNode as _;
+
+/// Span of `closing`, which is the text matched by `PEEK`.
+fn untyped(rule: untyped::Rule, input: &str) -> Option<(usize, usize)> {
+    let pairs = untyped::Parser::parse(rule, input).ok()?;
+    let closing = pairs
+        .flatten()
+        .find(|pair| pair.as_rule() == untyped::Rule::closing)?;
+    Some((closing.as_span().start(), closing.as_span().end()))
+}
+
+macro_rules! check {
+    ($rule:ident, $($input:literal),* $(,)?) => {
+        for input in [$($input),*] {
+            let typed = typed::pairs::$rule::try_parse_partial(input)
+                .ok()
+                .map(|(_, node)| (node.closing().span.start(), node.closing().span.end()));
+            assert_eq!(typed, untyped(untyped::Rule::$rule, input), "{:?}", input);
+        }
+    };
+}
+
+#[test]
+fn sequence() {
+    check!(
+        seq,
+        "a b|a b",
+        "a b |a b",
+        "a  b  |a  b",
+        "a#b # |a#b",
+        "a b | a b",
+        "a b |a b ",
+        "a b |a  b",
+        "a b |ab"
+    );
+}
+
+#[test]
+fn repetition() {
+    check!(
+        rep,
+        "|",
+        "a|a",
+        "a b c |a b c",
+        "a b  |a b",
+        "a b |a b  ",
+        "a # b#|a # b",
+        "a b |a b c"
+    );
+}
+
+#[test]
+fn optional() {
+    check!(
+        opt,
+        "a|a",
+        "a , b |a , b",
+        "a ,b |a ,b",
+        "a  |a",
+        "a , |a ,",
+        "a , b |a, b"
+    );
+}
+
+#[test]
+fn inner_rules() {
+    check!(nested, "a b|a b", "a b  |a b", "a  b |a  b", "a  b |a b");
+    check!(
+        inner,
+        "a b c d |a b c d",
+        "a b c  |a b",
+        "a b c d  # |a b c d",
+        "a b c d |a b c"
+    );
+}
+
+#[test]
+fn embedded_trivia() -> anyhow::Result<()> {
+    // Trivia between `a` and `b` is pushed, while the trailing one before `|` is not.
+    let seq = typed::pairs::seq::try_parse("a  b  |a  b")?;
+    assert_eq!(seq.closing().span.as_str(), "a  b");
+    typed::pairs::seq::try_parse("a  b  |a b").unwrap_err();
+    Ok(())
+}
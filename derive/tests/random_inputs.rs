@@ -0,0 +1,83 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Inputs from `generate_random` with `emit_generator` are parsed by the same rules.
+
+use pest_typed::random::SplitMix64;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r##"
+WHITESPACE = _{ " " | "\t" | NEWLINE }
+COMMENT    = _{ "/*" ~ ASCII_DIGIT* ~ "*/" }
+value      =  { object | array | string | raw | number | boolean | null }
+object     =  { "{" ~ (pair ~ ("," ~ pair)*)? ~ "}" }
+pair       =  { string ~ ":" ~ value }
+array      =  { "[" ~ (value ~ ("," ~ value)*)? ~ "]" }
+string     = ${ "\"" ~ inner ~ "\"" }
+inner      = @{ (ASCII_ALPHANUMERIC | " " | "\\" ~ ("n" | "\"" | "u" ~ ASCII_HEX_DIGIT{4}))* }
+raw        = ${ PUSH("#"*) ~ "'" ~ ('a'..'z')* ~ "'" ~ POP }
+number     = @{ "-"? ~ ("0" | ASCII_NONZERO_DIGIT ~ ASCII_DIGIT*) ~ ("." ~ ASCII_DIGIT+)? ~ (^"e" ~ ASCII_DIGIT+)? }
+boolean    =  { ^"true" | ^"false" }
+null       =  { "null" }
+"##]
+#[emit_generator]
+#[allow(dead_code)]
+struct Parser;
+
+const RULES: [Rule; 10] = [
+    Rule::value,
+    Rule::object,
+    Rule::pair,
+    Rule::array,
+    Rule::string,
+    Rule::inner,
+    Rule::raw,
+    Rule::number,
+    Rule::boolean,
+    Rule::null,
+];
+
+#[test]
+fn round_trip() {
+    let mut rng = SplitMix64::new(1082);
+    for max_depth in 0..8 {
+        for _ in 0..64 {
+            for rule in RULES {
+                let input = generate_random(rule, &mut rng, max_depth);
+                let res = parse_rule(rule, &input);
+                assert!(res.is_ok(), "{rule:?} {input:?}: {}", res.err().unwrap());
+                assert_eq!(res.unwrap().rule(), rule);
+            }
+        }
+    }
+}
+
+#[test]
+fn shallowest() {
+    let mut rng = SplitMix64::new(0);
+    assert_eq!(generate_random(Rule::value, &mut rng, 0), "{}");
+    assert_eq!(generate_random(Rule::array, &mut rng, 0), "[]");
+    assert_eq!(generate_random(Rule::object, &mut rng, 0), "{}");
+    assert_eq!(generate_random(Rule::number, &mut rng, 0), "0");
+    assert_eq!(generate_random(Rule::raw, &mut rng, 0), "''");
+    assert_eq!(generate_random(Rule::EOI, &mut rng, 0), "");
+}
+
+#[test]
+fn deterministic() {
+    let generate = |seed| {
+        let mut rng = SplitMix64::new(seed);
+        (0..16)
+            .map(|_| generate_random(Rule::value, &mut rng, 6))
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(generate(7), generate(7));
+    assert_ne!(generate(7), generate(8));
+}
@@ -0,0 +1,99 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\n" }
+program    =  { SOI ~ (stmt ~ sep)* ~ EOI }
+stmt       =  { ident ~ "=" ~ number }
+sep        =  { ";" }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+"#]
+#[emit_rule_reference]
+#[recover_at(sep)]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn no_error() {
+    let (program, errors) = pairs::program::parse_with_recovery("a = 1; b = 2;");
+    assert!(errors.is_empty());
+    let program = program.unwrap();
+    let stmts = program.stmt();
+    assert_eq!(stmts.len(), 2);
+    assert!(stmts.iter().all(Option::is_some));
+}
+
+#[test]
+fn multiple_errors() {
+    let input = "a = 1;\nb = ;\nc = 3;\nd 4;\ne = 5;";
+    let (program, errors) = pairs::program::parse_with_recovery(input);
+    let program = program.unwrap();
+
+    let recovered: Vec<_> = program
+        .content
+        .get_matched()
+        .1
+        .content
+        .iter()
+        .map(|element| element.is_recovered())
+        .collect();
+    assert_eq!(recovered, [false, true, false, true, false]);
+    let idents: Vec<_> = program
+        .stmt()
+        .into_iter()
+        .map(|stmt| stmt.map(|stmt| stmt.ident().span.as_str()))
+        .collect();
+    assert_eq!(idents, [Some("a"), None, Some("c"), None, Some("e")]);
+    assert_eq!(program.sep().len(), 5);
+
+    let lines: Vec<_> = errors
+        .iter()
+        .map(|error| match error.line_col {
+            pest_typed::error::LineColLocation::Pos(line_col) => line_col,
+            pest_typed::error::LineColLocation::Span(line_col, _) => line_col,
+        })
+        .collect();
+    assert_eq!(lines, [(2, 5), (4, 1)]);
+    assert_eq!(
+        errors[0].to_string(),
+        " --> 2:5
+  |
+2 | b = ;
+  |     ^---
+  |
+  = b = ^---
+    Expected [number], by stmt."
+    );
+
+    // Parsing without recovery stops at the first error.
+    let error = pairs::program::try_parse(input).unwrap_err();
+    assert_eq!(error.line_col, errors[0].line_col);
+}
+
+#[test]
+fn unrecoverable() {
+    let (program, errors) = pairs::program::parse_with_recovery("a = ;\nb = 2");
+    assert!(program.is_none());
+    assert_eq!(errors.len(), 2);
+    assert_eq!(
+        errors[1].to_string(),
+        " --> 2:6
+  |
+2 | b = 2
+  |      ^---
+  |
+  = b = 2^---
+    Expected [sep], by program."
+    );
+}
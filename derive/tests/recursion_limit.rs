@@ -0,0 +1,153 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Deeply nested inputs fail with an error instead of overflowing the stack.
+
+mod default {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+expr       =  { "(" ~ expr ~ ")" | "x" }
+sum        =  { product ~ ("+" ~ product)* }
+product    =  { unary ~ ("*" ~ unary)* }
+unary      =  { "-"* ~ postfix }
+postfix    =  { primary ~ "!"* }
+primary    =  { paren | "x" }
+paren      =  { "(" ~ sum ~ ")" }
+list       =  { items }
+items      = _{ "[" ~ items ~ "]" | "y" }
+"#]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod limited {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+expr       =  { "(" ~ expr ~ ")" | "x" }
+fallback   =  { "(" ~ fallback ~ ")" | "(" ~ ANY* }
+"#]
+    #[rule_recursion_limit = 4]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+use pest_typed::{
+    error::InputLocation,
+    tracker::{Tracker, DEFAULT_RECURSION_LIMIT},
+    ParsableTypedNode as _, Position, RuleWrapper, Stack,
+};
+
+fn nested(open: &str, inner: &str, close: &str, depth: usize) -> String {
+    format!("{}{}{}", open.repeat(depth), inner, close.repeat(depth))
+}
+
+/// Run `f` with the 8 MiB stack of a main thread, as test threads only have 2 MiB.
+fn with_main_stack(f: impl FnOnce() + Send + 'static) {
+    std::thread::Builder::new()
+        .stack_size(8 << 20)
+        .spawn(f)
+        .unwrap()
+        .join()
+        .unwrap();
+}
+
+#[test]
+fn pathological() {
+    let input = nested("(", "x", ")", 100_000);
+    let error = default::pairs::expr::try_parse(&input).unwrap_err();
+    assert_eq!(error.location, InputLocation::Pos(DEFAULT_RECURSION_LIMIT));
+    assert!(error
+        .to_string()
+        .ends_with("Recursion limit exceeded (128 nested recursive rules). (By expr)"));
+
+    let input = nested("[", "y", "]", 100_000);
+    let error = default::pairs::list::try_parse(&input).unwrap_err();
+    assert!(error.to_string().contains("Recursion limit exceeded"));
+
+    let input = nested("(", "x", ")", DEFAULT_RECURSION_LIMIT - 1);
+    assert!(default::pairs::expr::try_parse(&input).is_ok());
+}
+
+#[test]
+fn pathological_chain() {
+    // Each level of parentheses goes through 6 rules.
+    with_main_stack(|| {
+        let input = nested("(", "-x!", ")", 100_000);
+        let error = default::pairs::sum::try_parse(&input).unwrap_err();
+        assert_eq!(error.location, InputLocation::Pos(DEFAULT_RECURSION_LIMIT));
+        assert!(error.to_string().contains("Recursion limit exceeded"));
+    });
+}
+
+#[test]
+fn deeply_nested() {
+    // Only `sum` counts, so that every level of parentheses counts once.
+    assert_eq!(
+        [
+            <default::pairs::sum<'_> as RuleWrapper<default::Rule>>::COUNTS_RECURSION,
+            <default::pairs::product<'_> as RuleWrapper<default::Rule>>::COUNTS_RECURSION,
+            <default::pairs::paren<'_> as RuleWrapper<default::Rule>>::COUNTS_RECURSION,
+        ],
+        [true, false, false]
+    );
+    with_main_stack(|| {
+        let input = nested("(", "-x!", ")", DEFAULT_RECURSION_LIMIT - 1);
+        assert!(default::pairs::sum::try_parse(&input).is_ok());
+    });
+}
+
+#[test]
+fn limited() {
+    assert_eq!(
+        <limited::pairs::expr<'_> as RuleWrapper<limited::Rule>>::RECURSION_LIMIT,
+        4
+    );
+    assert_eq!(
+        <default::pairs::expr<'_> as RuleWrapper<default::Rule>>::RECURSION_LIMIT,
+        DEFAULT_RECURSION_LIMIT
+    );
+
+    assert!(limited::pairs::expr::try_parse("((( x )))").is_ok());
+    let error = limited::pairs::expr::try_parse("(((( x ))))").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:6
+  |
+1 | (((( x ))))
+  |      ^---
+  |
+  = (((( ^---
+    Recursion limit exceeded (4 nested recursive rules). (By expr)"
+    );
+
+    let error = limited::pairs::expr::try_parse_partial("(((( x ))))").unwrap_err();
+    assert!(error.to_string().contains("Recursion limit exceeded"));
+}
+
+#[test]
+fn no_fallback() {
+    // Shallower branches would match, but the input is still rejected.
+    assert!(limited::pairs::fallback::try_parse("((( x").is_ok());
+    let error = limited::pairs::fallback::try_parse("(((( x").unwrap_err();
+    assert!(error.to_string().contains("Recursion limit exceeded"));
+
+    let input = "(((( x";
+    let mut tracker = Tracker::new(Position::from_start(input));
+    assert!(
+        limited::pairs::fallback::try_parse_with_state(input, &mut Stack::new(), &mut tracker)
+            .is_none()
+    );
+    assert!(tracker.recursion_limit_exceeded());
+}
@@ -0,0 +1,44 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Non-empty views of rules aliased to `RepOnce`.
+
+#![cfg(feature = "grammar-extras")]
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+item = @{ ASCII_ALPHA+ }
+list = { item+ }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn first_and_rest() {
+    let list = pairs::list::try_parse("a bc d").unwrap();
+    assert_eq!(list.first().span.as_str(), "a");
+    let rest = list
+        .rest()
+        .iter()
+        .map(|i| i.span.as_str())
+        .collect::<Vec<_>>();
+    assert_eq!(rest, ["bc", "d"]);
+    let (first, rest) = list.split_first();
+    assert_eq!(first, list.first());
+    assert_eq!(rest.len(), 2);
+
+    let list = pairs::list::try_parse("a").unwrap();
+    assert_eq!(list.first().span.as_str(), "a");
+    assert!(list.rest().is_empty());
+}
@@ -0,0 +1,119 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! `(!terminator ~ item)*` is lowered to `RepUntil` with `lower_rep_until`
+//! where nothing is skipped in between.
+
+mod lowered {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+comment    = ${ "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+line       = ${ "//" ~ (!NEWLINE ~ ANY)* }
+until_eoi  = ${ (!EOI ~ ("a" | "b"))* }
+spaced     =  { "(" ~ (!")" ~ ASCII_DIGIT)* ~ ")" }
+"#]
+    #[emit_rule_reference]
+    #[lower_rep_until]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod desugared {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+comment    = ${ "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
+line       = ${ "//" ~ (!NEWLINE ~ ANY)* }
+until_eoi  = ${ (!EOI ~ ("a" | "b"))* }
+spaced     =  { "(" ~ (!")" ~ ASCII_DIGIT)* ~ ")" }
+"#]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+use pest_typed::{ParsableTypedNode, Spanned};
+
+/// End of the partial match, or the error message.
+fn parse<'i, N: ParsableTypedNode<'i, R>, R: pest_typed::RuleType>(
+    input: &'i str,
+) -> Result<usize, String> {
+    N::try_parse_partial(input)
+        .map(|(pos, _)| pos.pos())
+        .map_err(|err| err.to_string())
+}
+
+macro_rules! same {
+    ($rule:ident, $inputs:expr) => {
+        for input in $inputs {
+            assert_eq!(
+                parse::<lowered::pairs::$rule<'_>, lowered::Rule>(input),
+                parse::<desugared::pairs::$rule<'_>, desugared::Rule>(input),
+                "{:?}",
+                input
+            );
+        }
+    };
+}
+
+#[test]
+fn same_as_desugared() {
+    same!(
+        comment,
+        [
+            "/**/",
+            "/* a */",
+            "/* * / */",
+            "/* 世界 */ x",
+            "/*",
+            "/* a",
+            "/* a *",
+            "x"
+        ]
+    );
+    same!(line, ["//", "// a", "// a\nb", "// a\r\n", "/"]);
+    same!(until_eoi, ["", "ab", "abc", "c"]);
+    same!(spaced, ["()", "(1 2)", "(1 2 )", "(1", "(1 x)"]);
+}
+
+#[test]
+fn terminator() {
+    let comment = lowered::pairs::comment::try_parse("/* ab */").unwrap();
+    let body = &comment.content.content.1.matched;
+    assert_eq!(body.len(), 4);
+    assert_eq!(body.terminator.unwrap().as_str(), "*/");
+    assert_eq!(body.terminator.unwrap().start(), 6);
+    assert_eq!(comment.span().as_str(), "/* ab */");
+}
+
+#[test]
+fn unterminated() {
+    let (pos, line) = lowered::pairs::line::try_parse_partial("// ab").unwrap();
+    assert_eq!(pos.pos(), 5);
+    let body = &line.content.content.1.matched;
+    assert!(!body.terminated());
+    assert_eq!(body.len(), 3);
+
+    // Backtracks out of the whole rule at the end of input, just like `(!"*/" ~ ANY)*`.
+    assert!(lowered::pairs::comment::try_parse("/* ab").is_err());
+
+    let until_eoi = lowered::pairs::until_eoi::try_parse("abab").unwrap();
+    assert_eq!(until_eoi.content.len(), 4);
+    assert_eq!(until_eoi.content.terminator.unwrap().start(), 4);
+}
+
+#[test]
+fn skipped_not_lowered() {
+    let spaced = lowered::pairs::spaced::try_parse("(1 2)").unwrap();
+    assert_eq!(spaced.span().as_str(), "(1 2)");
+}
@@ -0,0 +1,52 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Rules named after Rust keywords or generated items.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+type       =  { "type" ~ Rule ~ generics? }
+Rule       =  { ASCII_ALPHA+ }
+generics   =  { "<" ~ pairs ~ ">" }
+pairs      =  { tags ~ ("," ~ tags)* }
+tags       =  { rules }
+rules      = @{ ASCII_DIGIT+ }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn parse() -> Result<(), Error> {
+    let ty = pairs::r#type::try_parse("type Map<1, 2>")?;
+    assert_eq!(ty.r#Rule().span.as_str(), "Map");
+    let generics = ty.generics().unwrap();
+    let (first, rest) = generics.pairs().tags();
+    assert_eq!(first.span.as_str(), "1");
+    assert_eq!(rest.len(), 1);
+    assert_eq!(rest[0].rules().span.as_str(), "2");
+
+    let ty = Parser::parse_type("type Set")?;
+    assert!(ty.generics().is_none());
+    assert_eq!(ty.span.as_str(), "type Set");
+    Ok(())
+}
+
+#[test]
+fn rule_enum() {
+    assert_eq!(Rule::r#type.name(), "type");
+    assert_eq!(Rule::from_name("Rule"), Some(Rule::r#Rule));
+    assert_eq!(Rule::from_name("pairs"), Some(Rule::r#pairs));
+    assert_eq!(Rule::r#generics.name(), "generics");
+}
@@ -0,0 +1,45 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Reading the stack left after a partial parse.
+
+use pest_typed::{ParsableTypedNode as _, StackExt as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+open  = ${ PUSH(" "*) ~ "begin" ~ NEWLINE }
+close = ${ " "* ~ "end" ~ DROP ~ NEWLINE }
+stmt  = ${ " "* ~ ASCII_ALPHA+ ~ NEWLINE }
+lines = ${ (open | close | stmt)* }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn unclosed_blocks() {
+    let input = "begin\n  x\n  begin\n    y\n  end\n  begin\n    z\n";
+    let (end, _, stack) = pairs::lines::try_parse_partial_with_stack(input).unwrap();
+    assert_eq!(end.pos(), input.len());
+    assert_eq!(stack.len(), 2);
+    let indents: Vec<_> = stack.iter().map(|span| span.as_str()).collect();
+    assert_eq!(indents, ["", "  "]);
+    assert_eq!(stack.peek_nth(0).unwrap().as_str(), "  ");
+    assert_eq!(stack.peek_nth(1).unwrap().as_str(), "");
+    assert!(stack.peek_nth(2).is_none());
+}
+
+#[test]
+fn closed_blocks() {
+    let input = "begin\n  begin\n  end\nend\nx\n";
+    let (_, _, stack) = pairs::lines::try_parse_partial_with_stack(input).unwrap();
+    assert!(stack.is_empty());
+    assert_eq!(stack.iter().count(), 0);
+    assert!(stack.peek_nth(0).is_none());
+}
@@ -0,0 +1,65 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Inner pairs can be iterated in reverse, and counted without being consumed.
+
+use pest_typed::{
+    iterators::{Pair as _, Pairs as _},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+item       =  { ASCII_ALPHA+ }
+sep        = _{ "," }
+list       =  { "[" ~ (item ~ (sep ~ item)*)? ~ "]" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn reversed() {
+    let list = pairs::list::try_parse("[a, b, c]").unwrap();
+    let items: Vec<_> = list
+        .content
+        .iter_pairs()
+        .rev()
+        .map(|pair| pair.span().as_str())
+        .collect();
+    assert_eq!(items, ["c", "b", "a"]);
+
+    let last = list.content.iter_pairs().next_back().unwrap();
+    assert_eq!(last.rule(), Rule::item);
+    assert_eq!(last.span().as_str(), "c");
+
+    let items: Vec<_> = list
+        .content
+        .into_iter_pairs()
+        .rev()
+        .map(|pair| pair.span().as_str())
+        .collect();
+    assert_eq!(items, ["c", "b", "a"]);
+}
+
+#[test]
+fn len() {
+    let list = pairs::list::try_parse("[a, b, c]").unwrap();
+    let mut inner = list.inner();
+    assert_eq!(inner.len(), 3);
+    assert_eq!(inner.next_back().unwrap().span().as_str(), "c");
+    assert_eq!(inner.len(), 2);
+    let items: Vec<_> = inner.rev().map(|pair| pair.span().as_str()).collect();
+    assert_eq!(items, ["b", "a"]);
+
+    let list = pairs::list::try_parse("[]").unwrap();
+    assert_eq!(list.inner().len(), 0);
+    assert!(list.content.iter_pairs().next_back().is_none());
+}
@@ -0,0 +1,40 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Dependency graph of rules and reachability from root rules.
+
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+file       =  { SOI ~ (item ~ ";")* ~ EOI }
+item       =  { key ~ "=" ~ value }
+key        = @{ ASCII_ALPHA+ }
+value      = _{ number | key }
+number     = @{ ASCII_DIGIT+ }
+"#]
+#[roots(file)]
+#[deny_unused_rules]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn dependencies() {
+    let dependencies = rule_dependencies();
+    assert_eq!(dependencies.len(), 6);
+    assert_eq!(dependencies[0], (Rule::WHITESPACE, &[][..]));
+    assert_eq!(dependencies[1], (Rule::file, &[Rule::item, Rule::EOI][..]));
+    assert_eq!(dependencies[2], (Rule::item, &[Rule::key, Rule::value][..]));
+    assert_eq!(
+        dependencies[4],
+        (Rule::value, &[Rule::key, Rule::number][..])
+    );
+    assert_eq!(dependencies[5], (Rule::number, &[][..]));
+}
@@ -0,0 +1,83 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Parse with a rule selected by a `Rule` value at runtime.
+
+use pest_typed::{
+    iterators::{AnyPair, Pair as _, PairTree as _},
+    ParsableTypedNode as _,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+file       =  { SOI ~ expr ~ (";" ~ expr)* ~ EOI }
+expr       =  { term ~ ("+" ~ term)* }
+term       = _{ number | ident }
+number     = @{ ASCII_DIGIT+ }
+ident      = ${ ASCII_ALPHA ~ ASCII_ALPHANUMERIC* }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn dispatch() -> anyhow::Result<()> {
+    let cases = [
+        (Rule::file, "1 + x; 2", (0, 8)),
+        (Rule::expr, "1 + x2 + 3", (0, 10)),
+        (Rule::number, "42", (0, 2)),
+        (Rule::ident, "x2", (0, 2)),
+        (Rule::EOI, "", (0, 0)),
+    ];
+    for (rule, input, (start, end)) in cases {
+        let pair: Box<dyn AnyPair<'_, Rule>> = parse_rule(rule, input)?;
+        assert_eq!(pair.rule(), rule);
+        assert_eq!((pair.span().start(), pair.span().end()), (start, end));
+        assert_eq!(pair.as_str(), input);
+    }
+    Ok(())
+}
+
+#[test]
+fn same_as_static() -> anyhow::Result<()> {
+    let input = "1 + x; 2";
+    let pair = parse_rule(Rule::file, input)?;
+    let file = pairs::file::try_parse(input)?;
+    assert_eq!(pair.token_tree(), file.as_token_tree());
+    assert_eq!(format!("{:?}", pair), format!("{:?}", file));
+
+    let rules: Vec<_> = pair.inner_pairs().map(|pair| pair.rule()).collect();
+    assert_eq!(rules, [Rule::expr, Rule::expr, Rule::EOI]);
+    let inner: Vec<_> = pair
+        .inner_pairs()
+        .next()
+        .unwrap()
+        .inner()
+        .map(|pair| pair.span().as_str())
+        .collect();
+    assert_eq!(inner, ["1", "x"]);
+    assert!(file.format_as_tree().is_ok());
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let err = parse_rule(Rule::number, "x").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        pairs::number::try_parse("x").unwrap_err().to_string()
+    );
+    let err = parse_rule(Rule::term, "1").unwrap_err();
+    assert!(err
+        .to_string()
+        .contains("Rule `term` is silent, so it can't be parsed into a pair."));
+    let err = parse_rule(Rule::WHITESPACE, " ").unwrap_err();
+    assert!(err.to_string().contains("Rule `WHITESPACE` is silent"));
+}
@@ -0,0 +1,55 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Atomicity, silence and docs of rules are available at runtime.
+
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+/// A name.
+ident      = @{ ASCII_ALPHA+ }
+/// A quoted string,
+/// without escapes.
+string     = ${ "\"" ~ inner ~ "\"" }
+inner      = @{ (!"\"" ~ ANY)* }
+value      = _{ ident | string }
+pair       =  { ident ~ "=" ~ value }
+block      = !{ "{" ~ pair* ~ "}" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn atomicity() {
+    let atomic: Vec<_> = Rule::ALL.iter().filter(|rule| rule.is_atomic()).collect();
+    assert_eq!(atomic, [&Rule::ident, &Rule::string, &Rule::inner]);
+    assert!(!Rule::block.is_atomic());
+    assert!(!Rule::EOI.is_atomic());
+}
+
+#[test]
+fn silence() {
+    let silent: Vec<_> = Rule::ALL.iter().filter(|rule| rule.is_silent()).collect();
+    assert_eq!(silent, [&Rule::WHITESPACE, &Rule::value]);
+    assert!(!Rule::pair.is_silent());
+    assert!(!Rule::EOI.is_silent());
+}
+
+#[test]
+fn docs() {
+    assert_eq!(Rule::ident.doc(), Some("A name."));
+    assert_eq!(
+        Rule::string.doc(),
+        Some("A quoted string,\nwithout escapes.")
+    );
+    assert_eq!(Rule::pair.doc(), None);
+    assert_eq!(Rule::EOI.doc(), None);
+}
@@ -0,0 +1,57 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Names of rules as in the grammar.
+
+use pest_typed_derive::TypedParser;
+use std::collections::BTreeSet;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+type       =  { ident }
+match      =  { "match" ~ type }
+fn         =  { "fn" ~ ident }
+silent     = _{ match | fn }
+"#]
+#[no_warnings]
+#[allow(dead_code)]
+struct Parser;
+
+const GRAMMAR_RULES: usize = 6;
+
+#[test]
+fn round_trip() {
+    assert_eq!(Rule::ALL.len(), GRAMMAR_RULES + 1);
+    assert_eq!(Rule::NAMES.len(), Rule::ALL.len());
+    for (rule, name) in Rule::ALL.iter().zip(Rule::NAMES) {
+        assert_eq!(rule.name(), *name);
+        assert_eq!(Rule::from_name(name), Some(*rule));
+    }
+    let names: BTreeSet<_> = Rule::ALL.iter().map(Rule::name).collect();
+    assert_eq!(names.len(), Rule::ALL.len());
+}
+
+#[test]
+fn names() {
+    assert_eq!(Rule::ALL[0], Rule::EOI);
+    assert_eq!(Rule::EOI.name(), "EOI");
+    assert_eq!(Rule::WHITESPACE.name(), "WHITESPACE");
+    // Names are not escaped like raw identifiers.
+    assert_eq!(Rule::r#type.name(), "type");
+    assert_eq!(Rule::r#match.name(), "match");
+    assert_eq!(Rule::r#fn.name(), "fn");
+    assert_eq!(Rule::from_name("type"), Some(Rule::r#type));
+    assert_eq!(Rule::from_name("silent"), Some(Rule::silent));
+
+    assert_eq!(Rule::from_name("r#type"), None);
+    assert_eq!(Rule::from_name("Type"), None);
+    assert_eq!(Rule::from_name(""), None);
+}
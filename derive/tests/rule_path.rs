@@ -0,0 +1,118 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+function   =  { "fn" ~ ident ~ params }
+params     =  { "(" ~ (param ~ ("," ~ param)*)? ~ ")" }
+param      =  { ident ~ ":" ~ ty }
+ty         =  { "int" | "bool" }
+ident      = @{ ASCII_ALPHA+ }
+
+stmt       =  { assign | call }
+assign     =  { ident ~ "=" ~ value }
+call       =  { ident ~ args }
+args       =  { "(" ~ (value ~ ("," ~ value)*)? ~ ")" }
+value      = _{ number }
+number     = @{ ASCII_DIGIT+ }
+
+paren      =  { "(" ~ bracket ~ ")" | number }
+bracket    =  { "[" ~ paren ~ "]" }
+
+expr       =  { term ~ ("+" ~ term)* }
+term       =  { factor ~ ("*" ~ factor)* }
+factor     =  { "-"? ~ primary }
+primary    =  { number | "(" ~ expr ~ ")" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn nested() {
+    let error = pairs::function::try_parse("fn f(a: int, b: x)").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:17
+  |
+1 | fn f(a: int, b: x)
+  |                 ^---
+  |
+  = In `function` → `params` → `param`:
+    fn f(a: int, b: ^---
+    Expected [ty], by param."
+    );
+}
+
+#[test]
+fn root() {
+    let error = pairs::function::try_parse("f").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:1
+  |
+1 | f
+  | ^---
+  |
+  = ^---
+    Expected [function]."
+    );
+}
+
+#[test]
+fn farthest_choice() {
+    // Silent rule `value` is not in the path.
+    let error = pairs::stmt::try_parse("f(1, x").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:6
+  |
+1 | f(1, x
+  |      ^---
+  |
+  = In `stmt` → `call` → `args`:
+    f(1, ^---
+    Expected [number], by args."
+    );
+}
+
+#[test]
+fn elided() {
+    let error = pairs::paren::try_parse("([([([([(x").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:10
+  |
+1 | ([([([([(x
+  |          ^---
+  |
+  = In … → `bracket` → `paren` → `bracket` → `paren` → `bracket` → `paren` → `bracket` → `paren`:
+    ([([([([(^---
+    Expected [bracket], by paren."
+    );
+}
+
+#[test]
+fn arithmetic() {
+    let error = pairs::expr::try_parse("1 + 2 * -x").unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        " --> 1:10
+  |
+1 | 1 + 2 * -x
+  |          ^---
+  |
+  = In `expr` → `term` → `factor` → `primary`:
+    1 + 2 * -^---
+    Expected [number], by factor."
+    );
+}
@@ -0,0 +1,46 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Accesser functions emitted for listed rules only.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+expr       =  { ident | number }
+stmt       =  { ident ~ "=" ~ expr ~ ";" }
+item       =  { "let" ~ stmt }
+block      =  { "{" ~ stmt* ~ "}" }
+"#]
+#[emit_rule_reference(expr, stmt)]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn listed() {
+    let stmt = pairs::stmt::try_parse("x = 1;").unwrap();
+    assert_eq!(stmt.ident().span.as_str(), "x");
+    let expr = stmt.expr();
+    assert!(expr.ident().is_none());
+    assert_eq!(expr.number().unwrap().span.as_str(), "1");
+
+    let item = pairs::item::try_parse("let y = z;").unwrap();
+    assert_eq!(item.span.as_str(), "let y = z;");
+    let (_, stmt) = item.get_matched();
+    assert_eq!(stmt.expr().ident().unwrap().span.as_str(), "z");
+
+    let block = pairs::block::try_parse("{ a = 1; b = c; }").unwrap();
+    let (_, stmts, _) = block.get_matched();
+    assert_eq!(stmts.len(), 2);
+    assert_eq!(stmts[1].ident().span.as_str(), "b");
+}
@@ -0,0 +1,60 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Stable discriminants of `Rule` with `rule_repr`.
+
+mod before {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+key   = { ASCII_ALPHA+ }
+value = { ASCII_DIGIT+ }
+pair  = { key ~ "=" ~ value }
+"#]
+    #[rule_repr(u16)]
+    #[rule_non_exhaustive]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod after {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+key   = { ASCII_ALPHA+ }
+value = { ASCII_DIGIT+ }
+pair  = { key ~ "=" ~ value }
+list  = { pair ~ ("," ~ pair)* }
+"#]
+    #[rule_repr(u16)]
+    #[rule_non_exhaustive]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+#[test]
+fn appended() {
+    use after::Rule as A;
+    use before::Rule as B;
+    assert_eq!(B::EOI as u16, 0);
+    assert_eq!(A::EOI as u16, B::EOI as u16);
+    assert_eq!(A::key as u16, B::key as u16);
+    assert_eq!(A::value as u16, B::value as u16);
+    assert_eq!(A::pair as u16, B::pair as u16);
+    assert_eq!(A::list as u16, 4);
+    assert_eq!(core::mem::size_of::<B>(), 2);
+}
+
+#[test]
+fn order() {
+    let discriminants: Vec<u16> = after::Rule::ALL.iter().map(|rule| *rule as u16).collect();
+    assert_eq!(discriminants, [0, 1, 2, 3, 4]);
+}
@@ -0,0 +1,47 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+use serde_json::Value;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+program    =  { SOI ~ item ~ ("," ~ item)* ~ ","? ~ EOI }
+item       = _{ number | ident }
+number     = @{ ASCII_DIGIT+ }
+ident      =  { ^"x" ~ suffix? }
+suffix     = ${ "_" ~ ('0'..'9') }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn snapshot() -> Result<(), Error> {
+    let program = pairs::program::try_parse("12 , X_1, x")?;
+    let actual = serde_json::to_value(&program)?;
+    let expected: Value = serde_json::from_str(include_str!("serde.json"))?;
+    assert_eq!(
+        actual,
+        expected,
+        "{}",
+        serde_json::to_string_pretty(&actual)?
+    );
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn rule() -> Result<(), Error> {
+    assert_eq!(serde_json::to_value(Rule::number)?, "number");
+    assert_eq!(serde_json::to_value(Rule::EOI)?, "EOI");
+    Ok(())
+}
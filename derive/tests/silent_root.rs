@@ -0,0 +1,75 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Silent rules used as the root of a grammar.
+
+use pest_typed::{iterators::Pairs as _, ParsableTypedNode as _, Root};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r##"
+WHITESPACE = _{ " " }
+COMMENT    = _{ "#" ~ (!"\n" ~ ANY)* }
+file       = _{ SOI ~ item ~ (("," | "\n") ~ item)* ~ EOI }
+item       =  { key ~ "=" ~ value }
+key        = @{ ASCII_ALPHA+ }
+value      = @{ ASCII_DIGIT+ }
+"##]
+#[entry_rules(file, item)]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn span() -> anyhow::Result<()> {
+    let input = " a = 1, b = 2 # end\nc = 3 ";
+    let file: Root<'_, pairs::file<'_>> = Parser::parse_file(input)?;
+    assert_eq!(file.span.as_str(), input);
+    assert_eq!((file.span.start(), file.span.end()), (0, input.len()));
+    assert_eq!(file.content.to_string(), input);
+
+    let file = Parser::parse_file("a=1")?;
+    assert_eq!(file.span.as_str(), "a=1");
+    Ok(())
+}
+
+#[test]
+fn accessers() -> anyhow::Result<()> {
+    let file = Parser::parse_file("a = 1, b = 2\nc = 3")?;
+    let (first, rest) = file.item();
+    assert_eq!(first.key().span.as_str(), "a");
+    let keys: Vec<_> = rest.iter().map(|item| item.key().span.as_str()).collect();
+    assert_eq!(keys, ["b", "c"]);
+    let values: Vec<_> = rest.iter().map(|item| item.value().span.as_str()).collect();
+    assert_eq!(values, ["2", "3"]);
+    Ok(())
+}
+
+#[test]
+fn pairs() -> anyhow::Result<()> {
+    let file = Parser::parse_file("a = 1, b = 2")?;
+    let rules: Vec<_> = file.iter_pairs().map(|pair| pair.rule()).collect();
+    assert_eq!(rules, [Rule::item, Rule::item, Rule::EOI]);
+
+    // Same as parsing with the trait.
+    let parsed = pairs::file::try_parse("a = 1, b = 2")?;
+    assert_eq!(parsed, file.content);
+    Ok(())
+}
+
+#[test]
+fn errors() {
+    let err = Parser::parse_file("a = 1,").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        pairs::file::try_parse("a = 1,").unwrap_err().to_string()
+    );
+    // Non-silent entry rules are not wrapped.
+    assert_eq!(Parser::parse_item("a = 1").unwrap().span.as_str(), "a = 1");
+}
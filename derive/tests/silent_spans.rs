@@ -0,0 +1,55 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Spans of silent rules with `silent_spans`.
+
+use pest_typed::{iterators::Pair, ParsableTypedNode as _, Spanned};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+entry      =  { "(" ~ body ~ ")" }
+body       = _{ key ~ sep ~ value }
+sep        = _{ "=" | ":" }
+key        = @{ ASCII_ALPHA+ }
+value      = @{ ASCII_DIGIT+ }
+"#]
+#[silent_spans]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn span() -> anyhow::Result<()> {
+    let entry = pairs::entry::try_parse("( a : 1 )")?;
+    let (_, body, _) = entry.get_matched();
+    assert_eq!(body.span.as_str(), "a : 1");
+    assert_eq!(body.span.start(), 2);
+    let (_, sep, _) = body.get_matched();
+    assert_eq!(sep.span(), sep.span);
+    assert_eq!(sep.as_str(), ":");
+    assert_eq!(body.to_string(), "a : 1");
+    Ok(())
+}
+
+#[test]
+fn not_in_pairs() -> anyhow::Result<()> {
+    let entry = pairs::entry::try_parse("(b=2)")?;
+    let inner: Vec<_> = entry.inner().map(|pair| pair.rule()).collect();
+    assert_eq!(inner, [Rule::key, Rule::value]);
+    Ok(())
+}
+
+#[test]
+fn constructor() -> anyhow::Result<()> {
+    let body = pairs::body::try_parse("x = 3")?;
+    let rebuilt = pairs::body::new(body.content.clone(), body.span);
+    assert_eq!(rebuilt, body);
+    Ok(())
+}
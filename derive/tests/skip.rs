@@ -18,7 +18,9 @@ WHITESPACE = { " " }
 COMMENT    = { "/*" ~ (!"*/" ~ ANY)* ~ "*/" }
 main       = { "x"* }
 program    = { SOI ~ main ~ EOI }
+assign     = { "a" ~ "=" ~ "b" }
 "#]
+#[allow(dead_code)]
 struct Parser;
 
 #[test]
@@ -50,3 +52,26 @@ fn pre_skip() -> Result<(), Error> {
     assert_eq!(main.iter_matched().len(), 2);
     Ok(())
 }
+
+fn comments(skipped: &[generics::Skipped<'_>]) -> Vec<String> {
+    skipped
+        .iter()
+        .flat_map(|skipped| skipped.iter())
+        .filter_map(|c| c._1())
+        .map(|comment| comment.to_string())
+        .collect()
+}
+
+#[test]
+fn skipped_comments() -> Result<(), Error> {
+    let assign = pairs::assign::try_parse("a /*1*/ = /*2*/ /*3*/ b")?;
+    let [first, second, third] = assign.skipped();
+    assert!(comments(first).is_empty());
+    assert_eq!(comments(second), ["/*1*/"]);
+    assert_eq!(comments(third), ["/*2*/", "/*3*/"]);
+
+    let main = pairs::main::try_parse("x/*1*/x x /*2*/x")?;
+    let skipped: Vec<_> = main.skipped().iter().map(|s| comments(s)).collect();
+    assert_eq!(skipped, [vec![], vec!["/*1*/"], vec![], vec!["/*2*/"]]);
+    Ok(())
+}
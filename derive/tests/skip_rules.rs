@@ -0,0 +1,64 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+SEPARATOR  = _{ " " | NEWLINE }
+call       =  { name ~ "(" ~ (template | list | name)? ~ ")" }
+template   =  { "`" ~ (text | "${" ~ name ~ "}")* ~ "`" }
+text       =  { (!("`" | "${") ~ ANY)+ }
+list       =  { "[" ~ (name ~ ("," ~ name)*)? ~ "]" }
+name       = @{ ASCII_ALPHA+ }
+"#]
+#[emit_rule_reference]
+#[skip_rules(template = "", list = "SEPARATOR")]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn outer_skips_spaces() -> Result<(), Error> {
+    let call = pairs::call::try_parse("f ( a ) ")?;
+    let (function, arg) = call.name();
+    assert_eq!(function.span.as_str(), "f");
+    assert_eq!(arg.unwrap().span.as_str(), "a");
+    Ok(())
+}
+
+#[test]
+fn embedded_skips_nothing() -> Result<(), Error> {
+    let call = pairs::call::try_parse("f( `  x ${b} y ` )")?;
+    let template = call.template().unwrap();
+    assert_eq!(template.span.as_str(), "`  x ${b} y `");
+    let texts = template.text().into_iter().flatten();
+    let texts = texts.map(|text| text.span.as_str());
+    assert_eq!(texts.collect::<Vec<_>>(), ["  x ", " y "]);
+    assert_eq!(template.name().into_iter().flatten().count(), 1);
+
+    // Not skipped inside interpolations.
+    assert!(pairs::call::try_parse("f(`${ b }`)").is_err());
+    assert!(pairs::template::try_parse("`x` ").is_err());
+    Ok(())
+}
+
+#[test]
+fn custom_skip_rule() -> Result<(), Error> {
+    let call = pairs::call::try_parse("f([\n  a,\n  b\n])")?;
+    let list = call.list().unwrap();
+    let (_, rest) = list.name().unwrap();
+    assert_eq!(rest.len(), 1);
+
+    // Outer rules still skip spaces only.
+    assert!(pairs::call::try_parse("f(\na)").is_err());
+    Ok(())
+}
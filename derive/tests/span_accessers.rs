@@ -0,0 +1,74 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Accessers to rules have `_span` companions with the same shape.
+
+use pest_typed::{ParsableTypedNode, Span};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar = "examples/csv.pest"]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Csv;
+
+mod shapes {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+a    =  { "a" }
+b    = _{ "b" }
+opt  =  { a? ~ ("x" | a) }
+both =  { a ~ b ~ a* }
+"#]
+    #[emit_rule_reference]
+    #[allow(dead_code)]
+    struct Shapes;
+}
+
+const INPUT: &str = "1,2,3\na,\"b\"\"\",c\n";
+
+fn as_str<'i>(spans: &[Span<'i>]) -> Vec<&'i str> {
+    spans.iter().map(Span::as_str).collect()
+}
+
+#[test]
+fn rows() {
+    let file = pairs::file::try_parse(INPUT).unwrap();
+    let (first, following) = file.row_span();
+    assert_eq!(first.as_str(), "1,2,3");
+    assert_eq!(as_str(&following), ["a,\"b\"\"\",c"]);
+
+    let (_, following) = file.row();
+    let (item, items) = following[0].item_span();
+    assert_eq!(item.as_str(), "a");
+    assert_eq!(as_str(&items), ["\"b\"\"\"", "c"]);
+    assert_eq!(as_str(&following[0].comma_span()), [",", ","]);
+}
+
+#[test]
+fn optional() {
+    let item = pairs::item::try_parse("\"x,y\"").unwrap();
+    assert_eq!(item.escaped_item_span().unwrap().as_str(), "\"x,y\"");
+    assert_eq!(item.non_escaped_item_span(), None);
+}
+
+#[test]
+fn shapes() {
+    let opt = shapes::pairs::opt::try_parse("aa").unwrap();
+    let (first, second) = opt.a_span();
+    assert_eq!(first.unwrap().as_str(), "a");
+    assert_eq!(second.unwrap().start(), 1);
+
+    let both = shapes::pairs::both::try_parse("abaa").unwrap();
+    let (first, rest) = both.a_span();
+    assert_eq!(first.start(), 0);
+    assert_eq!(rest.iter().map(Span::start).collect::<Vec<_>>(), [2, 3]);
+}
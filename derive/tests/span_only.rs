@@ -0,0 +1,47 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{iterators::Pair, ParsableTypedNode as _, Spanned};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+program    =  { block ~ block* }
+block      =  { "{" ~ stmt* ~ "}" }
+stmt       =  { ident ~ "=" ~ ident ~ ";" }
+ident      = @{ ASCII_ALPHA+ }
+"#]
+#[emit_rule_reference]
+#[span_only(block)]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn validated() {
+    pairs::block::try_parse("{ a = b; c=d ;}").unwrap();
+    pairs::block::try_parse("{}").unwrap();
+    pairs::block::try_parse("{ a = ; }").unwrap_err();
+    pairs::block::try_parse("{ a b = c; }").unwrap_err();
+    pairs::block::try_parse("{ a = b }").unwrap_err();
+}
+
+#[test]
+fn span() -> anyhow::Result<()> {
+    let program = pairs::program::try_parse("{ a = b; } {x=y;}")?;
+    let (first, rest) = program.block();
+    // Only a span is kept.
+    let pairs::block { span } = first;
+    assert_eq!(span.as_str(), "{ a = b; }");
+    assert_eq!(rest[0].as_str(), "{x=y;}");
+    // No inner pairs.
+    assert_eq!(first.inner().count(), 0);
+    assert_eq!(program.inner().count(), 2);
+    Ok(())
+}
@@ -0,0 +1,57 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Accessers to spans matched by stack operations.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+fence  = @{ "`"{3,} }
+line   = @{ (!NEWLINE ~ ANY)* ~ NEWLINE }
+block  = ${ PUSH(fence) ~ NEWLINE ~ (!PEEK ~ line)* ~ POP }
+quotes = ${ (PUSH("'" | "\"") ~ (!PEEK ~ ANY)* ~ POP ~ " "?)* }
+peek   =  { "peek" }
+nested =  { peek ~ PUSH("a") ~ PEEK }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn fenced_block() {
+    let block = pairs::block::try_parse("````\n```\nfoo\n````").unwrap();
+    let pushed = block.pushed();
+    let popped = block.pop();
+    assert_eq!(pushed.as_str(), "````");
+    assert_eq!(popped.as_str(), "````");
+    assert_eq!(pushed.as_str(), popped.as_str());
+    assert_eq!(block.fence().span.as_str(), "````");
+}
+
+#[test]
+fn repeated() {
+    let quotes = pairs::quotes::try_parse(r#""a" 'b'"#).unwrap();
+    let pushed = quotes.pushed();
+    let popped = quotes.pop();
+    assert_eq!(pushed.len(), 2);
+    assert_eq!(popped.len(), 2);
+    for (pushed, popped) in pushed.iter().zip(popped.iter()) {
+        assert_eq!(pushed.as_str(), popped.as_str());
+    }
+}
+
+#[test]
+fn collision() {
+    let nested = pairs::nested::try_parse("peekaa").unwrap();
+    assert_eq!(nested.peek().span.as_str(), "peek");
+    assert_eq!(nested.peek_().as_str(), "a");
+    assert_eq!(nested.pushed().as_str(), "a");
+}
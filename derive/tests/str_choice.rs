@@ -0,0 +1,121 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! With `lower_str_choices`, `"a" | "b" | ...` is lowered to `StrChoice` if no string is a prefix of a later one.
+//!
+//! It's off by default, as it changes the generated types.
+
+mod typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+keyword  = {
+    "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern"
+  | "false" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod"
+  | "move" | "mut" | "pub" | "ref" | "return" | "self" | "static" | "struct" | "super"
+  | "trait" | "true" | "typeof" | "type"
+}
+anchored = { SOI ~ keyword ~ EOI }
+shadowed = { "type" | "typeof" }
+"#]
+    #[lower_str_choices]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod default {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+keyword = { "if" | "else" }
+"#]
+    #[allow(dead_code)]
+    pub struct Parser;
+}
+
+mod untyped {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r#"
+keyword  = {
+    "as" | "break" | "const" | "continue" | "crate" | "else" | "enum" | "extern"
+  | "false" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match" | "mod"
+  | "move" | "mut" | "pub" | "ref" | "return" | "self" | "static" | "struct" | "super"
+  | "trait" | "true" | "typeof" | "type"
+}
+anchored = { SOI ~ keyword ~ EOI }
+shadowed = { "type" | "typeof" }
+"#]
+    pub struct Parser;
+}
+
+use pest::Parser as _;
+use pest_typed::ParsableTypedNode;
+
+const INPUTS: [&str; 12] = [
+    "if", "ifx", "i", "in", "impl", "type", "typeof", "typeo", "types", "", "x", "é",
+];
+
+fn typed<'i, N: ParsableTypedNode<'i, typed::Rule>>(input: &'i str) -> Option<usize> {
+    N::try_parse_partial(input).ok().map(|(pos, _)| pos.pos())
+}
+
+fn untyped(rule: untyped::Rule, input: &str) -> Option<usize> {
+    let mut pairs = untyped::Parser::parse(rule, input).ok()?;
+    Some(pairs.next()?.as_span().end())
+}
+
+#[test]
+fn same_as_choice() {
+    for input in INPUTS {
+        assert_eq!(
+            typed::<typed::pairs::keyword<'_>>(input),
+            untyped(untyped::Rule::keyword, input),
+            "{:?}",
+            input
+        );
+        assert_eq!(
+            typed::<typed::pairs::anchored<'_>>(input),
+            untyped(untyped::Rule::anchored, input),
+            "{:?}",
+            input
+        );
+        assert_eq!(
+            typed::<typed::pairs::shadowed<'_>>(input),
+            untyped(untyped::Rule::shadowed, input),
+            "{:?}",
+            input
+        );
+    }
+}
+
+#[test]
+fn node() -> anyhow::Result<()> {
+    let keyword = typed::pairs::keyword::try_parse("typeof")?;
+    assert_eq!(keyword.content.index, 29);
+    assert_eq!(keyword.content.get(), "typeof");
+    assert_eq!(keyword.to_string(), "typeof");
+    assert_eq!(typed::pairs::keyword::try_parse("as")?.content.index, 0);
+    assert!(typed::pairs::anchored::try_parse("ifx").is_err());
+    Ok(())
+}
+
+#[test]
+fn choice_by_default() -> anyhow::Result<()> {
+    // Code written against the `Choice*` type still compiles.
+    let keyword = default::pairs::keyword::try_parse("else")?;
+    let choice: &pest_typed::choices::Choice2<_, _> = &keyword.content;
+    assert!(choice._0().is_none());
+    assert!(choice._1().is_some());
+    assert_eq!(choice.variant_index(), 1);
+    Ok(())
+}
@@ -0,0 +1,60 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Accessers to tags wrapped like accessers to rules at the same positions.
+
+#![cfg(feature = "grammar-extras")]
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+expr = @{ ASCII_DIGIT+ }
+list = { "(" ~ (#item = expr)* ~ ")" }
+opt  = { "(" ~ (#item = expr)? ~ ")" }
+one  = { "(" ~ #item = expr ~ ")" }
+all  = { #item = expr }
+"#]
+#[emit_tagged_node_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn repeated() {
+    let list = pairs::list::try_parse("(1 22 333)").unwrap();
+    let items: Vec<&tags::list::item<'_, 1>> = list.item();
+    let items = items.iter().map(|i| i.span.as_str()).collect::<Vec<_>>();
+    assert_eq!(items, ["1", "22", "333"]);
+
+    let list = pairs::list::try_parse("()").unwrap();
+    assert!(list.item().is_empty());
+}
+
+#[test]
+fn optional() {
+    let opt = pairs::opt::try_parse("(1)").unwrap();
+    let item: Option<&tags::opt::item<'_, 1>> = opt.item();
+    assert_eq!(item.unwrap().span.as_str(), "1");
+
+    let opt = pairs::opt::try_parse("()").unwrap();
+    assert!(opt.item().is_none());
+}
+
+#[test]
+fn plain() {
+    let one = pairs::one::try_parse("(1)").unwrap();
+    let item: &tags::one::item<'_, 1> = one.item();
+    assert_eq!(item.span.as_str(), "1");
+
+    let all = pairs::all::try_parse("12").unwrap();
+    let item: &tags::all::item<'_, 1> = all.item();
+    assert_eq!(item.span.as_str(), "12");
+}
@@ -0,0 +1,118 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::{assert_parses, assert_parses_prefix, assert_rejects, assert_tree_snapshot};
+use pest_typed_derive::TypedParser;
+use std::panic::{catch_unwind, UnwindSafe};
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+list = { item ~ ("," ~ item)* }
+item = { ASCII_DIGIT+ }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+/// Message of the panic in `f`.
+fn panic_message(f: impl FnOnce() + UnwindSafe) -> String {
+    let payload = catch_unwind(f).unwrap_err();
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+#[test]
+fn passing() {
+    let list = assert_parses!(pairs::list<'_>, "1,23");
+    assert_eq!(list.item().1.len(), 1);
+    assert_rejects!(pairs::list<'_>, "1,");
+    let list = assert_parses_prefix!(pairs::list<'_>, "1,2,", 3);
+    assert_eq!(list.span.as_str(), "1,2");
+    assert_tree_snapshot!(
+        pairs::list<'_>,
+        "1,2",
+        r#"list
+    item "1"
+    item "2"
+"#
+    );
+}
+
+#[test]
+fn parses() {
+    let message = panic_message(|| {
+        assert_parses!(pairs::list<'_>, "1,x");
+    });
+    assert!(message.contains("failed to parse \"1,x\":"), "{message}");
+    assert!(message.contains("rules::list"), "{message}");
+    assert!(
+        message.ends_with(
+            " --> 1:3
+  |
+1 | 1,x
+  |   ^---
+  |
+  = 1,^---
+    Expected [item], by list."
+        ),
+        "{message}"
+    );
+}
+
+#[test]
+fn rejects() {
+    let message = panic_message(|| assert_rejects!(pairs::item<'_>, "12"));
+    assert!(message.contains("unexpectedly parsed \"12\":"), "{message}");
+    assert!(message.contains("item {"), "{message}");
+}
+
+#[test]
+fn parses_prefix() {
+    let message = panic_message(|| {
+        assert_parses_prefix!(pairs::list<'_>, "1,2,", 1);
+    });
+    assert!(
+        message.ends_with("consumed 3 bytes \"1,2\" of \"1,2,\", but 1 bytes \"1\" are expected."),
+        "{message}"
+    );
+    let message = panic_message(|| {
+        assert_parses_prefix!(pairs::list<'_>, ",", 0);
+    });
+    assert!(
+        message.contains("failed to parse a prefix of \",\":"),
+        "{message}"
+    );
+}
+
+#[test]
+fn tree_snapshot() {
+    let message = panic_message(|| {
+        assert_tree_snapshot!(
+            pairs::list<'_>,
+            "1,3",
+            r#"list
+    item "1"
+    item "2"
+"#
+        )
+    });
+    assert!(
+        message.ends_with(
+            r#"differs from the snapshot (-expected +actual):
+ list
+     item "1"
+-    item "2"
++    item "3"
+"#
+        ),
+        "{message}"
+    );
+}
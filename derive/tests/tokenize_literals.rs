@@ -0,0 +1,41 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Spans of string literals with `tokenize_literals`.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+pair       =  { key ~ ":" ~ value ~ ";"? }
+key        = @{ ASCII_ALPHA+ }
+value      =  { "true" | "false" }
+"#]
+#[tokenize_literals]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn colon() -> Result<(), Error> {
+    let pair = pairs::pair::try_parse("abc  : true")?;
+    let (_, colon, _, semicolon) = pair.get_matched();
+    assert_eq!(colon.span.start(), 5);
+    assert_eq!(colon.span.as_str(), ":");
+    assert!(semicolon.is_none());
+
+    let pair = pairs::pair::try_parse("a:false ;")?;
+    let (_, colon, value, semicolon) = pair.get_matched();
+    assert_eq!(colon.span.start(), 1);
+    assert_eq!(value.span.as_str(), "false");
+    assert_eq!(semicolon.as_ref().unwrap().span.start(), 8);
+    Ok(())
+}
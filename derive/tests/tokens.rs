@@ -0,0 +1,191 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Flat stream of rule starts and ends, like [`pest::iterators::Pairs::tokens`].
+
+mod typed {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+program    =  { block* }
+block      =  { "{" ~ stmt* ~ "}" }
+stmt       =  { expr ~ ";" }
+expr       =  { term ~ ("+" ~ term)* }
+term       =  { ident | number }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+"#]
+    #[allow(dead_code)]
+    pub struct Parser;
+
+    /// The same rules, with `block` and `term` emitting only spans.
+    pub mod span_only {
+        use pest_typed_derive::TypedParser;
+
+        #[derive(TypedParser)]
+        #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+program    =  { block* }
+block      =  { "{" ~ stmt* ~ "}" }
+stmt       =  { expr ~ ";" }
+expr       =  { term ~ ("+" ~ term)* }
+term       =  { ident | number }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+"#]
+        #[span_only(block, term)]
+        #[allow(dead_code)]
+        pub struct Parser;
+    }
+}
+
+mod untyped {
+    use pest_derive::Parser;
+
+    #[derive(Parser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+program    =  { block* }
+block      =  { "{" ~ stmt* ~ "}" }
+stmt       =  { expr ~ ";" }
+expr       =  { term ~ ("+" ~ term)* }
+term       =  { ident | number }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+"#]
+    pub struct Parser;
+}
+
+use pest::{Parser as _, Token};
+use pest_typed::{
+    iterators::{PairTree, TokenEvent},
+    ParsableTypedNode as _, RuleType,
+};
+
+const INPUT: &str = "{ a + 1; b; } {} { 2 + c + d; }";
+
+/// Rule names and positions of tokens, with `true` for starts.
+fn flatten<'i, R: RuleType>(
+    tokens: impl Iterator<Item = TokenEvent<'i, R>>,
+) -> Vec<(String, usize, bool)> {
+    tokens
+        .map(|token| match token {
+            TokenEvent::Start { rule, pos } => (format!("{:?}", rule), pos.pos(), true),
+            TokenEvent::End { rule, pos } => (format!("{:?}", rule), pos.pos(), false),
+        })
+        .collect()
+}
+
+/// Rule names, depths and start positions of pairs, reconstructed from tokens.
+fn depths(tokens: &[(String, usize, bool)]) -> Vec<(String, usize, usize)> {
+    let mut depth = 0;
+    let mut res = vec![];
+    for (rule, pos, start) in tokens {
+        if *start {
+            res.push((rule.clone(), depth, *pos));
+            depth += 1;
+        } else {
+            depth -= 1;
+        }
+    }
+    assert_eq!(depth, 0);
+    res
+}
+
+/// Rule names, depths and start positions of pairs in pre-order.
+fn traverse<'i: 'n, 'n, R: RuleType + 'n>(
+    pair: &'n impl PairTree<'i, 'n, R>,
+) -> Vec<(String, usize, usize)> {
+    let mut res = vec![];
+    pair.iterate_pre_order(|pair, depth, _| {
+        res.push((format!("{:?}", pair.rule()), depth, pair.span().start()));
+        Ok::<(), ()>(())
+    })
+    .unwrap();
+    res
+}
+
+fn check<'i: 'n, 'n, R: RuleType + 'n>(pair: &'n impl PairTree<'i, 'n, R>) {
+    let tokens = flatten(pair.tokens());
+    assert_eq!(depths(&tokens), traverse(pair));
+}
+
+#[test]
+fn nesting() {
+    let program = typed::pairs::program::try_parse(INPUT).unwrap();
+    check(&program);
+    // `term` and `ident` start and end at the same positions.
+    let tokens = flatten(program.tokens());
+    assert_eq!(
+        tokens[..6],
+        [
+            ("program".to_owned(), 0, true),
+            ("block".to_owned(), 0, true),
+            ("stmt".to_owned(), 2, true),
+            ("expr".to_owned(), 2, true),
+            ("term".to_owned(), 2, true),
+            ("ident".to_owned(), 2, true),
+        ]
+    );
+    assert_eq!(
+        tokens[6..8],
+        [
+            ("ident".to_owned(), 3, false),
+            ("term".to_owned(), 3, false)
+        ]
+    );
+    assert_eq!(
+        tokens.last().unwrap(),
+        &("program".to_owned(), INPUT.len(), false)
+    );
+}
+
+#[test]
+fn span_only() {
+    let program = typed::span_only::pairs::program::try_parse(INPUT).unwrap();
+    check(&program);
+    let tokens = flatten(program.tokens());
+    assert_eq!(tokens.len(), 2 + 3 * 2);
+    assert_eq!(
+        tokens[1..3],
+        [
+            ("block".to_owned(), 0, true),
+            ("block".to_owned(), 13, false)
+        ]
+    );
+
+    let stmt = typed::span_only::pairs::stmt::try_parse("a + 1;").unwrap();
+    check(&stmt);
+    assert_eq!(flatten(stmt.tokens()).len(), 2 * 4);
+}
+
+#[test]
+fn lazy() {
+    let program = typed::pairs::program::try_parse(INPUT).unwrap();
+    let mut tokens = program.tokens();
+    assert!(matches!(tokens.next(), Some(TokenEvent::Start { .. })));
+    assert_eq!(tokens.by_ref().count(), flatten(program.tokens()).len() - 1);
+    assert_eq!(tokens.next(), None);
+}
+
+#[test]
+fn parity() {
+    let typed = typed::pairs::program::try_parse(INPUT).unwrap();
+    let untyped = untyped::Parser::parse(untyped::Rule::program, INPUT).unwrap();
+    let untyped: Vec<_> = untyped
+        .tokens()
+        .map(|token| match token {
+            Token::Start { rule, pos } => (format!("{:?}", rule), pos.pos(), true),
+            Token::End { rule, pos } => (format!("{:?}", rule), pos.pos(), false),
+        })
+        .collect();
+    assert_eq!(flatten(typed.tokens()), untyped);
+}
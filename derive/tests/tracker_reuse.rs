@@ -0,0 +1,70 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Share a tracker among alternative rules to get a combined error.
+
+use pest_typed::{
+    error::{Error, InputLocation},
+    tracker::Tracker,
+    ParsableTypedNode as _, Position, Stack,
+};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+ident      = @{ ASCII_ALPHA+ }
+number     = @{ ASCII_DIGIT+ }
+string     = @{ "\"" ~ (!"\"" ~ ANY)* ~ "\"" }
+kw_mod     =  { "mod" }
+kw_let     =  { "let" }
+eq         =  { "=" }
+semi       =  { ";" }
+module     =  { SOI ~ kw_mod ~ ident ~ EOI }
+stmt       =  { SOI ~ kw_let ~ ident ~ eq ~ number ~ semi ~ EOI }
+decl       =  { SOI ~ kw_let ~ ident ~ eq ~ string ~ semi ~ EOI }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn combined() {
+    let input = "let x = ;";
+    let mut tracker = Tracker::new(Position::from_start(input));
+    assert!(pairs::module::try_parse_with_state(input, &mut Stack::new(), &mut tracker).is_none());
+    assert!(pairs::stmt::try_parse_with_state(input, &mut Stack::new(), &mut tracker).is_none());
+    assert!(pairs::decl::try_parse_with_state(input, &mut Stack::new(), &mut tracker).is_none());
+    let combined = tracker.collect();
+
+    let errors = [
+        pairs::module::try_parse(input).unwrap_err(),
+        pairs::stmt::try_parse(input).unwrap_err(),
+        pairs::decl::try_parse(input).unwrap_err(),
+    ];
+    let pos = |error: &Error<Rule>| match error.location {
+        InputLocation::Pos(pos) => pos,
+        InputLocation::Span((start, _)) => start,
+    };
+    let best = errors.iter().max_by_key(|error| pos(error)).unwrap();
+    assert_eq!(pos(best), 8);
+    assert_eq!(combined.location, best.location);
+    assert_eq!(combined.line_col, best.line_col);
+    let message = combined.variant.message();
+    assert!(
+        message.contains("Expected [number], by stmt."),
+        "{}",
+        message
+    );
+    assert!(
+        message.contains("Expected [string], by decl."),
+        "{}",
+        message
+    );
+    assert!(!message.contains("kw_mod"), "{}", message);
+}
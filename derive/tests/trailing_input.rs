@@ -0,0 +1,72 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Input left after a full parse is reported after trailing whitespaces and comments.
+
+use pest::error::InputLocation;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r##"
+WHITESPACE = _{ " " | "\n" }
+COMMENT    = _{ "#" ~ (!"\n" ~ ANY)* }
+ident      = @{ ASCII_ALPHA+ }
+list       =  { ident ~ ("," ~ ident)* }
+atomic     = @{ ident ~ ("," ~ ident)* }
+"##]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn trivia() {
+    pairs::list::try_parse("a, b  \n ").unwrap();
+    pairs::list::try_parse("a, b # c").unwrap();
+    pairs::list::try_parse("a, b # c\n # d\n").unwrap();
+}
+
+#[test]
+fn junk() {
+    let err = pairs::list::try_parse("a, b # c\n  xyz").unwrap_err();
+    assert_eq!(err.location, InputLocation::Pos(11));
+    assert_eq!(
+        err.to_string(),
+        " --> 2:3
+  |
+2 |   xyz
+  |   ^---
+  |
+  =   ^---
+    Expected end of input, found `xyz`."
+    );
+
+    let err = pairs::list::try_parse("a b").unwrap_err();
+    assert_eq!(err.location, InputLocation::Pos(2));
+    assert!(err
+        .to_string()
+        .ends_with("Expected end of input, found `b`."));
+}
+
+#[test]
+fn elided() {
+    let err = pairs::list::try_parse("a ?????????????????????").unwrap_err();
+    assert!(err
+        .to_string()
+        .ends_with("Expected end of input, found `????????????????…`."));
+}
+
+#[test]
+fn atomic() {
+    // Atomic rules don't skip anything, so the whitespaces are reported.
+    let err = pairs::atomic::try_parse("a,b\tx").unwrap_err();
+    assert_eq!(err.location, InputLocation::Pos(3));
+    assert!(err
+        .to_string()
+        .ends_with("Expected end of input, found `\\tx`."));
+}
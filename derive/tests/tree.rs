@@ -7,7 +7,7 @@
 // option. All files in the project carrying such notice may not be copied,
 // modified, or distributed except according to those terms.
 
-use pest_typed::{iterators::PairTree, ParsableTypedNode};
+use pest_typed::{assert_rejects, assert_tree_snapshot};
 use pest_typed_derive::TypedParser;
 
 #[derive(TypedParser)]
@@ -17,16 +17,14 @@ pre  = { "-" }
 val  = { ('0'..'9')+ }
 mid  = { "+" | "-" | "*" | "/" }
 "#]
+#[allow(dead_code)]
 struct Parser;
 
 #[test]
 fn tree() {
-    /// Note that [`indoc!`] is used.
     macro_rules! test {
         ($input:literal, $tree:literal) => {
-            let tree = pairs::main::try_parse($input).unwrap();
-            let string = tree.format_as_tree().unwrap();
-            assert_eq!(string, $tree);
+            assert_tree_snapshot!(pairs::main<'_>, $input, $tree);
         };
     }
 
@@ -73,3 +71,10 @@ fn tree() {
 "#
     );
 }
+
+#[test]
+fn rejected() {
+    assert_rejects!(pairs::main<'_>, "");
+    assert_rejects!(pairs::main<'_>, "1+");
+    assert_rejects!(pairs::main<'_>, "--1");
+}
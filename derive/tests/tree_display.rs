@@ -0,0 +1,71 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Display trees of rule structs without generic types.
+
+use anyhow::Error;
+use pest_typed::{iterators::PairTree as _, ParsableTypedNode as _};
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\n" }
+json       =  { SOI ~ value ~ EOI }
+value      = _{ object | array | string | number | boolean | null }
+object     =  { "{" ~ (pair ~ ("," ~ pair)*)? ~ "}" }
+pair       =  { string ~ ":" ~ value }
+array      =  { "[" ~ (value ~ ("," ~ value)*)? ~ "]" }
+string     = @{ "\"" ~ (!"\"" ~ ANY)* ~ "\"" }
+number     = @{ "-"? ~ ASCII_DIGIT+ ~ ("." ~ ASCII_DIGIT+)? }
+boolean    =  { "true" | "false" }
+null       =  { "null" }
+"#]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn json() -> Result<(), Error> {
+    let json = pairs::json::try_parse(r#"{"name": "pest-typed", "tags": [1, -2.5, true, null]}"#)?;
+    assert_eq!(
+        json.display_tree(16).to_string(),
+        r#"json 0..53 "{\"name\": \"pest-t…"
+    object 0..53 "{\"name\": \"pest-t…"
+        pair 1..21 "\"name\": \"pest-ty…"
+            string 1..7 "\"name\""
+            string 9..21 "\"pest-typed\""
+        pair 23..52 "\"tags\": [1, -2.5…"
+            string 23..29 "\"tags\""
+            array 31..52 "[1, -2.5, true, …"
+                number 32..33 "1"
+                number 35..39 "-2.5"
+                boolean 41..45 "true"
+                null 47..51 "null"
+    EOI 53..53 ""
+"#
+    );
+    Ok(())
+}
+
+#[test]
+fn truncated() -> Result<(), Error> {
+    let array = pairs::array::try_parse("[\"αβγ\"]")?;
+    assert_eq!(
+        array.display_tree(3).to_string(),
+        r#"array 0..10 "[\"α…"
+    string 1..9 "\"αβ…"
+"#
+    );
+    assert_eq!(
+        array.display_tree(0).to_string(),
+        r#"array 0..10 "…"
+    string 1..9 "…"
+"#
+    );
+    Ok(())
+}
@@ -0,0 +1,22 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Diagnostics reported by the derive macro, checked against `tests/ui/*.stderr`.
+//!
+//! Run with `TRYBUILD=overwrite` to update them.
+//! Skipped with `grammar-extras`, as trybuild doesn't enable it for dev-dependencies such as `pest_derive`.
+
+#![cfg(not(feature = "grammar-extras"))]
+
+#[test]
+fn ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+    t.pass("tests/ui/pass/*.rs");
+}
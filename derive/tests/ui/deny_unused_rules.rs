@@ -0,0 +1,11 @@
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+a = { "a" }
+b = _{ "b" }
+"#]
+#[deny_unused_rules]
+struct Parser;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+a = { "a" ~ }
+"#]
+struct Parser;
+
+fn main() {}
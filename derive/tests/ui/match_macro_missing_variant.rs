@@ -0,0 +1,18 @@
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+value  = { number | null }
+number = @{ ASCII_DIGIT+ }
+null   = { "null" }
+"#]
+#[emit_match_macros]
+struct Parser;
+
+fn main() {
+    let value = pairs::value::try_parse("null").unwrap();
+    let _ = match_value!(value, {
+        number(_) => "number",
+    });
+}
@@ -0,0 +1,13 @@
+#![deny(deprecated)]
+
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+a = { "a" }
+b = _{ "b" }
+"#]
+#[no_warnings]
+struct Parser;
+
+fn main() {}
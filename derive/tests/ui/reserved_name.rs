@@ -0,0 +1,9 @@
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+crate = { "crate" }
+"#]
+struct Parser;
+
+fn main() {}
@@ -0,0 +1,12 @@
+#![deny(deprecated)]
+
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+a = { "a" }
+b = _{ "b" }
+"#]
+struct Parser;
+
+fn main() {}
@@ -0,0 +1,63 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Texts matched by failed negative predicates are reported.
+
+use pest::error::InputLocation;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " }
+reserved   = @{ ("while" | "if" | "else") ~ !ASCII_ALPHA }
+ident      = @{ !reserved ~ ASCII_ALPHA+ }
+assign     =  { ident ~ "=" ~ ident ~ ";" }
+literal    = @{ !"0" ~ ASCII_DIGIT+ }
+"#]
+#[error_label(reserved = "reserved word")]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn reserved_word() {
+    let error = pairs::assign::try_parse("x = while;").unwrap_err();
+    assert_eq!(error.location, InputLocation::Span((4, 9)));
+    assert_eq!(
+        error.to_string(),
+        " --> 1:5
+  |
+1 | x = while;
+  |     ^---^
+  |
+  = In `assign` → `ident`:
+    x = ^---
+    Unexpected [reserved word] (found `while`), by assign."
+    );
+
+    pairs::assign::try_parse("x = whiles;").unwrap();
+}
+
+#[test]
+fn no_rule() {
+    let error = pairs::literal::try_parse("012").unwrap_err();
+    assert_eq!(error.location, InputLocation::Span((0, 1)));
+    assert!(
+        error
+            .to_string()
+            .ends_with("Expected [literal].\n    Found unexpected `0`."),
+        "{error}"
+    );
+}
+
+#[test]
+fn no_match() {
+    let error = pairs::ident::try_parse("1").unwrap_err();
+    assert_eq!(error.location, InputLocation::Pos(0));
+}
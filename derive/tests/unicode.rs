@@ -0,0 +1,45 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+letters = ${ (XID_START | HAN)* }
+"#]
+#[emit_rule_reference]
+#[allow(dead_code)]
+struct Parser;
+
+#[test]
+fn in_sets() -> anyhow::Result<()> {
+    let letters = pairs::letters::try_parse("ab世a界b")?;
+    let starts: HashSet<unicode::XID_START> = letters
+        .content
+        .iter_matched()
+        .filter_map(|c| c._0().cloned())
+        .collect();
+    assert_eq!(starts.len(), 4);
+    assert!(starts.contains(&unicode::XID_START::from('世')));
+
+    // Han characters are also `XID_START`, so the second branch never matches.
+    let hans: BTreeSet<char> = letters
+        .content
+        .iter_matched()
+        .filter_map(|c| c._1().map(|han| han.content))
+        .collect();
+    assert!(hans.is_empty());
+
+    let first = letters.content.as_slice()[0].clone();
+    assert_eq!(first, letters.content.as_slice()[0]);
+    assert_ne!(first, letters.content.as_slice()[1]);
+    Ok(())
+}
@@ -0,0 +1,93 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+
+mod crate_visible {
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+list       =  { item ~ ("," ~ item)* }
+item       =  { ASCII_DIGIT+ }
+"#]
+    #[emit_rule_reference]
+    #[typed_vis = "pub(crate)"]
+    #[allow(dead_code)]
+    pub(crate) struct Parser;
+}
+
+mod private {
+    use pest_typed::ParsableTypedNode as _;
+    use pest_typed_derive::TypedParser;
+
+    #[derive(TypedParser)]
+    #[grammar_inline = r#"
+item = { ASCII_DIGIT+ }
+"#]
+    #[typed_vis = ""]
+    #[allow(dead_code)]
+    struct Parser;
+
+    pub fn parse(input: &str) -> Option<&str> {
+        let item = pairs::item::try_parse(input).ok()?;
+        Some(item.span.as_str())
+    }
+}
+
+/// Simulates a library that exports its parser and typed API.
+mod library {
+    pub use parser::{pairs, Parser, Rule};
+
+    mod parser {
+        use pest_typed_derive::TypedParser;
+
+        #[derive(TypedParser)]
+        #[grammar_inline = r#"
+WHITESPACE = _{ " " }
+list       =  { item ~ ("," ~ item)* }
+item       =  { ASCII_DIGIT+ }
+"#]
+        #[emit_rule_reference]
+        #[typed_vis = "pub"]
+        #[allow(dead_code)]
+        pub struct Parser;
+    }
+}
+
+#[test]
+fn crate_visible() -> Result<(), Error> {
+    let list = crate_visible::pairs::list::try_parse("1, 2")?;
+    let (first, rest) = list.item();
+    assert_eq!(first.span.as_str(), "1");
+    assert_eq!(rest.len(), 1);
+    Ok(())
+}
+
+#[test]
+fn private() {
+    assert_eq!(private::parse("12"), Some("12"));
+}
+
+#[test]
+fn library() -> Result<(), Error> {
+    use library::{pairs, Parser, Rule};
+    use pest_typed::TypedParser as _;
+
+    let list = Parser::try_parse::<pairs::list<'_>>("1, 2, 3")?;
+    let (_, rest) = list.item();
+    assert_eq!(rest.len(), 2);
+    assert_eq!(
+        <pairs::item<'_> as pest_typed::RuleWrapper<Rule>>::RULE,
+        Rule::item
+    );
+    Ok(())
+}
@@ -0,0 +1,86 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+use anyhow::Error;
+use pest_typed::ParsableTypedNode as _;
+use pest_typed_derive::TypedParser;
+
+#[derive(TypedParser)]
+#[grammar_inline = r#"
+WHITESPACE = _{ " " | "\t" | NEWLINE }
+program    =  { SOI ~ statement* ~ EOI }
+statement  =  { ident ~ "=" ~ expr ~ ";" }
+expr       =  { term ~ (("+" | "-") ~ term)* }
+term       = _{ ident | number | "(" ~ expr ~ ")" | call }
+call       = ${ "@" ~ ident ~ args }
+args       = !{ "(" ~ (expr ~ ("," ~ expr)*)? ~ ")" }
+ident      = @{ XID_START ~ XID_CONTINUE* }
+number     = @{ ASCII_DIGIT+ }
+"#]
+#[emit_visitor]
+#[allow(dead_code)]
+struct Parser;
+
+use visitor::Visit;
+
+#[derive(Default)]
+struct Identifiers<'i> {
+    names: Vec<&'i str>,
+    statements: usize,
+}
+
+impl<'i> Visit<'i> for Identifiers<'i> {
+    fn visit_ident<const INHERITED: usize>(&mut self, node: &pairs::ident<'i, INHERITED>) {
+        self.names.push(node.span.as_str());
+    }
+    fn visit_statement<const INHERITED: usize>(&mut self, node: &pairs::statement<'i, INHERITED>) {
+        self.statements += 1;
+        visitor::walk_statement(self, node);
+    }
+}
+
+const INPUT: &str = "x = 1 + y;\ny = (x - 2) + @f(x, αβ + 3);";
+
+#[test]
+fn count_identifiers() -> Result<(), Error> {
+    let program = pairs::program::try_parse(INPUT)?;
+    let mut identifiers = Identifiers::default();
+    identifiers.visit_program(&program);
+    assert_eq!(identifiers.statements, 2);
+    assert_eq!(identifiers.names, ["x", "y", "y", "x", "f", "x", "αβ"]);
+    Ok(())
+}
+
+#[test]
+fn skip_subtree() -> Result<(), Error> {
+    /// Identifiers outside calls.
+    #[derive(Default)]
+    struct OutsideCalls(usize);
+    impl<'i> Visit<'i> for OutsideCalls {
+        fn visit_ident<const INHERITED: usize>(&mut self, _node: &pairs::ident<'i, INHERITED>) {
+            self.0 += 1;
+        }
+        fn visit_call<const INHERITED: usize>(&mut self, _node: &pairs::call<'i, INHERITED>) {}
+    }
+
+    let program = pairs::program::try_parse(INPUT)?;
+    let mut outside = OutsideCalls::default();
+    outside.visit_program(&program);
+    assert_eq!(outside.0, 4);
+    Ok(())
+}
+
+#[test]
+fn walk_from_inner_node() -> Result<(), Error> {
+    let expr = pairs::expr::try_parse("a + @g(b)")?;
+    let mut identifiers = Identifiers::default();
+    visitor::walk_expr(&mut identifiers, &expr);
+    assert_eq!(identifiers.names, ["a", "g", "b"]);
+    Ok(())
+}
@@ -7,6 +7,95 @@
 // option. All files in the project carrying such notice may not be copied,
 // modified, or distributed except according to those terms.
 
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+/// Visibility of generated modules.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Visibility {
+    /// `pub`.
+    Public,
+    /// `pub(crate)`.
+    Crate,
+    /// `pub(super)`.
+    Super,
+    /// Private.
+    Private,
+}
+impl ToTokens for Visibility {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::Public => quote! {pub},
+            Self::Crate => quote! {pub(crate)},
+            Self::Super => quote! {pub(super)},
+            Self::Private => quote! {},
+        })
+    }
+}
+
+/// Integer type that the `Rule` enum is represented as.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) enum Repr {
+    U8,
+    U16,
+    U32,
+    U64,
+    Usize,
+    I8,
+    I16,
+    I32,
+    I64,
+    Isize,
+}
+impl Repr {
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        Some(match name {
+            "u8" => Self::U8,
+            "u16" => Self::U16,
+            "u32" => Self::U32,
+            "u64" => Self::U64,
+            "usize" => Self::Usize,
+            "i8" => Self::I8,
+            "i16" => Self::I16,
+            "i32" => Self::I32,
+            "i64" => Self::I64,
+            "isize" => Self::Isize,
+            _ => return None,
+        })
+    }
+    /// Maximum number of variants with discriminants counted from 0.
+    pub(crate) fn capacity(&self) -> u128 {
+        match self {
+            Self::U8 => u8::MAX as u128 + 1,
+            Self::U16 => u16::MAX as u128 + 1,
+            Self::U32 => u32::MAX as u128 + 1,
+            Self::U64 => u64::MAX as u128 + 1,
+            Self::Usize => usize::MAX as u128 + 1,
+            Self::I8 => i8::MAX as u128 + 1,
+            Self::I16 => i16::MAX as u128 + 1,
+            Self::I32 => i32::MAX as u128 + 1,
+            Self::I64 => i64::MAX as u128 + 1,
+            Self::Isize => isize::MAX as u128 + 1,
+        }
+    }
+}
+impl ToTokens for Repr {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        tokens.extend(match self {
+            Self::U8 => quote! {u8},
+            Self::U16 => quote! {u16},
+            Self::U32 => quote! {u32},
+            Self::U64 => quote! {u64},
+            Self::Usize => quote! {usize},
+            Self::I8 => quote! {i8},
+            Self::I16 => quote! {i16},
+            Self::I32 => quote! {i32},
+            Self::I64 => quote! {i64},
+            Self::Isize => quote! {isize},
+        })
+    }
+}
+
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) struct Config {
     pub emit_rule_reference: bool,
@@ -16,6 +105,22 @@ pub(crate) struct Config {
     pub simulate_pair_api: bool,
     pub box_only_if_needed: bool,
     pub no_warnings: bool,
+    pub inline_grammar_docs: bool,
+    pub typed_vis: Visibility,
+    pub emit_visitor: bool,
+    pub atomic_content: bool,
+    pub recursion_limit: Option<usize>,
+    pub deny_unused_rules: bool,
+    pub lower_str_choices: bool,
+    pub rule_non_exhaustive: bool,
+    pub rule_repr: Option<Repr>,
+    pub silent_spans: bool,
+    pub tokenize_literals: bool,
+    pub emit_match_macros: bool,
+    pub lower_rep_until: bool,
+    pub emit_generator: bool,
+    pub emit_pest_check: bool,
+    pub unicode_case_insensitive: bool,
 }
 impl Default for Config {
     fn default() -> Self {
@@ -25,8 +130,24 @@ impl Default for Config {
             do_not_emit_span: false,
             truncate_accesser_at_node_tag: true,
             simulate_pair_api: false,
-            box_only_if_needed: false,
+            box_only_if_needed: true,
             no_warnings: false,
+            inline_grammar_docs: false,
+            typed_vis: Visibility::Public,
+            emit_visitor: false,
+            atomic_content: false,
+            recursion_limit: None,
+            deny_unused_rules: false,
+            lower_str_choices: false,
+            rule_non_exhaustive: false,
+            rule_repr: None,
+            silent_spans: false,
+            tokenize_literals: false,
+            emit_match_macros: false,
+            lower_rep_until: false,
+            emit_generator: false,
+            emit_pest_check: false,
+            unicode_case_insensitive: false,
         }
     }
 }
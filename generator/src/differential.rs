@@ -0,0 +1,92 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Generate `check_against_pest`, which compares typed rules with a parser from **pest_generator**.
+
+use crate::config::Config;
+use crate::graph::{collect_dependencies, pest_typed};
+use pest_meta::{ast::RuleType, optimizer::OptimizedRule};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Ident};
+
+/// Generate `check_against_pest`, with an untyped parser from the same grammar attributes.
+pub(crate) fn generate_pest_check(
+    attrs: &[Attribute],
+    rules: &[OptimizedRule],
+    config: Config,
+    mod_name: &Ident,
+) -> TokenStream {
+    let pest_typed = pest_typed();
+    let vis = config.typed_vis;
+    let grammar = attrs.iter().filter(|attr| {
+        let path = attr.meta.path();
+        path.is_ident("grammar") || path.is_ident("grammar_inline")
+    });
+    // Grammar files are already included by the typed parser.
+    let untyped = pest_generator::derive_parser(
+        quote! {
+            #(#grammar)*
+            pub struct Parser;
+        },
+        false,
+    );
+    let compare = |rule: &Ident, silent: bool| {
+        quote! {
+            {
+                let typed = <#mod_name::#rule<'_> as #pest_typed::ParsableTypedNode<'_, Rule>>::try_parse_partial(input).ok();
+                let typed = typed.as_ref().map(|(pos, tree)| {
+                    let tokens = #pest_typed::iterators::Pairs::iter_pairs(tree)
+                        .map(#pest_typed::iterators::Pair::as_token_tree)
+                        .collect();
+                    (pos.pos(), tokens)
+                });
+                let pest = <untyped::Parser as ::pest::Parser<untyped::Rule>>::parse(untyped::Rule::#rule, input).ok();
+                #pest_typed::testing::compare_with_pest(typed, pest, #silent)
+            }
+        }
+    };
+    // Pest only generates `EOI` if it's used.
+    let uses_eoi = collect_dependencies(rules)
+        .iter()
+        .any(|(_, used)| used.contains(&"EOI"));
+    let eoi = match uses_eoi {
+        true => compare(&format_ident!("EOI"), false),
+        false => quote! { ::core::result::Result::Ok(()) },
+    };
+    let arms = rules.iter().map(|rule| {
+        let name = format_ident!("r#{}", rule.name);
+        let compare = compare(&name, rule.ty == RuleType::Silent);
+        quote! {
+            Rule::#name => #compare,
+        }
+    });
+    quote! {
+        /// Parse a prefix of the input with a rule, both with the typed parser
+        /// and with a `pest::Parser` generated by **pest_generator** from the same grammar,
+        /// and compare whether they match, how many bytes they consume and their non-silent pairs.
+        ///
+        /// `Rule::EOI` is only compared if the grammar uses it.
+        #[allow(dead_code)]
+        #vis fn check_against_pest(
+            rule: Rule,
+            input: &::core::primitive::str,
+        ) -> ::core::result::Result<(), #pest_typed::testing::Mismatch> {
+            #[allow(dead_code, missing_docs, clippy::all)]
+            mod untyped {
+                pub struct Parser;
+                #untyped
+            }
+            match rule {
+                Rule::EOI => #eoi,
+                #(#arms)*
+            }
+        }
+    }
+}
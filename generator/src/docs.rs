@@ -14,6 +14,10 @@ pub(crate) struct DocComment {
     /// HashMap for store all doc_comments for rules.
     /// key is rule name, value is doc_comment.
     pub line_docs: HashMap<String, String>,
+
+    /// Source text of each rule in the grammar, without doc comments.
+    /// key is rule name, value is the source text.
+    pub rule_sources: HashMap<String, String>,
 }
 
 /// Consume pairs to matches `Rule::grammar_doc`, `Rule::line_doc` into `DocComment`
@@ -37,12 +41,14 @@ pub(crate) struct DocComment {
 /// ```js
 /// grammar_doc = "This is a grammar doc"
 /// line_docs = { "foo": "line doc 1\nline doc 2", "bar": "line doc 3" }
+/// rule_sources = { "foo": "foo = {}", "bar": "bar = {}" }
 /// ```
 pub(crate) fn consume(pairs: Pairs<'_, Rule>) -> DocComment {
     let mut grammar_doc = String::new();
 
     let mut line_docs: HashMap<String, String> = HashMap::new();
     let mut line_doc = String::new();
+    let mut rule_sources: HashMap<String, String> = HashMap::new();
 
     for pair in pairs {
         match pair.as_rule() {
@@ -53,6 +59,7 @@ pub(crate) fn consume(pairs: Pairs<'_, Rule>) -> DocComment {
                 grammar_doc.push('\n');
             }
             Rule::grammar_rule => {
+                let source = pair.as_str();
                 if let Some(inner) = pair.into_inner().next() {
                     // grammar_rule > line_doc | identifier
                     match inner.as_rule() {
@@ -63,9 +70,9 @@ pub(crate) fn consume(pairs: Pairs<'_, Rule>) -> DocComment {
                             }
                         }
                         Rule::identifier => {
+                            let rule_name = inner.as_str().to_owned();
+                            rule_sources.insert(rule_name.clone(), source.trim_end().to_owned());
                             if !line_doc.is_empty() {
-                                let rule_name = inner.as_str().to_owned();
-
                                 // Remove last \n
                                 line_doc.pop();
                                 line_docs.insert(rule_name, line_doc.clone());
@@ -88,6 +95,7 @@ pub(crate) fn consume(pairs: Pairs<'_, Rule>) -> DocComment {
     DocComment {
         grammar_doc,
         line_docs,
+        rule_sources,
     }
 }
 
@@ -119,6 +127,13 @@ mod tests {
         );
         assert_eq!(expected, doc_comment.line_docs);
 
+        assert_eq!(doc_comment.rule_sources["foo"], r#"foo = { "foo" }"#);
+        assert_eq!(
+            doc_comment.rule_sources["bar"],
+            r#"bar = { "bar" | "foobar" }"#
+        );
+        assert_eq!(doc_comment.rule_sources.len(), 4);
+
         assert_eq!(
             "A parser for JSON file.\nAnd this is a example for JSON parser.\n\n    indent-4-space\n",
             doc_comment.grammar_doc
@@ -10,34 +10,49 @@
 //! Copied from **pest/generator/src/generator.rs** (commit ac0aed3eecf435fd93ba575a39704aaa88a375b7)
 //! and modified.
 
+use std::collections::BTreeMap;
 use std::path::PathBuf;
 
-use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use proc_macro2::{Literal, TokenStream};
+use quote::{format_ident, quote, ToTokens};
 use syn::{self, Ident};
 
+use pest_meta::ast::RuleType;
 use pest_meta::optimizer::*;
 
 use super::docs::DocComment;
+use crate::config::Config;
 
 /// Generate Rust `include_str!` for grammar files, then Cargo will watch changes in grammars.
+///
+/// Paths inside `CARGO_MANIFEST_DIR` are emitted relative to it,
+/// so that generated codes don't depend on where the package is built.
 pub(crate) fn generate_include(name: &Ident, paths: Vec<PathBuf>) -> TokenStream {
     let const_name = format_ident!("_PEST_GRAMMAR_{}", name);
     // Need to make this relative to the current directory since the path to the file
     // is derived from the CARGO_MANIFEST_DIR environment variable
     let current_dir = std::env::current_dir().expect("Unable to get current directory");
+    let root = std::env::var_os("CARGO_MANIFEST_DIR").map(PathBuf::from);
 
     let include_tokens = paths.iter().map(|path| {
-        let path = path.to_str().expect("non-Unicode path");
-
-        let relative_path = current_dir
-            .join(path)
-            .to_str()
-            .expect("path contains invalid unicode")
-            .to_string();
-
-        quote! {
-            include_str!(#relative_path)
+        let path = current_dir.join(path);
+        match root.as_ref().and_then(|root| path.strip_prefix(root).ok()) {
+            Some(relative) => {
+                let relative = relative
+                    .components()
+                    .map(|component| component.as_os_str().to_str().expect("non-Unicode path"))
+                    .collect::<Vec<_>>()
+                    .join("/");
+                quote! {
+                    ::core::include_str!(::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", #relative))
+                }
+            }
+            None => {
+                let path = path.to_str().expect("path contains invalid unicode");
+                quote! {
+                    ::core::include_str!(#path)
+                }
+            }
         }
     });
 
@@ -49,39 +64,182 @@ pub(crate) fn generate_include(name: &Ident, paths: Vec<PathBuf>) -> TokenStream
         ];
     }
 }
-pub(crate) fn generate_enum(rules: &[OptimizedRule], doc_comment: &DocComment) -> TokenStream {
-    let rules = rules.iter().map(|rule| {
+pub(crate) fn generate_enum(
+    rules: &[OptimizedRule],
+    doc_comment: &DocComment,
+    error_labels: &BTreeMap<String, String>,
+    config: Config,
+) -> TokenStream {
+    let names = generate_names(rules, doc_comment, error_labels);
+    // Discriminants are counted in the order of the grammar,
+    // so that appending rules doesn't change those of existing ones.
+    let discriminant = |index: usize| match config.rule_repr {
+        Some(_) => {
+            let index = Literal::usize_unsuffixed(index);
+            quote! { = #index }
+        }
+        None => quote! {},
+    };
+    if let Some(repr) = config.rule_repr {
+        if rules.len() as u128 + 1 > repr.capacity() {
+            panic!(
+                "{} rules can't be represented as `{}`.",
+                rules.len() + 1,
+                repr.to_token_stream()
+            );
+        }
+    }
+    let eoi = discriminant(0);
+    let rules = rules.iter().enumerate().map(|(index, rule)| {
         let rule_name = format_ident!("r#{}", rule.name);
+        let discriminant = discriminant(index + 1);
 
         match doc_comment.line_docs.get(&rule.name) {
             Some(doc) => quote! {
                 #[doc = #doc]
-                #rule_name
+                #rule_name #discriminant
             },
             None => quote! {
-                #rule_name
+                #rule_name #discriminant
             },
         }
     });
 
     let grammar_doc = &doc_comment.grammar_doc;
+    let serde = if cfg!(feature = "serde") {
+        quote! {
+            #[derive(::pest_typed::serde::Serialize)]
+            #[serde(crate = "::pest_typed::serde")]
+        }
+    } else {
+        quote! {}
+    };
+    let repr = match config.rule_repr {
+        Some(repr) => quote! { #[repr(#repr)] },
+        None => quote! {},
+    };
+    let non_exhaustive = if config.rule_non_exhaustive {
+        quote! { #[non_exhaustive] }
+    } else {
+        quote! {}
+    };
     quote! {
         #[doc = #grammar_doc]
         #[allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
         #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+        #serde
+        #repr
+        #non_exhaustive
         pub enum Rule {
-            EOI,
+            EOI #eoi,
             #( #rules, )*
         }
+        #names
+    }
+}
+
+/// Names of rules as in the grammar, conversions between them and rules, and other metadata of rules.
+fn generate_names(
+    rules: &[OptimizedRule],
+    doc_comment: &DocComment,
+    error_labels: &BTreeMap<String, String>,
+) -> TokenStream {
+    let variants: Vec<_> = rules
+        .iter()
+        .map(|rule| format_ident!("r#{}", rule.name))
+        .collect();
+    let names: Vec<_> = rules.iter().map(|rule| rule.name.as_str()).collect();
+    let labels = rules.iter().map(|rule| match error_labels.get(&rule.name) {
+        Some(label) => quote! {::core::option::Option::Some(#label)},
+        None => quote! {::core::option::Option::None},
+    });
+    let atomic = rules
+        .iter()
+        .map(|rule| matches!(rule.ty, RuleType::Atomic | RuleType::CompoundAtomic));
+    let silent = rules.iter().map(|rule| rule.ty == RuleType::Silent);
+    let docs = rules
+        .iter()
+        .map(|rule| match doc_comment.line_docs.get(&rule.name) {
+            Some(doc) => quote! {::core::option::Option::Some(#doc)},
+            None => quote! {::core::option::Option::None},
+        });
+    quote! {
+        #[allow(dead_code)]
+        impl Rule {
+            /// All rules, starting with `EOI` and then in the order of the grammar.
+            pub const ALL: &'static [Rule] = &[Rule::EOI, #( Rule::#variants, )*];
+            /// Names of all rules as in the grammar, in the same order as [`Rule::ALL`].
+            pub const NAMES: &'static [&'static ::core::primitive::str] = &["EOI", #( #names, )*];
+            /// Name of the rule as in the grammar.
+            pub fn name(&self) -> &'static ::core::primitive::str {
+                match self {
+                    Rule::EOI => "EOI",
+                    #( Rule::#variants => #names, )*
+                }
+            }
+            /// The rule named `name` in the grammar.
+            pub fn from_name(name: &::core::primitive::str) -> ::core::option::Option<Rule> {
+                match name {
+                    "EOI" => ::core::option::Option::Some(Rule::EOI),
+                    #( #names => ::core::option::Option::Some(Rule::#variants), )*
+                    _ => ::core::option::Option::None,
+                }
+            }
+            /// Name of the rule in error messages, if it's given by `#[error_label]`.
+            pub fn error_label(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                match self {
+                    Rule::EOI => ::core::option::Option::None,
+                    #( Rule::#variants => #labels, )*
+                }
+            }
+            /// Whether the rule is atomic (`@`) or compound-atomic (`$`), so nothing is skipped implicitly inside it.
+            pub fn is_atomic(&self) -> ::core::primitive::bool {
+                match self {
+                    Rule::EOI => false,
+                    #( Rule::#variants => #atomic, )*
+                }
+            }
+            /// Whether the rule is silent (`_`), so it produces no pairs.
+            pub fn is_silent(&self) -> ::core::primitive::bool {
+                match self {
+                    Rule::EOI => false,
+                    #( Rule::#variants => #silent, )*
+                }
+            }
+            /// Doc comment of the rule in the grammar, with lines joined by `\n`.
+            pub fn doc(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                match self {
+                    Rule::EOI => ::core::option::Option::None,
+                    #( Rule::#variants => #docs, )*
+                }
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::config::Repr;
     use pest_meta::ast::RuleType;
     use std::collections::HashMap;
 
+    #[test]
+    fn include_relative() {
+        let root = PathBuf::from(std::env::var_os("CARGO_MANIFEST_DIR").unwrap());
+        let paths = vec![root.join("tests").join("syntax.pest")];
+        assert_eq!(
+            generate_include(&format_ident!("Parser"), paths).to_string(),
+            quote! {
+                #[allow(non_upper_case_globals)]
+                const _PEST_GRAMMAR_Parser: [&'static ::core::primitive::str; 1usize] = [
+                    ::core::include_str!(::core::concat!(::core::env!("CARGO_MANIFEST_DIR"), "/", "tests/syntax.pest"))
+                ];
+            }
+            .to_string()
+        );
+    }
+
     #[test]
     fn rule_enum_simple() {
         let rules = vec![OptimizedRule {
@@ -96,10 +254,11 @@ mod tests {
         let doc_comment = &DocComment {
             grammar_doc: "Rule doc\nhello".to_owned(),
             line_docs,
+            rule_sources: HashMap::new(),
         };
 
         assert_eq!(
-            generate_enum(&rules, doc_comment,).to_string(),
+            generate_enum(&rules, doc_comment, &BTreeMap::new(), Config::default()).to_string(),
             quote! {
                 #[doc = "Rule doc\nhello"]
                 #[allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
@@ -109,8 +268,110 @@ mod tests {
                     #[doc = "This is rule comment"]
                     r#f,
                 }
+                #[allow(dead_code)]
+                impl Rule {
+                    /// All rules, starting with `EOI` and then in the order of the grammar.
+                    pub const ALL: &'static [Rule] = &[Rule::EOI, Rule::r#f,];
+                    /// Names of all rules as in the grammar, in the same order as [`Rule::ALL`].
+                    pub const NAMES: &'static [&'static ::core::primitive::str] = &["EOI", "f",];
+                    /// Name of the rule as in the grammar.
+                    pub fn name(&self) -> &'static ::core::primitive::str {
+                        match self {
+                            Rule::EOI => "EOI",
+                            Rule::r#f => "f",
+                        }
+                    }
+                    /// The rule named `name` in the grammar.
+                    pub fn from_name(name: &::core::primitive::str) -> ::core::option::Option<Rule> {
+                        match name {
+                            "EOI" => ::core::option::Option::Some(Rule::EOI),
+                            "f" => ::core::option::Option::Some(Rule::r#f),
+                            _ => ::core::option::Option::None,
+                        }
+                    }
+                    /// Name of the rule in error messages, if it's given by `#[error_label]`.
+                    pub fn error_label(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                        match self {
+                            Rule::EOI => ::core::option::Option::None,
+                            Rule::r#f => ::core::option::Option::None,
+                        }
+                    }
+                    /// Whether the rule is atomic (`@`) or compound-atomic (`$`), so nothing is skipped implicitly inside it.
+                    pub fn is_atomic(&self) -> ::core::primitive::bool {
+                        match self {
+                            Rule::EOI => false,
+                            Rule::r#f => false,
+                        }
+                    }
+                    /// Whether the rule is silent (`_`), so it produces no pairs.
+                    pub fn is_silent(&self) -> ::core::primitive::bool {
+                        match self {
+                            Rule::EOI => false,
+                            Rule::r#f => false,
+                        }
+                    }
+                    /// Doc comment of the rule in the grammar, with lines joined by `\n`.
+                    pub fn doc(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+                        match self {
+                            Rule::EOI => ::core::option::Option::None,
+                            Rule::r#f => ::core::option::Option::Some("This is rule comment"),
+                        }
+                    }
+                }
             }
             .to_string()
         );
     }
+
+    #[test]
+    fn rule_enum_repr() {
+        let rules = ["f", "g"].map(|name| OptimizedRule {
+            name: name.to_owned(),
+            ty: RuleType::Normal,
+            expr: OptimizedExpr::Str("x".to_owned()),
+        });
+        let doc_comment = &DocComment {
+            grammar_doc: String::new(),
+            line_docs: HashMap::new(),
+            rule_sources: HashMap::new(),
+        };
+        let config = Config {
+            rule_non_exhaustive: true,
+            rule_repr: Some(Repr::U16),
+            ..Config::default()
+        };
+        let output = generate_enum(&rules, doc_comment, &BTreeMap::new(), config).to_string();
+        let expected = quote! {
+            #[repr(u16)]
+            #[non_exhaustive]
+            pub enum Rule {
+                EOI = 0,
+                r#f = 1,
+                r#g = 2,
+            }
+        };
+        assert!(output.contains(&expected.to_string()), "{output}");
+    }
+
+    #[test]
+    #[should_panic]
+    fn rule_enum_repr_overflow() {
+        let rules: Vec<_> = (0..128)
+            .map(|i| OptimizedRule {
+                name: format!("r{}", i),
+                ty: RuleType::Normal,
+                expr: OptimizedExpr::Str("x".to_owned()),
+            })
+            .collect();
+        let doc_comment = &DocComment {
+            grammar_doc: String::new(),
+            line_docs: HashMap::new(),
+            rule_sources: HashMap::new(),
+        };
+        let config = Config {
+            rule_repr: Some(Repr::I8),
+            ..Config::default()
+        };
+        let _ = generate_enum(&rules, doc_comment, &BTreeMap::new(), config);
+    }
 }
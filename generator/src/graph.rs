@@ -7,7 +7,7 @@
 // option. All files in the project carrying such notice may not be copied,
 // modified, or distributed except according to those terms.
 
-use crate::config::Config;
+use crate::config::{Config, Visibility};
 use crate::docs::DocComment;
 use crate::types::{box_type, option_type, vec_type};
 use pest::unicode::unicode_property_names;
@@ -15,7 +15,7 @@ use pest_meta::{
     ast::RuleType,
     optimizer::{OptimizedExpr, OptimizedRule},
 };
-use proc_macro2::{Ident, TokenStream};
+use proc_macro2::{Ident, Literal, TokenStream};
 use quote::{format_ident, quote, ToTokens, TokenStreamExt};
 use std::collections::{btree_map, BTreeMap, BTreeSet};
 use syn::Index;
@@ -62,8 +62,12 @@ fn rules_impl_mod() -> TokenStream {
     quote! {rules_impl}
 }
 
-fn pairs_mod() -> TokenStream {
-    quote! {pairs}
+fn bodies_mod() -> TokenStream {
+    quote! {bodies}
+}
+
+fn pairs_mod(mod_name: &Ident) -> TokenStream {
+    quote! {#mod_name}
 }
 
 fn ignore(root: &TokenStream) -> TokenStream {
@@ -73,6 +77,18 @@ fn ignore(root: &TokenStream) -> TokenStream {
     }
 }
 
+/// Skipped content in rules that use `skip_rule` instead of `WHITESPACE` and `COMMENT`.
+fn overridden_ignore(root: &TokenStream, skip_rule: &str) -> TokenStream {
+    let pest_typed = pest_typed();
+    if skip_rule.is_empty() {
+        quote! {#pest_typed::predefined_node::Empty::<'i>}
+    } else {
+        let rules = rules_mod();
+        let skip_rule = ident(skip_rule);
+        quote! {#pest_typed::predefined_node::AtomicRep::<#root::#rules::#skip_rule::<'i, 0>>}
+    }
+}
+
 #[derive(Clone)]
 enum Edge {
     // Type remained.
@@ -81,6 +97,7 @@ enum Edge {
     // Type wrapped by Option.
     ChoiceI(usize),
     Optional,
+    Recovered,
     // Type wrapped by Vec.
     Contents,
 }
@@ -89,8 +106,11 @@ enum Node<'g> {
     /// - Type: `&#ident`
     /// - Path: `.content.deref()`
     Rule(&'g str, bool, bool),
-    /// - Type: `&#ident`
-    /// - Path: `.content`
+    /// - Type: `#span<'i>`
+    /// - Path: `.span`
+    Span,
+    /// - Type: `&#root::tags::#rule::#tag`, or `&#inner` if the tag isn't aliased
+    /// - Path: `res`
     #[cfg(feature = "grammar-extras")]
     Tag(&'g str, &'g str, Vec<TokenStream>),
     // Type remained.
@@ -107,9 +127,12 @@ enum Node<'g> {
     /// - Type: `#opt::<#inner>`
     /// - Path: `.as_ref().and_then(|res| Some(#inner)) #flat`
     Optional(bool, Box<Self>),
+    /// - Type: `#opt::<#inner>`
+    /// - Path: `.matched().and_then(|res| Some(#inner)) #flat`
+    Recovered(bool, Box<Self>),
     // Type wrapped by Vec.
     /// - Type: `#vec::<#inner>`
-    /// - Path: `.content.iter().map(|res| {let res = res.matched; #inner}).collect::<#vec<_>>()`
+    /// - Path: `.content.iter().map(|res| #inner).collect::<#vec<_>>()`
     Contents(Box<Self>),
     // Type wrapped by tuple.
     /// - Type: `(#(#inner),*)`
@@ -125,23 +148,53 @@ impl<'g> Node<'g> {
     fn from_tag(rule_name: &'g str, tag_name: &'g str, tokens: Vec<TokenStream>) -> Self {
         Self::Tag(rule_name, tag_name, tokens)
     }
+    /// Whether the type of the node is an `Option`,
+    /// so that optional wrappers around it are flattened instead of nesting another `Option`.
     fn flattenable(&self) -> bool {
         match self {
-            Node::Rule(_, _, _) => false,
+            Node::Rule(_, _, _) | Node::Span => false,
             #[cfg(feature = "grammar-extras")]
             Node::Tag(_, _, _) => false,
             Node::Content(inner) | Node::SequenceI(_, inner) => inner.flattenable(),
-            Node::ChoiceI(_, false, _) | Node::Optional(false, _) => true,
-            Node::ChoiceI(_, true, inner) | Node::Optional(true, inner) => inner.flattenable(),
+            Node::ChoiceI(_, false, _) | Node::Optional(false, _) | Node::Recovered(false, _) => {
+                true
+            }
+            Node::ChoiceI(_, true, inner)
+            | Node::Optional(true, inner)
+            | Node::Recovered(true, inner) => inner.flattenable(),
             Node::Contents(_) | Node::Tuple(_) => false,
         }
     }
+    /// Whether the node only leads to tags.
+    #[cfg(feature = "grammar-extras")]
+    fn is_tag(&self) -> bool {
+        match self {
+            Node::Tag(_, _, _) => true,
+            Node::Rule(_, _, _) | Node::Span => false,
+            Node::Content(inner)
+            | Node::SequenceI(_, inner)
+            | Node::ChoiceI(_, _, inner)
+            | Node::Optional(_, inner)
+            | Node::Recovered(_, inner)
+            | Node::Contents(inner) => inner.is_tag(),
+            Node::Tuple(tuple) => tuple.iter().all(Node::is_tag),
+        }
+    }
+    /// Whether the type of the node is an `Option` from an optional expression.
+    fn optional(&self) -> bool {
+        match self {
+            Node::Content(inner) | Node::SequenceI(_, inner) => inner.optional(),
+            Node::Optional(_, _) => true,
+            _ => false,
+        }
+    }
     pub fn wrap(self, edge: Edge) -> Self {
         match edge {
             Edge::Content => Self::Content(Box::new(self)),
             Edge::ContentI(i) => Self::SequenceI(i, Box::new(self)),
             Edge::ChoiceI(i) => Self::ChoiceI(i, self.flattenable(), Box::new(self)),
             Edge::Optional => Self::Optional(self.flattenable(), Box::new(self)),
+            Edge::Recovered => Self::Recovered(self.flattenable(), Box::new(self)),
             Edge::Contents => Self::Contents(Box::new(self)),
         }
     }
@@ -207,11 +260,23 @@ impl<'g> Node<'g> {
                 let t = ident(t);
                 (quote! {res}, quote! {&'s #root::#rules_mod::#t #generics})
             }
+            Node::Span => {
+                let span = _span();
+                (quote! {res.span}, quote! {#span<'i>})
+            }
             #[cfg(feature = "grammar-extras")]
-            Node::Tag(_rule, _tag, inner) => {
-                // let tag_id = format_ident!("r#{tag}");
-                // let rule_id = format_ident!("r#{rule}");
-                (quote! {res}, quote! {(#(&#inner),*)})
+            Node::Tag(rule, tag, inner) => {
+                if config.aliased_tags.contains(tag) {
+                    let (rule, tag) = (ident(rule), ident(tag));
+                    (
+                        quote! {res},
+                        quote! {&'s #root::tags::#rule::#tag::<'i, INHERITED>},
+                    )
+                } else if let [inner] = inner.as_slice() {
+                    (quote! {res}, quote! {&'s #inner})
+                } else {
+                    (quote! {res}, quote! {(#(&'s #inner),*)})
+                }
             }
             Node::Content(inner) => {
                 let (pa, ty) = inner.expand(root, config);
@@ -233,6 +298,14 @@ impl<'g> Node<'g> {
                     opt(flatten, ty),
                 )
             }
+            Node::Recovered(flatten, inner) => {
+                let (pa, ty) = inner.expand(root, config);
+                let flat = flat(flatten);
+                (
+                    quote! {{let res = res.matched().map(|res| #pa) #flat; res}},
+                    opt(flatten, ty),
+                )
+            }
             Node::ChoiceI(index, flatten, inner) => {
                 let (pa, ty) = inner.expand(root, config);
                 let func = format_ident!("_{}", index);
@@ -245,7 +318,7 @@ impl<'g> Node<'g> {
             Node::Contents(inner) => {
                 let (pa, ty) = inner.expand(root, config);
                 (
-                    quote! {{let res = res.content.iter().map(|res| { let res = &res.matched; #pa }).collect::<#vec<_>>(); res}},
+                    quote! {{let res = res.content.iter().map(|res| #pa).collect::<#vec<_>>(); res}},
                     quote! {#vec::<#ty>},
                 )
             }
@@ -256,6 +329,85 @@ impl<'g> Node<'g> {
             }
         }
     }
+    /// Type of the result of the accesser to this node, in short for docs.
+    pub fn describe(&self) -> String {
+        match self {
+            Node::Rule(t, _, _) => format!("&{}", t),
+            Node::Span => "Span".to_owned(),
+            #[cfg(feature = "grammar-extras")]
+            Node::Tag(rule, tag, _) => format!("&tags::{}::{}", rule, tag),
+            Node::Content(inner)
+            | Node::SequenceI(_, inner)
+            | Node::ChoiceI(_, true, inner)
+            | Node::Optional(true, inner)
+            | Node::Recovered(true, inner) => inner.describe(),
+            Node::ChoiceI(_, false, inner)
+            | Node::Optional(false, inner)
+            | Node::Recovered(false, inner) => format!("Option<{}>", inner.describe()),
+            Node::Contents(inner) => format!("Vec<{}>", inner.describe()),
+            Node::Tuple(tuple) => {
+                let items: Vec<_> = tuple.iter().map(Node::describe).collect();
+                format!("({})", items.join(", "))
+            }
+        }
+    }
+    /// Path and type of spans in `res`, the result of the accesser to this node.
+    ///
+    /// Returns [`None`] if it leads to anything without a span,
+    /// such as silent rules, builtin rules and tags.
+    pub fn spans(
+        &self,
+        res: TokenStream,
+        config: &RuleConfig<'g>,
+    ) -> Option<(TokenStream, TokenStream)> {
+        match self {
+            Node::Rule(t, _, _) if config.spanned.contains(t) => {
+                let pest = pest();
+                let span = _span();
+                Some((quote! {#pest::Spanned::span(#res)}, quote! {#span<'i>}))
+            }
+            Node::Rule(_, _, _) | Node::Span => None,
+            #[cfg(feature = "grammar-extras")]
+            Node::Tag(_, _, _) => None,
+            // Flattened options have the same type as their inner nodes.
+            Node::Content(inner)
+            | Node::SequenceI(_, inner)
+            | Node::ChoiceI(_, true, inner)
+            | Node::Optional(true, inner)
+            | Node::Recovered(true, inner) => inner.spans(res, config),
+            Node::ChoiceI(_, false, inner)
+            | Node::Optional(false, inner)
+            | Node::Recovered(false, inner) => {
+                let (pa, ty) = inner.spans(quote! {res}, config)?;
+                let opt = option_type();
+                Some((quote! {#res.map(|res| #pa)}, quote! {#opt::<#ty>}))
+            }
+            Node::Contents(inner) => {
+                let (pa, ty) = inner.spans(quote! {res}, config)?;
+                let vec = vec_type();
+                Some((
+                    quote! {#res.into_iter().map(|res| #pa).collect::<#vec<_>>()},
+                    quote! {#vec::<#ty>},
+                ))
+            }
+            Node::Tuple(tuple) => {
+                let names: Vec<_> = (0..tuple.len())
+                    .map(|i| format_ident!("res{}", i))
+                    .collect();
+                let (pa, ty): (Vec<_>, Vec<_>) = tuple
+                    .iter()
+                    .zip(&names)
+                    .map(|(node, name)| node.spans(quote! {#name}, config))
+                    .collect::<Option<Vec<_>>>()?
+                    .into_iter()
+                    .unzip();
+                Some((
+                    quote! {{let (#(#names),*) = #res; (#(#pa),*)}},
+                    quote! {(#(#ty),*)},
+                ))
+            }
+        }
+    }
 }
 
 /// `'g` stands for the lifetime of rules.
@@ -279,6 +431,10 @@ impl<'g> Accesser<'g> {
         let res = BTreeMap::from([(name, Node::from_rule(id, has_life_time, has_skip))]);
         Self { accessers: res }
     }
+    pub fn from_span(name: &'g str) -> Self {
+        let res = BTreeMap::from([(name, Node::Span)]);
+        Self { accessers: res }
+    }
     #[cfg(feature = "grammar-extras")]
     pub fn from_tag(rule: &'g str, name: &'g str, tokens: TokenStream) -> Self {
         let res = BTreeMap::from([(name, Node::from_tag(rule, name, vec![tokens]))]);
@@ -296,6 +452,9 @@ impl<'g> Accesser<'g> {
     pub fn optional(self) -> Self {
         self.prepend(Edge::Optional)
     }
+    pub fn recovered(self) -> Self {
+        self.prepend(Edge::Recovered)
+    }
     pub fn choice(self, i: usize) -> Self {
         self.prepend(Edge::ChoiceI(i))
     }
@@ -323,11 +482,29 @@ impl<'g> Accesser<'g> {
             }
         });
     }
+    /// Whether any accesser leads to tags.
+    #[cfg(feature = "grammar-extras")]
+    pub fn has_tags(&self) -> bool {
+        self.accessers.values().any(Node::is_tag)
+    }
+    /// Only keep accessers that lead to tags.
+    #[cfg(feature = "grammar-extras")]
+    pub fn tags(mut self) -> Self {
+        self.accessers.retain(|_, node| node.is_tag());
+        self
+    }
     /// Join two accesser forest in the same level.
     pub fn join(mut self, other: Accesser<'g>) -> Self {
         self.join_mut(other);
         self
     }
+    /// List items of accessers and their result types in docs of rule structs.
+    pub fn describe(&self) -> Vec<String> {
+        self.accessers
+            .iter()
+            .map(|(name, node)| format!("- `{}()`: `{}`", name, node.describe()))
+            .collect()
+    }
     pub fn collect(&self, root: &TokenStream, config: &RuleConfig<'g>) -> TokenStream {
         let accessers = self.accessers.iter().map(|(name, node)| {
             let id = ident(name);
@@ -345,10 +522,51 @@ impl<'g> Accesser<'g> {
                 }
             };
             // We may generate source codes to help debugging here.
-            let doc = format! {"A helper function to access [`{}`].", name};
+            let doc = match config.stack_accessers.builtin(name) {
+                Some(builtin) => format!(
+                    "A helper function to access spans matched by `{}`.",
+                    builtin
+                ),
+                None => format! {"A helper function to access [`{}`].", name},
+            };
+            let rule_docs = config.accesser_docs.get(name).into_iter().flatten();
+            let is_present = match config.stack_accessers.builtin(name) {
+                None if node.optional() => {
+                    let is_present = format_ident!("is_present_{}", name);
+                    let doc = format!("Whether the optional [`{}`] is present.", name);
+                    let bool = _bool();
+                    quote! {
+                        #[doc = #doc]
+                        #[allow(non_snake_case)]
+                        pub fn #is_present(&self) -> #bool {
+                            self.#id().is_some()
+                        }
+                    }
+                }
+                _ => quote! {},
+            };
+            // Skipped if the name is taken by another accesser.
+            let span_name = format!("{}_span", name);
+            let spans = match node.spans(quote! {self.#id()}, config) {
+                Some((paths, types)) if !self.accessers.contains_key(span_name.as_str()) => {
+                    let span_id = format_ident!("{}", span_name);
+                    let doc = format!("A helper function to access spans of [`{}`].", name);
+                    quote! {
+                        #[doc = #doc]
+                        #[allow(non_snake_case)]
+                        pub fn #span_id(&self) -> #types {
+                            #paths
+                        }
+                    }
+                }
+                _ => quote! {},
+            };
             quote! {
                 #[doc = #doc]
+                #(#[doc = #rule_docs])*
                 #src
+                #is_present
+                #spans
             }
         });
         quote! {
@@ -394,6 +612,8 @@ enum Emission {
     /// Current rule will not contain a span.
     /// Current rule will not be visible in some APIs.
     Expression,
+    /// Current rule will contain a span, but still not be visible in some APIs.
+    SpannedExpression,
     /// Current rule will only contain a span.
     /// Inner structures will not be emitted.
     Span,
@@ -404,6 +624,7 @@ impl ToTokens for Emission {
     fn to_tokens(&self, tokens: &mut TokenStream) {
         match self {
             Self::Expression => tokens.append(format_ident!("Expression")),
+            Self::SpannedExpression => tokens.append(format_ident!("SpannedExpression")),
             Self::Span => tokens.append(format_ident!("Span")),
             Self::Both => tokens.append(format_ident!("Both")),
         }
@@ -415,43 +636,140 @@ struct RuleConfig<'g> {
     pub atomicity: Option<bool>,
     pub rule_id: Ident,
     pub boxed: bool,
-    #[allow(dead_code)]
     pub rule_name: &'g str,
     pub rule_desc: String,
     pub rule_doc: Option<&'g str>,
+    /// Source text of the rule in the grammar.
+    pub rule_source: Option<&'g str>,
+    /// Rule used for implicit skipping instead of `WHITESPACE` and `COMMENT`.
+    ///
+    /// Empty if nothing is skipped.
+    pub skip_rule: Option<&'g str>,
     pub defined: &'g BTreeSet<&'g str>,
+    /// Non-silent rules, whose structs implement `Spanned`.
+    pub spanned: &'g BTreeSet<&'g str>,
+    /// Rules that repetitions of sequences ending with them recover at.
+    pub recover_at: &'g BTreeSet<&'g str>,
     pub builtins_without_lifetime: &'g BTreeSet<&'g str>,
+    /// Rule Name -> Extra doc lines on accessers to the rule.
+    pub accesser_docs: &'g BTreeMap<&'g str, Vec<String>>,
+    /// Minimum number of bytes that the rule consumes.
+    pub min_len: usize,
+    /// Name of the rule in error messages.
+    pub error_label: Option<&'g str>,
+    /// Maximum number of nested recursive rules.
+    pub recursion_limit: Option<usize>,
+    /// Whether the rule counts towards the recursion limit.
+    pub counts_recursion: bool,
+    /// Whether the rule is an alias of a body shared with other rules,
+    /// unless its type may contain itself, as it's a head of recursion cycles or skipped as trivia.
+    pub shares_body: bool,
+    pub stack_accessers: &'g StackAccessers,
+    /// Name of the method that reparses a single item,
+    /// and path from the rule content to the repetition of items.
+    pub reparse: Option<(Ident, TokenStream)>,
+    /// Name of the method that parses items one by one,
+    /// the item rule, and whether `SOI` and `EOI` surround the items.
+    pub parse_iter: Option<(Ident, &'g str, bool, bool)>,
+    /// Name of the constructor from content and span.
+    pub constructor: Ident,
+    /// Whether the content is [`Default`], so is the rule struct.
+    pub default: bool,
+    /// Whether the content is a repetition implementing `BoundWrapper`, so is the rule struct.
+    pub bounded: bool,
+    /// Tags in the rule with the same type at all occurrences,
+    /// whose accessers return the alias in the tags module.
+    #[cfg(feature = "grammar-extras")]
+    pub aliased_tags: BTreeSet<&'g str>,
+}
+/// `name` suffixed with `_` until it doesn't collide with accessers to rules.
+fn escape(name: &str, defined: &BTreeSet<&str>) -> String {
+    let mut name = name.to_owned();
+    while defined.contains(name.as_str()) {
+        name.push('_');
+    }
+    name
 }
+/// Names of accessers to spans matched by `POP`, `PEEK` and `PUSH`.
+///
+/// Suffixed with `_` until they don't collide with accessers to rules.
+struct StackAccessers {
+    pop: String,
+    peek: String,
+    pushed: String,
+}
+impl StackAccessers {
+    fn new(defined: &BTreeSet<&str>) -> Self {
+        Self {
+            pop: escape("pop", defined),
+            peek: escape("peek", defined),
+            pushed: escape("pushed", defined),
+        }
+    }
+    /// The stack operation whose spans an accesser returns.
+    fn builtin(&self, name: &str) -> Option<&'static str> {
+        if name == self.pop {
+            Some("POP")
+        } else if name == self.peek {
+            Some("PEEK")
+        } else if name == self.pushed {
+            Some("PUSH")
+        } else {
+            None
+        }
+    }
+}
+
 impl<'g> RuleConfig<'g> {
-    fn get_doc<'s>(&'s self) -> impl Iterator<Item = &'s str>
-    where
-        'g: 's,
-    {
-        use core::iter::once;
-        once(self.rule_desc.as_str())
-            .chain(once(""))
-            .chain(self.rule_doc.iter().cloned())
+    /// Doc lines of the rule struct, followed by the source text of the rule and its accessers.
+    fn get_doc(&self, accessers: &[String]) -> Vec<String> {
+        let mut lines = vec![self.rule_desc.clone(), String::new()];
+        lines.extend(self.rule_doc.map(String::from));
+        if let Some(source) = self.rule_source {
+            // Longer than any run of backticks in the source.
+            let longest = source.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+            let fence = "`".repeat(longest.max(2) + 1);
+            if self.rule_doc.is_some() {
+                lines.push(String::new());
+            }
+            lines.push(format!("{}pest", fence));
+            lines.extend(source.lines().map(String::from));
+            lines.push(fence);
+        }
+        if !accessers.is_empty() {
+            lines.push(String::new());
+            lines.push("Accessers:".to_owned());
+            lines.push(String::new());
+            lines.extend(accessers.iter().cloned());
+        }
+        lines
     }
 }
 
+/// Rule struct, or an alias of `body` if given.
 fn rule<'g>(
     rule_config: &RuleConfig<'g>,
     type_name: TokenStream,
     accessers: &Accesser<'g>,
     emission: Emission,
+    body: Option<Ident>,
 ) -> TokenStream {
     let root = quote! {super::super};
     let _bool = _bool();
-    let accessers = match emission {
-        Emission::Both | Emission::Expression => accessers.collect(&root, rule_config),
-        Emission::Span => quote! {},
+    let (accessers, accesser_docs) = match emission {
+        Emission::Both | Emission::Expression | Emission::SpannedExpression => {
+            (accessers.collect(&root, rule_config), accessers.describe())
+        }
+        Emission::Span => (quote! {}, vec![]),
     };
     #[allow(clippy::needless_lifetimes)]
     fn create<'g>(
         rule_config: &RuleConfig<'g>,
         accesser_impl: TokenStream,
+        accesser_docs: &[String],
         inner_type: TokenStream,
         emission: Emission,
+        body: Option<Ident>,
     ) -> TokenStream {
         let root = quote! {super::super};
         let pest_typed = pest_typed();
@@ -461,38 +779,237 @@ fn rule<'g>(
             Some(false) => quote! {false},
             None => quote! {INHERITED},
         };
-        let docs = rule_config.get_doc();
-        let ignore = ignore(&root);
+        let docs = rule_config.get_doc(accesser_docs);
+        let ignore = match rule_config.skip_rule {
+            Some(skip_rule) => overridden_ignore(&root, skip_rule),
+            None => ignore(&root),
+        };
         let boxed = rule_config.boxed;
+        let min_len = Literal::usize_unsuffixed(rule_config.min_len);
+        let error_label = rule_config
+            .error_label
+            .map(|label| quote! {, error_label = #label});
+        let recursion_limit = rule_config.recursion_limit.map(|limit| {
+            let limit = Literal::usize_unsuffixed(limit);
+            quote! {, recursion_limit = #limit}
+        });
+        let counts_recursion = match rule_config.counts_recursion {
+            true => quote! {},
+            false => quote! {, counts_recursion = false},
+        };
         let usize = _usize();
-        quote! {
-            #pest_typed::rule!(#name, #(#docs)*, #root::Rule, #root::Rule::#name, #inner_type, #ignore, #atomicity, #emission, #boxed);
+        let str = _str();
+        let span = _span();
+        let constructor = &rule_config.constructor;
+        let constructors = match emission {
+            Emission::Expression => quote! {
+                impl<'i, const INHERITED: #usize> #name<'i, INHERITED> {
+                    /// Create the node from its content, without parsing.
+                    #[allow(clippy::self_named_constructors)]
+                    pub fn #constructor(content: #inner_type) -> Self {
+                        Self {
+                            content: ::core::convert::From::from(content),
+                            _phantom: ::core::marker::PhantomData,
+                        }
+                    }
+                }
+                impl<'i, const INHERITED: #usize> ::core::convert::From<#inner_type> for #name<'i, INHERITED> {
+                    fn from(content: #inner_type) -> Self {
+                        Self::#constructor(content)
+                    }
+                }
+            },
+            Emission::Span => quote! {
+                impl<'i, const INHERITED: #usize> #name<'i, INHERITED> {
+                    /// Create the node from its span, without parsing.
+                    #[allow(clippy::self_named_constructors)]
+                    pub fn #constructor(span: #span<'i>) -> Self {
+                        Self { span }
+                    }
+                }
+            },
+            Emission::Both | Emission::SpannedExpression => quote! {
+                impl<'i, const INHERITED: #usize> #name<'i, INHERITED> {
+                    /// Create the node from its content and span, without parsing.
+                    #[allow(clippy::self_named_constructors)]
+                    pub fn #constructor(content: #inner_type, span: #span<'i>) -> Self {
+                        Self {
+                            content: ::core::convert::From::from(content),
+                            span,
+                        }
+                    }
+                }
+                impl<'i, const INHERITED: #usize> ::core::convert::From<#inner_type> for #name<'i, INHERITED> {
+                    /// Create the node from its content with an empty span.
+                    fn from(content: #inner_type) -> Self {
+                        Self::#constructor(content, ::core::default::Default::default())
+                    }
+                }
+            },
+        };
+        let default = match emission {
+            Emission::Expression | Emission::SpannedExpression | Emission::Both
+                if rule_config.default =>
+            {
+                quote! {
+                    impl<'i, const INHERITED: #usize> ::core::default::Default for #name<'i, INHERITED> {
+                        fn default() -> Self {
+                            Self::from(<#inner_type as ::core::default::Default>::default())
+                        }
+                    }
+                }
+            }
+            _ => quote! {},
+        };
+        // Only implemented for the default `INHERITED`, so that `rule::MIN` can be inferred,
+        // as bounds don't depend on whether trivia is skipped.
+        let bounds = rule_config.bounded.then(|| {
+            quote! {
+                impl<'i> #pest_typed::BoundWrapper for #name<'i, 1> {
+                    const MIN: #usize = {
+                        #[allow(dead_code)]
+                        const INHERITED: #usize = 1;
+                        <#inner_type as #pest_typed::BoundWrapper>::MIN
+                    };
+                    const MAX: #usize = {
+                        #[allow(dead_code)]
+                        const INHERITED: #usize = 1;
+                        <#inner_type as #pest_typed::BoundWrapper>::MAX
+                    };
+                }
+            }
+        });
+        let reparse = rule_config.reparse.as_ref().map(|(method, path)| {
+            quote! {
+                impl<'i> #name<'i, 1> {
+                    /// Reparse `input`, which is the input of `self` with range `edit` replaced.
+                    ///
+                    /// Only the item that covers the edit is reparsed if possible,
+                    /// see [`try_reparse`](#pest_typed::incremental::try_reparse).
+                    pub fn #method(
+                        &self,
+                        edit: ::core::ops::Range<#usize>,
+                        input: &'i #str,
+                    ) -> #pest_typed::error::ParseResult<Self, #root::Rule> {
+                        #pest_typed::incremental::reparse(self, edit, input, |node| &mut node.content #path)
+                    }
+                }
+            }
+        });
+        let parse_iter = rule_config
+            .parse_iter
+            .as_ref()
+            .map(|(method, item_name, soi, eoi)| {
+                let item = ident(item_name);
+                let end = match eoi {
+                    true => quote! {#root::rules::EOI::<'i>},
+                    false => quote! {#pest_typed::predefined_node::Empty::<'i>},
+                };
+                let doc = format!(
+                    "Parse the whole input with this rule, passing each [`{}`] to `f` \
+                     and dropping it before the next one is parsed, instead of collecting them.",
+                    item_name
+                );
+                quote! {
+                    impl<'i> #name<'i, 1> {
+                        #[doc = #doc]
+                        ///
+                        /// Parsing stops without error once `f` returns [`ControlFlow::Break`](core::ops::ControlFlow::Break),
+                        /// and items passed to `f` before a failure are not rolled back.
+                        pub fn #method(
+                            input: &'i #str,
+                            f: impl ::core::ops::FnMut(#root::rules::#item<'i, 1>) -> ::core::ops::ControlFlow<()>,
+                        ) -> #pest_typed::error::ParseResult<(), #root::Rule> {
+                            #pest_typed::rule::parse_iter::<#root::Rule, Self, #root::rules::#item<'i, 1>, #end, #ignore>(input, #soi, f)
+                        }
+                    }
+                }
+            });
+        let accessers = quote! {
             impl<'i, const INHERITED: #usize> #name<'i, INHERITED> {
                 #accesser_impl
             }
+        };
+        let Some(body) = body else {
+            return quote! {
+                #pest_typed::rule!(#name, #(#docs)*, #root::Rule, #root::Rule::#name, #inner_type, #ignore, #atomicity, #emission, #boxed, min_len = #min_len #error_label #recursion_limit #counts_recursion);
+                #accessers
+                #constructors
+                #default
+                #bounds
+                #reparse
+                #parse_iter
+            };
+        };
+        // Constructors, `Default` and bounds of contents are implemented by the body.
+        let rules_impl = rules_impl_mod();
+        let bodies = bodies_mod();
+        let rule = quote! {{ #root::Rule::#name as #usize }};
+        let (body, inner, bounds) = match emission {
+            Emission::Span => (
+                quote! {#root::#rules_impl::#bodies::#body<'i, #rule, INHERITED>},
+                Some(quote! {, inner = #inner_type}),
+                bounds,
+            ),
+            Emission::Expression | Emission::SpannedExpression | Emission::Both => (
+                quote! {#root::#rules_impl::#bodies::#body<'i, #inner_type, #rule, INHERITED>},
+                None,
+                None,
+            ),
+        };
+        quote! {
+            #pest_typed::rule_instance!(#name, #(#docs)*, #root::Rule, #root::Rule::#name, #body, #atomicity, #emission #inner, min_len = #min_len #error_label #recursion_limit #counts_recursion);
+            #accessers
+            #bounds
+            #reparse
+            #parse_iter
         }
     }
-    create(rule_config, accessers, type_name, emission)
+    create(
+        rule_config,
+        accessers,
+        &accesser_docs,
+        type_name,
+        emission,
+        body,
+    )
 }
 
+/// Generated items.
+///
+/// Rule structs, tag modules and accessers are all keyed by their original names in the grammar,
+/// so they are emitted in alphabetical order of grammar names,
+/// no matter whether they need to be escaped as raw identifiers.
 struct Output<'g> {
-    content: Vec<TokenStream>,
+    /// Rule Name -> Rule Struct.
+    content: BTreeMap<&'g str, TokenStream>,
+    builtins: Vec<TokenStream>,
     wrappers: Vec<TokenStream>,
     wrapper_counter: usize,
-    rule_configs: BTreeMap<Ident, RuleConfig<'g>>,
+    /// String -> Wrapper path, shared by all occurrences of the same string.
+    string_wrappers: BTreeMap<String, TokenStream>,
+    /// Strings -> Wrapper path, shared by all occurrences of the same strings.
+    string_array_wrappers: BTreeMap<Vec<String>, TokenStream>,
+    rule_configs: BTreeMap<&'g str, RuleConfig<'g>>,
+    /// Body Name -> Body, shared by rules with the same emission, atomicity, boxing and skipped contents.
+    bodies: BTreeMap<Ident, TokenStream>,
     /// Rule Name -> (Tag Name, ([Type], Accesser)).
     #[cfg(feature = "grammar-extras")]
-    tagged_nodes: BTreeMap<Ident, BTreeMap<Ident, (Vec<TokenStream>, Accesser<'g>)>>,
+    tagged_nodes: BTreeMap<&'g str, BTreeMap<&'g str, (Vec<TokenStream>, Accesser<'g>)>>,
     sequences: BTreeSet<usize>,
     choices: BTreeSet<usize>,
 }
 impl<'g> Output<'g> {
     fn new() -> Self {
         Self {
-            content: Vec::new(),
+            content: BTreeMap::new(),
+            builtins: Vec::new(),
             wrappers: Vec::new(),
             wrapper_counter: 0,
+            string_wrappers: BTreeMap::new(),
+            string_array_wrappers: BTreeMap::new(),
             rule_configs: BTreeMap::new(),
+            bodies: BTreeMap::new(),
             #[cfg(feature = "grammar-extras")]
             tagged_nodes: BTreeMap::new(),
             sequences: BTreeSet::new(),
@@ -517,32 +1034,92 @@ impl<'g> Output<'g> {
     }
     /// Insert rule struct to rule module.
     fn insert(&mut self, tokens: TokenStream, config: RuleConfig<'g>) {
-        self.content.push(tokens);
-        let prev = self.rule_configs.insert(config.rule_id.clone(), config);
+        let prev = self.content.insert(config.rule_name, tokens);
         assert!(prev.is_none());
+        let prev = self.rule_configs.insert(config.rule_name, config);
+        assert!(prev.is_none());
+    }
+    /// Insert the body of the rule to bodies module, unless a rule with the same
+    /// emission, atomicity, boxing and skipped contents has inserted it.
+    /// Return the body name.
+    ///
+    /// Skipped contents don't matter to atomic rules, and boxing doesn't matter to rules without content.
+    fn insert_body(&mut self, rule_config: &RuleConfig<'g>, emission: Emission) -> Ident {
+        let atomic = rule_config.atomicity == Some(true);
+        let boxed = rule_config.boxed && !matches!(emission, Emission::Span);
+        let skip_rule = if atomic { None } else { rule_config.skip_rule };
+        let name = format_ident!(
+            "{}{}{}{}",
+            emission.to_token_stream().to_string(),
+            if atomic { "Atomic" } else { "" },
+            if boxed { "Boxed" } else { "" },
+            match skip_rule {
+                None => String::new(),
+                Some("") => "WithoutSkip".to_owned(),
+                Some(skip_rule) => format!("Skipping_{}", skip_rule),
+            },
+        );
+        if let btree_map::Entry::Vacant(entry) = self.bodies.entry(name.clone()) {
+            let root = quote! {super::super};
+            let pest_typed = pest_typed();
+            let ignore = match skip_rule {
+                Some(skip_rule) => overridden_ignore(&root, skip_rule),
+                None => ignore(&root),
+            };
+            let constructor = &rule_config.constructor;
+            let doc = format!(
+                "Body of {} rules emitting `{}`{}{}.",
+                if atomic { "atomic" } else { "non-atomic" },
+                emission.to_token_stream(),
+                if boxed { " with boxed content" } else { "" },
+                match skip_rule {
+                    None => String::new(),
+                    Some("") => ", skipping nothing".to_owned(),
+                    Some(skip_rule) => format!(", skipping `{}`", skip_rule),
+                },
+            );
+            entry.insert(quote! {
+                #pest_typed::rule_body!(#name, #doc, #root::Rule, #ignore, #atomic, #emission, #boxed, #constructor);
+            });
+        }
+        name
     }
     /// Insert built-in rule structs.
     fn insert_builtin(&mut self, tokens: TokenStream) {
-        self.content.push(tokens);
+        self.builtins.push(tokens);
     }
     /// Insert tag struct to tag module.
     /// Return the module path relative to module root.
     #[cfg(feature = "grammar-extras")]
     fn insert_tag(
         &mut self,
-        rule_name: &Ident,
-        tag_name: &Ident,
+        rule_name: &'g str,
+        tag_name: &'g str,
         inner: TokenStream,
         accesser: Accesser<'g>,
     ) {
-        let entry = self.tagged_nodes.entry(rule_name.clone()).or_default();
-        let entry = entry.entry(tag_name.clone()).or_default();
+        let entry = self.tagged_nodes.entry(rule_name).or_default();
+        let entry = entry.entry(tag_name).or_default();
         entry.0.push(inner);
         entry.1.join_mut(accesser);
     }
-    /// Insert a string wrapper to corresponding module.
+    /// Tags in the rule with the same type at all occurrences.
+    #[cfg(feature = "grammar-extras")]
+    fn aliased_tags(&self, rule_name: &str) -> BTreeSet<&'g str> {
+        self.tagged_nodes
+            .get(rule_name)
+            .into_iter()
+            .flatten()
+            .filter(|(_, (types, _))| tag_type(types).is_some())
+            .map(|(tag_name, _)| *tag_name)
+            .collect()
+    }
+    /// Insert a string wrapper to corresponding module, unless the string has been wrapped.
     /// Return the module path relative to module root.
     fn insert_string_wrapper(&mut self, string: &str) -> TokenStream {
+        if let Some(path) = self.string_wrappers.get(string) {
+            return path.clone();
+        }
         let s = ident(&format!("w_{}", self.wrapper_counter));
         self.wrapper_counter += 1;
         let doc = format!("A wrapper for `{:?}`.", string);
@@ -559,11 +1136,16 @@ impl<'g> Output<'g> {
             }
         };
         self.wrappers.push(wrapper);
-        quote! {#wrapper_mod::#s}
+        let path = quote! {#wrapper_mod::#s};
+        self.string_wrappers.insert(string.to_owned(), path.clone());
+        path
     }
-    /// Insert a string array wrapper to corresponding module.
+    /// Insert a string array wrapper to corresponding module, unless the strings have been wrapped.
     /// Return the module path relative to module root.
     fn insert_string_array_wrapper(&mut self, strings: &[String]) -> TokenStream {
+        if let Some(path) = self.string_array_wrappers.get(strings) {
+            return path.clone();
+        }
         let s = ident(&format!("w_{}", self.wrapper_counter));
         self.wrapper_counter += 1;
         let doc = format!("A wrapper for `{:?}`.", strings);
@@ -573,47 +1155,42 @@ impl<'g> Output<'g> {
         let wrapper = quote! {
             #[doc = #doc]
             #[allow(non_camel_case_types)]
-            #[derive(Clone, PartialEq)]
+            #[derive(Clone, Hash, PartialEq, Eq)]
             pub struct #s;
             impl #pest_typed::StringArrayWrapper for #s {
                 const CONTENT: &'static [&'static #str] = &[ #(#strings),* ];
             }
         };
         self.wrappers.push(wrapper);
-        quote! {#wrapper_mod::#s}
+        let path = quote! {#wrapper_mod::#s};
+        self.string_array_wrappers
+            .insert(strings.to_vec(), path.clone());
+        path
     }
     /// (nodes, wrappers)
-    fn collect(&self) -> TokenStream {
-        let content = &self.content;
+    fn collect(&self, vis: Visibility) -> TokenStream {
+        let content = self.content.values();
+        let builtins = &self.builtins;
         let wrappers = &self.wrappers;
         let wrapper_mod = constant_wrappers();
         let rules = rules_mod();
         #[cfg(feature = "grammar-extras")]
         let tags = self.tagged_nodes.iter().flat_map(|(rule_name, tags)| {
+            let rule_name = ident(rule_name);
             let usize = _usize();
             // let root = quote! {super::super};
             // let config = self.rule_configs.get(rule_name).unwrap();
-            #[allow(unused_variables)]
-            let tags = tags.iter().map(|(tag_name, (types, accesser))| {
+            // Tags with different types at different occurrences are not aliased,
+            // and their accessers return the types of the occurrences.
+            let tags = tags.iter().filter_map(|(tag_name, (types, _))| {
+                let ty = tag_type(types)?;
+                let tag_name = ident(tag_name);
                 let comment = format!("Tag {} referenced by {}.", tag_name, rule_name);
-                // let accesser = accesser.collect(&root, config);
-                quote! {
+                Some(quote! {
                     #[doc = #comment]
                     #[allow(non_camel_case_types)]
-                    pub type #tag_name<'i, 's, const INHERITED: #usize> = ( #(&'s #types),* );
-                }
-                /*
-                quote! {
-                    #[doc = #comment]
-                    pub struct #tag_name<'s, 'i, const INHERITED: #usize>{
-                        /// Tag contents.
-                        pub content: ( #(&'s #types, )* )
-                    }
-                    impl<'s, 'i, const INHERITED: #usize> #tag_name<'s, 'i, INHERITED> {
-                        #accesser
-                    }
-                }
-                */
+                    pub type #tag_name<'i, const INHERITED: #usize> = #ty;
+                })
             });
             let doc = format!("Tags inside rule [super::super::rules::{}].", rule_name);
             quote! {
@@ -627,30 +1204,47 @@ impl<'g> Output<'g> {
         #[cfg(feature = "grammar-extras")]
         let mod_tags = quote! {
             #[doc = "Generated structs for tags."]
-            pub mod tags {
+            #vis mod tags {
                 #(#tags)*
             }
         };
         #[cfg(not(feature = "grammar-extras"))]
         let mod_tags = quote! {};
         let rules_impl = rules_impl_mod();
+        let bodies = self.bodies.values();
+        let bodies_mod = bodies_mod();
         quote! {
             mod #wrapper_mod {
                 #(#wrappers)*
             }
             #mod_tags
             #[doc = "Definitions of statically typed nodes generated by pest-generator."]
-            pub mod #rules_impl {
+            #vis mod #rules_impl {
                 #[doc = "Definitions of statically typed nodes generated by pest-generator."]
                 pub mod #rules {
                     #(#content)*
+                    #(#builtins)*
+                }
+                #[doc = "Generic structs shared by rules in [rules], except rules whose types may contain themselves."]
+                pub mod #bodies_mod {
+                    #(#bodies)*
                 }
             }
-            pub use #rules_impl::#rules as #rules;
+            #vis use #rules_impl::#rules as #rules;
         }
     }
 }
 
+/// The type shared by all occurrences of a tag, if any.
+#[cfg(feature = "grammar-extras")]
+fn tag_type(types: &[TokenStream]) -> Option<&TokenStream> {
+    let (first, rest) = types.split_first()?;
+    let first_str = first.to_string();
+    rest.iter()
+        .all(|ty| ty.to_string() == first_str)
+        .then_some(first)
+}
+
 /// Returns (type name, accesser).
 fn process_single_alias<'g>(
     map: &mut Output<'g>,
@@ -662,8 +1256,23 @@ fn process_single_alias<'g>(
     explicit: bool,
 ) -> (TokenStream, Accesser<'g>) {
     if explicit {
+        #[cfg(feature = "grammar-extras")]
+        let rule_config = &RuleConfig {
+            aliased_tags: map.aliased_tags(rule_config.rule_name),
+            ..rule_config.clone()
+        };
+        // Rules that only keep their spans keep their contents as well if they contain tags,
+        // but only tags are accessible.
+        #[cfg(feature = "grammar-extras")]
+        let (emission, accessers) = match emission {
+            Emission::Span if accessers.has_tags() => (Emission::Both, accessers.tags()),
+            _ => (emission, accessers),
+        };
         let rule_id = &rule_config.rule_id;
-        let def = rule(rule_config, type_name, &accessers, emission);
+        let body = rule_config
+            .shares_body
+            .then(|| map.insert_body(rule_config, emission));
+        let def = rule(rule_config, type_name, &accessers, emission, body);
         map.insert(def, rule_config.clone());
         let rules = rules_mod();
         (quote! {#root::#rules::#rule_id::<'i>}, accessers)
@@ -672,6 +1281,188 @@ fn process_single_alias<'g>(
     }
 }
 
+/// Whether nothing is skipped between elements of sequences.
+fn no_skip(rule_config: &RuleConfig<'_>) -> bool {
+    rule_config.atomicity == Some(true) || rule_config.skip_rule == Some("")
+}
+
+/// Strings in `!("a" | "b" | ...) ~ ANY`, if it can be lowered to a `CharExcept`.
+///
+/// Only lowered where nothing is skipped between the predicate and `ANY`.
+fn char_except(
+    neg: &OptimizedExpr,
+    any: &OptimizedExpr,
+    rule_config: &RuleConfig<'_>,
+) -> Option<Vec<String>> {
+    if !no_skip(rule_config) {
+        return None;
+    }
+    match (neg, any) {
+        (OptimizedExpr::NegPred(inner), OptimizedExpr::Ident(any)) if any == "ANY" => {
+            let mut strings = Vec::new();
+            let mut current = inner.as_ref();
+            loop {
+                match current {
+                    OptimizedExpr::Choice(lhs, rhs) => match lhs.as_ref() {
+                        OptimizedExpr::Str(string) => {
+                            strings.push(string.clone());
+                            current = rhs;
+                        }
+                        _ => return None,
+                    },
+                    OptimizedExpr::Str(string) => {
+                        strings.push(string.clone());
+                        return Some(strings);
+                    }
+                    _ => return None,
+                }
+            }
+        }
+        _ => None,
+    }
+}
+
+/// Strings in `"a" | "b" | ...`, if it can be lowered to a `StrChoice`.
+///
+/// Only lowered if no string is a prefix of a later one,
+/// so that the longest match is also the first one.
+fn str_choice(choices: &[&OptimizedExpr]) -> Option<Vec<String>> {
+    let strings = choices
+        .iter()
+        .map(|choice| match choice {
+            OptimizedExpr::Str(string) => Some(string.clone()),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let shadowed = strings.iter().enumerate().any(|(i, former)| {
+        strings[i + 1..]
+            .iter()
+            .any(|latter| latter.len() > former.len() && latter.starts_with(former.as_str()))
+    });
+    (!shadowed).then_some(strings)
+}
+
+/// Keyword and boundary in `^"keyword" ~ !boundary`, if it can be lowered to a `Keyword`.
+///
+/// Only lowered where nothing is skipped between the keyword and the predicate.
+fn keyword<'g>(
+    insens: &'g OptimizedExpr,
+    neg: &'g OptimizedExpr,
+    rule_config: &RuleConfig<'_>,
+) -> Option<(&'g str, &'g OptimizedExpr)> {
+    if !no_skip(rule_config) {
+        return None;
+    }
+    match (insens, neg) {
+        (OptimizedExpr::Insens(content), OptimizedExpr::NegPred(boundary)) => {
+            Some((content.as_str(), boundary.as_ref()))
+        }
+        _ => None,
+    }
+}
+
+/// Terminator and item in `(!terminator ~ item)*`, if it can be lowered to a `RepUntil`.
+///
+/// Only lowered with `lower_rep_until` where nothing is skipped between the predicate and the item,
+/// and items don't recover.
+fn rep_until<'g>(
+    inner: &'g OptimizedExpr,
+    rule_config: &RuleConfig<'g>,
+    config: Config,
+) -> Option<(&'g OptimizedExpr, &'g OptimizedExpr)> {
+    if !config.lower_rep_until
+        || !no_skip(rule_config)
+        || recovery_sync(inner, rule_config).is_some()
+    {
+        return None;
+    }
+    match inner {
+        OptimizedExpr::Seq(neg, item) => match neg.as_ref() {
+            OptimizedExpr::NegPred(terminator) => Some((terminator.as_ref(), item.as_ref())),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The rule that ends a sequence, if elements of a repetition of it recover at the rule.
+fn recovery_sync<'g>(
+    expr: &'g OptimizedExpr,
+    rule_config: &RuleConfig<'g>,
+) -> Option<&'g OptimizedExpr> {
+    let mut current = match expr {
+        OptimizedExpr::Seq(_, rhs) => rhs.as_ref(),
+        _ => return None,
+    };
+    while let OptimizedExpr::Seq(_, rhs) = current {
+        current = rhs;
+    }
+    match current {
+        OptimizedExpr::Ident(id) if rule_config.recover_at.contains(id.as_str()) => Some(current),
+        _ => None,
+    }
+}
+
+/// Path from the content of a rule to its repetition of items, if items can be reparsed alone.
+///
+/// The rule should be either `item*` or a sequence of `item*` with `SOI` and `EOI`,
+/// where `item` is a rule with a span.
+fn reparse_items(expr: &OptimizedExpr, spanned: &BTreeSet<&str>) -> Option<TokenStream> {
+    let is_items = |expr: &OptimizedExpr| matches!(expr, OptimizedExpr::Rep(inner) if matches!(inner.as_ref(), OptimizedExpr::Ident(id) if spanned.contains(id.as_str())));
+    if is_items(expr) {
+        return Some(quote! {.content});
+    }
+    let mut elements = vec![];
+    let mut current = expr;
+    while let OptimizedExpr::Seq(lhs, rhs) = current {
+        elements.push(lhs.as_ref());
+        current = rhs;
+    }
+    elements.push(current);
+    let mut items = elements.iter().enumerate().filter(|(_, e)| is_items(e));
+    let (index, _) = items.next()?;
+    let others_are_boundaries = elements.iter().enumerate().all(|(i, e)| {
+        i == index || matches!(e, OptimizedExpr::Ident(id) if id == "SOI" || id == "EOI")
+    });
+    if items.next().is_some() || !others_are_boundaries {
+        return None;
+    }
+    let index = Index::from(index);
+    Some(quote! {.content.#index.matched.content})
+}
+
+/// The item rule of a rule that can be parsed item by item,
+/// and whether the items are preceded by `SOI` and followed by `EOI`.
+///
+/// The rule should be `item*`, optionally preceded by `SOI` and followed by `EOI`,
+/// where `item` is a rule defined in the grammar.
+fn streamed_items<'g>(
+    expr: &'g OptimizedExpr,
+    defined: &BTreeSet<&str>,
+) -> Option<(&'g str, bool, bool)> {
+    let mut elements = vec![];
+    let mut current = expr;
+    while let OptimizedExpr::Seq(lhs, rhs) = current {
+        elements.push(lhs.as_ref());
+        current = rhs;
+    }
+    elements.push(current);
+    let is =
+        |expr: &OptimizedExpr, name: &str| matches!(expr, OptimizedExpr::Ident(id) if id == name);
+    let soi = is(elements[0], "SOI");
+    let eoi = elements.len() > 1 && is(elements[elements.len() - 1], "EOI");
+    let items = &elements[soi as usize..elements.len() - eoi as usize];
+    match items {
+        [OptimizedExpr::Rep(inner)] => match inner.as_ref() {
+            OptimizedExpr::Ident(id) if defined.contains(id.as_str()) => {
+                Some((id.as_str(), soi, eoi))
+            }
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
 /// Returns type name.
 fn generate_graph_node<'g>(
     expr: &'g OptimizedExpr,
@@ -708,8 +1499,13 @@ fn generate_graph_node<'g>(
             process_single_alias(
                 map,
                 rule_config,
-                quote! {
-                    #root::#generics::Str::<#root::#wrapper>
+                match config.tokenize_literals {
+                    true => quote! {
+                        #root::#generics::StrSpanned::<'i, #root::#wrapper>
+                    },
+                    false => quote! {
+                        #root::#generics::Str::<#root::#wrapper>
+                    },
                 },
                 Accesser::new(),
                 root,
@@ -719,11 +1515,15 @@ fn generate_graph_node<'g>(
         }
         OptimizedExpr::Insens(content) => {
             let wrapper = map.insert_string_wrapper(content.as_str());
+            let insens = match config.unicode_case_insensitive {
+                true => quote! {InsensUnicode},
+                false => quote! {Insens},
+            };
             process_single_alias(
                 map,
                 rule_config,
                 quote! {
-                    #root::#generics::Insens::<'i, #root::#wrapper>
+                    #root::#generics::#insens::<'i, #root::#wrapper>
                 },
                 Accesser::new(),
                 root,
@@ -736,10 +1536,10 @@ fn generate_graph_node<'g>(
             rule_config,
             match end {
                 Some(end) => quote! {
-                    #root::#generics::PeekSlice2::<#start, #end>
+                    #root::#generics::PeekSlice2::<'i, #start, #end>
                 },
                 None => quote! {
-                    #root::#generics::PeekSlice1::<#start>
+                    #root::#generics::PeekSlice1::<'i, #start>
                 },
             },
             Accesser::new(),
@@ -750,13 +1550,18 @@ fn generate_graph_node<'g>(
         OptimizedExpr::Push(expr) => {
             let (inner, accesser) =
                 generate_graph_node(expr, rule_config, map, false, emission, config, root);
+            let accesser = if config.emit_rule_reference {
+                Accesser::from_span(&rule_config.stack_accessers.pushed).join(accesser.content())
+            } else {
+                accesser.content()
+            };
             process_single_alias(
                 map,
                 rule_config,
                 quote! {
-                    #root::#generics::Push::<#inner>
+                    #root::#generics::Push::<'i, #inner>
                 },
-                accesser.content(),
+                accesser,
                 root,
                 emission,
                 explicit,
@@ -779,6 +1584,12 @@ fn generate_graph_node<'g>(
         OptimizedExpr::Range(start, end) => {
             let start = start.chars().next().unwrap();
             let end = end.chars().next().unwrap();
+            if start > end {
+                panic!(
+                    "Range {:?}..{:?} in rule `{}` never matches, as its start is greater than its end.",
+                    start, end, rule_config.rule_name
+                );
+            }
             process_single_alias(
                 map,
                 rule_config,
@@ -804,7 +1615,15 @@ fn generate_graph_node<'g>(
                 (false, false) => quote! {},
             };
             let accessers = if config.emit_rule_reference {
-                Accesser::from_rule(id, id.as_str(), has_life_time, has_skip)
+                let accessers = Accesser::from_rule(id, id.as_str(), has_life_time, has_skip);
+                match id.as_str() {
+                    _ if rule_config.defined.contains(id.as_str()) => accessers,
+                    "POP" => accessers.join(Accesser::from_span(&rule_config.stack_accessers.pop)),
+                    "PEEK" => {
+                        accessers.join(Accesser::from_span(&rule_config.stack_accessers.peek))
+                    }
+                    _ => accessers,
+                }
             } else {
                 Accesser::new()
             };
@@ -855,11 +1674,59 @@ fn generate_graph_node<'g>(
         }
         OptimizedExpr::Seq(_, _) => {
             let vec = walk!(expr, Seq);
-            let mut types = Vec::<TokenStream>::with_capacity(vec.len());
+            let mut children = Vec::<(TokenStream, Accesser<'g>)>::with_capacity(vec.len());
+            let mut iter = vec.into_iter().peekable();
+            while let Some(expr) = iter.next() {
+                let next = iter.peek().copied();
+                let except = next.and_then(|next| char_except(expr, next, rule_config));
+                // `Keyword` matches like `Insens`.
+                let keyword = next
+                    .filter(|_| !config.unicode_case_insensitive)
+                    .and_then(|next| keyword(expr, next, rule_config));
+                let child = match (except, keyword) {
+                    (Some(strings), _) => {
+                        iter.next();
+                        let wrapper = map.insert_string_array_wrapper(&strings);
+                        let char_except = quote! {#root::#generics::CharExcept::<#root::#wrapper>};
+                        (char_except, Accesser::new())
+                    }
+                    (None, Some((content, boundary))) => {
+                        iter.next();
+                        let wrapper = map.insert_string_wrapper(content);
+                        let (boundary, _) = generate_graph_node(
+                            boundary,
+                            rule_config,
+                            map,
+                            false,
+                            emission,
+                            config,
+                            root,
+                        );
+                        let keyword =
+                            quote! {#root::#generics::Keyword::<'i, #root::#wrapper, #boundary>};
+                        (keyword, Accesser::new())
+                    }
+                    (None, None) => {
+                        generate_graph_node(expr, rule_config, map, false, emission, config, root)
+                    }
+                };
+                children.push(child);
+            }
+            if children.len() == 1 {
+                let (child, accesser) = children.pop().unwrap();
+                return process_single_alias(
+                    map,
+                    rule_config,
+                    child,
+                    accesser,
+                    root,
+                    emission,
+                    explicit,
+                );
+            }
+            let mut types = Vec::<TokenStream>::with_capacity(children.len());
             let mut accesser = Accesser::new();
-            for (i, expr) in vec.into_iter().enumerate() {
-                let (child, acc) =
-                    generate_graph_node(expr, rule_config, map, false, emission, config, root);
+            for (i, (child, acc)) in children.into_iter().enumerate() {
                 types.push(child);
                 accesser = accesser.join(acc.content_i(i));
             }
@@ -867,9 +1734,13 @@ fn generate_graph_node<'g>(
             map.record_seq(types.len());
 
             let pest_typed = pest_typed();
-            let args = types.iter().map(
-                |t| quote! {(#pest_typed::predefined_node::Skipped<#t, #root::generics::Skipped<'i>, #skip>)},
-            );
+            let ignore = match rule_config.skip_rule {
+                Some(skip_rule) => overridden_ignore(root, skip_rule),
+                None => quote! {#root::generics::Skipped<'i>},
+            };
+            let args = types
+                .iter()
+                .map(|t| quote! {#pest_typed::predefined_node::Skipped<#t, #ignore, #skip>});
             process_single_alias(
                 map,
                 rule_config,
@@ -882,6 +1753,22 @@ fn generate_graph_node<'g>(
         }
         OptimizedExpr::Choice(_, _) => {
             let vec = walk!(expr, Choice);
+            if let Some(strings) =
+                str_choice(&vec).filter(|_| config.lower_str_choices && !config.tokenize_literals)
+            {
+                let wrapper = map.insert_string_array_wrapper(&strings);
+                return process_single_alias(
+                    map,
+                    rule_config,
+                    quote! {
+                        #root::#generics::StrChoice::<#root::#wrapper>
+                    },
+                    Accesser::new(),
+                    root,
+                    emission,
+                    explicit,
+                );
+            }
             let mut types = Vec::<TokenStream>::with_capacity(vec.len());
             let mut accesser = Accesser::new();
             for (i, expr) in vec.into_iter().enumerate() {
@@ -903,6 +1790,21 @@ fn generate_graph_node<'g>(
             )
         }
         OptimizedExpr::Opt(inner) => {
+            if let (OptimizedExpr::Str(content), false) = (inner.as_ref(), config.tokenize_literals)
+            {
+                let wrapper = map.insert_string_wrapper(content.as_str());
+                return process_single_alias(
+                    map,
+                    rule_config,
+                    quote! {
+                        #root::#generics::Presence::<#root::#wrapper>
+                    },
+                    Accesser::new(),
+                    root,
+                    emission,
+                    explicit,
+                );
+            }
             let (inner_name, accessers) =
                 generate_graph_node(inner, rule_config, map, false, emission, config, root);
             let accessers = accessers.optional();
@@ -918,12 +1820,55 @@ fn generate_graph_node<'g>(
             )
         }
         OptimizedExpr::Rep(inner) => {
-            let (inner_name, accessers) =
+            if let Some((terminator, item)) = rep_until(inner, rule_config, config) {
+                let (terminator, _) = generate_graph_node(
+                    terminator,
+                    rule_config,
+                    map,
+                    false,
+                    emission,
+                    config,
+                    root,
+                );
+                let (item, accessers) =
+                    generate_graph_node(item, rule_config, map, false, emission, config, root);
+                let pest_typed = pest_typed();
+                return process_single_alias(
+                    map,
+                    rule_config,
+                    quote! { #pest_typed::predefined_node::RepUntil::<'i, #item, #terminator> },
+                    accessers.contents(),
+                    root,
+                    emission,
+                    explicit,
+                );
+            }
+            let (inner_name, accessers) =
                 generate_graph_node(inner, rule_config, map, false, emission, config, root);
+            let (inner_name, accessers) = match recovery_sync(inner, rule_config) {
+                Some(sync) => {
+                    let (sync_name, _) =
+                        generate_graph_node(sync, rule_config, map, false, emission, config, root);
+                    let pest_typed = pest_typed();
+                    (
+                        quote! { #pest_typed::predefined_node::Recover::<'i, #inner_name, #sync_name> },
+                        accessers.recovered(),
+                    )
+                }
+                None => (inner_name, accessers),
+            };
+            let rep = match rule_config.skip_rule {
+                Some(skip_rule) => {
+                    let pest_typed = pest_typed();
+                    let ignore = overridden_ignore(root, skip_rule);
+                    quote! { #pest_typed::predefined_node::Rep::<#inner_name, #ignore, #skip> }
+                }
+                None => quote! { #root::#generics::Rep::<'i, #skip, #inner_name> },
+            };
             process_single_alias(
                 map,
                 rule_config,
-                quote! { #root::#generics::Rep::<'i, #skip, #inner_name> },
+                rep,
                 accessers.contents(),
                 root,
                 emission,
@@ -934,10 +1879,30 @@ fn generate_graph_node<'g>(
         OptimizedExpr::RepOnce(inner) => {
             let (inner_name, accessers) =
                 generate_graph_node(inner, rule_config, map, false, emission, config, root);
+            let (inner_name, accessers) = match recovery_sync(inner, rule_config) {
+                Some(sync) => {
+                    let (sync_name, _) =
+                        generate_graph_node(sync, rule_config, map, false, emission, config, root);
+                    let pest_typed = pest_typed();
+                    (
+                        quote! { #pest_typed::predefined_node::Recover::<'i, #inner_name, #sync_name> },
+                        accessers.recovered(),
+                    )
+                }
+                None => (inner_name, accessers),
+            };
+            let rep = match rule_config.skip_rule {
+                Some(skip_rule) => {
+                    let pest_typed = pest_typed();
+                    let ignore = overridden_ignore(root, skip_rule);
+                    quote! { #pest_typed::predefined_node::RepOnce::<#inner_name, #ignore, #skip> }
+                }
+                None => quote! { #root::#generics::RepOnce::<'i, #skip, #inner_name> },
+            };
             process_single_alias(
                 map,
                 rule_config,
-                quote! { #root::#generics::RepOnce::<'i, #skip, #inner_name> },
+                rep,
                 accessers.contents(),
                 root,
                 emission,
@@ -947,29 +1912,40 @@ fn generate_graph_node<'g>(
         #[cfg(feature = "grammar-extras")]
         OptimizedExpr::NodeTag(inner_expr, tag) => {
             if config.emit_tagged_node_reference {
-                let tag_id = ident(tag.as_str());
+                // The rule struct is defined after the tag is recorded,
+                // so that a tag around the whole rule is aliased to the content of the rule.
                 let (inner, accesser) = generate_graph_node(
                     inner_expr,
                     rule_config,
                     map,
-                    explicit,
+                    false,
                     emission,
                     config,
                     root,
                 );
+                // Inside rules that only keep their spans, such as atomic rules,
+                // tags keep only their spans as well.
+                let (inner, accesser) = match emission {
+                    Emission::Span => (
+                        quote! { #root::#generics::SpanOnly::<'i, #inner> },
+                        Accesser::new(),
+                    ),
+                    _ => (inner, accesser),
+                };
                 map.insert_tag(
-                    &rule_config.rule_id,
-                    &tag_id,
+                    rule_config.rule_name,
+                    tag.as_str(),
                     inner.clone(),
                     accesser.clone(),
                 );
                 let new_accesser =
                     Accesser::from_tag(rule_config.rule_name, tag.as_str(), inner.clone());
-                if config.truncate_accesser_at_node_tag {
-                    (inner, new_accesser)
+                let accesser = if config.truncate_accesser_at_node_tag {
+                    new_accesser
                 } else {
-                    (inner, new_accesser.join(accesser))
-                }
+                    new_accesser.join(accesser)
+                };
+                process_single_alias(map, rule_config, inner, accesser, root, emission, explicit)
             } else {
                 let (inner, accesser) = generate_graph_node(
                     inner_expr,
@@ -986,24 +1962,51 @@ fn generate_graph_node<'g>(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn generate_graph<'g: 'f, 'f>(
     rules: &'g [OptimizedRule],
     defined: &'g BTreeSet<&'g str>,
+    spanned: &'g BTreeSet<&'g str>,
+    recover_at: &'g BTreeSet<&'g str>,
     not_boxed: &'f BTreeSet<&'g str>,
+    recursion_heads: &'f BTreeSet<&'g str>,
     builtins_without_lifetime: &'g BTreeSet<&'g str>,
+    accesser_docs: &'g BTreeMap<&'g str, Vec<String>>,
+    skip_rules: &'f BTreeMap<&'g str, &'g str>,
+    span_only: &BTreeSet<String>,
+    min_lens: &BTreeMap<&'g str, usize>,
+    error_labels: &'f BTreeMap<&'g str, &'g str>,
+    rule_references: &BTreeSet<String>,
+    stack_accessers: &'g StackAccessers,
     config: Config,
     doc: &'g DocComment,
 ) -> Output<'g> {
     let mut res = Output::new();
+    let reparse = ident(&escape("reparse", defined));
+    let parse_iter = ident(&escape("parse_iter", defined));
+    let constructor = ident(&escape("new", defined));
+    // Rules in the types of skipped contents.
+    let skipped: BTreeSet<&str> = ["WHITESPACE", "COMMENT"]
+        .into_iter()
+        .chain(skip_rules.values().copied())
+        .collect();
     for rule in rules.iter() {
         let rule_name = rule.name.as_str();
         let (atomicity, emission) = match rule.ty {
             RuleType::Normal => (None, Emission::Both),
+            RuleType::Silent if config.silent_spans => (None, Emission::SpannedExpression),
             RuleType::Silent => (None, Emission::Expression),
             RuleType::NonAtomic => (Some(false), Emission::Both),
             RuleType::CompoundAtomic => (Some(true), Emission::Both),
+            RuleType::Atomic if config.atomic_content => (Some(true), Emission::Both),
             RuleType::Atomic => (Some(true), Emission::Span),
         };
+        // Inner expression is still parsed with the same atomicity, but only the span is kept.
+        let emission = if span_only.contains(rule_name) {
+            Emission::Span
+        } else {
+            emission
+        };
         let atomicity_doc = match atomicity {
             Some(true) => "Atomic rule.",
             Some(false) => "Non-atomic rule.",
@@ -1015,15 +2018,61 @@ fn generate_graph<'g: 'f, 'f>(
         );
         let boxed = !config.box_only_if_needed || !not_boxed.contains(rule_name);
         let rule_doc = doc.line_docs.get(rule_name).map(|s| s.as_str());
-        let rule_config = RuleConfig {
+        let rule_source = doc.rule_sources.get(rule_name).map(|s| s.as_str());
+        let mut rule_config = RuleConfig {
             atomicity,
             boxed,
             rule_id: ident(rule_name),
             rule_name,
             rule_desc,
             rule_doc,
+            rule_source,
+            skip_rule: skip_rules.get(rule_name).copied(),
             defined,
+            spanned,
+            recover_at,
             builtins_without_lifetime,
+            accesser_docs,
+            min_len: min_lens[rule_name],
+            error_label: error_labels.get(rule_name).copied(),
+            recursion_limit: config.recursion_limit,
+            counts_recursion: recursion_heads.contains(rule_name),
+            shares_body: !recursion_heads.contains(rule_name) && !skipped.contains(rule_name),
+            stack_accessers,
+            reparse: match emission {
+                Emission::Both => {
+                    reparse_items(&rule.expr, spanned).map(|path| (reparse.clone(), path))
+                }
+                Emission::Expression | Emission::SpannedExpression | Emission::Span => None,
+            },
+            parse_iter: match (emission, atomicity) {
+                (Emission::Both, None | Some(false)) => streamed_items(&rule.expr, defined)
+                    .map(|(item, soi, eoi)| (parse_iter.clone(), item, soi, eoi)),
+                _ => None,
+            },
+            constructor: constructor.clone(),
+            default: match &rule.expr {
+                OptimizedExpr::Rep(_) => true,
+                // `"..."?` is matched by `Presence`, unless literals are tokenized.
+                OptimizedExpr::Opt(inner) => {
+                    config.tokenize_literals || !matches!(inner.as_ref(), OptimizedExpr::Str(_))
+                }
+                _ => false,
+            },
+            bounded: false,
+            #[cfg(feature = "grammar-extras")]
+            aliased_tags: BTreeSet::new(),
+        };
+        // Repetitions chosen here implement `BoundWrapper`, except `RepUntil`.
+        rule_config.bounded = match &rule.expr {
+            OptimizedExpr::Rep(inner) => rep_until(inner, &rule_config, config).is_none(),
+            #[cfg(feature = "grammar-extras")]
+            OptimizedExpr::RepOnce(_) => true,
+            _ => false,
+        };
+        let config = Config {
+            emit_rule_reference: config.emit_rule_reference || rule_references.contains(rule_name),
+            ..config
         };
         generate_graph_node(
             &rule.expr,
@@ -1038,6 +2087,100 @@ fn generate_graph<'g: 'f, 'f>(
     res
 }
 
+/// Collect doc comments and expressions of rules, which will be copied to accessers.
+fn collect_accesser_docs<'g>(
+    rules: &'g [OptimizedRule],
+    doc: &'g DocComment,
+) -> BTreeMap<&'g str, Vec<String>> {
+    rules
+        .iter()
+        .map(|rule| {
+            let rule_name = rule.name.as_str();
+            let mut lines = vec![];
+            if let Some(rule_doc) = doc.line_docs.get(rule_name) {
+                lines.push(String::new());
+                lines.push(format!("`{}`: {}", rule_name, rule_doc));
+            }
+            lines.push(String::new());
+            lines.push(format!("Corresponds to expression: `{}`.", rule.expr));
+            (rule_name, lines)
+        })
+        .collect()
+}
+
+/// Minimum number of bytes that a built-in rule consumes.
+fn builtin_min_len(rule_name: &str) -> usize {
+    match rule_name {
+        "ANY"
+        | "ASCII_DIGIT"
+        | "ASCII_NONZERO_DIGIT"
+        | "ASCII_BIN_DIGIT"
+        | "ASCII_OCT_DIGIT"
+        | "ASCII_HEX_DIGIT"
+        | "ASCII_ALPHA_LOWER"
+        | "ASCII_ALPHA_UPPER"
+        | "ASCII_ALPHA"
+        | "ASCII_ALPHANUMERIC"
+        | "ASCII"
+        | "NEWLINE" => 1,
+        _ if unicode_property_names().any(|name| name == rule_name) => 1,
+        // `SOI`, `EOI`, stack operations and undefined `WHITESPACE` or `COMMENT`.
+        _ => 0,
+    }
+}
+
+/// Minimum number of bytes that an expression consumes, given those of rules.
+fn expr_min_len(expr: &OptimizedExpr, rules: &BTreeMap<&str, usize>) -> usize {
+    match expr {
+        OptimizedExpr::Str(string) => string.len(),
+        // Unicode case folding may change the length in bytes, such as from `K` (Kelvin sign) to `k`.
+        // No case variant of an ASCII character is shorter, while others are counted as `0`.
+        OptimizedExpr::Insens(string) => string.chars().filter(char::is_ascii).count(),
+        OptimizedExpr::Range(start, _) => start.chars().next().map_or(0, char::len_utf8),
+        OptimizedExpr::Ident(rule_name) => match rules.get(rule_name.as_str()) {
+            Some(min_len) => *min_len,
+            None => builtin_min_len(rule_name),
+        },
+        OptimizedExpr::PeekSlice(_, _) | OptimizedExpr::Skip(_) => 0,
+        OptimizedExpr::PosPred(_) | OptimizedExpr::NegPred(_) => 0,
+        OptimizedExpr::Seq(lhs, rhs) => {
+            expr_min_len(lhs, rules).saturating_add(expr_min_len(rhs, rules))
+        }
+        OptimizedExpr::Choice(lhs, rhs) => expr_min_len(lhs, rules).min(expr_min_len(rhs, rules)),
+        OptimizedExpr::Opt(_) | OptimizedExpr::Rep(_) => 0,
+        #[cfg(feature = "grammar-extras")]
+        OptimizedExpr::RepOnce(expr) => expr_min_len(expr, rules),
+        OptimizedExpr::Push(expr) | OptimizedExpr::RestoreOnErr(expr) => expr_min_len(expr, rules),
+        #[cfg(feature = "grammar-extras")]
+        OptimizedExpr::NodeTag(expr, _) => expr_min_len(expr, rules),
+    }
+}
+
+/// Minimum number of bytes that each rule consumes.
+///
+/// Iterates from `0` for all rules, so every intermediate result is a lower bound.
+/// Iteration is capped for rules in cycles that never terminate, such as `a = { "a" ~ a }`,
+/// and results are capped at [`isize::MAX`], the maximum length of any input,
+/// as they may grow exponentially, such as `a = { "(" ~ a ~ a ~ ")" }`.
+fn collect_min_lens(rules: &[OptimizedRule]) -> BTreeMap<&str, usize> {
+    let mut res: BTreeMap<&str, usize> = rules.iter().map(|rule| (rule.name.as_str(), 0)).collect();
+    for _ in 0..=rules.len() {
+        let mut changed = false;
+        for rule in rules {
+            let min_len = expr_min_len(&rule.expr, &res).min(isize::MAX as usize);
+            let entry = res.get_mut(rule.name.as_str()).unwrap();
+            if *entry != min_len {
+                *entry = min_len;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    res
+}
+
 /// Whether skipped rules has been defined.
 #[derive(Clone, Copy)]
 struct Implicit {
@@ -1056,14 +2199,28 @@ impl<'s> From<&'s [OptimizedRule]> for Implicit {
     }
 }
 
-fn collect_used_rule<'s>(rule: &'s OptimizedRule, implicit: Implicit, res: &mut BTreeSet<&'s str>) {
+fn collect_used_rule<'s>(
+    rule: &'s OptimizedRule,
+    implicit: Implicit,
+    skip_rules: &BTreeMap<&'s str, &'s str>,
+    res: &mut BTreeSet<&'s str>,
+) {
     //
     if rule.ty == RuleType::Normal {
-        if implicit.comment {
-            res.insert("COMMENT");
-        }
-        if implicit.whitespace {
-            res.insert("WHITESPACE");
+        match skip_rules.get(rule.name.as_str()) {
+            Some(skip_rule) => {
+                if !skip_rule.is_empty() {
+                    res.insert(skip_rule);
+                }
+            }
+            None => {
+                if implicit.comment {
+                    res.insert("COMMENT");
+                }
+                if implicit.whitespace {
+                    res.insert("WHITESPACE");
+                }
+            }
         }
     }
     let mut exprs = vec![&rule.expr];
@@ -1090,22 +2247,57 @@ fn collect_used_rule<'s>(rule: &'s OptimizedRule, implicit: Implicit, res: &mut
     }
 }
 
-fn collect_used_rules<'s>(rules: &'s [OptimizedRule], implicit: Implicit) -> BTreeSet<&'s str> {
+fn collect_used_rules<'s>(
+    rules: &'s [OptimizedRule],
+    implicit: Implicit,
+    skip_rules: &BTreeMap<&'s str, &'s str>,
+) -> BTreeSet<&'s str> {
     let mut res = BTreeSet::<&'s str>::new();
     for rule in rules {
-        collect_used_rule(rule, implicit, &mut res);
+        collect_used_rule(rule, implicit, skip_rules, &mut res);
     }
     res
 }
 
+/// Rules directly referenced by each rule, in the order they are defined.
+///
+/// Only defined rules and `EOI` are listed, and implicit `WHITESPACE` and `COMMENT` are not.
+pub(crate) fn collect_dependencies(rules: &[OptimizedRule]) -> Vec<(&str, Vec<&str>)> {
+    let implicit = Implicit {
+        whitespace: false,
+        comment: false,
+    };
+    let skip_rules = BTreeMap::new();
+    let order = |name: &str| {
+        rules
+            .iter()
+            .position(|rule| rule.name == name)
+            .unwrap_or(rules.len())
+    };
+    rules
+        .iter()
+        .map(|rule| {
+            let mut used = BTreeSet::new();
+            collect_used_rule(rule, implicit, &skip_rules, &mut used);
+            let mut used: Vec<&str> = used
+                .into_iter()
+                .filter(|name| *name == "EOI" || rules.iter().any(|rule| rule.name == *name))
+                .collect();
+            used.sort_by_key(|name| order(name));
+            (rule.name.as_str(), used)
+        })
+        .collect()
+}
+
 fn collect_reachability<'g>(
     rules: &'g [OptimizedRule],
     implicit: Implicit,
+    skip_rules: &BTreeMap<&'g str, &'g str>,
 ) -> BTreeMap<&'g str, BTreeSet<&'g str>> {
     let mut res: BTreeMap<&'g str, BTreeSet<&'g str>> = BTreeMap::new();
     for rule in rules {
         let entry = res.entry(rule.name.as_str()).or_default();
-        collect_used_rule(rule, implicit, entry);
+        collect_used_rule(rule, implicit, skip_rules, entry);
     }
     for _ in 0..rules.len() {
         for rule in rules {
@@ -1125,45 +2317,280 @@ fn collect_reachability<'g>(
     res
 }
 
+/// Rules that count towards the recursion limit.
+///
+/// They are the rules that a reference leads back to
+/// in a depth-first search through references in the order of the grammar.
+/// Every recursion cycle goes through one of them,
+/// and a cycle of several rules usually goes through only one.
+fn collect_recursion_heads<'g>(
+    rules: &'g [OptimizedRule],
+    implicit: Implicit,
+    skip_rules: &BTreeMap<&'g str, &'g str>,
+) -> BTreeSet<&'g str> {
+    fn visit<'g>(
+        rule: &'g str,
+        references: &BTreeMap<&'g str, BTreeSet<&'g str>>,
+        on_path: &mut BTreeMap<&'g str, bool>,
+        res: &mut BTreeSet<&'g str>,
+    ) {
+        on_path.insert(rule, true);
+        for &referenced in references.get(rule).into_iter().flatten() {
+            match on_path.get(referenced) {
+                Some(true) => {
+                    res.insert(referenced);
+                }
+                Some(false) => (),
+                None => visit(referenced, references, on_path, res),
+            }
+        }
+        on_path.insert(rule, false);
+    }
+    let references: BTreeMap<&'g str, BTreeSet<&'g str>> = rules
+        .iter()
+        .map(|rule| {
+            let mut used = BTreeSet::new();
+            collect_used_rule(rule, implicit, skip_rules, &mut used);
+            (rule.name.as_str(), used)
+        })
+        .collect();
+    // Visited rule -> whether it's on the current search path.
+    let mut on_path = BTreeMap::new();
+    let mut res = BTreeSet::new();
+    for rule in rules {
+        if !on_path.contains_key(rule.name.as_str()) {
+            visit(rule.name.as_str(), &references, &mut on_path, &mut res);
+        }
+    }
+    res
+}
+
+/// Whether nothing can be skipped in the grammar,
+/// that is, all rules are atomic and neither `WHITESPACE` nor `COMMENT` is defined.
+fn is_lean(rules: &[OptimizedRule], implicit: Implicit) -> bool {
+    !implicit.whitespace
+        && !implicit.comment
+        && rules
+            .iter()
+            .all(|rule| matches!(rule.ty, RuleType::Atomic | RuleType::CompoundAtomic))
+}
+
+/// Resolve the skip rule of each rule.
+///
+/// Rules referenced by a rule with a skip rule inherit it,
+/// unless they are atomic or have been given their own skip rule.
+fn collect_skip_rules<'g>(
+    rules: &'g [OptimizedRule],
+    skip_rules: &'g BTreeMap<String, String>,
+) -> BTreeMap<&'g str, &'g str> {
+    let by_name: BTreeMap<&'g str, &'g OptimizedRule> = rules
+        .iter()
+        .map(|rule| (rule.name.as_str(), rule))
+        .collect();
+    for (rule_name, skip_rule) in skip_rules {
+        if !by_name.contains_key(rule_name.as_str()) {
+            panic!("Rule `{}` in `skip_rules` is not defined.", rule_name);
+        }
+        if !skip_rule.is_empty() && !by_name.contains_key(skip_rule.as_str()) {
+            panic!(
+                "Skip rule `{}` of rule `{}` is not defined.",
+                skip_rule, rule_name
+            );
+        }
+    }
+    let implicit = Implicit {
+        whitespace: false,
+        comment: false,
+    };
+    let none = BTreeMap::new();
+    let referenced = |rule: &'g OptimizedRule| {
+        let mut res = BTreeSet::new();
+        collect_used_rule(rule, implicit, &none, &mut res);
+        res.into_iter()
+            .filter_map(|name| by_name.get(name).copied())
+            .filter(|rule| {
+                matches!(
+                    rule.ty,
+                    RuleType::Normal | RuleType::Silent | RuleType::NonAtomic
+                )
+            })
+    };
+
+    let mut res: BTreeMap<&'g str, &'g str> = skip_rules
+        .iter()
+        .map(|(rule_name, skip_rule)| (rule_name.as_str(), skip_rule.as_str()))
+        .collect();
+    let mut stack: Vec<(&'g str, &'g str)> = res.iter().map(|(k, v)| (*k, *v)).collect();
+    while let Some((rule_name, skip_rule)) = stack.pop() {
+        for inner in referenced(by_name[rule_name]) {
+            let inner_name = inner.name.as_str();
+            if skip_rules.contains_key(inner_name) {
+                continue;
+            }
+            match res.entry(inner_name) {
+                btree_map::Entry::Vacant(entry) => {
+                    entry.insert(skip_rule);
+                    stack.push((inner_name, skip_rule));
+                }
+                btree_map::Entry::Occupied(entry) => {
+                    if *entry.get() != skip_rule {
+                        panic!(
+                            "Rule `{}` inherits different skip rules `{}` and `{}`. Specify its skip rule in `skip_rules`.",
+                            inner_name,
+                            entry.get(),
+                            skip_rule
+                        );
+                    }
+                }
+            }
+        }
+    }
+    for rule in rules {
+        if res.contains_key(rule.name.as_str())
+            || rule.ty == RuleType::Atomic
+            || rule.ty == RuleType::CompoundAtomic
+        {
+            continue;
+        }
+        for inner in referenced(rule) {
+            let inner_name = inner.name.as_str();
+            if let (Some(skip_rule), false) =
+                (res.get(inner_name), skip_rules.contains_key(inner_name))
+            {
+                panic!(
+                    "Rule `{}` inherits skip rule `{}`, but is also referenced by `{}`, which uses the default skip rule. Specify its skip rule in `skip_rules`.",
+                    inner_name, skip_rule, rule.name
+                );
+            }
+        }
+    }
+    res
+}
+
+#[allow(clippy::too_many_arguments)]
 pub(crate) fn generate_typed_pair_from_rule(
     rules: &[OptimizedRule],
     doc: &DocComment,
     config: Config,
+    skip_rules: &BTreeMap<String, String>,
+    span_only: &BTreeSet<String>,
+    recover_at: &BTreeSet<String>,
+    error_labels: &BTreeMap<String, String>,
+    rule_references: &BTreeSet<String>,
+    mod_name: &Ident,
 ) -> TokenStream {
     let pest_typed = pest_typed();
 
     let defined_rules: BTreeSet<&str> = rules.iter().map(|rule| rule.name.as_str()).collect();
+    let spanned_rules: BTreeSet<&str> = rules
+        .iter()
+        .filter(|rule| rule.ty != RuleType::Silent)
+        .map(|rule| rule.name.as_str())
+        .collect();
+
+    for rule_name in recover_at {
+        if !defined_rules.contains(rule_name.as_str()) {
+            panic!("Rule `{}` in `recover_at` is not defined.", rule_name);
+        }
+    }
+    let recover_at: BTreeSet<&str> = recover_at.iter().map(String::as_str).collect();
+
+    for rule_name in span_only {
+        match rules.iter().find(|rule| &rule.name == rule_name) {
+            None => panic!("Rule `{}` in `span_only` is not defined.", rule_name),
+            Some(rule) if rule.ty == RuleType::Silent => {
+                panic!("Silent rule `{}` can't be span-only.", rule_name)
+            }
+            Some(_) => (),
+        }
+    }
+
+    for rule_name in error_labels.keys() {
+        match rules.iter().find(|rule| &rule.name == rule_name) {
+            None => panic!("Rule `{}` in `error_label` is not defined.", rule_name),
+            Some(rule) if rule.ty == RuleType::Silent => {
+                panic!("Silent rule `{}` can't have an error label.", rule_name)
+            }
+            Some(_) => (),
+        }
+    }
+    for rule_name in rule_references {
+        if !defined_rules.contains(rule_name.as_str()) {
+            panic!(
+                "Rule `{}` in `emit_rule_reference` is not defined.",
+                rule_name
+            );
+        }
+    }
+
+    let error_labels: BTreeMap<&str, &str> = error_labels
+        .iter()
+        .map(|(rule_name, label)| (rule_name.as_str(), label.as_str()))
+        .collect();
 
     let implicit = Implicit::from(rules);
 
-    let referenced_rules = collect_used_rules(rules, implicit);
+    let mut skip_rules = collect_skip_rules(rules, skip_rules);
+
+    // Nothing is ever skipped, so we use `Empty` directly instead of generic aliases.
+    let lean = is_lean(rules, implicit);
+    if lean {
+        for rule in rules {
+            skip_rules.entry(rule.name.as_str()).or_insert("");
+        }
+    }
 
-    let (builtin, mut builtins_without_lifetime) =
-        generate_builtin(&defined_rules, &referenced_rules);
+    let referenced_rules = collect_used_rules(rules, implicit, &skip_rules);
 
-    let unicode_rule = generate_unicode(
+    let unicode_properties = collect_unicode_properties(&defined_rules, &referenced_rules);
+
+    let (builtin, mut builtins_without_lifetime) = generate_builtin(
         &defined_rules,
         &referenced_rules,
-        &mut builtins_without_lifetime,
+        !unicode_properties.is_empty(),
     );
+    builtins_without_lifetime.extend(&unicode_properties);
 
-    let not_boxed = collect_reachability(rules, implicit)
+    let not_boxed = collect_reachability(rules, implicit, &skip_rules)
         .keys()
         .cloned()
         .collect();
 
+    let accesser_docs = if config.inline_grammar_docs {
+        collect_accesser_docs(rules, doc)
+    } else {
+        BTreeMap::new()
+    };
+
+    let min_lens = collect_min_lens(rules);
+
+    let recursion_heads = collect_recursion_heads(rules, implicit, &skip_rules);
+
+    let stack_accessers = StackAccessers::new(&defined_rules);
+
     let mut graph = generate_graph(
         rules,
         &defined_rules,
+        &spanned_rules,
+        &recover_at,
         &not_boxed,
+        &recursion_heads,
         &builtins_without_lifetime,
+        &accesser_docs,
+        &skip_rules,
+        span_only,
+        &min_lens,
+        &error_labels,
+        rule_references,
+        &stack_accessers,
         config,
         doc,
     );
 
     graph.insert_builtin(quote! {#builtin});
 
-    let mods = graph.collect();
+    let vis = config.typed_vis;
+    let mods = graph.collect(vis);
     let unicode = unicode_mod();
     let generics = {
         let root = quote! {super};
@@ -1256,48 +2683,244 @@ pub(crate) fn generate_typed_pair_from_rule(
             },
         };
 
-        quote! {
-            #[doc = "Used generics."]
-            pub mod generics {
-                use #pest_typed::predefined_node;
+        let skipped = if lean {
+            quote! {}
+        } else {
+            quote! {
                 /// Skipped content.
                 pub type Skipped<'i> = #skip;
-                pub use predefined_node::{Str, Insens, PeekSlice1, PeekSlice2, Push, Skip, CharRange, Positive, Negative};
-                #(#seq)*
-                #(#chs)*
+            }
+        };
+        let reps = if lean {
+            quote! {}
+        } else {
+            quote! {
                 /// Repeat arbitrary times.
                 pub type Rep<'i, const SKIP: #usize, T> = predefined_node::Rep<T, Skipped<'i>, SKIP>;
                 /// Repeat at least once.
                 pub type RepOnce<'i, const SKIP: #usize, T> = predefined_node::RepOnce<T, Skipped<'i>, SKIP>;
             }
+        };
+
+        quote! {
+            #[doc = "Used generics."]
+            #vis mod generics {
+                use #pest_typed::predefined_node;
+                #skipped
+                pub use predefined_node::{Str, StrSpanned, Presence, Insens, InsensUnicode, Keyword, PeekSlice1, PeekSlice2, Push, PushLiteral, SpanOnly, Skip, CharExcept, StrChoice, CharRange, Positive, Negative};
+                #(#seq)*
+                #(#chs)*
+                #reps
+            }
         }
     };
-    let pairs = {
+    let pairs = if mod_name == "rules" {
+        quote! {}
+    } else {
         let rules_mod = rules_mod();
-        let pairs_mod = pairs_mod();
+        let pairs_mod = pairs_mod(mod_name);
         let doc = format! {"Re-export some types from {} to simplify the usage.", rules_mod};
         quote! {
             #[doc = #doc]
-            pub use #rules_mod as #pairs_mod;
+            #vis use #rules_mod as #pairs_mod;
         }
     };
-    let res = quote! {
-        #[doc = "Unicode rules."]
-        pub mod #unicode {
-            #unicode_rule
+    let visitor = if config.emit_visitor {
+        generate_visitor(rules, config, span_only)
+    } else {
+        quote! {}
+    };
+    let match_macros = if config.emit_match_macros {
+        generate_match_macros(rules, config, span_only)
+    } else {
+        quote! {}
+    };
+    let unicode = if unicode_properties.is_empty() {
+        quote! {}
+    } else {
+        let unicode_rule = generate_unicode(&unicode_properties);
+        quote! {
+            #[doc = "Unicode rules."]
+            #vis mod #unicode {
+                #unicode_rule
+            }
         }
+    };
+    let res = quote! {
+        #unicode
         #mods
         #generics
         #pairs
+        #visitor
+        #match_macros
     };
     res
 }
 
-fn generate_unicode(
+/// Generate a visitor trait with a method for each rule,
+/// and a function for each rule that walks into rule structs inside it.
+fn generate_visitor(
+    rules: &[OptimizedRule],
+    config: Config,
+    span_only: &BTreeSet<String>,
+) -> TokenStream {
+    let vis = config.typed_vis;
+    let pest_typed = pest_typed();
+    let usize = _usize();
+    let rules_mod = rules_mod();
+    let rules: BTreeMap<&str, &OptimizedRule> = rules
+        .iter()
+        .map(|rule| (rule.name.as_str(), rule))
+        .collect();
+    let mut methods = vec![];
+    let mut walks = vec![];
+    for (rule_name, rule) in rules {
+        let rule_id = ident(rule_name);
+        let visit = format_ident!("visit_{}", rule_name);
+        let walk = format_ident!("walk_{}", rule_name);
+        let node = quote! {super::#rules_mod::#rule_id<'i, INHERITED>};
+        let visit_doc = format!(
+            "Visit a node of rule `{}`. Walks into it with [`{}`] by default.",
+            rule_name, walk
+        );
+        methods.push(quote! {
+            #[doc = #visit_doc]
+            fn #visit<const INHERITED: #usize>(&mut self, node: &#node) {
+                #walk(self, node)
+            }
+        });
+        let walk_doc = format!("Visit rule structs inside a node of rule `{}`.", rule_name);
+        let body = match rule.ty {
+            RuleType::Atomic if !config.atomic_content => quote! {let _ = (visitor, node);},
+            _ if span_only.contains(rule_name) => quote! {let _ = (visitor, node);},
+            _ => quote! {#pest_typed::visitor::Walk::walk(&node.content, visitor)},
+        };
+        walks.push(quote! {
+            #[doc = #walk_doc]
+            pub fn #walk<'i, V: Visit<'i> + ?::core::marker::Sized, const INHERITED: #usize>(visitor: &mut V, node: &#node) {
+                #body
+            }
+            impl<'i, V: Visit<'i> + ?::core::marker::Sized, const INHERITED: #usize> #pest_typed::visitor::Walk<V> for #node {
+                fn walk(&self, visitor: &mut V) {
+                    visitor.#visit(self)
+                }
+            }
+        });
+    }
+    quote! {
+        #[doc = "Visitor of rule structs."]
+        #[allow(non_snake_case)]
+        #vis mod visitor {
+            #[doc = "Visit rule structs in a typed tree. Only override methods for rules you care about."]
+            pub trait Visit<'i> {
+                #(#methods)*
+            }
+            #(#walks)*
+        }
+    }
+}
+
+/// Names of rules in `a | b | ...`, if every branch is a distinct rule.
+fn ident_choices(expr: &OptimizedExpr) -> Option<Vec<&str>> {
+    let mut names = Vec::new();
+    let mut current = expr;
+    while let OptimizedExpr::Choice(lhs, rhs) = current {
+        match lhs.as_ref() {
+            OptimizedExpr::Ident(name) => names.push(name.as_str()),
+            _ => return None,
+        }
+        current = rhs;
+    }
+    match current {
+        OptimizedExpr::Ident(name) if !names.is_empty() => names.push(name.as_str()),
+        _ => return None,
+    }
+    let distinct: BTreeSet<&str> = names.iter().copied().collect();
+    (distinct.len() == names.len()).then_some(names)
+}
+
+/// Generate a macro `match_<rule>!` for each rule whose content is a choice of rules.
+///
+/// The macro expands to a `match` on a local enum with a variant for each branch,
+/// so that a missing branch without a wildcard arm fails to compile.
+fn generate_match_macros(
+    rules: &[OptimizedRule],
+    config: Config,
+    span_only: &BTreeSet<String>,
+) -> TokenStream {
+    let macros = rules.iter().filter_map(|rule| {
+        let emits_content = match rule.ty {
+            RuleType::Atomic => config.atomic_content,
+            _ => true,
+        };
+        if !emits_content || span_only.contains(&rule.name) {
+            return None;
+        }
+        let branches = ident_choices(&rule.expr)?;
+        let name = format_ident!("match_{}", rule.name);
+        // Raw identifiers in a macro pattern only match raw ones, so only keywords are escaped.
+        let variants: Vec<Ident> = branches
+            .iter()
+            .map(|branch| syn::parse_str::<Ident>(branch).unwrap_or_else(|_| ident(branch)))
+            .collect();
+        let getters: Vec<Ident> = (0..branches.len())
+            .map(|i| format_ident!("_{}", i))
+            .collect();
+        let (last, init) = variants.split_last().unwrap();
+        let doc = format!(
+            "Match the branch of a [`{0}`]({1}::{0}) node, such as `{2}!(node, {{ {3}(x) => ..., _ => ... }})`.",
+            rule.name,
+            rules_mod(),
+            name,
+            branches[0],
+        );
+        Some(quote! {
+            #[doc = #doc]
+            #[doc = ""]
+            #[doc = "Arms are separated by commas. Either all branches or a wildcard arm `_` are required."]
+            #[allow(unused_macros)]
+            macro_rules! #name {
+                ($node:expr, { $($arms:tt)* }) => {{
+                    #[allow(non_camel_case_types, dead_code)]
+                    enum __Branch { #(#variants),* }
+                    let choice = &$node.content;
+                    let branch = #(if choice.#getters().is_some() { __Branch::#init } else)* { __Branch::#last };
+                    #name!(@arms choice branch [] $($arms)*)
+                }};
+                (@arms $choice:ident $branch:ident [$($out:tt)*] _ => $body:expr $(, $($rest:tt)*)?) => {
+                    #name!(@arms $choice $branch [$($out)* _ => $body,] $($($rest)*)?)
+                };
+                #(
+                    (@arms $choice:ident $branch:ident [$($out:tt)*] #variants($pat:pat) => $body:expr $(, $($rest:tt)*)?) => {
+                        #name!(@arms $choice $branch [$($out)* __Branch::#variants => match $choice.#getters() {
+                            ::core::option::Option::Some($pat) => $body,
+                            ::core::option::Option::None => ::core::unreachable!(),
+                        },] $($($rest)*)?)
+                    };
+                )*
+                (@arms $choice:ident $branch:ident [$($out:tt)*]) => {
+                    match $branch { $($out)* }
+                };
+            }
+        })
+    });
+    quote! { #(#macros)* }
+}
+
+/// Unicode properties referenced in the grammar and not shadowed by user-defined rules.
+fn collect_unicode_properties(
     rule_names: &BTreeSet<&str>,
     referenced: &BTreeSet<&str>,
-    without_lifetime: &mut BTreeSet<&'static str>,
-) -> TokenStream {
+) -> BTreeSet<&'static str> {
+    let properties: BTreeSet<&'static str> = unicode_property_names().collect();
+    referenced
+        .iter()
+        .filter(|name| !rule_names.contains(*name))
+        .filter_map(|name| properties.get(*name).copied())
+        .collect()
+}
+
+fn generate_unicode(properties: &BTreeSet<&'static str>) -> TokenStream {
     let mut results = vec![];
     let pest_typed = pest_typed();
     let pest_unicode = pest_unicode();
@@ -1310,15 +2933,13 @@ fn generate_unicode(
     let root = quote! {super};
     let box_ = box_type();
 
-    for property in unicode_property_names() {
+    for property in properties {
         let property_ident: Ident = syn::parse_str(property).unwrap();
         // insert manually for #property substitution
 
         let doc = format!("Auto generated. Unicode property {}.", property);
 
-        if !rule_names.contains(property) && referenced.contains(property) {
-            without_lifetime.insert(property);
-            results.push(quote! {
+        results.push(quote! {
                 #[allow(non_camel_case_types)]
                 #[doc = #doc]
                 #[derive(Clone, Hash, PartialEq, Eq)]
@@ -1356,6 +2977,24 @@ fn generate_unicode(
                             .finish()
                     }
                 }
+                impl #pest_typed::MatchedText for #property_ident {
+                    fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::fmt::Write::write_char(f, self.content)
+                    }
+                }
+                impl ::core::fmt::Display for #property_ident {
+                    fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                        ::core::fmt::Write::write_char(f, self.content)
+                    }
+                }
+                impl #pest_typed::ContentEq for #property_ident {
+                    fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+                        self.content == other.content
+                    }
+                    fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        ::core::hash::Hash::hash(&self.content, state)
+                    }
+                }
                 impl<'i: 'n, 'n> #pest_typed::iterators::Pairs<'i, 'n, #root::Rule> for #property_ident {
                     type Iter = ::core::iter::Empty<&'n dyn #pest_typed::iterators::Pair<'i, 'n, #root::Rule>>;
                     type IntoIter = ::core::iter::Empty<#box_<dyn #pest_typed::iterators::Pair<'i, 'n, #root::Rule> + 'n>>;
@@ -1367,8 +3006,16 @@ fn generate_unicode(
                         ::core::iter::empty()
                     }
                 }
+                impl<V: ?::core::marker::Sized> #pest_typed::visitor::Walk<V> for #property_ident {
+                    #[inline]
+                    fn walk(&self, _visitor: &mut V) {}
+                }
+                impl<'i> #pest_typed::incremental::Offset<'i> for #property_ident {
+                    #[inline]
+                    fn offset(&mut self, _input: &'i ::core::primitive::str, _from: ::core::primitive::usize, _delta: ::core::primitive::isize) {}
+                }
+                #pest_typed::impl_serialize_content!(#property_ident);
             });
-        }
     }
     quote! {
         #(#results)*
@@ -1378,14 +3025,18 @@ fn generate_unicode(
 fn generate_builtin(
     defined: &BTreeSet<&str>,
     referenced: &BTreeSet<&str>,
+    use_unicode: bool,
 ) -> (TokenStream, BTreeSet<&'static str>) {
     let root = quote! {super::super};
     let pest_typed = pest_typed();
-    let unicode = unicode_mod();
-    let mut results = vec![quote! {
-        #[allow(unused_imports)]
-        use #root::#unicode::*;
-    }];
+    let mut results = vec![];
+    if use_unicode {
+        let unicode = unicode_mod();
+        results.push(quote! {
+            #[allow(unused_imports)]
+            use #root::#unicode::*;
+        });
+    }
     let mut builtins_without_lifetime = BTreeSet::new();
     macro_rules! insert_builtin {
         ($name:expr, $def:path) => {
@@ -1419,6 +3070,8 @@ fn generate_builtin(
     insert_builtin_with_lifetime!("PEEK_ALL", PEEK_ALL);
     insert_builtin_with_lifetime!("POP", POP);
     insert_builtin_with_lifetime!("POP_ALL", POP_ALL);
+    insert_builtin_with_lifetime!("PEEK_INSENS", PEEK_INSENS);
+    insert_builtin_with_lifetime!("POP_INSENS", POP_INSENS);
     insert_builtin!("DROP", DROP);
 
     insert_builtin!("ASCII_DIGIT", ASCII_DIGIT);
@@ -1462,7 +3115,7 @@ mod tests {
     fn inlined_used_rules() {
         let (_, rules) = parse_and_optimize(r#"x = { a ~ b } a = { "a" } b = { ^"b" }"#).unwrap();
         let implicit = Implicit::from(rules.as_slice());
-        let used = collect_used_rules(&rules, implicit);
+        let used = collect_used_rules(&rules, implicit, &BTreeMap::new());
         assert_eq!(used, BTreeSet::from(["a", "b"]));
     }
     #[test]
@@ -1472,7 +3125,7 @@ mod tests {
     fn used_rules() {
         let rules = &PARSE_RESULT.1;
         let implicit = Implicit::from(rules.as_slice());
-        let used = collect_used_rules(&rules, implicit);
+        let used = collect_used_rules(rules, implicit, &BTreeMap::new());
         let expected = include!("../tests/syntax.used.rules.txt");
         let expected = BTreeSet::from(expected);
         assert_eq!(used, expected);
@@ -1483,9 +3136,451 @@ mod tests {
         let (_, rules) =
             parse_and_optimize(r#"a = { "a" ~ b* } b = { "b" ~ c? } c = { a+ }"#).unwrap();
         let implicit = Implicit::from(rules.as_slice());
-        let used = collect_used_rules(&rules, implicit);
+        let used = collect_used_rules(&rules, implicit, &BTreeMap::new());
         assert_eq!(used, BTreeSet::from(["a", "b", "c"]));
-        let graph = collect_reachability(&rules, implicit);
+        let graph = collect_reachability(&rules, implicit, &BTreeMap::new());
         assert_eq!(graph, BTreeMap::from([("b", BTreeSet::from(["a", "c"]))]));
     }
+    #[test]
+    fn recursion_heads() {
+        let check = |grammar: &str, expected: &[&str]| {
+            let (_, rules) = parse_and_optimize(grammar).unwrap();
+            let implicit = Implicit::from(rules.as_slice());
+            let heads = collect_recursion_heads(&rules, implicit, &BTreeMap::new());
+            assert_eq!(heads, BTreeSet::from_iter(expected.iter().copied()));
+        };
+        check(r#"a = { "a" ~ b* } b = { "b" ~ c? } c = { a+ }"#, &["a"]);
+        check(r#"a = { "(" ~ a ~ ")" | b } b = { "b" }"#, &["a"]);
+        check(
+            r#"a = { b ~ c } b = { "(" ~ a ~ ")" | "b" } c = { "(" ~ c ~ ")" | "c" }"#,
+            &["a", "c"],
+        );
+        check(r#"a = { b } b = { "b" }"#, &[]);
+    }
+    #[test]
+    fn lean() {
+        let check = |grammar: &str, lean: bool| {
+            let (_, rules) = parse_and_optimize(grammar).unwrap();
+            assert_eq!(is_lean(&rules, Implicit::from(rules.as_slice())), lean);
+        };
+        check(r#"a = @{ "a" ~ b* } b = ${ "b" }"#, true);
+        check(r#"a = @{ "a" ~ b* } b = _{ "b" }"#, false);
+        check(r#"a = @{ "a" ~ b* } b = !{ "b" }"#, false);
+        check(r#"a = @{ "a" } WHITESPACE = @{ " " }"#, false);
+        check(r#"a = @{ "a" } COMMENT = @{ "//" }"#, false);
+    }
+    #[test]
+    fn str_choice() {
+        let strings = |grammar: &str| {
+            let (_, rules) = parse_and_optimize(grammar).unwrap();
+            let mut choices = vec![];
+            let mut current = &rules[0].expr;
+            while let OptimizedExpr::Choice(lhs, rhs) = current {
+                choices.push(lhs.as_ref());
+                current = rhs;
+            }
+            choices.push(current);
+            super::str_choice(&choices)
+        };
+        assert_eq!(
+            strings(r#"x = { "if" | "in" | "ifx" }"#),
+            None,
+            "`ifx` is never matched by ordered choice"
+        );
+        assert_eq!(
+            strings(r#"x = { "ifx" | "if" | "in" | "in" }"#),
+            Some(vec!["ifx".into(), "if".into(), "in".into(), "in".into()])
+        );
+        assert_eq!(strings(r#"x = { "a" | ^"b" }"#), None);
+    }
+    #[test]
+    fn reparse_items() {
+        let (_, rules) = parse_and_optimize(
+            r#"
+            item   = { "a" }
+            hidden = _{ "b" }
+            rep    = { item* }
+            file   = { SOI ~ item* ~ EOI }
+            tail   = { item* ~ EOI }
+            silent = { hidden* }
+            twice  = { item* ~ item* }
+            other  = { SOI ~ item* ~ "c" }
+            "#,
+        )
+        .unwrap();
+        let spanned = BTreeSet::from(["item", "rep", "file"]);
+        let path = |name: &str| {
+            let rule = rules.iter().find(|rule| rule.name == name).unwrap();
+            super::reparse_items(&rule.expr, &spanned).map(|path| path.to_string())
+        };
+        assert_eq!(path("rep").as_deref(), Some(". content"));
+        assert_eq!(
+            path("file").as_deref(),
+            Some(". content . 1 . matched . content")
+        );
+        assert_eq!(
+            path("tail").as_deref(),
+            Some(". content . 0 . matched . content")
+        );
+        assert_eq!(path("silent"), None);
+        assert_eq!(path("twice"), None);
+        assert_eq!(path("other"), None);
+        assert_eq!(path("item"), None);
+    }
+    #[test]
+    fn streamed_items() {
+        let (_, rules) = parse_and_optimize(
+            r#"
+            item   = { "a" }
+            rep    = { item* }
+            file   = { SOI ~ item* ~ EOI }
+            head   = { SOI ~ item* }
+            tail   = { item* ~ EOI }
+            swap   = { EOI ~ item* ~ SOI }
+            twice  = { item* ~ item* }
+            digits = { ASCII_DIGIT* }
+            "#,
+        )
+        .unwrap();
+        let defined = rules.iter().map(|rule| rule.name.as_str()).collect();
+        let items = |name: &str| {
+            let rule = rules.iter().find(|rule| rule.name == name).unwrap();
+            super::streamed_items(&rule.expr, &defined)
+        };
+        assert_eq!(items("rep"), Some(("item", false, false)));
+        assert_eq!(items("file"), Some(("item", true, true)));
+        assert_eq!(items("head"), Some(("item", true, false)));
+        assert_eq!(items("tail"), Some(("item", false, true)));
+        assert_eq!(items("swap"), None);
+        assert_eq!(items("twice"), None);
+        assert_eq!(items("digits"), None);
+        assert_eq!(items("item"), None);
+    }
+    #[test]
+    fn min_lens() {
+        let (_, rules) = parse_and_optimize(
+            r#"
+            a       = { "ab" ~ ^"cd" ~ 'x'..'z' }
+            choice  = { a | "e" ~ "f" | "ghi" }
+            opt     = { a? ~ "g"* ~ &"h" ~ !"i" ~ PEEK }
+            builtin = { ANY ~ ASCII_DIGIT ~ SOI ~ XID_START ~ EOI ~ NEWLINE }
+            wide    = { 'α'..'ω' ~ "好" }
+            paren   = { "(" ~ bracket ~ ")" | number }
+            bracket = { "[" ~ paren ~ "]" }
+            number  = @{ ASCII_DIGIT+ }
+            endless = { "a" ~ endless }
+            "#,
+        )
+        .unwrap();
+        let min_lens = collect_min_lens(&rules);
+        assert_eq!(min_lens["a"], 5);
+        assert_eq!(min_lens["choice"], 2);
+        assert_eq!(min_lens["opt"], 0);
+        assert_eq!(min_lens["builtin"], 4);
+        assert_eq!(min_lens["wide"], 5);
+        assert_eq!(min_lens["number"], 1);
+        assert_eq!(min_lens["paren"], 1);
+        assert_eq!(min_lens["bracket"], 3);
+        // Capped, but still a lower bound.
+        assert!(min_lens["endless"] >= 1);
+    }
+    #[test]
+    fn min_lens_insens() {
+        let (_, rules) = parse_and_optimize(
+            r#"
+            ascii  = { ^"abc" }
+            kelvin = { ^"\u{212A}" }
+            mixed  = { ^"straße" }
+            "#,
+        )
+        .unwrap();
+        let min_lens = collect_min_lens(&rules);
+        assert_eq!(min_lens["ascii"], 3);
+        // Matches `k`.
+        assert_eq!(min_lens["kelvin"], 0);
+        assert_eq!(min_lens["mixed"], 5);
+    }
+    #[test]
+    fn min_lens_saturated() {
+        let names: Vec<String> = (0..80).map(|i| format!("r{}", i)).collect();
+        let mut grammar = String::from(r#"a = { "(" ~ a ~ a ~ ")" }"#);
+        for name in &names {
+            grammar.push_str(&format!(" {} = {{ a }}", name));
+        }
+        let (_, rules) = parse_and_optimize(&grammar).unwrap();
+        let min_lens = collect_min_lens(&rules);
+        assert_eq!(min_lens["a"], isize::MAX as usize);
+        assert_eq!(min_lens["r0"], isize::MAX as usize);
+    }
+    #[test]
+    fn skip_rules() {
+        let (_, rules) = parse_and_optimize(
+            r#"
+            WHITESPACE = _{ " " }
+            TAB        = _{ "\t" }
+            outer      =  { inner ~ raw ~ word }
+            inner      =  { "(" ~ nested* ~ ")" }
+            nested     = _{ word | inner }
+            raw        =  { "r" ~ word }
+            word       = @{ ASCII_ALPHA+ }
+            "#,
+        )
+        .unwrap();
+        let skip_rules = BTreeMap::from([
+            ("inner".to_owned(), "TAB".to_owned()),
+            ("raw".to_owned(), "".to_owned()),
+        ]);
+        let resolved = collect_skip_rules(&rules, &skip_rules);
+        assert_eq!(
+            resolved,
+            BTreeMap::from([("inner", "TAB"), ("nested", "TAB"), ("raw", "")])
+        );
+        let used = collect_used_rules(&rules, Implicit::from(rules.as_slice()), &resolved);
+        assert!(used.contains("TAB") && used.contains("WHITESPACE"));
+    }
+    #[test]
+    #[should_panic(expected = "also referenced by `outer`")]
+    fn skip_rules_conflict() {
+        let (_, rules) =
+            parse_and_optimize(r#"outer = { inner ~ nested } inner = { nested } nested = { "a" }"#)
+                .unwrap();
+        let skip_rules = BTreeMap::from([("inner".to_owned(), "".to_owned())]);
+        collect_skip_rules(&rules, &skip_rules);
+    }
+    #[test]
+    #[should_panic(expected = "Range 'z'..'a' in rule `reversed` never matches")]
+    fn reversed_range() {
+        let grammar = r#"reversed = { 'z'..'a' }"#;
+        let (_, rules) = parse_and_optimize(grammar).unwrap();
+        let doc = consume(parse(Rule::grammar_rules, grammar).unwrap());
+        generate_typed_pair_from_rule(
+            &rules,
+            &doc,
+            Config::default(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &format_ident!("pairs"),
+        );
+    }
+    #[test]
+    /// Occurrences of the same string share a wrapper.
+    fn shared_wrappers() {
+        let grammar = r#"a = { "x" ~ ^"x" ~ "y" } b = { "x" | "y" } c = @{ (!("x" | "y") ~ ANY)* } d = @{ !("x" | "y") ~ ANY }"#;
+        let (_, rules) = parse_and_optimize(grammar).unwrap();
+        let doc = consume(parse(Rule::grammar_rules, grammar).unwrap());
+        let output = generate_typed_pair_from_rule(
+            &rules,
+            &doc,
+            Config::default(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &format_ident!("pairs"),
+        )
+        .to_string();
+        assert_eq!(output.matches(":: StringWrapper for").count(), 2);
+        assert_eq!(output.matches(":: StringArrayWrapper for").count(), 1);
+    }
+    #[test]
+    /// Optional wrappers around a rule reference collapse into a single `Option`.
+    fn flattened_accessers() {
+        let grammar = r#"
+            a = { "a" }
+            b = { "b" }
+            choice   = { (a | b)? }
+            nested   = { ("c" | (b | a)?)? }
+            sequence = { ("x" ~ a? | b)? }
+            deep     = { ((a ~ "q")? ~ "w")? }
+            tuple    = { ("x" ~ a ~ a | b)? }
+            "#;
+        let (_, rules) = parse_and_optimize(grammar).unwrap();
+        let doc = consume(parse(Rule::grammar_rules, grammar).unwrap());
+        let config = Config {
+            emit_rule_reference: true,
+            ..Config::default()
+        };
+        let output = generate_typed_pair_from_rule(
+            &rules,
+            &doc,
+            config,
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &format_ident!("pairs"),
+        )
+        .to_string();
+        let signatures: Vec<&str> = output
+            .split("pub fn r#a < 's > (& 's self) -> ")
+            .skip(1)
+            .map(|rest| rest.split(" {").next().unwrap())
+            .collect();
+        let single = ":: pest_typed :: re_exported :: Option :: < & 's super :: super :: rules :: r#a :: < 'i , INHERITED > >";
+        let pair = ":: pest_typed :: re_exported :: Option :: < (& 's super :: super :: rules :: r#a :: < 'i , INHERITED > , & 's super :: super :: rules :: r#a :: < 'i , INHERITED >) >";
+        // In the order of rule names.
+        assert_eq!(signatures, [single, single, single, single, pair]);
+    }
+    #[test]
+    /// Only accessers to rules with spans have `_span` companions,
+    /// unless the name is taken by another accesser.
+    fn span_accessers() {
+        let grammar = r#"
+            a      =  { "a" }
+            b      = _{ "b" }
+            a_span =  { "s" }
+            c      =  { "c" }
+            d      =  { a ~ b ~ ASCII_DIGIT ~ c* }
+            e      =  { a ~ a_span }
+            "#;
+        let (_, rules) = parse_and_optimize(grammar).unwrap();
+        let doc = consume(parse(Rule::grammar_rules, grammar).unwrap());
+        let config = Config {
+            emit_rule_reference: true,
+            ..Config::default()
+        };
+        let output = generate_typed_pair_from_rule(
+            &rules,
+            &doc,
+            config,
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &format_ident!("pairs"),
+        )
+        .to_string();
+        let companions: Vec<&str> = output
+            .split("pub fn ")
+            .skip(1)
+            .filter_map(|rest| rest.split(" (& self)").next())
+            .filter(|name| name.ends_with("_span"))
+            .collect();
+        // `a_span` and `c_span` of `d`, then only `a_span_span` of `e`.
+        assert_eq!(companions, ["a_span", "c_span", "a_span_span"]);
+    }
+    #[test]
+    /// Rule sources are embedded in docs, in fences longer than backtick runs in them.
+    fn rule_source_docs() {
+        let grammar = r#"
+            /// Letter a.
+            a = { "a" }
+            t = { "```" ~ a? }
+            "#;
+        let (_, rules) = parse_and_optimize(grammar).unwrap();
+        let doc = consume(parse(Rule::grammar_rules, grammar).unwrap());
+        let config = Config {
+            emit_rule_reference: true,
+            ..Config::default()
+        };
+        let output = generate_typed_pair_from_rule(
+            &rules,
+            &doc,
+            config,
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &format_ident!("pairs"),
+        )
+        .to_string();
+        assert!(output.contains(r#""Letter a." "" "```pest" "a = { \"a\" }" "```""#));
+        assert!(output.contains(
+            r#""````pest" "t = { \"```\" ~ a? }" "````" "" "Accessers:" "" "- `a()`: `Option<&a>`""#
+        ));
+    }
+    #[test]
+    /// Rules share bodies unless their types may contain themselves.
+    fn shared_bodies() {
+        let grammar =
+            r#"WHITESPACE = _{ " " } a = { "a" } b = { "b" } list = { "(" ~ list* ~ ")" | a ~ b }"#;
+        let (_, rules) = parse_and_optimize(grammar).unwrap();
+        let doc = consume(parse(Rule::grammar_rules, grammar).unwrap());
+        let output = generate_typed_pair_from_rule(
+            &rules,
+            &doc,
+            Config::default(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &format_ident!("pairs"),
+        )
+        .to_string();
+        assert_eq!(output.matches("rule_body ! (Both ,").count(), 1);
+        assert!(output.contains("rule_instance ! (r#a ,"));
+        assert!(output.contains("rule_instance ! (r#b ,"));
+        assert!(output.contains("rule ! (r#list ,"));
+        assert!(output.contains("rule ! (r#WHITESPACE ,"));
+    }
+    #[test]
+    /// Generated items are ordered by their names in the grammar,
+    /// including those that are escaped as raw identifiers.
+    fn ordering() {
+        #[cfg(not(feature = "grammar-extras"))]
+        let grammar = r#"z = { "z" } type = { "t" ~ fn } fn = { "f" } a = { type ~ fn ~ z }"#;
+        #[cfg(feature = "grammar-extras")]
+        let grammar = r#"z = { "z" } type = { "t" ~ fn } fn = { "f" } a = { #match = type ~ #else = fn ~ z }"#;
+        let (_, rules) = parse_and_optimize(grammar).unwrap();
+        let doc = consume(parse(Rule::grammar_rules, grammar).unwrap());
+        let config = Config {
+            emit_rule_reference: true,
+            emit_tagged_node_reference: true,
+            truncate_accesser_at_node_tag: false,
+            ..Default::default()
+        };
+        let output = generate_typed_pair_from_rule(
+            &rules,
+            &doc,
+            config,
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &BTreeSet::new(),
+            &BTreeMap::new(),
+            &BTreeSet::new(),
+            &format_ident!("pairs"),
+        )
+        .to_string();
+
+        let assert_ordered = |items: &[&str], from: usize| {
+            let positions = items
+                .iter()
+                .map(|item| {
+                    from + output[from..]
+                        .find(item)
+                        .unwrap_or_else(|| panic!("{item:?} not found."))
+                })
+                .collect::<Vec<_>>();
+            assert!(
+                positions.windows(2).all(|w| w[0] < w[1]),
+                "{items:?} are not ordered: {positions:?}."
+            );
+        };
+
+        let rule_structs = [
+            "rule_instance ! (r#a ,",
+            "rule_instance ! (r#fn ,",
+            "rule_instance ! (r#type ,",
+            "rule_instance ! (r#z ,",
+        ];
+        assert_ordered(&rule_structs, 0);
+
+        let rule_a = output.find("> r#a < 'i , INHERITED >").unwrap();
+        assert_ordered(&["fn r#fn", "fn r#type", "fn r#z"], rule_a);
+
+        #[cfg(feature = "grammar-extras")]
+        {
+            let tags = output.find("mod tags").unwrap();
+            assert_ordered(&["mod r#a", "type r#else", "type r#match"], tags);
+            assert_ordered(&["fn r#else", "fn r#fn", "fn r#match", "fn r#type"], rule_a);
+        }
+    }
 }
@@ -10,14 +10,39 @@
 //! Copied from **pest/generator/src/lib.rs** (commit ac0aed3eecf435fd93ba575a39704aaa88a375b7)
 //! and modified.
 
+use std::collections::BTreeMap;
 use std::env;
 use std::fs::File;
 use std::io::{self, Read};
 use std::path::{Path, PathBuf};
 
-use syn::{Attribute, Expr, ExprLit, Lit, Meta};
+use syn::{
+    ext::IdentExt, parse::ParseStream, punctuated::Punctuated, Attribute, Expr, ExprLit, Ident,
+    Lit, LitStr, Meta, MetaNameValue, Token,
+};
 
+use crate::config::{Repr, Visibility};
+
+/// Split leading grammar doc comments (`//!`) from the rest of a grammar.
+fn split_grammar_doc(data: &str) -> (&str, &str) {
+    let mut end = 0;
+    for line in data.split_inclusive('\n') {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("//!") || trimmed.trim_end().is_empty() {
+            end += line.len();
+        } else {
+            break;
+        }
+    }
+    data.split_at(end)
+}
+
+/// Concatenate grammars from all sources.
+///
+/// Grammar doc comments of all sources are moved to the front,
+/// as **pest** only accepts them at the beginning of a grammar.
 pub(crate) fn collect_data(contents: Vec<GrammarSource>) -> (String, Vec<PathBuf>) {
+    let mut docs = String::new();
     let mut data = String::new();
     let mut paths = vec![];
 
@@ -54,13 +79,21 @@ pub(crate) fn collect_data(contents: Vec<GrammarSource>) -> (String, Vec<PathBuf
             GrammarSource::Inline(content) => (content, None),
         };
 
-        data.push_str(&_data);
+        let (doc, rules) = split_grammar_doc(&_data);
+        docs.push_str(doc);
+        if !docs.is_empty() && !docs.ends_with('\n') {
+            docs.push('\n');
+        }
+        data.push_str(rules);
+        // A trailing line comment shouldn't swallow the first line of the next source.
+        data.push('\n');
         if let Some(path) = _path {
             paths.push(path);
         }
     }
 
-    (data, paths)
+    docs.push_str(&data);
+    (docs, paths)
 }
 
 #[derive(Debug, PartialEq)]
@@ -70,12 +103,16 @@ pub(crate) enum GrammarSource {
 }
 
 pub(crate) fn get_string(attr: &Attribute) -> String {
+    get_lit_str(attr).value()
+}
+
+pub(crate) fn get_lit_str(attr: &Attribute) -> &LitStr {
     match &attr.meta {
         Meta::NameValue(name_value) => match &name_value.value {
             Expr::Lit(ExprLit {
                 lit: Lit::Str(string),
                 ..
-            }) => string.value(),
+            }) => string,
             _ => panic!("grammar attribute must be a string"),
         },
         _ => panic!("grammar attribute must be of the form `grammar = \"...\"`"),
@@ -96,9 +133,123 @@ pub(crate) fn get_bool(attr: &Attribute) -> bool {
     }
 }
 
+pub(crate) fn get_usize(attr: &Attribute) -> usize {
+    match &attr.meta {
+        Meta::NameValue(name_value) => match &name_value.value {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            }) => match int.base10_parse() {
+                Ok(val) => val,
+                Err(_) => panic!("integer attribute must be a non-negative integer."),
+            },
+            _ => panic!("integer attribute must be an integer literal."),
+        },
+        _ => panic!("integer attribute must be of the form `attribute = 123`"),
+    }
+}
+
+pub(crate) fn get_visibility(attr: &Attribute) -> Visibility {
+    let vis = get_string(attr);
+    match syn::parse_str::<syn::Visibility>(&vis) {
+        Ok(syn::Visibility::Public(_)) => Visibility::Public,
+        Ok(syn::Visibility::Inherited) => Visibility::Private,
+        Ok(syn::Visibility::Restricted(restricted)) if restricted.in_token.is_none() => {
+            if restricted.path.is_ident("crate") {
+                Visibility::Crate
+            } else if restricted.path.is_ident("super") {
+                Visibility::Super
+            } else {
+                Visibility::Private
+            }
+        }
+        _ => panic!("visibility attribute must be one of `pub`, `pub(crate)`, `pub(super)`, `pub(self)` or empty, but got {:?}", vis),
+    }
+}
+
+pub(crate) fn get_mod_name(attr: &Attribute) -> Ident {
+    let name = get_string(attr);
+    match syn::parse_str::<Ident>(&name) {
+        Ok(ident) => ident,
+        Err(_) => panic!(
+            "mod_name attribute must be an identifier, but got {:?}",
+            name
+        ),
+    }
+}
+
+pub(crate) fn get_repr(attr: &Attribute) -> Repr {
+    let ty = match attr.parse_args::<Ident>() {
+        Ok(ty) => ty.to_string(),
+        Err(_) => panic!("repr attribute must be of the form `repr(type)`"),
+    };
+    match Repr::from_name(&ty) {
+        Some(repr) => repr,
+        None => panic!(
+            "repr attribute must be a primitive integer type, but got `{}`",
+            ty
+        ),
+    }
+}
+
+pub(crate) fn get_string_map(attr: &Attribute) -> BTreeMap<String, String> {
+    let pairs = match attr.parse_args_with(Punctuated::<MetaNameValue, Token![,]>::parse_terminated)
+    {
+        Ok(pairs) => pairs,
+        Err(_) => panic!("map attribute must be of the form `map(key = \"value\", ...)`"),
+    };
+    pairs
+        .into_iter()
+        .map(|pair| {
+            let key = match pair.path.get_ident() {
+                Some(key) => key.unraw().to_string(),
+                None => panic!("map attribute keys must be identifiers"),
+            };
+            let value = match &pair.value {
+                Expr::Lit(ExprLit {
+                    lit: Lit::Str(string),
+                    ..
+                }) => string.value(),
+                _ => panic!("map attribute values must be strings"),
+            };
+            (key, value)
+        })
+        .collect()
+}
+
+pub(crate) fn get_ident_list(attr: &Attribute) -> Vec<String> {
+    let idents = match attr.parse_args_with(|input: ParseStream<'_>| {
+        Punctuated::<Ident, Token![,]>::parse_terminated_with(input, Ident::parse_any)
+    }) {
+        Ok(idents) => idents,
+        Err(_) => panic!("list attribute must be of the form `list(ident, ...)`"),
+    };
+    idents
+        .into_iter()
+        .map(|ident| ident.unraw().to_string())
+        .collect()
+}
+
 fn read_file<P: AsRef<Path>>(path: P) -> io::Result<String> {
     let mut file = File::open(path.as_ref())?;
     let mut string = String::new();
     file.read_to_string(&mut string)?;
     Ok(string)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn concatenate() {
+        let (data, paths) = collect_data(vec![
+            GrammarSource::Inline("//! Base.\n\nident = { ASCII_ALPHA+ } // trailing".to_owned()),
+            GrammarSource::Inline("//! Language.\nlet = { \"let\" ~ ident }".to_owned()),
+        ]);
+        assert_eq!(
+            data,
+            "//! Base.\n\n//! Language.\nident = { ASCII_ALPHA+ } // trailing\nlet = { \"let\" ~ ident }\n"
+        );
+        assert!(paths.is_empty());
+    }
+}
@@ -15,6 +15,16 @@
 //!
 //!    Enables extra grammars of [`pest`].
 //!
+//! - `serde`.
+//!
+//!    Derives `serde::Serialize` for the generated `Rule` enumeration, which requires feature `serde` of **pest_typed**.
+//!
+//! - `differential`.
+//!
+//!    Enables `emit_pest_check`, which generates `check_against_pest` with an untyped parser from **pest_generator**.
+//!
+//!    It requires feature `std` of **pest_typed**, and **pest** as a dependency.
+//!
 //! It can create corresponding type definitions from pest grammar files.
 //!
 //! See [pest_typed](https://docs.rs/pest_typed/latest/pest_typed/) for related traits and types.
@@ -28,8 +38,11 @@
 )]
 
 mod config;
+#[cfg(feature = "differential")]
+mod differential;
 mod graph;
 mod match_choices;
+mod random;
 mod typed;
 pub use match_choices::match_choices;
 pub use typed::derive_typed_parser;
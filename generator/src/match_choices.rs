@@ -111,8 +111,7 @@ mod tests {
     fn choice_0() {
         let input = quote! {
             x {}
-        }
-        .into();
+        };
         let _: TokenStream = match_choices(input);
     }
     #[test]
@@ -123,8 +122,7 @@ mod tests {
                     println!("{a}");
                 }
             }
-        }
-        .into();
+        };
         let _: TokenStream = match_choices(input);
     }
     #[test]
@@ -134,8 +132,7 @@ mod tests {
                 a => a - 1,
                 b => b + 1
             }
-        }
-        .into();
+        };
         let _: TokenStream = match_choices(input);
     }
 }
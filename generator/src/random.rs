@@ -0,0 +1,344 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Generate `generate_random`, which builds random inputs from the grammar.
+
+use crate::config::Config;
+use crate::graph::pest_typed;
+use pest::unicode::unicode_property_names;
+use pest_meta::{
+    ast::RuleType,
+    optimizer::{OptimizedExpr, OptimizedRule},
+};
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Minimum number of nested rule calls to derive an expression, given those of rules.
+///
+/// `usize::MAX` means that no derivation is known yet.
+fn expr_depth(expr: &OptimizedExpr, depths: &BTreeMap<&str, usize>) -> usize {
+    match expr {
+        OptimizedExpr::Str(_) | OptimizedExpr::Insens(_) | OptimizedExpr::Range(_, _) => 0,
+        OptimizedExpr::Ident(rule_name) => depths.get(rule_name.as_str()).copied().unwrap_or(0),
+        OptimizedExpr::PeekSlice(_, _) | OptimizedExpr::Skip(_) => 0,
+        OptimizedExpr::PosPred(_) | OptimizedExpr::NegPred(_) => 0,
+        OptimizedExpr::Seq(lhs, rhs) => expr_depth(lhs, depths).max(expr_depth(rhs, depths)),
+        OptimizedExpr::Choice(lhs, rhs) => expr_depth(lhs, depths).min(expr_depth(rhs, depths)),
+        OptimizedExpr::Opt(_) | OptimizedExpr::Rep(_) => 0,
+        #[cfg(feature = "grammar-extras")]
+        OptimizedExpr::RepOnce(expr) => expr_depth(expr, depths),
+        OptimizedExpr::Push(expr) | OptimizedExpr::RestoreOnErr(expr) => expr_depth(expr, depths),
+        #[cfg(feature = "grammar-extras")]
+        OptimizedExpr::NodeTag(expr, _) => expr_depth(expr, depths),
+    }
+}
+
+/// Minimum number of nested rule calls to derive each rule, including itself.
+///
+/// Iterates downwards from `usize::MAX`, so rules that never terminate,
+/// such as `a = { "a" ~ a }`, keep `usize::MAX`.
+fn collect_depths(rules: &[OptimizedRule]) -> BTreeMap<&str, usize> {
+    let mut res: BTreeMap<&str, usize> = rules
+        .iter()
+        .map(|rule| (rule.name.as_str(), usize::MAX))
+        .collect();
+    loop {
+        let mut changed = false;
+        for rule in rules {
+            let depth = expr_depth(&rule.expr, &res).saturating_add(1);
+            let entry = res.get_mut(rule.name.as_str()).unwrap();
+            if depth < *entry {
+                *entry = depth;
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    res
+}
+
+/// Character ranges of built-in rules.
+fn builtin_ranges(rule_name: &str) -> Option<&'static [(char, char)]> {
+    Some(match rule_name {
+        "ASCII_DIGIT" => &[('0', '9')],
+        "ASCII_NONZERO_DIGIT" => &[('1', '9')],
+        "ASCII_BIN_DIGIT" => &[('0', '1')],
+        "ASCII_OCT_DIGIT" => &[('0', '7')],
+        "ASCII_HEX_DIGIT" => &[('0', '9'), ('a', 'f'), ('A', 'F')],
+        "ASCII_ALPHA_LOWER" => &[('a', 'z')],
+        "ASCII_ALPHA_UPPER" => &[('A', 'Z')],
+        "ASCII_ALPHA" => &[('a', 'z'), ('A', 'Z')],
+        "ASCII_ALPHANUMERIC" => &[('0', '9'), ('a', 'z'), ('A', 'Z')],
+        "ASCII" => &[('\0', '\x7f')],
+        _ => return None,
+    })
+}
+
+struct Context<'g> {
+    depths: BTreeMap<&'g str, usize>,
+    /// Defined `WHITESPACE` and `COMMENT`.
+    trivia: Vec<&'g str>,
+    /// Constructs that can't be generated, with the rules they are in.
+    unsupported: BTreeSet<String>,
+}
+
+impl<'g> Context<'g> {
+    fn skip(&self) -> TokenStream {
+        if self.trivia.is_empty() {
+            return quote! {};
+        }
+        let trivia = self.trivia.iter().map(|name| format_ident!("r#{}", name));
+        quote! {
+            g.skip(depth, atomic, &[#(self::#trivia),*]);
+        }
+    }
+
+    fn expr(&mut self, expr: &'g OptimizedExpr, rule_name: &str) -> TokenStream {
+        match expr {
+            OptimizedExpr::Str(string) => quote! { g.push_str(#string); },
+            OptimizedExpr::Insens(string) => quote! { g.push_insens(#string); },
+            OptimizedExpr::Range(start, end) => {
+                let start = start.chars().next().unwrap();
+                let end = end.chars().next().unwrap();
+                quote! { g.push_range(#start, #end); }
+            }
+            OptimizedExpr::Ident(name) if self.depths.contains_key(name.as_str()) => {
+                let name = format_ident!("r#{}", name);
+                quote! { self::#name(g, depth.saturating_sub(1), atomic); }
+            }
+            OptimizedExpr::Ident(name) => match name.as_str() {
+                "ANY" => quote! { g.push_any(); },
+                "SOI" | "EOI" => quote! {},
+                "NEWLINE" => quote! {
+                    {
+                        let newline = ["\n", "\r\n", "\r"][g.below(3)];
+                        g.push_str(newline);
+                    }
+                },
+                "PEEK" | "PEEK_INSENS" => quote! { g.peek(); },
+                "POP" | "POP_INSENS" => quote! { g.pop(); },
+                "DROP" => quote! { g.drop_top(); },
+                "PEEK_ALL" => quote! { g.peek_all(); },
+                "POP_ALL" => quote! { g.pop_all(); },
+                name => match builtin_ranges(name) {
+                    Some(ranges) => {
+                        let ranges = ranges.iter().map(|(min, max)| quote! { (#min, #max) });
+                        quote! { g.push_ranges(&[#(#ranges),*]); }
+                    }
+                    None => {
+                        if unicode_property_names().any(|property| property == name) {
+                            self.unsupported.insert(format!(
+                                "unicode property `{}` in rule `{}`",
+                                name, rule_name
+                            ));
+                        }
+                        quote! {}
+                    }
+                },
+            },
+            OptimizedExpr::PeekSlice(start, end) => {
+                let end = match end {
+                    Some(end) => quote! { ::core::option::Option::Some(#end) },
+                    None => quote! { ::core::option::Option::None },
+                };
+                quote! { g.peek_slice(#start, #end); }
+            }
+            OptimizedExpr::PosPred(_) => {
+                self.unsupported
+                    .insert(format!("positive predicate in rule `{}`", rule_name));
+                quote! {}
+            }
+            OptimizedExpr::NegPred(_) => {
+                self.unsupported
+                    .insert(format!("negative predicate in rule `{}`", rule_name));
+                quote! {}
+            }
+            OptimizedExpr::Skip(_) => {
+                self.unsupported
+                    .insert(format!("skip in rule `{}`", rule_name));
+                quote! {}
+            }
+            OptimizedExpr::Seq(_, _) => {
+                let mut items = vec![];
+                let mut current = expr;
+                while let OptimizedExpr::Seq(lhs, rhs) = current {
+                    items.push(lhs.as_ref());
+                    current = rhs;
+                }
+                items.push(current);
+                let skip = self.skip();
+                let items = items.into_iter().enumerate().map(|(index, item)| {
+                    let item = self.expr(item, rule_name);
+                    match index {
+                        0 => item,
+                        _ => quote! { #skip #item },
+                    }
+                });
+                let items: Vec<TokenStream> = items.collect();
+                quote! { #(#items)* }
+            }
+            OptimizedExpr::Choice(_, _) => {
+                let mut branches = vec![];
+                let mut current = expr;
+                while let OptimizedExpr::Choice(lhs, rhs) = current {
+                    branches.push(lhs.as_ref());
+                    current = rhs;
+                }
+                branches.push(current);
+                // The first one among the shallowest branches, so that the input ends at depth `0`.
+                let shallowest = branches
+                    .iter()
+                    .enumerate()
+                    .min_by_key(|(_, branch)| expr_depth(branch, &self.depths))
+                    .map(|(index, _)| index)
+                    .unwrap();
+                let count = branches.len();
+                let arms = branches.into_iter().enumerate().map(|(index, branch)| {
+                    let body = self.expr(branch, rule_name);
+                    quote! { #index => { #body } }
+                });
+                let arms: Vec<TokenStream> = arms.collect();
+                quote! {
+                    match if depth == 0 { #shallowest } else { g.below(#count) } {
+                        #(#arms)*
+                        _ => ::core::unreachable!(),
+                    }
+                }
+            }
+            OptimizedExpr::Opt(inner) => {
+                let inner = self.expr(inner, rule_name);
+                quote! {
+                    if depth > 0 && g.coin() {
+                        #inner
+                    }
+                }
+            }
+            OptimizedExpr::Rep(inner) => self.repeat(inner, rule_name, 0),
+            #[cfg(feature = "grammar-extras")]
+            OptimizedExpr::RepOnce(inner) => self.repeat(inner, rule_name, 1),
+            OptimizedExpr::Push(inner) => {
+                let inner = self.expr(inner, rule_name);
+                quote! {
+                    {
+                        let start = g.len();
+                        #inner
+                        g.push_stack(start);
+                    }
+                }
+            }
+            OptimizedExpr::RestoreOnErr(inner) => self.expr(inner, rule_name),
+            #[cfg(feature = "grammar-extras")]
+            OptimizedExpr::NodeTag(inner, _) => self.expr(inner, rule_name),
+        }
+    }
+
+    /// At least `min` items, and a few more if `depth` allows.
+    fn repeat(&mut self, inner: &'g OptimizedExpr, rule_name: &str, min: usize) -> TokenStream {
+        let skip = self.skip();
+        let inner = self.expr(inner, rule_name);
+        quote! {
+            for i in 0..#min + if depth > 0 { g.below(4) } else { 0 } {
+                if i > 0 {
+                    #skip
+                }
+                #inner
+            }
+        }
+    }
+}
+
+/// Generate `generate_random`, or a message listing constructs that it can't generate.
+///
+/// Each rule becomes a function writing a random match of it,
+/// which calls other rules with `depth` decreased and picks the shallowest choices at depth `0`.
+/// Between items of sequences and repetitions in non-atomic rules, `WHITESPACE` or `COMMENT` may be written.
+pub(crate) fn generate_random(
+    rules: &[OptimizedRule],
+    config: Config,
+) -> Result<TokenStream, String> {
+    let pest_typed = pest_typed();
+    let vis = config.typed_vis;
+    let depths = collect_depths(rules);
+    let trivia = rules
+        .iter()
+        .map(|rule| rule.name.as_str())
+        .filter(|name| *name == "WHITESPACE" || *name == "COMMENT")
+        .collect();
+    let mut context = Context {
+        depths,
+        trivia,
+        unsupported: BTreeSet::new(),
+    };
+    for rule in rules {
+        if context.depths[rule.name.as_str()] == usize::MAX {
+            context
+                .unsupported
+                .insert(format!("rule `{}` that never terminates", rule.name));
+        }
+    }
+    let functions: Vec<TokenStream> = rules
+        .iter()
+        .map(|rule| {
+            let name = format_ident!("r#{}", rule.name);
+            let atomic = match rule.ty {
+                RuleType::Atomic | RuleType::CompoundAtomic => quote! { let atomic = true; },
+                RuleType::NonAtomic => quote! { let atomic = false; },
+                RuleType::Normal | RuleType::Silent => quote! {},
+            };
+            let body = context.expr(&rule.expr, &rule.name);
+            quote! {
+                pub fn #name(g: &mut #pest_typed::random::Generator<'_>, depth: ::core::primitive::usize, atomic: ::core::primitive::bool) {
+                    #atomic
+                    #body
+                }
+            }
+        })
+        .collect();
+    if !context.unsupported.is_empty() {
+        let unsupported: Vec<String> = context.unsupported.into_iter().collect();
+        return Err(format!(
+            "Inputs can't be generated for grammars with {}.",
+            unsupported.join(", ")
+        ));
+    }
+    let arms = rules.iter().map(|rule| {
+        let name = format_ident!("r#{}", rule.name);
+        quote! {
+            Rule::#name => rules::#name(&mut g, max_depth, false),
+        }
+    });
+    Ok(quote! {
+        /// Generate a random input matching a rule.
+        ///
+        /// Choices and repetitions are decided by `rng`,
+        /// and rules nested deeper than `max_depth` take the shallowest choices and no optional items.
+        /// As choices are ordered, an input may still be rejected if it's matched by an earlier choice,
+        /// or if a repetition consumes what's after it.
+        #[allow(dead_code)]
+        #vis fn generate_random(
+            rule: Rule,
+            rng: &mut impl #pest_typed::random::RandomSource,
+            max_depth: ::core::primitive::usize,
+        ) -> #pest_typed::re_exported::String {
+            #[allow(non_snake_case, unused_variables, unused_mut)]
+            mod rules {
+                #(#functions)*
+            }
+            let mut g = #pest_typed::random::Generator::new(rng);
+            match rule {
+                Rule::EOI => {}
+                #(#arms)*
+            }
+            g.finish()
+        }
+    })
+}
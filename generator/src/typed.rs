@@ -22,16 +22,35 @@ use super::docs::{consume, DocComment};
 use super::generator::{generate_enum, generate_include};
 use super::helper::{collect_data, get_string, GrammarSource};
 use crate::config::Config;
-use crate::graph::{generate_typed_pair_from_rule, pest_typed};
-use crate::helper::get_bool;
+use crate::graph::{collect_dependencies, generate_typed_pair_from_rule, pest_typed};
+use crate::helper::{
+    get_bool, get_ident_list, get_lit_str, get_mod_name, get_repr, get_string_map, get_usize,
+    get_visibility,
+};
+use crate::random::generate_random;
+
+/// Without feature `differential`, there is no untyped parser to compare with.
+#[cfg(not(feature = "differential"))]
+fn generate_pest_check(
+    _attrs: &[Attribute],
+    _rules: &[OptimizedRule],
+    _config: Config,
+    _mod_name: &ModName,
+) -> TokenStream {
+    panic!("`emit_pest_check` requires feature `differential`.")
+}
+#[cfg(feature = "differential")]
+use crate::differential::generate_pest_check;
+use pest_meta::ast::RuleType;
 use pest_meta::optimizer::OptimizedRule;
 use pest_meta::parser::{consume_rules, parse, rename_meta_rule, Rule};
 use pest_meta::{optimizer::optimize, unwrap_or_report};
 use proc_macro2::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote, quote_spanned};
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::PathBuf;
-use syn::DeriveInput;
 use syn::{self, Generics, Ident};
+use syn::{Attribute, DeriveInput, Meta};
 
 /// Processes the derive/proc macro input and generates the corresponding typed parser and nodes
 /// based on the parsed grammar. It will generate an explicit "include_str" statement.
@@ -43,33 +62,154 @@ pub fn derive_typed_parser(
     include_derive: bool,
 ) -> TokenStream {
     let ast: DeriveInput = syn::parse2(input).unwrap();
-    let (name, generics, contents, config) = parse_typed_derive(ast);
+    let attrs = ast.attrs.clone();
+    let (
+        name,
+        generics,
+        contents,
+        config,
+        skip_rules,
+        span_only,
+        entry_rules,
+        recover_at,
+        error_labels,
+        rule_references,
+        roots,
+        mod_name,
+    ) = parse_typed_derive(ast);
 
     let (data, paths) = collect_data(contents);
 
     let pairs = match parse(Rule::grammar_rules, &data) {
         Ok(pairs) => pairs,
-        Err(error) => panic!("error parsing \n{}", error.renamed_rules(rename_meta_rule)),
+        Err(error) => match inline_grammar_error(&attrs) {
+            Some(error) => return error.to_compile_error(),
+            None => panic!("error parsing \n{}", error.renamed_rules(rename_meta_rule)),
+        },
     };
 
     let doc_comment = consume(pairs.clone());
     let ast = unwrap_or_report(consume_rules(pairs));
+    if let Some(rule) = ast
+        .iter()
+        .find(|rule| PEST_KEYWORDS.contains(&rule.name.as_str()))
+    {
+        let message = format!(
+            "Rule `{}` is a pest keyword and can't be redefined.",
+            rule.name
+        );
+        return syn::Error::new(name.span(), message).to_compile_error();
+    }
+    if let Some(reserved) = reserved_name(&ast) {
+        let message = format!(
+            "`{}` can't be the name of a rule or a tag, as it can't be a raw identifier. Reserved names are {}.",
+            reserved,
+            RESERVED_NAMES
+                .iter()
+                .map(|name| format!("`{}`", name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+        return syn::Error::new(name.span(), message).to_compile_error();
+    }
     let optimized = optimize(ast);
 
+    let mut warning = None;
+    if let Some(message) = unused_rules(&optimized, &roots, &entry_rules, &skip_rules) {
+        if config.deny_unused_rules {
+            return syn::Error::new(name.span(), message).to_compile_error();
+        }
+        if !config.no_warnings {
+            warning = Some(warn(&name, &message));
+        }
+    }
+
     let input = Input {
         rules: optimized,
         doc_comment,
     };
 
-    generate_typed(
+    let output = generate_typed(
         name,
         &generics,
+        &attrs,
         paths,
         &input,
         include_grammar,
         include_derive,
         config,
-    )
+        &skip_rules,
+        &span_only,
+        &entry_rules,
+        &recover_at,
+        &error_labels,
+        &rule_references,
+        &mod_name,
+    );
+    quote! {
+        #output
+        #warning
+    }
+}
+
+/// A warning at `name`, as stable Rust has no API for warnings from procedural macros.
+///
+/// It's reported as the use of a deprecated item, so it respects `#[allow(deprecated)]`.
+fn warn(name: &Ident, message: &str) -> TokenStream {
+    quote_spanned! {name.span()=>
+        const _: () = {
+            #[deprecated(note = #message)]
+            struct Warning;
+            let _ = Warning;
+        };
+    }
+}
+
+/// Built-in rules that pest and the generated codes rely on, which can't be redefined.
+///
+/// Other built-in rules, such as `NEWLINE`, are replaced by rules with the same names.
+const PEST_KEYWORDS: &[&str] = &[
+    "ANY", "DROP", "EOI", "PEEK", "PEEK_ALL", "POP", "POP_ALL", "PUSH", "SOI",
+];
+
+/// Rust keywords that can't be raw identifiers, which are used for rules and tags in generated codes.
+///
+/// Other names, such as `type`, `Rule` or `pairs`, are escaped as raw identifiers
+/// and live in modules of their own, so they don't collide with generated items.
+const RESERVED_NAMES: &[&str] = &["_", "crate", "self", "Self", "super"];
+
+/// First rule or tag with a name in [`RESERVED_NAMES`].
+fn reserved_name(rules: &[pest_meta::ast::Rule]) -> Option<String> {
+    rules.iter().find_map(|rule| {
+        let tags = rule.expr.iter_top_down().filter_map(|expr| match expr {
+            #[cfg(feature = "grammar-extras")]
+            pest_meta::ast::Expr::NodeTag(_, tag) => Some(tag),
+            _ => None,
+        });
+        core::iter::once(rule.name.clone())
+            .chain(tags)
+            .find(|name| RESERVED_NAMES.contains(&name.as_str()))
+    })
+}
+
+/// Syntax error in the first inline grammar that fails to be parsed on its own,
+/// reported at the string literal of the grammar.
+///
+/// Stable proc-macro API can't point into a string literal,
+/// so the message shows the offending line of the grammar with a caret instead.
+fn inline_grammar_error(attrs: &[Attribute]) -> Option<syn::Error> {
+    attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("grammar_inline"))
+        .find_map(|attr| {
+            let lit = get_lit_str(attr);
+            let error = parse(Rule::grammar_rules, &lit.value()).err()?;
+            let message = format!(
+                "error parsing inline grammar\n{}",
+                error.renamed_rules(rename_meta_rule)
+            );
+            Some(syn::Error::new(lit.span(), message))
+        })
 }
 
 struct Input {
@@ -77,12 +217,104 @@ struct Input {
     doc_comment: DocComment,
 }
 
-fn parse_typed_derive(ast: DeriveInput) -> (Ident, Generics, Vec<GrammarSource>, Config) {
+/// Rule Name -> Name of the rule to skip in it.
+type SkipRules = BTreeMap<String, String>;
+/// Names of rules that only emit spans.
+type SpanOnly = BTreeSet<String>;
+/// Names of rules with a `parse_<rule>` method on the parser. All non-silent rules if unspecified.
+type EntryRules = Option<BTreeSet<String>>;
+/// Names of rules that repetitions recover at.
+type RecoverAt = BTreeSet<String>;
+/// Rule Name -> Name of the rule in error messages.
+type ErrorLabels = BTreeMap<String, String>;
+/// Names of rules with accessers to referenced rules, besides all rules if `emit_rule_reference` is set.
+type RuleReferences = BTreeSet<String>;
+/// Names of rules that other rules should be reachable from. All non-silent rules if unspecified.
+type Roots = Option<BTreeSet<String>>;
+/// Name of the module re-exporting rule structs. `pairs` if unspecified.
+type ModName = Ident;
+
+/// Message listing rules that can't be reached from the roots, if any.
+///
+/// `WHITESPACE`, `COMMENT` and rules in `skip_rules` are used implicitly,
+/// and rules in `entry_rules` are parsed directly, so they are always reachable.
+fn unused_rules(
+    rules: &[OptimizedRule],
+    roots: &Roots,
+    entry_rules: &EntryRules,
+    skip_rules: &SkipRules,
+) -> Option<String> {
+    if let Some(roots) = roots {
+        for rule_name in roots {
+            if !rules.iter().any(|rule| &rule.name == rule_name) {
+                panic!("Rule `{}` in `roots` is not defined.", rule_name)
+            }
+        }
+    }
+    let dependencies: BTreeMap<&str, Vec<&str>> = collect_dependencies(rules).into_iter().collect();
+    let mut pending: Vec<&str> = rules
+        .iter()
+        .filter(|rule| match roots {
+            Some(roots) => roots.contains(&rule.name),
+            None => rule.ty != RuleType::Silent,
+        })
+        .map(|rule| rule.name.as_str())
+        .chain(["WHITESPACE", "COMMENT"])
+        .chain(entry_rules.iter().flatten().map(String::as_str))
+        .chain(skip_rules.values().map(String::as_str))
+        .collect();
+    let mut reachable = BTreeSet::new();
+    while let Some(rule_name) = pending.pop() {
+        if reachable.insert(rule_name) {
+            if let Some(used) = dependencies.get(rule_name) {
+                pending.extend(used);
+            }
+        }
+    }
+    let unused: Vec<String> = rules
+        .iter()
+        .filter(|rule| !reachable.contains(rule.name.as_str()))
+        .map(|rule| format!("`{}`", rule.name))
+        .collect();
+    if unused.is_empty() {
+        return None;
+    }
+    Some(format!(
+        "Rules unreachable from root rules: {}.",
+        unused.join(", ")
+    ))
+}
+
+#[allow(clippy::type_complexity)]
+fn parse_typed_derive(
+    ast: DeriveInput,
+) -> (
+    Ident,
+    Generics,
+    Vec<GrammarSource>,
+    Config,
+    SkipRules,
+    SpanOnly,
+    EntryRules,
+    RecoverAt,
+    ErrorLabels,
+    RuleReferences,
+    Roots,
+    ModName,
+) {
     let name = ast.ident;
     let generics = ast.generics;
 
     let mut grammar_sources = vec![];
     let mut config = Config::default();
+    let mut skip_rules = SkipRules::new();
+    let mut span_only = SpanOnly::new();
+    let mut entry_rules = EntryRules::None;
+    let mut recover_at = RecoverAt::new();
+    let mut error_labels = ErrorLabels::new();
+    let mut rule_references = RuleReferences::new();
+    let mut roots = Roots::None;
+    let mut mod_name = format_ident!("pairs");
     for attr in ast.attrs.iter() {
         let path = attr.meta.path();
         if path.is_ident("grammar") {
@@ -90,7 +322,10 @@ fn parse_typed_derive(ast: DeriveInput) -> (Ident, Generics, Vec<GrammarSource>,
         } else if path.is_ident("grammar_inline") {
             grammar_sources.push(GrammarSource::Inline(get_string(attr)));
         } else if path.is_ident("emit_rule_reference") {
-            config.emit_rule_reference = get_bool(attr);
+            match attr.meta {
+                Meta::List(_) => rule_references.extend(get_ident_list(attr)),
+                _ => config.emit_rule_reference = get_bool(attr),
+            }
         } else if path.is_ident("emit_tagged_node_reference") {
             config.emit_tagged_node_reference = get_bool(attr);
         } else if path.is_ident("do_not_emit_span") {
@@ -104,8 +339,60 @@ fn parse_typed_derive(ast: DeriveInput) -> (Ident, Generics, Vec<GrammarSource>,
             config.simulate_pair_api = get_bool(attr);
         } else if path.is_ident("box_only_if_needed") {
             config.box_only_if_needed = get_bool(attr);
+        } else if path.is_ident("box_all_rules") {
+            config.box_only_if_needed = !get_bool(attr);
         } else if path.is_ident("no_warnings") {
             config.no_warnings = get_bool(attr);
+        } else if path.is_ident("inline_grammar_docs") {
+            config.inline_grammar_docs = get_bool(attr);
+        } else if path.is_ident("typed_vis") {
+            config.typed_vis = get_visibility(attr);
+        } else if path.is_ident("emit_visitor") {
+            config.emit_visitor = get_bool(attr);
+        } else if path.is_ident("atomic_content") {
+            config.atomic_content = get_bool(attr);
+        } else if path.is_ident("rule_recursion_limit") {
+            config.recursion_limit = Some(get_usize(attr));
+        } else if path.is_ident("skip_rules") {
+            skip_rules.extend(get_string_map(attr));
+        } else if path.is_ident("span_only") {
+            span_only.extend(get_ident_list(attr));
+        } else if path.is_ident("entry_rules") {
+            entry_rules
+                .get_or_insert_with(BTreeSet::new)
+                .extend(get_ident_list(attr));
+        } else if path.is_ident("recover_at") {
+            recover_at.extend(get_ident_list(attr));
+        } else if path.is_ident("error_label") {
+            error_labels.extend(get_string_map(attr));
+        } else if path.is_ident("roots") {
+            roots
+                .get_or_insert_with(BTreeSet::new)
+                .extend(get_ident_list(attr));
+        } else if path.is_ident("deny_unused_rules") {
+            config.deny_unused_rules = get_bool(attr);
+        } else if path.is_ident("lower_str_choices") {
+            config.lower_str_choices = get_bool(attr);
+        } else if path.is_ident("rule_non_exhaustive") {
+            config.rule_non_exhaustive = get_bool(attr);
+        } else if path.is_ident("rule_repr") {
+            config.rule_repr = Some(get_repr(attr));
+        } else if path.is_ident("silent_spans") {
+            config.silent_spans = get_bool(attr);
+        } else if path.is_ident("tokenize_literals") {
+            config.tokenize_literals = get_bool(attr);
+        } else if path.is_ident("emit_match_macros") {
+            config.emit_match_macros = get_bool(attr);
+        } else if path.is_ident("lower_rep_until") {
+            config.lower_rep_until = get_bool(attr);
+        } else if path.is_ident("emit_generator") {
+            config.emit_generator = get_bool(attr);
+        } else if path.is_ident("emit_pest_check") {
+            config.emit_pest_check = get_bool(attr);
+        } else if path.is_ident("unicode_case_insensitive") {
+            config.unicode_case_insensitive = get_bool(attr);
+        } else if path.is_ident("mod_name") {
+            mod_name = get_mod_name(attr);
         }
     }
 
@@ -113,18 +400,40 @@ fn parse_typed_derive(ast: DeriveInput) -> (Ident, Generics, Vec<GrammarSource>,
         panic!("A grammar file needs to be provided with the #[grammar = \"PATH\"] or #[grammar_inline = \"GRAMMAR CONTENTS\"] attribute.");
     }
 
-    (name, generics, grammar_sources, config)
+    (
+        name,
+        generics,
+        grammar_sources,
+        config,
+        skip_rules,
+        span_only,
+        entry_rules,
+        recover_at,
+        error_labels,
+        rule_references,
+        roots,
+        mod_name,
+    )
 }
 
 /// Generate codes for Parser.
+#[allow(clippy::too_many_arguments)]
 fn generate_typed(
     name: Ident,
     generics: &Generics,
+    attrs: &[Attribute],
     paths: Vec<PathBuf>,
     input: &Input,
     include_grammar: bool,
     include_derive: bool,
     config: Config,
+    skip_rules: &SkipRules,
+    span_only: &SpanOnly,
+    entry_rules: &EntryRules,
+    recover_at: &RecoverAt,
+    error_labels: &ErrorLabels,
+    rule_references: &RuleReferences,
+    mod_name: &ModName,
 ) -> TokenStream {
     let include_fix = if include_grammar {
         generate_include(&name, paths)
@@ -132,33 +441,192 @@ fn generate_typed(
         quote!()
     };
     let Input { rules, doc_comment } = input;
-    let rule_enum = generate_enum(rules, doc_comment);
-    let pairs = generate_typed_pair_from_rule(rules, doc_comment, config);
+    let rule_enum = generate_enum(rules, doc_comment, error_labels, config);
+    let pairs = generate_typed_pair_from_rule(
+        rules,
+        doc_comment,
+        config,
+        skip_rules,
+        span_only,
+        recover_at,
+        error_labels,
+        rule_references,
+        mod_name,
+    );
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let pest_typed = pest_typed();
 
     let parser_impl = if include_derive {
+        let entries = generate_entries(rules, entry_rules, config, mod_name);
         quote! {
             impl #impl_generics #pest_typed::TypedParser<Rule> for #name #ty_generics #where_clause {}
+            impl #impl_generics #name #ty_generics #where_clause {
+                #entries
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let dispatch = generate_dispatch(rules, config, mod_name);
+    let dependencies = generate_dependencies(rules, config);
+    let generator = if config.emit_generator {
+        match generate_random(rules, config) {
+            Ok(generator) => generator,
+            Err(message) => syn::Error::new(name.span(), message).to_compile_error(),
         }
     } else {
         quote! {}
     };
+    let pest_check = if config.emit_pest_check {
+        generate_pest_check(attrs, rules, config, mod_name)
+    } else {
+        quote! {}
+    };
 
     let res = quote! {
         #include_fix
         #rule_enum
         #pairs
+        #dispatch
+        #dependencies
+        #generator
+        #pest_check
         #parser_impl
     };
     res
 }
 
+/// Generate `parse_<rule>` methods for entry rules.
+fn generate_entries(
+    rules: &[OptimizedRule],
+    entry_rules: &EntryRules,
+    config: Config,
+    mod_name: &ModName,
+) -> TokenStream {
+    let pest_typed = pest_typed();
+    let vis = config.typed_vis;
+    if let Some(entry_rules) = entry_rules {
+        for rule_name in entry_rules {
+            if !rules.iter().any(|rule| &rule.name == rule_name) {
+                panic!("Rule `{}` in `entry_rules` is not defined.", rule_name)
+            }
+        }
+    }
+    let entries = rules.iter().filter(|rule| match entry_rules {
+        Some(entry_rules) => entry_rules.contains(&rule.name),
+        None => rule.ty != RuleType::Silent,
+    });
+    let methods = entries.map(|rule| {
+        let rule_name = &rule.name;
+        let rule_ident = format_ident!("r#{}", rule_name);
+        let method = format_ident!("parse_{}", rule_name);
+        let doc = format!(
+            "Parse the whole input with rule [`{1}`]({0}::{1}).",
+            mod_name, rule_name
+        );
+        if rule.ty == RuleType::Silent {
+            // Silent rule structs have no span, so the span of the whole input is attached.
+            return quote! {
+                #[doc = #doc]
+                #[doc = ""]
+                #[doc = "The rule is silent, so the result is wrapped with the span of the whole input."]
+                #[allow(non_snake_case)]
+                #vis fn #method<'i>(
+                    input: &'i ::core::primitive::str,
+                ) -> #pest_typed::error::ParseResult<#pest_typed::Root<'i, #mod_name::#rule_ident<'i>>, Rule> {
+                    <#mod_name::#rule_ident<'i> as #pest_typed::ParsableTypedNode<'i, Rule>>::try_parse_root(input)
+                }
+            };
+        }
+        quote! {
+            #[doc = #doc]
+            #[allow(non_snake_case)]
+            #vis fn #method<'i>(
+                input: &'i ::core::primitive::str,
+            ) -> #pest_typed::error::ParseResult<#mod_name::#rule_ident<'i>, Rule> {
+                <#mod_name::#rule_ident<'i> as #pest_typed::ParsableTypedNode<'i, Rule>>::try_parse(input)
+            }
+        }
+    });
+    quote! { #(#methods)* }
+}
+
+/// Generate `parse_rule`, which parses with a rule selected by a `Rule` value.
+fn generate_dispatch(rules: &[OptimizedRule], config: Config, mod_name: &ModName) -> TokenStream {
+    let pest_typed = pest_typed();
+    let vis = config.typed_vis;
+    let arms = rules.iter().map(|rule| {
+        let rule_ident = format_ident!("r#{}", rule.name);
+        if rule.ty == RuleType::Silent {
+            let message = format!(
+                "Rule `{}` is silent, so it can't be parsed into a pair.",
+                rule.name
+            );
+            quote! {
+                Rule::#rule_ident => ::core::result::Result::Err(#pest_typed::Box::new(
+                    #pest_typed::error::ParseFailure::new(
+                        <#pest_typed::error::Error<Rule> as #pest_typed::error::CustomError<'i>>::custom_at_pos(
+                            #pest_typed::Position::from_start(input),
+                            #message,
+                        ),
+                        #pest_typed::Position::from_start(input),
+                    ),
+                )),
+            }
+        } else {
+            quote! {
+                Rule::#rule_ident => parse::<#mod_name::#rule_ident<'i>>(input),
+            }
+        }
+    });
+    quote! {
+        /// Parse the whole input with a rule selected at runtime.
+        ///
+        /// Silent rules have no pairs, so an error is returned for them.
+        #[allow(dead_code)]
+        #vis fn parse_rule<'i>(
+            rule: Rule,
+            input: &'i ::core::primitive::str,
+        ) -> #pest_typed::error::ParseResult<#pest_typed::Box<dyn #pest_typed::iterators::AnyPair<'i, Rule> + 'i>, Rule> {
+            fn parse<'i, T: #pest_typed::ParsableTypedNode<'i, Rule> + #pest_typed::iterators::AnyPair<'i, Rule> + 'i>(
+                input: &'i ::core::primitive::str,
+            ) -> #pest_typed::error::ParseResult<#pest_typed::Box<dyn #pest_typed::iterators::AnyPair<'i, Rule> + 'i>, Rule> {
+                let res = T::try_parse(input)?;
+                ::core::result::Result::Ok(#pest_typed::Box::new(res))
+            }
+            match rule {
+                Rule::EOI => parse::<#mod_name::EOI<'i>>(input),
+                #(#arms)*
+            }
+        }
+    }
+}
+
+/// Generate `rule_dependencies`, which lists rules directly referenced by each rule.
+fn generate_dependencies(rules: &[OptimizedRule], config: Config) -> TokenStream {
+    let vis = config.typed_vis;
+    let entries = collect_dependencies(rules).into_iter().map(|(rule, used)| {
+        let rule = format_ident!("r#{}", rule);
+        let used = used.into_iter().map(|used| format_ident!("r#{}", used));
+        quote! { (Rule::#rule, &[#(Rule::#used),*]) }
+    });
+    quote! {
+        /// Rules directly referenced by each rule, in the order they are defined.
+        ///
+        /// Built-in rules other than `EOI` and implicit `WHITESPACE` and `COMMENT` are not listed.
+        #[allow(dead_code)]
+        #vis fn rule_dependencies() -> &'static [(Rule, &'static [Rule])] {
+            &[#(#entries),*]
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use quote::format_ident;
+    use crate::config::Visibility;
     #[test]
     fn test_default_config() {
         let ast: DeriveInput = syn::parse2(quote! {
@@ -166,13 +634,34 @@ mod tests {
             struct x;
         })
         .unwrap();
-        let (name, _, contents, config) = parse_typed_derive(ast);
+        let (
+            name,
+            _,
+            contents,
+            config,
+            skip_rules,
+            span_only,
+            entry_rules,
+            recover_at,
+            error_labels,
+            rule_references,
+            roots,
+            mod_name,
+        ) = parse_typed_derive(ast);
         assert_eq!(name, format_ident!("x"));
         assert_eq!(
             contents,
             vec![GrammarSource::Inline(r#"x = { "x" }"#.to_owned())]
         );
         assert_eq!(config, Config::default());
+        assert!(skip_rules.is_empty());
+        assert!(span_only.is_empty());
+        assert!(entry_rules.is_none());
+        assert!(recover_at.is_empty());
+        assert!(error_labels.is_empty());
+        assert!(rule_references.is_empty());
+        assert!(roots.is_none());
+        assert_eq!(mod_name, format_ident!("pairs"));
         assert_eq!(
             format!("{:?}", config.clone()),
             format!("{:?}", Config::default())
@@ -186,10 +675,14 @@ mod tests {
             #[emit_rule_reference]
             #[no_warnings = true]
             #[truncate_accesser_at_node_tag = false]
+            #[rule_recursion_limit = 64]
+            #[tokenize_literals]
+            #[emit_match_macros]
+            #[lower_str_choices]
             struct x;
         })
         .unwrap();
-        let (_, _, _, config) = parse_typed_derive(ast);
+        let (_, _, _, config, _, _, _, _, _, _, _, _) = parse_typed_derive(ast);
         assert_eq!(
             config,
             Config {
@@ -198,12 +691,503 @@ mod tests {
                 do_not_emit_span: false,
                 truncate_accesser_at_node_tag: false,
                 simulate_pair_api: false,
-                box_only_if_needed: false,
-                no_warnings: true
+                box_only_if_needed: true,
+                no_warnings: true,
+                inline_grammar_docs: false,
+                typed_vis: Visibility::Public,
+                emit_visitor: false,
+                atomic_content: false,
+                recursion_limit: Some(64),
+                deny_unused_rules: false,
+                lower_str_choices: true,
+                rule_non_exhaustive: false,
+                rule_repr: None,
+                silent_spans: false,
+                tokenize_literals: true,
+                emit_match_macros: true,
+                lower_rep_until: false,
+                emit_generator: false,
+                emit_pest_check: false,
+                unicode_case_insensitive: false,
             }
         );
     }
 
+    #[test]
+    fn box_all_rules() {
+        let parse = |attr: TokenStream| {
+            let ast: DeriveInput = syn::parse2(quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #attr
+                struct x;
+            })
+            .unwrap();
+            parse_typed_derive(ast).3.box_only_if_needed
+        };
+        assert!(parse(quote! {}));
+        assert!(!parse(quote! {#[box_all_rules]}));
+        assert!(parse(quote! {#[box_all_rules = false]}));
+        assert!(!parse(quote! {#[box_only_if_needed = false]}));
+    }
+
+    #[test]
+    fn typed_vis() {
+        let parse = |vis: &str| {
+            let ast: DeriveInput = syn::parse2(quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[typed_vis = #vis]
+                struct x;
+            })
+            .unwrap();
+            parse_typed_derive(ast).3.typed_vis
+        };
+        assert_eq!(parse("pub"), Visibility::Public);
+        assert_eq!(parse("pub(crate)"), Visibility::Crate);
+        assert_eq!(parse("pub(super)"), Visibility::Super);
+        assert_eq!(parse("pub(self)"), Visibility::Private);
+        assert_eq!(parse(""), Visibility::Private);
+    }
+
+    #[test]
+    #[should_panic]
+    fn typed_vis_error() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[typed_vis = "pub(in crate::x)"]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn mod_name() {
+        let ast: DeriveInput = syn::parse2(quote! {
+            #[grammar_inline = "x = { \"x\" }"]
+            #[mod_name = "nodes"]
+            struct x;
+        })
+        .unwrap();
+        assert_eq!(parse_typed_derive(ast).11, format_ident!("nodes"));
+
+        let output = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[mod_name = "nodes"]
+                struct x;
+            },
+            false,
+            true,
+        )
+        .to_string();
+        assert!(output.contains("use rules as nodes ;"));
+        assert!(output.contains("parse :: < nodes :: EOI"));
+        assert!(!output.contains("pairs ::"));
+    }
+
+    #[test]
+    #[should_panic(expected = "mod_name attribute must be an identifier")]
+    fn mod_name_error() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[mod_name = "a::b"]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn skip_rules() {
+        let ast: DeriveInput = syn::parse2(quote! {
+            #[grammar_inline = "x = { \"x\" }"]
+            #[skip_rules(x = "", r#type = "SPACE")]
+            #[skip_rules(y = "TAB")]
+            struct x;
+        })
+        .unwrap();
+        let (_, _, _, _, skip_rules, _, _, _, _, _, _, _) = parse_typed_derive(ast);
+        assert_eq!(
+            skip_rules,
+            SkipRules::from([
+                ("type".to_owned(), "SPACE".to_owned()),
+                ("x".to_owned(), "".to_owned()),
+                ("y".to_owned(), "TAB".to_owned()),
+            ])
+        );
+    }
+
+    #[test]
+    fn span_only() {
+        let ast: DeriveInput = syn::parse2(quote! {
+            #[grammar_inline = "x = { \"x\" }"]
+            #[span_only(x, r#type)]
+            #[span_only(y)]
+            struct x;
+        })
+        .unwrap();
+        let (_, _, _, _, _, span_only, _, _, _, _, _, _) = parse_typed_derive(ast);
+        assert_eq!(
+            span_only,
+            SpanOnly::from(["type".to_owned(), "x".to_owned(), "y".to_owned()])
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn span_only_undefined() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[span_only(y)]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn entry_rules() {
+        let ast: DeriveInput = syn::parse2(quote! {
+            #[grammar_inline = "x = { \"x\" }"]
+            #[entry_rules(x, r#type)]
+            struct x;
+        })
+        .unwrap();
+        let (_, _, _, _, _, _, entry_rules, _, _, _, _, _) = parse_typed_derive(ast);
+        assert_eq!(
+            entry_rules,
+            Some(BTreeSet::from(["type".to_owned(), "x".to_owned()]))
+        );
+
+        let methods = |attr: TokenStream| {
+            let output = derive_typed_parser(
+                quote! {
+                    #[grammar_inline = "a = { b } b = { \"b\" } c = _{ b }"]
+                    #attr
+                    struct x;
+                },
+                false,
+                true,
+            )
+            .to_string();
+            ["parse_a", "parse_b", "parse_c"].map(|method| output.contains(method))
+        };
+        assert_eq!(methods(quote! {}), [true, true, false]);
+        assert_eq!(methods(quote! {#[entry_rules(a)]}), [true, false, false]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn entry_rules_undefined() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[entry_rules(y)]
+                struct x;
+            },
+            false,
+            true,
+        );
+    }
+
+    #[test]
+    fn entry_rules_silent() {
+        let output = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = _{ \"x\" }"]
+                #[entry_rules(x)]
+                struct x;
+            },
+            false,
+            true,
+        )
+        .to_string();
+        assert!(output.contains("parse_x"));
+        assert!(output.contains("try_parse_root"));
+    }
+
+    #[test]
+    fn predicate_accessers() {
+        let output = |grammar: &str| {
+            derive_typed_parser(
+                quote! {
+                    #[grammar_inline = #grammar]
+                    #[emit_rule_reference]
+                    struct x;
+                },
+                false,
+                true,
+            )
+            .to_string()
+        };
+        let accesser = "pub fn r#y";
+        assert!(output(r#"x = { &y ~ ANY } y = { "y" }"#).contains(accesser));
+        assert!(output(r#"x = { &(!"z" ~ y) ~ ANY } y = { "y" }"#).contains(accesser));
+        assert!(!output(r#"x = { !y ~ ANY } y = { "y" }"#).contains(accesser));
+    }
+
+    #[test]
+    fn recover_at() {
+        let ast: DeriveInput = syn::parse2(quote! {
+            #[grammar_inline = "x = { \"x\" }"]
+            #[recover_at(x)]
+            #[recover_at(y, z)]
+            struct x;
+        })
+        .unwrap();
+        let (_, _, _, _, _, _, _, recover_at, _, _, _, _) = parse_typed_derive(ast);
+        assert_eq!(
+            recover_at,
+            RecoverAt::from(["x".to_owned(), "y".to_owned(), "z".to_owned()])
+        );
+
+        let recovers = |grammar: &str| {
+            derive_typed_parser(
+                quote! {
+                    #[grammar_inline = #grammar]
+                    #[recover_at(sep)]
+                    struct x;
+                },
+                false,
+                false,
+            )
+            .to_string()
+            .contains("Recover")
+        };
+        assert!(recovers("a = { (\"a\" ~ sep)* } sep = { \";\" }"));
+        assert!(!recovers("a = { (sep ~ \"a\")* } sep = { \";\" }"));
+        assert!(!recovers("a = { sep* } sep = { \";\" }"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn recover_at_undefined() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[recover_at(y)]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn recursion_limit_format_error() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[rule_recursion_limit = "64"]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn skip_rules_format_error() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[skip_rules = "x"]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn skip_rules_undefined() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[skip_rules(x = "y")]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn error_label() {
+        let ast: DeriveInput = syn::parse2(quote! {
+            #[grammar_inline = "x = { \"x\" }"]
+            #[error_label(x = "an x", r#type = "a type")]
+            struct x;
+        })
+        .unwrap();
+        let (_, _, _, _, _, _, _, _, error_labels, _, _, _) = parse_typed_derive(ast);
+        assert_eq!(
+            error_labels,
+            ErrorLabels::from([
+                ("type".to_owned(), "a type".to_owned()),
+                ("x".to_owned(), "an x".to_owned()),
+            ])
+        );
+
+        let output = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" } y = { \"y\" }"]
+                #[error_label(x = "an x")]
+                struct x;
+            },
+            false,
+            false,
+        )
+        .to_string();
+        assert_eq!(output.matches("error_label = \"an x\"").count(), 1);
+        assert!(output.contains("Rule :: r#x => :: core :: option :: Option :: Some (\"an x\")"));
+        assert!(output.contains("Rule :: r#y => :: core :: option :: Option :: None"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_label_undefined() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[error_label(y = "a y")]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn error_label_silent() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = _{ \"x\" }"]
+                #[error_label(x = "an x")]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
+    #[test]
+    fn rule_references() {
+        let ast: DeriveInput = syn::parse2(quote! {
+            #[grammar_inline = "x = { \"x\" }"]
+            #[emit_rule_reference(x, r#type)]
+            #[emit_rule_reference(y)]
+            struct x;
+        })
+        .unwrap();
+        let (_, _, _, config, _, _, _, _, _, rule_references, _, _) = parse_typed_derive(ast);
+        assert!(!config.emit_rule_reference);
+        assert_eq!(
+            rule_references,
+            RuleReferences::from(["type".to_owned(), "x".to_owned(), "y".to_owned()])
+        );
+
+        let output = |attr: TokenStream| {
+            derive_typed_parser(
+                quote! {
+                    #[grammar_inline = "a = { b ~ c } b = { c* } c = { \"c\" } d = { a ~ b ~ c }"]
+                    #attr
+                    struct x;
+                },
+                false,
+                false,
+            )
+            .to_string()
+        };
+        let accessers = |output: &str| {
+            ["pub fn r#a", "pub fn r#b", "pub fn r#c"]
+                .map(|accesser| output.matches(accesser).count())
+        };
+        let all = output(quote! {#[emit_rule_reference]});
+        let filtered = output(quote! {#[emit_rule_reference(b)]});
+        let none = output(quote! {});
+        assert_eq!(accessers(&all), [1, 2, 3]);
+        assert_eq!(accessers(&filtered), [0, 0, 1]);
+        assert_eq!(accessers(&none), [0, 0, 0]);
+        assert!(none.len() < filtered.len());
+        assert!(filtered.len() < all.len());
+    }
+
+    #[test]
+    fn redefined_keyword() {
+        let output = |grammar: &str| {
+            derive_typed_parser(
+                quote! {
+                    #[grammar_inline = #grammar]
+                    struct x;
+                },
+                false,
+                false,
+            )
+            .to_string()
+        };
+        for keyword in ["ANY", "EOI", "SOI", "PEEK"] {
+            let output = output(&format!("{} = {{ \"a\" }} x = {{ {} }}", keyword, keyword));
+            assert!(output.contains("compile_error"), "{}", output);
+            assert!(output.contains("is a pest keyword"), "{}", output);
+        }
+        let output = output("NEWLINE = { \";\" } x = { NEWLINE }");
+        assert!(!output.contains("compile_error"));
+        assert!(!output.contains("predefined_node :: NEWLINE"));
+    }
+
+    #[test]
+    fn reserved_names() {
+        let output = |grammar: &str| {
+            derive_typed_parser(
+                quote! {
+                    #[grammar_inline = #grammar]
+                    struct x;
+                },
+                false,
+                false,
+            )
+            .to_string()
+        };
+        for name in RESERVED_NAMES {
+            let output = output(&format!("{} = {{ \"a\" }} x = {{ {} }}", name, name));
+            assert!(output.contains("compile_error"), "{}", output);
+            assert!(output.contains("can't be a raw identifier"), "{}", output);
+        }
+        #[cfg(feature = "grammar-extras")]
+        {
+            let output = output("x = { #self = \"a\" }");
+            assert!(output.contains("`self` can't be the name"), "{}", output);
+        }
+        let output =
+            output("type = { \"a\" } Rule = { type } pairs = { Rule ~ tags } tags = { \"b\" }");
+        assert!(!output.contains("compile_error"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rule_references_undefined() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[emit_rule_reference(y)]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
+
     #[test]
     #[should_panic]
     fn invalid_path() {
@@ -244,9 +1228,8 @@ mod tests {
     }
 
     #[test]
-    #[should_panic]
     fn parse_failure() {
-        let _ = derive_typed_parser(
+        let output = derive_typed_parser(
             quote! {
                 #[grammar_inline = "x = { }"]
                 struct x;
@@ -254,6 +1237,27 @@ mod tests {
             false,
             false,
         );
+        let output = output.to_string();
+        assert!(output.starts_with(":: core :: compile_error !"), "{output}");
+        assert!(output.contains("error parsing inline grammar"), "{output}");
+    }
+
+    #[test]
+    fn parse_failure_location() {
+        let grammar = "\nx = { \"x\" }\ny = { x ~ }\nz = { y }\n";
+        let output = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "a = { \"a\" }"]
+                #[grammar_inline = #grammar]
+                struct x;
+            },
+            false,
+            false,
+        );
+        let output = output.to_string();
+        assert!(output.contains(" --> 3:11"), "{output}");
+        assert!(output.contains("3 | y = { x ~ }"), "{output}");
+        assert!(output.contains("|           ^---"), "{output}");
     }
 
     #[test]
@@ -283,4 +1287,108 @@ mod tests {
             false,
         );
     }
+
+    #[test]
+    fn unused_rules() {
+        let message = |attr: TokenStream| {
+            let ast: DeriveInput = syn::parse2(quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #attr
+                struct x;
+            })
+            .unwrap();
+            let (_, _, _, _, _, _, entry_rules, _, _, _, roots, _) = parse_typed_derive(ast);
+            let grammar = r#"
+WHITESPACE = _{ " " }
+file       =  { SOI ~ item* ~ EOI }
+item       =  { key ~ "=" ~ value }
+key        = _{ ASCII_ALPHA+ }
+value      = _{ ASCII_DIGIT+ }
+orphan     = _{ "x" ~ helper }
+helper     = _{ "y" }
+"#;
+            let pairs = parse(Rule::grammar_rules, grammar).unwrap();
+            let rules = optimize(consume_rules(pairs).unwrap());
+            super::unused_rules(&rules, &roots, &entry_rules, &SkipRules::new())
+        };
+        assert_eq!(
+            message(quote! {}).as_deref(),
+            Some("Rules unreachable from root rules: `orphan`, `helper`.")
+        );
+        assert_eq!(message(quote! {#[roots(file, orphan)]}), None);
+        assert_eq!(
+            message(quote! {#[roots(item)]}).as_deref(),
+            Some("Rules unreachable from root rules: `file`, `orphan`, `helper`.")
+        );
+        assert_eq!(message(quote! {#[entry_rules(file, orphan)]}), None);
+    }
+
+    #[test]
+    fn deny_unused_rules() {
+        let output = |attr: TokenStream| {
+            derive_typed_parser(
+                quote! {
+                    #[grammar_inline = "a = { b } b = { \"b\" } c = { b }"]
+                    #attr
+                    struct x;
+                },
+                false,
+                false,
+            )
+            .to_string()
+        };
+        let error = "compile_error";
+        assert!(!output(quote! {#[deny_unused_rules]}).contains(error));
+        let denied = output(quote! {#[roots(a)] #[deny_unused_rules]});
+        assert!(denied.contains(error), "{denied}");
+        assert!(denied.contains("Rules unreachable from root rules: `c`."));
+        assert!(!output(quote! {#[roots(a)] #[no_warnings]}).contains(error));
+
+        let warning = "deprecated";
+        assert!(!output(quote! {}).contains(warning));
+        let warned = output(quote! {#[roots(a)]});
+        assert!(warned.contains(warning), "{warned}");
+        assert!(warned.contains("Rules unreachable from root rules: `c`."));
+        assert!(!output(quote! {#[roots(a)] #[no_warnings]}).contains(warning));
+    }
+
+    #[test]
+    fn emit_generator_unsupported() {
+        let output = |grammar: &str| {
+            derive_typed_parser(
+                quote! {
+                    #[grammar_inline = #grammar]
+                    #[emit_generator]
+                    struct x;
+                },
+                false,
+                false,
+            )
+            .to_string()
+        };
+        let supported = output("a = { PUSH(\"a\") ~ b* ~ POP } b = { ^\"b\" | 'c'..'d' }");
+        assert!(!supported.contains("compile_error"), "{supported}");
+        assert!(supported.contains("generate_random"));
+        let unsupported = output("a = { !\"b\" ~ ANY ~ b } b = { \"b\" ~ b } c = { &a ~ LETTER }");
+        assert!(unsupported.contains("compile_error"), "{unsupported}");
+        assert!(unsupported.contains(
+            "Inputs can't be generated for grammars with negative predicate in rule `a`, \
+             positive predicate in rule `c`, rule `a` that never terminates, \
+             rule `b` that never terminates, unicode property `LETTER` in rule `c`."
+        ));
+    }
+
+    #[test]
+    #[should_panic]
+    fn roots_undefined() {
+        let _ = derive_typed_parser(
+            quote! {
+                #[grammar_inline = "x = { \"x\" }"]
+                #[roots(y)]
+                struct x;
+            },
+            false,
+            false,
+        );
+    }
 }
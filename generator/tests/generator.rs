@@ -38,10 +38,11 @@ fn generate(name: &'static str) {
     let actual = actual.to_string();
     let mut f = std::fs::File::create(&path_generated).unwrap();
     writeln!(f, "{}", feature).unwrap();
-    writeln!(f, "{}", "#![allow(unused_parens)]").unwrap();
+    writeln!(f, "#![allow(clippy::all)]").unwrap();
     writeln!(f, "{}", actual).unwrap();
     drop(f);
     let output = std::process::Command::new("rustfmt")
+        .arg("--edition=2021")
         .arg(&path_generated)
         .output()
         .unwrap();
@@ -66,3 +67,105 @@ fn syntax() {
 fn grammar() {
     generate("grammar")
 }
+
+#[test]
+fn tokens() {
+    generate("tokens")
+}
+
+#[test]
+fn inline_grammar_docs() {
+    let generate = |inline_grammar_docs: bool| {
+        derive_typed_parser(
+            quote! {
+                #[grammar_inline = "/// An item.\nitem = { \"x\" }\nlist = { item ~ (\",\" ~ item)* }"]
+                #[emit_rule_reference]
+                #[inline_grammar_docs = #inline_grammar_docs]
+                #[no_warnings]
+                struct Parser;
+            },
+            false,
+            false,
+        )
+        .to_string()
+    };
+    // Doc comments between the first line of accesser doc and the accesser itself.
+    let accesser_docs = |generated: &str| {
+        let start = generated
+            .find("A helper function to access [`item`].")
+            .unwrap();
+        let end = start + generated[start..].find("pub fn r#item").unwrap();
+        generated[start..end].to_owned()
+    };
+
+    let docs = accesser_docs(&generate(true));
+    assert!(docs.contains("`item`: An item."), "{docs}");
+    assert!(
+        docs.contains(r#"Corresponds to expression: `\"x\"`."#),
+        "{docs}"
+    );
+
+    let docs = accesser_docs(&generate(false));
+    assert!(!docs.contains("An item."), "{docs}");
+    assert!(!docs.contains("Corresponds to expression"), "{docs}");
+}
+
+#[test]
+fn unicode_module() {
+    let generate = |grammar: &str| {
+        derive_typed_parser(
+            quote! {
+                #[grammar_inline = #grammar]
+                #[no_warnings]
+                struct Parser;
+            },
+            false,
+            false,
+        )
+        .to_string()
+    };
+
+    let plain = generate("item = { ASCII_ALPHA+ }");
+    assert!(!plain.contains("mod unicode"), "{plain}");
+    assert!(!plain.contains("unicode :: *"), "{plain}");
+
+    let one = generate("item = { XID_START ~ ASCII_ALPHA* }");
+    assert!(one.contains("mod unicode"), "{one}");
+    assert!(one.contains("use super :: super :: unicode :: *"), "{one}");
+    assert_eq!(
+        one.matches("pub struct ").count() - plain.matches("pub struct ").count(),
+        1
+    );
+    assert!(one.contains("pub struct XID_START"), "{one}");
+
+    // User-defined rules shadow unicode properties.
+    let shadowed = generate("item = { XID_START+ } XID_START = { \"x\" }");
+    assert!(!shadowed.contains("mod unicode"), "{shadowed}");
+}
+
+#[test]
+fn span_only() {
+    let generate = |span_only: bool| {
+        let attr = if span_only {
+            quote! {#[span_only(block)]}
+        } else {
+            quote! {}
+        };
+        derive_typed_parser(
+            quote! {
+                #[grammar_inline = "block = { \"{\" ~ stmt* ~ \"}\" } stmt = { \"x\" }"]
+                #[emit_rule_reference]
+                #attr
+                #[no_warnings]
+                struct Parser;
+            },
+            false,
+            false,
+        )
+        .to_string()
+    };
+    assert!(generate(false).contains("pub fn r#stmt"));
+    let generated = generate(true);
+    assert!(!generated.contains("pub fn r#stmt"), "{generated}");
+    assert!(generated.contains(", INHERITED , Span ,"), "{generated}");
+}
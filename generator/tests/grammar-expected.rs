@@ -1,5 +1,5 @@
 #![cfg(not(feature = "grammar-extras"))]
-#![allow(unused_parens)]
+#![allow(clippy::all)]
 #[doc = ""]
 #[allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -67,20 +67,564 @@ pub enum Rule {
     r#WHITESPACE,
     r#COMMENT,
 }
+#[allow(dead_code)]
+impl Rule {
+    #[doc = r" All rules, starting with `EOI` and then in the order of the grammar."]
+    pub const ALL: &'static [Rule] = &[
+        Rule::EOI,
+        Rule::r#string,
+        Rule::r#insensitive,
+        Rule::r#range,
+        Rule::r#ident,
+        Rule::r#pos_pred,
+        Rule::r#neg_pred,
+        Rule::r#double_neg_pred,
+        Rule::r#sequence,
+        Rule::r#sequence_compound,
+        Rule::r#sequence_atomic,
+        Rule::r#sequence_non_atomic,
+        Rule::r#sequence_atomic_compound,
+        Rule::r#sequence_nested,
+        Rule::r#sequence_compound_nested,
+        Rule::r#node_tag,
+        Rule::r#choice,
+        Rule::r#choice_prefix,
+        Rule::r#optional,
+        Rule::r#repeat,
+        Rule::r#repeat_atomic,
+        Rule::r#repeat_once,
+        Rule::r#repeat_once_atomic,
+        Rule::r#repeat_min_max,
+        Rule::r#repeat_min_max_atomic,
+        Rule::r#repeat_exact,
+        Rule::r#repeat_min,
+        Rule::r#repeat_min_atomic,
+        Rule::r#repeat_max,
+        Rule::r#repeat_max_atomic,
+        Rule::r#soi_at_start,
+        Rule::r#repeat_mutate_stack,
+        Rule::r#repeat_mutate_stack_pop_all,
+        Rule::r#will_fail,
+        Rule::r#stack_resume_after_fail,
+        Rule::r#peek_,
+        Rule::r#peek_all,
+        Rule::r#peek_slice_23,
+        Rule::r#pop_,
+        Rule::r#pop_all,
+        Rule::r#pop_fail,
+        Rule::r#checkpoint_restore,
+        Rule::r#ascii_digits,
+        Rule::r#ascii_nonzero_digits,
+        Rule::r#ascii_bin_digits,
+        Rule::r#ascii_oct_digits,
+        Rule::r#ascii_hex_digits,
+        Rule::r#ascii_alpha_lowers,
+        Rule::r#ascii_alpha_uppers,
+        Rule::r#ascii_alphas,
+        Rule::r#ascii_alphanumerics,
+        Rule::r#asciis,
+        Rule::r#newline,
+        Rule::r#unicode,
+        Rule::r#SYMBOL,
+        Rule::r#han,
+        Rule::r#hangul,
+        Rule::r#hiragana,
+        Rule::r#arabic,
+        Rule::r#emoji,
+        Rule::r#WHITESPACE,
+        Rule::r#COMMENT,
+    ];
+    #[doc = r" Names of all rules as in the grammar, in the same order as [`Rule::ALL`]."]
+    pub const NAMES: &'static [&'static ::core::primitive::str] = &[
+        "EOI",
+        "string",
+        "insensitive",
+        "range",
+        "ident",
+        "pos_pred",
+        "neg_pred",
+        "double_neg_pred",
+        "sequence",
+        "sequence_compound",
+        "sequence_atomic",
+        "sequence_non_atomic",
+        "sequence_atomic_compound",
+        "sequence_nested",
+        "sequence_compound_nested",
+        "node_tag",
+        "choice",
+        "choice_prefix",
+        "optional",
+        "repeat",
+        "repeat_atomic",
+        "repeat_once",
+        "repeat_once_atomic",
+        "repeat_min_max",
+        "repeat_min_max_atomic",
+        "repeat_exact",
+        "repeat_min",
+        "repeat_min_atomic",
+        "repeat_max",
+        "repeat_max_atomic",
+        "soi_at_start",
+        "repeat_mutate_stack",
+        "repeat_mutate_stack_pop_all",
+        "will_fail",
+        "stack_resume_after_fail",
+        "peek_",
+        "peek_all",
+        "peek_slice_23",
+        "pop_",
+        "pop_all",
+        "pop_fail",
+        "checkpoint_restore",
+        "ascii_digits",
+        "ascii_nonzero_digits",
+        "ascii_bin_digits",
+        "ascii_oct_digits",
+        "ascii_hex_digits",
+        "ascii_alpha_lowers",
+        "ascii_alpha_uppers",
+        "ascii_alphas",
+        "ascii_alphanumerics",
+        "asciis",
+        "newline",
+        "unicode",
+        "SYMBOL",
+        "han",
+        "hangul",
+        "hiragana",
+        "arabic",
+        "emoji",
+        "WHITESPACE",
+        "COMMENT",
+    ];
+    #[doc = r" Name of the rule as in the grammar."]
+    pub fn name(&self) -> &'static ::core::primitive::str {
+        match self {
+            Rule::EOI => "EOI",
+            Rule::r#string => "string",
+            Rule::r#insensitive => "insensitive",
+            Rule::r#range => "range",
+            Rule::r#ident => "ident",
+            Rule::r#pos_pred => "pos_pred",
+            Rule::r#neg_pred => "neg_pred",
+            Rule::r#double_neg_pred => "double_neg_pred",
+            Rule::r#sequence => "sequence",
+            Rule::r#sequence_compound => "sequence_compound",
+            Rule::r#sequence_atomic => "sequence_atomic",
+            Rule::r#sequence_non_atomic => "sequence_non_atomic",
+            Rule::r#sequence_atomic_compound => "sequence_atomic_compound",
+            Rule::r#sequence_nested => "sequence_nested",
+            Rule::r#sequence_compound_nested => "sequence_compound_nested",
+            Rule::r#node_tag => "node_tag",
+            Rule::r#choice => "choice",
+            Rule::r#choice_prefix => "choice_prefix",
+            Rule::r#optional => "optional",
+            Rule::r#repeat => "repeat",
+            Rule::r#repeat_atomic => "repeat_atomic",
+            Rule::r#repeat_once => "repeat_once",
+            Rule::r#repeat_once_atomic => "repeat_once_atomic",
+            Rule::r#repeat_min_max => "repeat_min_max",
+            Rule::r#repeat_min_max_atomic => "repeat_min_max_atomic",
+            Rule::r#repeat_exact => "repeat_exact",
+            Rule::r#repeat_min => "repeat_min",
+            Rule::r#repeat_min_atomic => "repeat_min_atomic",
+            Rule::r#repeat_max => "repeat_max",
+            Rule::r#repeat_max_atomic => "repeat_max_atomic",
+            Rule::r#soi_at_start => "soi_at_start",
+            Rule::r#repeat_mutate_stack => "repeat_mutate_stack",
+            Rule::r#repeat_mutate_stack_pop_all => "repeat_mutate_stack_pop_all",
+            Rule::r#will_fail => "will_fail",
+            Rule::r#stack_resume_after_fail => "stack_resume_after_fail",
+            Rule::r#peek_ => "peek_",
+            Rule::r#peek_all => "peek_all",
+            Rule::r#peek_slice_23 => "peek_slice_23",
+            Rule::r#pop_ => "pop_",
+            Rule::r#pop_all => "pop_all",
+            Rule::r#pop_fail => "pop_fail",
+            Rule::r#checkpoint_restore => "checkpoint_restore",
+            Rule::r#ascii_digits => "ascii_digits",
+            Rule::r#ascii_nonzero_digits => "ascii_nonzero_digits",
+            Rule::r#ascii_bin_digits => "ascii_bin_digits",
+            Rule::r#ascii_oct_digits => "ascii_oct_digits",
+            Rule::r#ascii_hex_digits => "ascii_hex_digits",
+            Rule::r#ascii_alpha_lowers => "ascii_alpha_lowers",
+            Rule::r#ascii_alpha_uppers => "ascii_alpha_uppers",
+            Rule::r#ascii_alphas => "ascii_alphas",
+            Rule::r#ascii_alphanumerics => "ascii_alphanumerics",
+            Rule::r#asciis => "asciis",
+            Rule::r#newline => "newline",
+            Rule::r#unicode => "unicode",
+            Rule::r#SYMBOL => "SYMBOL",
+            Rule::r#han => "han",
+            Rule::r#hangul => "hangul",
+            Rule::r#hiragana => "hiragana",
+            Rule::r#arabic => "arabic",
+            Rule::r#emoji => "emoji",
+            Rule::r#WHITESPACE => "WHITESPACE",
+            Rule::r#COMMENT => "COMMENT",
+        }
+    }
+    #[doc = r" The rule named `name` in the grammar."]
+    pub fn from_name(name: &::core::primitive::str) -> ::core::option::Option<Rule> {
+        match name {
+            "EOI" => ::core::option::Option::Some(Rule::EOI),
+            "string" => ::core::option::Option::Some(Rule::r#string),
+            "insensitive" => ::core::option::Option::Some(Rule::r#insensitive),
+            "range" => ::core::option::Option::Some(Rule::r#range),
+            "ident" => ::core::option::Option::Some(Rule::r#ident),
+            "pos_pred" => ::core::option::Option::Some(Rule::r#pos_pred),
+            "neg_pred" => ::core::option::Option::Some(Rule::r#neg_pred),
+            "double_neg_pred" => ::core::option::Option::Some(Rule::r#double_neg_pred),
+            "sequence" => ::core::option::Option::Some(Rule::r#sequence),
+            "sequence_compound" => ::core::option::Option::Some(Rule::r#sequence_compound),
+            "sequence_atomic" => ::core::option::Option::Some(Rule::r#sequence_atomic),
+            "sequence_non_atomic" => ::core::option::Option::Some(Rule::r#sequence_non_atomic),
+            "sequence_atomic_compound" => {
+                ::core::option::Option::Some(Rule::r#sequence_atomic_compound)
+            }
+            "sequence_nested" => ::core::option::Option::Some(Rule::r#sequence_nested),
+            "sequence_compound_nested" => {
+                ::core::option::Option::Some(Rule::r#sequence_compound_nested)
+            }
+            "node_tag" => ::core::option::Option::Some(Rule::r#node_tag),
+            "choice" => ::core::option::Option::Some(Rule::r#choice),
+            "choice_prefix" => ::core::option::Option::Some(Rule::r#choice_prefix),
+            "optional" => ::core::option::Option::Some(Rule::r#optional),
+            "repeat" => ::core::option::Option::Some(Rule::r#repeat),
+            "repeat_atomic" => ::core::option::Option::Some(Rule::r#repeat_atomic),
+            "repeat_once" => ::core::option::Option::Some(Rule::r#repeat_once),
+            "repeat_once_atomic" => ::core::option::Option::Some(Rule::r#repeat_once_atomic),
+            "repeat_min_max" => ::core::option::Option::Some(Rule::r#repeat_min_max),
+            "repeat_min_max_atomic" => ::core::option::Option::Some(Rule::r#repeat_min_max_atomic),
+            "repeat_exact" => ::core::option::Option::Some(Rule::r#repeat_exact),
+            "repeat_min" => ::core::option::Option::Some(Rule::r#repeat_min),
+            "repeat_min_atomic" => ::core::option::Option::Some(Rule::r#repeat_min_atomic),
+            "repeat_max" => ::core::option::Option::Some(Rule::r#repeat_max),
+            "repeat_max_atomic" => ::core::option::Option::Some(Rule::r#repeat_max_atomic),
+            "soi_at_start" => ::core::option::Option::Some(Rule::r#soi_at_start),
+            "repeat_mutate_stack" => ::core::option::Option::Some(Rule::r#repeat_mutate_stack),
+            "repeat_mutate_stack_pop_all" => {
+                ::core::option::Option::Some(Rule::r#repeat_mutate_stack_pop_all)
+            }
+            "will_fail" => ::core::option::Option::Some(Rule::r#will_fail),
+            "stack_resume_after_fail" => {
+                ::core::option::Option::Some(Rule::r#stack_resume_after_fail)
+            }
+            "peek_" => ::core::option::Option::Some(Rule::r#peek_),
+            "peek_all" => ::core::option::Option::Some(Rule::r#peek_all),
+            "peek_slice_23" => ::core::option::Option::Some(Rule::r#peek_slice_23),
+            "pop_" => ::core::option::Option::Some(Rule::r#pop_),
+            "pop_all" => ::core::option::Option::Some(Rule::r#pop_all),
+            "pop_fail" => ::core::option::Option::Some(Rule::r#pop_fail),
+            "checkpoint_restore" => ::core::option::Option::Some(Rule::r#checkpoint_restore),
+            "ascii_digits" => ::core::option::Option::Some(Rule::r#ascii_digits),
+            "ascii_nonzero_digits" => ::core::option::Option::Some(Rule::r#ascii_nonzero_digits),
+            "ascii_bin_digits" => ::core::option::Option::Some(Rule::r#ascii_bin_digits),
+            "ascii_oct_digits" => ::core::option::Option::Some(Rule::r#ascii_oct_digits),
+            "ascii_hex_digits" => ::core::option::Option::Some(Rule::r#ascii_hex_digits),
+            "ascii_alpha_lowers" => ::core::option::Option::Some(Rule::r#ascii_alpha_lowers),
+            "ascii_alpha_uppers" => ::core::option::Option::Some(Rule::r#ascii_alpha_uppers),
+            "ascii_alphas" => ::core::option::Option::Some(Rule::r#ascii_alphas),
+            "ascii_alphanumerics" => ::core::option::Option::Some(Rule::r#ascii_alphanumerics),
+            "asciis" => ::core::option::Option::Some(Rule::r#asciis),
+            "newline" => ::core::option::Option::Some(Rule::r#newline),
+            "unicode" => ::core::option::Option::Some(Rule::r#unicode),
+            "SYMBOL" => ::core::option::Option::Some(Rule::r#SYMBOL),
+            "han" => ::core::option::Option::Some(Rule::r#han),
+            "hangul" => ::core::option::Option::Some(Rule::r#hangul),
+            "hiragana" => ::core::option::Option::Some(Rule::r#hiragana),
+            "arabic" => ::core::option::Option::Some(Rule::r#arabic),
+            "emoji" => ::core::option::Option::Some(Rule::r#emoji),
+            "WHITESPACE" => ::core::option::Option::Some(Rule::r#WHITESPACE),
+            "COMMENT" => ::core::option::Option::Some(Rule::r#COMMENT),
+            _ => ::core::option::Option::None,
+        }
+    }
+    #[doc = r" Name of the rule in error messages, if it's given by `#[error_label]`."]
+    pub fn error_label(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+        match self {
+            Rule::EOI => ::core::option::Option::None,
+            Rule::r#string => ::core::option::Option::None,
+            Rule::r#insensitive => ::core::option::Option::None,
+            Rule::r#range => ::core::option::Option::None,
+            Rule::r#ident => ::core::option::Option::None,
+            Rule::r#pos_pred => ::core::option::Option::None,
+            Rule::r#neg_pred => ::core::option::Option::None,
+            Rule::r#double_neg_pred => ::core::option::Option::None,
+            Rule::r#sequence => ::core::option::Option::None,
+            Rule::r#sequence_compound => ::core::option::Option::None,
+            Rule::r#sequence_atomic => ::core::option::Option::None,
+            Rule::r#sequence_non_atomic => ::core::option::Option::None,
+            Rule::r#sequence_atomic_compound => ::core::option::Option::None,
+            Rule::r#sequence_nested => ::core::option::Option::None,
+            Rule::r#sequence_compound_nested => ::core::option::Option::None,
+            Rule::r#node_tag => ::core::option::Option::None,
+            Rule::r#choice => ::core::option::Option::None,
+            Rule::r#choice_prefix => ::core::option::Option::None,
+            Rule::r#optional => ::core::option::Option::None,
+            Rule::r#repeat => ::core::option::Option::None,
+            Rule::r#repeat_atomic => ::core::option::Option::None,
+            Rule::r#repeat_once => ::core::option::Option::None,
+            Rule::r#repeat_once_atomic => ::core::option::Option::None,
+            Rule::r#repeat_min_max => ::core::option::Option::None,
+            Rule::r#repeat_min_max_atomic => ::core::option::Option::None,
+            Rule::r#repeat_exact => ::core::option::Option::None,
+            Rule::r#repeat_min => ::core::option::Option::None,
+            Rule::r#repeat_min_atomic => ::core::option::Option::None,
+            Rule::r#repeat_max => ::core::option::Option::None,
+            Rule::r#repeat_max_atomic => ::core::option::Option::None,
+            Rule::r#soi_at_start => ::core::option::Option::None,
+            Rule::r#repeat_mutate_stack => ::core::option::Option::None,
+            Rule::r#repeat_mutate_stack_pop_all => ::core::option::Option::None,
+            Rule::r#will_fail => ::core::option::Option::None,
+            Rule::r#stack_resume_after_fail => ::core::option::Option::None,
+            Rule::r#peek_ => ::core::option::Option::None,
+            Rule::r#peek_all => ::core::option::Option::None,
+            Rule::r#peek_slice_23 => ::core::option::Option::None,
+            Rule::r#pop_ => ::core::option::Option::None,
+            Rule::r#pop_all => ::core::option::Option::None,
+            Rule::r#pop_fail => ::core::option::Option::None,
+            Rule::r#checkpoint_restore => ::core::option::Option::None,
+            Rule::r#ascii_digits => ::core::option::Option::None,
+            Rule::r#ascii_nonzero_digits => ::core::option::Option::None,
+            Rule::r#ascii_bin_digits => ::core::option::Option::None,
+            Rule::r#ascii_oct_digits => ::core::option::Option::None,
+            Rule::r#ascii_hex_digits => ::core::option::Option::None,
+            Rule::r#ascii_alpha_lowers => ::core::option::Option::None,
+            Rule::r#ascii_alpha_uppers => ::core::option::Option::None,
+            Rule::r#ascii_alphas => ::core::option::Option::None,
+            Rule::r#ascii_alphanumerics => ::core::option::Option::None,
+            Rule::r#asciis => ::core::option::Option::None,
+            Rule::r#newline => ::core::option::Option::None,
+            Rule::r#unicode => ::core::option::Option::None,
+            Rule::r#SYMBOL => ::core::option::Option::None,
+            Rule::r#han => ::core::option::Option::None,
+            Rule::r#hangul => ::core::option::Option::None,
+            Rule::r#hiragana => ::core::option::Option::None,
+            Rule::r#arabic => ::core::option::Option::None,
+            Rule::r#emoji => ::core::option::Option::None,
+            Rule::r#WHITESPACE => ::core::option::Option::None,
+            Rule::r#COMMENT => ::core::option::Option::None,
+        }
+    }
+    #[doc = r" Whether the rule is atomic (`@`) or compound-atomic (`$`), so nothing is skipped implicitly inside it."]
+    pub fn is_atomic(&self) -> ::core::primitive::bool {
+        match self {
+            Rule::EOI => false,
+            Rule::r#string => false,
+            Rule::r#insensitive => false,
+            Rule::r#range => false,
+            Rule::r#ident => false,
+            Rule::r#pos_pred => false,
+            Rule::r#neg_pred => false,
+            Rule::r#double_neg_pred => false,
+            Rule::r#sequence => false,
+            Rule::r#sequence_compound => true,
+            Rule::r#sequence_atomic => true,
+            Rule::r#sequence_non_atomic => true,
+            Rule::r#sequence_atomic_compound => true,
+            Rule::r#sequence_nested => false,
+            Rule::r#sequence_compound_nested => true,
+            Rule::r#node_tag => false,
+            Rule::r#choice => false,
+            Rule::r#choice_prefix => false,
+            Rule::r#optional => false,
+            Rule::r#repeat => false,
+            Rule::r#repeat_atomic => true,
+            Rule::r#repeat_once => false,
+            Rule::r#repeat_once_atomic => true,
+            Rule::r#repeat_min_max => false,
+            Rule::r#repeat_min_max_atomic => true,
+            Rule::r#repeat_exact => false,
+            Rule::r#repeat_min => false,
+            Rule::r#repeat_min_atomic => true,
+            Rule::r#repeat_max => false,
+            Rule::r#repeat_max_atomic => true,
+            Rule::r#soi_at_start => false,
+            Rule::r#repeat_mutate_stack => false,
+            Rule::r#repeat_mutate_stack_pop_all => false,
+            Rule::r#will_fail => false,
+            Rule::r#stack_resume_after_fail => false,
+            Rule::r#peek_ => false,
+            Rule::r#peek_all => false,
+            Rule::r#peek_slice_23 => false,
+            Rule::r#pop_ => false,
+            Rule::r#pop_all => false,
+            Rule::r#pop_fail => false,
+            Rule::r#checkpoint_restore => true,
+            Rule::r#ascii_digits => false,
+            Rule::r#ascii_nonzero_digits => false,
+            Rule::r#ascii_bin_digits => false,
+            Rule::r#ascii_oct_digits => false,
+            Rule::r#ascii_hex_digits => false,
+            Rule::r#ascii_alpha_lowers => false,
+            Rule::r#ascii_alpha_uppers => false,
+            Rule::r#ascii_alphas => false,
+            Rule::r#ascii_alphanumerics => false,
+            Rule::r#asciis => false,
+            Rule::r#newline => false,
+            Rule::r#unicode => false,
+            Rule::r#SYMBOL => false,
+            Rule::r#han => false,
+            Rule::r#hangul => false,
+            Rule::r#hiragana => false,
+            Rule::r#arabic => false,
+            Rule::r#emoji => false,
+            Rule::r#WHITESPACE => false,
+            Rule::r#COMMENT => false,
+        }
+    }
+    #[doc = r" Whether the rule is silent (`_`), so it produces no pairs."]
+    pub fn is_silent(&self) -> ::core::primitive::bool {
+        match self {
+            Rule::EOI => false,
+            Rule::r#string => false,
+            Rule::r#insensitive => false,
+            Rule::r#range => false,
+            Rule::r#ident => false,
+            Rule::r#pos_pred => false,
+            Rule::r#neg_pred => false,
+            Rule::r#double_neg_pred => false,
+            Rule::r#sequence => false,
+            Rule::r#sequence_compound => false,
+            Rule::r#sequence_atomic => false,
+            Rule::r#sequence_non_atomic => false,
+            Rule::r#sequence_atomic_compound => false,
+            Rule::r#sequence_nested => false,
+            Rule::r#sequence_compound_nested => false,
+            Rule::r#node_tag => false,
+            Rule::r#choice => false,
+            Rule::r#choice_prefix => false,
+            Rule::r#optional => false,
+            Rule::r#repeat => false,
+            Rule::r#repeat_atomic => false,
+            Rule::r#repeat_once => false,
+            Rule::r#repeat_once_atomic => false,
+            Rule::r#repeat_min_max => false,
+            Rule::r#repeat_min_max_atomic => false,
+            Rule::r#repeat_exact => false,
+            Rule::r#repeat_min => false,
+            Rule::r#repeat_min_atomic => false,
+            Rule::r#repeat_max => false,
+            Rule::r#repeat_max_atomic => false,
+            Rule::r#soi_at_start => false,
+            Rule::r#repeat_mutate_stack => false,
+            Rule::r#repeat_mutate_stack_pop_all => false,
+            Rule::r#will_fail => false,
+            Rule::r#stack_resume_after_fail => false,
+            Rule::r#peek_ => false,
+            Rule::r#peek_all => false,
+            Rule::r#peek_slice_23 => false,
+            Rule::r#pop_ => false,
+            Rule::r#pop_all => false,
+            Rule::r#pop_fail => false,
+            Rule::r#checkpoint_restore => false,
+            Rule::r#ascii_digits => false,
+            Rule::r#ascii_nonzero_digits => false,
+            Rule::r#ascii_bin_digits => false,
+            Rule::r#ascii_oct_digits => false,
+            Rule::r#ascii_hex_digits => false,
+            Rule::r#ascii_alpha_lowers => false,
+            Rule::r#ascii_alpha_uppers => false,
+            Rule::r#ascii_alphas => false,
+            Rule::r#ascii_alphanumerics => false,
+            Rule::r#asciis => false,
+            Rule::r#newline => false,
+            Rule::r#unicode => false,
+            Rule::r#SYMBOL => false,
+            Rule::r#han => false,
+            Rule::r#hangul => false,
+            Rule::r#hiragana => false,
+            Rule::r#arabic => false,
+            Rule::r#emoji => false,
+            Rule::r#WHITESPACE => true,
+            Rule::r#COMMENT => true,
+        }
+    }
+    #[doc = r" Doc comment of the rule in the grammar, with lines joined by `\n`."]
+    pub fn doc(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+        match self {
+            Rule::EOI => ::core::option::Option::None,
+            Rule::r#string => ::core::option::Option::None,
+            Rule::r#insensitive => ::core::option::Option::None,
+            Rule::r#range => ::core::option::Option::None,
+            Rule::r#ident => ::core::option::Option::None,
+            Rule::r#pos_pred => ::core::option::Option::None,
+            Rule::r#neg_pred => ::core::option::Option::None,
+            Rule::r#double_neg_pred => ::core::option::Option::None,
+            Rule::r#sequence => ::core::option::Option::None,
+            Rule::r#sequence_compound => ::core::option::Option::None,
+            Rule::r#sequence_atomic => ::core::option::Option::None,
+            Rule::r#sequence_non_atomic => ::core::option::Option::None,
+            Rule::r#sequence_atomic_compound => ::core::option::Option::None,
+            Rule::r#sequence_nested => ::core::option::Option::None,
+            Rule::r#sequence_compound_nested => ::core::option::Option::None,
+            Rule::r#node_tag => ::core::option::Option::None,
+            Rule::r#choice => ::core::option::Option::None,
+            Rule::r#choice_prefix => ::core::option::Option::None,
+            Rule::r#optional => ::core::option::Option::None,
+            Rule::r#repeat => ::core::option::Option::None,
+            Rule::r#repeat_atomic => ::core::option::Option::None,
+            Rule::r#repeat_once => ::core::option::Option::None,
+            Rule::r#repeat_once_atomic => ::core::option::Option::None,
+            Rule::r#repeat_min_max => ::core::option::Option::None,
+            Rule::r#repeat_min_max_atomic => ::core::option::Option::None,
+            Rule::r#repeat_exact => ::core::option::Option::None,
+            Rule::r#repeat_min => ::core::option::Option::None,
+            Rule::r#repeat_min_atomic => ::core::option::Option::None,
+            Rule::r#repeat_max => ::core::option::Option::None,
+            Rule::r#repeat_max_atomic => ::core::option::Option::None,
+            Rule::r#soi_at_start => ::core::option::Option::None,
+            Rule::r#repeat_mutate_stack => ::core::option::Option::None,
+            Rule::r#repeat_mutate_stack_pop_all => ::core::option::Option::None,
+            Rule::r#will_fail => ::core::option::Option::None,
+            Rule::r#stack_resume_after_fail => ::core::option::Option::None,
+            Rule::r#peek_ => ::core::option::Option::None,
+            Rule::r#peek_all => ::core::option::Option::None,
+            Rule::r#peek_slice_23 => ::core::option::Option::None,
+            Rule::r#pop_ => ::core::option::Option::None,
+            Rule::r#pop_all => ::core::option::Option::None,
+            Rule::r#pop_fail => ::core::option::Option::None,
+            Rule::r#checkpoint_restore => ::core::option::Option::None,
+            Rule::r#ascii_digits => ::core::option::Option::None,
+            Rule::r#ascii_nonzero_digits => ::core::option::Option::None,
+            Rule::r#ascii_bin_digits => ::core::option::Option::None,
+            Rule::r#ascii_oct_digits => ::core::option::Option::None,
+            Rule::r#ascii_hex_digits => ::core::option::Option::None,
+            Rule::r#ascii_alpha_lowers => ::core::option::Option::None,
+            Rule::r#ascii_alpha_uppers => ::core::option::Option::None,
+            Rule::r#ascii_alphas => ::core::option::Option::None,
+            Rule::r#ascii_alphanumerics => ::core::option::Option::None,
+            Rule::r#asciis => ::core::option::Option::None,
+            Rule::r#newline => ::core::option::Option::None,
+            Rule::r#unicode => ::core::option::Option::None,
+            Rule::r#SYMBOL => ::core::option::Option::None,
+            Rule::r#han => ::core::option::Option::None,
+            Rule::r#hangul => ::core::option::Option::None,
+            Rule::r#hiragana => ::core::option::Option::None,
+            Rule::r#arabic => ::core::option::Option::None,
+            Rule::r#emoji => ::core::option::Option::None,
+            Rule::r#WHITESPACE => ::core::option::Option::None,
+            Rule::r#COMMENT => ::core::option::Option::None,
+        }
+    }
+}
 #[doc = "Unicode rules."]
 pub mod unicode {
     #[allow(non_camel_case_types)]
-    #[doc = "Auto generated. Unicode property EMOJI."]
+    #[doc = "Auto generated. Unicode property ARABIC."]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct EMOJI {
+    pub struct ARABIC {
         pub content: ::core::primitive::char,
     }
-    impl ::core::convert::From<::core::primitive::char> for EMOJI {
+    impl ::core::convert::From<::core::primitive::char> for ARABIC {
         fn from(content: ::core::primitive::char) -> Self {
             Self { content }
         }
     }
-    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for EMOJI {
+    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for ARABIC {
         #[inline]
         fn try_parse_with(
             mut input: ::pest_typed::Position<'i>,
@@ -89,25 +633,43 @@ pub mod unicode {
         ) -> ::pest_typed::re_exported::Option<(::pest_typed::Position<'i>, Self)> {
             match ::pest_typed::predefined_node::match_char_by(
                 &mut input,
-                ::pest_typed::unicode::EMOJI,
+                ::pest_typed::unicode::ARABIC,
             ) {
                 Some(content) => Some((input, Self::from(content))),
                 None => None,
             }
         }
     }
-    impl ::core::fmt::Debug for EMOJI {
+    impl ::core::fmt::Debug for ARABIC {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("EMOJI")
+            f.debug_struct("ARABIC")
                 .field("content", &self.content)
                 .finish()
         }
     }
-    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for EMOJI {
-        type Iter = ::core::iter::Empty<&'n dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
+    impl ::pest_typed::MatchedText for ARABIC {
+        fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::core::fmt::Display for ARABIC {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::pest_typed::ContentEq for ARABIC {
+        fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+            self.content == other.content
+        }
+        fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            ::core::hash::Hash::hash(&self.content, state)
+        }
+    }
+    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for ARABIC {
+        type Iter = ::core::iter::Empty<&'n dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
         type IntoIter = ::core::iter::Empty<
             ::pest_typed::re_exported::Box<
-                dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
+                dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
             >,
         >;
         fn iter_pairs(&'n self) -> Self::Iter {
@@ -117,18 +679,33 @@ pub mod unicode {
             ::core::iter::empty()
         }
     }
+    impl<V: ?::core::marker::Sized> ::pest_typed::visitor::Walk<V> for ARABIC {
+        #[inline]
+        fn walk(&self, _visitor: &mut V) {}
+    }
+    impl<'i> ::pest_typed::incremental::Offset<'i> for ARABIC {
+        #[inline]
+        fn offset(
+            &mut self,
+            _input: &'i ::core::primitive::str,
+            _from: ::core::primitive::usize,
+            _delta: ::core::primitive::isize,
+        ) {
+        }
+    }
+    ::pest_typed::impl_serialize_content!(ARABIC);
     #[allow(non_camel_case_types)]
-    #[doc = "Auto generated. Unicode property XID_CONTINUE."]
+    #[doc = "Auto generated. Unicode property EMOJI."]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct XID_CONTINUE {
+    pub struct EMOJI {
         pub content: ::core::primitive::char,
     }
-    impl ::core::convert::From<::core::primitive::char> for XID_CONTINUE {
+    impl ::core::convert::From<::core::primitive::char> for EMOJI {
         fn from(content: ::core::primitive::char) -> Self {
             Self { content }
         }
     }
-    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for XID_CONTINUE {
+    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for EMOJI {
         #[inline]
         fn try_parse_with(
             mut input: ::pest_typed::Position<'i>,
@@ -137,25 +714,43 @@ pub mod unicode {
         ) -> ::pest_typed::re_exported::Option<(::pest_typed::Position<'i>, Self)> {
             match ::pest_typed::predefined_node::match_char_by(
                 &mut input,
-                ::pest_typed::unicode::XID_CONTINUE,
+                ::pest_typed::unicode::EMOJI,
             ) {
                 Some(content) => Some((input, Self::from(content))),
                 None => None,
             }
         }
     }
-    impl ::core::fmt::Debug for XID_CONTINUE {
+    impl ::core::fmt::Debug for EMOJI {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("XID_CONTINUE")
+            f.debug_struct("EMOJI")
                 .field("content", &self.content)
                 .finish()
         }
     }
-    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for XID_CONTINUE {
-        type Iter = ::core::iter::Empty<&'n dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
+    impl ::pest_typed::MatchedText for EMOJI {
+        fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::core::fmt::Display for EMOJI {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::pest_typed::ContentEq for EMOJI {
+        fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+            self.content == other.content
+        }
+        fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            ::core::hash::Hash::hash(&self.content, state)
+        }
+    }
+    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for EMOJI {
+        type Iter = ::core::iter::Empty<&'n dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
         type IntoIter = ::core::iter::Empty<
             ::pest_typed::re_exported::Box<
-                dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
+                dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
             >,
         >;
         fn iter_pairs(&'n self) -> Self::Iter {
@@ -165,18 +760,33 @@ pub mod unicode {
             ::core::iter::empty()
         }
     }
+    impl<V: ?::core::marker::Sized> ::pest_typed::visitor::Walk<V> for EMOJI {
+        #[inline]
+        fn walk(&self, _visitor: &mut V) {}
+    }
+    impl<'i> ::pest_typed::incremental::Offset<'i> for EMOJI {
+        #[inline]
+        fn offset(
+            &mut self,
+            _input: &'i ::core::primitive::str,
+            _from: ::core::primitive::usize,
+            _delta: ::core::primitive::isize,
+        ) {
+        }
+    }
+    ::pest_typed::impl_serialize_content!(EMOJI);
     #[allow(non_camel_case_types)]
-    #[doc = "Auto generated. Unicode property XID_START."]
+    #[doc = "Auto generated. Unicode property HAN."]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct XID_START {
+    pub struct HAN {
         pub content: ::core::primitive::char,
     }
-    impl ::core::convert::From<::core::primitive::char> for XID_START {
+    impl ::core::convert::From<::core::primitive::char> for HAN {
         fn from(content: ::core::primitive::char) -> Self {
             Self { content }
         }
     }
-    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for XID_START {
+    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for HAN {
         #[inline]
         fn try_parse_with(
             mut input: ::pest_typed::Position<'i>,
@@ -185,25 +795,43 @@ pub mod unicode {
         ) -> ::pest_typed::re_exported::Option<(::pest_typed::Position<'i>, Self)> {
             match ::pest_typed::predefined_node::match_char_by(
                 &mut input,
-                ::pest_typed::unicode::XID_START,
+                ::pest_typed::unicode::HAN,
             ) {
                 Some(content) => Some((input, Self::from(content))),
                 None => None,
             }
         }
     }
-    impl ::core::fmt::Debug for XID_START {
+    impl ::core::fmt::Debug for HAN {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("XID_START")
+            f.debug_struct("HAN")
                 .field("content", &self.content)
                 .finish()
         }
     }
-    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for XID_START {
-        type Iter = ::core::iter::Empty<&'n dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
+    impl ::pest_typed::MatchedText for HAN {
+        fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::core::fmt::Display for HAN {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::pest_typed::ContentEq for HAN {
+        fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+            self.content == other.content
+        }
+        fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            ::core::hash::Hash::hash(&self.content, state)
+        }
+    }
+    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for HAN {
+        type Iter = ::core::iter::Empty<&'n dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
         type IntoIter = ::core::iter::Empty<
             ::pest_typed::re_exported::Box<
-                dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
+                dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
             >,
         >;
         fn iter_pairs(&'n self) -> Self::Iter {
@@ -213,18 +841,33 @@ pub mod unicode {
             ::core::iter::empty()
         }
     }
+    impl<V: ?::core::marker::Sized> ::pest_typed::visitor::Walk<V> for HAN {
+        #[inline]
+        fn walk(&self, _visitor: &mut V) {}
+    }
+    impl<'i> ::pest_typed::incremental::Offset<'i> for HAN {
+        #[inline]
+        fn offset(
+            &mut self,
+            _input: &'i ::core::primitive::str,
+            _from: ::core::primitive::usize,
+            _delta: ::core::primitive::isize,
+        ) {
+        }
+    }
+    ::pest_typed::impl_serialize_content!(HAN);
     #[allow(non_camel_case_types)]
-    #[doc = "Auto generated. Unicode property ARABIC."]
+    #[doc = "Auto generated. Unicode property HANGUL."]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct ARABIC {
+    pub struct HANGUL {
         pub content: ::core::primitive::char,
     }
-    impl ::core::convert::From<::core::primitive::char> for ARABIC {
+    impl ::core::convert::From<::core::primitive::char> for HANGUL {
         fn from(content: ::core::primitive::char) -> Self {
             Self { content }
         }
     }
-    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for ARABIC {
+    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for HANGUL {
         #[inline]
         fn try_parse_with(
             mut input: ::pest_typed::Position<'i>,
@@ -233,25 +876,43 @@ pub mod unicode {
         ) -> ::pest_typed::re_exported::Option<(::pest_typed::Position<'i>, Self)> {
             match ::pest_typed::predefined_node::match_char_by(
                 &mut input,
-                ::pest_typed::unicode::ARABIC,
+                ::pest_typed::unicode::HANGUL,
             ) {
                 Some(content) => Some((input, Self::from(content))),
                 None => None,
             }
         }
     }
-    impl ::core::fmt::Debug for ARABIC {
+    impl ::core::fmt::Debug for HANGUL {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("ARABIC")
+            f.debug_struct("HANGUL")
                 .field("content", &self.content)
                 .finish()
         }
     }
-    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for ARABIC {
-        type Iter = ::core::iter::Empty<&'n dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
+    impl ::pest_typed::MatchedText for HANGUL {
+        fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::core::fmt::Display for HANGUL {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::pest_typed::ContentEq for HANGUL {
+        fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+            self.content == other.content
+        }
+        fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            ::core::hash::Hash::hash(&self.content, state)
+        }
+    }
+    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for HANGUL {
+        type Iter = ::core::iter::Empty<&'n dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
         type IntoIter = ::core::iter::Empty<
             ::pest_typed::re_exported::Box<
-                dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
+                dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
             >,
         >;
         fn iter_pairs(&'n self) -> Self::Iter {
@@ -261,18 +922,33 @@ pub mod unicode {
             ::core::iter::empty()
         }
     }
+    impl<V: ?::core::marker::Sized> ::pest_typed::visitor::Walk<V> for HANGUL {
+        #[inline]
+        fn walk(&self, _visitor: &mut V) {}
+    }
+    impl<'i> ::pest_typed::incremental::Offset<'i> for HANGUL {
+        #[inline]
+        fn offset(
+            &mut self,
+            _input: &'i ::core::primitive::str,
+            _from: ::core::primitive::usize,
+            _delta: ::core::primitive::isize,
+        ) {
+        }
+    }
+    ::pest_typed::impl_serialize_content!(HANGUL);
     #[allow(non_camel_case_types)]
-    #[doc = "Auto generated. Unicode property HAN."]
+    #[doc = "Auto generated. Unicode property HIRAGANA."]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct HAN {
+    pub struct HIRAGANA {
         pub content: ::core::primitive::char,
     }
-    impl ::core::convert::From<::core::primitive::char> for HAN {
+    impl ::core::convert::From<::core::primitive::char> for HIRAGANA {
         fn from(content: ::core::primitive::char) -> Self {
             Self { content }
         }
     }
-    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for HAN {
+    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for HIRAGANA {
         #[inline]
         fn try_parse_with(
             mut input: ::pest_typed::Position<'i>,
@@ -281,25 +957,43 @@ pub mod unicode {
         ) -> ::pest_typed::re_exported::Option<(::pest_typed::Position<'i>, Self)> {
             match ::pest_typed::predefined_node::match_char_by(
                 &mut input,
-                ::pest_typed::unicode::HAN,
+                ::pest_typed::unicode::HIRAGANA,
             ) {
                 Some(content) => Some((input, Self::from(content))),
                 None => None,
             }
         }
     }
-    impl ::core::fmt::Debug for HAN {
+    impl ::core::fmt::Debug for HIRAGANA {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("HAN")
+            f.debug_struct("HIRAGANA")
                 .field("content", &self.content)
                 .finish()
         }
     }
-    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for HAN {
-        type Iter = ::core::iter::Empty<&'n dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
+    impl ::pest_typed::MatchedText for HIRAGANA {
+        fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::core::fmt::Display for HIRAGANA {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::pest_typed::ContentEq for HIRAGANA {
+        fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+            self.content == other.content
+        }
+        fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            ::core::hash::Hash::hash(&self.content, state)
+        }
+    }
+    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for HIRAGANA {
+        type Iter = ::core::iter::Empty<&'n dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
         type IntoIter = ::core::iter::Empty<
             ::pest_typed::re_exported::Box<
-                dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
+                dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
             >,
         >;
         fn iter_pairs(&'n self) -> Self::Iter {
@@ -309,18 +1003,33 @@ pub mod unicode {
             ::core::iter::empty()
         }
     }
+    impl<V: ?::core::marker::Sized> ::pest_typed::visitor::Walk<V> for HIRAGANA {
+        #[inline]
+        fn walk(&self, _visitor: &mut V) {}
+    }
+    impl<'i> ::pest_typed::incremental::Offset<'i> for HIRAGANA {
+        #[inline]
+        fn offset(
+            &mut self,
+            _input: &'i ::core::primitive::str,
+            _from: ::core::primitive::usize,
+            _delta: ::core::primitive::isize,
+        ) {
+        }
+    }
+    ::pest_typed::impl_serialize_content!(HIRAGANA);
     #[allow(non_camel_case_types)]
-    #[doc = "Auto generated. Unicode property HANGUL."]
+    #[doc = "Auto generated. Unicode property XID_CONTINUE."]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct HANGUL {
+    pub struct XID_CONTINUE {
         pub content: ::core::primitive::char,
     }
-    impl ::core::convert::From<::core::primitive::char> for HANGUL {
+    impl ::core::convert::From<::core::primitive::char> for XID_CONTINUE {
         fn from(content: ::core::primitive::char) -> Self {
             Self { content }
         }
     }
-    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for HANGUL {
+    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for XID_CONTINUE {
         #[inline]
         fn try_parse_with(
             mut input: ::pest_typed::Position<'i>,
@@ -329,25 +1038,43 @@ pub mod unicode {
         ) -> ::pest_typed::re_exported::Option<(::pest_typed::Position<'i>, Self)> {
             match ::pest_typed::predefined_node::match_char_by(
                 &mut input,
-                ::pest_typed::unicode::HANGUL,
+                ::pest_typed::unicode::XID_CONTINUE,
             ) {
                 Some(content) => Some((input, Self::from(content))),
                 None => None,
             }
         }
     }
-    impl ::core::fmt::Debug for HANGUL {
+    impl ::core::fmt::Debug for XID_CONTINUE {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("HANGUL")
+            f.debug_struct("XID_CONTINUE")
                 .field("content", &self.content)
                 .finish()
         }
     }
-    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for HANGUL {
-        type Iter = ::core::iter::Empty<&'n dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
+    impl ::pest_typed::MatchedText for XID_CONTINUE {
+        fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::core::fmt::Display for XID_CONTINUE {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::pest_typed::ContentEq for XID_CONTINUE {
+        fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+            self.content == other.content
+        }
+        fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            ::core::hash::Hash::hash(&self.content, state)
+        }
+    }
+    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for XID_CONTINUE {
+        type Iter = ::core::iter::Empty<&'n dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
         type IntoIter = ::core::iter::Empty<
             ::pest_typed::re_exported::Box<
-                dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
+                dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
             >,
         >;
         fn iter_pairs(&'n self) -> Self::Iter {
@@ -357,18 +1084,33 @@ pub mod unicode {
             ::core::iter::empty()
         }
     }
+    impl<V: ?::core::marker::Sized> ::pest_typed::visitor::Walk<V> for XID_CONTINUE {
+        #[inline]
+        fn walk(&self, _visitor: &mut V) {}
+    }
+    impl<'i> ::pest_typed::incremental::Offset<'i> for XID_CONTINUE {
+        #[inline]
+        fn offset(
+            &mut self,
+            _input: &'i ::core::primitive::str,
+            _from: ::core::primitive::usize,
+            _delta: ::core::primitive::isize,
+        ) {
+        }
+    }
+    ::pest_typed::impl_serialize_content!(XID_CONTINUE);
     #[allow(non_camel_case_types)]
-    #[doc = "Auto generated. Unicode property HIRAGANA."]
+    #[doc = "Auto generated. Unicode property XID_START."]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct HIRAGANA {
+    pub struct XID_START {
         pub content: ::core::primitive::char,
     }
-    impl ::core::convert::From<::core::primitive::char> for HIRAGANA {
+    impl ::core::convert::From<::core::primitive::char> for XID_START {
         fn from(content: ::core::primitive::char) -> Self {
             Self { content }
         }
     }
-    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for HIRAGANA {
+    impl<'i> ::pest_typed::TypedNode<'i, super::Rule> for XID_START {
         #[inline]
         fn try_parse_with(
             mut input: ::pest_typed::Position<'i>,
@@ -377,25 +1119,43 @@ pub mod unicode {
         ) -> ::pest_typed::re_exported::Option<(::pest_typed::Position<'i>, Self)> {
             match ::pest_typed::predefined_node::match_char_by(
                 &mut input,
-                ::pest_typed::unicode::HIRAGANA,
+                ::pest_typed::unicode::XID_START,
             ) {
                 Some(content) => Some((input, Self::from(content))),
                 None => None,
             }
         }
     }
-    impl ::core::fmt::Debug for HIRAGANA {
+    impl ::core::fmt::Debug for XID_START {
         fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-            f.debug_struct("HIRAGANA")
+            f.debug_struct("XID_START")
                 .field("content", &self.content)
                 .finish()
         }
     }
-    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for HIRAGANA {
-        type Iter = ::core::iter::Empty<&'n dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
+    impl ::pest_typed::MatchedText for XID_START {
+        fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::core::fmt::Display for XID_START {
+        fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+            ::core::fmt::Write::write_char(f, self.content)
+        }
+    }
+    impl ::pest_typed::ContentEq for XID_START {
+        fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+            self.content == other.content
+        }
+        fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+            ::core::hash::Hash::hash(&self.content, state)
+        }
+    }
+    impl<'i: 'n, 'n> ::pest_typed::iterators::Pairs<'i, 'n, super::Rule> for XID_START {
+        type Iter = ::core::iter::Empty<&'n dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule>>;
         type IntoIter = ::core::iter::Empty<
             ::pest_typed::re_exported::Box<
-                dyn::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
+                dyn ::pest_typed::iterators::Pair<'i, 'n, super::Rule> + 'n,
             >,
         >;
         fn iter_pairs(&'n self) -> Self::Iter {
@@ -405,6 +1165,21 @@ pub mod unicode {
             ::core::iter::empty()
         }
     }
+    impl<V: ?::core::marker::Sized> ::pest_typed::visitor::Walk<V> for XID_START {
+        #[inline]
+        fn walk(&self, _visitor: &mut V) {}
+    }
+    impl<'i> ::pest_typed::incremental::Offset<'i> for XID_START {
+        #[inline]
+        fn offset(
+            &mut self,
+            _input: &'i ::core::primitive::str,
+            _from: ::core::primitive::usize,
+            _delta: ::core::primitive::isize,
+        ) {
+        }
+    }
+    ::pest_typed::impl_serialize_content!(XID_START);
 }
 mod constant_wrappers {
     #[doc = "A wrapper for `\"abc\"`."]
@@ -414,144 +1189,241 @@ mod constant_wrappers {
     impl ::pest_typed::StringWrapper for r#w_0 {
         const CONTENT: &'static ::core::primitive::str = "abc";
     }
-    #[doc = "A wrapper for `\"abc\"`."]
+    #[doc = "A wrapper for `\",\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_1;
     impl ::pest_typed::StringWrapper for r#w_1 {
-        const CONTENT: &'static ::core::primitive::str = "abc";
+        const CONTENT: &'static ::core::primitive::str = ",";
     }
-    #[doc = "A wrapper for `\",\"`."]
+    #[doc = "A wrapper for `\"FAIL\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_2;
     impl ::pest_typed::StringWrapper for r#w_2 {
-        const CONTENT: &'static ::core::primitive::str = ",";
+        const CONTENT: &'static ::core::primitive::str = "FAIL";
     }
-    #[doc = "A wrapper for `\",\"`."]
+    #[doc = "A wrapper for `\"\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_3;
     impl ::pest_typed::StringWrapper for r#w_3 {
-        const CONTENT: &'static ::core::primitive::str = ",";
+        const CONTENT: &'static ::core::primitive::str = "";
     }
-    #[doc = "A wrapper for `\"FAIL\"`."]
+    #[doc = "A wrapper for `\"a\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_4;
     impl ::pest_typed::StringWrapper for r#w_4 {
-        const CONTENT: &'static ::core::primitive::str = "FAIL";
+        const CONTENT: &'static ::core::primitive::str = "a";
     }
-    #[doc = "A wrapper for `\"\"`."]
+    #[doc = "A wrapper for `\"b\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_5;
     impl ::pest_typed::StringWrapper for r#w_5 {
-        const CONTENT: &'static ::core::primitive::str = "";
+        const CONTENT: &'static ::core::primitive::str = "b";
     }
-    #[doc = "A wrapper for `\"a\"`."]
+    #[doc = "A wrapper for `\"shadows builtin\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_6;
     impl ::pest_typed::StringWrapper for r#w_6 {
-        const CONTENT: &'static ::core::primitive::str = "a";
+        const CONTENT: &'static ::core::primitive::str = "shadows builtin";
     }
-    #[doc = "A wrapper for `\"b\"`."]
+    #[doc = "A wrapper for `\" \"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_7;
     impl ::pest_typed::StringWrapper for r#w_7 {
-        const CONTENT: &'static ::core::primitive::str = "b";
+        const CONTENT: &'static ::core::primitive::str = " ";
     }
-    #[doc = "A wrapper for `\"b\"`."]
+    #[doc = "A wrapper for `\"$\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_8;
     impl ::pest_typed::StringWrapper for r#w_8 {
-        const CONTENT: &'static ::core::primitive::str = "b";
-    }
-    #[doc = "A wrapper for `\"a\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_9;
-    impl ::pest_typed::StringWrapper for r#w_9 {
-        const CONTENT: &'static ::core::primitive::str = "a";
-    }
-    #[doc = "A wrapper for `\"shadows builtin\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_10;
-    impl ::pest_typed::StringWrapper for r#w_10 {
-        const CONTENT: &'static ::core::primitive::str = "shadows builtin";
-    }
-    #[doc = "A wrapper for `\" \"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_11;
-    impl ::pest_typed::StringWrapper for r#w_11 {
-        const CONTENT: &'static ::core::primitive::str = " ";
-    }
-    #[doc = "A wrapper for `\"$\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_12;
-    impl ::pest_typed::StringWrapper for r#w_12 {
-        const CONTENT: &'static ::core::primitive::str = "$";
-    }
-    #[doc = "A wrapper for `\"$\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_13;
-    impl ::pest_typed::StringWrapper for r#w_13 {
-        const CONTENT: &'static ::core::primitive::str = "$";
+        const CONTENT: &'static ::core::primitive::str = "$";
     }
 }
 #[doc = "Definitions of statically typed nodes generated by pest-generator."]
 pub mod rules_impl {
     #[doc = "Definitions of statically typed nodes generated by pest-generator."]
     pub mod rules {
-        :: pest_typed :: rule ! (r#string , "Corresponds to expression: `\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#string , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#string<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#insensitive , "Corresponds to expression: `^\"abc\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#insensitive , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#insensitive<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#range , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#range , super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#range<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#ident , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ident , super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ident<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule ! (r#COMMENT , "Corresponds to expression: `(\"$\" ~ \"$\"*)`. Normal rule." "" "```pest" "COMMENT = _{ \"$\"+ }" "```" , super :: super :: Rule , super :: super :: Rule :: r#COMMENT , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Expression , false , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#COMMENT<'i, INHERITED> {}
+        impl<'i, const INHERITED: ::core::primitive::usize> r#COMMENT<'i, INHERITED> {
+            #[doc = r" Create the node from its content, without parsing."]
+            #[allow(clippy::self_named_constructors)]
+            pub fn r#new(
+                content: super::super::generics::Seq2<
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Str<super::super::constant_wrappers::r#w_8>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Rep<
+                            'i,
+                            INHERITED,
+                            super::super::generics::Str<super::super::constant_wrappers::r#w_8>,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                >,
+            ) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    _phantom: ::core::marker::PhantomData,
+                }
+            }
+        }
+        impl<'i, const INHERITED: ::core::primitive::usize>
+            ::core::convert::From<
+                super::super::generics::Seq2<
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Str<super::super::constant_wrappers::r#w_8>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Rep<
+                            'i,
+                            INHERITED,
+                            super::super::generics::Str<super::super::constant_wrappers::r#w_8>,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                >,
+            > for r#COMMENT<'i, INHERITED>
+        {
+            fn from(
+                content: super::super::generics::Seq2<
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Str<super::super::constant_wrappers::r#w_8>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::generics::Rep<
+                            'i,
+                            INHERITED,
+                            super::super::generics::Str<super::super::constant_wrappers::r#w_8>,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                >,
+            ) -> Self {
+                Self::r#new(content)
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#SYMBOL , "Corresponds to expression: `\"shadows builtin\"`. Normal rule." "" "```pest" "SYMBOL = { \"shadows builtin\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#SYMBOL , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_6 > , { super :: super :: Rule :: r#SYMBOL as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 15 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#SYMBOL<'i, INHERITED> {}
+        :: pest_typed :: rule ! (r#WHITESPACE , "Corresponds to expression: `\" \"`. Normal rule." "" "```pest" "WHITESPACE = _{ \" \" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#WHITESPACE , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Expression , false , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#WHITESPACE<'i, INHERITED> {}
+        impl<'i, const INHERITED: ::core::primitive::usize> r#WHITESPACE<'i, INHERITED> {
+            #[doc = r" Create the node from its content, without parsing."]
+            #[allow(clippy::self_named_constructors)]
+            pub fn r#new(
+                content: super::super::generics::Str<super::super::constant_wrappers::r#w_7>,
+            ) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    _phantom: ::core::marker::PhantomData,
+                }
+            }
+        }
+        impl<'i, const INHERITED: ::core::primitive::usize>
+            ::core::convert::From<
+                super::super::generics::Str<super::super::constant_wrappers::r#w_7>,
+            > for r#WHITESPACE<'i, INHERITED>
+        {
+            fn from(
+                content: super::super::generics::Str<super::super::constant_wrappers::r#w_7>,
+            ) -> Self {
+                Self::r#new(content)
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#arabic , "Corresponds to expression: `(ARABIC ~ ARABIC*)`. Normal rule." "" "```pest" "arabic = { ARABIC+ }" "```" "" "Accessers:" "" "- `ARABIC()`: `(&ARABIC, Vec<&ARABIC>)`" , super :: super :: Rule , super :: super :: Rule :: r#arabic , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ARABIC , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ARABIC > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#arabic as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#arabic<'i, INHERITED> {
+            #[doc = "A helper function to access [`ARABIC`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(&'s self) -> &'s super::super::rules::r#string<'i, INHERITED> {
-                let res = &*self.content;
-                res
+            pub fn r#ARABIC<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ARABIC,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ARABIC>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
             }
         }
-        :: pest_typed :: rule ! (r#pos_pred , "Corresponds to expression: `&string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pos_pred , super :: super :: generics :: Positive :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#pos_pred<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule_instance ! (r#ascii_alpha_lowers , "Corresponds to expression: `(ASCII_ALPHA_LOWER ~ ASCII_ALPHA_LOWER*)`. Normal rule." "" "```pest" "ascii_alpha_lowers = { ASCII_ALPHA_LOWER+ }" "```" "" "Accessers:" "" "- `ASCII_ALPHA_LOWER()`: `(&ASCII_ALPHA_LOWER, Vec<&ASCII_ALPHA_LOWER>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_lowers , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_LOWER , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHA_LOWER > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_alpha_lowers as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alpha_lowers<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_ALPHA_LOWER`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(&'s self) -> &'s super::super::rules::r#string<'i, INHERITED> {
-                let res = &*self.content;
+            pub fn r#ASCII_ALPHA_LOWER<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII_ALPHA_LOWER,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_LOWER>,
+            ) {
+                let res = &self.content;
                 {
-                    let res = &res.content;
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
                     res
                 }
             }
         }
-        :: pest_typed :: rule ! (r#neg_pred , "Corresponds to expression: `!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#neg_pred , super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#neg_pred<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#double_neg_pred , "Corresponds to expression: `!!string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#double_neg_pred , super :: super :: generics :: Negative :: < super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#double_neg_pred<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#sequence , "Corresponds to expression: `(string ~ string)`. Non-atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 >) , > , super :: super :: generics :: Skipped :: < 'i > , false , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule_instance ! (r#ascii_alpha_uppers , "Corresponds to expression: `(ASCII_ALPHA_UPPER ~ ASCII_ALPHA_UPPER*)`. Normal rule." "" "```pest" "ascii_alpha_uppers = { ASCII_ALPHA_UPPER+ }" "```" "" "Accessers:" "" "- `ASCII_ALPHA_UPPER()`: `(&ASCII_ALPHA_UPPER, Vec<&ASCII_ALPHA_UPPER>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_uppers , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_UPPER , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHA_UPPER > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_alpha_uppers as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alpha_uppers<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_ALPHA_UPPER`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
+            pub fn r#ASCII_ALPHA_UPPER<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#string<'i, 1>,
-                &'s super::super::rules::r#string<'i, 1>,
+                &'s super::super::rules::r#ASCII_ALPHA_UPPER,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_UPPER>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -560,24 +1432,196 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#ascii_alphanumerics , "Corresponds to expression: `(ASCII_ALPHANUMERIC ~ ASCII_ALPHANUMERIC*)`. Normal rule." "" "```pest" "ascii_alphanumerics = { ASCII_ALPHANUMERIC+ }" "```" "" "Accessers:" "" "- `ASCII_ALPHANUMERIC()`: `(&ASCII_ALPHANUMERIC, Vec<&ASCII_ALPHANUMERIC>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphanumerics , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHANUMERIC , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHANUMERIC > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_alphanumerics as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alphanumerics<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_ALPHANUMERIC`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ASCII_ALPHANUMERIC<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII_ALPHANUMERIC,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHANUMERIC>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
                             res
                         },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
                     );
                     res
                 }
             }
         }
-        :: pest_typed :: rule ! (r#sequence_compound , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_compound<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule_instance ! (r#ascii_alphas , "Corresponds to expression: `(ASCII_ALPHA ~ ASCII_ALPHA*)`. Normal rule." "" "```pest" "ascii_alphas = { ASCII_ALPHA+ }" "```" "" "Accessers:" "" "- `ASCII_ALPHA()`: `(&ASCII_ALPHA, Vec<&ASCII_ALPHA>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphas , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHA > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_alphas as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alphas<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_ALPHA`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
+            pub fn r#ASCII_ALPHA<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#string<'i, 0>,
-                &'s super::super::rules::r#string<'i, 0>,
+                &'s super::super::rules::r#ASCII_ALPHA,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#ascii_bin_digits , "Corresponds to expression: `(ASCII_BIN_DIGIT ~ ASCII_BIN_DIGIT*)`. Normal rule." "" "```pest" "ascii_bin_digits = { ASCII_BIN_DIGIT+ }" "```" "" "Accessers:" "" "- `ASCII_BIN_DIGIT()`: `(&ASCII_BIN_DIGIT, Vec<&ASCII_BIN_DIGIT>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_bin_digits , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_BIN_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_BIN_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_bin_digits as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_bin_digits<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_BIN_DIGIT`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ASCII_BIN_DIGIT<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII_BIN_DIGIT,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_BIN_DIGIT>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#ascii_digits , "Corresponds to expression: `(ASCII_DIGIT ~ ASCII_DIGIT*)`. Normal rule." "" "```pest" "ascii_digits = { ASCII_DIGIT+ }" "```" "" "Accessers:" "" "- `ASCII_DIGIT()`: `(&ASCII_DIGIT, Vec<&ASCII_DIGIT>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_digits , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_digits as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_digits<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_DIGIT`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ASCII_DIGIT<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII_DIGIT,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_DIGIT>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#ascii_hex_digits , "Corresponds to expression: `(ASCII_HEX_DIGIT ~ ASCII_HEX_DIGIT*)`. Normal rule." "" "```pest" "ascii_hex_digits = { ASCII_HEX_DIGIT+ }" "```" "" "Accessers:" "" "- `ASCII_HEX_DIGIT()`: `(&ASCII_HEX_DIGIT, Vec<&ASCII_HEX_DIGIT>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_hex_digits , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_HEX_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_HEX_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_hex_digits as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_hex_digits<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_HEX_DIGIT`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ASCII_HEX_DIGIT<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII_HEX_DIGIT,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_HEX_DIGIT>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#ascii_nonzero_digits , "Corresponds to expression: `(ASCII_NONZERO_DIGIT ~ ASCII_NONZERO_DIGIT*)`. Normal rule." "" "```pest" "ascii_nonzero_digits = { ASCII_NONZERO_DIGIT+ }" "```" "" "Accessers:" "" "- `ASCII_NONZERO_DIGIT()`: `(&ASCII_NONZERO_DIGIT, Vec<&ASCII_NONZERO_DIGIT>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_nonzero_digits , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_NONZERO_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_NONZERO_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_nonzero_digits as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_nonzero_digits<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_NONZERO_DIGIT`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ASCII_NONZERO_DIGIT<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII_NONZERO_DIGIT,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_NONZERO_DIGIT>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -586,66 +1630,209 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#ascii_oct_digits , "Corresponds to expression: `(ASCII_OCT_DIGIT ~ ASCII_OCT_DIGIT*)`. Normal rule." "" "```pest" "ascii_oct_digits = { ASCII_OCT_DIGIT+ }" "```" "" "Accessers:" "" "- `ASCII_OCT_DIGIT()`: `(&ASCII_OCT_DIGIT, Vec<&ASCII_OCT_DIGIT>)`" , super :: super :: Rule , super :: super :: Rule :: r#ascii_oct_digits , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_OCT_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_OCT_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#ascii_oct_digits as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_oct_digits<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII_OCT_DIGIT`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ASCII_OCT_DIGIT<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII_OCT_DIGIT,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_OCT_DIGIT>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#asciis , "Corresponds to expression: `(ASCII ~ ASCII*)`. Normal rule." "" "```pest" "asciis = { ASCII+ }" "```" "" "Accessers:" "" "- `ASCII()`: `(&ASCII, Vec<&ASCII>)`" , super :: super :: Rule , super :: super :: Rule :: r#asciis , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#asciis as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#asciis<'i, INHERITED> {
+            #[doc = "A helper function to access [`ASCII`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ASCII<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#ASCII,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
                             res
                         },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
                     );
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#sequence_atomic , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#sequence_non_atomic , "Corresponds to expression: `sequence`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_non_atomic , super :: super :: rules :: r#sequence :: < 'i , 0 > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_non_atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#sequence_atomic_compound , "Corresponds to expression: `sequence_compound`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic_compound , super :: super :: rules :: r#sequence_compound :: < 'i , 0 > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_atomic_compound<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#sequence_nested , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_nested , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_nested<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        }
+        :: pest_typed :: rule_instance ! (r#checkpoint_restore , "Corresponds to expression: `(PUSH(\"\") ~ ((PUSH(\"a\") ~ \"b\" ~ POP) | (DROP ~ \"b\") | (POP ~ \"a\")) ~ EOI)`. Atomic rule." "" "```pest" "checkpoint_restore = ${" "\t\tPUSH(\"\") ~ (PUSH(\"a\") ~ \"b\" ~ POP | DROP ~ \"b\" | POP ~ \"a\") ~ EOI" "}" "```" "" "Accessers:" "" "- `DROP()`: `Option<&DROP>`" "- `EOI()`: `&EOI`" "- `POP()`: `(Option<&POP>, Option<&POP>)`" "- `pop()`: `(Option<Span>, Option<Span>)`" "- `pushed()`: `(Span, Option<Span>)`" , super :: super :: Rule , super :: super :: Rule :: r#checkpoint_restore , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Choice3 :: < super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , 0 > , > , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#DROP , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Skipped < 'i > , 0 > , > , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > , super :: super :: generics :: Skipped < 'i > , 0 > , > , > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < 'i > , super :: super :: generics :: Skipped < 'i > , 0 > , > , { super :: super :: Rule :: r#checkpoint_restore as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#checkpoint_restore<'i, INHERITED> {
+            #[doc = "A helper function to access [`DROP`]."]
+            #[allow(non_snake_case)]
+            pub fn r#DROP<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#DROP> {
+                let res = &self.content;
+                {
+                    let res = &res.content.1.matched;
+                    {
+                        let res = res._1().map(|res| {
+                            let res = &res.content.0.matched;
+                            res
+                        });
+                        res
+                    }
+                }
+            }
+            #[doc = "A helper function to access [`EOI`]."]
+            #[allow(non_snake_case)]
+            pub fn r#EOI<'s>(&'s self) -> &'s super::super::rules::r#EOI<'i> {
+                let res = &self.content;
+                {
+                    let res = &res.content.2.matched;
+                    res
+                }
+            }
+            #[doc = "A helper function to access [`POP`]."]
+            #[allow(non_snake_case)]
+            pub fn r#POP<'s>(
+                &'s self,
+            ) -> (
+                ::pest_typed::re_exported::Option<&'s super::super::rules::r#POP<'i>>,
+                ::pest_typed::re_exported::Option<&'s super::super::rules::r#POP<'i>>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = &res.content.1.matched;
+                    {
+                        let res = (
+                            {
+                                let res = res._0().map(|res| {
+                                    let res = &res.content.2.matched;
+                                    res
+                                });
+                                res
+                            },
+                            {
+                                let res = res._2().map(|res| {
+                                    let res = &res.content.0.matched;
+                                    res
+                                });
+                                res
+                            },
+                        );
+                        res
+                    }
+                }
+            }
+            #[doc = "A helper function to access spans matched by `POP`."]
+            #[allow(non_snake_case)]
+            pub fn r#pop<'s>(
+                &'s self,
+            ) -> (
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = &res.content.1.matched;
+                    {
+                        let res = (
+                            {
+                                let res = res._0().map(|res| {
+                                    let res = &res.content.2.matched;
+                                    res.span
+                                });
+                                res
+                            },
+                            {
+                                let res = res._2().map(|res| {
+                                    let res = &res.content.0.matched;
+                                    res.span
+                                });
+                                res
+                            },
+                        );
+                        res
+                    }
+                }
+            }
+            #[doc = "A helper function to access spans matched by `PUSH`."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
+            pub fn r#pushed<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#string<'i, INHERITED>,
-                &'s super::super::rules::r#string<'i, INHERITED>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
                             let res = &res.content.0.matched;
-                            res
+                            res.span
                         },
                         {
                             let res = &res.content.1.matched;
-                            res
+                            {
+                                let res = res._0().map(|res| {
+                                    let res = &res.content.0.matched;
+                                    res.span
+                                });
+                                res
+                            }
                         },
                     );
                     res
                 }
             }
         }
-        :: pest_typed :: rule ! (r#sequence_compound_nested , "Corresponds to expression: `sequence_nested`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound_nested , super :: super :: rules :: r#sequence_nested :: < 'i , 0 > , super :: super :: generics :: Skipped :: < 'i > , true , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_compound_nested<'i, INHERITED> {
-            #[doc = "A helper function to access [`sequence_nested`]."]
-            #[allow(non_snake_case)]
-            pub fn r#sequence_nested<'s>(
-                &'s self,
-            ) -> &'s super::super::rules::r#sequence_nested<'i, 0> {
-                let res = &*self.content;
-                res
-            }
-        }
-        :: pest_typed :: rule ! (r#node_tag , "Corresponds to expression: `string`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#node_tag , super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#node_tag<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
-            #[allow(non_snake_case)]
-            pub fn r#string<'s>(&'s self) -> &'s super::super::rules::r#string<'i, INHERITED> {
-                let res = &*self.content;
-                res
-            }
-        }
-        :: pest_typed :: rule ! (r#choice , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: rules :: r#range :: < 'i , INHERITED > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
+        :: pest_typed :: rule_instance ! (r#choice , "Corresponds to expression: `(string | range)`. Normal rule." "" "```pest" "choice = { string | range }" "```" "" "Accessers:" "" "- `range()`: `Option<&range>`" "- `string()`: `Option<&string>`" , super :: super :: Rule , super :: super :: Rule :: r#choice , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: rules :: r#range :: < 'i , INHERITED > , > , { super :: super :: Rule :: r#choice as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#choice<'i, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -653,26 +1840,36 @@ pub mod rules_impl {
                 &'s self,
             ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#range<'i, INHERITED>>
             {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = res._1().map(|res| res);
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`range`]."]
+            #[allow(non_snake_case)]
+            pub fn range_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#range().map(|res| ::pest_typed::Spanned::span(res))
+            }
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
             pub fn r#string<'s>(
                 &'s self,
             ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>
             {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = res._0().map(|res| res);
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#string().map(|res| ::pest_typed::Spanned::span(res))
+            }
         }
-        :: pest_typed :: rule ! (r#choice_prefix , "Corresponds to expression: `(string | range)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#choice_prefix , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: rules :: r#range :: < 'i , INHERITED > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
+        :: pest_typed :: rule_instance ! (r#choice_prefix , "Corresponds to expression: `(string | range)`. Normal rule." "" "```pest" "choice_prefix = { | string | range }" "```" "" "Accessers:" "" "- `range()`: `Option<&range>`" "- `string()`: `Option<&string>`" , super :: super :: Rule , super :: super :: Rule :: r#choice_prefix , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: rules :: r#range :: < 'i , INHERITED > , > , { super :: super :: Rule :: r#choice_prefix as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#choice_prefix<'i, INHERITED> {
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
@@ -680,75 +1877,48 @@ pub mod rules_impl {
                 &'s self,
             ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#range<'i, INHERITED>>
             {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = res._1().map(|res| res);
                     res
                 }
             }
-            #[doc = "A helper function to access [`string`]."]
+            #[doc = "A helper function to access spans of [`range`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._0().map(|res| res);
-                    res
-                }
+            pub fn range_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#range().map(|res| ::pest_typed::Spanned::span(res))
             }
-        }
-        :: pest_typed :: rule ! (r#optional , "Corresponds to expression: `string?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#optional , :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#optional<'i, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
             pub fn r#string<'s>(
                 &'s self,
             ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>
             {
-                let res = &*self.content;
+                let res = &self.content;
                 {
-                    let res = res.as_ref().map(|res| res);
+                    let res = res._0().map(|res| res);
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#repeat , "Corresponds to expression: `string*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat , super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+            #[doc = "A helper function to access spans of [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#string<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res
-                        .content
-                        .iter()
-                        .map(|res| {
-                            let res = &res.matched;
-                            res
-                        })
-                        .collect::<::pest_typed::re_exported::Vec<_>>();
-                    res
-                }
+            pub fn string_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#string().map(|res| ::pest_typed::Spanned::span(res))
             }
         }
-        :: pest_typed :: rule ! (r#repeat_atomic , "Corresponds to expression: `string*`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_atomic , super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#repeat_once , "Corresponds to expression: `(string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_once<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule_instance ! (r#double_neg_pred , "Corresponds to expression: `!!string`. Normal rule." "" "```pest" "double_neg_pred = { !!string }" "```" , super :: super :: Rule , super :: super :: Rule :: r#double_neg_pred , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Negative :: < super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > > , { super :: super :: Rule :: r#double_neg_pred as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#double_neg_pred<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#emoji , "Corresponds to expression: `(EMOJI ~ EMOJI*)`. Normal rule." "" "```pest" "emoji = { EMOJI+ }" "```" "" "Accessers:" "" "- `EMOJI()`: `(&EMOJI, Vec<&EMOJI>)`" , super :: super :: Rule , super :: super :: Rule :: r#emoji , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EMOJI , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#EMOJI > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#emoji as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#emoji<'i, INHERITED> {
+            #[doc = "A helper function to access [`EMOJI`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
+            pub fn r#EMOJI<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#string<'i, INHERITED>,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#string<'i, INHERITED>>,
+                &'s super::super::rules::r#EMOJI,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#EMOJI>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -761,10 +1931,7 @@ pub mod rules_impl {
                                 let res = res
                                     .content
                                     .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
+                                    .map(|res| res)
                                     .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
                             }
@@ -774,20 +1941,17 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (r#repeat_once_atomic , "Corresponds to expression: `(string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_once_atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#repeat_min_max , "Corresponds to expression: `(string ~ string ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min_max<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule_instance ! (r#han , "Corresponds to expression: `(HAN ~ HAN*)`. Normal rule." "" "```pest" "han = { HAN+ }" "```" "" "Accessers:" "" "- `HAN()`: `(&HAN, Vec<&HAN>)`" , super :: super :: Rule , super :: super :: Rule :: r#han , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HAN , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#HAN > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#han as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#han<'i, INHERITED> {
+            #[doc = "A helper function to access [`HAN`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
+            pub fn r#HAN<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#string<'i, INHERITED>,
-                &'s super::super::rules::r#string<'i, INHERITED>,
-                ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>,
+                &'s super::super::rules::r#HAN,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HAN>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -796,12 +1960,12 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.2.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
                             }
                         },
@@ -810,46 +1974,17 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (r#repeat_min_max_atomic , "Corresponds to expression: `(string ~ string ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min_max_atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#repeat_exact , "Corresponds to expression: `(string ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_exact , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_exact<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
-            #[allow(non_snake_case)]
-            pub fn r#string<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#string<'i, INHERITED>,
-                &'s super::super::rules::r#string<'i, INHERITED>,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            res
-                        },
-                    );
-                    res
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#repeat_min , "Corresponds to expression: `(string ~ string ~ string*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule_instance ! (r#hangul , "Corresponds to expression: `(HANGUL ~ HANGUL*)`. Normal rule." "" "```pest" "hangul = { HANGUL+ }" "```" "" "Accessers:" "" "- `HANGUL()`: `(&HANGUL, Vec<&HANGUL>)`" , super :: super :: Rule , super :: super :: Rule :: r#hangul , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HANGUL , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#HANGUL > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#hangul as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#hangul<'i, INHERITED> {
+            #[doc = "A helper function to access [`HANGUL`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
+            pub fn r#HANGUL<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#string<'i, INHERITED>,
-                &'s super::super::rules::r#string<'i, INHERITED>,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#string<'i, INHERITED>>,
+                &'s super::super::rules::r#HANGUL,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HANGUL>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -858,18 +1993,11 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.2.matched;
                             {
                                 let res = res
                                     .content
                                     .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
+                                    .map(|res| res)
                                     .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
                             }
@@ -879,32 +2007,31 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (r#repeat_min_atomic , "Corresponds to expression: `(string ~ string ~ string*)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_atomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min_atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#repeat_max , "Corresponds to expression: `(string? ~ string?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_max<'i, INHERITED> {
-            #[doc = "A helper function to access [`string`]."]
+        :: pest_typed :: rule_instance ! (r#hiragana , "Corresponds to expression: `(HIRAGANA ~ HIRAGANA*)`. Normal rule." "" "```pest" "hiragana = { HIRAGANA+ }" "```" "" "Accessers:" "" "- `HIRAGANA()`: `(&HIRAGANA, Vec<&HIRAGANA>)`" , super :: super :: Rule , super :: super :: Rule :: r#hiragana , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HIRAGANA , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#HIRAGANA > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#hiragana as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#hiragana<'i, INHERITED> {
+            #[doc = "A helper function to access [`HIRAGANA`]."]
             #[allow(non_snake_case)]
-            pub fn r#string<'s>(
+            pub fn r#HIRAGANA<'s>(
                 &'s self,
             ) -> (
-                ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>,
-                ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>,
+                &'s super::super::rules::r#HIRAGANA,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HIRAGANA>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
                             let res = &res.content.0.matched;
-                            {
-                                let res = res.as_ref().map(|res| res);
-                                res
-                            }
+                            res
                         },
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res.as_ref().map(|res| res);
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
                             }
                         },
@@ -913,116 +2040,97 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (r#repeat_max_atomic , "Corresponds to expression: `(string? ~ string?)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max_atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_max_atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#soi_at_start , "Corresponds to expression: `(SOI ~ string)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#soi_at_start , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#soi_at_start<'i, INHERITED> {
-            #[doc = "A helper function to access [`SOI`]."]
-            #[allow(non_snake_case)]
-            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI {
-                let res = &*self.content;
-                {
-                    let res = &res.content.0.matched;
-                    res
-                }
-            }
+        :: pest_typed :: rule_instance ! (r#ident , "Corresponds to expression: `string`. Normal rule." "" "```pest" "ident = { string }" "```" "" "Accessers:" "" "- `string()`: `&string`" , super :: super :: Rule , super :: super :: Rule :: r#ident , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: rules :: r#string :: < 'i , INHERITED > , { super :: super :: Rule :: r#ident as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ident<'i, INHERITED> {
             #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
             pub fn r#string<'s>(&'s self) -> &'s super::super::rules::r#string<'i, INHERITED> {
-                let res = &*self.content;
-                {
-                    let res = &res.content.1.matched;
-                    res
-                }
+                let res = &self.content;
+                res
+            }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#string())
             }
         }
-        :: pest_typed :: rule ! (r#repeat_mutate_stack , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack<'i, INHERITED> {
-            #[doc = "A helper function to access [`POP`]."]
+        :: pest_typed :: rule_instance ! (r#insensitive , "Corresponds to expression: `^\"abc\"`. Normal rule." "" "```pest" "insensitive = { ^\"abc\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#insensitive , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_0 > , { super :: super :: Rule :: r#insensitive as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#insensitive<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#neg_pred , "Corresponds to expression: `!string`. Normal rule." "" "```pest" "neg_pred = { !string }" "```" , super :: super :: Rule , super :: super :: Rule :: r#neg_pred , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Negative :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , { super :: super :: Rule :: r#neg_pred as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#neg_pred<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#newline , "Corresponds to expression: `(NEWLINE ~ NEWLINE*)`. Normal rule." "" "```pest" "newline = { NEWLINE+ }" "```" "" "Accessers:" "" "- `NEWLINE()`: `(&NEWLINE, Vec<&NEWLINE>)`" , super :: super :: Rule , super :: super :: Rule :: r#newline , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#NEWLINE , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#NEWLINE > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#newline as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#newline<'i, INHERITED> {
+            #[doc = "A helper function to access [`NEWLINE`]."]
             #[allow(non_snake_case)]
-            pub fn r#POP<'s>(
+            pub fn r#NEWLINE<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#POP<'i>,
-                &'s super::super::rules::r#POP<'i>,
-                &'s super::super::rules::r#POP<'i>,
+                &'s super::super::rules::r#NEWLINE,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NEWLINE>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
-                            let res = &res.content.1.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.2.matched;
+                            let res = &res.content.0.matched;
                             res
                         },
                         {
-                            let res = &res.content.3.matched;
-                            res
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
                         },
                     );
                     res
                 }
             }
         }
-        :: pest_typed :: rule ! (r#repeat_mutate_stack_pop_all , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack_pop_all<'i, INHERITED> {
-            #[doc = "A helper function to access [`POP_ALL`]."]
+        :: pest_typed :: rule_instance ! (r#node_tag , "Corresponds to expression: `string`. Normal rule." "" "```pest" "node_tag = { #string = string }" "```" "" "Accessers:" "" "- `string()`: `&string`" , super :: super :: Rule , super :: super :: Rule :: r#node_tag , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: rules :: r#string :: < 'i , INHERITED > , { super :: super :: Rule :: r#node_tag as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#node_tag<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#POP_ALL<'s>(&'s self) -> &'s super::super::rules::r#POP_ALL<'i> {
-                let res = &*self.content;
-                {
-                    let res = &res.content.1.matched;
-                    res
-                }
+            pub fn r#string<'s>(&'s self) -> &'s super::super::rules::r#string<'i, INHERITED> {
+                let res = &self.content;
+                res
             }
-        }
-        :: pest_typed :: rule ! (r#will_fail , "Corresponds to expression: `(repeat_mutate_stack_pop_all ~ \"FAIL\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#will_fail , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#will_fail<'i, INHERITED> {
-            #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
+            #[doc = "A helper function to access spans of [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#repeat_mutate_stack_pop_all<'s>(
-                &'s self,
-            ) -> &'s super::super::rules::r#repeat_mutate_stack_pop_all<'i, INHERITED> {
-                let res = &*self.content;
-                {
-                    let res = &res.content.0.matched;
-                    res
-                }
+            pub fn string_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#string())
             }
         }
-        :: pest_typed :: rule ! (r#stack_resume_after_fail , "Corresponds to expression: `(will_fail | repeat_mutate_stack_pop_all)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#stack_resume_after_fail , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#will_fail :: < 'i , INHERITED > , super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < 'i , INHERITED > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#stack_resume_after_fail<'i, INHERITED> {
-            #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
-            #[allow(non_snake_case)]
-            pub fn r#repeat_mutate_stack_pop_all<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<
-                &'s super::super::rules::r#repeat_mutate_stack_pop_all<'i, INHERITED>,
-            > {
-                let res = &*self.content;
-                {
-                    let res = res._1().map(|res| res);
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`will_fail`]."]
+        :: pest_typed :: rule_instance ! (r#optional , "Corresponds to expression: `string?`. Normal rule." "" "```pest" "optional = { string? }" "```" "" "Accessers:" "" "- `string()`: `Option<&string>`" , super :: super :: Rule , super :: super :: Rule :: r#optional , super :: super :: rules_impl :: bodies :: Both < 'i , :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , { super :: super :: Rule :: r#optional as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#optional<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#will_fail<'s>(
+            pub fn r#string<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Option<
-                &'s super::super::rules::r#will_fail<'i, INHERITED>,
-            > {
-                let res = &*self.content;
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>
+            {
+                let res = &self.content;
                 {
-                    let res = res._0().map(|res| res);
+                    let res = res.as_ref().map(|res| res);
                     res
                 }
             }
+            #[doc = "Whether the optional [`string`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_string(&self) -> ::core::primitive::bool {
+                self.r#string().is_some()
+            }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#string().map(|res| ::pest_typed::Spanned::span(res))
+            }
         }
-        :: pest_typed :: rule ! (r#peek_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK ~ PEEK)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
+        :: pest_typed :: rule_instance ! (r#peek_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK ~ PEEK)`. Normal rule." "" "```pest" "peek_ = { PUSH(range) ~ PUSH(range) ~ PEEK ~ PEEK }" "```" "" "Accessers:" "" "- `PEEK()`: `(&PEEK, &PEEK)`" "- `peek()`: `(Span, Span)`" "- `pushed()`: `(Span, Span)`" "- `range()`: `(&range, &range)`" , super :: super :: Rule , super :: super :: Rule :: r#peek_ , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#peek_ as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 2 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#peek_<'i, INHERITED> {
             #[doc = "A helper function to access [`PEEK`]."]
             #[allow(non_snake_case)]
@@ -1032,7 +2140,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#PEEK<'i>,
                 &'s super::super::rules::r#PEEK<'i>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1047,6 +2155,42 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans matched by `PEEK`."]
+            #[allow(non_snake_case)]
+            pub fn r#peek<'s>(&'s self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.2.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.3.matched;
+                            res.span
+                        },
+                    );
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans matched by `PUSH`."]
+            #[allow(non_snake_case)]
+            pub fn r#pushed<'s>(&'s self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            res.span
+                        },
+                    );
+                    res
+                }
+            }
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
             pub fn r#range<'s>(
@@ -1055,7 +2199,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#range<'i, INHERITED>,
                 &'s super::super::rules::r#range<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1076,18 +2220,47 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`range`]."]
+            #[allow(non_snake_case)]
+            pub fn range_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#range();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#peek_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK_ALL :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
+        :: pest_typed :: rule_instance ! (r#peek_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PEEK_ALL)`. Normal rule." "" "```pest" "peek_all = { PUSH(range) ~ PUSH(range) ~ PEEK_ALL }" "```" "" "Accessers:" "" "- `PEEK_ALL()`: `&PEEK_ALL`" "- `pushed()`: `(Span, Span)`" "- `range()`: `(&range, &range)`" , super :: super :: Rule , super :: super :: Rule :: r#peek_all , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PEEK_ALL :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#peek_all as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 2 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#peek_all<'i, INHERITED> {
             #[doc = "A helper function to access [`PEEK_ALL`]."]
             #[allow(non_snake_case)]
             pub fn r#PEEK_ALL<'s>(&'s self) -> &'s super::super::rules::r#PEEK_ALL<'i> {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = &res.content.2.matched;
                     res
                 }
             }
+            #[doc = "A helper function to access spans matched by `PUSH`."]
+            #[allow(non_snake_case)]
+            pub fn r#pushed<'s>(&'s self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            res.span
+                        },
+                    );
+                    res
+                }
+            }
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
             pub fn r#range<'s>(
@@ -1096,7 +2269,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#range<'i, INHERITED>,
                 &'s super::super::rules::r#range<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1117,9 +2290,58 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`range`]."]
+            #[allow(non_snake_case)]
+            pub fn range_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#range();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#peek_slice_23 , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PEEK[1..-2])`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#peek_slice_23 , super :: super :: generics :: Seq6 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: PeekSlice2 :: < 1i32 , - 2i32 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
+        :: pest_typed :: rule_instance ! (r#peek_slice_23 , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PEEK[1..-2])`. Normal rule." "" "```pest" "peek_slice_23 = { PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PUSH(range) ~ PEEK[1..-2] }" "```" "" "Accessers:" "" "- `pushed()`: `(Span, Span, Span, Span, Span)`" "- `range()`: `(&range, &range, &range, &range, &range)`" , super :: super :: Rule , super :: super :: Rule :: r#peek_slice_23 , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq6 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: PeekSlice2 :: < 'i , 1i32 , - 2i32 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#peek_slice_23 as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 5 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#peek_slice_23<'i, INHERITED> {
+            #[doc = "A helper function to access spans matched by `PUSH`."]
+            #[allow(non_snake_case)]
+            pub fn r#pushed<'s>(
+                &'s self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.2.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.3.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.4.matched;
+                            res.span
+                        },
+                    );
+                    res
+                }
+            }
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
             pub fn r#range<'s>(
@@ -1131,7 +2353,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#range<'i, INHERITED>,
                 &'s super::super::rules::r#range<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1173,8 +2395,30 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`range`]."]
+            #[allow(non_snake_case)]
+            pub fn range_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+            ) {
+                {
+                    let (res0, res1, res2, res3, res4) = self.r#range();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                        ::pest_typed::Spanned::span(res2),
+                        ::pest_typed::Spanned::span(res3),
+                        ::pest_typed::Spanned::span(res4),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#pop_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_ , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
+        :: pest_typed :: rule_instance ! (r#pop_ , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP ~ POP)`. Normal rule." "" "```pest" "pop_ = { PUSH(range) ~ PUSH(range) ~ POP ~ POP }" "```" "" "Accessers:" "" "- `POP()`: `(&POP, &POP)`" "- `pop()`: `(Span, Span)`" "- `pushed()`: `(Span, Span)`" "- `range()`: `(&range, &range)`" , super :: super :: Rule , super :: super :: Rule :: r#pop_ , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#pop_ as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 2 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#pop_<'i, INHERITED> {
             #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
@@ -1184,7 +2428,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#POP<'i>,
                 &'s super::super::rules::r#POP<'i>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1199,88 +2443,42 @@ pub mod rules_impl {
                     res
                 }
             }
-            #[doc = "A helper function to access [`range`]."]
+            #[doc = "A helper function to access spans matched by `POP`."]
             #[allow(non_snake_case)]
-            pub fn r#range<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#range<'i, INHERITED>,
-                &'s super::super::rules::r#range<'i, INHERITED>,
-            ) {
-                let res = &*self.content;
+            pub fn r#pop<'s>(&'s self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                let res = &self.content;
                 {
                     let res = (
                         {
-                            let res = &res.content.0.matched;
-                            {
-                                let res = &res.content;
-                                res
-                            }
+                            let res = &res.content.2.matched;
+                            res.span
                         },
                         {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = &res.content;
-                                res
-                            }
+                            let res = &res.content.3.matched;
+                            res.span
                         },
                     );
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#pop_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP_ALL)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_all , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#pop_all<'i, INHERITED> {
-            #[doc = "A helper function to access [`POP_ALL`]."]
-            #[allow(non_snake_case)]
-            pub fn r#POP_ALL<'s>(&'s self) -> &'s super::super::rules::r#POP_ALL<'i> {
-                let res = &*self.content;
-                {
-                    let res = &res.content.2.matched;
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`range`]."]
+            #[doc = "A helper function to access spans matched by `PUSH`."]
             #[allow(non_snake_case)]
-            pub fn r#range<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#range<'i, INHERITED>,
-                &'s super::super::rules::r#range<'i, INHERITED>,
-            ) {
-                let res = &*self.content;
+            pub fn r#pushed<'s>(&'s self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                let res = &self.content;
                 {
                     let res = (
                         {
                             let res = &res.content.0.matched;
-                            {
-                                let res = &res.content;
-                                res
-                            }
+                            res.span
                         },
                         {
                             let res = &res.content.1.matched;
-                            {
-                                let res = &res.content;
-                                res
-                            }
+                            res.span
                         },
                     );
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#pop_fail , "Corresponds to expression: `(PUSH(range) ~ !POP ~ range ~ POP)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#pop_fail , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#POP :: < 'i > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#range :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#pop_fail<'i, INHERITED> {
-            #[doc = "A helper function to access [`POP`]."]
-            #[allow(non_snake_case)]
-            pub fn r#POP<'s>(&'s self) -> &'s super::super::rules::r#POP<'i> {
-                let res = &*self.content;
-                {
-                    let res = &res.content.3.matched;
-                    res
-                }
-            }
             #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
             pub fn r#range<'s>(
@@ -1289,7 +2487,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#range<'i, INHERITED>,
                 &'s super::super::rules::r#range<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1299,104 +2497,10 @@ pub mod rules_impl {
                                 res
                             }
                         },
-                        {
-                            let res = &res.content.2.matched;
-                            res
-                        },
-                    );
-                    res
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#checkpoint_restore , "Corresponds to expression: `(PUSH(\"\") ~ ((PUSH(\"a\") ~ \"b\" ~ POP) | (DROP ~ \"b\") | (POP ~ \"a\")) ~ EOI)`. Atomic rule." "" , super :: super :: Rule , super :: super :: Rule :: r#checkpoint_restore , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Choice3 :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_6 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#DROP , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_9 > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < 'i > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#checkpoint_restore<'i, INHERITED> {
-            #[doc = "A helper function to access [`DROP`]."]
-            #[allow(non_snake_case)]
-            pub fn r#DROP<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#DROP> {
-                let res = &*self.content;
-                {
-                    let res = &res.content.1.matched;
-                    {
-                        let res = res._1().map(|res| {
-                            let res = &res.content.0.matched;
-                            res
-                        });
-                        res
-                    }
-                }
-            }
-            #[doc = "A helper function to access [`EOI`]."]
-            #[allow(non_snake_case)]
-            pub fn r#EOI<'s>(&'s self) -> &'s super::super::rules::r#EOI<'i> {
-                let res = &*self.content;
-                {
-                    let res = &res.content.2.matched;
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`POP`]."]
-            #[allow(non_snake_case)]
-            pub fn r#POP<'s>(
-                &'s self,
-            ) -> (
-                ::pest_typed::re_exported::Option<&'s super::super::rules::r#POP<'i>>,
-                ::pest_typed::re_exported::Option<&'s super::super::rules::r#POP<'i>>,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = &res.content.1.matched;
-                    {
-                        let res = (
-                            {
-                                let res = res._0().map(|res| {
-                                    let res = &res.content.2.matched;
-                                    res
-                                });
-                                res
-                            },
-                            {
-                                let res = res._2().map(|res| {
-                                    let res = &res.content.0.matched;
-                                    res
-                                });
-                                res
-                            },
-                        );
-                        res
-                    }
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#ascii_digits , "Corresponds to expression: `(ASCII_DIGIT ~ ASCII_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_digits<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_DIGIT`]."]
-            #[allow(non_snake_case)]
-            pub fn r#ASCII_DIGIT<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#ASCII_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_DIGIT>,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                let res = &res.content;
                                 res
                             }
                         },
@@ -1404,107 +2508,69 @@ pub mod rules_impl {
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#ascii_nonzero_digits , "Corresponds to expression: `(ASCII_NONZERO_DIGIT ~ ASCII_NONZERO_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_nonzero_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_NONZERO_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_NONZERO_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_nonzero_digits<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_NONZERO_DIGIT`]."]
+            #[doc = "A helper function to access spans of [`range`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_NONZERO_DIGIT<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#ASCII_NONZERO_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_NONZERO_DIGIT>,
-            ) {
-                let res = &*self.content;
+            pub fn range_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
                 {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
-                        },
-                    );
+                    let (res0, res1) = self.r#range();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#pop_all , "Corresponds to expression: `(PUSH(range) ~ PUSH(range) ~ POP_ALL)`. Normal rule." "" "```pest" "pop_all = { PUSH(range) ~ PUSH(range) ~ POP_ALL }" "```" "" "Accessers:" "" "- `POP_ALL()`: `&POP_ALL`" "- `pushed()`: `(Span, Span)`" "- `range()`: `(&range, &range)`" , super :: super :: Rule , super :: super :: Rule :: r#pop_all , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#pop_all as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#pop_all<'i, INHERITED> {
+            #[doc = "A helper function to access [`POP_ALL`]."]
+            #[allow(non_snake_case)]
+            pub fn r#POP_ALL<'s>(&'s self) -> &'s super::super::rules::r#POP_ALL<'i> {
+                let res = &self.content;
+                {
+                    let res = &res.content.2.matched;
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#ascii_bin_digits , "Corresponds to expression: `(ASCII_BIN_DIGIT ~ ASCII_BIN_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_bin_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_BIN_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_BIN_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_bin_digits<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_BIN_DIGIT`]."]
+            #[doc = "A helper function to access spans matched by `PUSH`."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_BIN_DIGIT<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#ASCII_BIN_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_BIN_DIGIT>,
-            ) {
-                let res = &*self.content;
+            pub fn r#pushed<'s>(&'s self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                let res = &self.content;
                 {
                     let res = (
                         {
                             let res = &res.content.0.matched;
-                            res
+                            res.span
                         },
                         {
                             let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
+                            res.span
                         },
                     );
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#ascii_oct_digits , "Corresponds to expression: `(ASCII_OCT_DIGIT ~ ASCII_OCT_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_oct_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_OCT_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_OCT_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_oct_digits<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_OCT_DIGIT`]."]
+            #[doc = "A helper function to access [`range`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_OCT_DIGIT<'s>(
+            pub fn r#range<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_OCT_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_OCT_DIGIT>,
+                &'s super::super::rules::r#range<'i, INHERITED>,
+                &'s super::super::rules::r#range<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
                             let res = &res.content.0.matched;
-                            res
+                            {
+                                let res = &res.content;
+                                res
+                            }
                         },
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                let res = &res.content;
                                 res
                             }
                         },
@@ -1512,54 +2578,191 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`range`]."]
+            #[allow(non_snake_case)]
+            pub fn range_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#range();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#ascii_hex_digits , "Corresponds to expression: `(ASCII_HEX_DIGIT ~ ASCII_HEX_DIGIT*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_hex_digits , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_HEX_DIGIT , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_HEX_DIGIT > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_hex_digits<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_HEX_DIGIT`]."]
+        :: pest_typed :: rule_instance ! (r#pop_fail , "Corresponds to expression: `(PUSH(range) ~ !POP ~ range ~ POP)`. Normal rule." "" "```pest" "pop_fail = { PUSH(range) ~ !POP ~ range ~ POP }" "```" "" "Accessers:" "" "- `POP()`: `&POP`" "- `pop()`: `Span`" "- `pushed()`: `Span`" "- `range()`: `(&range, &range)`" , super :: super :: Rule , super :: super :: Rule :: r#pop_fail , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#range :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#POP :: < 'i > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#range :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#pop_fail as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#pop_fail<'i, INHERITED> {
+            #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_HEX_DIGIT<'s>(
+            pub fn r#POP<'s>(&'s self) -> &'s super::super::rules::r#POP<'i> {
+                let res = &self.content;
+                {
+                    let res = &res.content.3.matched;
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans matched by `POP`."]
+            #[allow(non_snake_case)]
+            pub fn r#pop<'s>(&'s self) -> ::pest_typed::Span<'i> {
+                let res = &self.content;
+                {
+                    let res = &res.content.3.matched;
+                    res.span
+                }
+            }
+            #[doc = "A helper function to access spans matched by `PUSH`."]
+            #[allow(non_snake_case)]
+            pub fn r#pushed<'s>(&'s self) -> ::pest_typed::Span<'i> {
+                let res = &self.content;
+                {
+                    let res = &res.content.0.matched;
+                    res.span
+                }
+            }
+            #[doc = "A helper function to access [`range`]."]
+            #[allow(non_snake_case)]
+            pub fn r#range<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_HEX_DIGIT,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_HEX_DIGIT>,
+                &'s super::super::rules::r#range<'i, INHERITED>,
+                &'s super::super::rules::r#range<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
                             let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
                             {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                let res = &res.content;
                                 res
                             }
                         },
+                        {
+                            let res = &res.content.2.matched;
+                            res
+                        },
                     );
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`range`]."]
+            #[allow(non_snake_case)]
+            pub fn range_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#range();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#ascii_alpha_lowers , "Corresponds to expression: `(ASCII_ALPHA_LOWER ~ ASCII_ALPHA_LOWER*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_lowers , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_LOWER , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHA_LOWER > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alpha_lowers<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_ALPHA_LOWER`]."]
+        :: pest_typed :: rule_instance ! (r#pos_pred , "Corresponds to expression: `&string`. Normal rule." "" "```pest" "pos_pred = { &string }" "```" "" "Accessers:" "" "- `string()`: `&string`" , super :: super :: Rule , super :: super :: Rule :: r#pos_pred , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Positive :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , { super :: super :: Rule :: r#pos_pred as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#pos_pred<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_ALPHA_LOWER<'s>(
+            pub fn r#string<'s>(&'s self) -> &'s super::super::rules::r#string<'i, INHERITED> {
+                let res = &self.content;
+                {
+                    let res = &res.content;
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#string())
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#range , "Corresponds to expression: `('0'..'9')`. Normal rule." "" "```pest" "range = { '0'..'9' }" "```" , super :: super :: Rule , super :: super :: Rule :: r#range , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: CharRange :: < '0' , '9' > , { super :: super :: Rule :: r#range as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#range<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#repeat , "Corresponds to expression: `string*`. Normal rule." "" "```pest" "repeat = { string* }" "```" "" "Accessers:" "" "- `string()`: `Vec<&string>`" , super :: super :: Rule , super :: super :: Rule :: r#repeat , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#string :: < 'i , INHERITED > > , { super :: super :: Rule :: r#repeat as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn r#string<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#string<'i, INHERITED>>
+            {
+                let res = &self.content;
+                {
+                    let res = res
+                        .content
+                        .iter()
+                        .map(|res| res)
+                        .collect::<::pest_typed::re_exported::Vec<_>>();
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>> {
+                self.r#string()
+                    .into_iter()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+                    .collect::<::pest_typed::re_exported::Vec<_>>()
+            }
+        }
+        impl<'i> r#repeat<'i, 1> {
+            #[doc = r" Reparse `input`, which is the input of `self` with range `edit` replaced."]
+            #[doc = r""]
+            #[doc = r" Only the item that covers the edit is reparsed if possible,"]
+            #[doc = r" see [`try_reparse`](#pest_typed::incremental::try_reparse)."]
+            pub fn r#reparse(
+                &self,
+                edit: ::core::ops::Range<::core::primitive::usize>,
+                input: &'i ::core::primitive::str,
+            ) -> ::pest_typed::error::ParseResult<Self, super::super::Rule> {
+                ::pest_typed::incremental::reparse(self, edit, input, |node| {
+                    &mut node.content.content
+                })
+            }
+        }
+        impl<'i> r#repeat<'i, 1> {
+            #[doc = "Parse the whole input with this rule, passing each [`string`] to `f` and dropping it before the next one is parsed, instead of collecting them."]
+            #[doc = r""]
+            #[doc = r" Parsing stops without error once `f` returns [`ControlFlow::Break`](core::ops::ControlFlow::Break),"]
+            #[doc = r" and items passed to `f` before a failure are not rolled back."]
+            pub fn r#parse_iter(
+                input: &'i ::core::primitive::str,
+                f: impl ::core::ops::FnMut(
+                    super::super::rules::r#string<'i, 1>,
+                ) -> ::core::ops::ControlFlow<()>,
+            ) -> ::pest_typed::error::ParseResult<(), super::super::Rule> {
+                ::pest_typed::rule::parse_iter::<
+                    super::super::Rule,
+                    Self,
+                    super::super::rules::r#string<'i, 1>,
+                    ::pest_typed::predefined_node::Empty<'i>,
+                    super::super::generics::Skipped<'i>,
+                >(input, false, f)
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#repeat_atomic , "Corresponds to expression: `string*`. Atomic rule." "" "```pest" "repeat_atomic = @{ string* }" "```" , super :: super :: Rule , super :: super :: Rule :: r#repeat_atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#repeat_atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_atomic<'i, INHERITED> {}
+        impl<'i> ::pest_typed::BoundWrapper for r#repeat_atomic<'i, 1> {
+            const MIN: ::core::primitive::usize = {
+                #[allow(dead_code)]
+                const INHERITED: ::core::primitive::usize = 1;
+                < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > as :: pest_typed :: BoundWrapper > :: MIN
+            };
+            const MAX: ::core::primitive::usize = {
+                #[allow(dead_code)]
+                const INHERITED: ::core::primitive::usize = 1;
+                < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > as :: pest_typed :: BoundWrapper > :: MAX
+            };
+        }
+        :: pest_typed :: rule_instance ! (r#repeat_exact , "Corresponds to expression: `(string ~ string)`. Normal rule." "" "```pest" "repeat_exact = { string{2} }" "```" "" "Accessers:" "" "- `string()`: `(&string, &string)`" , super :: super :: Rule , super :: super :: Rule :: r#repeat_exact , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#repeat_exact as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_exact<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHA_LOWER,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_LOWER>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1568,51 +2771,48 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
+                            res
                         },
                     );
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#string();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#ascii_alpha_uppers , "Corresponds to expression: `(ASCII_ALPHA_UPPER ~ ASCII_ALPHA_UPPER*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alpha_uppers , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA_UPPER , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHA_UPPER > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alpha_uppers<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_ALPHA_UPPER`]."]
+        :: pest_typed :: rule_instance ! (r#repeat_max , "Corresponds to expression: `(string? ~ string?)`. Normal rule." "" "```pest" "repeat_max = { string{, 2} }" "```" "" "Accessers:" "" "- `string()`: `(Option<&string>, Option<&string>)`" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#repeat_max as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_max<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_ALPHA_UPPER<'s>(
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHA_UPPER,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA_UPPER>,
+                ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>,
+                ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
                             let res = &res.content.0.matched;
-                            res
+                            {
+                                let res = res.as_ref().map(|res| res);
+                                res
+                            }
                         },
                         {
                             let res = &res.content.1.matched;
                             {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                let res = res.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -1620,18 +2820,37 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(
+                &self,
+            ) -> (
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1) = self.r#string();
+                    (
+                        res0.map(|res| ::pest_typed::Spanned::span(res)),
+                        res1.map(|res| ::pest_typed::Spanned::span(res)),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#ascii_alphas , "Corresponds to expression: `(ASCII_ALPHA ~ ASCII_ALPHA*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphas , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHA > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alphas<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_ALPHA`]."]
+        :: pest_typed :: rule_instance ! (r#repeat_max_atomic , "Corresponds to expression: `(string? ~ string?)`. Atomic rule." "" "```pest" "repeat_max_atomic = @{ string{, 2} }" "```" , super :: super :: Rule , super :: super :: Rule :: r#repeat_max_atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#repeat_max_atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 > , > , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_max_atomic<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#repeat_min , "Corresponds to expression: `(string ~ string ~ string*)`. Normal rule." "" "```pest" "repeat_min = { string{2,} }" "```" "" "Accessers:" "" "- `string()`: `(&string, &string, Vec<&string>)`" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#repeat_min as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_ALPHA<'s>(
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHA,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHA>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#string<'i, INHERITED>>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1640,14 +2859,15 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.2.matched;
                             {
                                 let res = res
                                     .content
                                     .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
+                                    .map(|res| res)
                                     .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
                             }
@@ -1656,18 +2876,41 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1, res2) = self.r#string();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                        res2.into_iter()
+                            .map(|res| ::pest_typed::Spanned::span(res))
+                            .collect::<::pest_typed::re_exported::Vec<_>>(),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#ascii_alphanumerics , "Corresponds to expression: `(ASCII_ALPHANUMERIC ~ ASCII_ALPHANUMERIC*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ascii_alphanumerics , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHANUMERIC , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII_ALPHANUMERIC > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ascii_alphanumerics<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII_ALPHANUMERIC`]."]
+        :: pest_typed :: rule_instance ! (r#repeat_min_atomic , "Corresponds to expression: `(string ~ string ~ string*)`. Atomic rule." "" "```pest" "repeat_min_atomic = @{ string{2,} }" "```" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#repeat_min_atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 > , > , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min_atomic<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#repeat_min_max , "Corresponds to expression: `(string ~ string ~ string?)`. Normal rule." "" "```pest" "repeat_min_max = { string{2, 3} }" "```" "" "Accessers:" "" "- `string()`: `(&string, &string, Option<&string>)`" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#repeat_min_max as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min_max<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII_ALPHANUMERIC<'s>(
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII_ALPHANUMERIC,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII_ALPHANUMERIC>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
+                ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, INHERITED>>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1676,15 +2919,12 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.2.matched;
                             {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                let res = res.as_ref().map(|res| res);
                                 res
                             }
                         },
@@ -1692,126 +2932,151 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1, res2) = self.r#string();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                        res2.map(|res| ::pest_typed::Spanned::span(res)),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#asciis , "Corresponds to expression: `(ASCII ~ ASCII*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#asciis , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ASCII > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#asciis<'i, INHERITED> {
-            #[doc = "A helper function to access [`ASCII`]."]
+        :: pest_typed :: rule_instance ! (r#repeat_min_max_atomic , "Corresponds to expression: `(string ~ string ~ string?)`. Atomic rule." "" "```pest" "repeat_min_max_atomic = @{ string{2, 3} }" "```" , super :: super :: Rule , super :: super :: Rule :: r#repeat_min_max_atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#repeat_min_max_atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 > , > , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_min_max_atomic<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#repeat_mutate_stack , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP ~ POP ~ POP)`. Normal rule." "" "```pest" "repeat_mutate_stack = { (PUSH('a'..'c') ~ \",\")* ~ POP ~ POP ~ POP }" "```" "" "Accessers:" "" "- `POP()`: `(&POP, &POP, &POP)`" "- `pop()`: `(Span, Span, Span)`" "- `pushed()`: `Vec<Span>`" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#repeat_mutate_stack as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack<'i, INHERITED> {
+            #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
-            pub fn r#ASCII<'s>(
+            pub fn r#POP<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ASCII,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ASCII>,
+                &'s super::super::rules::r#POP<'i>,
+                &'s super::super::rules::r#POP<'i>,
+                &'s super::super::rules::r#POP<'i>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
-                            let res = &res.content.0.matched;
+                            let res = &res.content.1.matched;
                             res
                         },
                         {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
+                            let res = &res.content.2.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.3.matched;
+                            res
                         },
                     );
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#newline , "Corresponds to expression: `(NEWLINE ~ NEWLINE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#newline , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#NEWLINE , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#NEWLINE > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#newline<'i, INHERITED> {
-            #[doc = "A helper function to access [`NEWLINE`]."]
+            #[doc = "A helper function to access spans matched by `POP`."]
             #[allow(non_snake_case)]
-            pub fn r#NEWLINE<'s>(
+            pub fn r#pop<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#NEWLINE,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NEWLINE>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
-                            let res = &res.content.0.matched;
-                            res
+                            let res = &res.content.1.matched;
+                            res.span
+                        },
+                        {
+                            let res = &res.content.2.matched;
+                            res.span
                         },
                         {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
+                            let res = &res.content.3.matched;
+                            res.span
                         },
                     );
                     res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#unicode , "Corresponds to expression: `(XID_START ~ XID_CONTINUE*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#unicode , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#XID_START , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#XID_CONTINUE > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#unicode<'i, INHERITED> {
-            #[doc = "A helper function to access [`XID_CONTINUE`]."]
+            #[doc = "A helper function to access spans matched by `PUSH`."]
             #[allow(non_snake_case)]
-            pub fn r#XID_CONTINUE<'s>(
+            pub fn r#pushed<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#XID_CONTINUE>
-            {
-                let res = &*self.content;
+            ) -> ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>> {
+                let res = &self.content;
                 {
-                    let res = &res.content.1.matched;
+                    let res = &res.content.0.matched;
                     {
                         let res = res
                             .content
                             .iter()
                             .map(|res| {
-                                let res = &res.matched;
-                                res
+                                let res = &res.content.0.matched;
+                                res.span
                             })
                             .collect::<::pest_typed::re_exported::Vec<_>>();
                         res
                     }
                 }
             }
-            #[doc = "A helper function to access [`XID_START`]."]
+        }
+        :: pest_typed :: rule_instance ! (r#repeat_mutate_stack_pop_all , "Corresponds to expression: `((PUSH(('a'..'c')) ~ \",\")* ~ POP_ALL)`. Normal rule." "" "```pest" "repeat_mutate_stack_pop_all = { (PUSH('a'..'c') ~ \",\")* ~ POP_ALL }" "```" "" "Accessers:" "" "- `POP_ALL()`: `&POP_ALL`" "- `pushed()`: `Vec<Span>`" , super :: super :: Rule , super :: super :: Rule :: r#repeat_mutate_stack_pop_all , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: generics :: CharRange :: < 'a' , 'c' > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#POP_ALL :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#repeat_mutate_stack_pop_all as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_mutate_stack_pop_all<'i, INHERITED> {
+            #[doc = "A helper function to access [`POP_ALL`]."]
             #[allow(non_snake_case)]
-            pub fn r#XID_START<'s>(&'s self) -> &'s super::super::rules::r#XID_START {
-                let res = &*self.content;
+            pub fn r#POP_ALL<'s>(&'s self) -> &'s super::super::rules::r#POP_ALL<'i> {
+                let res = &self.content;
                 {
-                    let res = &res.content.0.matched;
+                    let res = &res.content.1.matched;
                     res
                 }
             }
+            #[doc = "A helper function to access spans matched by `PUSH`."]
+            #[allow(non_snake_case)]
+            pub fn r#pushed<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>> {
+                let res = &self.content;
+                {
+                    let res = &res.content.0.matched;
+                    {
+                        let res = res
+                            .content
+                            .iter()
+                            .map(|res| {
+                                let res = &res.content.0.matched;
+                                res.span
+                            })
+                            .collect::<::pest_typed::re_exported::Vec<_>>();
+                        res
+                    }
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#SYMBOL , "Corresponds to expression: `\"shadows builtin\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#SYMBOL , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#SYMBOL<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#han , "Corresponds to expression: `(HAN ~ HAN*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#han , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HAN , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#HAN > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#han<'i, INHERITED> {
-            #[doc = "A helper function to access [`HAN`]."]
+        :: pest_typed :: rule_instance ! (r#repeat_once , "Corresponds to expression: `(string ~ string*)`. Normal rule." "" "```pest" "repeat_once = { string+ }" "```" "" "Accessers:" "" "- `string()`: `(&string, Vec<&string>)`" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#string :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#repeat_once as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_once<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#HAN<'s>(
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#HAN,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HAN>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#string<'i, INHERITED>>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1824,10 +3089,7 @@ pub mod rules_impl {
                                 let res = res
                                     .content
                                     .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
+                                    .map(|res| res)
                                     .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
                             }
@@ -1836,18 +3098,38 @@ pub mod rules_impl {
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1) = self.r#string();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        res1.into_iter()
+                            .map(|res| ::pest_typed::Spanned::span(res))
+                            .collect::<::pest_typed::re_exported::Vec<_>>(),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#hangul , "Corresponds to expression: `(HANGUL ~ HANGUL*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hangul , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HANGUL , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#HANGUL > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#hangul<'i, INHERITED> {
-            #[doc = "A helper function to access [`HANGUL`]."]
+        :: pest_typed :: rule_instance ! (r#repeat_once_atomic , "Corresponds to expression: `(string ~ string*)`. Atomic rule." "" "```pest" "repeat_once_atomic = @{ string+ }" "```" , super :: super :: Rule , super :: super :: Rule :: r#repeat_once_atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#repeat_once_atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: rules :: r#string :: < 'i , 0 > > , super :: super :: generics :: Skipped < 'i > , 0 > , > , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#repeat_once_atomic<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#sequence , "Corresponds to expression: `(string ~ string)`. Non-atomic rule." "" "```pest" "sequence = !{ string ~ string }" "```" "" "Accessers:" "" "- `string()`: `(&string, &string)`" , super :: super :: Rule , super :: super :: Rule :: r#sequence , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 > , > , { super :: super :: Rule :: r#sequence as :: core :: primitive :: usize } , INHERITED > , false , Both , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#HANGUL<'s>(
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#HANGUL,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HANGUL>,
+                &'s super::super::rules::r#string<'i, 1>,
+                &'s super::super::rules::r#string<'i, 1>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1856,34 +3138,39 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
+                            res
                         },
                     );
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#string();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#hiragana , "Corresponds to expression: `(HIRAGANA ~ HIRAGANA*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#hiragana , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#HIRAGANA , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#HIRAGANA > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#hiragana<'i, INHERITED> {
-            #[doc = "A helper function to access [`HIRAGANA`]."]
+        :: pest_typed :: rule_instance ! (r#sequence_atomic , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" "```pest" "sequence_atomic = @{ string ~ string }" "```" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#sequence_atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , > , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_atomic<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#sequence_atomic_compound , "Corresponds to expression: `sequence_compound`. Atomic rule." "" "```pest" "sequence_atomic_compound = @{ sequence_compound }" "```" , super :: super :: Rule , super :: super :: Rule :: r#sequence_atomic_compound , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#sequence_atomic_compound as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: rules :: r#sequence_compound :: < 'i , 0 > , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_atomic_compound<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#sequence_compound , "Corresponds to expression: `(string ~ string)`. Atomic rule." "" "```pest" "sequence_compound = ${ string ~ string }" "```" "" "Accessers:" "" "- `string()`: `(&string, &string)`" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: generics :: Skipped < 'i > , 0 > , > , { super :: super :: Rule :: r#sequence_compound as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_compound<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
             #[allow(non_snake_case)]
-            pub fn r#HIRAGANA<'s>(
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#HIRAGANA,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#HIRAGANA>,
+                &'s super::super::rules::r#string<'i, 0>,
+                &'s super::super::rules::r#string<'i, 0>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1892,34 +3179,51 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
+                            res
                         },
                     );
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#string();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#arabic , "Corresponds to expression: `(ARABIC ~ ARABIC*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#arabic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ARABIC , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ARABIC > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#arabic<'i, INHERITED> {
-            #[doc = "A helper function to access [`ARABIC`]."]
+        :: pest_typed :: rule_instance ! (r#sequence_compound_nested , "Corresponds to expression: `sequence_nested`. Atomic rule." "" "```pest" "sequence_compound_nested = ${ sequence_nested }" "```" "" "Accessers:" "" "- `sequence_nested()`: `&sequence_nested`" , super :: super :: Rule , super :: super :: Rule :: r#sequence_compound_nested , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: rules :: r#sequence_nested :: < 'i , 0 > , { super :: super :: Rule :: r#sequence_compound_nested as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_compound_nested<'i, INHERITED> {
+            #[doc = "A helper function to access [`sequence_nested`]."]
             #[allow(non_snake_case)]
-            pub fn r#ARABIC<'s>(
+            pub fn r#sequence_nested<'s>(
+                &'s self,
+            ) -> &'s super::super::rules::r#sequence_nested<'i, 0> {
+                let res = &self.content;
+                res
+            }
+            #[doc = "A helper function to access spans of [`sequence_nested`]."]
+            #[allow(non_snake_case)]
+            pub fn sequence_nested_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#sequence_nested())
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#sequence_nested , "Corresponds to expression: `(string ~ string)`. Normal rule." "" "```pest" "sequence_nested = { string ~ string }" "```" "" "Accessers:" "" "- `string()`: `(&string, &string)`" , super :: super :: Rule , super :: super :: Rule :: r#sequence_nested , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#sequence_nested as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_nested<'i, INHERITED> {
+            #[doc = "A helper function to access [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn r#string<'s>(
                 &'s self,
             ) -> (
-                &'s super::super::rules::r#ARABIC,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ARABIC>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
+                &'s super::super::rules::r#string<'i, INHERITED>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -1928,89 +3232,208 @@ pub mod rules_impl {
                         },
                         {
                             let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
+                            res
                         },
                     );
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#string();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
         }
-        :: pest_typed :: rule ! (r#emoji , "Corresponds to expression: `(EMOJI ~ EMOJI*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#emoji , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EMOJI , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#EMOJI > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#emoji<'i, INHERITED> {
-            #[doc = "A helper function to access [`EMOJI`]."]
+        :: pest_typed :: rule_instance ! (r#sequence_non_atomic , "Corresponds to expression: `sequence`. Atomic rule." "" "```pest" "sequence_non_atomic = @{ sequence }" "```" , super :: super :: Rule , super :: super :: Rule :: r#sequence_non_atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#sequence_non_atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: rules :: r#sequence :: < 'i , 0 > , min_len = 6 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#sequence_non_atomic<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#soi_at_start , "Corresponds to expression: `(SOI ~ string)`. Normal rule." "" "```pest" "soi_at_start = { SOI ~ string }" "```" "" "Accessers:" "" "- `SOI()`: `&SOI`" "- `string()`: `&string`" , super :: super :: Rule , super :: super :: Rule :: r#soi_at_start , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#string :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#soi_at_start as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#soi_at_start<'i, INHERITED> {
+            #[doc = "A helper function to access [`SOI`]."]
             #[allow(non_snake_case)]
-            pub fn r#EMOJI<'s>(
+            pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI {
+                let res = &self.content;
+                {
+                    let res = &res.content.0.matched;
+                    res
+                }
+            }
+            #[doc = "A helper function to access [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn r#string<'s>(&'s self) -> &'s super::super::rules::r#string<'i, INHERITED> {
+                let res = &self.content;
+                {
+                    let res = &res.content.1.matched;
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#string())
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#stack_resume_after_fail , "Corresponds to expression: `(will_fail | repeat_mutate_stack_pop_all)`. Normal rule." "" "```pest" "stack_resume_after_fail = { will_fail | repeat_mutate_stack_pop_all }" "```" "" "Accessers:" "" "- `repeat_mutate_stack_pop_all()`: `Option<&repeat_mutate_stack_pop_all>`" "- `will_fail()`: `Option<&will_fail>`" , super :: super :: Rule , super :: super :: Rule :: r#stack_resume_after_fail , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#will_fail :: < 'i , INHERITED > , super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < 'i , INHERITED > , > , { super :: super :: Rule :: r#stack_resume_after_fail as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#stack_resume_after_fail<'i, INHERITED> {
+            #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
+            #[allow(non_snake_case)]
+            pub fn r#repeat_mutate_stack_pop_all<'s>(
                 &'s self,
-            ) -> (
-                &'s super::super::rules::r#EMOJI,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#EMOJI>,
-            ) {
-                let res = &*self.content;
+            ) -> ::pest_typed::re_exported::Option<
+                &'s super::super::rules::r#repeat_mutate_stack_pop_all<'i, INHERITED>,
+            > {
+                let res = &self.content;
                 {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
-                        },
-                    );
+                    let res = res._1().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`repeat_mutate_stack_pop_all`]."]
+            #[allow(non_snake_case)]
+            pub fn repeat_mutate_stack_pop_all_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#repeat_mutate_stack_pop_all()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`will_fail`]."]
+            #[allow(non_snake_case)]
+            pub fn r#will_fail<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<
+                &'s super::super::rules::r#will_fail<'i, INHERITED>,
+            > {
+                let res = &self.content;
+                {
+                    let res = res._0().map(|res| res);
                     res
                 }
             }
+            #[doc = "A helper function to access spans of [`will_fail`]."]
+            #[allow(non_snake_case)]
+            pub fn will_fail_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#will_fail()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#string , "Corresponds to expression: `\"abc\"`. Normal rule." "" "```pest" "string = { \"abc\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#string , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , { super :: super :: Rule :: r#string as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#string<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#unicode , "Corresponds to expression: `(XID_START ~ XID_CONTINUE*)`. Normal rule." "" "```pest" "unicode = { XID_START ~ XID_CONTINUE* }" "```" "" "Accessers:" "" "- `XID_CONTINUE()`: `Vec<&XID_CONTINUE>`" "- `XID_START()`: `&XID_START`" , super :: super :: Rule , super :: super :: Rule :: r#unicode , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#XID_START , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#XID_CONTINUE > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#unicode as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#unicode<'i, INHERITED> {
+            #[doc = "A helper function to access [`XID_CONTINUE`]."]
+            #[allow(non_snake_case)]
+            pub fn r#XID_CONTINUE<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#XID_CONTINUE>
+            {
+                let res = &self.content;
+                {
+                    let res = &res.content.1.matched;
+                    {
+                        let res = res
+                            .content
+                            .iter()
+                            .map(|res| res)
+                            .collect::<::pest_typed::re_exported::Vec<_>>();
+                        res
+                    }
+                }
+            }
+            #[doc = "A helper function to access [`XID_START`]."]
+            #[allow(non_snake_case)]
+            pub fn r#XID_START<'s>(&'s self) -> &'s super::super::rules::r#XID_START {
+                let res = &self.content;
+                {
+                    let res = &res.content.0.matched;
+                    res
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#will_fail , "Corresponds to expression: `(repeat_mutate_stack_pop_all ~ \"FAIL\")`. Normal rule." "" "```pest" "will_fail = { repeat_mutate_stack_pop_all ~ \"FAIL\" }" "```" "" "Accessers:" "" "- `repeat_mutate_stack_pop_all()`: `&repeat_mutate_stack_pop_all`" , super :: super :: Rule , super :: super :: Rule :: r#will_fail , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#repeat_mutate_stack_pop_all :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#will_fail as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 4 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#will_fail<'i, INHERITED> {
+            #[doc = "A helper function to access [`repeat_mutate_stack_pop_all`]."]
+            #[allow(non_snake_case)]
+            pub fn r#repeat_mutate_stack_pop_all<'s>(
+                &'s self,
+            ) -> &'s super::super::rules::r#repeat_mutate_stack_pop_all<'i, INHERITED> {
+                let res = &self.content;
+                {
+                    let res = &res.content.0.matched;
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`repeat_mutate_stack_pop_all`]."]
+            #[allow(non_snake_case)]
+            pub fn repeat_mutate_stack_pop_all_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#repeat_mutate_stack_pop_all())
+            }
         }
-        :: pest_typed :: rule ! (r#WHITESPACE , "Corresponds to expression: `\" \"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#WHITESPACE , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Expression , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#WHITESPACE<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#COMMENT , "Corresponds to expression: `(\"$\" ~ \"$\"*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#COMMENT , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_12 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_13 > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Expression , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#COMMENT<'i, INHERITED> {}
         #[allow(unused_imports)]
         use super::super::unicode::*;
         ::pest_typed::rule_eoi!(EOI, super::super::Rule);
-        pub use pest_typed::predefined_node::ASCII;
-        pub use pest_typed::predefined_node::ASCII_ALPHA;
-        pub use pest_typed::predefined_node::ASCII_ALPHANUMERIC;
-        pub use pest_typed::predefined_node::ASCII_ALPHA_LOWER;
-        pub use pest_typed::predefined_node::ASCII_ALPHA_UPPER;
-        pub use pest_typed::predefined_node::ASCII_BIN_DIGIT;
-        pub use pest_typed::predefined_node::ASCII_DIGIT;
-        pub use pest_typed::predefined_node::ASCII_HEX_DIGIT;
-        pub use pest_typed::predefined_node::ASCII_NONZERO_DIGIT;
-        pub use pest_typed::predefined_node::ASCII_OCT_DIGIT;
-        pub use pest_typed::predefined_node::DROP;
-        pub use pest_typed::predefined_node::NEWLINE;
-        pub use pest_typed::predefined_node::PEEK;
-        pub use pest_typed::predefined_node::PEEK_ALL;
-        pub use pest_typed::predefined_node::POP;
-        pub use pest_typed::predefined_node::POP_ALL;
-        pub use pest_typed::predefined_node::SOI;
+        pub use ::pest_typed::predefined_node::ASCII;
+        pub use ::pest_typed::predefined_node::ASCII_ALPHA;
+        pub use ::pest_typed::predefined_node::ASCII_ALPHANUMERIC;
+        pub use ::pest_typed::predefined_node::ASCII_ALPHA_LOWER;
+        pub use ::pest_typed::predefined_node::ASCII_ALPHA_UPPER;
+        pub use ::pest_typed::predefined_node::ASCII_BIN_DIGIT;
+        pub use ::pest_typed::predefined_node::ASCII_DIGIT;
+        pub use ::pest_typed::predefined_node::ASCII_HEX_DIGIT;
+        pub use ::pest_typed::predefined_node::ASCII_NONZERO_DIGIT;
+        pub use ::pest_typed::predefined_node::ASCII_OCT_DIGIT;
+        pub use ::pest_typed::predefined_node::DROP;
+        pub use ::pest_typed::predefined_node::NEWLINE;
+        pub use ::pest_typed::predefined_node::PEEK;
+        pub use ::pest_typed::predefined_node::PEEK_ALL;
+        pub use ::pest_typed::predefined_node::POP;
+        pub use ::pest_typed::predefined_node::POP_ALL;
+        pub use ::pest_typed::predefined_node::SOI;
+    }
+    #[doc = "Generic structs shared by rules in [rules], except rules whose types may contain themselves."]
+    pub mod bodies {
+        ::pest_typed::rule_body!(
+            Both,
+            "Body of non-atomic rules emitting `Both`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            false,
+            Both,
+            false,
+            r#new
+        );
+        ::pest_typed::rule_body!(
+            BothAtomic,
+            "Body of atomic rules emitting `Both`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            true,
+            Both,
+            false,
+            r#new
+        );
+        ::pest_typed::rule_body!(
+            SpanAtomic,
+            "Body of atomic rules emitting `Span`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            true,
+            Span,
+            false,
+            r#new
+        );
     }
 }
 pub use rules_impl::rules;
 #[doc = "Used generics."]
 pub mod generics {
-    use pest_typed::predefined_node;
+    use ::pest_typed::predefined_node;
     #[doc = r" Skipped content."]
     pub type Skipped<'i> = predefined_node::AtomicRep<
         ::pest_typed::choices::Choice2<
@@ -2025,7 +3448,8 @@ pub mod generics {
     pub use pest_typed::sequence::Seq4;
     pub use pest_typed::sequence::Seq6;
     pub use predefined_node::{
-        CharRange, Insens, Negative, PeekSlice1, PeekSlice2, Positive, Push, Skip, Str,
+        CharExcept, CharRange, Insens, InsensUnicode, Keyword, Negative, PeekSlice1, PeekSlice2,
+        Positive, Presence, Push, PushLiteral, Skip, SpanOnly, Str, StrChoice, StrSpanned,
     };
     #[doc = r" Repeat arbitrary times."]
     pub type Rep<'i, const SKIP: ::core::primitive::usize, T> =
@@ -2036,3 +3460,196 @@ pub mod generics {
 }
 #[doc = "Re-export some types from rules to simplify the usage."]
 pub use rules as pairs;
+#[doc = r" Parse the whole input with a rule selected at runtime."]
+#[doc = r""]
+#[doc = r" Silent rules have no pairs, so an error is returned for them."]
+#[allow(dead_code)]
+pub fn parse_rule<'i>(
+    rule: Rule,
+    input: &'i ::core::primitive::str,
+) -> ::pest_typed::error::ParseResult<
+    ::pest_typed::Box<dyn ::pest_typed::iterators::AnyPair<'i, Rule> + 'i>,
+    Rule,
+> {
+    fn parse<
+        'i,
+        T: ::pest_typed::ParsableTypedNode<'i, Rule> + ::pest_typed::iterators::AnyPair<'i, Rule> + 'i,
+    >(
+        input: &'i ::core::primitive::str,
+    ) -> ::pest_typed::error::ParseResult<
+        ::pest_typed::Box<dyn ::pest_typed::iterators::AnyPair<'i, Rule> + 'i>,
+        Rule,
+    > {
+        let res = T::try_parse(input)?;
+        ::core::result::Result::Ok(::pest_typed::Box::new(res))
+    }
+    match rule {
+        Rule::EOI => parse::<pairs::EOI<'i>>(input),
+        Rule::r#string => parse::<pairs::r#string<'i>>(input),
+        Rule::r#insensitive => parse::<pairs::r#insensitive<'i>>(input),
+        Rule::r#range => parse::<pairs::r#range<'i>>(input),
+        Rule::r#ident => parse::<pairs::r#ident<'i>>(input),
+        Rule::r#pos_pred => parse::<pairs::r#pos_pred<'i>>(input),
+        Rule::r#neg_pred => parse::<pairs::r#neg_pred<'i>>(input),
+        Rule::r#double_neg_pred => parse::<pairs::r#double_neg_pred<'i>>(input),
+        Rule::r#sequence => parse::<pairs::r#sequence<'i>>(input),
+        Rule::r#sequence_compound => parse::<pairs::r#sequence_compound<'i>>(input),
+        Rule::r#sequence_atomic => parse::<pairs::r#sequence_atomic<'i>>(input),
+        Rule::r#sequence_non_atomic => parse::<pairs::r#sequence_non_atomic<'i>>(input),
+        Rule::r#sequence_atomic_compound => parse::<pairs::r#sequence_atomic_compound<'i>>(input),
+        Rule::r#sequence_nested => parse::<pairs::r#sequence_nested<'i>>(input),
+        Rule::r#sequence_compound_nested => parse::<pairs::r#sequence_compound_nested<'i>>(input),
+        Rule::r#node_tag => parse::<pairs::r#node_tag<'i>>(input),
+        Rule::r#choice => parse::<pairs::r#choice<'i>>(input),
+        Rule::r#choice_prefix => parse::<pairs::r#choice_prefix<'i>>(input),
+        Rule::r#optional => parse::<pairs::r#optional<'i>>(input),
+        Rule::r#repeat => parse::<pairs::r#repeat<'i>>(input),
+        Rule::r#repeat_atomic => parse::<pairs::r#repeat_atomic<'i>>(input),
+        Rule::r#repeat_once => parse::<pairs::r#repeat_once<'i>>(input),
+        Rule::r#repeat_once_atomic => parse::<pairs::r#repeat_once_atomic<'i>>(input),
+        Rule::r#repeat_min_max => parse::<pairs::r#repeat_min_max<'i>>(input),
+        Rule::r#repeat_min_max_atomic => parse::<pairs::r#repeat_min_max_atomic<'i>>(input),
+        Rule::r#repeat_exact => parse::<pairs::r#repeat_exact<'i>>(input),
+        Rule::r#repeat_min => parse::<pairs::r#repeat_min<'i>>(input),
+        Rule::r#repeat_min_atomic => parse::<pairs::r#repeat_min_atomic<'i>>(input),
+        Rule::r#repeat_max => parse::<pairs::r#repeat_max<'i>>(input),
+        Rule::r#repeat_max_atomic => parse::<pairs::r#repeat_max_atomic<'i>>(input),
+        Rule::r#soi_at_start => parse::<pairs::r#soi_at_start<'i>>(input),
+        Rule::r#repeat_mutate_stack => parse::<pairs::r#repeat_mutate_stack<'i>>(input),
+        Rule::r#repeat_mutate_stack_pop_all => {
+            parse::<pairs::r#repeat_mutate_stack_pop_all<'i>>(input)
+        }
+        Rule::r#will_fail => parse::<pairs::r#will_fail<'i>>(input),
+        Rule::r#stack_resume_after_fail => parse::<pairs::r#stack_resume_after_fail<'i>>(input),
+        Rule::r#peek_ => parse::<pairs::r#peek_<'i>>(input),
+        Rule::r#peek_all => parse::<pairs::r#peek_all<'i>>(input),
+        Rule::r#peek_slice_23 => parse::<pairs::r#peek_slice_23<'i>>(input),
+        Rule::r#pop_ => parse::<pairs::r#pop_<'i>>(input),
+        Rule::r#pop_all => parse::<pairs::r#pop_all<'i>>(input),
+        Rule::r#pop_fail => parse::<pairs::r#pop_fail<'i>>(input),
+        Rule::r#checkpoint_restore => parse::<pairs::r#checkpoint_restore<'i>>(input),
+        Rule::r#ascii_digits => parse::<pairs::r#ascii_digits<'i>>(input),
+        Rule::r#ascii_nonzero_digits => parse::<pairs::r#ascii_nonzero_digits<'i>>(input),
+        Rule::r#ascii_bin_digits => parse::<pairs::r#ascii_bin_digits<'i>>(input),
+        Rule::r#ascii_oct_digits => parse::<pairs::r#ascii_oct_digits<'i>>(input),
+        Rule::r#ascii_hex_digits => parse::<pairs::r#ascii_hex_digits<'i>>(input),
+        Rule::r#ascii_alpha_lowers => parse::<pairs::r#ascii_alpha_lowers<'i>>(input),
+        Rule::r#ascii_alpha_uppers => parse::<pairs::r#ascii_alpha_uppers<'i>>(input),
+        Rule::r#ascii_alphas => parse::<pairs::r#ascii_alphas<'i>>(input),
+        Rule::r#ascii_alphanumerics => parse::<pairs::r#ascii_alphanumerics<'i>>(input),
+        Rule::r#asciis => parse::<pairs::r#asciis<'i>>(input),
+        Rule::r#newline => parse::<pairs::r#newline<'i>>(input),
+        Rule::r#unicode => parse::<pairs::r#unicode<'i>>(input),
+        Rule::r#SYMBOL => parse::<pairs::r#SYMBOL<'i>>(input),
+        Rule::r#han => parse::<pairs::r#han<'i>>(input),
+        Rule::r#hangul => parse::<pairs::r#hangul<'i>>(input),
+        Rule::r#hiragana => parse::<pairs::r#hiragana<'i>>(input),
+        Rule::r#arabic => parse::<pairs::r#arabic<'i>>(input),
+        Rule::r#emoji => parse::<pairs::r#emoji<'i>>(input),
+        Rule::r#WHITESPACE => {
+            ::core::result::Result::Err(
+                ::pest_typed::Box::new(
+                    ::pest_typed::error::ParseFailure::new(
+                        <::pest_typed::error::Error<Rule> as ::pest_typed::error::CustomError<
+                            'i,
+                        >>::custom_at_pos(
+                            ::pest_typed::Position::from_start(input),
+                            "Rule `WHITESPACE` is silent, so it can't be parsed into a pair.",
+                        ),
+                        ::pest_typed::Position::from_start(input),
+                    ),
+                ),
+            )
+        }
+        Rule::r#COMMENT => {
+            ::core::result::Result::Err(
+                ::pest_typed::Box::new(
+                    ::pest_typed::error::ParseFailure::new(
+                        <::pest_typed::error::Error<Rule> as ::pest_typed::error::CustomError<
+                            'i,
+                        >>::custom_at_pos(
+                            ::pest_typed::Position::from_start(input),
+                            "Rule `COMMENT` is silent, so it can't be parsed into a pair.",
+                        ),
+                        ::pest_typed::Position::from_start(input),
+                    ),
+                ),
+            )
+        }
+    }
+}
+#[doc = r" Rules directly referenced by each rule, in the order they are defined."]
+#[doc = r""]
+#[doc = r" Built-in rules other than `EOI` and implicit `WHITESPACE` and `COMMENT` are not listed."]
+#[allow(dead_code)]
+pub fn rule_dependencies() -> &'static [(Rule, &'static [Rule])] {
+    &[
+        (Rule::r#string, &[]),
+        (Rule::r#insensitive, &[]),
+        (Rule::r#range, &[]),
+        (Rule::r#ident, &[Rule::r#string]),
+        (Rule::r#pos_pred, &[Rule::r#string]),
+        (Rule::r#neg_pred, &[Rule::r#string]),
+        (Rule::r#double_neg_pred, &[Rule::r#string]),
+        (Rule::r#sequence, &[Rule::r#string]),
+        (Rule::r#sequence_compound, &[Rule::r#string]),
+        (Rule::r#sequence_atomic, &[Rule::r#string]),
+        (Rule::r#sequence_non_atomic, &[Rule::r#sequence]),
+        (
+            Rule::r#sequence_atomic_compound,
+            &[Rule::r#sequence_compound],
+        ),
+        (Rule::r#sequence_nested, &[Rule::r#string]),
+        (Rule::r#sequence_compound_nested, &[Rule::r#sequence_nested]),
+        (Rule::r#node_tag, &[Rule::r#string]),
+        (Rule::r#choice, &[Rule::r#string, Rule::r#range]),
+        (Rule::r#choice_prefix, &[Rule::r#string, Rule::r#range]),
+        (Rule::r#optional, &[Rule::r#string]),
+        (Rule::r#repeat, &[Rule::r#string]),
+        (Rule::r#repeat_atomic, &[Rule::r#string]),
+        (Rule::r#repeat_once, &[Rule::r#string]),
+        (Rule::r#repeat_once_atomic, &[Rule::r#string]),
+        (Rule::r#repeat_min_max, &[Rule::r#string]),
+        (Rule::r#repeat_min_max_atomic, &[Rule::r#string]),
+        (Rule::r#repeat_exact, &[Rule::r#string]),
+        (Rule::r#repeat_min, &[Rule::r#string]),
+        (Rule::r#repeat_min_atomic, &[Rule::r#string]),
+        (Rule::r#repeat_max, &[Rule::r#string]),
+        (Rule::r#repeat_max_atomic, &[Rule::r#string]),
+        (Rule::r#soi_at_start, &[Rule::r#string]),
+        (Rule::r#repeat_mutate_stack, &[]),
+        (Rule::r#repeat_mutate_stack_pop_all, &[]),
+        (Rule::r#will_fail, &[Rule::r#repeat_mutate_stack_pop_all]),
+        (
+            Rule::r#stack_resume_after_fail,
+            &[Rule::r#repeat_mutate_stack_pop_all, Rule::r#will_fail],
+        ),
+        (Rule::r#peek_, &[Rule::r#range]),
+        (Rule::r#peek_all, &[Rule::r#range]),
+        (Rule::r#peek_slice_23, &[Rule::r#range]),
+        (Rule::r#pop_, &[Rule::r#range]),
+        (Rule::r#pop_all, &[Rule::r#range]),
+        (Rule::r#pop_fail, &[Rule::r#range]),
+        (Rule::r#checkpoint_restore, &[Rule::r#EOI]),
+        (Rule::r#ascii_digits, &[]),
+        (Rule::r#ascii_nonzero_digits, &[]),
+        (Rule::r#ascii_bin_digits, &[]),
+        (Rule::r#ascii_oct_digits, &[]),
+        (Rule::r#ascii_hex_digits, &[]),
+        (Rule::r#ascii_alpha_lowers, &[]),
+        (Rule::r#ascii_alpha_uppers, &[]),
+        (Rule::r#ascii_alphas, &[]),
+        (Rule::r#ascii_alphanumerics, &[]),
+        (Rule::r#asciis, &[]),
+        (Rule::r#newline, &[]),
+        (Rule::r#unicode, &[]),
+        (Rule::r#SYMBOL, &[]),
+        (Rule::r#han, &[]),
+        (Rule::r#hangul, &[]),
+        (Rule::r#hiragana, &[]),
+        (Rule::r#arabic, &[]),
+        (Rule::r#emoji, &[]),
+        (Rule::r#WHITESPACE, &[]),
+        (Rule::r#COMMENT, &[]),
+    ]
+}
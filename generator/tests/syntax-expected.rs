@@ -1,5 +1,5 @@
 #![cfg(not(feature = "grammar-extras"))]
-#![allow(unused_parens)]
+#![allow(clippy::all)]
 #[doc = ""]
 #[allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
 #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -42,8 +42,294 @@ pub enum Rule {
     r#Drop,
     r#PeekAll,
 }
-#[doc = "Unicode rules."]
-pub mod unicode {}
+#[allow(dead_code)]
+impl Rule {
+    #[doc = r" All rules, starting with `EOI` and then in the order of the grammar."]
+    pub const ALL: &'static [Rule] = &[
+        Rule::EOI,
+        Rule::r#Regular,
+        Rule::r#Atomic,
+        Rule::r#Silent,
+        Rule::r#CompoundAtomic,
+        Rule::r#Tag,
+        Rule::r#NonAtomic,
+        Rule::r#ExactString,
+        Rule::r#CaseInsensitive,
+        Rule::r#CharRange,
+        Rule::r#Any,
+        Rule::r#Seq,
+        Rule::r#Choice,
+        Rule::r#Rep,
+        Rule::r#RepAtLeastOnce,
+        Rule::r#Opt,
+        Rule::r#RepExact,
+        Rule::r#RepLeft,
+        Rule::r#RepRight,
+        Rule::r#RepLeftRight,
+        Rule::r#Pos,
+        Rule::r#Neg,
+        Rule::r#Push,
+        Rule::r#Pop,
+        Rule::r#PopAll,
+        Rule::r#Peek,
+        Rule::r#PeekLeft,
+        Rule::r#PeekRight,
+        Rule::r#PeekLeftRight,
+        Rule::r#Drop,
+        Rule::r#PeekAll,
+    ];
+    #[doc = r" Names of all rules as in the grammar, in the same order as [`Rule::ALL`]."]
+    pub const NAMES: &'static [&'static ::core::primitive::str] = &[
+        "EOI",
+        "Regular",
+        "Atomic",
+        "Silent",
+        "CompoundAtomic",
+        "Tag",
+        "NonAtomic",
+        "ExactString",
+        "CaseInsensitive",
+        "CharRange",
+        "Any",
+        "Seq",
+        "Choice",
+        "Rep",
+        "RepAtLeastOnce",
+        "Opt",
+        "RepExact",
+        "RepLeft",
+        "RepRight",
+        "RepLeftRight",
+        "Pos",
+        "Neg",
+        "Push",
+        "Pop",
+        "PopAll",
+        "Peek",
+        "PeekLeft",
+        "PeekRight",
+        "PeekLeftRight",
+        "Drop",
+        "PeekAll",
+    ];
+    #[doc = r" Name of the rule as in the grammar."]
+    pub fn name(&self) -> &'static ::core::primitive::str {
+        match self {
+            Rule::EOI => "EOI",
+            Rule::r#Regular => "Regular",
+            Rule::r#Atomic => "Atomic",
+            Rule::r#Silent => "Silent",
+            Rule::r#CompoundAtomic => "CompoundAtomic",
+            Rule::r#Tag => "Tag",
+            Rule::r#NonAtomic => "NonAtomic",
+            Rule::r#ExactString => "ExactString",
+            Rule::r#CaseInsensitive => "CaseInsensitive",
+            Rule::r#CharRange => "CharRange",
+            Rule::r#Any => "Any",
+            Rule::r#Seq => "Seq",
+            Rule::r#Choice => "Choice",
+            Rule::r#Rep => "Rep",
+            Rule::r#RepAtLeastOnce => "RepAtLeastOnce",
+            Rule::r#Opt => "Opt",
+            Rule::r#RepExact => "RepExact",
+            Rule::r#RepLeft => "RepLeft",
+            Rule::r#RepRight => "RepRight",
+            Rule::r#RepLeftRight => "RepLeftRight",
+            Rule::r#Pos => "Pos",
+            Rule::r#Neg => "Neg",
+            Rule::r#Push => "Push",
+            Rule::r#Pop => "Pop",
+            Rule::r#PopAll => "PopAll",
+            Rule::r#Peek => "Peek",
+            Rule::r#PeekLeft => "PeekLeft",
+            Rule::r#PeekRight => "PeekRight",
+            Rule::r#PeekLeftRight => "PeekLeftRight",
+            Rule::r#Drop => "Drop",
+            Rule::r#PeekAll => "PeekAll",
+        }
+    }
+    #[doc = r" The rule named `name` in the grammar."]
+    pub fn from_name(name: &::core::primitive::str) -> ::core::option::Option<Rule> {
+        match name {
+            "EOI" => ::core::option::Option::Some(Rule::EOI),
+            "Regular" => ::core::option::Option::Some(Rule::r#Regular),
+            "Atomic" => ::core::option::Option::Some(Rule::r#Atomic),
+            "Silent" => ::core::option::Option::Some(Rule::r#Silent),
+            "CompoundAtomic" => ::core::option::Option::Some(Rule::r#CompoundAtomic),
+            "Tag" => ::core::option::Option::Some(Rule::r#Tag),
+            "NonAtomic" => ::core::option::Option::Some(Rule::r#NonAtomic),
+            "ExactString" => ::core::option::Option::Some(Rule::r#ExactString),
+            "CaseInsensitive" => ::core::option::Option::Some(Rule::r#CaseInsensitive),
+            "CharRange" => ::core::option::Option::Some(Rule::r#CharRange),
+            "Any" => ::core::option::Option::Some(Rule::r#Any),
+            "Seq" => ::core::option::Option::Some(Rule::r#Seq),
+            "Choice" => ::core::option::Option::Some(Rule::r#Choice),
+            "Rep" => ::core::option::Option::Some(Rule::r#Rep),
+            "RepAtLeastOnce" => ::core::option::Option::Some(Rule::r#RepAtLeastOnce),
+            "Opt" => ::core::option::Option::Some(Rule::r#Opt),
+            "RepExact" => ::core::option::Option::Some(Rule::r#RepExact),
+            "RepLeft" => ::core::option::Option::Some(Rule::r#RepLeft),
+            "RepRight" => ::core::option::Option::Some(Rule::r#RepRight),
+            "RepLeftRight" => ::core::option::Option::Some(Rule::r#RepLeftRight),
+            "Pos" => ::core::option::Option::Some(Rule::r#Pos),
+            "Neg" => ::core::option::Option::Some(Rule::r#Neg),
+            "Push" => ::core::option::Option::Some(Rule::r#Push),
+            "Pop" => ::core::option::Option::Some(Rule::r#Pop),
+            "PopAll" => ::core::option::Option::Some(Rule::r#PopAll),
+            "Peek" => ::core::option::Option::Some(Rule::r#Peek),
+            "PeekLeft" => ::core::option::Option::Some(Rule::r#PeekLeft),
+            "PeekRight" => ::core::option::Option::Some(Rule::r#PeekRight),
+            "PeekLeftRight" => ::core::option::Option::Some(Rule::r#PeekLeftRight),
+            "Drop" => ::core::option::Option::Some(Rule::r#Drop),
+            "PeekAll" => ::core::option::Option::Some(Rule::r#PeekAll),
+            _ => ::core::option::Option::None,
+        }
+    }
+    #[doc = r" Name of the rule in error messages, if it's given by `#[error_label]`."]
+    pub fn error_label(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+        match self {
+            Rule::EOI => ::core::option::Option::None,
+            Rule::r#Regular => ::core::option::Option::None,
+            Rule::r#Atomic => ::core::option::Option::None,
+            Rule::r#Silent => ::core::option::Option::None,
+            Rule::r#CompoundAtomic => ::core::option::Option::None,
+            Rule::r#Tag => ::core::option::Option::None,
+            Rule::r#NonAtomic => ::core::option::Option::None,
+            Rule::r#ExactString => ::core::option::Option::None,
+            Rule::r#CaseInsensitive => ::core::option::Option::None,
+            Rule::r#CharRange => ::core::option::Option::None,
+            Rule::r#Any => ::core::option::Option::None,
+            Rule::r#Seq => ::core::option::Option::None,
+            Rule::r#Choice => ::core::option::Option::None,
+            Rule::r#Rep => ::core::option::Option::None,
+            Rule::r#RepAtLeastOnce => ::core::option::Option::None,
+            Rule::r#Opt => ::core::option::Option::None,
+            Rule::r#RepExact => ::core::option::Option::None,
+            Rule::r#RepLeft => ::core::option::Option::None,
+            Rule::r#RepRight => ::core::option::Option::None,
+            Rule::r#RepLeftRight => ::core::option::Option::None,
+            Rule::r#Pos => ::core::option::Option::None,
+            Rule::r#Neg => ::core::option::Option::None,
+            Rule::r#Push => ::core::option::Option::None,
+            Rule::r#Pop => ::core::option::Option::None,
+            Rule::r#PopAll => ::core::option::Option::None,
+            Rule::r#Peek => ::core::option::Option::None,
+            Rule::r#PeekLeft => ::core::option::Option::None,
+            Rule::r#PeekRight => ::core::option::Option::None,
+            Rule::r#PeekLeftRight => ::core::option::Option::None,
+            Rule::r#Drop => ::core::option::Option::None,
+            Rule::r#PeekAll => ::core::option::Option::None,
+        }
+    }
+    #[doc = r" Whether the rule is atomic (`@`) or compound-atomic (`$`), so nothing is skipped implicitly inside it."]
+    pub fn is_atomic(&self) -> ::core::primitive::bool {
+        match self {
+            Rule::EOI => false,
+            Rule::r#Regular => false,
+            Rule::r#Atomic => true,
+            Rule::r#Silent => false,
+            Rule::r#CompoundAtomic => true,
+            Rule::r#Tag => false,
+            Rule::r#NonAtomic => false,
+            Rule::r#ExactString => false,
+            Rule::r#CaseInsensitive => false,
+            Rule::r#CharRange => false,
+            Rule::r#Any => false,
+            Rule::r#Seq => false,
+            Rule::r#Choice => false,
+            Rule::r#Rep => false,
+            Rule::r#RepAtLeastOnce => false,
+            Rule::r#Opt => false,
+            Rule::r#RepExact => false,
+            Rule::r#RepLeft => false,
+            Rule::r#RepRight => false,
+            Rule::r#RepLeftRight => false,
+            Rule::r#Pos => false,
+            Rule::r#Neg => false,
+            Rule::r#Push => false,
+            Rule::r#Pop => false,
+            Rule::r#PopAll => false,
+            Rule::r#Peek => false,
+            Rule::r#PeekLeft => false,
+            Rule::r#PeekRight => false,
+            Rule::r#PeekLeftRight => false,
+            Rule::r#Drop => false,
+            Rule::r#PeekAll => false,
+        }
+    }
+    #[doc = r" Whether the rule is silent (`_`), so it produces no pairs."]
+    pub fn is_silent(&self) -> ::core::primitive::bool {
+        match self {
+            Rule::EOI => false,
+            Rule::r#Regular => false,
+            Rule::r#Atomic => false,
+            Rule::r#Silent => true,
+            Rule::r#CompoundAtomic => false,
+            Rule::r#Tag => false,
+            Rule::r#NonAtomic => false,
+            Rule::r#ExactString => false,
+            Rule::r#CaseInsensitive => false,
+            Rule::r#CharRange => false,
+            Rule::r#Any => true,
+            Rule::r#Seq => false,
+            Rule::r#Choice => false,
+            Rule::r#Rep => false,
+            Rule::r#RepAtLeastOnce => false,
+            Rule::r#Opt => false,
+            Rule::r#RepExact => false,
+            Rule::r#RepLeft => false,
+            Rule::r#RepRight => false,
+            Rule::r#RepLeftRight => false,
+            Rule::r#Pos => false,
+            Rule::r#Neg => false,
+            Rule::r#Push => false,
+            Rule::r#Pop => false,
+            Rule::r#PopAll => false,
+            Rule::r#Peek => false,
+            Rule::r#PeekLeft => false,
+            Rule::r#PeekRight => false,
+            Rule::r#PeekLeftRight => false,
+            Rule::r#Drop => false,
+            Rule::r#PeekAll => false,
+        }
+    }
+    #[doc = r" Doc comment of the rule in the grammar, with lines joined by `\n`."]
+    pub fn doc(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+        match self {
+            Rule::EOI => ::core::option::Option::None,
+            Rule::r#Regular => ::core::option::Option::Some("Regular rule."),
+            Rule::r#Atomic => ::core::option::Option::Some("Atomic rule."),
+            Rule::r#Silent => ::core::option::Option::Some("Silent rule."),
+            Rule::r#CompoundAtomic => ::core::option::Option::Some("Compound atomic rule."),
+            Rule::r#Tag => ::core::option::Option::Some("Tagged rule."),
+            Rule::r#NonAtomic => ::core::option::Option::Some("Non-atomic rule."),
+            Rule::r#ExactString => ::core::option::Option::None,
+            Rule::r#CaseInsensitive => ::core::option::Option::None,
+            Rule::r#CharRange => ::core::option::Option::None,
+            Rule::r#Any => ::core::option::Option::None,
+            Rule::r#Seq => ::core::option::Option::None,
+            Rule::r#Choice => ::core::option::Option::None,
+            Rule::r#Rep => ::core::option::Option::None,
+            Rule::r#RepAtLeastOnce => ::core::option::Option::None,
+            Rule::r#Opt => ::core::option::Option::None,
+            Rule::r#RepExact => ::core::option::Option::None,
+            Rule::r#RepLeft => ::core::option::Option::None,
+            Rule::r#RepRight => ::core::option::Option::None,
+            Rule::r#RepLeftRight => ::core::option::Option::None,
+            Rule::r#Pos => ::core::option::Option::None,
+            Rule::r#Neg => ::core::option::Option::None,
+            Rule::r#Push => ::core::option::Option::None,
+            Rule::r#Pop => ::core::option::Option::None,
+            Rule::r#PopAll => ::core::option::Option::None,
+            Rule::r#Peek => ::core::option::Option::None,
+            Rule::r#PeekLeft => ::core::option::Option::None,
+            Rule::r#PeekRight => ::core::option::Option::None,
+            Rule::r#PeekLeftRight => ::core::option::Option::None,
+            Rule::r#Drop => ::core::option::Option::None,
+            Rule::r#PeekAll => ::core::option::Option::None,
+        }
+    }
+}
 mod constant_wrappers {
     #[doc = "A wrapper for `\"+\"`."]
     #[allow(non_camel_case_types)]
@@ -73,116 +359,74 @@ mod constant_wrappers {
     impl ::pest_typed::StringWrapper for r#w_3 {
         const CONTENT: &'static ::core::primitive::str = "\"";
     }
-    #[doc = "A wrapper for `\"\\\"\"`."]
+    #[doc = "A wrapper for `[\"\\\"\"]`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_4;
-    impl ::pest_typed::StringWrapper for r#w_4 {
-        const CONTENT: &'static ::core::primitive::str = "\"";
+    impl ::pest_typed::StringArrayWrapper for r#w_4 {
+        const CONTENT: &'static [&'static ::core::primitive::str] = &["\""];
     }
-    #[doc = "A wrapper for `\"\\\"\"`."]
+    #[doc = "A wrapper for `\"b\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
     pub struct r#w_5;
     impl ::pest_typed::StringWrapper for r#w_5 {
-        const CONTENT: &'static ::core::primitive::str = "\"";
-    }
-    #[doc = "A wrapper for `\"b\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_6;
-    impl ::pest_typed::StringWrapper for r#w_6 {
         const CONTENT: &'static ::core::primitive::str = "b";
     }
     #[doc = "A wrapper for `\"BB\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_7;
-    impl ::pest_typed::StringWrapper for r#w_7 {
+    pub struct r#w_6;
+    impl ::pest_typed::StringWrapper for r#w_6 {
         const CONTENT: &'static ::core::primitive::str = "BB";
     }
-    #[doc = "A wrapper for `\"b\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_8;
-    impl ::pest_typed::StringWrapper for r#w_8 {
-        const CONTENT: &'static ::core::primitive::str = "b";
-    }
     #[doc = "A wrapper for `\"c\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_9;
-    impl ::pest_typed::StringWrapper for r#w_9 {
+    pub struct r#w_7;
+    impl ::pest_typed::StringWrapper for r#w_7 {
         const CONTENT: &'static ::core::primitive::str = "c";
     }
     #[doc = "A wrapper for `\"r#\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_10;
-    impl ::pest_typed::StringWrapper for r#w_10 {
+    pub struct r#w_8;
+    impl ::pest_typed::StringWrapper for r#w_8 {
         const CONTENT: &'static ::core::primitive::str = "r#";
     }
     #[doc = "A wrapper for `\"0x\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_11;
-    impl ::pest_typed::StringWrapper for r#w_11 {
+    pub struct r#w_9;
+    impl ::pest_typed::StringWrapper for r#w_9 {
         const CONTENT: &'static ::core::primitive::str = "0x";
     }
     #[doc = "A wrapper for `\"1\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_12;
-    impl ::pest_typed::StringWrapper for r#w_12 {
+    pub struct r#w_10;
+    impl ::pest_typed::StringWrapper for r#w_10 {
         const CONTENT: &'static ::core::primitive::str = "1";
     }
     #[doc = "A wrapper for `\".\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_13;
-    impl ::pest_typed::StringWrapper for r#w_13 {
+    pub struct r#w_11;
+    impl ::pest_typed::StringWrapper for r#w_11 {
         const CONTENT: &'static ::core::primitive::str = ".";
     }
     #[doc = "A wrapper for `\"a\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_14;
-    impl ::pest_typed::StringWrapper for r#w_14 {
+    pub struct r#w_12;
+    impl ::pest_typed::StringWrapper for r#w_12 {
         const CONTENT: &'static ::core::primitive::str = "a";
     }
-    #[doc = "A wrapper for `\"b\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_15;
-    impl ::pest_typed::StringWrapper for r#w_15 {
-        const CONTENT: &'static ::core::primitive::str = "b";
-    }
-    #[doc = "A wrapper for `\"b\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_16;
-    impl ::pest_typed::StringWrapper for r#w_16 {
-        const CONTENT: &'static ::core::primitive::str = "b";
-    }
-    #[doc = "A wrapper for `\"c\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_17;
-    impl ::pest_typed::StringWrapper for r#w_17 {
-        const CONTENT: &'static ::core::primitive::str = "c";
-    }
-    #[doc = "A wrapper for `\"b\"`."]
-    #[allow(non_camel_case_types)]
-    #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_18;
-    impl ::pest_typed::StringWrapper for r#w_18 {
-        const CONTENT: &'static ::core::primitive::str = "b";
-    }
     #[doc = "A wrapper for `\"?\"`."]
     #[allow(non_camel_case_types)]
     #[derive(Clone, Hash, PartialEq, Eq)]
-    pub struct r#w_19;
-    impl ::pest_typed::StringWrapper for r#w_19 {
+    pub struct r#w_13;
+    impl ::pest_typed::StringWrapper for r#w_13 {
         const CONTENT: &'static ::core::primitive::str = "?";
     }
 }
@@ -190,254 +434,459 @@ mod constant_wrappers {
 pub mod rules_impl {
     #[doc = "Definitions of statically typed nodes generated by pest-generator."]
     pub mod rules {
-        :: pest_typed :: rule ! (r#Regular , "Corresponds to expression: `((CharRange ~ CharRange*) ~ \"+\" ~ CharRange ~ CharRange*)`. Normal rule." "" "Regular rule." , super :: super :: Rule , super :: super :: Rule :: r#Regular , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#CharRange :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#CharRange :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Regular<'i, INHERITED> {
-            #[doc = "A helper function to access [`CharRange`]."]
+        :: pest_typed :: rule_instance ! (r#Any , "Corresponds to expression: `ANY`. Normal rule." "" "```pest" "Any             = _{ ANY }" "```" "" "Accessers:" "" "- `ANY()`: `&ANY`" , super :: super :: Rule , super :: super :: Rule :: r#Any , super :: super :: rules_impl :: bodies :: Expression < 'i , super :: super :: rules :: r#ANY , { super :: super :: Rule :: r#Any as :: core :: primitive :: usize } , INHERITED > , INHERITED , Expression , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Any<'i, INHERITED> {
+            #[doc = "A helper function to access [`ANY`]."]
             #[allow(non_snake_case)]
-            pub fn r#CharRange<'s>(
-                &'s self,
-            ) -> (
-                (
-                    &'s super::super::rules::r#CharRange<'i, INHERITED>,
-                    ::pest_typed::re_exported::Vec<
-                        &'s super::super::rules::r#CharRange<'i, INHERITED>,
-                    >,
-                ),
-                &'s super::super::rules::r#CharRange<'i, INHERITED>,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#CharRange<'i, INHERITED>>,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            {
-                                let res = (
-                                    {
-                                        let res = &res.content.0.matched;
-                                        res
-                                    },
-                                    {
-                                        let res = &res.content.1.matched;
-                                        {
-                                            let res = res
-                                                .content
-                                                .iter()
-                                                .map(|res| {
-                                                    let res = &res.matched;
-                                                    res
-                                                })
-                                                .collect::<::pest_typed::re_exported::Vec<_>>();
-                                            res
-                                        }
-                                    },
-                                );
-                                res
-                            }
-                        },
-                        {
-                            let res = &res.content.2.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.3.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
-                        },
-                    );
-                    res
-                }
+            pub fn r#ANY<'s>(&'s self) -> &'s super::super::rules::r#ANY {
+                let res = &self.content;
+                res
             }
         }
-        :: pest_typed :: rule ! (r#Atomic , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Atomic rule." "" "Atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#Atomic , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: generics :: CharRange :: < '0' , '9' > > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Span , true);
+        :: pest_typed :: rule_instance ! (r#Atomic , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Atomic rule." "" "Atomic rule." "" "```pest" "Atomic = @{ ('0'..'9')+ }" "```" , super :: super :: Rule , super :: super :: Rule :: r#Atomic , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#Atomic as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: generics :: CharRange :: < '0' , '9' > > , super :: super :: generics :: Skipped < 'i > , 0 > , > , min_len = 1 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#Atomic<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#Silent , "Corresponds to expression: `(\"(\" | \")\")`. Normal rule." "" "Silent rule." , super :: super :: Rule , super :: super :: Rule :: r#Silent , super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Expression , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Silent<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#CompoundAtomic , "Corresponds to expression: `(\"\\\"\" ~ (!\"\\\"\" ~ ANY)* ~ \"\\\"\")`. Atomic rule." "" "Compound atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#CompoundAtomic , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ANY , super :: super :: generics :: Skipped < 'i > , 0 >) , > > , super :: super :: generics :: Skipped < 'i > , 0 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Skipped < 'i > , 0 >) , > , super :: super :: generics :: Skipped :: < 'i > , true , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#CompoundAtomic<'i, INHERITED> {
-            #[doc = "A helper function to access [`ANY`]."]
+        :: pest_typed :: rule_instance ! (r#CaseInsensitive , "Corresponds to expression: `^\"0x\"`. Normal rule." "" "```pest" "CaseInsensitive =  { ^\"0x\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#CaseInsensitive , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_9 > , { super :: super :: Rule :: r#CaseInsensitive as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#CaseInsensitive<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#CharRange , "Corresponds to expression: `('0'..'9')`. Normal rule." "" "```pest" "CharRange       =  { '0'..'9' }" "```" , super :: super :: Rule , super :: super :: Rule :: r#CharRange , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: CharRange :: < '0' , '9' > , { super :: super :: Rule :: r#CharRange as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#CharRange<'i, INHERITED> {}
+        :: pest_typed :: rule ! (r#Choice , "Corresponds to expression: `(\"a\" | ((^\"b\" ~ ^\"b\"*) ~ RepAtLeastOnce) | (&\"c\" ~ Choice ~ Rep ~ Opt) | Peek | PeekLeft | PeekRight | PeekLeftRight | Drop | PeekAll)`. Normal rule." "" "```pest" "Choice          =  { \"a\" | ^\"b\"+ ~ RepAtLeastOnce | &\"c\" ~ Choice ~ Rep ~ Opt | Peek | PeekLeft | PeekRight | PeekLeftRight | Drop | PeekAll }" "```" "" "Accessers:" "" "- `Choice()`: `Option<&Choice>`" "- `Drop()`: `Option<&Drop>`" "- `Opt()`: `Option<&Opt>`" "- `Peek()`: `Option<&Peek>`" "- `PeekAll()`: `Option<&PeekAll>`" "- `PeekLeft()`: `Option<&PeekLeft>`" "- `PeekLeftRight()`: `Option<&PeekLeftRight>`" "- `PeekRight()`: `Option<&PeekRight>`" "- `Rep()`: `Option<&Rep>`" "- `RepAtLeastOnce()`: `Option<&RepAtLeastOnce>`" , super :: super :: Rule , super :: super :: Rule :: r#Choice , super :: super :: generics :: Choice9 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_12 > , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_5 > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_7 > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Choice :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Rep :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Opt :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , super :: super :: rules :: r#Peek :: < 'i , INHERITED > , super :: super :: rules :: r#PeekLeft :: < 'i , INHERITED > , super :: super :: rules :: r#PeekRight :: < 'i , INHERITED > , super :: super :: rules :: r#PeekLeftRight :: < 'i , INHERITED > , super :: super :: rules :: r#Drop :: < 'i , INHERITED > , super :: super :: rules :: r#PeekAll :: < 'i , INHERITED > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true , min_len = 0);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Choice<'i, INHERITED> {
+            #[doc = "A helper function to access [`Choice`]."]
             #[allow(non_snake_case)]
-            pub fn r#ANY<'s>(
+            pub fn r#Choice<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#ANY> {
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Choice<'i, INHERITED>>
+            {
                 let res = &*self.content;
                 {
-                    let res = &res.content.1.matched;
-                    {
-                        let res = res
-                            .content
-                            .iter()
-                            .map(|res| {
-                                let res = &res.matched;
-                                {
-                                    let res = &res.content.1.matched;
-                                    res
-                                }
-                            })
-                            .collect::<::pest_typed::re_exported::Vec<_>>();
+                    let res = res._2().map(|res| {
+                        let res = &res.content.1.matched;
                         res
-                    }
+                    });
+                    res
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#Tag , "Corresponds to expression: `(Any ~ (PUSH(CompoundAtomic) ~ Any ~ &Silent ~ !Atomic)? ~ Any ~ (NonAtomic* ~ Regular ~ Regular*)?)`. Normal rule." "" "Tagged rule." , super :: super :: Rule , super :: super :: Rule :: r#Tag , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < super :: super :: rules :: r#CompoundAtomic :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: rules :: r#Silent :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Atomic :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#NonAtomic :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Regular :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#Regular :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Tag<'i, INHERITED> {
-            #[doc = "A helper function to access [`Any`]."]
+            #[doc = "A helper function to access spans of [`Choice`]."]
             #[allow(non_snake_case)]
-            pub fn r#Any<'s>(
+            pub fn Choice_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#Choice().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`Drop`]."]
+            #[allow(non_snake_case)]
+            pub fn r#Drop<'s>(
                 &'s self,
-            ) -> (
-                &'s super::super::rules::r#Any<'i, INHERITED>,
-                ::pest_typed::re_exported::Option<&'s super::super::rules::r#Any<'i, INHERITED>>,
-                &'s super::super::rules::r#Any<'i, INHERITED>,
-            ) {
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Drop<'i, INHERITED>>
+            {
                 let res = &*self.content;
                 {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res.as_ref().map(|res| {
-                                    let res = &res.content.1.matched;
-                                    res
-                                });
-                                res
-                            }
-                        },
-                        {
-                            let res = &res.content.2.matched;
-                            res
-                        },
-                    );
+                    let res = res._7().map(|res| res);
                     res
                 }
             }
-            #[doc = "A helper function to access [`CompoundAtomic`]."]
+            #[doc = "A helper function to access spans of [`Drop`]."]
             #[allow(non_snake_case)]
-            pub fn r#CompoundAtomic<'s>(
+            pub fn Drop_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#Drop().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`Opt`]."]
+            #[allow(non_snake_case)]
+            pub fn r#Opt<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Option<
-                &'s super::super::rules::r#CompoundAtomic<'i, INHERITED>,
-            > {
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Opt<'i, INHERITED>>
+            {
                 let res = &*self.content;
                 {
-                    let res = &res.content.1.matched;
-                    {
-                        let res = res.as_ref().map(|res| {
-                            let res = &res.content.0.matched;
-                            {
-                                let res = &res.content;
-                                res
-                            }
-                        });
+                    let res = res._2().map(|res| {
+                        let res = &res.content.3.matched;
                         res
-                    }
+                    });
+                    res
                 }
             }
-            #[doc = "A helper function to access [`NonAtomic`]."]
+            #[doc = "A helper function to access spans of [`Opt`]."]
             #[allow(non_snake_case)]
-            pub fn r#NonAtomic<'s>(
+            pub fn Opt_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#Opt().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`Peek`]."]
+            #[allow(non_snake_case)]
+            pub fn r#Peek<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Option<
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NonAtomic<'i, INHERITED>>,
-            > {
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Peek<'i, INHERITED>>
+            {
                 let res = &*self.content;
                 {
-                    let res = &res.content.3.matched;
-                    {
-                        let res = res.as_ref().map(|res| {
-                            let res = &res.content.0.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
-                        });
-                        res
-                    }
+                    let res = res._3().map(|res| res);
+                    res
                 }
             }
-            #[doc = "A helper function to access [`Regular`]."]
+            #[doc = "A helper function to access spans of [`Peek`]."]
             #[allow(non_snake_case)]
-            pub fn r#Regular<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<(
-                &'s super::super::rules::r#Regular<'i, INHERITED>,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#Regular<'i, INHERITED>>,
-            )> {
+            pub fn Peek_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#Peek().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`PeekAll`]."]
+            #[allow(non_snake_case)]
+            pub fn r#PeekAll<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#PeekAll<'i, INHERITED>>
+            {
                 let res = &*self.content;
                 {
-                    let res = &res.content.3.matched;
-                    {
-                        let res = res.as_ref().map(|res| {
-                            let res = (
-                                {
-                                    let res = &res.content.1.matched;
-                                    res
-                                },
-                                {
-                                    let res = &res.content.2.matched;
-                                    {
-                                        let res = res
-                                            .content
-                                            .iter()
-                                            .map(|res| {
-                                                let res = &res.matched;
-                                                res
-                                            })
-                                            .collect::<::pest_typed::re_exported::Vec<_>>();
-                                        res
-                                    }
-                                },
-                            );
-                            res
-                        });
-                        res
-                    }
+                    let res = res._8().map(|res| res);
+                    res
                 }
             }
-            #[doc = "A helper function to access [`Silent`]."]
+            #[doc = "A helper function to access spans of [`PeekAll`]."]
             #[allow(non_snake_case)]
-            pub fn r#Silent<'s>(
+            pub fn PeekAll_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#PeekAll().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`PeekLeft`]."]
+            #[allow(non_snake_case)]
+            pub fn r#PeekLeft<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Silent<'i, INHERITED>>
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#PeekLeft<'i, INHERITED>>
             {
                 let res = &*self.content;
                 {
-                    let res = &res.content.1.matched;
-                    {
-                        let res = res.as_ref().map(|res| {
-                            let res = &res.content.2.matched;
-                            {
-                                let res = &res.content;
-                                res
-                            }
-                        });
+                    let res = res._4().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`PeekLeft`]."]
+            #[allow(non_snake_case)]
+            pub fn PeekLeft_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#PeekLeft()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`PeekLeftRight`]."]
+            #[allow(non_snake_case)]
+            pub fn r#PeekLeftRight<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<
+                &'s super::super::rules::r#PeekLeftRight<'i, INHERITED>,
+            > {
+                let res = &*self.content;
+                {
+                    let res = res._6().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`PeekLeftRight`]."]
+            #[allow(non_snake_case)]
+            pub fn PeekLeftRight_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#PeekLeftRight()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`PeekRight`]."]
+            #[allow(non_snake_case)]
+            pub fn r#PeekRight<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<
+                &'s super::super::rules::r#PeekRight<'i, INHERITED>,
+            > {
+                let res = &*self.content;
+                {
+                    let res = res._5().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`PeekRight`]."]
+            #[allow(non_snake_case)]
+            pub fn PeekRight_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#PeekRight()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`Rep`]."]
+            #[allow(non_snake_case)]
+            pub fn r#Rep<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Rep<'i, INHERITED>>
+            {
+                let res = &*self.content;
+                {
+                    let res = res._2().map(|res| {
+                        let res = &res.content.2.matched;
                         res
-                    }
+                    });
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`Rep`]."]
+            #[allow(non_snake_case)]
+            pub fn Rep_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#Rep().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`RepAtLeastOnce`]."]
+            #[allow(non_snake_case)]
+            pub fn r#RepAtLeastOnce<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<
+                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
+            > {
+                let res = &*self.content;
+                {
+                    let res = res._1().map(|res| {
+                        let res = &res.content.1.matched;
+                        res
+                    });
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`RepAtLeastOnce`]."]
+            #[allow(non_snake_case)]
+            pub fn RepAtLeastOnce_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#RepAtLeastOnce()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+        }
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Choice<'i, INHERITED> {
+            #[doc = r" Create the node from its content and span, without parsing."]
+            #[allow(clippy::self_named_constructors)]
+            pub fn r#new(
+                content: super::super::generics::Choice9<
+                    super::super::generics::Str<super::super::constant_wrappers::r#w_12>,
+                    super::super::generics::Seq2<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Insens<
+                                        'i,
+                                        super::super::constant_wrappers::r#w_5,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::generics::Insens<
+                                            'i,
+                                            super::super::constant_wrappers::r#w_5,
+                                        >,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                    super::super::generics::Seq4<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Positive<
+                                super::super::generics::Str<super::super::constant_wrappers::r#w_7>,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Choice<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Rep<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Opt<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                    super::super::rules::r#Peek<'i, INHERITED>,
+                    super::super::rules::r#PeekLeft<'i, INHERITED>,
+                    super::super::rules::r#PeekRight<'i, INHERITED>,
+                    super::super::rules::r#PeekLeftRight<'i, INHERITED>,
+                    super::super::rules::r#Drop<'i, INHERITED>,
+                    super::super::rules::r#PeekAll<'i, INHERITED>,
+                >,
+                span: ::pest_typed::Span<'i>,
+            ) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    span,
                 }
             }
         }
-        :: pest_typed :: rule ! (r#NonAtomic , "Corresponds to expression: `((CaseInsensitive? ~ (CharRange ~ CharRange*) ~ Tag*)? ~ (\"b\" | (\"BB\" ~ \"b\"?))? ~ ^\"c\"* ~ (!Seq ~ Any) ~ (!Seq ~ Any)*)`. Non-atomic rule." "" "Non-atomic rule." , super :: super :: Rule , super :: super :: Rule :: r#NonAtomic , super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#CaseInsensitive :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: rules :: r#CharRange :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 >) , > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: rules :: r#Tag :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 >) , > > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_6 > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_7 > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > > , super :: super :: generics :: Skipped < 'i > , 1 >) , > , > > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_9 > > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Seq :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 >) , > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Seq :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 >) , > > , super :: super :: generics :: Skipped < 'i > , 1 >) , > , super :: super :: generics :: Skipped :: < 'i > , false , Both , true);
+        impl<'i, const INHERITED: ::core::primitive::usize>
+            ::core::convert::From<
+                super::super::generics::Choice9<
+                    super::super::generics::Str<super::super::constant_wrappers::r#w_12>,
+                    super::super::generics::Seq2<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Insens<
+                                        'i,
+                                        super::super::constant_wrappers::r#w_5,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::generics::Insens<
+                                            'i,
+                                            super::super::constant_wrappers::r#w_5,
+                                        >,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                    super::super::generics::Seq4<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Positive<
+                                super::super::generics::Str<super::super::constant_wrappers::r#w_7>,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Choice<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Rep<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Opt<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                    super::super::rules::r#Peek<'i, INHERITED>,
+                    super::super::rules::r#PeekLeft<'i, INHERITED>,
+                    super::super::rules::r#PeekRight<'i, INHERITED>,
+                    super::super::rules::r#PeekLeftRight<'i, INHERITED>,
+                    super::super::rules::r#Drop<'i, INHERITED>,
+                    super::super::rules::r#PeekAll<'i, INHERITED>,
+                >,
+            > for r#Choice<'i, INHERITED>
+        {
+            #[doc = r" Create the node from its content with an empty span."]
+            fn from(
+                content: super::super::generics::Choice9<
+                    super::super::generics::Str<super::super::constant_wrappers::r#w_12>,
+                    super::super::generics::Seq2<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Insens<
+                                        'i,
+                                        super::super::constant_wrappers::r#w_5,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::generics::Insens<
+                                            'i,
+                                            super::super::constant_wrappers::r#w_5,
+                                        >,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                    super::super::generics::Seq4<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Positive<
+                                super::super::generics::Str<super::super::constant_wrappers::r#w_7>,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Choice<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Rep<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Opt<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                    super::super::rules::r#Peek<'i, INHERITED>,
+                    super::super::rules::r#PeekLeft<'i, INHERITED>,
+                    super::super::rules::r#PeekRight<'i, INHERITED>,
+                    super::super::rules::r#PeekLeftRight<'i, INHERITED>,
+                    super::super::rules::r#Drop<'i, INHERITED>,
+                    super::super::rules::r#PeekAll<'i, INHERITED>,
+                >,
+            ) -> Self {
+                Self::r#new(content, ::core::default::Default::default())
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#CompoundAtomic , "Corresponds to expression: `(\"\\\"\" ~ (!\"\\\"\" ~ ANY)* ~ \"\\\"\")`. Atomic rule." "" "Compound atomic rule." "" "```pest" "CompoundAtomic = ${ \"\\\"\" ~ (!\"\\\"\" ~ ANY)* ~ \"\\\"\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#CompoundAtomic , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 0 , super :: super :: generics :: CharExcept :: < super :: super :: constant_wrappers :: r#w_4 > > , super :: super :: generics :: Skipped < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_3 > , super :: super :: generics :: Skipped < 'i > , 0 > , > , { super :: super :: Rule :: r#CompoundAtomic as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#CompoundAtomic<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#Drop , "Corresponds to expression: `DROP`. Normal rule." "" "```pest" "Drop = { DROP }" "```" "" "Accessers:" "" "- `DROP()`: `&DROP`" , super :: super :: Rule , super :: super :: Rule :: r#Drop , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: rules :: r#DROP , { super :: super :: Rule :: r#Drop as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Drop<'i, INHERITED> {
+            #[doc = "A helper function to access [`DROP`]."]
+            #[allow(non_snake_case)]
+            pub fn r#DROP<'s>(&'s self) -> &'s super::super::rules::r#DROP {
+                let res = &self.content;
+                res
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#ExactString , "Corresponds to expression: `\"r#\"`. Normal rule." "" "```pest" "ExactString     =  { \"r#\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#ExactString , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_8 > , { super :: super :: Rule :: r#ExactString as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ExactString<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#Neg , "Corresponds to expression: `!(EOI ~ Pos)`. Normal rule." "" "```pest" "Neg = { !(EOI ~ Pos) }" "```" , super :: super :: Rule , super :: super :: Rule :: r#Neg , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Negative :: < super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pos :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > > , { super :: super :: Rule :: r#Neg as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Neg<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#NonAtomic , "Corresponds to expression: `((CaseInsensitive? ~ (CharRange ~ CharRange*) ~ Tag*)? ~ (\"b\" | (\"BB\" ~ \"b\"?))? ~ ^\"c\"* ~ (!Seq ~ Any) ~ (!Seq ~ Any)*)`. Non-atomic rule." "" "Non-atomic rule." "" "```pest" "NonAtomic       = !{ (CaseInsensitive? ~ CharRange+ ~ Tag*)? ~ (\"b\" | \"BB\" ~ \"b\"?)? ~ ^\"c\"* ~ (!Seq ~ Any)+ }" "```" "" "Accessers:" "" "- `Any()`: `(&Any, Vec<&Any>)`" "- `CaseInsensitive()`: `Option<&CaseInsensitive>`" "- `CharRange()`: `Option<(&CharRange, Vec<&CharRange>)>`" "- `Tag()`: `Option<Vec<&Tag>>`" , super :: super :: Rule , super :: super :: Rule :: r#NonAtomic , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq5 :: < :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#CaseInsensitive :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: rules :: r#CharRange :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 > , > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: rules :: r#Tag :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 > , > > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_6 > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Presence :: < super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: Skipped < 'i > , 1 > , > , > > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_7 > > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Seq :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 > , > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , 1 , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Seq :: < 'i , 1 > > , super :: super :: generics :: Skipped < 'i > , 1 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , 1 > , super :: super :: generics :: Skipped < 'i > , 1 > , > > , super :: super :: generics :: Skipped < 'i > , 1 > , > , { super :: super :: Rule :: r#NonAtomic as :: core :: primitive :: usize } , INHERITED > , false , Both , min_len = 1 , counts_recursion = false);
         impl<'i, const INHERITED: ::core::primitive::usize> r#NonAtomic<'i, INHERITED> {
             #[doc = "A helper function to access [`Any`]."]
             #[allow(non_snake_case)]
@@ -447,7 +896,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#Any<'i, 1>,
                 ::pest_typed::re_exported::Vec<&'s super::super::rules::r#Any<'i, 1>>,
             ) {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = (
                         {
@@ -464,11 +913,8 @@ pub mod rules_impl {
                                     .content
                                     .iter()
                                     .map(|res| {
-                                        let res = &res.matched;
-                                        {
-                                            let res = &res.content.1.matched;
-                                            res
-                                        }
+                                        let res = &res.content.1.matched;
+                                        res
                                     })
                                     .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
@@ -484,7 +930,7 @@ pub mod rules_impl {
                 &'s self,
             ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#CaseInsensitive<'i, 1>>
             {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = &res.content.0.matched;
                     {
@@ -502,6 +948,19 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "Whether the optional [`CaseInsensitive`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_CaseInsensitive(&self) -> ::core::primitive::bool {
+                self.r#CaseInsensitive().is_some()
+            }
+            #[doc = "A helper function to access spans of [`CaseInsensitive`]."]
+            #[allow(non_snake_case)]
+            pub fn CaseInsensitive_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#CaseInsensitive()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
             #[doc = "A helper function to access [`CharRange`]."]
             #[allow(non_snake_case)]
             pub fn r#CharRange<'s>(
@@ -510,7 +969,7 @@ pub mod rules_impl {
                 &'s super::super::rules::r#CharRange<'i, 1>,
                 ::pest_typed::re_exported::Vec<&'s super::super::rules::r#CharRange<'i, 1>>,
             )> {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = &res.content.0.matched;
                     {
@@ -525,14 +984,11 @@ pub mod rules_impl {
                                     {
                                         let res = &res.content.1.matched;
                                         {
-                                            let res = res
-                                                .content
-                                                .iter()
-                                                .map(|res| {
-                                                    let res = &res.matched;
-                                                    res
-                                                })
-                                                .collect::<::pest_typed::re_exported::Vec<_>>();
+                                            let res =
+                                                res.content
+                                                    .iter()
+                                                    .map(|res| res)
+                                                    .collect::<::pest_typed::re_exported::Vec<_>>();
                                             res
                                         }
                                     },
@@ -544,6 +1000,29 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "Whether the optional [`CharRange`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_CharRange(&self) -> ::core::primitive::bool {
+                self.r#CharRange().is_some()
+            }
+            #[doc = "A helper function to access spans of [`CharRange`]."]
+            #[allow(non_snake_case)]
+            pub fn CharRange_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<(
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            )> {
+                self.r#CharRange().map(|res| {
+                    let (res0, res1) = res;
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        res1.into_iter()
+                            .map(|res| ::pest_typed::Spanned::span(res))
+                            .collect::<::pest_typed::re_exported::Vec<_>>(),
+                    )
+                })
+            }
             #[doc = "A helper function to access [`Tag`]."]
             #[allow(non_snake_case)]
             pub fn r#Tag<'s>(
@@ -551,7 +1030,7 @@ pub mod rules_impl {
             ) -> ::pest_typed::re_exported::Option<
                 ::pest_typed::re_exported::Vec<&'s super::super::rules::r#Tag<'i, 1>>,
             > {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = &res.content.0.matched;
                     {
@@ -561,10 +1040,7 @@ pub mod rules_impl {
                                 let res = res
                                     .content
                                     .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
+                                    .map(|res| res)
                                     .collect::<::pest_typed::re_exported::Vec<_>>();
                                 res
                             }
@@ -573,319 +1049,85 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "Whether the optional [`Tag`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_Tag(&self) -> ::core::primitive::bool {
+                self.r#Tag().is_some()
+            }
+            #[doc = "A helper function to access spans of [`Tag`]."]
+            #[allow(non_snake_case)]
+            pub fn Tag_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            > {
+                self.r#Tag().map(|res| {
+                    res.into_iter()
+                        .map(|res| ::pest_typed::Spanned::span(res))
+                        .collect::<::pest_typed::re_exported::Vec<_>>()
+                })
+            }
         }
-        :: pest_typed :: rule ! (r#ExactString , "Corresponds to expression: `\"r#\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#ExactString , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#ExactString<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#CaseInsensitive , "Corresponds to expression: `^\"0x\"`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#CaseInsensitive , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#CaseInsensitive<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#CharRange , "Corresponds to expression: `('0'..'9')`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#CharRange , super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#CharRange<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#Any , "Corresponds to expression: `ANY`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Any , super :: super :: rules :: r#ANY , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Expression , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Any<'i, INHERITED> {
-            #[doc = "A helper function to access [`ANY`]."]
+        :: pest_typed :: rule_instance ! (r#Opt , "Corresponds to expression: `\"?\"?`. Normal rule." "" "```pest" "Opt             =  { \"?\"? }" "```" , super :: super :: Rule , super :: super :: Rule :: r#Opt , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Presence :: < super :: super :: constant_wrappers :: r#w_13 > , { super :: super :: Rule :: r#Opt as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Opt<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#Peek , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" "```pest" "Peek          = { PEEK[..] }" "```" , super :: super :: Rule , super :: super :: Rule :: r#Peek , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: PeekSlice1 :: < 'i , 0i32 > , { super :: super :: Rule :: r#Peek as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Peek<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#PeekAll , "Corresponds to expression: `PEEK_ALL`. Normal rule." "" "```pest" "PeekAll = { PEEK_ALL }" "```" "" "Accessers:" "" "- `PEEK_ALL()`: `&PEEK_ALL`" , super :: super :: Rule , super :: super :: Rule :: r#PeekAll , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: rules :: r#PEEK_ALL :: < 'i > , { super :: super :: Rule :: r#PeekAll as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekAll<'i, INHERITED> {
+            #[doc = "A helper function to access [`PEEK_ALL`]."]
             #[allow(non_snake_case)]
-            pub fn r#ANY<'s>(&'s self) -> &'s super::super::rules::r#ANY {
-                let res = &*self.content;
+            pub fn r#PEEK_ALL<'s>(&'s self) -> &'s super::super::rules::r#PEEK_ALL<'i> {
+                let res = &self.content;
                 res
             }
         }
-        :: pest_typed :: rule ! (r#Seq , "Corresponds to expression: `(\"1\" ~ ('2'..'9') ~ \".\")`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Seq , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_12 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '2' , '9' > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_13 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Seq<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#Choice , "Corresponds to expression: `(\"a\" | ((^\"b\" ~ ^\"b\"*) ~ RepAtLeastOnce) | (&\"c\" ~ Choice ~ Rep ~ Opt) | Peek | PeekLeft | PeekRight | PeekLeftRight | Drop | PeekAll)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Choice , super :: super :: generics :: Choice9 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_14 > , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_15 > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_16 > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Seq4 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_17 > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Choice :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Rep :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Opt :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: rules :: r#Peek :: < 'i , INHERITED > , super :: super :: rules :: r#PeekLeft :: < 'i , INHERITED > , super :: super :: rules :: r#PeekRight :: < 'i , INHERITED > , super :: super :: rules :: r#PeekLeftRight :: < 'i , INHERITED > , super :: super :: rules :: r#Drop :: < 'i , INHERITED > , super :: super :: rules :: r#PeekAll :: < 'i , INHERITED > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Choice<'i, INHERITED> {
-            #[doc = "A helper function to access [`Choice`]."]
+        :: pest_typed :: rule_instance ! (r#PeekLeft , "Corresponds to expression: `PEEK[1..]`. Normal rule." "" "```pest" "PeekLeft      = { PEEK[1..] }" "```" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeft , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: PeekSlice1 :: < 'i , 1i32 > , { super :: super :: Rule :: r#PeekLeft as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekLeft<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#PeekLeftRight , "Corresponds to expression: `PEEK[1..2]`. Normal rule." "" "```pest" "PeekLeftRight = { PEEK[1..2] }" "```" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeftRight , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: PeekSlice2 :: < 'i , 1i32 , 2i32 > , { super :: super :: Rule :: r#PeekLeftRight as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekLeftRight<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#PeekRight , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" "```pest" "PeekRight     = { PEEK[..] }" "```" , super :: super :: Rule , super :: super :: Rule :: r#PeekRight , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: PeekSlice1 :: < 'i , 0i32 > , { super :: super :: Rule :: r#PeekRight as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekRight<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#Pop , "Corresponds to expression: `POP`. Normal rule." "" "```pest" "Pop    = { POP }" "```" "" "Accessers:" "" "- `POP()`: `&POP`" "- `pop()`: `Span`" , super :: super :: Rule , super :: super :: Rule :: r#Pop , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: rules :: r#POP :: < 'i > , { super :: super :: Rule :: r#Pop as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Pop<'i, INHERITED> {
+            #[doc = "A helper function to access [`POP`]."]
             #[allow(non_snake_case)]
-            pub fn r#Choice<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Choice<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._2().map(|res| {
-                        let res = &res.content.1.matched;
-                        res
-                    });
-                    res
-                }
+            pub fn r#POP<'s>(&'s self) -> &'s super::super::rules::r#POP<'i> {
+                let res = &self.content;
+                res
             }
-            #[doc = "A helper function to access [`Drop`]."]
+            #[doc = "A helper function to access spans matched by `POP`."]
             #[allow(non_snake_case)]
-            pub fn r#Drop<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Drop<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._7().map(|res| res);
-                    res
-                }
+            pub fn r#pop<'s>(&'s self) -> ::pest_typed::Span<'i> {
+                let res = &self.content;
+                res.span
             }
-            #[doc = "A helper function to access [`Opt`]."]
+        }
+        :: pest_typed :: rule_instance ! (r#PopAll , "Corresponds to expression: `POP_ALL`. Normal rule." "" "```pest" "PopAll = { POP_ALL }" "```" "" "Accessers:" "" "- `POP_ALL()`: `&POP_ALL`" , super :: super :: Rule , super :: super :: Rule :: r#PopAll , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: rules :: r#POP_ALL :: < 'i > , { super :: super :: Rule :: r#PopAll as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#PopAll<'i, INHERITED> {
+            #[doc = "A helper function to access [`POP_ALL`]."]
             #[allow(non_snake_case)]
-            pub fn r#Opt<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Opt<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._2().map(|res| {
-                        let res = &res.content.3.matched;
-                        res
-                    });
-                    res
-                }
+            pub fn r#POP_ALL<'s>(&'s self) -> &'s super::super::rules::r#POP_ALL<'i> {
+                let res = &self.content;
+                res
             }
-            #[doc = "A helper function to access [`Peek`]."]
+        }
+        :: pest_typed :: rule_instance ! (r#Pos , "Corresponds to expression: `&(SOI ~ RepLeftRight ~ RepLeftRight ~ RepLeftRight? ~ RepLeftRight?)`. Normal rule." "" "```pest" "Pos = { &(SOI ~ RepLeftRight{2, 4}) }" "```" "" "Accessers:" "" "- `RepLeftRight()`: `(&RepLeftRight, &RepLeftRight, Option<&RepLeftRight>, Option<&RepLeftRight>)`" "- `SOI()`: `&SOI`" , super :: super :: Rule , super :: super :: Rule :: r#Pos , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Positive :: < super :: super :: generics :: Seq5 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > > , { super :: super :: Rule :: r#Pos as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Pos<'i, INHERITED> {
+            #[doc = "A helper function to access [`RepLeftRight`]."]
             #[allow(non_snake_case)]
-            pub fn r#Peek<'s>(
+            pub fn r#RepLeftRight<'s>(
                 &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Peek<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._3().map(|res| res);
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`PeekAll`]."]
-            #[allow(non_snake_case)]
-            pub fn r#PeekAll<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#PeekAll<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._8().map(|res| res);
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`PeekLeft`]."]
-            #[allow(non_snake_case)]
-            pub fn r#PeekLeft<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#PeekLeft<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._4().map(|res| res);
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`PeekLeftRight`]."]
-            #[allow(non_snake_case)]
-            pub fn r#PeekLeftRight<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<
-                &'s super::super::rules::r#PeekLeftRight<'i, INHERITED>,
-            > {
-                let res = &*self.content;
-                {
-                    let res = res._6().map(|res| res);
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`PeekRight`]."]
-            #[allow(non_snake_case)]
-            pub fn r#PeekRight<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<
-                &'s super::super::rules::r#PeekRight<'i, INHERITED>,
-            > {
-                let res = &*self.content;
-                {
-                    let res = res._5().map(|res| res);
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`Rep`]."]
-            #[allow(non_snake_case)]
-            pub fn r#Rep<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Rep<'i, INHERITED>>
-            {
-                let res = &*self.content;
-                {
-                    let res = res._2().map(|res| {
-                        let res = &res.content.2.matched;
-                        res
-                    });
-                    res
-                }
-            }
-            #[doc = "A helper function to access [`RepAtLeastOnce`]."]
-            #[allow(non_snake_case)]
-            pub fn r#RepAtLeastOnce<'s>(
-                &'s self,
-            ) -> ::pest_typed::re_exported::Option<
-                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
-            > {
-                let res = &*self.content;
-                {
-                    let res = res._1().map(|res| {
-                        let res = &res.content.1.matched;
-                        res
-                    });
-                    res
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#Rep , "Corresponds to expression: `^\"b\"*`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Rep , super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_18 > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Rep<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#RepAtLeastOnce , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: CharRange :: < '0' , '9' > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#RepAtLeastOnce<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#Opt , "Corresponds to expression: `\"?\"?`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Opt , :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_19 > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Opt<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#RepExact , "Corresponds to expression: `(RepAtLeastOnce ~ RepAtLeastOnce ~ RepAtLeastOnce)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepExact , super :: super :: generics :: Seq3 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#RepExact<'i, INHERITED> {
-            #[doc = "A helper function to access [`RepAtLeastOnce`]."]
-            #[allow(non_snake_case)]
-            pub fn r#RepAtLeastOnce<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
-                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
-                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.2.matched;
-                            res
-                        },
-                    );
-                    res
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#RepLeft , "Corresponds to expression: `(RepExact ~ RepExact*)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepLeft , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepExact :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#RepExact :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#RepLeft<'i, INHERITED> {
-            #[doc = "A helper function to access [`RepExact`]."]
-            #[allow(non_snake_case)]
-            pub fn r#RepExact<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#RepExact<'i, INHERITED>,
-                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#RepExact<'i, INHERITED>>,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res
-                                    .content
-                                    .iter()
-                                    .map(|res| {
-                                        let res = &res.matched;
-                                        res
-                                    })
-                                    .collect::<::pest_typed::re_exported::Vec<_>>();
-                                res
-                            }
-                        },
-                    );
-                    res
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#RepRight , "Corresponds to expression: `(RepLeft? ~ RepLeft?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepRight , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeft :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeft :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#RepRight<'i, INHERITED> {
-            #[doc = "A helper function to access [`RepLeft`]."]
-            #[allow(non_snake_case)]
-            pub fn r#RepLeft<'s>(
-                &'s self,
-            ) -> (
-                ::pest_typed::re_exported::Option<
-                    &'s super::super::rules::r#RepLeft<'i, INHERITED>,
-                >,
-                ::pest_typed::re_exported::Option<
-                    &'s super::super::rules::r#RepLeft<'i, INHERITED>,
-                >,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            {
-                                let res = res.as_ref().map(|res| res);
-                                res
-                            }
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res.as_ref().map(|res| res);
-                                res
-                            }
-                        },
-                    );
-                    res
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#RepLeftRight , "Corresponds to expression: `(RepRight ~ RepRight?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#RepLeftRight , super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepRight :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepRight :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#RepLeftRight<'i, INHERITED> {
-            #[doc = "A helper function to access [`RepRight`]."]
-            #[allow(non_snake_case)]
-            pub fn r#RepRight<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#RepRight<'i, INHERITED>,
-                ::pest_typed::re_exported::Option<
-                    &'s super::super::rules::r#RepRight<'i, INHERITED>,
-                >,
-            ) {
-                let res = &*self.content;
-                {
-                    let res = (
-                        {
-                            let res = &res.content.0.matched;
-                            res
-                        },
-                        {
-                            let res = &res.content.1.matched;
-                            {
-                                let res = res.as_ref().map(|res| res);
-                                res
-                            }
-                        },
-                    );
-                    res
-                }
-            }
-        }
-        :: pest_typed :: rule ! (r#Pos , "Corresponds to expression: `&(SOI ~ RepLeftRight ~ RepLeftRight ~ RepLeftRight? ~ RepLeftRight?)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Pos , super :: super :: generics :: Positive :: < super :: super :: generics :: Seq5 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#SOI , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeftRight :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Pos<'i, INHERITED> {
-            #[doc = "A helper function to access [`RepLeftRight`]."]
-            #[allow(non_snake_case)]
-            pub fn r#RepLeftRight<'s>(
-                &'s self,
-            ) -> (
-                &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
-                &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
-                ::pest_typed::re_exported::Option<
-                    &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
-                >,
-                ::pest_typed::re_exported::Option<
-                    &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
-                >,
-            ) {
-                let res = &*self.content;
+            ) -> (
+                &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
+                &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
+                ::pest_typed::re_exported::Option<
+                    &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
+                >,
+                ::pest_typed::re_exported::Option<
+                    &'s super::super::rules::r#RepLeftRight<'i, INHERITED>,
+                >,
+            ) {
+                let res = &self.content;
                 {
                     let res = &res.content;
                     {
@@ -917,10 +1159,30 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "A helper function to access spans of [`RepLeftRight`]."]
+            #[allow(non_snake_case)]
+            pub fn RepLeftRight_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1, res2, res3) = self.r#RepLeftRight();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                        res2.map(|res| ::pest_typed::Spanned::span(res)),
+                        res3.map(|res| ::pest_typed::Spanned::span(res)),
+                    )
+                }
+            }
             #[doc = "A helper function to access [`SOI`]."]
             #[allow(non_snake_case)]
             pub fn r#SOI<'s>(&'s self) -> &'s super::super::rules::r#SOI {
-                let res = &*self.content;
+                let res = &self.content;
                 {
                     let res = &res.content;
                     {
@@ -930,9 +1192,7 @@ pub mod rules_impl {
                 }
             }
         }
-        :: pest_typed :: rule ! (r#Neg , "Corresponds to expression: `!(EOI ~ Pos)`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Neg , super :: super :: generics :: Negative :: < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#EOI :: < 'i > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pos :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Neg<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#Push , "Corresponds to expression: `PUSH((RepLeft* ~ Neg ~ (ExactString ~ ExactString*) ~ Push ~ Pop ~ Push ~ PopAll))`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Push , super :: super :: generics :: Push :: < super :: super :: generics :: Seq7 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#RepLeft :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Neg :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ExactString :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ExactString :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pop :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , (:: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PopAll :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED >) , > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
+        :: pest_typed :: rule ! (r#Push , "Corresponds to expression: `PUSH((RepLeft* ~ Neg ~ (ExactString ~ ExactString*) ~ Push ~ Pop ~ Push ~ PopAll))`. Normal rule." "" "```pest" "Push = { PUSH(RepLeft* ~ Neg ~ ExactString+ ~ Push ~ Pop ~ Push ~ PopAll) }" "```" "" "Accessers:" "" "- `ExactString()`: `(&ExactString, Vec<&ExactString>)`" "- `Neg()`: `&Neg`" "- `Pop()`: `&Pop`" "- `PopAll()`: `&PopAll`" "- `Push()`: `(&Push, &Push)`" "- `RepLeft()`: `Vec<&RepLeft>`" "- `pushed()`: `Span`" , super :: super :: Rule , super :: super :: Rule :: r#Push , super :: super :: generics :: Push :: < 'i , super :: super :: generics :: Seq7 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#RepLeft :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Neg :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ExactString :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#ExactString :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Pop :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Push :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#PopAll :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true , min_len = 4294967294);
         impl<'i, const INHERITED: ::core::primitive::usize> r#Push<'i, INHERITED> {
             #[doc = "A helper function to access [`ExactString`]."]
             #[allow(non_snake_case)]
@@ -961,10 +1221,7 @@ pub mod rules_impl {
                                         let res = res
                                             .content
                                             .iter()
-                                            .map(|res| {
-                                                let res = &res.matched;
-                                                res
-                                            })
+                                            .map(|res| res)
                                             .collect::<::pest_typed::re_exported::Vec<_>>();
                                         res
                                     }
@@ -975,6 +1232,24 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "A helper function to access spans of [`ExactString`]."]
+            #[allow(non_snake_case)]
+            pub fn ExactString_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1) = self.r#ExactString();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        res1.into_iter()
+                            .map(|res| ::pest_typed::Spanned::span(res))
+                            .collect::<::pest_typed::re_exported::Vec<_>>(),
+                    )
+                }
+            }
             #[doc = "A helper function to access [`Neg`]."]
             #[allow(non_snake_case)]
             pub fn r#Neg<'s>(&'s self) -> &'s super::super::rules::r#Neg<'i, INHERITED> {
@@ -987,6 +1262,11 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "A helper function to access spans of [`Neg`]."]
+            #[allow(non_snake_case)]
+            pub fn Neg_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#Neg())
+            }
             #[doc = "A helper function to access [`Pop`]."]
             #[allow(non_snake_case)]
             pub fn r#Pop<'s>(&'s self) -> &'s super::super::rules::r#Pop<'i, INHERITED> {
@@ -999,6 +1279,11 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "A helper function to access spans of [`Pop`]."]
+            #[allow(non_snake_case)]
+            pub fn Pop_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#Pop())
+            }
             #[doc = "A helper function to access [`PopAll`]."]
             #[allow(non_snake_case)]
             pub fn r#PopAll<'s>(&'s self) -> &'s super::super::rules::r#PopAll<'i, INHERITED> {
@@ -1011,6 +1296,11 @@ pub mod rules_impl {
                     }
                 }
             }
+            #[doc = "A helper function to access spans of [`PopAll`]."]
+            #[allow(non_snake_case)]
+            pub fn PopAll_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#PopAll())
+            }
             #[doc = "A helper function to access [`Push`]."]
             #[allow(non_snake_case)]
             pub fn r#Push<'s>(
@@ -1037,10 +1327,21 @@ pub mod rules_impl {
                     }
                 }
             }
-            #[doc = "A helper function to access [`RepLeft`]."]
+            #[doc = "A helper function to access spans of [`Push`]."]
             #[allow(non_snake_case)]
-            pub fn r#RepLeft<'s>(
-                &'s self,
+            pub fn Push_span(&self) -> (::pest_typed::Span<'i>, ::pest_typed::Span<'i>) {
+                {
+                    let (res0, res1) = self.r#Push();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                    )
+                }
+            }
+            #[doc = "A helper function to access [`RepLeft`]."]
+            #[allow(non_snake_case)]
+            pub fn r#RepLeft<'s>(
+                &'s self,
             ) -> ::pest_typed::re_exported::Vec<&'s super::super::rules::r#RepLeft<'i, INHERITED>>
             {
                 let res = &*self.content;
@@ -1052,76 +1353,1057 @@ pub mod rules_impl {
                             let res = res
                                 .content
                                 .iter()
-                                .map(|res| {
-                                    let res = &res.matched;
-                                    res
-                                })
+                                .map(|res| res)
                                 .collect::<::pest_typed::re_exported::Vec<_>>();
                             res
                         }
                     }
                 }
             }
-        }
-        :: pest_typed :: rule ! (r#Pop , "Corresponds to expression: `POP`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Pop , super :: super :: rules :: r#POP :: < 'i > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Pop<'i, INHERITED> {
-            #[doc = "A helper function to access [`POP`]."]
+            #[doc = "A helper function to access spans of [`RepLeft`]."]
             #[allow(non_snake_case)]
-            pub fn r#POP<'s>(&'s self) -> &'s super::super::rules::r#POP<'i> {
+            pub fn RepLeft_span(&self) -> ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>> {
+                self.r#RepLeft()
+                    .into_iter()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+                    .collect::<::pest_typed::re_exported::Vec<_>>()
+            }
+            #[doc = "A helper function to access spans matched by `PUSH`."]
+            #[allow(non_snake_case)]
+            pub fn r#pushed<'s>(&'s self) -> ::pest_typed::Span<'i> {
                 let res = &*self.content;
-                res
+                res.span
             }
         }
-        :: pest_typed :: rule ! (r#PopAll , "Corresponds to expression: `POP_ALL`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PopAll , super :: super :: rules :: r#POP_ALL :: < 'i > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#PopAll<'i, INHERITED> {
-            #[doc = "A helper function to access [`POP_ALL`]."]
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Push<'i, INHERITED> {
+            #[doc = r" Create the node from its content and span, without parsing."]
+            #[allow(clippy::self_named_constructors)]
+            pub fn r#new(
+                content: super::super::generics::Push<
+                    'i,
+                    super::super::generics::Seq7<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Rep<
+                                'i,
+                                INHERITED,
+                                super::super::rules::r#RepLeft<'i, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Neg<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#ExactString<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#ExactString<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Pop<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#PopAll<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                >,
+                span: ::pest_typed::Span<'i>,
+            ) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    span,
+                }
+            }
+        }
+        impl<'i, const INHERITED: ::core::primitive::usize>
+            ::core::convert::From<
+                super::super::generics::Push<
+                    'i,
+                    super::super::generics::Seq7<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Rep<
+                                'i,
+                                INHERITED,
+                                super::super::rules::r#RepLeft<'i, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Neg<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#ExactString<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#ExactString<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Pop<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#PopAll<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                >,
+            > for r#Push<'i, INHERITED>
+        {
+            #[doc = r" Create the node from its content with an empty span."]
+            fn from(
+                content: super::super::generics::Push<
+                    'i,
+                    super::super::generics::Seq7<
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Rep<
+                                'i,
+                                INHERITED,
+                                super::super::rules::r#RepLeft<'i, INHERITED>,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Neg<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::generics::Seq2<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#ExactString<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#ExactString<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Pop<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#Push<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                        ::pest_typed::predefined_node::Skipped<
+                            super::super::rules::r#PopAll<'i, INHERITED>,
+                            super::super::generics::Skipped<'i>,
+                            INHERITED,
+                        >,
+                    >,
+                >,
+            ) -> Self {
+                Self::r#new(content, ::core::default::Default::default())
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#Regular , "Corresponds to expression: `((CharRange ~ CharRange*) ~ \"+\" ~ CharRange ~ CharRange*)`. Normal rule." "" "Regular rule." "" "```pest" "Regular = { CharRange+ ~ \"+\" ~ CharRange+ }" "```" "" "Accessers:" "" "- `CharRange()`: `((&CharRange, Vec<&CharRange>), &CharRange, Vec<&CharRange>)`" , super :: super :: Rule , super :: super :: Rule :: r#Regular , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#CharRange :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#CharRange :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#CharRange :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#Regular as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Regular<'i, INHERITED> {
+            #[doc = "A helper function to access [`CharRange`]."]
             #[allow(non_snake_case)]
-            pub fn r#POP_ALL<'s>(&'s self) -> &'s super::super::rules::r#POP_ALL<'i> {
-                let res = &*self.content;
-                res
+            pub fn r#CharRange<'s>(
+                &'s self,
+            ) -> (
+                (
+                    &'s super::super::rules::r#CharRange<'i, INHERITED>,
+                    ::pest_typed::re_exported::Vec<
+                        &'s super::super::rules::r#CharRange<'i, INHERITED>,
+                    >,
+                ),
+                &'s super::super::rules::r#CharRange<'i, INHERITED>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#CharRange<'i, INHERITED>>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            {
+                                let res = (
+                                    {
+                                        let res = &res.content.0.matched;
+                                        res
+                                    },
+                                    {
+                                        let res = &res.content.1.matched;
+                                        {
+                                            let res =
+                                                res.content
+                                                    .iter()
+                                                    .map(|res| res)
+                                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                            res
+                                        }
+                                    },
+                                );
+                                res
+                            }
+                        },
+                        {
+                            let res = &res.content.2.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.3.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`CharRange`]."]
+            #[allow(non_snake_case)]
+            pub fn CharRange_span(
+                &self,
+            ) -> (
+                (
+                    ::pest_typed::Span<'i>,
+                    ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+                ),
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1, res2) = self.r#CharRange();
+                    (
+                        {
+                            let (res0, res1) = res0;
+                            (
+                                ::pest_typed::Spanned::span(res0),
+                                res1.into_iter()
+                                    .map(|res| ::pest_typed::Spanned::span(res))
+                                    .collect::<::pest_typed::re_exported::Vec<_>>(),
+                            )
+                        },
+                        ::pest_typed::Spanned::span(res1),
+                        res2.into_iter()
+                            .map(|res| ::pest_typed::Spanned::span(res))
+                            .collect::<::pest_typed::re_exported::Vec<_>>(),
+                    )
+                }
             }
         }
-        :: pest_typed :: rule ! (r#Peek , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Peek , super :: super :: generics :: PeekSlice1 :: < 0i32 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Peek<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#PeekLeft , "Corresponds to expression: `PEEK[1..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeft , super :: super :: generics :: PeekSlice1 :: < 1i32 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekLeft<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#PeekRight , "Corresponds to expression: `PEEK[0..]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekRight , super :: super :: generics :: PeekSlice1 :: < 0i32 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekRight<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#PeekLeftRight , "Corresponds to expression: `PEEK[1..2]`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekLeftRight , super :: super :: generics :: PeekSlice2 :: < 1i32 , 2i32 > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekLeftRight<'i, INHERITED> {}
-        :: pest_typed :: rule ! (r#Drop , "Corresponds to expression: `DROP`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#Drop , super :: super :: rules :: r#DROP , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#Drop<'i, INHERITED> {
-            #[doc = "A helper function to access [`DROP`]."]
+        :: pest_typed :: rule_instance ! (r#Rep , "Corresponds to expression: `^\"b\"*`. Normal rule." "" "```pest" "Rep             =  { ^\"b\"* }" "```" , super :: super :: Rule , super :: super :: Rule :: r#Rep , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: Insens :: < 'i , super :: super :: constant_wrappers :: r#w_5 > > , { super :: super :: Rule :: r#Rep as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Rep<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#RepAtLeastOnce , "Corresponds to expression: `(('0'..'9') ~ ('0'..'9')*)`. Normal rule." "" "```pest" "RepAtLeastOnce  =  { '0'..'9'+ }" "```" , super :: super :: Rule , super :: super :: Rule :: r#RepAtLeastOnce , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '0' , '9' > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: generics :: CharRange :: < '0' , '9' > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#RepAtLeastOnce as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#RepAtLeastOnce<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#RepExact , "Corresponds to expression: `(RepAtLeastOnce ~ RepAtLeastOnce ~ RepAtLeastOnce)`. Normal rule." "" "```pest" "RepExact        =  { RepAtLeastOnce{3} }" "```" "" "Accessers:" "" "- `RepAtLeastOnce()`: `(&RepAtLeastOnce, &RepAtLeastOnce, &RepAtLeastOnce)`" , super :: super :: Rule , super :: super :: Rule :: r#RepExact , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepAtLeastOnce :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#RepExact as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#RepExact<'i, INHERITED> {
+            #[doc = "A helper function to access [`RepAtLeastOnce`]."]
             #[allow(non_snake_case)]
-            pub fn r#DROP<'s>(&'s self) -> &'s super::super::rules::r#DROP {
-                let res = &*self.content;
-                res
+            pub fn r#RepAtLeastOnce<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
+                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
+                &'s super::super::rules::r#RepAtLeastOnce<'i, INHERITED>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.2.matched;
+                            res
+                        },
+                    );
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`RepAtLeastOnce`]."]
+            #[allow(non_snake_case)]
+            pub fn RepAtLeastOnce_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+                ::pest_typed::Span<'i>,
+            ) {
+                {
+                    let (res0, res1, res2) = self.r#RepAtLeastOnce();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        ::pest_typed::Spanned::span(res1),
+                        ::pest_typed::Spanned::span(res2),
+                    )
+                }
             }
         }
-        :: pest_typed :: rule ! (r#PeekAll , "Corresponds to expression: `PEEK_ALL`. Normal rule." "" , super :: super :: Rule , super :: super :: Rule :: r#PeekAll , super :: super :: rules :: r#PEEK_ALL :: < 'i > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true);
-        impl<'i, const INHERITED: ::core::primitive::usize> r#PeekAll<'i, INHERITED> {
-            #[doc = "A helper function to access [`PEEK_ALL`]."]
+        :: pest_typed :: rule_instance ! (r#RepLeft , "Corresponds to expression: `(RepExact ~ RepExact*)`. Normal rule." "" "```pest" "RepLeft         =  { RepExact{1, } }" "```" "" "Accessers:" "" "- `RepExact()`: `(&RepExact, Vec<&RepExact>)`" , super :: super :: Rule , super :: super :: Rule :: r#RepLeft , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepExact :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#RepExact :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#RepLeft as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#RepLeft<'i, INHERITED> {
+            #[doc = "A helper function to access [`RepExact`]."]
             #[allow(non_snake_case)]
-            pub fn r#PEEK_ALL<'s>(&'s self) -> &'s super::super::rules::r#PEEK_ALL<'i> {
+            pub fn r#RepExact<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#RepExact<'i, INHERITED>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#RepExact<'i, INHERITED>>,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`RepExact`]."]
+            #[allow(non_snake_case)]
+            pub fn RepExact_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1) = self.r#RepExact();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        res1.into_iter()
+                            .map(|res| ::pest_typed::Spanned::span(res))
+                            .collect::<::pest_typed::re_exported::Vec<_>>(),
+                    )
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#RepLeftRight , "Corresponds to expression: `(RepRight ~ RepRight?)`. Normal rule." "" "```pest" "RepLeftRight    =  { RepRight{1, 2} }" "```" "" "Accessers:" "" "- `RepRight()`: `(&RepRight, Option<&RepRight>)`" , super :: super :: Rule , super :: super :: Rule :: r#RepLeftRight , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#RepRight :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepRight :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#RepLeftRight as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#RepLeftRight<'i, INHERITED> {
+            #[doc = "A helper function to access [`RepRight`]."]
+            #[allow(non_snake_case)]
+            pub fn r#RepRight<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#RepRight<'i, INHERITED>,
+                ::pest_typed::re_exported::Option<
+                    &'s super::super::rules::r#RepRight<'i, INHERITED>,
+                >,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res.as_ref().map(|res| res);
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`RepRight`]."]
+            #[allow(non_snake_case)]
+            pub fn RepRight_span(
+                &self,
+            ) -> (
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1) = self.r#RepRight();
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        res1.map(|res| ::pest_typed::Spanned::span(res)),
+                    )
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#RepRight , "Corresponds to expression: `(RepLeft? ~ RepLeft?)`. Normal rule." "" "```pest" "RepRight        =  { RepLeft{, 2} }" "```" "" "Accessers:" "" "- `RepLeft()`: `(Option<&RepLeft>, Option<&RepLeft>)`" , super :: super :: Rule , super :: super :: Rule :: r#RepRight , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeft :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: rules :: r#RepLeft :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#RepRight as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#RepRight<'i, INHERITED> {
+            #[doc = "A helper function to access [`RepLeft`]."]
+            #[allow(non_snake_case)]
+            pub fn r#RepLeft<'s>(
+                &'s self,
+            ) -> (
+                ::pest_typed::re_exported::Option<
+                    &'s super::super::rules::r#RepLeft<'i, INHERITED>,
+                >,
+                ::pest_typed::re_exported::Option<
+                    &'s super::super::rules::r#RepLeft<'i, INHERITED>,
+                >,
+            ) {
+                let res = &self.content;
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            {
+                                let res = res.as_ref().map(|res| res);
+                                res
+                            }
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res.as_ref().map(|res| res);
+                                res
+                            }
+                        },
+                    );
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`RepLeft`]."]
+            #[allow(non_snake_case)]
+            pub fn RepLeft_span(
+                &self,
+            ) -> (
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+                ::pest_typed::re_exported::Option<::pest_typed::Span<'i>>,
+            ) {
+                {
+                    let (res0, res1) = self.r#RepLeft();
+                    (
+                        res0.map(|res| ::pest_typed::Spanned::span(res)),
+                        res1.map(|res| ::pest_typed::Spanned::span(res)),
+                    )
+                }
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#Seq , "Corresponds to expression: `(\"1\" ~ ('2'..'9') ~ \".\")`. Normal rule." "" "```pest" "Seq             =  { \"1\" ~ '2'..'9' ~ \".\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#Seq , super :: super :: rules_impl :: bodies :: Both < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_10 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: CharRange :: < '2' , '9' > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_11 > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , { super :: super :: Rule :: r#Seq as :: core :: primitive :: usize } , INHERITED > , INHERITED , Both , min_len = 3 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Seq<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#Silent , "Corresponds to expression: `(\"(\" | \")\")`. Normal rule." "" "Silent rule." "" "```pest" "Silent = _{ \"(\" | \")\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#Silent , super :: super :: rules_impl :: bodies :: Expression < 'i , super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_1 > , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , > , { super :: super :: Rule :: r#Silent as :: core :: primitive :: usize } , INHERITED > , INHERITED , Expression , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Silent<'i, INHERITED> {}
+        :: pest_typed :: rule ! (r#Tag , "Corresponds to expression: `(Any ~ (PUSH(CompoundAtomic) ~ Any ~ &Silent ~ !Atomic)? ~ Any ~ (NonAtomic* ~ Regular ~ Regular*)?)`. Normal rule." "" "Tagged rule." "" "```pest" "Tag = { Any ~ (#Second = (PUSH(CompoundAtomic) ~ (#inner = Any) ~ &Silent ~ !Atomic))? ~ (#inner = Any) ~ (#Third = NonAtomic* ~ Regular+)? }" "```" "" "Accessers:" "" "- `Any()`: `(&Any, Option<&Any>, &Any)`" "- `CompoundAtomic()`: `Option<&CompoundAtomic>`" "- `NonAtomic()`: `Option<Vec<&NonAtomic>>`" "- `Regular()`: `Option<(&Regular, Vec<&Regular>)>`" "- `Silent()`: `Option<&Silent>`" "- `pushed()`: `Option<Span>`" , super :: super :: Rule , super :: super :: Rule :: r#Tag , super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq4 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Push :: < 'i , super :: super :: rules :: r#CompoundAtomic :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Positive :: < super :: super :: rules :: r#Silent :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Negative :: < super :: super :: rules :: r#Atomic :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Any :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#NonAtomic :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#Regular :: < 'i , INHERITED > , super :: super :: generics :: Skipped < 'i > , INHERITED > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Rep :: < 'i , INHERITED , super :: super :: rules :: r#Regular :: < 'i , INHERITED > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > > , super :: super :: generics :: Skipped < 'i > , INHERITED > , > , super :: super :: generics :: Skipped :: < 'i > , INHERITED , Both , true , min_len = 2);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Tag<'i, INHERITED> {
+            #[doc = "A helper function to access [`Any`]."]
+            #[allow(non_snake_case)]
+            pub fn r#Any<'s>(
+                &'s self,
+            ) -> (
+                &'s super::super::rules::r#Any<'i, INHERITED>,
+                ::pest_typed::re_exported::Option<&'s super::super::rules::r#Any<'i, INHERITED>>,
+                &'s super::super::rules::r#Any<'i, INHERITED>,
+            ) {
                 let res = &*self.content;
-                res
+                {
+                    let res = (
+                        {
+                            let res = &res.content.0.matched;
+                            res
+                        },
+                        {
+                            let res = &res.content.1.matched;
+                            {
+                                let res = res.as_ref().map(|res| {
+                                    let res = &res.content.1.matched;
+                                    res
+                                });
+                                res
+                            }
+                        },
+                        {
+                            let res = &res.content.2.matched;
+                            res
+                        },
+                    );
+                    res
+                }
+            }
+            #[doc = "A helper function to access [`CompoundAtomic`]."]
+            #[allow(non_snake_case)]
+            pub fn r#CompoundAtomic<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<
+                &'s super::super::rules::r#CompoundAtomic<'i, INHERITED>,
+            > {
+                let res = &*self.content;
+                {
+                    let res = &res.content.1.matched;
+                    {
+                        let res = res.as_ref().map(|res| {
+                            let res = &res.content.0.matched;
+                            {
+                                let res = &res.content;
+                                res
+                            }
+                        });
+                        res
+                    }
+                }
+            }
+            #[doc = "Whether the optional [`CompoundAtomic`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_CompoundAtomic(&self) -> ::core::primitive::bool {
+                self.r#CompoundAtomic().is_some()
+            }
+            #[doc = "A helper function to access spans of [`CompoundAtomic`]."]
+            #[allow(non_snake_case)]
+            pub fn CompoundAtomic_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#CompoundAtomic()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`NonAtomic`]."]
+            #[allow(non_snake_case)]
+            pub fn r#NonAtomic<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#NonAtomic<'i, INHERITED>>,
+            > {
+                let res = &*self.content;
+                {
+                    let res = &res.content.3.matched;
+                    {
+                        let res = res.as_ref().map(|res| {
+                            let res = &res.content.0.matched;
+                            {
+                                let res = res
+                                    .content
+                                    .iter()
+                                    .map(|res| res)
+                                    .collect::<::pest_typed::re_exported::Vec<_>>();
+                                res
+                            }
+                        });
+                        res
+                    }
+                }
+            }
+            #[doc = "Whether the optional [`NonAtomic`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_NonAtomic(&self) -> ::core::primitive::bool {
+                self.r#NonAtomic().is_some()
+            }
+            #[doc = "A helper function to access spans of [`NonAtomic`]."]
+            #[allow(non_snake_case)]
+            pub fn NonAtomic_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            > {
+                self.r#NonAtomic().map(|res| {
+                    res.into_iter()
+                        .map(|res| ::pest_typed::Spanned::span(res))
+                        .collect::<::pest_typed::re_exported::Vec<_>>()
+                })
+            }
+            #[doc = "A helper function to access [`Regular`]."]
+            #[allow(non_snake_case)]
+            pub fn r#Regular<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<(
+                &'s super::super::rules::r#Regular<'i, INHERITED>,
+                ::pest_typed::re_exported::Vec<&'s super::super::rules::r#Regular<'i, INHERITED>>,
+            )> {
+                let res = &*self.content;
+                {
+                    let res = &res.content.3.matched;
+                    {
+                        let res = res.as_ref().map(|res| {
+                            let res = (
+                                {
+                                    let res = &res.content.1.matched;
+                                    res
+                                },
+                                {
+                                    let res = &res.content.2.matched;
+                                    {
+                                        let res = res
+                                            .content
+                                            .iter()
+                                            .map(|res| res)
+                                            .collect::<::pest_typed::re_exported::Vec<_>>();
+                                        res
+                                    }
+                                },
+                            );
+                            res
+                        });
+                        res
+                    }
+                }
+            }
+            #[doc = "Whether the optional [`Regular`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_Regular(&self) -> ::core::primitive::bool {
+                self.r#Regular().is_some()
+            }
+            #[doc = "A helper function to access spans of [`Regular`]."]
+            #[allow(non_snake_case)]
+            pub fn Regular_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<(
+                ::pest_typed::Span<'i>,
+                ::pest_typed::re_exported::Vec<::pest_typed::Span<'i>>,
+            )> {
+                self.r#Regular().map(|res| {
+                    let (res0, res1) = res;
+                    (
+                        ::pest_typed::Spanned::span(res0),
+                        res1.into_iter()
+                            .map(|res| ::pest_typed::Spanned::span(res))
+                            .collect::<::pest_typed::re_exported::Vec<_>>(),
+                    )
+                })
+            }
+            #[doc = "A helper function to access [`Silent`]."]
+            #[allow(non_snake_case)]
+            pub fn r#Silent<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#Silent<'i, INHERITED>>
+            {
+                let res = &*self.content;
+                {
+                    let res = &res.content.1.matched;
+                    {
+                        let res = res.as_ref().map(|res| {
+                            let res = &res.content.2.matched;
+                            {
+                                let res = &res.content;
+                                res
+                            }
+                        });
+                        res
+                    }
+                }
+            }
+            #[doc = "Whether the optional [`Silent`] is present."]
+            #[allow(non_snake_case)]
+            pub fn is_present_Silent(&self) -> ::core::primitive::bool {
+                self.r#Silent().is_some()
+            }
+            #[doc = "A helper function to access spans matched by `PUSH`."]
+            #[allow(non_snake_case)]
+            pub fn r#pushed<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                let res = &*self.content;
+                {
+                    let res = &res.content.1.matched;
+                    {
+                        let res = res.as_ref().map(|res| {
+                            let res = &res.content.0.matched;
+                            res.span
+                        });
+                        res
+                    }
+                }
+            }
+        }
+        impl<'i, const INHERITED: ::core::primitive::usize> r#Tag<'i, INHERITED> {
+            #[doc = r" Create the node from its content and span, without parsing."]
+            #[allow(clippy::self_named_constructors)]
+            pub fn r#new(
+                content: super::super::generics::Seq4<
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#Any<'i, INHERITED>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        ::pest_typed::re_exported::Option<
+                            super::super::generics::Seq4<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Push<
+                                        'i,
+                                        super::super::rules::r#CompoundAtomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#Any<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Positive<
+                                        super::super::rules::r#Silent<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Negative<
+                                        super::super::rules::r#Atomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#Any<'i, INHERITED>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        ::pest_typed::re_exported::Option<
+                            super::super::generics::Seq3<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#NonAtomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#Regular<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#Regular<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                >,
+                span: ::pest_typed::Span<'i>,
+            ) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    span,
+                }
+            }
+        }
+        impl<'i, const INHERITED: ::core::primitive::usize>
+            ::core::convert::From<
+                super::super::generics::Seq4<
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#Any<'i, INHERITED>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        ::pest_typed::re_exported::Option<
+                            super::super::generics::Seq4<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Push<
+                                        'i,
+                                        super::super::rules::r#CompoundAtomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#Any<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Positive<
+                                        super::super::rules::r#Silent<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Negative<
+                                        super::super::rules::r#Atomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#Any<'i, INHERITED>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        ::pest_typed::re_exported::Option<
+                            super::super::generics::Seq3<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#NonAtomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#Regular<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#Regular<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                >,
+            > for r#Tag<'i, INHERITED>
+        {
+            #[doc = r" Create the node from its content with an empty span."]
+            fn from(
+                content: super::super::generics::Seq4<
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#Any<'i, INHERITED>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        ::pest_typed::re_exported::Option<
+                            super::super::generics::Seq4<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Push<
+                                        'i,
+                                        super::super::rules::r#CompoundAtomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#Any<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Positive<
+                                        super::super::rules::r#Silent<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Negative<
+                                        super::super::rules::r#Atomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        super::super::rules::r#Any<'i, INHERITED>,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                    ::pest_typed::predefined_node::Skipped<
+                        ::pest_typed::re_exported::Option<
+                            super::super::generics::Seq3<
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#NonAtomic<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::rules::r#Regular<'i, INHERITED>,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                                ::pest_typed::predefined_node::Skipped<
+                                    super::super::generics::Rep<
+                                        'i,
+                                        INHERITED,
+                                        super::super::rules::r#Regular<'i, INHERITED>,
+                                    >,
+                                    super::super::generics::Skipped<'i>,
+                                    INHERITED,
+                                >,
+                            >,
+                        >,
+                        super::super::generics::Skipped<'i>,
+                        INHERITED,
+                    >,
+                >,
+            ) -> Self {
+                Self::r#new(content, ::core::default::Default::default())
             }
         }
-        #[allow(unused_imports)]
-        use super::super::unicode::*;
         ::pest_typed::rule_eoi!(EOI, super::super::Rule);
-        pub use pest_typed::predefined_node::ANY;
-        pub use pest_typed::predefined_node::DROP;
-        pub use pest_typed::predefined_node::PEEK_ALL;
-        pub use pest_typed::predefined_node::POP;
-        pub use pest_typed::predefined_node::POP_ALL;
-        pub use pest_typed::predefined_node::SOI;
+        pub use ::pest_typed::predefined_node::ANY;
+        pub use ::pest_typed::predefined_node::DROP;
+        pub use ::pest_typed::predefined_node::PEEK_ALL;
+        pub use ::pest_typed::predefined_node::POP;
+        pub use ::pest_typed::predefined_node::POP_ALL;
+        pub use ::pest_typed::predefined_node::SOI;
+    }
+    #[doc = "Generic structs shared by rules in [rules], except rules whose types may contain themselves."]
+    pub mod bodies {
+        ::pest_typed::rule_body!(
+            Both,
+            "Body of non-atomic rules emitting `Both`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            false,
+            Both,
+            false,
+            r#new
+        );
+        ::pest_typed::rule_body!(
+            BothAtomic,
+            "Body of atomic rules emitting `Both`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            true,
+            Both,
+            false,
+            r#new
+        );
+        ::pest_typed::rule_body!(
+            Expression,
+            "Body of non-atomic rules emitting `Expression`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            false,
+            Expression,
+            false,
+            r#new
+        );
+        ::pest_typed::rule_body!(
+            SpanAtomic,
+            "Body of atomic rules emitting `Span`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            true,
+            Span,
+            false,
+            r#new
+        );
     }
 }
 pub use rules_impl::rules;
 #[doc = "Used generics."]
 pub mod generics {
-    use pest_typed::predefined_node;
+    use ::pest_typed::predefined_node;
     #[doc = r" Skipped content."]
     pub type Skipped<'i> = predefined_node::Empty<'i>;
     pub use pest_typed::choices::Choice2;
@@ -1132,7 +2414,8 @@ pub mod generics {
     pub use pest_typed::sequence::Seq5;
     pub use pest_typed::sequence::Seq7;
     pub use predefined_node::{
-        CharRange, Insens, Negative, PeekSlice1, PeekSlice2, Positive, Push, Skip, Str,
+        CharExcept, CharRange, Insens, InsensUnicode, Keyword, Negative, PeekSlice1, PeekSlice2,
+        Positive, Presence, Push, PushLiteral, Skip, SpanOnly, Str, StrChoice, StrSpanned,
     };
     #[doc = r" Repeat arbitrary times."]
     pub type Rep<'i, const SKIP: ::core::primitive::usize, T> =
@@ -1143,3 +2426,169 @@ pub mod generics {
 }
 #[doc = "Re-export some types from rules to simplify the usage."]
 pub use rules as pairs;
+#[doc = r" Parse the whole input with a rule selected at runtime."]
+#[doc = r""]
+#[doc = r" Silent rules have no pairs, so an error is returned for them."]
+#[allow(dead_code)]
+pub fn parse_rule<'i>(
+    rule: Rule,
+    input: &'i ::core::primitive::str,
+) -> ::pest_typed::error::ParseResult<
+    ::pest_typed::Box<dyn ::pest_typed::iterators::AnyPair<'i, Rule> + 'i>,
+    Rule,
+> {
+    fn parse<
+        'i,
+        T: ::pest_typed::ParsableTypedNode<'i, Rule> + ::pest_typed::iterators::AnyPair<'i, Rule> + 'i,
+    >(
+        input: &'i ::core::primitive::str,
+    ) -> ::pest_typed::error::ParseResult<
+        ::pest_typed::Box<dyn ::pest_typed::iterators::AnyPair<'i, Rule> + 'i>,
+        Rule,
+    > {
+        let res = T::try_parse(input)?;
+        ::core::result::Result::Ok(::pest_typed::Box::new(res))
+    }
+    match rule {
+        Rule::EOI => parse::<pairs::EOI<'i>>(input),
+        Rule::r#Regular => parse::<pairs::r#Regular<'i>>(input),
+        Rule::r#Atomic => parse::<pairs::r#Atomic<'i>>(input),
+        Rule::r#Silent => {
+            ::core::result::Result::Err(
+                ::pest_typed::Box::new(
+                    ::pest_typed::error::ParseFailure::new(
+                        <::pest_typed::error::Error<Rule> as ::pest_typed::error::CustomError<
+                            'i,
+                        >>::custom_at_pos(
+                            ::pest_typed::Position::from_start(input),
+                            "Rule `Silent` is silent, so it can't be parsed into a pair.",
+                        ),
+                        ::pest_typed::Position::from_start(input),
+                    ),
+                ),
+            )
+        }
+        Rule::r#CompoundAtomic => parse::<pairs::r#CompoundAtomic<'i>>(input),
+        Rule::r#Tag => parse::<pairs::r#Tag<'i>>(input),
+        Rule::r#NonAtomic => parse::<pairs::r#NonAtomic<'i>>(input),
+        Rule::r#ExactString => parse::<pairs::r#ExactString<'i>>(input),
+        Rule::r#CaseInsensitive => parse::<pairs::r#CaseInsensitive<'i>>(input),
+        Rule::r#CharRange => parse::<pairs::r#CharRange<'i>>(input),
+        Rule::r#Any => {
+            ::core::result::Result::Err(
+                ::pest_typed::Box::new(
+                    ::pest_typed::error::ParseFailure::new(
+                        <::pest_typed::error::Error<Rule> as ::pest_typed::error::CustomError<
+                            'i,
+                        >>::custom_at_pos(
+                            ::pest_typed::Position::from_start(input),
+                            "Rule `Any` is silent, so it can't be parsed into a pair.",
+                        ),
+                        ::pest_typed::Position::from_start(input),
+                    ),
+                ),
+            )
+        }
+        Rule::r#Seq => parse::<pairs::r#Seq<'i>>(input),
+        Rule::r#Choice => parse::<pairs::r#Choice<'i>>(input),
+        Rule::r#Rep => parse::<pairs::r#Rep<'i>>(input),
+        Rule::r#RepAtLeastOnce => parse::<pairs::r#RepAtLeastOnce<'i>>(input),
+        Rule::r#Opt => parse::<pairs::r#Opt<'i>>(input),
+        Rule::r#RepExact => parse::<pairs::r#RepExact<'i>>(input),
+        Rule::r#RepLeft => parse::<pairs::r#RepLeft<'i>>(input),
+        Rule::r#RepRight => parse::<pairs::r#RepRight<'i>>(input),
+        Rule::r#RepLeftRight => parse::<pairs::r#RepLeftRight<'i>>(input),
+        Rule::r#Pos => parse::<pairs::r#Pos<'i>>(input),
+        Rule::r#Neg => parse::<pairs::r#Neg<'i>>(input),
+        Rule::r#Push => parse::<pairs::r#Push<'i>>(input),
+        Rule::r#Pop => parse::<pairs::r#Pop<'i>>(input),
+        Rule::r#PopAll => parse::<pairs::r#PopAll<'i>>(input),
+        Rule::r#Peek => parse::<pairs::r#Peek<'i>>(input),
+        Rule::r#PeekLeft => parse::<pairs::r#PeekLeft<'i>>(input),
+        Rule::r#PeekRight => parse::<pairs::r#PeekRight<'i>>(input),
+        Rule::r#PeekLeftRight => parse::<pairs::r#PeekLeftRight<'i>>(input),
+        Rule::r#Drop => parse::<pairs::r#Drop<'i>>(input),
+        Rule::r#PeekAll => parse::<pairs::r#PeekAll<'i>>(input),
+    }
+}
+#[doc = r" Rules directly referenced by each rule, in the order they are defined."]
+#[doc = r""]
+#[doc = r" Built-in rules other than `EOI` and implicit `WHITESPACE` and `COMMENT` are not listed."]
+#[allow(dead_code)]
+pub fn rule_dependencies() -> &'static [(Rule, &'static [Rule])] {
+    &[
+        (Rule::r#Regular, &[Rule::r#CharRange]),
+        (Rule::r#Atomic, &[]),
+        (Rule::r#Silent, &[]),
+        (Rule::r#CompoundAtomic, &[]),
+        (
+            Rule::r#Tag,
+            &[
+                Rule::r#Regular,
+                Rule::r#Atomic,
+                Rule::r#Silent,
+                Rule::r#CompoundAtomic,
+                Rule::r#NonAtomic,
+                Rule::r#Any,
+            ],
+        ),
+        (
+            Rule::r#NonAtomic,
+            &[
+                Rule::r#Tag,
+                Rule::r#CaseInsensitive,
+                Rule::r#CharRange,
+                Rule::r#Any,
+                Rule::r#Seq,
+            ],
+        ),
+        (Rule::r#ExactString, &[]),
+        (Rule::r#CaseInsensitive, &[]),
+        (Rule::r#CharRange, &[]),
+        (Rule::r#Any, &[]),
+        (Rule::r#Seq, &[]),
+        (
+            Rule::r#Choice,
+            &[
+                Rule::r#Choice,
+                Rule::r#Rep,
+                Rule::r#RepAtLeastOnce,
+                Rule::r#Opt,
+                Rule::r#Peek,
+                Rule::r#PeekLeft,
+                Rule::r#PeekRight,
+                Rule::r#PeekLeftRight,
+                Rule::r#Drop,
+                Rule::r#PeekAll,
+            ],
+        ),
+        (Rule::r#Rep, &[]),
+        (Rule::r#RepAtLeastOnce, &[]),
+        (Rule::r#Opt, &[]),
+        (Rule::r#RepExact, &[Rule::r#RepAtLeastOnce]),
+        (Rule::r#RepLeft, &[Rule::r#RepExact]),
+        (Rule::r#RepRight, &[Rule::r#RepLeft]),
+        (Rule::r#RepLeftRight, &[Rule::r#RepRight]),
+        (Rule::r#Pos, &[Rule::r#RepLeftRight]),
+        (Rule::r#Neg, &[Rule::r#Pos, Rule::r#EOI]),
+        (
+            Rule::r#Push,
+            &[
+                Rule::r#ExactString,
+                Rule::r#RepLeft,
+                Rule::r#Neg,
+                Rule::r#Push,
+                Rule::r#Pop,
+                Rule::r#PopAll,
+            ],
+        ),
+        (Rule::r#Pop, &[]),
+        (Rule::r#PopAll, &[]),
+        (Rule::r#Peek, &[]),
+        (Rule::r#PeekLeft, &[]),
+        (Rule::r#PeekRight, &[]),
+        (Rule::r#PeekLeftRight, &[]),
+        (Rule::r#Drop, &[]),
+        (Rule::r#PeekAll, &[]),
+    ]
+}
@@ -0,0 +1,488 @@
+#![cfg(feature = "grammar-extras")]
+#![allow(clippy::all)]
+#[doc = ""]
+#[allow(dead_code, non_camel_case_types, clippy::upper_case_acronyms)]
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    EOI,
+    r#ident,
+    r#number,
+    r#string,
+    r#inner,
+    r#token,
+    r#quoted,
+    r#kw_if,
+    r#kw_else,
+    r#kw_while,
+    r#keyword,
+}
+#[allow(dead_code)]
+impl Rule {
+    #[doc = r" All rules, starting with `EOI` and then in the order of the grammar."]
+    pub const ALL: &'static [Rule] = &[
+        Rule::EOI,
+        Rule::r#ident,
+        Rule::r#number,
+        Rule::r#string,
+        Rule::r#inner,
+        Rule::r#token,
+        Rule::r#quoted,
+        Rule::r#kw_if,
+        Rule::r#kw_else,
+        Rule::r#kw_while,
+        Rule::r#keyword,
+    ];
+    #[doc = r" Names of all rules as in the grammar, in the same order as [`Rule::ALL`]."]
+    pub const NAMES: &'static [&'static ::core::primitive::str] = &[
+        "EOI", "ident", "number", "string", "inner", "token", "quoted", "kw_if", "kw_else",
+        "kw_while", "keyword",
+    ];
+    #[doc = r" Name of the rule as in the grammar."]
+    pub fn name(&self) -> &'static ::core::primitive::str {
+        match self {
+            Rule::EOI => "EOI",
+            Rule::r#ident => "ident",
+            Rule::r#number => "number",
+            Rule::r#string => "string",
+            Rule::r#inner => "inner",
+            Rule::r#token => "token",
+            Rule::r#quoted => "quoted",
+            Rule::r#kw_if => "kw_if",
+            Rule::r#kw_else => "kw_else",
+            Rule::r#kw_while => "kw_while",
+            Rule::r#keyword => "keyword",
+        }
+    }
+    #[doc = r" The rule named `name` in the grammar."]
+    pub fn from_name(name: &::core::primitive::str) -> ::core::option::Option<Rule> {
+        match name {
+            "EOI" => ::core::option::Option::Some(Rule::EOI),
+            "ident" => ::core::option::Option::Some(Rule::r#ident),
+            "number" => ::core::option::Option::Some(Rule::r#number),
+            "string" => ::core::option::Option::Some(Rule::r#string),
+            "inner" => ::core::option::Option::Some(Rule::r#inner),
+            "token" => ::core::option::Option::Some(Rule::r#token),
+            "quoted" => ::core::option::Option::Some(Rule::r#quoted),
+            "kw_if" => ::core::option::Option::Some(Rule::r#kw_if),
+            "kw_else" => ::core::option::Option::Some(Rule::r#kw_else),
+            "kw_while" => ::core::option::Option::Some(Rule::r#kw_while),
+            "keyword" => ::core::option::Option::Some(Rule::r#keyword),
+            _ => ::core::option::Option::None,
+        }
+    }
+    #[doc = r" Name of the rule in error messages, if it's given by `#[error_label]`."]
+    pub fn error_label(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+        match self {
+            Rule::EOI => ::core::option::Option::None,
+            Rule::r#ident => ::core::option::Option::None,
+            Rule::r#number => ::core::option::Option::None,
+            Rule::r#string => ::core::option::Option::None,
+            Rule::r#inner => ::core::option::Option::None,
+            Rule::r#token => ::core::option::Option::None,
+            Rule::r#quoted => ::core::option::Option::None,
+            Rule::r#kw_if => ::core::option::Option::None,
+            Rule::r#kw_else => ::core::option::Option::None,
+            Rule::r#kw_while => ::core::option::Option::None,
+            Rule::r#keyword => ::core::option::Option::None,
+        }
+    }
+    #[doc = r" Whether the rule is atomic (`@`) or compound-atomic (`$`), so nothing is skipped implicitly inside it."]
+    pub fn is_atomic(&self) -> ::core::primitive::bool {
+        match self {
+            Rule::EOI => false,
+            Rule::r#ident => true,
+            Rule::r#number => true,
+            Rule::r#string => true,
+            Rule::r#inner => true,
+            Rule::r#token => true,
+            Rule::r#quoted => true,
+            Rule::r#kw_if => true,
+            Rule::r#kw_else => true,
+            Rule::r#kw_while => true,
+            Rule::r#keyword => true,
+        }
+    }
+    #[doc = r" Whether the rule is silent (`_`), so it produces no pairs."]
+    pub fn is_silent(&self) -> ::core::primitive::bool {
+        match self {
+            Rule::EOI => false,
+            Rule::r#ident => false,
+            Rule::r#number => false,
+            Rule::r#string => false,
+            Rule::r#inner => false,
+            Rule::r#token => false,
+            Rule::r#quoted => false,
+            Rule::r#kw_if => false,
+            Rule::r#kw_else => false,
+            Rule::r#kw_while => false,
+            Rule::r#keyword => false,
+        }
+    }
+    #[doc = r" Doc comment of the rule in the grammar, with lines joined by `\n`."]
+    pub fn doc(&self) -> ::core::option::Option<&'static ::core::primitive::str> {
+        match self {
+            Rule::EOI => ::core::option::Option::None,
+            Rule::r#ident => ::core::option::Option::None,
+            Rule::r#number => ::core::option::Option::None,
+            Rule::r#string => ::core::option::Option::None,
+            Rule::r#inner => ::core::option::Option::None,
+            Rule::r#token => ::core::option::Option::None,
+            Rule::r#quoted => ::core::option::Option::None,
+            Rule::r#kw_if => ::core::option::Option::None,
+            Rule::r#kw_else => ::core::option::Option::None,
+            Rule::r#kw_while => ::core::option::Option::None,
+            Rule::r#keyword => ::core::option::Option::None,
+        }
+    }
+}
+mod constant_wrappers {
+    #[doc = "A wrapper for `\"_\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_0;
+    impl ::pest_typed::StringWrapper for r#w_0 {
+        const CONTENT: &'static ::core::primitive::str = "_";
+    }
+    #[doc = "A wrapper for `\".\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_1;
+    impl ::pest_typed::StringWrapper for r#w_1 {
+        const CONTENT: &'static ::core::primitive::str = ".";
+    }
+    #[doc = "A wrapper for `\"\\\"\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_2;
+    impl ::pest_typed::StringWrapper for r#w_2 {
+        const CONTENT: &'static ::core::primitive::str = "\"";
+    }
+    #[doc = "A wrapper for `[\"\\\"\"]`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_3;
+    impl ::pest_typed::StringArrayWrapper for r#w_3 {
+        const CONTENT: &'static [&'static ::core::primitive::str] = &["\""];
+    }
+    #[doc = "A wrapper for `\"'\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_4;
+    impl ::pest_typed::StringWrapper for r#w_4 {
+        const CONTENT: &'static ::core::primitive::str = "'";
+    }
+    #[doc = "A wrapper for `\"\\\\'\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_5;
+    impl ::pest_typed::StringWrapper for r#w_5 {
+        const CONTENT: &'static ::core::primitive::str = "\\'";
+    }
+    #[doc = "A wrapper for `[\"'\", \"\\\\\"]`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_6;
+    impl ::pest_typed::StringArrayWrapper for r#w_6 {
+        const CONTENT: &'static [&'static ::core::primitive::str] = &["'", "\\"];
+    }
+    #[doc = "A wrapper for `\"if\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_7;
+    impl ::pest_typed::StringWrapper for r#w_7 {
+        const CONTENT: &'static ::core::primitive::str = "if";
+    }
+    #[doc = "A wrapper for `\"else\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_8;
+    impl ::pest_typed::StringWrapper for r#w_8 {
+        const CONTENT: &'static ::core::primitive::str = "else";
+    }
+    #[doc = "A wrapper for `\"while\"`."]
+    #[allow(non_camel_case_types)]
+    #[derive(Clone, Hash, PartialEq, Eq)]
+    pub struct r#w_9;
+    impl ::pest_typed::StringWrapper for r#w_9 {
+        const CONTENT: &'static ::core::primitive::str = "while";
+    }
+}
+#[doc = "Generated structs for tags."]
+pub mod tags {}
+#[doc = "Definitions of statically typed nodes generated by pest-generator."]
+pub mod rules_impl {
+    #[doc = "Definitions of statically typed nodes generated by pest-generator."]
+    pub mod rules {
+        :: pest_typed :: rule_instance ! (r#ident , "Corresponds to expression: `(ASCII_ALPHA ~ (ASCII_ALPHANUMERIC | \"_\")*)`. Atomic rule." "" "```pest" "ident  = @{ ASCII_ALPHA ~ (ASCII_ALPHANUMERIC | \"_\")* }" "```" , super :: super :: Rule , super :: super :: Rule :: r#ident , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#ident as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#ASCII_ALPHA , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: predefined_node :: Rep :: < super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#ASCII_ALPHANUMERIC , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , > , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#ident<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#inner , "Corresponds to expression: `(!(\"\\\"\") ~ ANY)*`. Atomic rule." "" "```pest" "inner  = @{ (!\"\\\"\" ~ ANY)* }" "```" , super :: super :: Rule , super :: super :: Rule :: r#inner , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#inner as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Skip :: < super :: super :: constant_wrappers :: r#w_3 > , min_len = 0 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#inner<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#keyword , "Corresponds to expression: `(kw_if | kw_else | kw_while)`. Atomic rule." "" "```pest" "keyword  = ${ kw_if | kw_else | kw_while }" "```" "" "Accessers:" "" "- `kw_else()`: `Option<&kw_else>`" "- `kw_if()`: `Option<&kw_if>`" "- `kw_while()`: `Option<&kw_while>`" , super :: super :: Rule , super :: super :: Rule :: r#keyword , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: generics :: Choice3 :: < super :: super :: rules :: r#kw_if :: < 'i , 0 > , super :: super :: rules :: r#kw_else :: < 'i , 0 > , super :: super :: rules :: r#kw_while :: < 'i , 0 > , > , { super :: super :: Rule :: r#keyword as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#keyword<'i, INHERITED> {
+            #[doc = "A helper function to access [`kw_else`]."]
+            #[allow(non_snake_case)]
+            pub fn r#kw_else<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#kw_else<'i, 0>>
+            {
+                let res = &self.content;
+                {
+                    let res = res._1().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`kw_else`]."]
+            #[allow(non_snake_case)]
+            pub fn kw_else_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#kw_else().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`kw_if`]."]
+            #[allow(non_snake_case)]
+            pub fn r#kw_if<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#kw_if<'i, 0>>
+            {
+                let res = &self.content;
+                {
+                    let res = res._0().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`kw_if`]."]
+            #[allow(non_snake_case)]
+            pub fn kw_if_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#kw_if().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`kw_while`]."]
+            #[allow(non_snake_case)]
+            pub fn r#kw_while<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#kw_while<'i, 0>>
+            {
+                let res = &self.content;
+                {
+                    let res = res._2().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`kw_while`]."]
+            #[allow(non_snake_case)]
+            pub fn kw_while_span(
+                &self,
+            ) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#kw_while()
+                    .map(|res| ::pest_typed::Spanned::span(res))
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#kw_else , "Corresponds to expression: `(^\"else\" ~ !(ASCII_ALPHANUMERIC | \"_\"))`. Atomic rule." "" "```pest" "kw_else  = @{ ^\"else\" ~ !(ASCII_ALPHANUMERIC | \"_\") }" "```" , super :: super :: Rule , super :: super :: Rule :: r#kw_else , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#kw_else as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Keyword :: < 'i , super :: super :: constant_wrappers :: r#w_8 , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#ASCII_ALPHANUMERIC , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , > > , min_len = 4 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#kw_else<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#kw_if , "Corresponds to expression: `(^\"if\" ~ !(ASCII_ALPHANUMERIC | \"_\"))`. Atomic rule." "" "```pest" "kw_if    = @{ ^\"if\" ~ !(ASCII_ALPHANUMERIC | \"_\") }" "```" , super :: super :: Rule , super :: super :: Rule :: r#kw_if , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#kw_if as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Keyword :: < 'i , super :: super :: constant_wrappers :: r#w_7 , super :: super :: generics :: Choice2 :: < super :: super :: rules :: r#ASCII_ALPHANUMERIC , super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_0 > , > > , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#kw_if<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#kw_while , "Corresponds to expression: `(^\"while\" ~ !ASCII_ALPHANUMERIC)`. Atomic rule." "" "```pest" "kw_while = ${ ^\"while\" ~ !ASCII_ALPHANUMERIC }" "```" , super :: super :: Rule , super :: super :: Rule :: r#kw_while , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: generics :: Keyword :: < 'i , super :: super :: constant_wrappers :: r#w_9 , super :: super :: rules :: r#ASCII_ALPHANUMERIC > , { super :: super :: Rule :: r#kw_while as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 5 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#kw_while<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#number , "Corresponds to expression: `(ASCII_DIGIT+ ~ (\".\" ~ ASCII_DIGIT+)?)`. Atomic rule." "" "```pest" "number = @{ ASCII_DIGIT+ ~ (\".\" ~ ASCII_DIGIT+)? }" "```" , super :: super :: Rule , super :: super :: Rule :: r#number , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#number as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: predefined_node :: RepOnce :: < super :: super :: rules :: r#ASCII_DIGIT , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: re_exported :: Option :: < super :: super :: generics :: Seq2 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_1 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: predefined_node :: RepOnce :: < super :: super :: rules :: r#ASCII_DIGIT , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , > > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , > , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#number<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#quoted , "Corresponds to expression: `(\"'\" ~ (\"\\\\'\" | (!(\"'\" | \"\\\\\") ~ ANY))* ~ \"'\")`. Atomic rule." "" "```pest" "quoted = @{ \"'\" ~ (\"\\\\'\" | !(\"'\" | \"\\\\\") ~ ANY)* ~ \"'\" }" "```" , super :: super :: Rule , super :: super :: Rule :: r#quoted , super :: super :: rules_impl :: bodies :: SpanAtomic < 'i , { super :: super :: Rule :: r#quoted as :: core :: primitive :: usize } , INHERITED > , true , Span , inner = super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < :: pest_typed :: predefined_node :: Rep :: < super :: super :: generics :: Choice2 :: < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_5 > , super :: super :: generics :: CharExcept :: < super :: super :: constant_wrappers :: r#w_6 > , > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_4 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , > , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#quoted<'i, INHERITED> {}
+        :: pest_typed :: rule_instance ! (r#string , "Corresponds to expression: `(\"\\\"\" ~ inner ~ \"\\\"\")`. Atomic rule." "" "```pest" "string = ${ \"\\\"\" ~ inner ~ \"\\\"\" }" "```" "" "Accessers:" "" "- `inner()`: `&inner`" , super :: super :: Rule , super :: super :: Rule :: r#string , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: generics :: Seq3 :: < :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: rules :: r#inner :: < 'i , 0 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , :: pest_typed :: predefined_node :: Skipped < super :: super :: generics :: Str :: < super :: super :: constant_wrappers :: r#w_2 > , :: pest_typed :: predefined_node :: Empty :: < 'i > , 0 > , > , { super :: super :: Rule :: r#string as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 2 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#string<'i, INHERITED> {
+            #[doc = "A helper function to access [`inner`]."]
+            #[allow(non_snake_case)]
+            pub fn r#inner<'s>(&'s self) -> &'s super::super::rules::r#inner<'i, 0> {
+                let res = &self.content;
+                {
+                    let res = &res.content.1.matched;
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`inner`]."]
+            #[allow(non_snake_case)]
+            pub fn inner_span(&self) -> ::pest_typed::Span<'i> {
+                ::pest_typed::Spanned::span(self.r#inner())
+            }
+        }
+        :: pest_typed :: rule_instance ! (r#token , "Corresponds to expression: `(ident | number | string | quoted)`. Atomic rule." "" "```pest" "token  = ${ ident | number | string | quoted }" "```" "" "Accessers:" "" "- `ident()`: `Option<&ident>`" "- `number()`: `Option<&number>`" "- `quoted()`: `Option<&quoted>`" "- `string()`: `Option<&string>`" , super :: super :: Rule , super :: super :: Rule :: r#token , super :: super :: rules_impl :: bodies :: BothAtomic < 'i , super :: super :: generics :: Choice4 :: < super :: super :: rules :: r#ident :: < 'i , 0 > , super :: super :: rules :: r#number :: < 'i , 0 > , super :: super :: rules :: r#string :: < 'i , 0 > , super :: super :: rules :: r#quoted :: < 'i , 0 > , > , { super :: super :: Rule :: r#token as :: core :: primitive :: usize } , INHERITED > , true , Both , min_len = 1 , counts_recursion = false);
+        impl<'i, const INHERITED: ::core::primitive::usize> r#token<'i, INHERITED> {
+            #[doc = "A helper function to access [`ident`]."]
+            #[allow(non_snake_case)]
+            pub fn r#ident<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#ident<'i, 0>>
+            {
+                let res = &self.content;
+                {
+                    let res = res._0().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`ident`]."]
+            #[allow(non_snake_case)]
+            pub fn ident_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#ident().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`number`]."]
+            #[allow(non_snake_case)]
+            pub fn r#number<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#number<'i, 0>>
+            {
+                let res = &self.content;
+                {
+                    let res = res._1().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`number`]."]
+            #[allow(non_snake_case)]
+            pub fn number_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#number().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`quoted`]."]
+            #[allow(non_snake_case)]
+            pub fn r#quoted<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#quoted<'i, 0>>
+            {
+                let res = &self.content;
+                {
+                    let res = res._3().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`quoted`]."]
+            #[allow(non_snake_case)]
+            pub fn quoted_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#quoted().map(|res| ::pest_typed::Spanned::span(res))
+            }
+            #[doc = "A helper function to access [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn r#string<'s>(
+                &'s self,
+            ) -> ::pest_typed::re_exported::Option<&'s super::super::rules::r#string<'i, 0>>
+            {
+                let res = &self.content;
+                {
+                    let res = res._2().map(|res| res);
+                    res
+                }
+            }
+            #[doc = "A helper function to access spans of [`string`]."]
+            #[allow(non_snake_case)]
+            pub fn string_span(&self) -> ::pest_typed::re_exported::Option<::pest_typed::Span<'i>> {
+                self.r#string().map(|res| ::pest_typed::Spanned::span(res))
+            }
+        }
+        ::pest_typed::rule_eoi!(EOI, super::super::Rule);
+        pub use ::pest_typed::predefined_node::ANY;
+        pub use ::pest_typed::predefined_node::ASCII_ALPHA;
+        pub use ::pest_typed::predefined_node::ASCII_ALPHANUMERIC;
+        pub use ::pest_typed::predefined_node::ASCII_DIGIT;
+    }
+    #[doc = "Generic structs shared by rules in [rules], except rules whose types may contain themselves."]
+    pub mod bodies {
+        ::pest_typed::rule_body!(
+            BothAtomic,
+            "Body of atomic rules emitting `Both`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            true,
+            Both,
+            false,
+            r#new
+        );
+        ::pest_typed::rule_body!(
+            SpanAtomic,
+            "Body of atomic rules emitting `Span`.",
+            super::super::Rule,
+            super::super::generics::Skipped::<'i>,
+            true,
+            Span,
+            false,
+            r#new
+        );
+    }
+}
+pub use rules_impl::rules;
+#[doc = "Used generics."]
+pub mod generics {
+    use ::pest_typed::predefined_node;
+    pub use pest_typed::choices::Choice2;
+    pub use pest_typed::choices::Choice3;
+    pub use pest_typed::choices::Choice4;
+    pub use pest_typed::sequence::Seq2;
+    pub use pest_typed::sequence::Seq3;
+    pub use predefined_node::{
+        CharExcept, CharRange, Insens, InsensUnicode, Keyword, Negative, PeekSlice1, PeekSlice2,
+        Positive, Presence, Push, PushLiteral, Skip, SpanOnly, Str, StrChoice, StrSpanned,
+    };
+}
+#[doc = "Re-export some types from rules to simplify the usage."]
+pub use rules as pairs;
+#[doc = r" Parse the whole input with a rule selected at runtime."]
+#[doc = r""]
+#[doc = r" Silent rules have no pairs, so an error is returned for them."]
+#[allow(dead_code)]
+pub fn parse_rule<'i>(
+    rule: Rule,
+    input: &'i ::core::primitive::str,
+) -> ::pest_typed::error::ParseResult<
+    ::pest_typed::Box<dyn ::pest_typed::iterators::AnyPair<'i, Rule> + 'i>,
+    Rule,
+> {
+    fn parse<
+        'i,
+        T: ::pest_typed::ParsableTypedNode<'i, Rule> + ::pest_typed::iterators::AnyPair<'i, Rule> + 'i,
+    >(
+        input: &'i ::core::primitive::str,
+    ) -> ::pest_typed::error::ParseResult<
+        ::pest_typed::Box<dyn ::pest_typed::iterators::AnyPair<'i, Rule> + 'i>,
+        Rule,
+    > {
+        let res = T::try_parse(input)?;
+        ::core::result::Result::Ok(::pest_typed::Box::new(res))
+    }
+    match rule {
+        Rule::EOI => parse::<pairs::EOI<'i>>(input),
+        Rule::r#ident => parse::<pairs::r#ident<'i>>(input),
+        Rule::r#number => parse::<pairs::r#number<'i>>(input),
+        Rule::r#string => parse::<pairs::r#string<'i>>(input),
+        Rule::r#inner => parse::<pairs::r#inner<'i>>(input),
+        Rule::r#token => parse::<pairs::r#token<'i>>(input),
+        Rule::r#quoted => parse::<pairs::r#quoted<'i>>(input),
+        Rule::r#kw_if => parse::<pairs::r#kw_if<'i>>(input),
+        Rule::r#kw_else => parse::<pairs::r#kw_else<'i>>(input),
+        Rule::r#kw_while => parse::<pairs::r#kw_while<'i>>(input),
+        Rule::r#keyword => parse::<pairs::r#keyword<'i>>(input),
+    }
+}
+#[doc = r" Rules directly referenced by each rule, in the order they are defined."]
+#[doc = r""]
+#[doc = r" Built-in rules other than `EOI` and implicit `WHITESPACE` and `COMMENT` are not listed."]
+#[allow(dead_code)]
+pub fn rule_dependencies() -> &'static [(Rule, &'static [Rule])] {
+    &[
+        (Rule::r#ident, &[]),
+        (Rule::r#number, &[]),
+        (Rule::r#string, &[Rule::r#inner]),
+        (Rule::r#inner, &[]),
+        (
+            Rule::r#token,
+            &[
+                Rule::r#ident,
+                Rule::r#number,
+                Rule::r#string,
+                Rule::r#quoted,
+            ],
+        ),
+        (Rule::r#quoted, &[]),
+        (Rule::r#kw_if, &[]),
+        (Rule::r#kw_else, &[]),
+        (Rule::r#kw_while, &[]),
+        (
+            Rule::r#keyword,
+            &[Rule::r#kw_if, Rule::r#kw_else, Rule::r#kw_while],
+        ),
+    ]
+}
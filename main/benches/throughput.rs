@@ -0,0 +1,95 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Parse throughput of hand-composed rules on a synthetic input of about 1 MB,
+//! dominated by skipping white spaces and comments between short tokens.
+
+#![allow(clippy::upper_case_acronyms)]
+
+use criterion::{criterion_group, criterion_main, Criterion, Throughput};
+use pest_typed::{
+    atomic_rule, choices::Choice2, compound_atomic_rule, non_atomic_rule, predefined_node::*,
+    sequence::Seq2, ParsableTypedNode, StringArrayWrapper, StringWrapper,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    WHITESPACE,
+    COMMENT,
+    Word,
+    Words,
+    EOI,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Hash;
+impl StringWrapper for Hash {
+    const CONTENT: &'static str = "#";
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LineEnd;
+impl StringArrayWrapper for LineEnd {
+    const CONTENT: &'static [&'static str] = &["\n"];
+}
+
+atomic_rule!(
+    WHITESPACE,
+    "White space.",
+    Rule,
+    Rule::WHITESPACE,
+    Choice2<CharRange<' ', ' '>, CharRange<'\n', '\n'>>
+);
+compound_atomic_rule!(
+    COMMENT,
+    "Comment till the end of line.",
+    Rule,
+    Rule::COMMENT,
+    Seq2<Skipped<Str<Hash>, Empty<'i>, 0>, Skipped<Skip<'i, LineEnd>, Empty<'i>, 0>>,
+    false
+);
+
+type Ignore<'i> = AtomicRep<Choice2<WHITESPACE<'i, 0>, COMMENT<'i, 0>>>;
+
+atomic_rule!(
+    Word,
+    "Lowercase word.",
+    Rule,
+    Rule::Word,
+    RepMin<CharRange<'a', 'z'>, Empty<'i>, 0, 1>
+);
+
+non_atomic_rule!(
+    Words,
+    "Words separated by white spaces and comments.",
+    Rule,
+    Rule::Words,
+    Rep<Word<'i, 1>, Ignore<'i>, 1>,
+    Ignore<'i>,
+    false
+);
+
+fn input() -> String {
+    let line = "lorem ipsum dolor sit amet # consectetur adipiscing elit\n";
+    line.repeat((1 << 20) / line.len())
+}
+
+fn throughput(c: &mut Criterion) {
+    let input = input();
+    let mut group = c.benchmark_group("throughput");
+    group.sample_size(10);
+    group.throughput(Throughput::Bytes(input.len() as u64));
+    group.bench_function("words", |b| {
+        b.iter(|| Words::<1>::try_parse(&input).unwrap());
+    });
+    group.finish();
+}
+
+criterion_group!(benches, throughput);
+criterion_main!(benches);
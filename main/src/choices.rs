@@ -10,6 +10,18 @@
 //! Definition of choices-related macros and types.
 //!
 //! Choices with more than 12 branches should be defined in your own crate with [`crate::choices!`].
+//! Those have no `new_<index>` constructors, so create them with their variants, such as `Choice13::_0`.
+
+/// A value of one of two types.
+///
+/// Converted from [`Choice2`] with [`Choice2::into_either`] or [`Choice2::as_either`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Either<L, R> {
+    /// The first branch.
+    Left(L),
+    /// The second branch.
+    Right(R),
+}
 
 /// Choice helper with a next branch.
 pub trait NextChoice {
@@ -69,7 +81,7 @@ macro_rules! choices_helper {
 }
 
 #[macro_export]
-/// Generate an iterator type that implements [`core::iter::Iterator`] for choices.
+/// Generate an iterator type that implements [`core::iter::Iterator`] and [`core::iter::DoubleEndedIterator`] for choices.
 /// Automatically called by [`crate::choices!`].
 macro_rules! choices_iter {
     ($name:ident, $pest_typed:ident, $iter_type:ident, $iter_func:ident, $item:ty, $V0:ident, $v0:tt, $( $V:ident, $v:tt, )* ) => {
@@ -89,6 +101,61 @@ macro_rules! choices_iter {
                     $(Self::$v($v) => $v.next(), )*
                 }
             }
+            fn size_hint(&self) -> (::core::primitive::usize, Option<::core::primitive::usize>) {
+                match self {
+                    Self::$v0($v0) => $v0.size_hint(),
+                    $(Self::$v($v) => $v.size_hint(), )*
+                }
+            }
+        }
+        impl<'i: 'n, 'n, R: $pest_typed::RuleType + 'n, $V0: $pest_typed::iterators::Pairs<'i, 'n, R>, $($V: $pest_typed::iterators::Pairs<'i, 'n, R>, )* >
+            ::core::iter::DoubleEndedIterator for $iter_type<'i, 'n, R, $V0, $($V, )*>
+        {
+            fn next_back(&mut self) -> Option<<Self as ::core::iter::Iterator>::Item> {
+                match self {
+                    Self::$v0($v0) => $v0.next_back(),
+                    $(Self::$v($v) => $v.next_back(), )*
+                }
+            }
+        }
+        impl<'i: 'n, 'n, R: $pest_typed::RuleType + 'n, $V0: $pest_typed::iterators::Pairs<'i, 'n, R>, $($V: $pest_typed::iterators::Pairs<'i, 'n, R>, )* >
+            ::core::iter::ExactSizeIterator for $iter_type<'i, 'n, R, $V0, $($V, )*>
+        where
+            $V0::$iter_type: ::core::iter::ExactSizeIterator,
+            $($V::$iter_type: ::core::iter::ExactSizeIterator, )*
+        {
+        }
+    };
+}
+
+#[macro_export]
+/// Try a branch of choices.
+///
+/// Returns if it consumes some input.
+/// If a previous branch has matched nothing, only probe it with [`Tracker::probe_during`](crate::tracker::Tracker::probe_during).
+///
+/// Automatically called by [`crate::choices!`].
+macro_rules! choices_arm {
+    ($pest_typed:ident, $input:ident, $stack:ident, $tracker:ident, $rest:ident, $committed:ident, $V:ident, $v:tt) => {
+        $rest -= 1;
+        match $committed {
+            None => {
+                let res = $pest_typed::predefined_node::restore_on_none($stack, |stack| {
+                    $tracker.pruning_during($rest > 0, |tracker| {
+                        $V::try_parse_with($input, stack, tracker)
+                    })
+                });
+                if let Some((input, res)) = res {
+                    if input != $input {
+                        return Some((input, Self::$v(res)));
+                    }
+                    $committed = Some((input, Self::$v(res)));
+                }
+            }
+            Some(_) => $tracker.probe_during($input, |tracker| {
+                let mut stack = $pest_typed::StackExt::snapshot_guard($stack);
+                let _ = $V::try_parse_with($input, &mut stack, tracker);
+            }),
         }
     };
 }
@@ -160,6 +227,34 @@ macro_rules! choices {
                         }
                     }
                 )*
+                /// Index of the matched branch, counted from `0`.
+                pub fn variant_index(&self) -> ::core::primitive::usize {
+                    let mut index: ::core::primitive::usize = 0;
+                    if let Self::$v0(_) = self {
+                        return index;
+                    }
+                    $(
+                        index += 1;
+                        if let Self::$v(_) = self {
+                            return index;
+                        }
+                    )*
+                    ::core::unreachable!()
+                }
+                /// Map the matched branch with the function for it, one for each branch in order.
+                #[allow(clippy::too_many_arguments)]
+                pub fn map_all<Ret>(
+                    self,
+                    $v0: impl FnOnce($V0) -> Ret,
+                    $($v: impl FnOnce($V) -> Ret, )*
+                ) -> Ret {
+                    match self {
+                        Self::$v0(c) => $v0(c),
+                        $(
+                            Self::$v(c) => $v(c),
+                        )*
+                    }
+                }
             }
             impl<'i, R: $pest_typed::RuleType, $V0: $pest_typed::TypedNode<'i, R>, $($V: $pest_typed::TypedNode<'i, R>, )* > $pest_typed::TypedNode<'i, R>
                 for $name<$V0, $($V, )* >
@@ -170,17 +265,15 @@ macro_rules! choices {
                     stack: &mut $pest_typed::Stack<$pest_typed::Span<'i>>,
                     tracker: &mut $pest_typed::tracker::Tracker<'i, R>,
                 ) -> ::core::option::Option<($pest_typed::Position<'i>, Self)> {
-                    let res = $pest_typed::predefined_node::restore_on_none(stack, |stack| $V0::try_parse_with(input, stack, tracker));
-                    if let Some((input, res)) = res {
-                        return Some((input, Self::$v0(res)));
-                    }
+                    // Rules in all branches but the last one may be pruned.
+                    let mut rest: ::core::primitive::usize = $number;
+                    // Set if a branch matched nothing, then later branches are only probed.
+                    let mut committed = None;
+                    $crate::choices_arm!($pest_typed, input, stack, tracker, rest, committed, $V0, $v0);
                     $(
-                        let res = $pest_typed::predefined_node::restore_on_none(stack, |stack| $V::try_parse_with(input, stack, tracker));
-                        if let Some((input, res)) = res {
-                            return Some((input, Self::$v(res)));
-                        }
+                        $crate::choices_arm!($pest_typed, input, stack, tracker, rest, committed, $V, $v);
                     )*
-                    None
+                    committed
                 }
             }
             impl<
@@ -221,17 +314,161 @@ macro_rules! choices {
                     }
                 }
             }
+            impl<$V0: $pest_typed::MatchedText, $($V: $pest_typed::MatchedText, )* >
+                $pest_typed::MatchedText for $name<$V0, $($V, )* >
+            {
+                fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    match self {
+                        Self::$v0($v0) => $pest_typed::MatchedText::fmt_matched($v0, f),
+                        $(
+                            Self::$v($v) => $pest_typed::MatchedText::fmt_matched($v, f),
+                        )*
+                    }
+                }
+            }
+            impl<$V0: $pest_typed::MatchedText, $($V: $pest_typed::MatchedText, )* >
+                ::core::fmt::Display for $name<$V0, $($V, )* >
+            {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    $pest_typed::MatchedText::fmt_matched(self, f)
+                }
+            }
+            impl<$V0: $pest_typed::ContentEq, $($V: $pest_typed::ContentEq, )* >
+                $pest_typed::ContentEq for $name<$V0, $($V, )* >
+            {
+                fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+                    match (self, other) {
+                        (Self::$v0(l), Self::$v0(r)) => $pest_typed::ContentEq::content_eq(l, r),
+                        $(
+                            (Self::$v(l), Self::$v(r)) => $pest_typed::ContentEq::content_eq(l, r),
+                        )*
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+                fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                    ::core::hash::Hash::hash(&::core::mem::discriminant(self), state);
+                    match self {
+                        Self::$v0($v0) => $pest_typed::ContentEq::content_hash($v0, state),
+                        $(
+                            Self::$v($v) => $pest_typed::ContentEq::content_hash($v, state),
+                        )*
+                    }
+                }
+                fn content_eq_ignore_case(&self, other: &Self) -> ::core::primitive::bool {
+                    match (self, other) {
+                        (Self::$v0(l), Self::$v0(r)) => {
+                            $pest_typed::ContentEq::content_eq_ignore_case(l, r)
+                        }
+                        $(
+                            (Self::$v(l), Self::$v(r)) => {
+                                $pest_typed::ContentEq::content_eq_ignore_case(l, r)
+                            }
+                        )*
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+            }
+            impl<
+                    V: ?::core::marker::Sized,
+                    $V0: $pest_typed::visitor::Walk<V>,
+                    $($V: $pest_typed::visitor::Walk<V>, )*
+                > $pest_typed::visitor::Walk<V> for $name<$V0, $($V, )* >
+            {
+                fn walk(&self, visitor: &mut V) {
+                    match self {
+                        Self::$v0($v0) => $pest_typed::visitor::Walk::walk($v0, visitor),
+                        $(
+                            Self::$v($v) => $pest_typed::visitor::Walk::walk($v, visitor),
+                        )*
+                    }
+                }
+            }
+            impl<
+                    'i,
+                    $V0: $pest_typed::incremental::Offset<'i>,
+                    $($V: $pest_typed::incremental::Offset<'i>, )*
+                > $pest_typed::incremental::Offset<'i> for $name<$V0, $($V, )* >
+            {
+                fn offset(
+                    &mut self,
+                    input: &'i ::core::primitive::str,
+                    from: ::core::primitive::usize,
+                    delta: ::core::primitive::isize,
+                ) {
+                    match self {
+                        Self::$v0($v0) => $pest_typed::incremental::Offset::offset($v0, input, from, delta),
+                        $(
+                            Self::$v($v) => $pest_typed::incremental::Offset::offset($v, input, from, delta),
+                        )*
+                    }
+                }
+            }
+            $crate::choices_serialize!($name, $V0, $v0, $( $V, $v, )* );
             mod helper {
                 $crate::choices_helper!($pest_typed, $name, ($V0, $v0, $( $V, $v, )* ), $V0, $v0, $( $V, $v, )* );
             }
             mod iterators {
-                $crate::choices_iter!($name, $pest_typed, Iter, iter, &'n (dyn $pest_typed::iterators::Pair<'i, 'n, R>), $V0, $v0, $( $V, $v, )* );
+                $crate::choices_iter!($name, $pest_typed, Iter, iter, &'n dyn $pest_typed::iterators::Pair<'i, 'n, R>, $V0, $v0, $( $V, $v, )* );
                 $crate::choices_iter!($name, $pest_typed, IntoIter, into_iter, $pest_typed::Box<dyn $pest_typed::iterators::Pair<'i, 'n, R> + 'n>, $V0, $v0, $( $V, $v, )* );
             }
         }
     };
 }
 
+#[cfg(feature = "serde")]
+#[macro_export]
+/// Implement `serde::Serialize` for choices if feature `serde` is enabled.
+///
+/// The matched branch is serialized as a map with a single entry, whose key is the name of the branch.
+///
+/// Automatically called by [`crate::choices!`].
+macro_rules! choices_serialize {
+    ($name:ident, $V0:ident, $v0:tt, $( $V:ident, $v:tt, )* ) => {
+        impl<$V0: $crate::serde::Serialize, $($V: $crate::serde::Serialize, )* >
+            $crate::serde::Serialize for $name<$V0, $($V, )* >
+        {
+            fn serialize<S: $crate::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                use $crate::serde::ser::SerializeMap;
+                let mut map = serializer.serialize_map(::core::option::Option::Some(1))?;
+                match self {
+                    Self::$v0($v0) => map.serialize_entry(::core::stringify!($v0), $v0)?,
+                    $(
+                        Self::$v($v) => map.serialize_entry(::core::stringify!($v), $v)?,
+                    )*
+                }
+                map.end()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+/// Implement `serde::Serialize` for choices if feature `serde` is enabled.
+///
+/// Feature `serde` is disabled, so nothing is generated.
+macro_rules! choices_serialize {
+    ($name:ident, $V0:ident, $v0:tt, $( $V:ident, $v:tt, )* ) => {};
+}
+
+/// Generate constructors `new_<index>` of choices, one for each branch.
+///
+/// Choices don't implement [`From`] for their branches, as types of branches may be the same.
+macro_rules! choices_constructors {
+    ($name:ident, $( $V:ident, $v:ident, $new:ident, )* ) => {
+        impl<$($V, )* > $name<$($V, )* > {
+            $(
+                #[doc = ::core::concat!("Create with branch `", ::core::stringify!($v), "` matched.")]
+                pub const fn $new(c: $V) -> Self {
+                    Self::$v(c)
+                }
+            )*
+        }
+    };
+}
+
 // Choices helper and iterator.
 
 choices!(Choice2, crate, choice2, 2, T0, _0, T1, _1,);
@@ -259,3 +496,105 @@ choices!(
     Choice12, crate, choice12, 12, T0, _0, T1, _1, T2, _2, T3, _3, T4, _4, T5, _5, T6, _6, T7, _7,
     T8, _8, T9, _9, T10, _10, T11, _11,
 );
+
+choices_constructors!(Choice2, T0, _0, new_0, T1, _1, new_1,);
+choices_constructors!(Choice3, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2,);
+choices_constructors!(Choice4, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3,);
+choices_constructors!(
+    Choice5, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4,
+);
+choices_constructors!(
+    Choice6, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4, T5, _5,
+    new_5,
+);
+choices_constructors!(
+    Choice7, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4, T5, _5,
+    new_5, T6, _6, new_6,
+);
+choices_constructors!(
+    Choice8, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4, T5, _5,
+    new_5, T6, _6, new_6, T7, _7, new_7,
+);
+choices_constructors!(
+    Choice9, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4, T5, _5,
+    new_5, T6, _6, new_6, T7, _7, new_7, T8, _8, new_8,
+);
+choices_constructors!(
+    Choice10, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4, T5, _5,
+    new_5, T6, _6, new_6, T7, _7, new_7, T8, _8, new_8, T9, _9, new_9,
+);
+choices_constructors!(
+    Choice11, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4, T5, _5,
+    new_5, T6, _6, new_6, T7, _7, new_7, T8, _8, new_8, T9, _9, new_9, T10, _10, new_10,
+);
+choices_constructors!(
+    Choice12, T0, _0, new_0, T1, _1, new_1, T2, _2, new_2, T3, _3, new_3, T4, _4, new_4, T5, _5,
+    new_5, T6, _6, new_6, T7, _7, new_7, T8, _8, new_8, T9, _9, new_9, T10, _10, new_10, T11, _11,
+    new_11,
+);
+
+impl<T0, T1> Choice2<T0, T1> {
+    /// Convert into an [`Either`].
+    pub fn into_either(self) -> Either<T0, T1> {
+        match self {
+            Self::_0(c) => Either::Left(c),
+            Self::_1(c) => Either::Right(c),
+        }
+    }
+    /// Convert into an [`Either`] of references.
+    pub fn as_either(&self) -> Either<&T0, &T1> {
+        match self {
+            Self::_0(c) => Either::Left(c),
+            Self::_1(c) => Either::Right(c),
+        }
+    }
+}
+impl<T0, T1> From<Choice2<T0, T1>> for Either<T0, T1> {
+    fn from(choice: Choice2<T0, T1>) -> Self {
+        choice.into_either()
+    }
+}
+impl<T0, T1> From<Either<T0, T1>> for Choice2<T0, T1> {
+    fn from(either: Either<T0, T1>) -> Self {
+        match either {
+            Either::Left(c) => Self::_0(c),
+            Either::Right(c) => Self::_1(c),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Choice3;
+    use alloc::{format, string::String};
+
+    fn describe(choice: Choice3<u8, u8, &str>) -> String {
+        choice.map_all(
+            |c| format!("first {c}"),
+            |c| format!("second {c}"),
+            |c| format!("third {c}"),
+        )
+    }
+
+    #[test]
+    fn constructors() {
+        // Branches of the same type are distinguished by constructors.
+        assert_eq!(Choice3::<u8, u8, &str>::new_0(1), Choice3::_0(1));
+        assert_eq!(Choice3::<u8, u8, &str>::new_1(1), Choice3::_1(1));
+        assert_ne!(Choice3::<u8, u8, &str>::new_0(1), Choice3::new_1(1));
+    }
+
+    #[test]
+    fn variant_index() {
+        assert_eq!(Choice3::<u8, u8, &str>::new_0(7).variant_index(), 0);
+        assert_eq!(Choice3::<u8, u8, &str>::new_1(7).variant_index(), 1);
+        assert_eq!(Choice3::<u8, u8, &str>::new_2("x").variant_index(), 2);
+    }
+
+    #[test]
+    fn map_all() {
+        assert_eq!(describe(Choice3::new_0(1)), "first 1");
+        assert_eq!(describe(Choice3::new_1(2)), "second 2");
+        assert_eq!(describe(Choice3::new_2("x")), "third x");
+    }
+}
@@ -0,0 +1,272 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Types for different kinds of parsing failures.
+//!
+//! Re-exported from [`pest::error`], with [`CustomError`] for reporting errors found after parsing,
+//! [`ErrorDetails`] for rendering errors with diagnostic libraries,
+//! and [`ParseFailure`] for errors from typed parsers, with the rules attempted and how far parsing got.
+
+pub use pest::error::*;
+
+use crate::{position::Position, span::Span, RuleType};
+use alloc::{boxed::Box, format, string::String, vec::Vec};
+use core::{
+    fmt::{self, Display, Formatter},
+    ops::{Deref, Range},
+};
+
+/// Construct [`Error`]s with custom messages,
+/// which are rendered in the same way as parsing errors.
+///
+/// Useful for semantic errors found after a successful parse, such as duplicate keys.
+///
+/// ```
+/// # use pest_typed::{error::{CustomError, Error}, Span};
+/// # #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # enum Rule {}
+/// let span = Span::new("a = 1", 0, 1).unwrap();
+/// let error = Error::<Rule>::custom_at_span(span, "undefined variable `a`");
+/// assert_eq!(
+///     error.to_string(),
+///     " --> 1:1
+///   |
+/// 1 | a = 1
+///   | ^
+///   |
+///   = undefined variable `a`"
+/// );
+/// ```
+pub trait CustomError<'i>: Sized {
+    /// Create an error with given message, underlining `span`.
+    fn custom_at_span(span: Span<'i>, message: impl Into<String>) -> Self;
+    /// Create an error with given message, pointing at `pos`.
+    fn custom_at_pos(pos: Position<'i>, message: impl Into<String>) -> Self;
+}
+
+impl<'i, R: RuleType> CustomError<'i> for Error<R> {
+    fn custom_at_span(span: Span<'i>, message: impl Into<String>) -> Self {
+        let variant = ErrorVariant::CustomError {
+            message: message.into(),
+        };
+        match pest::Span::new(span.get_input(), span.start(), span.end()) {
+            Some(span) => Error::new_from_span(variant, span),
+            None => invalid_index(span.get_input(), span.start()),
+        }
+    }
+    fn custom_at_pos(pos: Position<'i>, message: impl Into<String>) -> Self {
+        let variant = ErrorVariant::CustomError {
+            message: message.into(),
+        };
+        match pest::Position::new(pos.input, pos.pos()) {
+            Some(pos) => Error::new_from_pos(variant, pos),
+            None => invalid_index(pos.input, pos.pos()),
+        }
+    }
+}
+
+/// Structured details of an [`Error`],
+/// so that integrations with diagnostic libraries such as `miette` or `ariadne`
+/// don't need to parse its rendered message.
+///
+/// ```
+/// # use pest_typed::{error::{CustomError, Error, ErrorDetails, ErrorVariant}, Span};
+/// # #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # enum Rule { key, value }
+/// let input = "a = 1\nb = ?";
+/// let span = Span::new(input, 10, 11).unwrap();
+/// let error = Error::<Rule>::custom_at_span(span, "unexpected `?`");
+/// assert_eq!(error.range(), 10..11);
+/// assert_eq!(error.snippet(), "b = ?");
+/// assert!(error.expected().is_empty());
+///
+/// let pos = pest::Position::new(input, 4).unwrap();
+/// let variant = ErrorVariant::ParsingError {
+///     positives: vec![Rule::value],
+///     negatives: vec![],
+/// };
+/// let error = Error::new_from_pos(variant, pos);
+/// assert_eq!(error.range(), 4..4);
+/// assert_eq!(error.expected(), &[Rule::value]);
+/// ```
+pub trait ErrorDetails<R> {
+    /// Byte range in the input that the error points at.
+    ///
+    /// Empty if the error points at a position.
+    fn range(&self) -> Range<usize>;
+    /// The line of input where the error starts, without the line ending.
+    fn snippet(&self) -> &str;
+    /// Rules expected where the error occurred.
+    ///
+    /// For an [`Error`], empty unless it's an [`ErrorVariant::ParsingError`].
+    /// Typed parsers report [`ErrorVariant::CustomError`]s wrapped in [`ParseFailure`]s,
+    /// which carry the rules attempted.
+    fn expected(&self) -> &[R];
+    /// Rules that unexpectedly matched where the error occurred.
+    ///
+    /// For an [`Error`], empty unless it's an [`ErrorVariant::ParsingError`].
+    fn unexpected(&self) -> &[R];
+}
+
+impl<R: RuleType> ErrorDetails<R> for Error<R> {
+    fn range(&self) -> Range<usize> {
+        match self.location {
+            InputLocation::Pos(pos) => pos..pos,
+            InputLocation::Span((start, end)) => start..end,
+        }
+    }
+    fn snippet(&self) -> &str {
+        self.line()
+    }
+    fn expected(&self) -> &[R] {
+        match &self.variant {
+            ErrorVariant::ParsingError { positives, .. } => positives,
+            ErrorVariant::CustomError { .. } => &[],
+        }
+    }
+    fn unexpected(&self) -> &[R] {
+        match &self.variant {
+            ErrorVariant::ParsingError { negatives, .. } => negatives,
+            ErrorVariant::CustomError { .. } => &[],
+        }
+    }
+}
+
+/// Result of fallible entry points, such as [`ParsableTypedNode::try_parse`](crate::ParsableTypedNode::try_parse),
+/// generated `parse_<rule>` methods and [`TypedParser::try_parse`](crate::TypedParser::try_parse).
+///
+/// The error is boxed on all of them, as a [`ParseFailure`] is much larger than most nodes
+/// and would enlarge every successful result. Write signatures with this alias
+/// to keep up with the error form.
+///
+/// `?` converts it into a boxed [`Error`], dropping what the failure carries besides the error.
+pub type ParseResult<T, R> = Result<T, Box<ParseFailure<R>>>;
+
+/// An [`Error`] from a typed parser, with the rules attempted where it occurred,
+/// and the farthest position that any match has reached before parsing failed,
+/// including matches in abandoned branches.
+///
+/// Created by [`Tracker::collect_failure`](crate::tracker::Tracker::collect_failure),
+/// and returned by all fallible entry points, see [`ParseResult`].
+/// It dereferences to the error, and is displayed the same as the error.
+/// Rules attempted are available through [`ErrorDetails`].
+///
+/// With the `miette` feature, it's also a `miette::Diagnostic` labelling the failing range.
+/// Its labels are at byte offsets in the whole input,
+/// so attach the input to the report with `miette::Report::with_source_code`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseFailure<R: RuleType> {
+    error: Error<R>,
+    expected: Vec<R>,
+    unexpected: Vec<R>,
+    farthest: usize,
+    line_col: (usize, usize),
+}
+impl<R: RuleType> ParseFailure<R> {
+    /// Attach the farthest position reached to an error, with no rules attempted.
+    pub fn new(error: Error<R>, farthest: Position<'_>) -> Self {
+        Self {
+            error,
+            expected: Vec::new(),
+            unexpected: Vec::new(),
+            farthest: farthest.pos(),
+            line_col: farthest.line_col(),
+        }
+    }
+    /// Attach rules expected and rules unexpectedly matched where the error occurred,
+    /// see [`ErrorDetails::expected`] and [`ErrorDetails::unexpected`].
+    pub fn with_attempts(mut self, expected: Vec<R>, unexpected: Vec<R>) -> Self {
+        self.expected = expected;
+        self.unexpected = unexpected;
+        self
+    }
+    /// Byte offset of the farthest position reached.
+    pub fn farthest(&self) -> usize {
+        self.farthest
+    }
+    /// Line and column of the farthest position reached, see [`Position::line_col`].
+    pub fn farthest_line_col(&self) -> (usize, usize) {
+        self.line_col
+    }
+    /// The error.
+    pub fn error(&self) -> &Error<R> {
+        &self.error
+    }
+    /// Take the error.
+    pub fn into_error(self) -> Error<R> {
+        self.error
+    }
+}
+impl<R: RuleType> Deref for ParseFailure<R> {
+    type Target = Error<R>;
+    fn deref(&self) -> &Self::Target {
+        &self.error
+    }
+}
+impl<R: RuleType> Display for ParseFailure<R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.error, f)
+    }
+}
+impl<R: RuleType> ErrorDetails<R> for ParseFailure<R> {
+    fn range(&self) -> Range<usize> {
+        self.error.range()
+    }
+    fn snippet(&self) -> &str {
+        self.error.snippet()
+    }
+    fn expected(&self) -> &[R] {
+        &self.expected
+    }
+    fn unexpected(&self) -> &[R] {
+        &self.unexpected
+    }
+}
+impl<R: RuleType> From<ParseFailure<R>> for Error<R> {
+    fn from(failure: ParseFailure<R>) -> Self {
+        failure.error
+    }
+}
+impl<R: RuleType> From<Box<ParseFailure<R>>> for Box<Error<R>> {
+    fn from(failure: Box<ParseFailure<R>>) -> Self {
+        Box::new(failure.error)
+    }
+}
+#[cfg(feature = "std")]
+impl<R: RuleType> std::error::Error for ParseFailure<R> {}
+#[cfg(feature = "miette")]
+impl<R: RuleType> miette::Diagnostic for ParseFailure<R> {
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = miette::LabeledSpan> + '_>> {
+        let names = |rules: &[R]| {
+            let names: Vec<String> = rules.iter().map(|rule| format!("{:?}", rule)).collect();
+            names.join(", ")
+        };
+        let label = match (self.expected.is_empty(), self.unexpected.is_empty()) {
+            (true, true) => None,
+            (false, true) => Some(format!("expected {}", names(&self.expected))),
+            (true, false) => Some(format!("unexpected {}", names(&self.unexpected))),
+            (false, false) => Some(format!(
+                "unexpected {}, expected {}",
+                names(&self.unexpected),
+                names(&self.expected),
+            )),
+        };
+        let span = miette::LabeledSpan::new_primary_with_span(label, self.range());
+        Some(Box::new(core::iter::once(span)))
+    }
+}
+
+fn invalid_index<R: RuleType>(input: &str, index: usize) -> Error<R> {
+    Error::new_from_pos(
+        ErrorVariant::CustomError {
+            message: format!("Internal error (invalid character index {}).", index),
+        },
+        pest::Position::from_start(input),
+    )
+}
@@ -60,6 +60,17 @@ fn visualize_ws_and_cntrl(line: &str) -> String {
         .collect()
 }
 
+/// Lines of the whole input, including line endings.
+///
+/// Empty input has a single empty line, so that there is always a line to point at.
+fn input_lines(input: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = input.split_inclusive('\n').collect();
+    if lines.is_empty() {
+        lines.push("");
+    }
+    lines
+}
+
 struct Partition2<'i> {
     line: usize,
     former: String,
@@ -331,8 +342,8 @@ impl<SF, MF, NF> FormatOption<SF, MF, NF> {
         let mut start = None;
         let mut end = None;
         let mut pos = 0usize;
-        let input = Span::new(span.get_input(), 0, span.get_input().len()).unwrap();
-        let mut iter = input.lines().enumerate().peekable();
+        let input_lines = input_lines(span.get_input());
+        let mut iter = input_lines.iter().enumerate().peekable();
         while let Some((index, line)) = iter.peek() {
             if pos + line.len() >= span.start() {
                 start = Some(Pos {
@@ -354,13 +365,10 @@ impl<SF, MF, NF> FormatOption<SF, MF, NF> {
             }
             pos += line.len();
         }
+        // Lines cover the whole input, so both are found.
         let start = start.unwrap();
         let end = end.unwrap();
-        let mut lines = input
-            .lines()
-            .skip(start.line)
-            .take(end.line - start.line + 1)
-            .peekable();
+        let mut lines = input_lines[start.line..=end.line].iter().copied();
         let index_digit = Self::ceil_log10(end.line + 1);
         if start.line == end.line {
             let cur_line = lines.next().unwrap();
@@ -410,11 +418,10 @@ impl<SF, MF, NF> FormatOption<SF, MF, NF> {
         NF: FnMut(&str, &mut Writer) -> fmt::Result,
     {
         let mut pos = 0usize;
-        let input = Span::new(position.input, 0, position.input.len()).unwrap();
-        let mut iter = input.lines().enumerate().peekable();
-        while let Some((index, line)) = iter.peek() {
-            if pos + line.len() > position.pos() {
-                let l = *index;
+        let lines = input_lines(position.input);
+        for (l, line) in lines.iter().enumerate() {
+            // The end of input is pointed at on the last line.
+            if pos + line.len() > position.pos() || l + 1 == lines.len() {
                 let c = position.pos() - pos;
                 let index_digit = Self::ceil_log10(l + 1);
                 let line = Partition::new(l, line, c);
@@ -422,7 +429,6 @@ impl<SF, MF, NF> FormatOption<SF, MF, NF> {
                 break;
             }
             pos += line.len();
-            iter.next();
         }
         Ok(())
     }
@@ -482,6 +488,32 @@ mod position {
   |
 3 | 789␊
   | ^
+"
+        );
+    }
+
+    #[test]
+    fn end_pos() {
+        let msg = Position::new("123\n456", 7).unwrap().to_string();
+        assert_eq!(
+            msg,
+            "  \
+  |
+2 | 456
+  |    ^
+"
+        );
+    }
+
+    #[test]
+    fn empty_input() {
+        let msg = Position::new("", 0).unwrap().to_string();
+        assert_eq!(
+            msg,
+            "  \
+  |
+1 | 
+  | ^
 "
         );
     }
@@ -545,6 +577,19 @@ mod span {
         );
     }
 
+    #[test]
+    fn display_span_empty_input() {
+        let msg = Span::new("", 0, 0).unwrap().to_string();
+        assert_eq!(
+            msg,
+            "  \
+  |
+1 | 
+  | 
+"
+        );
+    }
+
     #[test]
     fn display_span_cr_lf_single_line() {
         let msg = Span::new("123\r\n", 4, 5).unwrap().to_string();
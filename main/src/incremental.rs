@@ -0,0 +1,266 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Reparse an edited input by reparsing only the item that covers the edit.
+//!
+//! Rules like `file = { SOI ~ item* ~ EOI }` have a generated method `reparse` built on [`reparse`].
+
+use crate::{
+    error::ParseResult,
+    predefined_node::{
+        AlwaysFail, AtomicRep, CharExcept, CharRange, Empty, Insens, InsensUnicode, Keyword,
+        Negative, PeekSlice1, PeekSlice2, Positive, Presence, Push, PushLiteral, Recover, RepMin,
+        RepMinMax, RepUntil, Skip, SkipChar, Skipped, SpanOnly, Str, StrChoice, StrSpanned, ANY,
+        DROP, EOI, NEWLINE, PEEK, PEEK_ALL, PEEK_INSENS, POP, POP_ALL, POP_INSENS, SOI,
+    },
+    tracker::Tracker,
+    ParsableTypedNode, Position, RuleType, Span, Spanned, Stack, StringArrayWrapper, StringWrapper,
+    TypedNode,
+};
+use alloc::boxed::Box;
+use core::ops::Range;
+
+/// A node that can be moved into an edited input.
+pub trait Offset<'i> {
+    /// Point spans to `input`, and move positions at or after `from` by `delta` bytes.
+    ///
+    /// Texts at moved positions in `input` should be the same as those in the original input.
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize);
+}
+
+impl<'i> Offset<'i> for Span<'i> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        let shift = |pos: usize| {
+            if pos >= from {
+                pos.checked_add_signed(delta)
+                    .expect("Span moved out of the input.")
+            } else {
+                pos
+            }
+        };
+        *self = Span::new(input, shift(self.start()), shift(self.end()))
+            .expect("Span moved out of the input.");
+    }
+}
+
+macro_rules! impl_empty {
+    ($node:ty, $($tt:tt)*) => {
+        impl<'i, $($tt)*> Offset<'i> for $node {
+            #[inline]
+            fn offset(&mut self, _input: &'i str, _from: usize, _delta: isize) {}
+        }
+    };
+}
+
+// Matched strings are borrowed from the original input, which lives as long as the edited one.
+impl_empty!(Str<T>, T: StringWrapper);
+impl_empty!(Presence<T>, T: StringWrapper);
+impl_empty!(Insens<'i, T>, T: StringWrapper);
+impl_empty!(InsensUnicode<'i, T>, T: StringWrapper);
+impl_empty!(Keyword<'i, T, Boundary>, T: StringWrapper, Boundary);
+impl_empty!(PushLiteral<T>, T: StringWrapper);
+impl_empty!(CharExcept<Strings>, Strings: StringArrayWrapper);
+impl_empty!(StrChoice<Strings>, Strings: StringArrayWrapper);
+impl_empty!(CharRange<MIN, MAX>, const MIN: char, const MAX: char);
+impl_empty!(Positive<T>, T);
+impl_empty!(Negative<T>, T);
+impl_empty!(ANY,);
+impl_empty!(SOI,);
+impl_empty!(EOI,);
+impl_empty!(NEWLINE,);
+impl_empty!(DROP,);
+impl_empty!(AlwaysFail<'i>,);
+impl_empty!(Empty<'i>,);
+
+macro_rules! impl_with_span {
+    ($node:ty, $($tt:tt)*) => {
+        impl<'i, $($tt)*> Offset<'i> for $node {
+            fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+                self.span.offset(input, from, delta)
+            }
+        }
+    };
+}
+
+impl_with_span!(Skip<'i, Strings>, Strings: StringArrayWrapper);
+impl_with_span!(SkipChar<'i, N>, const N: usize);
+impl_with_span!(StrSpanned<'i, T>, T: StringWrapper);
+impl_with_span!(SpanOnly<'i, T>, T);
+impl_with_span!(PeekSlice2<'i, START, END>, const START: i32, const END: i32);
+impl_with_span!(PeekSlice1<'i, START>, const START: i32);
+impl_with_span!(PEEK<'i>,);
+impl_with_span!(PEEK_ALL<'i>,);
+impl_with_span!(POP<'i>,);
+impl_with_span!(POP_ALL<'i>,);
+impl_with_span!(PEEK_INSENS<'i>,);
+impl_with_span!(POP_INSENS<'i>,);
+
+impl<'i, T: Offset<'i>> Offset<'i> for Push<'i, T> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        self.content.offset(input, from, delta);
+        self.span.offset(input, from, delta);
+    }
+}
+
+impl<'i, T: Offset<'i> + ?Sized> Offset<'i> for Box<T> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        T::offset(self, input, from, delta)
+    }
+}
+
+impl<'i, T: Offset<'i>> Offset<'i> for Option<T> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        if let Some(inner) = self {
+            inner.offset(input, from, delta)
+        }
+    }
+}
+
+impl<'i, T: Offset<'i>, Sync: Offset<'i>> Offset<'i> for Recover<'i, T, Sync> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        match self {
+            Self::Matched(matched) => matched.offset(input, from, delta),
+            Self::Recovered { span, sync } => {
+                span.offset(input, from, delta);
+                sync.offset(input, from, delta);
+            }
+        }
+    }
+}
+
+impl<'i, T1: Offset<'i>, T2: Offset<'i>> Offset<'i> for (T1, T2) {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        self.0.offset(input, from, delta);
+        self.1.offset(input, from, delta);
+    }
+}
+
+impl<'i, T: Offset<'i>> Offset<'i> for [T] {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        self.iter_mut().for_each(|i| i.offset(input, from, delta))
+    }
+}
+
+impl<'i, T: Offset<'i>, const N: usize> Offset<'i> for [T; N] {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        self[..].offset(input, from, delta)
+    }
+}
+
+impl<'i, T: Offset<'i>, Skip: Offset<'i>, const SKIP: usize> Offset<'i> for Skipped<T, Skip, SKIP> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        self.skipped.offset(input, from, delta);
+        self.matched.offset(input, from, delta);
+    }
+}
+
+impl<'i, T: Offset<'i>> Offset<'i> for AtomicRep<T> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        self.content.offset(input, from, delta)
+    }
+}
+
+macro_rules! impl_with_skipped_vec {
+    ($name:ident, $(const $args:ident : $t:ty,)*) => {
+        impl<'i, T: Offset<'i>, Skip: Offset<'i>, const SKIP: usize, $(const $args: $t, )*> Offset<'i>
+            for $name<T, Skip, SKIP, $($args, )*>
+        {
+            fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+                self.skipped.iter_mut().for_each(|i| i.offset(input, from, delta));
+                self.content.offset(input, from, delta);
+            }
+        }
+    };
+}
+
+impl_with_skipped_vec!(RepMinMax, const MIN: usize, const MAX: usize,);
+impl_with_skipped_vec!(RepMin, const MIN: usize,);
+
+impl<'i, T: Offset<'i>, Terminator> Offset<'i> for RepUntil<'i, T, Terminator> {
+    fn offset(&mut self, input: &'i str, from: usize, delta: isize) {
+        self.content.offset(input, from, delta);
+        if let Some(terminator) = &mut self.terminator {
+            terminator.offset(input, from, delta);
+        }
+    }
+}
+
+/// Reparse `input`, which is the input of `old` with range `edit` replaced,
+/// by reparsing only the item that covers the edit.
+///
+/// `items` returns the repetition of items in a tree.
+///
+/// Returns [`None`] if it can't be done, such as when:
+///
+/// - The edit is not inside a single item.
+/// - Texts outside the item are changed.
+/// - The reparsed item doesn't end where the edited item ends.
+/// - The reparsed item leaves something on the stack.
+///
+/// Lookaheads from other items into the edited one are not checked again.
+pub fn try_reparse<'i, R, N, T>(
+    old: &N,
+    edit: Range<usize>,
+    input: &'i str,
+    items: impl Fn(&mut N) -> &mut [T],
+) -> Option<N>
+where
+    R: RuleType,
+    N: Spanned<'i, R> + Offset<'i> + Clone,
+    T: TypedNode<'i, R> + Spanned<'i, R> + Offset<'i>,
+{
+    let old_input = old.input();
+    let delta = input.len() as isize - old_input.len() as isize;
+    let mut new = old.clone();
+    let list = items(&mut new);
+    let index = list.iter().position(|item| {
+        let span = item.span();
+        span.start() < span.end() && span.start() <= edit.start && edit.end <= span.end()
+    })?;
+    let span = list[index].span();
+    let (start, end) = (span.start(), span.end());
+    let new_end = end
+        .checked_add_signed(delta)
+        .filter(|&new_end| new_end >= start)?;
+    if input.get(..start)? != &old_input[..start] || input.get(new_end..)? != &old_input[end..] {
+        return None;
+    }
+
+    let mut stack = Stack::new();
+    let mut tracker = Tracker::new(Position::from_start(input));
+    let (next, item) = T::try_parse_with(Position::new(input, start)?, &mut stack, &mut tracker)?;
+    if next.pos() != new_end || !stack.is_empty() {
+        return None;
+    }
+
+    new.offset(input, end, delta);
+    items(&mut new)[index] = item;
+    Some(new)
+}
+
+/// Reparse `input`, which is the input of `old` with range `edit` replaced.
+///
+/// Only the item that covers the edit is reparsed if possible, see [`try_reparse`].
+/// Otherwise, the whole input is parsed again.
+pub fn reparse<'i, R, N, T>(
+    old: &N,
+    edit: Range<usize>,
+    input: &'i str,
+    items: impl Fn(&mut N) -> &mut [T],
+) -> ParseResult<N, R>
+where
+    R: RuleType,
+    N: ParsableTypedNode<'i, R> + Spanned<'i, R> + Offset<'i>,
+    T: TypedNode<'i, R> + Spanned<'i, R> + Offset<'i>,
+{
+    match try_reparse(old, edit, input, items) {
+        Some(new) => Ok(new),
+        None => N::try_parse(input),
+    }
+}
@@ -11,16 +11,19 @@
 
 use crate::{
     predefined_node::{
-        AlwaysFail, AtomicRep, CharRange, Empty, Insens, Negative, PeekSlice1, PeekSlice2,
-        Positive, Push, RepMin, RepMinMax, Skip, Skipped, Str, ANY, DROP, NEWLINE, PEEK, PEEK_ALL,
-        POP, POP_ALL, SOI,
+        AlwaysFail, AtomicRep, CharExcept, CharRange, Empty, Insens, InsensUnicode, Keyword,
+        Negative, PeekSlice1, PeekSlice2, Positive, Presence, Push, PushLiteral, Recover, RepMin,
+        RepMinMax, RepStorage, RepUntil, Skip, Skipped, SpanOnly, Str, StrChoice, StrSpanned, ANY,
+        DROP, NEWLINE, PEEK, PEEK_ALL, PEEK_INSENS, POP, POP_ALL, POP_INSENS, SOI,
     },
     typed_node::{RuleStorage, RuleStruct, Spanned},
-    StringArrayWrapper, StringWrapper, TypedNode,
+    Position, StringArrayWrapper, StringWrapper, TypedNode,
 };
-use alloc::{boxed, collections::VecDeque, string::String, vec, vec::Vec};
+use alloc::{boxed, collections::VecDeque, format, string::String, vec, vec::Vec};
 use core::{
+    fmt::{Debug, Display, Formatter},
     iter::{self, empty, once, Chain, FlatMap, Iterator},
+    marker::PhantomData,
     mem::swap,
 };
 use pest::RuleType;
@@ -38,22 +41,63 @@ pub struct Token<R: RuleType> {
     pub children: Vec<Self>,
 }
 
+/// Start or end of a rule in a flat stream, simulating [`pest::Token`].
+///
+/// Yielded by [`Tokens`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TokenEvent<'i, R: RuleType> {
+    /// Start of a rule.
+    Start {
+        /// Rule.
+        rule: R,
+        /// Start position.
+        pos: Position<'i>,
+    },
+    /// End of a rule.
+    End {
+        /// Rule.
+        rule: R,
+        /// End position.
+        pos: Position<'i>,
+    },
+}
+
 /// Simulate [`pest::iterators::Pairs`].
 pub trait Pairs<'i: 'n, 'n, R: RuleType + 'n> {
     /// Iterator type that iterate on inner pairs by reference.
-    type Iter: Iterator<Item = &'n (dyn Pair<'i, 'n, R>)>;
+    ///
+    /// It can be reversed, so that the last pairs are found without collecting all of them.
+    type Iter: DoubleEndedIterator<Item = &'n dyn Pair<'i, 'n, R>>;
     /// Iterator type that iterate on inner pairs by value.
-    type IntoIter: Iterator<Item = boxed::Box<dyn Pair<'i, 'n, R> + 'n>>;
+    type IntoIter: DoubleEndedIterator<Item = boxed::Box<dyn Pair<'i, 'n, R> + 'n>>;
     /// Iterate on inner pairs by reference. Returns [`Pairs::Iter`].
     fn iter_pairs(&'n self) -> Self::Iter;
     /// Iterate on inner pairs by value. Returns [`Pairs::IntoIter`].
     fn into_iter_pairs(self) -> Self::IntoIter;
+    /// Debug inner pairs with bounded depth and bounded number of children.
+    ///
+    /// See [`DebugLimited`].
+    fn debug_limited(
+        &'n self,
+        max_depth: usize,
+        max_children: usize,
+    ) -> DebugLimited<'i, 'n, R, Self>
+    where
+        Self: Sized,
+    {
+        DebugLimited {
+            pairs: self,
+            max_depth,
+            max_children,
+            _phantom: PhantomData,
+        }
+    }
 }
 
 /// Simulate [`pest::iterators::Pair`].
 pub trait Pair<'i: 'n, 'n, R: RuleType + 'n>: Spanned<'i, R> + RuleStorage<R> {
     /// Collect inner pairs' [`Pairs::Iter`] and make them into a [`vec::IntoIter`].
-    fn inner(&'n self) -> vec::IntoIter<&'n (dyn Pair<'i, 'n, R>)>;
+    fn inner(&'n self) -> vec::IntoIter<&'n dyn Pair<'i, 'n, R>>;
     /// Collect inner pairs' [`Pairs::IntoIter`] and make them into a [`vec::IntoIter`].
     fn into_inner(self) -> vec::IntoIter<boxed::Box<dyn Pair<'i, 'n, R> + 'n>>;
     /// As tokens.
@@ -70,15 +114,32 @@ pub trait Pair<'i: 'n, 'n, R: RuleType + 'n>: Spanned<'i, R> + RuleStorage<R> {
     }
 }
 
+/// A rule struct whose type is erased, such as one parsed by a rule selected at runtime.
+///
+/// [`Pair`] borrows a struct for its second lifetime parameter,
+/// so a boxed `dyn Pair` is hard to use. This trait only has the input lifetime.
+pub trait AnyPair<'i, R: RuleType>: Spanned<'i, R> + RuleStorage<R> + Debug {
+    /// Collect inner pairs, see [`Pair::inner`].
+    fn inner_pairs<'n>(&'n self) -> vec::IntoIter<&'n dyn Pair<'i, 'n, R>>
+    where
+        'i: 'n;
+    /// As tokens, the same as [`Pair::as_token_tree`].
+    fn token_tree(&self) -> Token<R> {
+        let children = self.inner_pairs().map(|p| p.as_token_tree()).collect();
+        Token::<R> {
+            rule: self.rule(),
+            start: self.span().start(),
+            end: self.span().end(),
+            children,
+        }
+    }
+}
+
 fn iterate_level_order<'i: 'n, 'n, R: RuleType + 'n, E>(
     p: &'n impl Pair<'i, 'n, R>,
-    mut f: impl FnMut(
-        &'n (dyn Pair<'i, 'n, R>),
-        usize,
-        &VecDeque<&dyn Pair<'i, 'n, R>>,
-    ) -> Result<(), E>,
+    mut f: impl FnMut(&'n dyn Pair<'i, 'n, R>, usize, &VecDeque<&dyn Pair<'i, 'n, R>>) -> Result<(), E>,
 ) -> Result<(), E> {
-    let mut queue: VecDeque<&'n (dyn Pair<'i, 'n, R>)> = VecDeque::new();
+    let mut queue: VecDeque<&'n dyn Pair<'i, 'n, R>> = VecDeque::new();
     let mut next = VecDeque::new();
     queue.push_back(p);
     loop {
@@ -96,16 +157,12 @@ fn iterate_level_order<'i: 'n, 'n, R: RuleType + 'n, E>(
 /// Pre-order traversal
 fn iterate_pre_order<'i: 'n, 'n, R: RuleType + 'n, E>(
     p: &'n impl Pair<'i, 'n, R>,
-    mut f: impl FnMut(
-        &'n (dyn Pair<'i, 'n, R>),
-        usize,
-        &VecDeque<&dyn Pair<'i, 'n, R>>,
-    ) -> Result<(), E>,
+    mut f: impl FnMut(&'n dyn Pair<'i, 'n, R>, usize, &VecDeque<&dyn Pair<'i, 'n, R>>) -> Result<(), E>,
 ) -> Result<(), E> {
-    let mut stack: Vec<VecDeque<&'n (dyn Pair<'i, 'n, R>)>> = Vec::new();
+    let mut stack: Vec<VecDeque<&'n dyn Pair<'i, 'n, R>>> = Vec::new();
 
-    let root: &'n (dyn Pair<'i, 'n, R>) = p;
-    stack.push(VecDeque::<&'n (dyn Pair<'i, 'n, R>)>::from_iter(once(root)));
+    let root: &'n dyn Pair<'i, 'n, R> = p;
+    stack.push(VecDeque::<&'n dyn Pair<'i, 'n, R>>::from_iter(once(root)));
 
     loop {
         if let Some(parent) = stack.last_mut() {
@@ -141,27 +198,65 @@ fn write_tree_to<'i: 'n, 'n, R: RuleType + 'n>(
     })
 }
 
+/// Write the tree to `buf` as JSON.
+#[cfg(feature = "json")]
+fn write_json_to<'i: 'n, 'n, R: RuleType + 'n>(
+    p: &'n dyn Pair<'i, 'n, R>,
+    buf: &mut impl core::fmt::Write,
+) -> core::fmt::Result {
+    let span = p.span();
+    buf.write_str("{\"rule\":")?;
+    write_json_str(&format!("{:?}", p.rule()), buf)?;
+    write!(
+        buf,
+        ",\"start\":{},\"end\":{},\"text\":",
+        span.start(),
+        span.end()
+    )?;
+    write_json_str(span.as_str(), buf)?;
+    buf.write_str(",\"children\":[")?;
+    for (i, child) in p.inner().enumerate() {
+        if i > 0 {
+            buf.write_char(',')?;
+        }
+        write_json_to(child, buf)?;
+    }
+    buf.write_str("]}")
+}
+
+/// Write `s` as a JSON string literal.
+#[cfg(feature = "json")]
+fn write_json_str(s: &str, buf: &mut impl core::fmt::Write) -> core::fmt::Result {
+    buf.write_char('"')?;
+    for c in s.chars() {
+        match c {
+            '"' => buf.write_str("\\\"")?,
+            '\\' => buf.write_str("\\\\")?,
+            '\n' => buf.write_str("\\n")?,
+            '\r' => buf.write_str("\\r")?,
+            '\t' => buf.write_str("\\t")?,
+            '\u{8}' => buf.write_str("\\b")?,
+            '\u{c}' => buf.write_str("\\f")?,
+            c if (c as u32) < 0x20 => write!(buf, "\\u{:04x}", c as u32)?,
+            c => buf.write_char(c)?,
+        }
+    }
+    buf.write_char('"')
+}
+
 /// A trait to traverse the pair as the root of a tree.
 pub trait PairTree<'i: 'n, 'n, R: RuleType + 'n>: Pair<'i, 'n, R> + Sized {
     /// Level order traversal
     fn iterate_level_order<E>(
         &'n self,
-        f: impl FnMut(
-            &'n (dyn Pair<'i, 'n, R>),
-            usize,
-            &VecDeque<&dyn Pair<'i, 'n, R>>,
-        ) -> Result<(), E>,
+        f: impl FnMut(&'n dyn Pair<'i, 'n, R>, usize, &VecDeque<&dyn Pair<'i, 'n, R>>) -> Result<(), E>,
     ) -> Result<(), E> {
         iterate_level_order(self, f)
     }
     /// Pre-order traversal
     fn iterate_pre_order<E>(
         &'n self,
-        f: impl FnMut(
-            &'n (dyn Pair<'i, 'n, R>),
-            usize,
-            &VecDeque<&dyn Pair<'i, 'n, R>>,
-        ) -> Result<(), E>,
+        f: impl FnMut(&'n dyn Pair<'i, 'n, R>, usize, &VecDeque<&dyn Pair<'i, 'n, R>>) -> Result<(), E>,
     ) -> Result<(), E> {
         iterate_pre_order(self, f)
     }
@@ -177,6 +272,42 @@ pub trait PairTree<'i: 'n, 'n, R: RuleType + 'n>: Pair<'i, 'n, R> + Sized {
         self.write_tree_to(&mut buf)?;
         Ok(buf)
     }
+
+    /// Dump the tree as JSON, for debugging and bug reports.
+    ///
+    /// Each pair is an object with its rule name (formatted with [`Debug`]),
+    /// `start` and `end` byte offsets, matched `text` and an array of `children`:
+    ///
+    /// ```json
+    /// {"rule":"pair","start":0,"end":5,"text":"a = 1","children":[...]}
+    /// ```
+    ///
+    /// Matched text is always sliced at char boundaries of the input [`str`],
+    /// so the output is valid UTF-8, and control characters, quotes and backslashes are escaped.
+    #[cfg(feature = "json")]
+    fn to_json(&'n self) -> String {
+        let mut buf = String::new();
+        write_json_to(self, &mut buf).expect("Writing to a string never fails.");
+        buf
+    }
+
+    /// Flat stream of starts and ends of the pair and all pairs inside it, in document order.
+    ///
+    /// See [`Tokens`].
+    fn tokens(&'n self) -> Tokens<'i, 'n, R> {
+        Tokens::new(self)
+    }
+
+    /// Display the tree with rules, spans and matched text of at most `max_text` characters.
+    ///
+    /// See [`TreeDisplay`].
+    fn display_tree(&'n self, max_text: usize) -> TreeDisplay<'i, 'n, R, Self> {
+        TreeDisplay {
+            pair: self,
+            max_text,
+            _phantom: PhantomData,
+        }
+    }
 }
 
 impl<'i: 'n, 'n, R: RuleType + 'n, T: RuleStruct<'i, R> + Pairs<'i, 'n, R> + Pair<'i, 'n, R>>
@@ -187,7 +318,7 @@ impl<'i: 'n, 'n, R: RuleType + 'n, T: RuleStruct<'i, R> + Pairs<'i, 'n, R> + Pai
 macro_rules! impl_empty {
     ($node:ty, $($tt:tt)*) => {
         impl<'i: 'n, 'n, R: RuleType + 'n, $($tt)*> Pairs<'i, 'n, R> for $node {
-            type Iter = iter::Empty<&'n (dyn Pair<'i, 'n, R>)>;
+            type Iter = iter::Empty<&'n dyn Pair<'i, 'n, R>>;
             type IntoIter = iter::Empty<boxed::Box<dyn Pair<'i, 'n, R> + 'n>>;
 
             fn iter_pairs(&'n self) -> Self::Iter {
@@ -203,7 +334,7 @@ macro_rules! impl_empty {
 macro_rules! impl_forward_inner {
     ($node:ident) => {
         impl<'i: 'n, 'n, R: RuleType + 'n, T: TypedNode<'i, R> + Pairs<'i, 'n, R>> Pairs<'i, 'n, R>
-            for $node<T>
+            for $node<'i, T>
         {
             type Iter = T::Iter;
             type IntoIter = T::IntoIter;
@@ -219,11 +350,19 @@ macro_rules! impl_forward_inner {
 }
 
 impl_empty!(Str<T>, T: StringWrapper);
+impl_empty!(StrSpanned<'i, T>, T: StringWrapper);
+impl_empty!(Presence<T>, T: StringWrapper);
 impl_empty!(Insens<'i, T>, T: StringWrapper);
-impl_empty!(PeekSlice2<START, END>, const START: i32, const END: i32);
-impl_empty!(PeekSlice1<START>, const START: i32);
+impl_empty!(InsensUnicode<'i, T>, T: StringWrapper);
+impl_empty!(Keyword<'i, T, Boundary>, T: StringWrapper, Boundary);
+impl_empty!(PushLiteral<T>, T: StringWrapper);
+impl_empty!(PeekSlice2<'i, START, END>, const START: i32, const END: i32);
+impl_empty!(PeekSlice1<'i, START>, const START: i32);
 impl_forward_inner!(Push);
 impl_empty!(Skip<'i, Strings>, Strings: StringArrayWrapper);
+impl_empty!(SpanOnly<'i, T>, T);
+impl_empty!(CharExcept<Strings>, Strings: StringArrayWrapper);
+impl_empty!(StrChoice<Strings>, Strings: StringArrayWrapper);
 impl_empty!(CharRange<MIN, MAX>, const MIN: char, const MAX: char);
 impl_empty!(Positive<T>, T: TypedNode<'i, R>);
 impl_empty!(Negative<T>, T: TypedNode<'i, R>);
@@ -269,7 +408,7 @@ impl<'i: 'n, 'n, R: RuleType + 'n, T: Pairs<'i, 'n, R> + 'n, const N: usize> Pai
 impl<'i: 'n, 'n, R: RuleType + 'n, T: TypedNode<'i, R> + Pairs<'i, 'n, R>> Pairs<'i, 'n, R>
     for Option<T>
 {
-    type Iter = Maybe<&'n (dyn Pair<'i, 'n, R>), T::Iter>;
+    type Iter = Maybe<&'n dyn Pair<'i, 'n, R>, T::Iter>;
     type IntoIter = Maybe<boxed::Box<dyn Pair<'i, 'n, R> + 'n>, T::IntoIter>;
 
     fn iter_pairs(&'n self) -> Self::Iter {
@@ -286,6 +425,31 @@ impl<'i: 'n, 'n, R: RuleType + 'n, T: TypedNode<'i, R> + Pairs<'i, 'n, R>> Pairs
     }
 }
 
+/// Pairs of the matched expression, or of the synchronization node if recovered.
+impl<'i: 'n, 'n, R: RuleType + 'n, T: Pairs<'i, 'n, R>, Sync: Pairs<'i, 'n, R>> Pairs<'i, 'n, R>
+    for Recover<'i, T, Sync>
+{
+    type Iter =
+        Chain<Maybe<&'n dyn Pair<'i, 'n, R>, T::Iter>, Maybe<&'n dyn Pair<'i, 'n, R>, Sync::Iter>>;
+    type IntoIter = Chain<
+        Maybe<boxed::Box<dyn Pair<'i, 'n, R> + 'n>, T::IntoIter>,
+        Maybe<boxed::Box<dyn Pair<'i, 'n, R> + 'n>, Sync::IntoIter>,
+    >;
+
+    fn iter_pairs(&'n self) -> Self::Iter {
+        match self {
+            Self::Matched(matched) => Maybe(Some(matched.iter_pairs())).chain(Maybe(None)),
+            Self::Recovered { sync, .. } => Maybe(None).chain(Maybe(Some(sync.iter_pairs()))),
+        }
+    }
+    fn into_iter_pairs(self) -> Self::IntoIter {
+        match self {
+            Self::Matched(matched) => Maybe(Some(matched.into_iter_pairs())).chain(Maybe(None)),
+            Self::Recovered { sync, .. } => Maybe(None).chain(Maybe(Some(sync.into_iter_pairs()))),
+        }
+    }
+}
+
 impl<
         'i: 'n,
         'n,
@@ -342,14 +506,76 @@ macro_rules! impl_with_vec {
     };
 }
 
-impl_with_vec!(RepMinMax, const MIN: usize, const MAX: usize,);
-impl_with_vec!(RepMin, const MIN: usize,);
 impl_with_vec!(AtomicRep,);
 
+type SkippedIter<'i, 'n, R, T, Skip, const SKIP: usize> =
+    Chain<<[Skip; SKIP] as Pairs<'i, 'n, R>>::Iter, <T as Pairs<'i, 'n, R>>::Iter>;
+type SkippedIntoIter<'i, 'n, R, T, Skip, const SKIP: usize> =
+    Chain<<[Skip; SKIP] as Pairs<'i, 'n, R>>::IntoIter, <T as Pairs<'i, 'n, R>>::IntoIter>;
+
+macro_rules! impl_with_skipped_vec {
+    ($name:ident, $(const $args:ident : $t:ty,)*) => {
+        impl<
+                'i: 'n,
+                'n,
+                R: RuleType + 'n,
+                T: Pairs<'i, 'n, R> + 'n,
+                Skip: Pairs<'i, 'n, R> + 'n,
+                const SKIP: usize,
+                $(const $args: $t, )*
+            > Pairs<'i, 'n, R> for $name<T, Skip, SKIP, $($args, )*>
+        {
+            type Iter = FlatMap<
+                iter::Zip<core::slice::Iter<'n, [Skip; SKIP]>, core::slice::Iter<'n, T>>,
+                SkippedIter<'i, 'n, R, T, Skip, SKIP>,
+                fn((&'n [Skip; SKIP], &'n T)) -> SkippedIter<'i, 'n, R, T, Skip, SKIP>,
+            >;
+            type IntoIter = FlatMap<
+                iter::Zip<
+                    <RepStorage<[Skip; SKIP]> as IntoIterator>::IntoIter,
+                    <RepStorage<T> as IntoIterator>::IntoIter,
+                >,
+                SkippedIntoIter<'i, 'n, R, T, Skip, SKIP>,
+                fn(([Skip; SKIP], T)) -> SkippedIntoIter<'i, 'n, R, T, Skip, SKIP>,
+            >;
+
+            fn iter_pairs(&'n self) -> Self::Iter {
+                self.skipped
+                    .iter()
+                    .zip(self.content.iter())
+                    .flat_map(|(s, i)| s.iter_pairs().chain(i.iter_pairs()))
+            }
+            fn into_iter_pairs(self) -> Self::IntoIter {
+                self.skipped
+                    .into_iter()
+                    .zip(self.content)
+                    .flat_map(|(s, i)| s.into_iter_pairs().chain(i.into_iter_pairs()))
+            }
+        }
+    };
+}
+
+impl_with_skipped_vec!(RepMinMax, const MIN: usize, const MAX: usize,);
+impl_with_skipped_vec!(RepMin, const MIN: usize,);
+
+impl<'i: 'n, 'n, R: RuleType + 'n, T: Pairs<'i, 'n, R> + 'n, Terminator> Pairs<'i, 'n, R>
+    for RepUntil<'i, T, Terminator>
+{
+    type Iter = FlatMap<core::slice::Iter<'n, T>, T::Iter, fn(&'n T) -> T::Iter>;
+    type IntoIter = FlatMap<vec::IntoIter<T>, T::IntoIter, fn(T) -> T::IntoIter>;
+
+    fn iter_pairs(&'n self) -> Self::Iter {
+        self.content.iter().flat_map(|i| i.iter_pairs())
+    }
+    fn into_iter_pairs(self) -> Self::IntoIter {
+        self.content.into_iter().flat_map(|i| i.into_iter_pairs())
+    }
+}
+
 macro_rules! impl_without_lifetime {
     ($id: ident) => {
         impl<'i: 'n, 'n, R: RuleType + 'n> Pairs<'i, 'n, R> for $id {
-            type Iter = iter::Empty<&'n (dyn Pair<'i, 'n, R>)>;
+            type Iter = iter::Empty<&'n dyn Pair<'i, 'n, R>>;
             type IntoIter = iter::Empty<boxed::Box<dyn Pair<'i, 'n, R> + 'n>>;
 
             fn iter_pairs(&'n self) -> Self::Iter {
@@ -364,7 +590,7 @@ macro_rules! impl_without_lifetime {
 macro_rules! impl_with_lifetime {
     ($id: ident) => {
         impl<'i: 'n, 'n, R: RuleType + 'n> Pairs<'i, 'n, R> for $id<'i> {
-            type Iter = iter::Empty<&'n (dyn Pair<'i, 'n, R>)>;
+            type Iter = iter::Empty<&'n dyn Pair<'i, 'n, R>>;
             type IntoIter = iter::Empty<boxed::Box<dyn Pair<'i, 'n, R> + 'n>>;
 
             fn iter_pairs(&'n self) -> Self::Iter {
@@ -384,6 +610,8 @@ impl_with_lifetime!(PEEK);
 impl_with_lifetime!(PEEK_ALL);
 impl_with_lifetime!(POP);
 impl_with_lifetime!(POP_ALL);
+impl_with_lifetime!(PEEK_INSENS);
+impl_with_lifetime!(POP_INSENS);
 impl_without_lifetime!(DROP);
 
 impl_with_lifetime!(AlwaysFail);
@@ -400,4 +628,166 @@ impl<Item, T: Iterator<Item = Item>> Iterator for Maybe<Item, T> {
             None => None,
         }
     }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match &self.0 {
+            Some(inner) => inner.size_hint(),
+            None => (0, Some(0)),
+        }
+    }
+}
+impl<Item, T: DoubleEndedIterator<Item = Item>> DoubleEndedIterator for Maybe<Item, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        match &mut self.0 {
+            Some(inner) => inner.next_back(),
+            None => None,
+        }
+    }
+}
+impl<Item, T: ExactSizeIterator<Item = Item>> ExactSizeIterator for Maybe<Item, T> {}
+
+/// Debug pairs with bounded depth and bounded number of children.
+///
+/// Created by [`Pairs::debug_limited`].
+///
+/// - Pairs are listed with their rules and spans, and leaf pairs also show their matched strings.
+/// - Pairs deeper than `max_depth` are elided as `… depth limit`.
+/// - Children after the first `max_children` ones are elided as `… +N children`.
+pub struct DebugLimited<'i, 'n, R, T> {
+    pairs: &'n T,
+    max_depth: usize,
+    max_children: usize,
+    _phantom: PhantomData<&'i R>,
+}
+impl<'i: 'n, 'n, R: RuleType + 'n, T: Pairs<'i, 'n, R>> Debug for DebugLimited<'i, 'n, R, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let limits = (self.max_depth, self.max_children);
+        let pairs = self.pairs.iter_pairs().collect::<Vec<_>>();
+        Debug::fmt(&LimitedPairs(pairs.into_iter(), 0, limits), f)
+    }
+}
+
+/// Iterator over starts and ends of pairs in a tree, in document order.
+///
+/// Created by [`PairTree::tokens`].
+///
+/// A pair starts before its first child and ends after its last child,
+/// even if they share the same position.
+/// Children of a pair are only collected when the pair is started,
+/// so the whole tree is never flattened at once.
+pub struct Tokens<'i, 'n, R: RuleType> {
+    /// Remaining pairs on each level, and the pair to end after them.
+    stack: Vec<TokenLevel<'i, 'n, R>>,
+}
+type TokenLevel<'i, 'n, R> = (
+    vec::IntoIter<&'n dyn Pair<'i, 'n, R>>,
+    Option<&'n dyn Pair<'i, 'n, R>>,
+);
+impl<'i: 'n, 'n, R: RuleType + 'n> Tokens<'i, 'n, R> {
+    fn new(root: &'n dyn Pair<'i, 'n, R>) -> Self {
+        Self {
+            stack: vec![(vec![root].into_iter(), None)],
+        }
+    }
+}
+impl<'i: 'n, 'n, R: RuleType + 'n> Iterator for Tokens<'i, 'n, R> {
+    type Item = TokenEvent<'i, R>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (pairs, _) = self.stack.last_mut()?;
+        match pairs.next() {
+            Some(pair) => {
+                self.stack.push((pair.inner(), Some(pair)));
+                Some(TokenEvent::Start {
+                    rule: pair.rule(),
+                    pos: pair.span().start_pos(),
+                })
+            }
+            None => match self.stack.pop() {
+                Some((_, Some(pair))) => Some(TokenEvent::End {
+                    rule: pair.rule(),
+                    pos: pair.span().end_pos(),
+                }),
+                _ => None,
+            },
+        }
+    }
+}
+
+/// Display a tree of pairs, one line for each pair, and children are indented under their parents.
+///
+/// Created by [`PairTree::display_tree`].
+///
+/// Each line contains the rule, the span and the matched text of the pair.
+/// Matched text longer than `max_text` characters is truncated and ends with `…`.
+///
+/// Only rule structs are listed, so the output doesn't depend on generic types inside rules.
+pub struct TreeDisplay<'i, 'n, R, T> {
+    pair: &'n T,
+    max_text: usize,
+    _phantom: PhantomData<&'i R>,
+}
+impl<'i: 'n, 'n, R: RuleType + 'n, T: Pair<'i, 'n, R>> Display for TreeDisplay<'i, 'n, R, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        iterate_pre_order(self.pair, |p, depth, _children| {
+            let span = p.span();
+            let text = span.as_str();
+            let text = match text.char_indices().nth(self.max_text) {
+                Some((end, _)) => format!("{}…", &text[..end]),
+                None => String::from(text),
+            };
+            writeln!(
+                f,
+                "{}{:?} {}..{} {:?}",
+                "    ".repeat(depth),
+                p.rule(),
+                span.start(),
+                span.end(),
+                text
+            )
+        })
+    }
+}
+
+/// Pairs at given depth.
+struct LimitedPairs<'i, 'n, R>(
+    vec::IntoIter<&'n dyn Pair<'i, 'n, R>>,
+    usize,
+    (usize, usize),
+);
+impl<'i: 'n, 'n, R: RuleType + 'n> Debug for LimitedPairs<'i, 'n, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self(pairs, depth, (max_depth, max_children)) = self;
+        if depth >= max_depth {
+            return f.write_str("… depth limit");
+        }
+        let mut list = f.debug_list();
+        let mut pairs = pairs.clone();
+        for pair in pairs.by_ref().take(*max_children) {
+            list.entry(&LimitedPair(pair, *depth, (*max_depth, *max_children)));
+        }
+        let rest = pairs.len();
+        if rest > 0 {
+            list.entry(&format_args!("… +{} children", rest));
+        }
+        list.finish()
+    }
+}
+
+/// A pair at given depth.
+struct LimitedPair<'i, 'n, R>(&'n dyn Pair<'i, 'n, R>, usize, (usize, usize));
+impl<'i: 'n, 'n, R: RuleType + 'n> Debug for LimitedPair<'i, 'n, R> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        let Self(pair, depth, limits) = self;
+        let span = pair.span();
+        let name = format!("{:?}", pair.rule());
+        let mut s = f.debug_struct(&name);
+        s.field("span", &(span.start()..span.end()));
+        let children = pair.inner();
+        if children.len() == 0 {
+            s.field("str", &span.as_str());
+        } else {
+            s.field("inner", &LimitedPairs(children, depth + 1, *limits));
+        }
+        s.finish()
+    }
 }
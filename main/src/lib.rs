@@ -13,10 +13,31 @@
 //!
 //! Features:
 //!
-//! - `std`: include [`std`] support.
+//! - `std`: include [`std`] support, and module `testing` with assertion helpers for grammar test suites.
 //!
 //!   Without this feature, we'll use [core] and [alloc].
 //!
+//! - `serde`: implement `serde::Serialize` for rule structs, generics and [`Span`].
+//!
+//! - `compact_debug`: [`Debug`](core::fmt::Debug) of repetitions shows at most [`predefined_node::COMPACT_DEBUG_MAX_ELEMENTS`] elements.
+//!
+//!   See also [`iterators::Pairs::debug_limited`] for bounded output of a whole tree.
+//!
+//! - `json`: [`iterators::PairTree::to_json`] dumps a tree with rules, spans and matched text as JSON.
+//!
+//! - `smallvec`: [`predefined_node::RepMin`] and [`predefined_node::RepMinMax`] keep up to 2 elements inline,
+//!   see [`predefined_node::RepStorage`]. Most repetitions are short, so this saves allocations.
+//!
+//!   As `smallvec::SmallVec` has a [`Drop`] implementation, a tree must be dropped before the input it borrows, even if the tree is no longer used.
+//!
+//! - `miette` (implies `std`): [`error::ParseFailure`] implements `miette::Diagnostic`,
+//!   labelling the failing range with the rules attempted there.
+//!
+//! Parsing keeps no global state.
+//! Each call to [`ParsableTypedNode`] creates its own [`Stack`] and [`tracker::Tracker`],
+//! so the same generated types can parse different inputs on multiple threads at once.
+//! Typed nodes only borrow the input, and they are [`Send`] and [`Sync`].
+//!
 //! It's suggested that you use [pest_typed_derive](https://crates.io/pest_typed_derive) to automatically generate types from your grammar.
 //!
 //! And though we have a lot of macros in this crate, only some of them are designed for usage outside the crate. They're listed below:
@@ -31,6 +52,7 @@
 //!     - Silent rule: [silent_rule!].
 //!     - End-Of-Input rule: [rule_eoi!].
 //!     - Tag: [tag!].
+//! - Testing (with feature `std`): `assert_parses!`, `assert_rejects!`, `assert_parses_prefix!` and `assert_tree_snapshot!`.
 
 #![no_std]
 #![warn(
@@ -47,22 +69,34 @@ extern crate std;
 
 pub use pest::RuleType;
 use typed_node::NeverFailedParsableTypedNode;
-pub use typed_node::{NeverFailedTypedNode, ParsableTypedNode, RuleStruct, Spanned, TypedNode};
+pub use typed_node::{
+    ByContent, ContentEq, MatchedText, NeverFailedTypedNode, ParsableTypedNode, Root, RuleStruct,
+    Spanned, TypedNode,
+};
 pub use wrapper::{
     BoundWrapper, ConstantStorage, RuleWrapper, Storage, StringArrayWrapper, StringWrapper,
     TypeWrapper,
 };
 
 pub mod choices;
+pub mod error;
 mod formatter;
+pub mod incremental;
 pub mod iterators;
-mod pratt;
+pub mod pratt;
 pub mod predefined_node;
+pub mod random;
 pub mod re_exported;
 pub mod rule;
 pub mod sequence;
+#[cfg(feature = "serde")]
+mod serialization;
+mod stack;
+#[cfg(feature = "std")]
+pub mod testing;
 pub mod tracker;
 mod typed_node;
+pub mod visitor;
 mod wrapper;
 pub use alloc::boxed::Box;
 
@@ -71,20 +105,23 @@ mod parser_state;
 mod position;
 mod span;
 
-pub use pest::error;
 pub use pest::Stack;
 pub use position::Position;
 pub use span::{merge_spans, Span};
+pub use stack::{StackExt, StackGuard};
 
 // Re-export unicode.
 pub use pest::unicode;
 
+// Re-export serde, so that generated codes don't need to depend on it.
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+pub use serde;
+
 /// A trait with a single method that parses strings into typed concrete syntax tree.
 pub trait TypedParser<R: RuleType> {
     /// Try to parse a `&str` into a tree starting from T.
-    fn try_parse<'i, T: ParsableTypedNode<'i, R>>(
-        input: &'i str,
-    ) -> Result<T, Box<error::Error<R>>> {
+    fn try_parse<'i, T: ParsableTypedNode<'i, R>>(input: &'i str) -> error::ParseResult<T, R> {
         T::try_parse(input)
     }
     /// Parse a `&str` into a tree starting from T.
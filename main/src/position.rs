@@ -13,7 +13,7 @@
 use core::cmp::Ordering;
 use core::fmt::{self, Write};
 use core::hash::{Hash, Hasher};
-use core::ops::Range;
+use core::ops::RangeInclusive;
 use core::ptr;
 use core::str;
 
@@ -332,10 +332,10 @@ impl<'i> Position<'i> {
                         }
                     }
                 }
-                [s1, s2, s3] if !s1.is_empty() && !s2.is_empty() && s3.is_empty() => {
+                [s1, s2, s3] if !s1.is_empty() && !s2.is_empty() && !s3.is_empty() => {
                     let b1 = s1.as_bytes()[0];
                     let b2 = s2.as_bytes()[0];
-                    let b3 = s2.as_bytes()[0];
+                    let b3 = s3.as_bytes()[0];
                     let miter =
                         memchr::memchr3_iter(b1, b2, b3, &self.input.as_bytes()[self.pos..]);
                     for from in miter {
@@ -442,12 +442,12 @@ impl<'i> Position<'i> {
         }
     }
 
-    /// Matches `char` `range` from the `Position` and returns `true` if a match was made or `false`
+    /// Matches `char` `range` (inclusive of both ends) from the `Position` and returns `true` if a match was made or `false`
     /// otherwise. If no match was made, `pos` will not be updated.
     #[inline]
-    pub(crate) fn match_range(&mut self, range: Range<char>) -> bool {
+    pub(crate) fn match_range(&mut self, range: RangeInclusive<char>) -> bool {
         if let Some(c) = self.input[self.pos..].chars().next() {
-            if range.start <= c && c <= range.end {
+            if range.contains(&c) {
                 self.pos += c.len_utf8();
                 return true;
             }
@@ -502,8 +502,8 @@ impl<'i> PartialOrd for Position<'i> {
 
 impl<'i> Ord for Position<'i> {
     fn cmp(&self, other: &Position<'i>) -> Ordering {
-        assert_eq!(
-            self.input, other.input,
+        assert!(
+            ptr::eq(self.input, other.input),
             "cannot compare positions from different strs"
         );
         self.pos.cmp(&other.pos)
@@ -641,17 +641,26 @@ mod tests {
         test_pos = pos;
         assert!(!test_pos.skip_until(&["z"]));
         assert_eq!(test_pos.pos(), 5);
+
+        test_pos = pos;
+        assert!(test_pos.skip_until(&["z", "y", "c"]));
+        assert_eq!(test_pos.pos(), 4);
+
+        test_pos = pos;
+        assert!(test_pos.skip_until(&["z", "c", ""]));
+        assert_eq!(test_pos.pos(), 0);
     }
 
     #[test]
     fn match_range() {
         let input = "b";
 
-        assert!(Position::new(input, 0).unwrap().match_range('a'..'c'));
-        assert!(Position::new(input, 0).unwrap().match_range('b'..'b'));
-        assert!(!Position::new(input, 0).unwrap().match_range('a'..'a'));
-        assert!(!Position::new(input, 0).unwrap().match_range('c'..'c'));
-        assert!(Position::new(input, 0).unwrap().match_range('a'..'嗨'));
+        assert!(Position::new(input, 0).unwrap().match_range('a'..='c'));
+        assert!(Position::new(input, 0).unwrap().match_range('b'..='b'));
+        assert!(Position::new(input, 0).unwrap().match_range('a'..='b'));
+        assert!(!Position::new(input, 0).unwrap().match_range('a'..='a'));
+        assert!(!Position::new(input, 0).unwrap().match_range('c'..='c'));
+        assert!(Position::new(input, 0).unwrap().match_range('a'..='嗨'));
     }
 
     #[test]
@@ -8,16 +8,353 @@
 // modified, or distributed except according to those terms.
 
 //! For Pratt Parser. See [`pest::pratt_parser`].
+//!
+//! [`PrattParser`] folds typed expressions shaped like
+//! `prefix* ~ primary ~ postfix* ~ (infix ~ prefix* ~ primary ~ postfix*)*`,
+//! the same shape [`pest::pratt_parser::PrattParser`] expects,
+//! with precedence given by functions over typed operator nodes.
+//!
+//! ```ignore
+//! let pratt = PrattParser::new()
+//!     .prefix(|_: &pairs::prefix<'_>| 3)
+//!     .infix(|op: &pairs::infix<'_>| match op.deref() {
+//!         Choice2::_0(_add) => (1, Assoc::Left),
+//!         Choice2::_1(_pow) => (2, Assoc::Right),
+//!     })
+//!     .postfix(|_: &pairs::postfix<'_>| 4);
+//! let value = pratt
+//!     .map_primary(|primary| ...)
+//!     .map_prefix(|op, rhs| ...)
+//!     .map_infix(|lhs, op, rhs| ...)
+//!     .map_postfix(|lhs, op| ...)
+//!     .parse(expr.deref());
+//! ```
 
-use crate::{RuleType, TypedNode};
+use crate::{
+    predefined_node::{RepMin, Skipped},
+    sequence::Seq4,
+};
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::{
+    fmt::{self, Debug, Formatter},
+    iter::Peekable,
+};
 
-/// Pratt parser for those nodes with prefix, infix and postfix.
-pub trait PrattPrefixInfixPostfix<
-    'i,
-    R: RuleType,
-    Prefix: TypedNode<'i, R>,
-    InFix: TypedNode<'i, R>,
-    Postfix: TypedNode<'i, R>,
->
+/// Precedence of an operator.
+///
+/// Operators with higher precedence bind tighter. Precedences should be greater than 0.
+pub type Prec = u32;
+
+/// Associativity of an infix operator.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum Assoc {
+    /// Left associative, `a - b - c` is `(a - b) - c`.
+    Left,
+    /// Right associative, `a ^ b ^ c` is `a ^ (b ^ c)`.
+    Right,
+}
+
+/// An operand or an operator in a typed expression.
+pub enum Token<'n, Prefix, Primary, Infix, Postfix> {
+    /// Prefix operator.
+    Prefix(&'n Prefix),
+    /// Operand.
+    Primary(&'n Primary),
+    /// Infix operator.
+    Infix(&'n Infix),
+    /// Postfix operator.
+    Postfix(&'n Postfix),
+}
+impl<Prefix, Primary, Infix, Postfix> Clone for Token<'_, Prefix, Primary, Infix, Postfix> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<Prefix, Primary, Infix, Postfix> Copy for Token<'_, Prefix, Primary, Infix, Postfix> {}
+impl<Prefix: Debug, Primary: Debug, Infix: Debug, Postfix: Debug> Debug
+    for Token<'_, Prefix, Primary, Infix, Postfix>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Prefix(op) => f.debug_tuple("Prefix").field(op).finish(),
+            Self::Primary(primary) => f.debug_tuple("Primary").field(primary).finish(),
+            Self::Infix(op) => f.debug_tuple("Infix").field(op).finish(),
+            Self::Postfix(op) => f.debug_tuple("Postfix").field(op).finish(),
+        }
+    }
+}
+
+/// Typed expressions that can be folded by [`PrattParser`].
+///
+/// Implemented for sequences generated from
+/// `prefix* ~ primary ~ postfix* ~ (infix ~ prefix* ~ primary ~ postfix*)*`.
+/// Other shapes can implement it by listing their tokens in positional order.
+pub trait PrattSequence {
+    /// Prefix operator.
+    type Prefix;
+    /// Operand.
+    type Primary;
+    /// Infix operator.
+    type Infix;
+    /// Postfix operator.
+    type Postfix;
+    /// Operands and operators in positional order.
+    #[allow(clippy::type_complexity)]
+    fn tokens(&self) -> Vec<Token<'_, Self::Prefix, Self::Primary, Self::Infix, Self::Postfix>>;
+}
+
+impl<Prefix, Primary, Infix, Postfix, Skip, const SKIP: usize> PrattSequence
+    for Seq4<
+        Skipped<RepMin<Prefix, Skip, SKIP, 0>, Skip, SKIP>,
+        Skipped<Primary, Skip, SKIP>,
+        Skipped<RepMin<Postfix, Skip, SKIP, 0>, Skip, SKIP>,
+        Skipped<
+            RepMin<
+                Seq4<
+                    Skipped<Infix, Skip, SKIP>,
+                    Skipped<RepMin<Prefix, Skip, SKIP, 0>, Skip, SKIP>,
+                    Skipped<Primary, Skip, SKIP>,
+                    Skipped<RepMin<Postfix, Skip, SKIP, 0>, Skip, SKIP>,
+                >,
+                Skip,
+                SKIP,
+                0,
+            >,
+            Skip,
+            SKIP,
+        >,
+    >
 {
+    type Prefix = Prefix;
+    type Primary = Primary;
+    type Infix = Infix;
+    type Postfix = Postfix;
+
+    fn tokens(&self) -> Vec<Token<'_, Prefix, Primary, Infix, Postfix>> {
+        fn push_operand<'n, Prefix, Primary, Infix, Postfix>(
+            tokens: &mut Vec<Token<'n, Prefix, Primary, Infix, Postfix>>,
+            prefixes: &'n [Prefix],
+            primary: &'n Primary,
+            postfixes: &'n [Postfix],
+        ) {
+            tokens.extend(prefixes.iter().map(Token::Prefix));
+            tokens.push(Token::Primary(primary));
+            tokens.extend(postfixes.iter().map(Token::Postfix));
+        }
+        let (prefixes, primary, postfixes, rest) = self.get_matched();
+        let mut tokens = vec![];
+        push_operand(
+            &mut tokens,
+            prefixes.as_slice(),
+            primary,
+            postfixes.as_slice(),
+        );
+        for item in rest.iter_matched() {
+            let (infix, prefixes, primary, postfixes) = item.get_matched();
+            tokens.push(Token::Infix(infix));
+            push_operand(
+                &mut tokens,
+                prefixes.as_slice(),
+                primary,
+                postfixes.as_slice(),
+            );
+        }
+        tokens
+    }
+}
+
+/// Precedences of typed operators.
+///
+/// See [`PrattParser::map_primary`] for how an expression is folded.
+#[allow(clippy::type_complexity)]
+pub struct PrattParser<Prefix, Infix, Postfix> {
+    prefix: Option<fn(&Prefix) -> Prec>,
+    infix: Option<fn(&Infix) -> (Prec, Assoc)>,
+    postfix: Option<fn(&Postfix) -> Prec>,
+}
+impl<Prefix, Infix, Postfix> Default for PrattParser<Prefix, Infix, Postfix> {
+    fn default() -> Self {
+        Self {
+            prefix: None,
+            infix: None,
+            postfix: None,
+        }
+    }
+}
+impl<Prefix, Infix, Postfix> PrattParser<Prefix, Infix, Postfix> {
+    /// Create a parser without any operators.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Set precedences of prefix operators.
+    pub fn prefix(mut self, prec: fn(&Prefix) -> Prec) -> Self {
+        self.prefix = Some(prec);
+        self
+    }
+    /// Set precedences and associativities of infix operators.
+    pub fn infix(mut self, prec: fn(&Infix) -> (Prec, Assoc)) -> Self {
+        self.infix = Some(prec);
+        self
+    }
+    /// Set precedences of postfix operators.
+    pub fn postfix(mut self, prec: fn(&Postfix) -> Prec) -> Self {
+        self.postfix = Some(prec);
+        self
+    }
+    /// Map operands with `primary`, and then set mapping functions for operators
+    /// on the returned [`PrattParserMap`].
+    pub fn map_primary<'p, Primary, T, X>(
+        &'p self,
+        primary: X,
+    ) -> PrattParserMap<'p, Prefix, Primary, Infix, Postfix, T>
+    where
+        X: FnMut(&Primary) -> T + 'p,
+    {
+        PrattParserMap {
+            pratt: self,
+            primary: Box::new(primary),
+            prefix: None,
+            infix: None,
+            postfix: None,
+        }
+    }
+}
+
+/// Mapping functions that fold a typed expression into `T`.
+///
+/// Created by [`PrattParser::map_primary`].
+#[allow(clippy::type_complexity)]
+pub struct PrattParserMap<'p, Prefix, Primary, Infix, Postfix, T> {
+    pratt: &'p PrattParser<Prefix, Infix, Postfix>,
+    primary: Box<dyn FnMut(&Primary) -> T + 'p>,
+    prefix: Option<Box<dyn FnMut(&Prefix, T) -> T + 'p>>,
+    infix: Option<Box<dyn FnMut(T, &Infix, T) -> T + 'p>>,
+    postfix: Option<Box<dyn FnMut(T, &Postfix) -> T + 'p>>,
+}
+impl<'p, Prefix, Primary, Infix, Postfix, T>
+    PrattParserMap<'p, Prefix, Primary, Infix, Postfix, T>
+{
+    /// Map a prefix operator and its operand.
+    pub fn map_prefix<X>(mut self, prefix: X) -> Self
+    where
+        X: FnMut(&Prefix, T) -> T + 'p,
+    {
+        self.prefix = Some(Box::new(prefix));
+        self
+    }
+    /// Map an infix operator and its operands.
+    pub fn map_infix<X>(mut self, infix: X) -> Self
+    where
+        X: FnMut(T, &Infix, T) -> T + 'p,
+    {
+        self.infix = Some(Box::new(infix));
+        self
+    }
+    /// Map a postfix operator and its operand.
+    pub fn map_postfix<X>(mut self, postfix: X) -> Self
+    where
+        X: FnMut(T, &Postfix) -> T + 'p,
+    {
+        self.postfix = Some(Box::new(postfix));
+        self
+    }
+    /// Fold `sequence`.
+    ///
+    /// # Panics
+    ///
+    /// If an operator occurs without its precedence or mapping function set,
+    /// or if tokens of `sequence` are not well ordered.
+    pub fn parse<S>(&mut self, sequence: &S) -> T
+    where
+        S: PrattSequence<Prefix = Prefix, Primary = Primary, Infix = Infix, Postfix = Postfix>
+            + ?Sized,
+    {
+        let mut tokens = sequence.tokens().into_iter().peekable();
+        let res = self.expr(&mut tokens, 0);
+        assert!(tokens.next().is_none(), "Pratt parser left tokens unused.");
+        res
+    }
+
+    fn expr<'n>(
+        &mut self,
+        tokens: &mut Peekable<impl Iterator<Item = Token<'n, Prefix, Primary, Infix, Postfix>>>,
+        rbp: Prec,
+    ) -> T
+    where
+        Prefix: 'n,
+        Primary: 'n,
+        Infix: 'n,
+        Postfix: 'n,
+    {
+        let mut lhs = self.nud(tokens);
+        while rbp < self.lbp(tokens.peek()) {
+            lhs = self.led(tokens, lhs);
+        }
+        lhs
+    }
+
+    fn nud<'n>(
+        &mut self,
+        tokens: &mut Peekable<impl Iterator<Item = Token<'n, Prefix, Primary, Infix, Postfix>>>,
+    ) -> T
+    where
+        Prefix: 'n,
+        Primary: 'n,
+        Infix: 'n,
+        Postfix: 'n,
+    {
+        match tokens.next() {
+            Some(Token::Prefix(op)) => {
+                let prec = self.pratt.prefix.expect("Prefix precedence is not set.")(op);
+                let rhs = self.expr(tokens, prec.saturating_sub(1));
+                let prefix = self.prefix.as_mut().expect("Prefix mapping is not set.");
+                prefix(op, rhs)
+            }
+            Some(Token::Primary(primary)) => (self.primary)(primary),
+            _ => panic!("Expected a prefix operator or an operand."),
+        }
+    }
+
+    fn led<'n>(
+        &mut self,
+        tokens: &mut Peekable<impl Iterator<Item = Token<'n, Prefix, Primary, Infix, Postfix>>>,
+        lhs: T,
+    ) -> T
+    where
+        Prefix: 'n,
+        Primary: 'n,
+        Infix: 'n,
+        Postfix: 'n,
+    {
+        match tokens.next() {
+            Some(Token::Infix(op)) => {
+                let (prec, assoc) = self.pratt.infix.expect("Infix precedence is not set.")(op);
+                let rbp = match assoc {
+                    Assoc::Left => prec,
+                    Assoc::Right => prec.saturating_sub(1),
+                };
+                let rhs = self.expr(tokens, rbp);
+                let infix = self.infix.as_mut().expect("Infix mapping is not set.");
+                infix(lhs, op, rhs)
+            }
+            Some(Token::Postfix(op)) => {
+                let postfix = self.postfix.as_mut().expect("Postfix mapping is not set.");
+                postfix(lhs, op)
+            }
+            _ => panic!("Expected an infix or postfix operator."),
+        }
+    }
+
+    fn lbp(&self, token: Option<&Token<'_, Prefix, Primary, Infix, Postfix>>) -> Prec {
+        match token {
+            Some(Token::Infix(op)) => self.pratt.infix.expect("Infix precedence is not set.")(op).0,
+            Some(Token::Postfix(op)) => {
+                self.pratt.postfix.expect("Postfix precedence is not set.")(op)
+            }
+            Some(Token::Prefix(_) | Token::Primary(_)) => {
+                panic!("Expected an infix or postfix operator.")
+            }
+            None => 0,
+        }
+    }
 }
@@ -0,0 +1,212 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Implementations of [`ContentEq`] for predefined nodes.
+//!
+//! - Terminal nodes compare the string or character they matched.
+//! - Nodes that can't carry content, such as [`SOI`] and predicates, are always equal.
+//! - [`Skipped`] and repetitions compare matched nodes only.
+//!
+//! [`ContentEq::content_eq_ignore_case`] compares the texts matched by [`Insens`], [`InsensUnicode`], [`Keyword`],
+//! [`PEEK_INSENS`] and [`POP_INSENS`] ignoring case, and forwards to children elsewhere.
+
+use super::{
+    AlwaysFail, AtomicRep, CharExcept, CharRange, Empty, Insens, InsensUnicode, Keyword, Negative,
+    PeekSlice1, PeekSlice2, Positive, Presence, Push, PushLiteral, Recover, RepMin, RepMinMax,
+    RepUntil, Skip, SkipChar, Skipped, SpanOnly, Str, StrChoice, StrSpanned, ANY, DROP, EOI,
+    NEWLINE, PEEK, PEEK_ALL, PEEK_INSENS, POP, POP_ALL, POP_INSENS, SOI,
+};
+use crate::{ContentEq, StringArrayWrapper, StringWrapper};
+use core::hash::{Hash, Hasher};
+
+/// Whether two strings are the same ignoring case.
+fn eq_ignore_case(lhs: &str, rhs: &str) -> bool {
+    lhs.chars()
+        .flat_map(char::to_lowercase)
+        .eq(rhs.chars().flat_map(char::to_lowercase))
+}
+
+/// Implement [`ContentEq`] by comparing and hashing a key.
+///
+/// Keys are compared by `$ignore_case` in [`ContentEq::content_eq_ignore_case`] if given.
+macro_rules! impl_content_by {
+    ($name:ty, ($($params:tt)*), $node:ident => $key:expr $(, ignore_case => $ignore_case:path)?) => {
+        impl<$($params)*> ContentEq for $name {
+            fn content_eq(&self, other: &Self) -> bool {
+                let lhs = {
+                    let $node = self;
+                    $key
+                };
+                let $node = other;
+                lhs == $key
+            }
+            fn content_hash<H: Hasher>(&self, state: &mut H) {
+                let $node = self;
+                $key.hash(state)
+            }
+            $(
+                fn content_eq_ignore_case(&self, other: &Self) -> bool {
+                    let lhs = {
+                        let $node = self;
+                        $key
+                    };
+                    let $node = other;
+                    $ignore_case(lhs, $key)
+                }
+            )?
+        }
+    };
+}
+
+impl_content_by!(Str<T>, (T: StringWrapper + 'static), _node => ());
+impl_content_by!(StrSpanned<'i, T>, ('i, T: StringWrapper + 'static), _node => ());
+impl_content_by!(Presence<T>, (T: StringWrapper + 'static), node => node.present());
+impl_content_by!(Insens<'i, T>, ('i, T: StringWrapper), node => node.content, ignore_case => eq_ignore_case);
+impl_content_by!(InsensUnicode<'i, T>, ('i, T: StringWrapper), node => node.content, ignore_case => eq_ignore_case);
+impl_content_by!(Keyword<'i, T, Boundary>, ('i, T: StringWrapper, Boundary), node => node.content, ignore_case => eq_ignore_case);
+impl_content_by!(Skip<'i, Strings>, ('i, Strings: StringArrayWrapper), node => node.span.as_str());
+impl_content_by!(SpanOnly<'i, T>, ('i, T), node => node.span.as_str());
+impl_content_by!(SkipChar<'i, N>, ('i, const N: usize), node => node.span.as_str());
+impl_content_by!(PEEK_ALL<'i>, ('i), node => node.span.as_str());
+impl_content_by!(PEEK<'i>, ('i), node => node.span.as_str());
+impl_content_by!(PeekSlice2<'i, START, END>, ('i, const START: i32, const END: i32), node => node.span.as_str());
+impl_content_by!(PeekSlice1<'i, START>, ('i, const START: i32), node => node.span.as_str());
+impl_content_by!(POP<'i>, ('i), node => node.span.as_str());
+impl_content_by!(POP_ALL<'i>, ('i), node => node.span.as_str());
+impl_content_by!(PEEK_INSENS<'i>, ('i), node => node.span.as_str(), ignore_case => eq_ignore_case);
+impl_content_by!(POP_INSENS<'i>, ('i), node => node.span.as_str(), ignore_case => eq_ignore_case);
+impl_content_by!(NEWLINE, (), node => &node.content);
+impl_content_by!(CharExcept<Strings>, (Strings: StringArrayWrapper), node => node.content);
+impl_content_by!(StrChoice<Strings>, (Strings: StringArrayWrapper), node => node.index);
+impl_content_by!(CharRange<MIN, MAX>, (const MIN: char, const MAX: char), node => node.content);
+impl_content_by!(ANY, (), node => node.content);
+
+impl_content_by!(Positive<N>, (N), _node => ());
+impl_content_by!(Negative<T>, (T), _node => ());
+impl_content_by!(SOI, (), _node => ());
+impl_content_by!(EOI, (), _node => ());
+impl_content_by!(DROP, (), _node => ());
+impl_content_by!(PushLiteral<T>, (T: StringWrapper + 'static), _node => ());
+impl_content_by!(AlwaysFail<'i>, ('i), _node => ());
+impl_content_by!(Empty<'i>, ('i), _node => ());
+
+impl<'i, T: ContentEq> ContentEq for Push<'i, T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.content.content_eq(&other.content)
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.content.content_hash(state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.content.content_eq_ignore_case(&other.content)
+    }
+}
+
+impl<'i, T: ContentEq, Sync: ContentEq> ContentEq for Recover<'i, T, Sync> {
+    fn content_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Matched(lhs), Self::Matched(rhs)) => lhs.content_eq(rhs),
+            (
+                Self::Recovered { span, sync },
+                Self::Recovered {
+                    span: other_span,
+                    sync: other_sync,
+                },
+            ) => span.as_str() == other_span.as_str() && sync.content_eq(other_sync),
+            _ => false,
+        }
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_recovered().hash(state);
+        match self {
+            Self::Matched(matched) => matched.content_hash(state),
+            Self::Recovered { span, sync } => {
+                span.as_str().hash(state);
+                sync.content_hash(state);
+            }
+        }
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Matched(lhs), Self::Matched(rhs)) => lhs.content_eq_ignore_case(rhs),
+            (
+                Self::Recovered { span, sync },
+                Self::Recovered {
+                    span: other_span,
+                    sync: other_sync,
+                },
+            ) => span.as_str() == other_span.as_str() && sync.content_eq_ignore_case(other_sync),
+            _ => false,
+        }
+    }
+}
+
+impl<T: ContentEq, Skip, const SKIP: usize> ContentEq for Skipped<T, Skip, SKIP> {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.matched.content_eq(&other.matched)
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.matched.content_hash(state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.matched.content_eq_ignore_case(&other.matched)
+    }
+}
+
+impl<T: ContentEq> ContentEq for AtomicRep<T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.content[..].content_eq(&other.content[..])
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.content[..].content_hash(state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.content[..].content_eq_ignore_case(&other.content[..])
+    }
+}
+
+impl<'i, T: ContentEq, Terminator> ContentEq for RepUntil<'i, T, Terminator> {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.content[..].content_eq(&other.content[..])
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.content[..].content_hash(state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.content[..].content_eq_ignore_case(&other.content[..])
+    }
+}
+
+impl<T: ContentEq, Skip, const SKIP: usize, const MIN: usize> ContentEq
+    for RepMin<T, Skip, SKIP, MIN>
+{
+    fn content_eq(&self, other: &Self) -> bool {
+        self.content[..].content_eq(&other.content[..])
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.content[..].content_hash(state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.content[..].content_eq_ignore_case(&other.content[..])
+    }
+}
+
+impl<T: ContentEq, Skip, const SKIP: usize, const MIN: usize, const MAX: usize> ContentEq
+    for RepMinMax<T, Skip, SKIP, MIN, MAX>
+{
+    fn content_eq(&self, other: &Self) -> bool {
+        self.content[..].content_eq(&other.content[..])
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.content[..].content_hash(state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.content[..].content_eq_ignore_case(&other.content[..])
+    }
+}
@@ -0,0 +1,129 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Implementations of [`MatchedText`] and [`Display`] for predefined nodes.
+//!
+//! - Terminal nodes write the string or character they matched.
+//! - Nodes that match nothing, such as [`SOI`] and predicates, write nothing.
+//! - [`Skipped`] writes the skipped contents and then the matched node.
+
+use super::{
+    AlwaysFail, AtomicRep, CharExcept, CharRange, Empty, Insens, InsensUnicode, Keyword, Negative,
+    NewLineType, PeekSlice1, PeekSlice2, Positive, Presence, Push, PushLiteral, Recover, RepMin,
+    RepMinMax, RepUntil, Skip, SkipChar, Skipped, SpanOnly, Str, StrChoice, StrSpanned, ANY, DROP,
+    EOI, NEWLINE, PEEK, PEEK_ALL, PEEK_INSENS, POP, POP_ALL, POP_INSENS, SOI,
+};
+use crate::{MatchedText, StringArrayWrapper, StringWrapper};
+use core::fmt::{self, Display, Formatter, Write};
+
+/// Implement [`MatchedText`] with given body, and [`Display`] with [`MatchedText`].
+macro_rules! impl_matched_text {
+    ($name:ty, ($($params:tt)*), $self:ident, $f:ident => $body:expr) => {
+        impl<$($params)*> MatchedText for $name {
+            fn fmt_matched(&$self, $f: &mut Formatter<'_>) -> fmt::Result {
+                $body
+            }
+        }
+        impl<$($params)*> Display for $name {
+            fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+                self.fmt_matched(f)
+            }
+        }
+    };
+}
+
+impl_matched_text!(Str<T>, (T: StringWrapper + 'static), self, f => f.write_str(T::CONTENT));
+impl_matched_text!(StrSpanned<'i, T>, ('i, T: StringWrapper + 'static), self, f => f.write_str(T::CONTENT));
+impl_matched_text!(Presence<T>, (T: StringWrapper + 'static), self, f => match self.present() {
+    true => f.write_str(T::CONTENT),
+    false => Ok(()),
+});
+impl_matched_text!(Insens<'i, T>, ('i, T: StringWrapper), self, f => f.write_str(self.content));
+impl_matched_text!(InsensUnicode<'i, T>, ('i, T: StringWrapper), self, f => f.write_str(self.content));
+impl_matched_text!(Keyword<'i, T, Boundary>, ('i, T: StringWrapper, Boundary), self, f => f.write_str(self.content));
+impl_matched_text!(Skip<'i, Strings>, ('i, Strings: StringArrayWrapper), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(SpanOnly<'i, T>, ('i, T), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(SkipChar<'i, N>, ('i, const N: usize), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(PEEK_ALL<'i>, ('i), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(PEEK<'i>, ('i), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(PeekSlice2<'i, START, END>, ('i, const START: i32, const END: i32), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(PeekSlice1<'i, START>, ('i, const START: i32), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(POP<'i>, ('i), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(POP_ALL<'i>, ('i), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(PEEK_INSENS<'i>, ('i), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(POP_INSENS<'i>, ('i), self, f => f.write_str(self.span.as_str()));
+impl_matched_text!(NEWLINE, (), self, f => f.write_str(match self.content {
+    NewLineType::CRLF => "\r\n",
+    NewLineType::LF => "\n",
+    NewLineType::CR => "\r",
+}));
+impl_matched_text!(CharExcept<Strings>, (Strings: StringArrayWrapper), self, f => f.write_char(self.content));
+impl_matched_text!(StrChoice<Strings>, (Strings: StringArrayWrapper), self, f => f.write_str(self.get()));
+impl_matched_text!(CharRange<MIN, MAX>, (const MIN: char, const MAX: char), self, f => f.write_char(self.content));
+impl_matched_text!(ANY, (), self, f => f.write_char(self.content));
+
+impl_matched_text!(Push<'i, T>, ('i, T: MatchedText), self, f => self.content.fmt_matched(f));
+impl_matched_text!(Recover<'i, T, Sync>, ('i, T: MatchedText, Sync: MatchedText), self, f => match self {
+    Recover::Matched(matched) => matched.fmt_matched(f),
+    Recover::Recovered { span, sync } => {
+        f.write_str(span.as_str())?;
+        sync.fmt_matched(f)
+    }
+});
+impl_matched_text!(
+    Skipped<T, Skip, SKIP>,
+    (T: MatchedText, Skip: MatchedText, const SKIP: usize),
+    self,
+    f => {
+        for skipped in &self.skipped {
+            skipped.fmt_matched(f)?;
+        }
+        self.matched.fmt_matched(f)
+    }
+);
+impl_matched_text!(AtomicRep<T>, (T: MatchedText), self, f => {
+    self.content.iter().try_for_each(|c| c.fmt_matched(f))
+});
+impl_matched_text!(RepUntil<'i, T, Terminator>, ('i, T: MatchedText, Terminator), self, f => {
+    self.content.iter().try_for_each(|c| c.fmt_matched(f))
+});
+fn fmt_rep<T: MatchedText, Skip: MatchedText, const SKIP: usize>(
+    content: &[T],
+    skipped: &[[Skip; SKIP]],
+    f: &mut Formatter<'_>,
+) -> fmt::Result {
+    for (skipped, matched) in skipped.iter().zip(content) {
+        for skipped in skipped {
+            skipped.fmt_matched(f)?;
+        }
+        matched.fmt_matched(f)?;
+    }
+    Ok(())
+}
+impl_matched_text!(
+    RepMin<T, Skip, SKIP, MIN>,
+    (T: MatchedText, Skip: MatchedText, const SKIP: usize, const MIN: usize),
+    self,
+    f => fmt_rep(&self.content, &self.skipped, f)
+);
+impl_matched_text!(
+    RepMinMax<T, Skip, SKIP, MIN, MAX>,
+    (T: MatchedText, Skip: MatchedText, const SKIP: usize, const MIN: usize, const MAX: usize),
+    self,
+    f => fmt_rep(&self.content, &self.skipped, f)
+);
+
+impl_matched_text!(Positive<N>, (N), self, _f => Ok(()));
+impl_matched_text!(Negative<T>, (T), self, _f => Ok(()));
+impl_matched_text!(SOI, (), self, _f => Ok(()));
+impl_matched_text!(EOI, (), self, _f => Ok(()));
+impl_matched_text!(DROP, (), self, _f => Ok(()));
+impl_matched_text!(PushLiteral<T>, (T: StringWrapper + 'static), self, _f => Ok(()));
+impl_matched_text!(AlwaysFail<'i>, ('i), self, _f => Ok(()));
+impl_matched_text!(Empty<'i>, ('i), self, _f => Ok(()));
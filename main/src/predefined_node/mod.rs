@@ -11,9 +11,11 @@
 //! The generator may use this for convenience.
 //! Normally you don't need to reference this module by yourself.
 
+mod content;
+mod display;
 mod repetition;
 
-use super::{parser_state::constrain_idxs, position::Position, Stack};
+use super::{parser_state::constrain_idxs, position::Position, Stack, StackExt};
 use super::{
     span::Span,
     tracker::Tracker,
@@ -24,7 +26,9 @@ use super::{
 use core::ops::{Deref, DerefMut};
 use core::{fmt::Debug, marker::PhantomData};
 use custom_debug_derive::Debug as Dbg;
-pub use repetition::{AtomicRep, Rep, RepMin, RepMinMax, RepOnce};
+pub use repetition::{
+    AtomicRep, Rep, RepMin, RepMinMax, RepOnce, RepStorage, RepUntil, COMPACT_DEBUG_MAX_ELEMENTS,
+};
 
 /// Match given string case sensitively.
 ///
@@ -34,7 +38,7 @@ pub use repetition::{AtomicRep, Rep, RepMin, RepMinMax, RepOnce};
 #[derive(Clone, Dbg, Hash, PartialEq, Eq)]
 pub struct Str<T: StringWrapper + 'static> {
     #[debug(skip)]
-    _phantom: PhantomData<&'static T>,
+    _phantom: PhantomData<fn() -> T>,
 }
 impl<T: StringWrapper> StringWrapper for Str<T> {
     const CONTENT: &'static str = T::CONTENT;
@@ -60,6 +64,84 @@ impl<'i, R: RuleType, T: StringWrapper> TypedNode<'i, R> for Str<T> {
     }
 }
 
+/// Match given string case sensitively, and keep the matched span.
+///
+/// The same as [`Str`], which is zero-sized, except that it records where the string is.
+/// Generated for string literals instead of [`Str`] with attribute `tokenize_literals`.
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct StrSpanned<'i, T: StringWrapper + 'static> {
+    /// Matched span.
+    pub span: Span<'i>,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> T>,
+}
+impl<'i, T: StringWrapper> StringWrapper for StrSpanned<'i, T> {
+    const CONTENT: &'static str = T::CONTENT;
+}
+impl<'i, T: StringWrapper> From<Span<'i>> for StrSpanned<'i, T> {
+    fn from(span: Span<'i>) -> Self {
+        Self {
+            span,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, T: StringWrapper> TypedNode<'i, R> for StrSpanned<'i, T> {
+    fn try_parse_with(
+        mut input: Position<'i>,
+        _stack: &mut Stack<Span<'i>>,
+        _tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let start = input;
+        if input.match_string(Self::CONTENT) {
+            Some((input, Self::from(start.span(&input))))
+        } else {
+            None
+        }
+    }
+}
+
+/// Optionally match given string case sensitively.
+///
+/// Matches the same as `Option<Str<T>>`, which is replaced by this in generated code,
+/// so that whether the string is present can be read as a [`bool`] by [`Presence::present`].
+///
+/// `Option<Insens<T>>` is not replaced, as [`Insens`] keeps the matched text.
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct Presence<T: StringWrapper + 'static> {
+    present: bool,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> T>,
+}
+impl<T: StringWrapper> Presence<T> {
+    /// Whether the string is matched.
+    pub fn present(&self) -> bool {
+        self.present
+    }
+}
+impl<T: StringWrapper> StringWrapper for Presence<T> {
+    const CONTENT: &'static str = T::CONTENT;
+}
+impl<T: StringWrapper> From<bool> for Presence<T> {
+    fn from(present: bool) -> Self {
+        Self {
+            present,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, T: StringWrapper> TypedNode<'i, R> for Presence<T> {
+    #[inline]
+    fn try_parse_with(
+        mut input: Position<'i>,
+        _stack: &mut Stack<Span<'i>>,
+        _tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let present = input.match_string(Self::CONTENT);
+        Some((input, Self::from(present)))
+    }
+}
+
 /// Match given string case insensitively.
 ///
 /// - The field `content` is the matched string.
@@ -73,7 +155,7 @@ pub struct Insens<'i, T: StringWrapper> {
     /// Matched content.
     pub content: &'i str,
     #[debug(skip)]
-    _phantom: PhantomData<&'i T>,
+    _phantom: PhantomData<fn() -> T>,
 }
 impl<'i, T: StringWrapper> StringWrapper for Insens<'i, T> {
     const CONTENT: &'static str = T::CONTENT;
@@ -102,21 +184,117 @@ impl<'i, R: RuleType, T: StringWrapper> TypedNode<'i, R> for Insens<'i, T> {
     }
 }
 
+/// Match given string case insensitively with Unicode simple case folding.
+///
+/// Each character in the input is compared with the corresponding one in `CONTENT`
+/// by [`char::to_lowercase`], so one character never matches several ones.
+/// For example, `^"straße"` matches `"STRAẞE"` but not `"STRASSE"`,
+/// and `"K"` (Kelvin sign) matches `^"k"`.
+///
+/// - The field `content` is the matched string, which may differ from `CONTENT` in case and in length.
+///
+/// See [`Insens`] for ASCII case-insensitive matching as in pest.
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct InsensUnicode<'i, T: StringWrapper> {
+    /// Matched content.
+    pub content: &'i str,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> T>,
+}
+impl<'i, T: StringWrapper> StringWrapper for InsensUnicode<'i, T> {
+    const CONTENT: &'static str = T::CONTENT;
+}
+impl<'i, T: StringWrapper> From<&'i str> for InsensUnicode<'i, T> {
+    fn from(content: &'i str) -> Self {
+        Self {
+            content,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, T: StringWrapper> TypedNode<'i, R> for InsensUnicode<'i, T> {
+    fn try_parse_with(
+        mut input: Position<'i>,
+        _stack: &mut Stack<Span<'i>>,
+        _tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let start = input;
+        for expected in Self::CONTENT.chars() {
+            match_char_by(&mut input, |c| c.to_lowercase().eq(expected.to_lowercase()))?;
+        }
+        let span = start.span(&input);
+        Some((input, Self::from(span.as_str())))
+    }
+}
+
+/// Match a case-insensitive keyword not followed by `Boundary`.
+///
+/// Equivalent to `^"keyword" ~ !Boundary` without implicit skipping,
+/// which is lowered to this node by the generator.
+/// `Boundary` defaults to [`KeywordBoundary`], so that `if` doesn't match the start of `iffy`.
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct Keyword<'i, T: StringWrapper, Boundary = KeywordBoundary> {
+    /// Matched content.
+    pub content: &'i str,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> (T, Boundary)>,
+}
+impl<'i, T: StringWrapper, Boundary: Clone + PartialEq> StringWrapper for Keyword<'i, T, Boundary> {
+    const CONTENT: &'static str = T::CONTENT;
+}
+impl<'i, T: StringWrapper, Boundary> From<&'i str> for Keyword<'i, T, Boundary> {
+    fn from(content: &'i str) -> Self {
+        Self {
+            content,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, T: StringWrapper, Boundary: TypedNode<'i, R>> TypedNode<'i, R>
+    for Keyword<'i, T, Boundary>
+{
+    fn try_parse_with(
+        input: Position<'i>,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let (input, keyword) = Insens::<'i, T>::try_parse_with(input, stack, tracker)?;
+        let (input, _) = Negative::<Boundary>::try_parse_with(input, stack, tracker)?;
+        Some((input, Self::from(keyword.content)))
+    }
+}
+
 /// Skips until one of the given strings.
+///
+/// Fails if none of them is found.
 #[derive(Clone, Dbg, Hash, PartialEq, Eq)]
 pub struct Skip<'i, Strings: StringArrayWrapper> {
     /// Skipped span.
     pub span: Span<'i>,
+    /// Index of the string in `Strings::CONTENT` that follows the skipped span,
+    /// or [`None`] if there is none, such as at the end of input.
+    ///
+    /// The first one is taken if several of them follow.
+    pub terminator: Option<usize>,
     #[debug(skip)]
-    _phantom: PhantomData<&'i Strings>,
+    _phantom: PhantomData<fn() -> Strings>,
+}
+impl<'i, Strings: StringArrayWrapper> Skip<'i, Strings> {
+    /// The string that follows the skipped span, see [`Skip::terminator`].
+    pub fn terminated_by(&self) -> Option<&'static str> {
+        self.terminator.map(|i| Strings::CONTENT[i])
+    }
 }
 impl<'i, Strings: StringArrayWrapper> StringArrayWrapper for Skip<'i, Strings> {
     const CONTENT: &'static [&'static str] = Strings::CONTENT;
 }
 impl<'i, Strings: StringArrayWrapper> From<Span<'i>> for Skip<'i, Strings> {
     fn from(span: Span<'i>) -> Self {
+        let rest = &span.get_input()[span.end()..];
+        let terminator = Strings::CONTENT.iter().position(|s| rest.starts_with(s));
         Self {
             span,
+            terminator,
             _phantom: PhantomData,
         }
     }
@@ -138,6 +316,107 @@ impl<'i, R: RuleType, Strings: StringArrayWrapper> TypedNode<'i, R> for Skip<'i,
     }
 }
 
+/// Match a character, as long as none of the given strings matches here.
+///
+/// Equivalent to `!("a" | "b" | ...) ~ ANY` without implicit skipping,
+/// which is lowered to this node by the generator.
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct CharExcept<Strings: StringArrayWrapper + 'static> {
+    /// Matched character.
+    pub content: char,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> Strings>,
+}
+impl<Strings: StringArrayWrapper> StringArrayWrapper for CharExcept<Strings> {
+    const CONTENT: &'static [&'static str] = Strings::CONTENT;
+}
+impl<Strings: StringArrayWrapper> From<char> for CharExcept<Strings> {
+    fn from(content: char) -> Self {
+        Self {
+            content,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, Strings: StringArrayWrapper> TypedNode<'i, R> for CharExcept<Strings> {
+    #[inline]
+    fn try_parse_with(
+        mut input: Position<'i>,
+        _stack: &mut Stack<Span<'i>>,
+        _tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let rest = &input.input[input.pos()..];
+        let excluded = |_| !Strings::CONTENT.iter().any(|s| rest.starts_with(s));
+        let content = match_char_by(&mut input, excluded)?;
+        Some((input, Self::from(content)))
+    }
+}
+
+/// Match the longest of the given strings case sensitively.
+///
+/// Equivalent to `"a" | "b" | ...` if no string is a prefix of a later one,
+/// which is lowered to this node by the generator.
+/// The ordered choice then always matches the longest string, too.
+///
+/// ```
+/// # use pest_typed::{predefined_node::StrChoice, tracker::Tracker, Position, Stack, StringArrayWrapper, TypedNode};
+/// # #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # enum Rule {}
+/// #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+/// struct Keywords;
+/// impl StringArrayWrapper for Keywords {
+///     const CONTENT: &'static [&'static str] = &["if", "in", "ifx"];
+/// }
+/// let input = Position::from_start("ifx");
+/// let mut tracker = Tracker::<Rule>::new(input);
+/// let (_, keyword) = StrChoice::<Keywords>::try_parse_with(input, &mut Stack::new(), &mut tracker).unwrap();
+/// assert_eq!(keyword.index, 2);
+/// assert_eq!(keyword.get(), "ifx");
+/// ```
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct StrChoice<Strings: StringArrayWrapper + 'static> {
+    /// Index of the matched string in `CONTENT`.
+    pub index: usize,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> Strings>,
+}
+impl<Strings: StringArrayWrapper> StrChoice<Strings> {
+    /// The matched string.
+    pub fn get(&self) -> &'static str {
+        Strings::CONTENT[self.index]
+    }
+}
+impl<Strings: StringArrayWrapper> StringArrayWrapper for StrChoice<Strings> {
+    const CONTENT: &'static [&'static str] = Strings::CONTENT;
+}
+impl<Strings: StringArrayWrapper> From<usize> for StrChoice<Strings> {
+    fn from(index: usize) -> Self {
+        Self {
+            index,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, Strings: StringArrayWrapper> TypedNode<'i, R> for StrChoice<Strings> {
+    #[inline]
+    fn try_parse_with(
+        mut input: Position<'i>,
+        _stack: &mut Stack<Span<'i>>,
+        _tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let rest = input.input.as_bytes().get(input.pos()..)?;
+        // The first of the longest matched strings, as an ordered choice would match.
+        let (index, string) = Strings::CONTENT
+            .iter()
+            .enumerate()
+            .rev()
+            .filter(|(_, s)| rest.starts_with(s.as_bytes()))
+            .max_by_key(|(_, s)| s.len())?;
+        input.match_string(string);
+        Some((input, Self::from(index)))
+    }
+}
+
 /// Skip `n` characters if there are.
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct SkipChar<'i, const N: usize> {
@@ -163,19 +442,48 @@ impl<'i, R: RuleType, const N: usize> TypedNode<'i, R> for SkipChar<'i, N> {
 
 /// Match a character in the range `[MIN, MAX]`.
 /// Inclusively both below and above.
+///
+/// Parsing with it fails to compile if `MIN` is greater than `MAX`.
+///
+/// ```
+/// # use pest_typed::{predefined_node::CharRange, tracker::Tracker, Position, Stack, TypedNode};
+/// # #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # enum Rule {}
+/// let input = Position::from_start("c");
+/// let mut tracker = Tracker::<Rule>::new(input);
+/// let (_, c) = CharRange::<'a', 'c'>::try_parse_with(input, &mut Stack::new(), &mut tracker).unwrap();
+/// assert_eq!(c.content, 'c');
+/// ```
+///
+/// ```compile_fail
+/// # use pest_typed::{predefined_node::CharRange, tracker::Tracker, Position, Stack, TypedNode};
+/// # #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// # enum Rule {}
+/// let input = Position::from_start("c");
+/// let mut tracker = Tracker::<Rule>::new(input);
+/// let _ = CharRange::<'c', 'a'>::try_parse_with(input, &mut Stack::new(), &mut tracker);
+/// ```
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub struct CharRange<const MIN: char, const MAX: char> {
     /// Matched character.
     pub content: char,
 }
+impl<const MIN: char, const MAX: char> CharRange<MIN, MAX> {
+    const ORDERED: () = assert!(
+        MIN as u32 <= MAX as u32,
+        "`MIN` of a `CharRange` is greater than `MAX`."
+    );
+}
 impl<'i, R: RuleType, const MIN: char, const MAX: char> TypedNode<'i, R> for CharRange<MIN, MAX> {
     fn try_parse_with(
         mut input: Position<'i>,
         _stack: &mut Stack<Span<'i>>,
         _tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
+        #[allow(clippy::let_unit_value)]
+        let () = Self::ORDERED;
         let start = input;
-        match input.match_range(MIN..MAX) {
+        match input.match_range(MIN..=MAX) {
             true => {
                 let span = start.span(&input);
                 let content = span.as_str().chars().next().unwrap();
@@ -260,17 +568,9 @@ impl<'i, R: RuleType, N: TypedNode<'i, R>> TypedNode<'i, R> for Positive<N> {
         tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
         tracker.positive_during(|tracker| {
-            stack.snapshot();
-            match N::try_parse_with(input, stack, tracker) {
-                Some((_, content)) => {
-                    stack.restore();
-                    Some((input, Self::from(content)))
-                }
-                None => {
-                    stack.restore();
-                    None
-                }
-            }
+            let mut stack = stack.snapshot_guard();
+            let (_, content) = N::try_parse_with(input, &mut stack, tracker)?;
+            Some((input, Self::from(content)))
         })
     }
 }
@@ -281,7 +581,7 @@ impl<'i, R: RuleType, N: TypedNode<'i, R>> TypedNode<'i, R> for Positive<N> {
 #[derive(Clone, Dbg, Hash, PartialEq, Eq)]
 pub struct Negative<T> {
     #[debug(skip)]
-    _phantom: PhantomData<T>,
+    _phantom: PhantomData<fn() -> T>,
 }
 impl<T> From<()> for Negative<T> {
     fn from(_value: ()) -> Self {
@@ -296,19 +596,17 @@ impl<'i, R: RuleType, T: TypedNode<'i, R>> TypedNode<'i, R> for Negative<T> {
         stack: &mut Stack<Span<'i>>,
         tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
-        tracker.negative_during(|tracker| {
-            stack.snapshot();
-            match T::try_parse_with(input, stack, tracker) {
-                Some(_) => {
-                    stack.restore();
-                    None
-                }
-                None => {
-                    stack.restore();
-                    Some((input, Self::from(())))
-                }
+        let matched = tracker.negative_during(|tracker| {
+            let mut stack = stack.snapshot_guard();
+            T::try_parse_with(input, &mut stack, tracker).map(|(end, _)| end)
+        });
+        match matched {
+            Some(end) => {
+                tracker.unexpected(input.span(&end));
+                None
             }
-        })
+            None => Some((input, Self::from(()))),
+        }
     }
 }
 
@@ -468,6 +766,42 @@ impl<'i, R: RuleType> TypedNode<'i, R> for PEEK<'i> {
     }
 }
 
+/// Match top span in stack case-insensitively.
+/// Will consume input.
+///
+/// The matched span is taken from the input rather than the stack.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PEEK_INSENS<'i> {
+    /// Pair span.
+    pub span: Span<'i>,
+}
+impl<'i> From<Span<'i>> for PEEK_INSENS<'i> {
+    fn from(span: Span<'i>) -> Self {
+        Self { span }
+    }
+}
+impl<'i, R: RuleType> TypedNode<'i, R> for PEEK_INSENS<'i> {
+    #[inline]
+    fn try_parse_with(
+        mut input: Position<'i>,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let start = input;
+        match stack.peek() {
+            Some(string) => match input.match_insensitive(string.as_str()) {
+                true => Some((input, Self::from(start.span(&input)))),
+                false => None,
+            },
+            None => {
+                tracker.empty_stack(input);
+                None
+            }
+        }
+    }
+}
+
 /// Skip comments (by rule `COMMENT`) or white spaces (by rule `WHITESPACE`) if there is any.
 #[derive(Clone, Hash, PartialEq, Eq)]
 pub struct Skipped<T, Skip, const SKIP: usize> {
@@ -543,6 +877,41 @@ impl<'i, R: RuleType> TypedNode<'i, R> for POP<'i> {
     }
 }
 
+/// Match the top span of the stack case-insensitively and pop it.
+///
+/// The matched span is taken from the input rather than the stack.
+#[allow(non_camel_case_types)]
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct POP_INSENS<'i> {
+    /// Matched span.
+    pub span: Span<'i>,
+}
+impl<'i> From<Span<'i>> for POP_INSENS<'i> {
+    fn from(span: Span<'i>) -> Self {
+        Self { span }
+    }
+}
+impl<'i, R: RuleType> TypedNode<'i, R> for POP_INSENS<'i> {
+    #[inline]
+    fn try_parse_with(
+        mut input: Position<'i>,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let start = input;
+        match stack.pop() {
+            Some(span) => match input.match_insensitive(span.as_str()) {
+                true => Some((input, Self::from(start.span(&input)))),
+                false => None,
+            },
+            None => {
+                tracker.empty_stack(input);
+                None
+            }
+        }
+    }
+}
+
 /// Match and pop all spans in the stack in top-to-bottom-order.
 #[allow(non_camel_case_types)]
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -616,16 +985,18 @@ impl<'i, R: RuleType> TypedNode<'i, R> for Empty<'i> {
 
 /// Match an expression and push it to the [Stack].
 #[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Push<T> {
+pub struct Push<'i, T> {
     /// Matched content.
     pub content: T,
+    /// Matched span, which is pushed to the [Stack].
+    pub span: Span<'i>,
 }
-impl<T> From<T> for Push<T> {
-    fn from(content: T) -> Self {
-        Self { content }
+impl<'i, T> From<(T, Span<'i>)> for Push<'i, T> {
+    fn from((content, span): (T, Span<'i>)) -> Self {
+        Self { content, span }
     }
 }
-impl<'i, R: RuleType, T: TypedNode<'i, R>> TypedNode<'i, R> for Push<T> {
+impl<'i, R: RuleType, T: TypedNode<'i, R>> TypedNode<'i, R> for Push<'i, T> {
     #[inline]
     fn try_parse_with(
         input: Position<'i>,
@@ -634,27 +1005,173 @@ impl<'i, R: RuleType, T: TypedNode<'i, R>> TypedNode<'i, R> for Push<T> {
     ) -> Option<(Position<'i>, Self)> {
         let start = input;
         let (input, content) = T::try_parse_with(input, stack, tracker)?;
-        stack.push(start.span(&input));
-        Some((input, Self::from(content)))
+        let span = start.span(&input);
+        stack.push(span);
+        Some((input, Self::from((content, span))))
     }
 }
-impl<T> Deref for Push<T> {
+impl<'i, T> Deref for Push<'i, T> {
     type Target = T;
     fn deref(&self) -> &Self::Target {
         &self.content
     }
 }
-impl<T> DerefMut for Push<T> {
+impl<'i, T> DerefMut for Push<'i, T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
         &mut self.content
     }
 }
 
+/// Match an expression and keep only its span.
+///
+/// Generated for node tags inside rules that only keep their spans, such as atomic rules.
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct SpanOnly<'i, T> {
+    /// Matched span.
+    pub span: Span<'i>,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> T>,
+}
+impl<'i, T> From<Span<'i>> for SpanOnly<'i, T> {
+    fn from(span: Span<'i>) -> Self {
+        Self {
+            span,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, T: TypedNode<'i, R>> TypedNode<'i, R> for SpanOnly<'i, T> {
+    #[inline]
+    fn try_parse_with(
+        input: Position<'i>,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let start = input;
+        let (input, _) = T::try_parse_with(input, stack, tracker)?;
+        Some((input, Self::from(start.span(&input))))
+    }
+}
+
+/// Push the literal `CONTENT` on the type (by [`StringWrapper`]) to the [Stack].
+/// Will not consume input.
+///
+/// The pushed span is taken from the literal rather than the input,
+/// so [`PEEK`] and [`POP`] match it by text.
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct PushLiteral<T: StringWrapper + 'static> {
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> T>,
+}
+impl<T: StringWrapper> StringWrapper for PushLiteral<T> {
+    const CONTENT: &'static str = T::CONTENT;
+}
+impl<T: StringWrapper> From<()> for PushLiteral<T> {
+    fn from(_value: ()) -> Self {
+        Self {
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, T: StringWrapper> TypedNode<'i, R> for PushLiteral<T> {
+    #[inline]
+    fn try_parse_with(
+        input: Position<'i>,
+        stack: &mut Stack<Span<'i>>,
+        _tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        stack.push(Span::new(Self::CONTENT, 0, Self::CONTENT.len()).unwrap());
+        Some((input, Self::from(())))
+    }
+}
+
+/// Match `T`, or skip to the next `Sync` when `T` fails during error recovery.
+///
+/// Only recovers when parsing with [`ParsableTypedNode::parse_with_recovery`](crate::ParsableTypedNode::parse_with_recovery),
+/// in which case the error of `T` is recorded,
+/// and the input is skipped character by character until `Sync` matches.
+/// Fails as `T` does otherwise, or if `Sync` never matches.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub enum Recover<'i, T, Sync> {
+    /// Matched expression.
+    Matched(T),
+    /// Skipped text after a failure.
+    Recovered {
+        /// Skipped text before `sync`.
+        span: Span<'i>,
+        /// Matched synchronization node that ends the skipped text.
+        sync: Sync,
+    },
+}
+impl<'i, T, Sync> Recover<'i, T, Sync> {
+    /// Matched expression, or [`None`] if recovered.
+    pub fn matched(&self) -> Option<&T> {
+        match self {
+            Self::Matched(matched) => Some(matched),
+            Self::Recovered { .. } => None,
+        }
+    }
+    /// Whether the expression failed and was skipped.
+    pub fn is_recovered(&self) -> bool {
+        matches!(self, Self::Recovered { .. })
+    }
+}
+impl<'i, R: RuleType, T: TypedNode<'i, R>, Sync: TypedNode<'i, R>> TypedNode<'i, R>
+    for Recover<'i, T, Sync>
+{
+    fn try_parse_with(
+        input: Position<'i>,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let res = restore_on_none(stack, |stack| T::try_parse_with(input, stack, tracker));
+        if let Some((next, matched)) = res {
+            return Some((next, Self::Matched(matched)));
+        }
+        if !tracker.is_recovering() {
+            return None;
+        }
+        let mut pos = input;
+        loop {
+            let mut sync_tracker = tracker.nested(pos);
+            let res = restore_on_none(stack, |stack| {
+                Sync::try_parse_with(pos, stack, &mut sync_tracker)
+            });
+            match res {
+                // Something must be consumed, or the repetition won't end.
+                Some((next, sync)) if next > input => {
+                    // Parse again, so that the error only contains attempts inside `T`.
+                    let mut element_tracker = tracker.nested(input);
+                    let _ = restore_on_none(stack, |stack| {
+                        T::try_parse_with(input, stack, &mut element_tracker)
+                    });
+                    tracker.recover(element_tracker.collect());
+                    let span = input.span(&pos);
+                    return Some((next, Self::Recovered { span, sync }));
+                }
+                _ => {
+                    if !pos.skip(1) {
+                        return None;
+                    }
+                }
+            }
+        }
+    }
+}
+
 /// Match `[START..END]` in top-to-bottom order of the stack.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PeekSlice2<const START: i32, const END: i32>;
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PeekSlice2<'i, const START: i32, const END: i32> {
+    /// Matched span.
+    pub span: Span<'i>,
+}
+impl<'i, const START: i32, const END: i32> From<Span<'i>> for PeekSlice2<'i, START, END> {
+    fn from(span: Span<'i>) -> Self {
+        Self { span }
+    }
+}
 impl<'i, R: RuleType, const START: i32, const END: i32> TypedNode<'i, R>
-    for PeekSlice2<START, END>
+    for PeekSlice2<'i, START, END>
 {
     #[inline]
     fn try_parse_with(
@@ -663,15 +1180,23 @@ impl<'i, R: RuleType, const START: i32, const END: i32> TypedNode<'i, R>
         tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
         let spans = stack_slice(input, START, Some(END), stack, tracker)?;
-        let (input, _) = peek_spans::<R>(input, spans, tracker)?;
-        Some((input, Self))
+        let (input, span) = peek_spans::<R>(input, spans, tracker)?;
+        Some((input, Self::from(span)))
     }
 }
 
 /// Match `[START..]` in top-to-bottom order of the stack.
-#[derive(Clone, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
-pub struct PeekSlice1<const START: i32>;
-impl<'i, R: RuleType, const START: i32> TypedNode<'i, R> for PeekSlice1<START> {
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct PeekSlice1<'i, const START: i32> {
+    /// Matched span.
+    pub span: Span<'i>,
+}
+impl<'i, const START: i32> From<Span<'i>> for PeekSlice1<'i, START> {
+    fn from(span: Span<'i>) -> Self {
+        Self { span }
+    }
+}
+impl<'i, R: RuleType, const START: i32> TypedNode<'i, R> for PeekSlice1<'i, START> {
     #[inline]
     fn try_parse_with(
         input: Position<'i>,
@@ -679,8 +1204,8 @@ impl<'i, R: RuleType, const START: i32> TypedNode<'i, R> for PeekSlice1<START> {
         tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
         let spans = stack_slice(input, START, None, stack, tracker)?;
-        let (input, _) = peek_spans::<R>(input, spans, tracker)?;
-        Some((input, Self))
+        let (input, span) = peek_spans::<R>(input, spans, tracker)?;
+        Some((input, Self::from(span)))
     }
 }
 
@@ -721,6 +1246,9 @@ pub type ASCII_ALPHA = Choice2<ASCII_ALPHA_LOWER, ASCII_ALPHA_UPPER>;
 #[allow(non_camel_case_types)]
 pub type ASCII_ALPHANUMERIC = Choice2<ASCII_ALPHA, ASCII_DIGIT>;
 
+/// ASCII alphabet, digit or `_`, which can't follow a [`Keyword`] by default.
+pub type KeywordBoundary = Choice2<ASCII_ALPHANUMERIC, CharRange<'_', '_'>>;
+
 /// ASCII alphabet.
 #[allow(non_camel_case_types)]
 pub type ASCII = CharRange<'\x00', '\x7f'>;
@@ -741,16 +1269,17 @@ pub fn match_char_by(position: &mut Position<'_>, pred: impl FnOnce(char) -> boo
 }
 
 /// Restore on error.
+///
+/// The stack is also restored if `f` panics, see [`StackGuard`](crate::StackGuard).
 #[inline]
 pub fn restore_on_none<'i, T>(
     stack: &mut Stack<Span<'i>>,
     f: impl FnOnce(&mut Stack<Span<'i>>) -> Option<T>,
 ) -> Option<T> {
-    stack.snapshot();
-    let res = f(stack);
-    match res.as_ref() {
-        Some(_) => stack.clear_snapshot(),
-        None => stack.restore(),
+    let mut stack = stack.snapshot_guard();
+    let res = f(&mut stack);
+    if res.is_some() {
+        stack.commit();
     }
     res
 }
@@ -11,27 +11,57 @@
 //! The generator may use this for convenience.
 //! Normally you don't need to reference this module by yourself.
 
-use core::ops::{Deref, DerefMut};
+use core::{
+    fmt::{Debug, Formatter},
+    marker::PhantomData,
+    ops::{Deref, DerefMut, Index},
+};
 
 use crate::{
     predefined_node::{restore_on_none, Skipped},
     tracker::Tracker,
     wrapper::BoundWrapper,
-    NeverFailedTypedNode, Position, RuleType, Span, Stack, TypedNode,
+    NeverFailedTypedNode, Position, RuleType, Span, Stack, StackExt, TypedNode,
 };
 use alloc::vec::Vec;
+use custom_debug_derive::Debug as Dbg;
+
+/// Storage of matched expressions and skipped contents in [`RepMin`] and [`RepMinMax`].
+///
+/// It's a [`Vec`], or a `smallvec::SmallVec` that keeps up to 2 elements inline with feature `smallvec`.
+/// Either way it dereferences to a slice.
+#[cfg(not(feature = "smallvec"))]
+pub type RepStorage<T> = Vec<T>;
+/// Storage of matched expressions and skipped contents in [`RepMin`] and [`RepMinMax`].
+///
+/// It's a [`Vec`], or a `smallvec::SmallVec` that keeps up to 2 elements inline with feature `smallvec`.
+/// Either way it dereferences to a slice.
+#[cfg(feature = "smallvec")]
+pub type RepStorage<T> = smallvec::SmallVec<[T; 2]>;
+
+/// Move elements out of a [`RepStorage`], reusing its allocation if there is one.
+#[cfg(not(feature = "smallvec"))]
+fn storage_into_vec<T>(storage: RepStorage<T>) -> Vec<T> {
+    storage
+}
+/// Move elements out of a [`RepStorage`], reusing its allocation if there is one.
+#[cfg(feature = "smallvec")]
+fn storage_into_vec<T>(storage: RepStorage<T>) -> Vec<T> {
+    storage.into_vec()
+}
 
-type Iter<'n, T, IGNORED, const SKIP: usize> = core::iter::Map<
-    alloc::slice::Iter<'n, Skipped<T, IGNORED, SKIP>>,
-    fn(&'n Skipped<T, IGNORED, SKIP>) -> &'n T,
+type IterAll<'n, T, IGNORED, const SKIP: usize> = core::iter::Map<
+    core::iter::Zip<alloc::slice::Iter<'n, [IGNORED; SKIP]>, alloc::slice::Iter<'n, T>>,
+    fn((&'n [IGNORED; SKIP], &'n T)) -> Skipped<&'n T, &'n IGNORED, SKIP>,
 >;
-type IntoIter<T, IGNORED, const SKIP: usize> = core::iter::Map<
-    alloc::vec::IntoIter<Skipped<T, IGNORED, SKIP>>,
-    fn(Skipped<T, IGNORED, SKIP>) -> T,
+type IntoIterAll<T, IGNORED, const SKIP: usize> = core::iter::Map<
+    core::iter::Zip<alloc::vec::IntoIter<[IGNORED; SKIP]>, alloc::vec::IntoIter<T>>,
+    fn(([IGNORED; SKIP], T)) -> Skipped<T, IGNORED, SKIP>,
 >;
 
 /// Repeatably match `T` at least `MIN` times.
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+#[derive(Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(not(feature = "compact_debug"), derive(Debug))]
 pub struct AtomicRep<T> {
     /// Skipped and Matched expressions.
     pub content: Vec<T>,
@@ -45,7 +75,7 @@ impl<T> Default for AtomicRep<T> {
 impl<'i, R: RuleType, T: TypedNode<'i, R>> NeverFailedTypedNode<'i, R> for AtomicRep<T> {
     fn parse_with(mut input: Position<'i>, stack: &mut Stack<Span<'i>>) -> (Position<'i>, Self) {
         let mut vec = Vec::new();
-        let mut tracker = Tracker::new(input);
+        let mut tracker = Tracker::discarding(input);
 
         for _ in 0usize.. {
             match restore_on_none(stack, |stack| T::try_parse_with(input, stack, &mut tracker)) {
@@ -84,12 +114,114 @@ impl<T: Clone + PartialEq> BoundWrapper for AtomicRep<T> {
     const MIN: usize = 0;
     const MAX: usize = usize::MAX;
 }
+impl<T> AtomicRep<T> {
+    /// Matched expressions as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.content
+    }
+    /// Clone matched expressions into a [`Vec`].
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.content.clone()
+    }
+    /// Take matched expressions.
+    pub fn into_vec(self) -> Vec<T> {
+        self.content
+    }
+}
 
-/// Repeatably match `T` at least `MIN` times.
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct RepMin<T, const MIN: usize> {
-    /// Skipped and Matched expressions.
+/// Repeatably match `T` until `Terminator` matches, without consuming `Terminator`.
+///
+/// Matches the same as `(!Terminator ~ T)*` where nothing is skipped,
+/// which is replaced by this in generated code with attribute `lower_rep_until`.
+/// The repetition also stops where `T` fails, such as at the end of input,
+/// and then [`RepUntil::terminator`] is [`None`].
+#[derive(Clone, Dbg, Hash, PartialEq, Eq)]
+pub struct RepUntil<'i, T, Terminator> {
+    /// Matched expressions.
     pub content: Vec<T>,
+    /// Span matched by `Terminator` after the last matched expression,
+    /// or [`None`] if the repetition stopped because `T` failed.
+    pub terminator: Option<Span<'i>>,
+    #[debug(skip)]
+    _phantom: PhantomData<fn() -> Terminator>,
+}
+impl<'i, T, Terminator> Default for RepUntil<'i, T, Terminator> {
+    fn default() -> Self {
+        Self {
+            content: Vec::new(),
+            terminator: None,
+            _phantom: PhantomData,
+        }
+    }
+}
+impl<'i, R: RuleType, T: TypedNode<'i, R>, Terminator: TypedNode<'i, R>> TypedNode<'i, R>
+    for RepUntil<'i, T, Terminator>
+{
+    fn try_parse_with(
+        mut input: Position<'i>,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        let mut res = Self::default();
+        loop {
+            // Checked the same way as `!Terminator`.
+            let end = tracker.negative_during(|tracker| {
+                let mut stack = stack.snapshot_guard();
+                Terminator::try_parse_with(input, &mut stack, tracker).map(|(end, _)| end)
+            });
+            if let Some(end) = end {
+                let span = input.span(&end);
+                tracker.unexpected(span);
+                res.terminator = Some(span);
+                break;
+            }
+            match restore_on_none(stack, |stack| T::try_parse_with(input, stack, tracker)) {
+                Some((next, matched)) => {
+                    input = next;
+                    tracker.reach(input);
+                    res.content.push(matched);
+                }
+                None => break,
+            }
+        }
+        Some((input, res))
+    }
+}
+impl<'i, T, Terminator> Deref for RepUntil<'i, T, Terminator> {
+    type Target = Vec<T>;
+    fn deref(&self) -> &Self::Target {
+        &self.content
+    }
+}
+impl<'i, T, Terminator> DerefMut for RepUntil<'i, T, Terminator> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.content
+    }
+}
+impl<'i, T, Terminator> RepUntil<'i, T, Terminator> {
+    /// Whether the repetition stopped at `Terminator`.
+    pub fn terminated(&self) -> bool {
+        self.terminator.is_some()
+    }
+    /// Take matched expressions.
+    pub fn into_vec(self) -> Vec<T> {
+        self.content
+    }
+}
+
+/// Repeatably match `T` at least `MIN` times, with `SKIP` `Skip`s between each two of them.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct RepMin<T, Skip, const SKIP: usize, const MIN: usize> {
+    /// Matched expressions.
+    pub content: RepStorage<T>,
+    /// Skipped contents before each matched expression.
+    ///
+    /// Has the same length as `content`,
+    /// and the first one is always [`Default`] as nothing is skipped before the first expression.
+    pub skipped: RepStorage<[Skip; SKIP]>,
 }
 impl<
         'i,
@@ -97,28 +229,30 @@ impl<
         T: TypedNode<'i, R>,
         Skip: NeverFailedTypedNode<'i, R>,
         const SKIP: usize,
-    > NeverFailedTypedNode<'i, R> for RepMin<Skipped<T, Skip, SKIP>, 0>
+    > NeverFailedTypedNode<'i, R> for RepMin<T, Skip, SKIP, 0>
 {
     fn parse_with(mut input: Position<'i>, stack: &mut Stack<Span<'i>>) -> (Position<'i>, Self) {
-        let mut vec = Vec::new();
-        let mut tracker = Tracker::new(input);
+        let mut res = Self::default();
+        let mut tracker = Tracker::discarding(input);
 
         for i in 0usize.. {
             match restore_on_none(stack, |stack| try_parse_unit(input, stack, &mut tracker, i)) {
                 Some((next, matched)) => {
                     input = next;
-                    vec.push(matched);
+                    res.push(matched);
                 }
                 None => break,
             }
         }
-        (input, Self { content: vec })
+        (input, res)
     }
 }
-impl<T> Default for RepMin<T, 0> {
+impl<T, Skip, const SKIP: usize> Default for RepMin<T, Skip, SKIP, 0> {
     fn default() -> Self {
-        let content = Vec::new();
-        Self { content }
+        Self {
+            content: RepStorage::new(),
+            skipped: RepStorage::new(),
+        }
     }
 }
 impl<
@@ -128,7 +262,7 @@ impl<
         Skip: NeverFailedTypedNode<'i, R>,
         const SKIP: usize,
         const MIN: usize,
-    > TypedNode<'i, R> for RepMin<Skipped<T, Skip, SKIP>, MIN>
+    > TypedNode<'i, R> for RepMin<T, Skip, SKIP, MIN>
 {
     #[inline]
     fn try_parse_with(
@@ -136,13 +270,16 @@ impl<
         stack: &mut Stack<Span<'i>>,
         tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
-        let mut vec = Vec::new();
+        let mut res = Self {
+            content: RepStorage::new(),
+            skipped: RepStorage::new(),
+        };
 
         for i in 0usize.. {
             match restore_on_none(stack, |stack| try_parse_unit(input, stack, tracker, i)) {
                 Some((next, matched)) => {
                     input = next;
-                    vec.push(matched);
+                    res.push(matched);
                 }
                 None => {
                     if i < MIN {
@@ -154,45 +291,159 @@ impl<
             }
         }
 
-        Some((input, Self { content: vec }))
+        Some((input, res))
     }
 }
-impl<T, IGNORED, const SKIP: usize, const MIN: usize> RepMin<Skipped<T, IGNORED, SKIP>, MIN> {
+impl<T, IGNORED, const SKIP: usize, const MIN: usize> RepMin<T, IGNORED, SKIP, MIN> {
+    fn push(&mut self, Skipped { skipped, matched }: Skipped<T, IGNORED, SKIP>) {
+        self.skipped.push(skipped);
+        self.content.push(matched);
+    }
     /// Returns an iterator over all matched expressions by reference.
-    pub fn iter_matched(&'_ self) -> Iter<'_, T, IGNORED, SKIP> {
-        self.content.iter().map(|s| &s.matched)
+    pub fn iter_matched(&'_ self) -> alloc::slice::Iter<'_, T> {
+        self.content.iter()
     }
     /// Returns an iterator over all matched expressions by value.
-    pub fn into_iter_matched(self) -> IntoIter<T, IGNORED, SKIP> {
-        self.content.into_iter().map(|s| s.matched)
+    pub fn into_iter_matched(self) -> alloc::vec::IntoIter<T> {
+        storage_into_vec(self.content).into_iter()
+    }
+    /// Returns an iterator over all skipped and matched expressions by reference.
+    pub fn iter_all(&'_ self) -> IterAll<'_, T, IGNORED, SKIP> {
+        self.skipped
+            .iter()
+            .zip(self.content.iter())
+            .map(|(skipped, matched)| Skipped {
+                skipped: skipped.each_ref(),
+                matched,
+            })
+    }
+    /// Returns an iterator over all skipped and matched expressions by value.
+    pub fn into_iter_all(self) -> IntoIterAll<T, IGNORED, SKIP> {
+        storage_into_vec(self.skipped)
+            .into_iter()
+            .zip(storage_into_vec(self.content))
+            .map(|(skipped, matched)| Skipped { skipped, matched })
+    }
+    /// Matched expressions as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.content
+    }
+    /// Number of matched expressions.
+    pub fn len(&self) -> usize {
+        self.content.len()
+    }
+    /// Whether nothing is matched.
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+    /// The `i`-th matched expression, or [`None`] if out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.content.get(i)
+    }
+    /// The last matched expression, or [`None`] if nothing is matched.
+    pub fn last(&self) -> Option<&T> {
+        self.content.last()
+    }
+    /// Clone matched expressions into a [`Vec`].
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.content.to_vec()
+    }
+    /// Take matched expressions, dropping skipped contents.
+    pub fn into_vec(self) -> Vec<T> {
+        storage_into_vec(self.content)
+    }
+    /// Skipped contents before each matched expression in positional order.
+    pub fn skipped(&self) -> &[[IGNORED; SKIP]] {
+        &self.skipped
+    }
+    /// Remove all matched expressions and skipped contents.
+    pub fn clear(&mut self) {
+        self.content.clear();
+        self.skipped.clear();
     }
 }
-impl<T, const MIN: usize> RepMin<T, MIN> {
-    /// Returns an iterator over all skipped or matched expressions by reference.
-    pub fn iter_all(&'_ self) -> alloc::slice::Iter<'_, T> {
-        self.content.iter()
+impl<T, IGNORED, const SKIP: usize> RepMin<T, IGNORED, SKIP, 0> {
+    /// The first matched expression, or [`None`] if nothing is matched.
+    pub fn first(&self) -> Option<&T> {
+        self.content.first()
     }
-    /// Returns an iterator over all skipped or matched expressions by value.
-    pub fn into_iter_all(self) -> alloc::vec::IntoIter<T> {
-        self.content.into_iter()
+}
+impl<T, IGNORED, const SKIP: usize> RepMin<T, IGNORED, SKIP, 1> {
+    /// The first matched expression, which always exists.
+    pub fn first(&self) -> &T {
+        self.split_first().0
+    }
+    /// Matched expressions after the first one.
+    pub fn rest(&self) -> &[T] {
+        self.split_first().1
+    }
+    /// The first matched expression and the rest ones.
+    pub fn split_first(&self) -> (&T, &[T]) {
+        self.content
+            .split_first()
+            .expect("At least one expression is matched.")
     }
 }
-impl<T: Clone + PartialEq, const MIN: usize> BoundWrapper for RepMin<T, MIN> {
+/// Fails with the given [`Vec`] if it's empty.
+///
+/// Nothing is skipped between matched expressions.
+impl<T, Skip: Default, const SKIP: usize> TryFrom<Vec<T>> for RepMin<T, Skip, SKIP, 1> {
+    type Error = Vec<T>;
+    fn try_from(content: Vec<T>) -> Result<Self, Self::Error> {
+        if content.is_empty() {
+            return Err(content);
+        }
+        let skipped = content
+            .iter()
+            .map(|_| core::array::from_fn(|_| Skip::default()))
+            .collect();
+        Ok(Self {
+            content: RepStorage::from(content),
+            skipped,
+        })
+    }
+}
+impl<T: Clone + PartialEq, Skip: Clone + PartialEq, const SKIP: usize, const MIN: usize>
+    BoundWrapper for RepMin<T, Skip, SKIP, MIN>
+{
     const MIN: usize = MIN;
     const MAX: usize = usize::MAX;
 }
+impl<T, Skip, const SKIP: usize, const MIN: usize> Index<usize> for RepMin<T, Skip, SKIP, MIN> {
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.content[index]
+    }
+}
+impl<T: Debug, Skip: Debug, const SKIP: usize, const MIN: usize> Debug
+    for RepMin<T, Skip, SKIP, MIN>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_rep(f, "RepMin", &self.content, &self.skipped)
+    }
+}
 
-/// Repeatably match `T` at least `MIN` times and at most `MAX` times.
-#[derive(Clone, Debug, Hash, PartialEq, Eq)]
-pub struct RepMinMax<T, const MIN: usize, const MAX: usize> {
-    /// Skipped and Matched expressions.
-    pub content: Vec<T>,
+/// Repeatably match `T` at least `MIN` times and at most `MAX` times,
+/// with `SKIP` `Skip`s between each two of them.
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct RepMinMax<T, Skip, const SKIP: usize, const MIN: usize, const MAX: usize> {
+    /// Matched expressions.
+    pub content: RepStorage<T>,
+    /// Skipped contents before each matched expression.
+    ///
+    /// Has the same length as `content`,
+    /// and the first one is always [`Default`] as nothing is skipped before the first expression.
+    pub skipped: RepStorage<[Skip; SKIP]>,
 }
 
-impl<T, const MAX: usize> Default for RepMinMax<T, 0, MAX> {
+impl<T, Skip, const SKIP: usize, const MAX: usize> Default for RepMinMax<T, Skip, SKIP, 0, MAX> {
     fn default() -> Self {
         Self {
-            content: Vec::new(),
+            content: RepStorage::new(),
+            skipped: RepStorage::new(),
         }
     }
 }
@@ -203,19 +454,19 @@ impl<
         Skip: NeverFailedTypedNode<'i, R>,
         const SKIP: usize,
         const MAX: usize,
-    > NeverFailedTypedNode<'i, R> for RepMinMax<Skipped<T, Skip, SKIP>, 0, MAX>
+    > NeverFailedTypedNode<'i, R> for RepMinMax<T, Skip, SKIP, 0, MAX>
 {
     #[inline]
     fn parse_with(mut input: Position<'i>, stack: &mut Stack<Span<'i>>) -> (Position<'i>, Self) {
-        let mut vec = Vec::new();
+        let mut res = Self::default();
 
-        let mut tracker = Tracker::new(input);
+        let mut tracker = Tracker::discarding(input);
 
         for i in 0..MAX {
             match restore_on_none(stack, |stack| try_parse_unit(input, stack, &mut tracker, i)) {
                 Some((next, matched)) => {
                     input = next;
-                    vec.push(matched);
+                    res.push(matched);
                 }
                 None => {
                     break;
@@ -223,7 +474,7 @@ impl<
             }
         }
 
-        (input, Self { content: vec })
+        (input, res)
     }
 }
 impl<
@@ -234,7 +485,7 @@ impl<
         const SKIP: usize,
         const MIN: usize,
         const MAX: usize,
-    > TypedNode<'i, R> for RepMinMax<Skipped<T, Skip, SKIP>, MIN, MAX>
+    > TypedNode<'i, R> for RepMinMax<T, Skip, SKIP, MIN, MAX>
 {
     #[inline]
     fn try_parse_with(
@@ -242,13 +493,16 @@ impl<
         stack: &mut Stack<Span<'i>>,
         tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
-        let mut vec = Vec::new();
+        let mut res = Self {
+            content: RepStorage::new(),
+            skipped: RepStorage::new(),
+        };
 
         for i in 0..MAX {
             match restore_on_none(stack, |stack| try_parse_unit(input, stack, tracker, i)) {
                 Some((next, matched)) => {
                     input = next;
-                    vec.push(matched);
+                    res.push(matched);
                 }
                 None => {
                     if i < MIN {
@@ -260,42 +514,178 @@ impl<
             }
         }
 
-        Some((input, Self { content: vec }))
+        Some((input, res))
     }
 }
 impl<T, IGNORED, const SKIP: usize, const MIN: usize, const MAX: usize>
-    RepMinMax<Skipped<T, IGNORED, SKIP>, MIN, MAX>
+    RepMinMax<T, IGNORED, SKIP, MIN, MAX>
 {
+    fn push(&mut self, Skipped { skipped, matched }: Skipped<T, IGNORED, SKIP>) {
+        self.skipped.push(skipped);
+        self.content.push(matched);
+    }
     /// Returns an iterator over all matched expressions by reference.
-    pub fn iter_matched(&'_ self) -> Iter<'_, T, IGNORED, SKIP> {
-        self.content.iter().map(|s| &s.matched)
+    pub fn iter_matched(&'_ self) -> alloc::slice::Iter<'_, T> {
+        self.content.iter()
     }
     /// Returns an iterator over all matched expressions by value.
-    pub fn into_iter_matched(self) -> IntoIter<T, IGNORED, SKIP> {
-        self.content.into_iter().map(|s| s.matched)
+    pub fn into_iter_matched(self) -> alloc::vec::IntoIter<T> {
+        storage_into_vec(self.content).into_iter()
     }
-}
-impl<T, const MIN: usize, const MAX: usize> RepMinMax<T, MIN, MAX> {
-    /// Returns an iterator over all skipped or matched expressions by reference.
-    pub fn iter_all(&'_ self) -> alloc::slice::Iter<'_, T> {
-        self.content.iter()
+    /// Returns an iterator over all skipped and matched expressions by reference.
+    pub fn iter_all(&'_ self) -> IterAll<'_, T, IGNORED, SKIP> {
+        self.skipped
+            .iter()
+            .zip(self.content.iter())
+            .map(|(skipped, matched)| Skipped {
+                skipped: skipped.each_ref(),
+                matched,
+            })
+    }
+    /// Returns an iterator over all skipped and matched expressions by value.
+    pub fn into_iter_all(self) -> IntoIterAll<T, IGNORED, SKIP> {
+        storage_into_vec(self.skipped)
+            .into_iter()
+            .zip(storage_into_vec(self.content))
+            .map(|(skipped, matched)| Skipped { skipped, matched })
+    }
+    /// Matched expressions as a slice.
+    pub fn as_slice(&self) -> &[T] {
+        &self.content
+    }
+    /// Number of matched expressions.
+    pub fn len(&self) -> usize {
+        self.content.len()
     }
-    /// Returns an iterator over all skipped or matched expressions by value.
-    pub fn into_iter_all(self) -> alloc::vec::IntoIter<T> {
-        self.content.into_iter()
+    /// Whether nothing is matched.
+    pub fn is_empty(&self) -> bool {
+        self.content.is_empty()
+    }
+    /// The `i`-th matched expression, or [`None`] if out of bounds.
+    pub fn get(&self, i: usize) -> Option<&T> {
+        self.content.get(i)
+    }
+    /// The first matched expression, or [`None`] if nothing is matched.
+    pub fn first(&self) -> Option<&T> {
+        self.content.first()
+    }
+    /// The last matched expression, or [`None`] if nothing is matched.
+    pub fn last(&self) -> Option<&T> {
+        self.content.last()
+    }
+    /// Clone matched expressions into a [`Vec`].
+    pub fn to_vec(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.content.to_vec()
+    }
+    /// Take matched expressions, dropping skipped contents.
+    pub fn into_vec(self) -> Vec<T> {
+        storage_into_vec(self.content)
+    }
+    /// Skipped contents before each matched expression in positional order.
+    pub fn skipped(&self) -> &[[IGNORED; SKIP]] {
+        &self.skipped
+    }
+    /// Remove all matched expressions and skipped contents.
+    pub fn clear(&mut self) {
+        self.content.clear();
+        self.skipped.clear();
     }
 }
-impl<T: Clone + PartialEq, const MIN: usize, const MAX: usize> BoundWrapper
-    for RepMinMax<T, MIN, MAX>
+impl<
+        T: Clone + PartialEq,
+        Skip: Clone + PartialEq,
+        const SKIP: usize,
+        const MIN: usize,
+        const MAX: usize,
+    > BoundWrapper for RepMinMax<T, Skip, SKIP, MIN, MAX>
 {
     const MIN: usize = MIN;
     const MAX: usize = MAX;
 }
+impl<T, Skip, const SKIP: usize, const MIN: usize, const MAX: usize> Index<usize>
+    for RepMinMax<T, Skip, SKIP, MIN, MAX>
+{
+    type Output = T;
+    fn index(&self, index: usize) -> &Self::Output {
+        &self.content[index]
+    }
+}
+impl<T: Debug, Skip: Debug, const SKIP: usize, const MIN: usize, const MAX: usize> Debug
+    for RepMinMax<T, Skip, SKIP, MIN, MAX>
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        fmt_rep(f, "RepMinMax", &self.content, &self.skipped)
+    }
+}
+
+/// Skipped contents are omitted if nothing can be skipped, just like [`Skipped`].
+fn fmt_rep<T: Debug, Skip: Debug, const SKIP: usize>(
+    f: &mut Formatter<'_>,
+    name: &str,
+    content: &[T],
+    skipped: &[[Skip; SKIP]],
+) -> core::fmt::Result {
+    #[cfg(feature = "compact_debug")]
+    let (content, skipped) = (
+        compact_debug::Elided(content),
+        compact_debug::Elided(skipped),
+    );
+    let mut res = f.debug_struct(name);
+    res.field("content", &content);
+    if SKIP > 0 {
+        res.field("skipped", &skipped);
+    }
+    res.finish()
+}
+
+/// Maximum number of elements shown in [`Debug`](core::fmt::Debug) of repetitions.
+///
+/// Only takes effect with feature `compact_debug`.
+pub const COMPACT_DEBUG_MAX_ELEMENTS: usize = 1024;
+
+#[cfg(feature = "compact_debug")]
+mod compact_debug {
+    use super::{AtomicRep, COMPACT_DEBUG_MAX_ELEMENTS};
+    use core::fmt::{Debug, Formatter, Result};
+
+    /// Elements of a repetition, elided after [`COMPACT_DEBUG_MAX_ELEMENTS`] ones.
+    pub(super) struct Elided<'n, T>(pub(super) &'n [T]);
+    impl<'n, T: Debug> Debug for Elided<'n, T> {
+        fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+            let mut list = f.debug_list();
+            list.entries(self.0.iter().take(COMPACT_DEBUG_MAX_ELEMENTS));
+            if self.0.len() > COMPACT_DEBUG_MAX_ELEMENTS {
+                let rest = self.0.len() - COMPACT_DEBUG_MAX_ELEMENTS;
+                list.entry(&format_args!("… +{} elements", rest));
+            }
+            list.finish()
+        }
+    }
+
+    macro_rules! impl_debug {
+        ($name:ident, $($args:ident),*) => {
+            impl<T: Debug, $(const $args: usize, )*> Debug for $name<T, $($args, )*> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+                    f.debug_struct(::core::stringify!($name))
+                        .field("content", &Elided(&self.content))
+                        .finish()
+                }
+            }
+        };
+    }
+
+    impl_debug!(AtomicRep,);
+}
 
 /// Repeat arbitrary times.
-pub type Rep<T, IGNORED, const SKIP: usize> = RepMin<Skipped<T, IGNORED, SKIP>, 0>;
+pub type Rep<T, IGNORED, const SKIP: usize> = RepMin<T, IGNORED, SKIP, 0>;
 /// Repeat at least one times.
-pub type RepOnce<T, IGNORED, const SKIP: usize> = RepMin<Skipped<T, IGNORED, SKIP>, 1>;
+///
+/// The first matched expression is accessed without [`Option`] through `first`, `rest` and `split_first`.
+pub type RepOnce<T, IGNORED, const SKIP: usize> = RepMin<T, IGNORED, SKIP, 1>;
 
 fn try_parse_unit<
     'i,
@@ -320,6 +710,7 @@ fn try_parse_unit<
     });
     let (next, matched) = T::try_parse_with(input, stack, tracker)?;
     input = next;
+    tracker.reach(input);
     let res = Skipped { skipped, matched };
     Some((input, res))
 }
@@ -0,0 +1,180 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Building random inputs from a grammar, used by `generate_random` generated with `#[emit_generator]`.
+//!
+//! A [`Generator`] writes matched text in order and keeps its own stack of pushed text,
+//! and [`RandomSource`] provides the random numbers, so that no random number crate is required.
+
+use alloc::{string::String, vec::Vec};
+
+/// Source of random numbers.
+///
+/// It's implemented by [`SplitMix64`], and can be implemented for generators from other crates,
+/// such as by forwarding to `RngCore::next_u64` of **rand**.
+pub trait RandomSource {
+    /// Next random number.
+    fn next_u64(&mut self) -> u64;
+}
+
+impl<R: RandomSource + ?Sized> RandomSource for &mut R {
+    fn next_u64(&mut self) -> u64 {
+        (**self).next_u64()
+    }
+}
+
+/// A small and seedable [`RandomSource`], see <https://prng.di.unimi.it/splitmix64.c>.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Create from a seed. The same seed gives the same numbers.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl RandomSource for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+}
+
+/// Output and stack of a random input being generated.
+///
+/// Stack operations work on text pushed by the generator itself,
+/// so `PEEK` and `POP` repeat what `PUSH` wrote, and write nothing if the stack is empty.
+pub struct Generator<'r> {
+    rng: &'r mut dyn RandomSource,
+    output: String,
+    stack: Vec<String>,
+}
+
+impl<'r> Generator<'r> {
+    /// Create an empty generator.
+    pub fn new(rng: &'r mut dyn RandomSource) -> Self {
+        Self {
+            rng,
+            output: String::new(),
+            stack: Vec::new(),
+        }
+    }
+    /// A random number in `0..n`. `n` must not be `0`.
+    pub fn below(&mut self, n: usize) -> usize {
+        (self.rng.next_u64() % n as u64) as usize
+    }
+    /// A random boolean.
+    pub fn coin(&mut self) -> bool {
+        self.rng.next_u64() & 1 == 1
+    }
+    /// Length of text written so far.
+    pub fn len(&self) -> usize {
+        self.output.len()
+    }
+    /// Whether nothing has been written.
+    pub fn is_empty(&self) -> bool {
+        self.output.is_empty()
+    }
+    /// Write a string.
+    pub fn push_str(&mut self, s: &str) {
+        self.output.push_str(s);
+    }
+    /// Write a string, with the case of each ASCII letter chosen at random.
+    pub fn push_insens(&mut self, s: &str) {
+        for c in s.chars() {
+            let c = match self.coin() {
+                true => c.to_ascii_uppercase(),
+                false => c.to_ascii_lowercase(),
+            };
+            self.output.push(c);
+        }
+    }
+    /// Write a character in `min..=max`.
+    pub fn push_range(&mut self, min: char, max: char) {
+        let (min, max) = (min as u32, max as u32);
+        let c = min + self.below((max - min) as usize + 1) as u32;
+        // Surrogates are not characters, so fall back to the lower bound.
+        self.output
+            .push(char::from_u32(c).unwrap_or(char::from_u32(min).unwrap()));
+    }
+    /// Write a character in one of the ranges, chosen at random.
+    pub fn push_ranges(&mut self, ranges: &[(char, char)]) {
+        let (min, max) = ranges[self.below(ranges.len())];
+        self.push_range(min, max);
+    }
+    /// Write a printable ASCII character, for `ANY`.
+    pub fn push_any(&mut self) {
+        self.push_range(' ', '~');
+    }
+    /// Push text written since `start` onto the stack.
+    pub fn push_stack(&mut self, start: usize) {
+        self.stack.push(String::from(&self.output[start..]));
+    }
+    /// Write the top of the stack.
+    pub fn peek(&mut self) {
+        if let Some(top) = self.stack.last() {
+            self.output.push_str(top);
+        }
+    }
+    /// Write and pop the top of the stack.
+    pub fn pop(&mut self) {
+        if let Some(top) = self.stack.pop() {
+            self.output.push_str(&top);
+        }
+    }
+    /// Pop the top of the stack without writing it.
+    pub fn drop_top(&mut self) {
+        self.stack.pop();
+    }
+    /// Write the whole stack from the top to the bottom.
+    pub fn peek_all(&mut self) {
+        for s in self.stack.iter().rev() {
+            self.output.push_str(s);
+        }
+    }
+    /// Write the whole stack from the top to the bottom, and clear it.
+    pub fn pop_all(&mut self) {
+        self.peek_all();
+        self.stack.clear();
+    }
+    /// Write `[start..end]` of the stack, with the same indices as `PEEK[start..end]`.
+    pub fn peek_slice(&mut self, start: i32, end: Option<i32>) {
+        let len = self.stack.len() as i32;
+        let normalize = |i: i32| (if i < 0 { len + i } else { i }).clamp(0, len) as usize;
+        let start = normalize(start);
+        let end = end.map_or(len as usize, normalize);
+        for s in &self.stack[start..end.max(start)] {
+            self.output.push_str(s);
+        }
+    }
+    /// Sometimes write one of `trivia`, such as `WHITESPACE` and `COMMENT`,
+    /// between two items in a non-atomic rule.
+    pub fn skip(
+        &mut self,
+        depth: usize,
+        atomic: bool,
+        trivia: &[fn(&mut Generator<'_>, usize, bool)],
+    ) {
+        if atomic || depth == 0 || trivia.is_empty() || self.below(4) != 0 {
+            return;
+        }
+        let rule = trivia[self.below(trivia.len())];
+        rule(self, depth - 1, true);
+    }
+    /// Take the generated text.
+    pub fn finish(self) -> String {
+        self.output
+    }
+}
@@ -10,9 +10,13 @@
 //! Macros and functions for defining structs, most of which are [RuleStruct](crate::RuleStruct).
 
 use crate::{
-    predefined_node::EOI, tracker::Tracker, NeverFailedTypedNode, Position, RuleType, Span, Stack,
-    TypedNode,
+    error::ParseResult,
+    predefined_node::{restore_on_none, EOI},
+    tracker::Tracker,
+    NeverFailedTypedNode, Position, RuleType, RuleWrapper, Span, Stack, TypedNode,
 };
+use alloc::boxed::Box;
+use core::ops::ControlFlow;
 
 /// Implement [`Pairs`](crate::iterators::Pairs) for a struct that is a [`Pair`](crate::iterators::Pair).
 ///
@@ -89,12 +93,15 @@ macro_rules! impl_pairs_with_inner {
 /// - `$name:ident`. Name of generated struct.
 /// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
 /// - `$inner:ty`. Type of inner parsing expression.
-/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
 #[macro_export]
 macro_rules! impl_pairs {
     ($name:ident, $Rule:ty, $inner:ty, Expression) => {
         $crate::impl_pairs_with_inner!($name, $Rule, $inner);
     };
+    ($name:ident, $Rule:ty, $inner:ty, SpannedExpression) => {
+        $crate::impl_pairs_with_inner!($name, $Rule, $inner);
+    };
     ($name:ident, $Rule:ty, $inner:ty, $emit:tt) => {
         $crate::impl_pairs_with_self!($name, $Rule);
     };
@@ -133,7 +140,7 @@ macro_rules! impl_deref_with_content {
 ///
 /// - `$name:ident`. Name of generated struct.
 /// - `$inner:ty`. Type of inner parsing expression.
-/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
 #[macro_export]
 macro_rules! impl_deref {
     ($name:ident, $inner:ty, Span) => {};
@@ -179,6 +186,20 @@ macro_rules! impl_pair_with_empty {
                 $crate::re_exported::Vec::new().into_iter()
             }
         }
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::iterators::AnyPair<'i, $Rule>
+            for $name<'i, INHERITED>
+        {
+            fn inner_pairs<'n>(
+                &'n self,
+            ) -> $crate::re_exported::vec::IntoIter<
+                &'n (dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n),
+            >
+            where
+                'i: 'n,
+            {
+                $crate::re_exported::Vec::new().into_iter()
+            }
+        }
     };
 }
 
@@ -228,6 +249,34 @@ macro_rules! impl_pair_with_content {
                 i.collect::<$crate::re_exported::Vec<_>>().into_iter()
             }
         }
+        $crate::impl_any_pair_with_content!($name, $Rule);
+    };
+}
+
+/// Implement [`AnyPair`](crate::iterators::AnyPair) for a struct with inner [`Pair`](crate::iterators::Pair)s,
+/// by forwarding to its [`Pair`](crate::iterators::Pair) impl.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
+#[macro_export]
+macro_rules! impl_any_pair_with_content {
+    ($name:ident, $Rule:ty) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::iterators::AnyPair<'i, $Rule>
+            for $name<'i, INHERITED>
+        {
+            fn inner_pairs<'n>(
+                &'n self,
+            ) -> $crate::re_exported::vec::IntoIter<
+                &'n (dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n),
+            >
+            where
+                'i: 'n,
+            {
+                <Self as $crate::iterators::Pair<'i, 'n, $Rule>>::inner(self)
+            }
+        }
     };
 }
 
@@ -284,10 +333,22 @@ macro_rules! impl_rule_struct {
 /// - `$rule:expr`. Rule enumeration.
 /// - `$inner:ty`. Type of inner parsing expression.
 /// - `$atomicity:tt`. `true`, `false` or `INHERITED`.
-/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
 #[macro_export]
 macro_rules! impl_pair {
-    ($name:ident, $Rule:ty, $rule:expr, $inner:ty, $atomicity:expr, Expression) => {};
+    ($name:ident, $Rule:ty, $rule:expr, $inner:ty, $atomicity:tt, Expression) => {};
+    ($name:ident, $Rule:ty, $rule:expr, $inner:ty, $atomicity:tt, SpannedExpression) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::Spanned<'i, $Rule>
+            for $name<'i, INHERITED>
+        {
+            fn span(&self) -> $crate::Span<'i> {
+                self.span
+            }
+        }
+    };
+    ($name:ident, $Rule:ty, $rule:expr, $inner:ty, $atomicity:tt, Span) => {
+        $crate::impl_pair_with_empty!($name, $Rule, $rule);
+    };
     ($name:ident, $Rule:ty, $rule:expr, $inner:ty, true, $emit:tt) => {
         $crate::impl_pair_with_empty!($name, $Rule, $rule);
     };
@@ -320,12 +381,7 @@ macro_rules! impl_parse {
                 stack: &mut $crate::Stack<$crate::Span<'i>>,
                 tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
             ) -> ::core::option::Option<Self> {
-                $crate::rule::parse_without_ignore::<$Rule, Self>(
-                    input,
-                    stack,
-                    tracker,
-                    <$Rule>::EOI,
-                )
+                $crate::rule::parse_without_ignore::<$Rule, Self>(input, stack, tracker)
             }
         }
     };
@@ -337,7 +393,7 @@ macro_rules! impl_parse {
                 stack: &mut $crate::Stack<$crate::Span<'i>>,
                 tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
             ) -> ::core::option::Option<Self> {
-                $crate::rule::parse::<$Rule, Self, $ignored>(input, stack, tracker, <$Rule>::EOI)
+                $crate::rule::parse::<$Rule, Self, $ignored>(input, stack, tracker)
             }
         }
     };
@@ -345,13 +401,19 @@ macro_rules! impl_parse {
 
 /// Implement [TypedNode::try_parse_with](crate::TypedNode::try_parse_with()) for structs.
 ///
+/// Non-silent rules fail fast if the remaining input is shorter than [`MIN_LEN`](crate::RuleWrapper::MIN_LEN)
+/// and there is a choice branch to fall back to. Then the rule is tracked as if it matched nothing.
+///
+/// All rules fail if they are nested deeper than [`RECURSION_LIMIT`](crate::RuleWrapper::RECURSION_LIMIT),
+/// counting rules that [count towards it](crate::RuleWrapper::COUNTS_RECURSION).
+///
 /// Arguments:
 ///
 /// - `$name:ident`. Name of generated struct.
 /// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
 /// - `$inner:ty`. Type of inner parsing expression.
 /// - `$atomicity:tt`. `true`, `false` or `INHERITED`.
-/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
 #[macro_export]
 macro_rules! impl_try_parse_with {
     ($name:ident, $Rule:ty, $inner:ty, $atomicity:expr, Expression) => {
@@ -364,7 +426,9 @@ macro_rules! impl_try_parse_with {
                 stack: &mut $crate::Stack<$crate::Span<'i>>,
                 tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
             ) -> ::core::option::Option<($crate::Position<'i>, Self)> {
-                let (input, content) = <$inner>::try_parse_with(input, stack, tracker)?;
+                let (input, content) = tracker.nest_rule_during::<Self, _>(input, |tracker| {
+                    <$inner>::try_parse_with(input, stack, tracker)
+                })?;
                 let content = content.into();
                 Some((
                     input,
@@ -376,6 +440,26 @@ macro_rules! impl_try_parse_with {
             }
         }
     };
+    ($name:ident, $Rule:ty, $inner:ty, $atomicity:expr, SpannedExpression) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::TypedNode<'i, $Rule>
+            for $name<'i, INHERITED>
+        {
+            #[inline]
+            fn try_parse_with(
+                input: $crate::Position<'i>,
+                stack: &mut $crate::Stack<$crate::Span<'i>>,
+                tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
+            ) -> ::core::option::Option<($crate::Position<'i>, Self)> {
+                let start = input;
+                let (input, content) = tracker.nest_rule_during::<Self, _>(input, |tracker| {
+                    <$inner>::try_parse_with(input, stack, tracker)
+                })?;
+                let span = start.span(&input);
+                let content = content.into();
+                Some((input, Self { content, span }))
+            }
+        }
+    };
     ($name:ident, $Rule:ty, $inner:ty, $atomicity:expr, Span) => {
         impl<'i, const INHERITED: ::core::primitive::usize> $crate::TypedNode<'i, $Rule>
             for $name<'i, INHERITED>
@@ -387,6 +471,9 @@ macro_rules! impl_try_parse_with {
                 tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
             ) -> ::core::option::Option<($crate::Position<'i>, Self)> {
                 tracker.record_during(input, |tracker| {
+                    if tracker.prunes(input, <Self as $crate::RuleWrapper<$Rule>>::MIN_LEN) {
+                        return None;
+                    }
                     let start = input;
                     let (input, _) = <$inner>::try_parse_with(input, stack, tracker)?;
                     let span = start.span(&input);
@@ -406,6 +493,9 @@ macro_rules! impl_try_parse_with {
                 tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
             ) -> ::core::option::Option<($crate::Position<'i>, Self)> {
                 tracker.record_during(input, |tracker| {
+                    if tracker.prunes(input, <Self as $crate::RuleWrapper<$Rule>>::MIN_LEN) {
+                        return None;
+                    }
                     let start = input;
                     let (input, content) = <$inner>::try_parse_with(input, stack, tracker)?;
                     let span = start.span(&input);
@@ -424,14 +514,22 @@ macro_rules! impl_try_parse_with {
 /// - `$name:ident`. Name of generated struct.
 /// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
 /// - `$rule:expr`. Rule enumeration.
+/// - `min_len = $min_len:expr`. Optional. See [`RuleWrapper::MIN_LEN`](crate::RuleWrapper::MIN_LEN).
+/// - `error_label = $error_label:expr`. Optional. See [`RuleWrapper::ERROR_LABEL`](crate::RuleWrapper::ERROR_LABEL).
+/// - `recursion_limit = $recursion_limit:expr`. Optional. See [`RuleWrapper::RECURSION_LIMIT`](crate::RuleWrapper::RECURSION_LIMIT).
+/// - `counts_recursion = $counts_recursion:expr`. Optional. See [`RuleWrapper::COUNTS_RECURSION`](crate::RuleWrapper::COUNTS_RECURSION).
 #[macro_export]
 macro_rules! impl_rule_wrapper {
-    ($name:ident, $Rule:ty, $rule:expr) => {
+    ($name:ident, $Rule:ty, $rule:expr $(, min_len = $min_len:expr)? $(, error_label = $error_label:expr)? $(, recursion_limit = $recursion_limit:expr)? $(, counts_recursion = $counts_recursion:expr)?) => {
         impl<'i, const INHERITED: ::core::primitive::usize> $crate::RuleWrapper<$Rule>
             for $name<'i, INHERITED>
         {
             const RULE: $Rule = $rule;
             type Rule = $Rule;
+            $(const MIN_LEN: ::core::primitive::usize = $min_len;)?
+            $(const ERROR_LABEL: ::core::option::Option<&'static ::core::primitive::str> = $error_label;)?
+            $(const RECURSION_LIMIT: ::core::primitive::usize = $recursion_limit;)?
+            $(const COUNTS_RECURSION: ::core::primitive::bool = $counts_recursion;)?
         }
     };
 }
@@ -459,14 +557,17 @@ macro_rules! rule_inner {
 /// - `$name:ident`. Name of generated struct.
 /// - `$($doc:literal)*`. A list of strings that is prepended to generated struct as document comments.
 /// - `$inner:ty`. Type of inner parsing expression.
-/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
 /// - `$boxed:tt`. `true` or `false`.
+///
+/// Leading attributes are forwarded onto the struct.
 #[macro_export]
 macro_rules! declare_rule_struct {
-    ($name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Expression, $boxed:tt) => {
+    ($(#[$attr:meta])* $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Expression, $boxed:tt) => {
         $(
             #[doc = $doc]
         )*
+        $(#[$attr])*
         #[allow(non_camel_case_types)]
         #[derive(Clone, Hash, PartialEq, Eq)]
         pub struct $name<'i, const INHERITED: ::core::primitive::usize = 1> {
@@ -474,37 +575,45 @@ macro_rules! declare_rule_struct {
             pub content: $crate::rule_inner!($inner, $boxed),
             _phantom: ::core::marker::PhantomData<&'i ::core::primitive::str>,
         }
-        impl<'i, const INHERITED: ::core::primitive::usize> ::core::fmt::Debug for $name<'i, INHERITED> {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_struct(::core::stringify!($name))
-                    .field("content", &self.content)
-                    .finish()
-            }
-        }
+        $crate::impl_debug!($name, Expression);
         $crate::impl_rule_struct!($name, $Rule, $inner, $boxed);
     };
-    ($name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Span, $boxed:tt) => {
+    ($(#[$attr:meta])* $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, SpannedExpression, $boxed:tt) => {
         $(
             #[doc = $doc]
         )*
+        $(#[$attr])*
         #[allow(non_camel_case_types)]
         #[derive(Clone, Hash, PartialEq, Eq)]
         pub struct $name<'i, const INHERITED: ::core::primitive::usize = 1> {
+            /// Matched expression.
+            pub content: $crate::rule_inner!($inner, $boxed),
             /// Span of matched expression.
             pub span: $crate::Span<'i>,
         }
-        impl<'i, const INHERITED: ::core::primitive::usize> ::core::fmt::Debug for $name<'i, INHERITED> {
-            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
-                f.debug_struct(::core::stringify!($name))
-                    .field("span", &self.span)
-                    .finish()
-            }
+        $crate::impl_debug!($name, SpannedExpression);
+        $crate::impl_ord_by_span!($name);
+        $crate::impl_rule_struct!($name, $Rule, $inner, $boxed);
+    };
+    ($(#[$attr:meta])* $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Span, $boxed:tt) => {
+        $(
+            #[doc = $doc]
+        )*
+        $(#[$attr])*
+        #[allow(non_camel_case_types)]
+        #[derive(Clone, Hash, PartialEq, Eq)]
+        pub struct $name<'i, const INHERITED: ::core::primitive::usize = 1> {
+            /// Span of matched expression.
+            pub span: $crate::Span<'i>,
         }
+        $crate::impl_debug!($name, Span);
+        $crate::impl_ord_by_span!($name);
     };
-    ($name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Both, $boxed:tt) => {
+    ($(#[$attr:meta])* $name:ident, $($doc:literal)*, $Rule:ty, $inner:ty, Both, $boxed:tt) => {
         $(
             #[doc = $doc]
         )*
+        $(#[$attr])*
         #[allow(non_camel_case_types)]
         #[derive(Clone, Hash, PartialEq, Eq)]
         pub struct $name<'i, const INHERITED: ::core::primitive::usize = 1> {
@@ -513,7 +622,46 @@ macro_rules! declare_rule_struct {
             /// Span of matched expression.
             pub span: $crate::Span<'i>,
         }
-        impl<'i, const INHERITED: ::core::primitive::usize> ::core::fmt::Debug for $name<'i, INHERITED> {
+        $crate::impl_debug!($name, Both);
+        $crate::impl_ord_by_span!($name);
+        $crate::impl_rule_struct!($name, $Rule, $inner, $boxed);
+    };
+}
+
+/// Implement [`Debug`](core::fmt::Debug) for the struct, named after the rule.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+#[macro_export]
+macro_rules! impl_debug {
+    ($name:ident, Expression) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> ::core::fmt::Debug
+            for $name<'i, INHERITED>
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(::core::stringify!($name))
+                    .field("content", &self.content)
+                    .finish()
+            }
+        }
+    };
+    ($name:ident, Span) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> ::core::fmt::Debug
+            for $name<'i, INHERITED>
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.debug_struct(::core::stringify!($name))
+                    .field("span", &self.span)
+                    .finish()
+            }
+        }
+    };
+    ($name:ident, $emission:tt) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> ::core::fmt::Debug
+            for $name<'i, INHERITED>
+        {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 f.debug_struct(::core::stringify!($name))
                     .field("content", &self.content)
@@ -521,7 +669,311 @@ macro_rules! declare_rule_struct {
                     .finish()
             }
         }
-        $crate::impl_rule_struct!($name, $Rule, $inner, $boxed);
+    };
+}
+
+/// Implement [`PartialOrd`] and [`Ord`] for structs with a span, ordering them by their spans.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+#[macro_export]
+macro_rules! impl_ord_by_span {
+    ($name:ident) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> ::core::cmp::PartialOrd
+            for $name<'i, INHERITED>
+        {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+        impl<'i, const INHERITED: ::core::primitive::usize> ::core::cmp::Ord
+            for $name<'i, INHERITED>
+        {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.span, &other.span)
+            }
+        }
+    };
+}
+
+/// Implement [`MatchedText`](crate::MatchedText) and [`Display`](core::fmt::Display) for the struct.
+///
+/// - Silent rules (`Expression` and `SpannedExpression`) write their content, including skipped contents.
+/// - Other rules (`Span` and `Both`) write their span.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+#[macro_export]
+macro_rules! impl_display {
+    ($name:ident, SpannedExpression) => {
+        $crate::impl_display!($name, Expression);
+    };
+    ($name:ident, Expression) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::MatchedText
+            for $name<'i, INHERITED>
+        {
+            fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::MatchedText::fmt_matched(&self.content, f)
+            }
+        }
+        $crate::impl_display!($name);
+    };
+    ($name:ident, $emission:tt) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::MatchedText
+            for $name<'i, INHERITED>
+        {
+            fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.span.as_str())
+            }
+        }
+        $crate::impl_display!($name);
+    };
+    ($name:ident) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> ::core::fmt::Display
+            for $name<'i, INHERITED>
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::MatchedText::fmt_matched(self, f)
+            }
+        }
+    };
+}
+
+/// Implement [`ContentEq`](crate::ContentEq) for the struct.
+///
+/// - Atomic rules (`Span`) compare their matched strings.
+/// - Other rules (`Expression`, `SpannedExpression` and `Both`) compare their content,
+///   and forward [`ContentEq::content_eq_ignore_case`](crate::ContentEq::content_eq_ignore_case) to it.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+#[macro_export]
+macro_rules! impl_content_eq {
+    ($name:ident, Span) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::ContentEq
+            for $name<'i, INHERITED>
+        {
+            fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+                self.span.as_str() == other.span.as_str()
+            }
+            fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(self.span.as_str(), state)
+            }
+        }
+    };
+    ($name:ident, $emission:tt) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::ContentEq
+            for $name<'i, INHERITED>
+        {
+            fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+                $crate::ContentEq::content_eq(&self.content, &other.content)
+            }
+            fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                $crate::ContentEq::content_hash(&self.content, state)
+            }
+            fn content_eq_ignore_case(&self, other: &Self) -> ::core::primitive::bool {
+                $crate::ContentEq::content_eq_ignore_case(&self.content, &other.content)
+            }
+        }
+    };
+}
+
+/// Implement [`Offset`](crate::incremental::Offset) for the struct.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+#[macro_export]
+macro_rules! impl_offset {
+    ($name:ident, Expression) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::incremental::Offset<'i>
+            for $name<'i, INHERITED>
+        {
+            fn offset(
+                &mut self,
+                input: &'i ::core::primitive::str,
+                from: ::core::primitive::usize,
+                delta: ::core::primitive::isize,
+            ) {
+                $crate::incremental::Offset::offset(&mut self.content, input, from, delta)
+            }
+        }
+    };
+    ($name:ident, Span) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::incremental::Offset<'i>
+            for $name<'i, INHERITED>
+        {
+            fn offset(
+                &mut self,
+                input: &'i ::core::primitive::str,
+                from: ::core::primitive::usize,
+                delta: ::core::primitive::isize,
+            ) {
+                $crate::incremental::Offset::offset(&mut self.span, input, from, delta)
+            }
+        }
+    };
+    ($name:ident, SpannedExpression) => {
+        $crate::impl_offset!($name, Both);
+    };
+    ($name:ident, Both) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::incremental::Offset<'i>
+            for $name<'i, INHERITED>
+        {
+            fn offset(
+                &mut self,
+                input: &'i ::core::primitive::str,
+                from: ::core::primitive::usize,
+                delta: ::core::primitive::isize,
+            ) {
+                $crate::incremental::Offset::offset(&mut self.content, input, from, delta);
+                $crate::incremental::Offset::offset(&mut self.span, input, from, delta);
+            }
+        }
+    };
+}
+
+/// Implement `serde::Serialize` for the struct if feature `serde` is enabled.
+///
+/// - Silent rules (`Expression` and `SpannedExpression`) are serialized as their content.
+/// - Atomic rules (`Span`) are serialized as the matched string.
+/// - Other rules (`Both`) are serialized as a struct with `content` and `span`.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! impl_serialize {
+    ($name:ident, SpannedExpression) => {
+        $crate::impl_serialize!($name, Expression);
+    };
+    ($name:ident, Expression) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::serde::Serialize
+            for $name<'i, INHERITED>
+        {
+            fn serialize<S: $crate::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::serde::Serialize::serialize(&self.content, serializer)
+            }
+        }
+    };
+    ($name:ident, Span) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::serde::Serialize
+            for $name<'i, INHERITED>
+        {
+            fn serialize<S: $crate::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                serializer.serialize_str(self.span.as_str())
+            }
+        }
+    };
+    ($name:ident, Both) => {
+        impl<'i, const INHERITED: ::core::primitive::usize> $crate::serde::Serialize
+            for $name<'i, INHERITED>
+        {
+            fn serialize<S: $crate::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                use $crate::serde::ser::SerializeStruct;
+                let mut s = serializer.serialize_struct(::core::stringify!($name), 2)?;
+                s.serialize_field("content", &self.content)?;
+                s.serialize_field("span", &self.span)?;
+                s.end()
+            }
+        }
+    };
+}
+
+/// Implement `serde::Serialize` for the struct if feature `serde` is enabled.
+///
+/// Feature `serde` is disabled, so nothing is generated.
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! impl_serialize {
+    ($name:ident, $emission:tt) => {};
+}
+
+/// Implement `serde::Serialize` for a struct without lifetime as its `content`, if feature `serde` is enabled.
+///
+/// Used by generated unicode properties.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of the struct.
+#[cfg(feature = "serde")]
+#[macro_export]
+macro_rules! impl_serialize_content {
+    ($name:ident) => {
+        impl $crate::serde::Serialize for $name {
+            fn serialize<S: $crate::serde::Serializer>(
+                &self,
+                serializer: S,
+            ) -> ::core::result::Result<S::Ok, S::Error> {
+                $crate::serde::Serialize::serialize(&self.content, serializer)
+            }
+        }
+    };
+}
+
+/// Implement `serde::Serialize` for a struct without lifetime as its `content`, if feature `serde` is enabled.
+///
+/// Feature `serde` is disabled, so nothing is generated.
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+macro_rules! impl_serialize_content {
+    ($name:ident) => {};
+}
+
+/// Split leading attributes of a rule into `#[cfg]`s and the others,
+/// and call `$callback!(@split [cfgs] [others] ...)`.
+///
+/// `#[cfg]`s are applied to all generated items, while the others are only forwarded onto the struct.
+#[macro_export]
+macro_rules! split_cfg_attrs {
+    ($callback:ident, [$($cfg:tt)*] [$($attr:tt)*] #[cfg $($c:tt)*] $($rest:tt)*) => {
+        $crate::split_cfg_attrs!($callback, [$($cfg)* #[cfg $($c)*]] [$($attr)*] $($rest)*);
+    };
+    ($callback:ident, [$($cfg:tt)*] [$($attr:tt)*] #[$($a:tt)*] $($rest:tt)*) => {
+        $crate::split_cfg_attrs!($callback, [$($cfg)*] [$($attr)* #[$($a)*]] $($rest)*);
+    };
+    ($callback:ident, [$($cfg:tt)*] [$($attr:tt)*] $($rest:tt)*) => {
+        $crate::$callback!(@split [$($cfg)*] [$($attr)*] $($rest)*);
+    };
+}
+
+/// Implement accessers as inherent methods.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `{ $($accesser:ident => $($path:tt).+ : $ty:ty),* }`. Optional. Each accesser returns `&self.$path` as `&$ty`.
+#[macro_export]
+macro_rules! impl_accessers {
+    ($name:ident) => {};
+    ($name:ident, { $($accesser:ident => $($path:tt).+ : $ty:ty),* $(,)? }) => {
+        #[allow(non_snake_case)]
+        impl<'i, const INHERITED: ::core::primitive::usize> $name<'i, INHERITED> {
+            $(
+                #[doc = ::core::concat!("A helper function to access `", ::core::stringify!($($path).+), "`.")]
+                #[inline]
+                pub fn $accesser(&self) -> &$ty {
+                    &self.$($path).+
+                }
+            )*
+        }
     };
 }
 
@@ -539,7 +991,17 @@ macro_rules! declare_rule_struct {
 ///   Must implement [NeverFailedTypedNode](`crate::NeverFailedTypedNode`). Normally using [Skipped](`crate::predefined_node::Skipped`).
 ///
 /// - `$atomicity:tt`. `true`, `false` or `INHERITED`.
-/// - `$emission:tt`. `Span`, `Expression` or `Both`.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+/// - `$boxed:tt`. `true` or `false`.
+/// - `min_len = $min_len:literal`. Optional. See [`RuleWrapper::MIN_LEN`](crate::RuleWrapper::MIN_LEN).
+/// - `error_label = $error_label:literal`. Optional. See [`RuleWrapper::ERROR_LABEL`](crate::RuleWrapper::ERROR_LABEL).
+/// - `recursion_limit = $recursion_limit:literal`. Optional. See [`RuleWrapper::RECURSION_LIMIT`](crate::RuleWrapper::RECURSION_LIMIT).
+/// - `counts_recursion = $counts_recursion:literal`. Optional. See [`RuleWrapper::COUNTS_RECURSION`](crate::RuleWrapper::COUNTS_RECURSION).
+/// - `$accessers:tt`. Optional. Accessers in the form of `{ name => path: Type, ... }`,
+///   see [impl_accessers](`crate::impl_accessers!`).
+///
+/// Leading attributes, including doc comments, are forwarded onto the struct,
+/// except that `#[cfg]`s are applied to all generated items.
 ///
 /// See the below macros that reference this:
 /// - [atomic_rule](`crate::atomic_rule!`).
@@ -549,14 +1011,34 @@ macro_rules! declare_rule_struct {
 /// - [silent_rule](`crate::silent_rule!`).
 #[macro_export]
 macro_rules! rule {
-    ($name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $atomicity:tt, $emission:tt, $boxed:tt) => {
-        $crate::declare_rule_struct!($name, $($doc)*, $Rule, $inner, $emission, $boxed);
-        $crate::impl_rule_wrapper!($name, $Rule, $rule);
+    (@split [$(#[$cfg:meta])*] [$(#[$attr:meta])*] $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $atomicity:tt, $emission:tt, $boxed:tt $(, min_len = $min_len:literal)? $(, error_label = $error_label:literal)? $(, recursion_limit = $recursion_limit:literal)? $(, counts_recursion = $counts_recursion:literal)? $(, { $($accessers:tt)* })?) => {
+        $(#[$cfg])*
+        $crate::declare_rule_struct!($(#[$attr])* $name, $($doc)*, $Rule, $inner, $emission, $boxed);
+        $(#[$cfg])*
+        $crate::impl_rule_wrapper!($name, $Rule, $rule $(, min_len = $min_len)? $(, error_label = ::core::option::Option::Some($error_label))? $(, recursion_limit = $recursion_limit)? $(, counts_recursion = $counts_recursion)?);
+        $(#[$cfg])*
         $crate::impl_try_parse_with!($name, $Rule, $inner, $atomicity, $emission);
+        $(#[$cfg])*
         $crate::impl_parse!($name, $Rule, $ignored, $atomicity);
+        $(#[$cfg])*
         $crate::impl_deref!($name, $inner, $emission);
+        $(#[$cfg])*
         $crate::impl_pairs!($name, $Rule, $inner, $emission);
+        $(#[$cfg])*
         $crate::impl_pair!($name, $Rule, $rule, $inner, $atomicity, $emission);
+        $(#[$cfg])*
+        $crate::impl_display!($name, $emission);
+        $(#[$cfg])*
+        $crate::impl_content_eq!($name, $emission);
+        $(#[$cfg])*
+        $crate::impl_offset!($name, $emission);
+        $(#[$cfg])*
+        $crate::impl_serialize!($name, $emission);
+        $(#[$cfg])*
+        $crate::impl_accessers!($name $(, { $($accessers)* })?);
+    };
+    ($($tt:tt)*) => {
+        $crate::split_cfg_attrs!(rule, [] [] $($tt)*);
     };
 }
 
@@ -569,10 +1051,13 @@ macro_rules! rule {
 /// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
 /// - `$rule:expr`. Rule enumeration.
 /// - `$inner:ty`. Type of inner parsing expression.
+/// - `$accessers:tt`. Optional. Accessers, see [rule](`crate::rule!`).
+///
+/// Leading attributes are handled as in [rule](`crate::rule!`).
 #[macro_export]
 macro_rules! atomic_rule {
-    ($name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty) => {
-        $crate::rule!($name, $($doc)*, $Rule, $rule, $inner, (), true, Span, false);
+    ($(#[$($attr:tt)*])* $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty $(, $accessers:tt)?) => {
+        $crate::rule!($(#[$($attr)*])* $name, $($doc)*, $Rule, $rule, $inner, (), true, Span, false $(, $accessers)?);
     };
 }
 
@@ -586,10 +1071,13 @@ macro_rules! atomic_rule {
 /// - `$rule:expr`. Rule enumeration.
 /// - `$inner:ty`. Type of inner parsing expression.
 /// - `$boxed:tt`. Whether wrap inner type in a [Box](crate::re_exported::Box). `true` or `false`.
+/// - `$accessers:tt`. Optional. Accessers, see [rule](`crate::rule!`).
+///
+/// Leading attributes are handled as in [rule](`crate::rule!`).
 #[macro_export]
 macro_rules! compound_atomic_rule {
-    ($name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $boxed:tt) => {
-        $crate::rule!($name, $($doc)*, $Rule, $rule, $inner, (), true, Both, $boxed);
+    ($(#[$($attr:tt)*])* $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $boxed:tt $(, $accessers:tt)?) => {
+        $crate::rule!($(#[$($attr)*])* $name, $($doc)*, $Rule, $rule, $inner, (), true, Both, $boxed $(, $accessers)?);
     };
 }
 
@@ -607,10 +1095,13 @@ macro_rules! compound_atomic_rule {
 ///    Must implement [NeverFailedTypedNode](`crate::NeverFailedTypedNode`). Normally using [Skipped](`crate::predefined_node::Skipped`).
 ///
 /// - `$boxed:tt`. Whether wrap inner type in a [Box](crate::re_exported::Box). `true` or `false`.
+/// - `$accessers:tt`. Optional. Accessers, see [rule](`crate::rule!`).
+///
+/// Leading attributes are handled as in [rule](`crate::rule!`).
 #[macro_export]
 macro_rules! non_atomic_rule {
-    ($name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $boxed:tt) => {
-        $crate::rule!($name, $($doc)*, $Rule, $rule, $inner, $ignored, false, Both, $boxed);
+    ($(#[$($attr:tt)*])* $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $boxed:tt $(, $accessers:tt)?) => {
+        $crate::rule!($(#[$($attr)*])* $name, $($doc)*, $Rule, $rule, $inner, $ignored, false, Both, $boxed $(, $accessers)?);
     };
 }
 
@@ -628,10 +1119,13 @@ macro_rules! non_atomic_rule {
 ///    Must implement [NeverFailedTypedNode](`crate::NeverFailedTypedNode`). Normally using [Skipped](`crate::predefined_node::Skipped`).
 ///
 /// - `$boxed:tt`. Whether wrap inner type in a [Box](crate::re_exported::Box). `true` or `false`.
+/// - `$accessers:tt`. Optional. Accessers, see [rule](`crate::rule!`).
+///
+/// Leading attributes are handled as in [rule](`crate::rule!`).
 #[macro_export]
 macro_rules! normal_rule {
-    ($name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $boxed:tt) => {
-        $crate::rule!($name, $($doc)*, $Rule, $rule, $inner, $ignored, INHERITED, Both, $boxed);
+    ($(#[$($attr:tt)*])* $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $boxed:tt $(, $accessers:tt)?) => {
+        $crate::rule!($(#[$($attr)*])* $name, $($doc)*, $Rule, $rule, $inner, $ignored, INHERITED, Both, $boxed $(, $accessers)?);
     };
 }
 
@@ -649,10 +1143,581 @@ macro_rules! normal_rule {
 ///    Must implement [NeverFailedTypedNode](`crate::NeverFailedTypedNode`). Normally using [Skipped](`crate::predefined_node::Skipped`).
 ///
 /// - `$boxed:tt`. Whether wrap inner type in a [Box](crate::re_exported::Box). `true` or `false`.
+/// - `$accessers:tt`. Optional. Accessers, see [rule](`crate::rule!`).
+///
+/// Leading attributes are handled as in [rule](`crate::rule!`).
 #[macro_export]
 macro_rules! silent_rule {
-    ($name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $boxed:tt) => {
-        $crate::rule!($name, $($doc)*, $Rule, $rule, $inner, $ignored, INHERITED, Expression, $boxed);
+    ($(#[$($attr:tt)*])* $name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $inner:ty, $ignored:ty, $boxed:tt $(, $accessers:tt)?) => {
+        $crate::rule!($(#[$($attr)*])* $name, $($doc)*, $Rule, $rule, $inner, $ignored, INHERITED, Expression, $boxed $(, $accessers)?);
+    };
+}
+
+/// Declare a rule body, a generic struct shared by rules
+/// with the same emission, atomicity, boxing and auto-skipped parsing expressions.
+///
+/// Rules declared by [rule_instance](crate::rule_instance!) are aliases of rule bodies,
+/// with their inner types and rules as generic arguments,
+/// so that impls that don't depend on the rule itself are generated once for each body
+/// instead of once for each rule.
+///
+/// The struct has generic parameters `<'i, T, const RULE: usize, const INHERITED: usize = 1>`,
+/// where `T` is the type of inner parsing expression and `RULE` tells rules apart.
+/// Bodies of `Span` rules have no `T`, as they don't keep their content.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated struct.
+/// - `$($doc:literal)*`. A list of strings that is prepended to generated struct as document comments.
+/// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
+/// - `$ignored:ty`. Type of auto-skipped parsing expressions.
+///
+///   Must implement [NeverFailedTypedNode](`crate::NeverFailedTypedNode`). Normally using [Skipped](`crate::predefined_node::Skipped`).
+///
+/// - `$atomic:tt`. `true` or `false`. Rules with inherited atomicity share bodies with non-atomic rules.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+/// - `$boxed:tt`. `true` or `false`.
+/// - `$new:ident`. Name of the constructor from content and span,
+///   which must not collide with accessers of rules.
+#[macro_export]
+macro_rules! rule_body {
+    // `derive` doesn't accept type macros such as `rule_inner` in generic structs.
+    (@struct $name:ident, [$($doc:literal)*], true, { $($fields:tt)* }) => {
+        $crate::rule_body!(@struct $name, [$($doc)*], $crate::re_exported::Box<T>, { $($fields)* });
+    };
+    (@struct $name:ident, [$($doc:literal)*], false, { $($fields:tt)* }) => {
+        $crate::rule_body!(@struct $name, [$($doc)*], T, { $($fields)* });
+    };
+    (@struct $name:ident, [$($doc:literal)*], $content:ty, { $($fields:tt)* }) => {
+        $(
+            #[doc = $doc]
+        )*
+        #[derive(Clone, Hash, PartialEq, Eq)]
+        pub struct $name<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize = 1> {
+            /// Matched expression.
+            pub content: $content,
+            $($fields)*
+        }
+    };
+    (@take true, $content:expr) => {
+        *$content
+    };
+    (@take false, $content:expr) => {
+        $content
+    };
+    (@parse $name:ident, $Rule:ty, $ignored:ty, true, [$($g:tt)*] [$($a:tt)*]) => {
+        impl<'i, $($g)*> $crate::ParsableTypedNode<'i, $Rule> for $name<'i, $($a)* 1>
+        where
+            Self: $crate::TypedNode<'i, $Rule>,
+        {
+            #[inline]
+            fn try_parse_with_until_end(
+                input: $crate::Position<'i>,
+                stack: &mut $crate::Stack<$crate::Span<'i>>,
+                tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
+            ) -> ::core::option::Option<Self> {
+                $crate::rule::parse_without_ignore::<$Rule, Self>(input, stack, tracker)
+            }
+        }
+    };
+    (@parse $name:ident, $Rule:ty, $ignored:ty, false, [$($g:tt)*] [$($a:tt)*]) => {
+        impl<'i, $($g)*> $crate::ParsableTypedNode<'i, $Rule> for $name<'i, $($a)* 1>
+        where
+            Self: $crate::TypedNode<'i, $Rule>,
+        {
+            #[inline]
+            fn try_parse_with_until_end(
+                input: $crate::Position<'i>,
+                stack: &mut $crate::Stack<$crate::Span<'i>>,
+                tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
+            ) -> ::core::option::Option<Self> {
+                $crate::rule::parse::<$Rule, Self, $ignored>(input, stack, tracker)
+            }
+        }
+    };
+    (@ord $name:ident, [$($g:tt)*] [$($a:tt)*]) => {
+        impl<'i, $($g)* const INHERITED: ::core::primitive::usize> ::core::cmp::PartialOrd
+            for $name<'i, $($a)* INHERITED>
+        where
+            Self: ::core::cmp::Eq,
+        {
+            fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                ::core::option::Option::Some(::core::cmp::Ord::cmp(self, other))
+            }
+        }
+        impl<'i, $($g)* const INHERITED: ::core::primitive::usize> ::core::cmp::Ord
+            for $name<'i, $($a)* INHERITED>
+        where
+            Self: ::core::cmp::Eq,
+        {
+            fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                ::core::cmp::Ord::cmp(&self.span, &other.span)
+            }
+        }
+    };
+    (@spanned $name:ident, $Rule:ty, [$($g:tt)*] [$($a:tt)*]) => {
+        impl<'i, $($g)* const INHERITED: ::core::primitive::usize> $crate::Spanned<'i, $Rule>
+            for $name<'i, $($a)* INHERITED>
+        {
+            fn span(&self) -> $crate::Span<'i> {
+                self.span
+            }
+        }
+    };
+    (@pairs_with_self $name:ident, $Rule:ty, [$($g:tt)*] [$($a:tt)*]) => {
+        impl<'i: 'n, 'n, $($g)* const INHERITED: ::core::primitive::usize>
+            $crate::iterators::Pairs<'i, 'n, $Rule> for $name<'i, $($a)* INHERITED>
+        where
+            Self: $crate::iterators::Pair<'i, 'n, $Rule> + 'n,
+        {
+            type Iter = ::core::iter::Once<&'n dyn $crate::iterators::Pair<'i, 'n, $Rule>>;
+            type IntoIter = ::core::iter::Once<
+                $crate::re_exported::Box<dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n>,
+            >;
+
+            fn iter_pairs(&'n self) -> Self::Iter {
+                ::core::iter::once(self)
+            }
+            fn into_iter_pairs(self) -> Self::IntoIter {
+                ::core::iter::once($crate::re_exported::Box::new(self))
+            }
+        }
+    };
+    (@pair_with_empty $name:ident, $Rule:ty, [$($g:tt)*] [$($a:tt)*]) => {
+        $crate::rule_body!(@spanned $name, $Rule, [$($g)*] [$($a)*]);
+        impl<'i: 'n, 'n, $($g)* const INHERITED: ::core::primitive::usize>
+            $crate::iterators::Pair<'i, 'n, $Rule> for $name<'i, $($a)* INHERITED>
+        where
+            Self: $crate::RuleWrapper<$Rule>,
+        {
+            fn inner(
+                &'n self,
+            ) -> $crate::re_exported::vec::IntoIter<
+                &'n (dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n),
+            > {
+                $crate::re_exported::Vec::new().into_iter()
+            }
+            fn into_inner(
+                self,
+            ) -> $crate::re_exported::vec::IntoIter<
+                $crate::re_exported::Box<(dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n)>,
+            > {
+                $crate::re_exported::Vec::new().into_iter()
+            }
+        }
+        impl<'i, $($g)* const INHERITED: ::core::primitive::usize> $crate::iterators::AnyPair<'i, $Rule>
+            for $name<'i, $($a)* INHERITED>
+        where
+            Self: $crate::RuleWrapper<$Rule> + ::core::fmt::Debug,
+        {
+            fn inner_pairs<'n>(
+                &'n self,
+            ) -> $crate::re_exported::vec::IntoIter<
+                &'n (dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n),
+            >
+            where
+                'i: 'n,
+            {
+                $crate::re_exported::Vec::new().into_iter()
+            }
+        }
+    };
+    (@matched_span $name:ident, [$($g:tt)*] [$($a:tt)*]) => {
+        impl<'i, $($g)* const INHERITED: ::core::primitive::usize> $crate::MatchedText
+            for $name<'i, $($a)* INHERITED>
+        {
+            fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.write_str(self.span.as_str())
+            }
+        }
+    };
+    (@display $name:ident, [$($g:tt)*] [$($a:tt)*]) => {
+        impl<'i, $($g)* const INHERITED: ::core::primitive::usize> ::core::fmt::Display
+            for $name<'i, $($a)* INHERITED>
+        where
+            Self: $crate::MatchedText,
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::MatchedText::fmt_matched(self, f)
+            }
+        }
+    };
+    // Impls shared by bodies with content.
+    (@content $name:ident, $Rule:ty, $ignored:ty, $atomic:tt, $boxed:tt) => {
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::RuleStruct<'i, $Rule> for $name<'i, T, RULE, INHERITED>
+        where
+            Self: $crate::RuleWrapper<$Rule>,
+        {
+            type Inner = T;
+            fn take_inner(self) -> T {
+                $crate::rule_body!(@take $boxed, self.content)
+            }
+            fn ref_inner(&self) -> &T {
+                &self.content
+            }
+            fn mut_inner(&mut self) -> &mut T {
+                &mut self.content
+            }
+        }
+        $crate::rule_body!(@parse $name, $Rule, $ignored, $atomic, [T, const RULE: ::core::primitive::usize] [T, RULE,]);
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            ::core::ops::Deref for $name<'i, T, RULE, INHERITED>
+        {
+            type Target = T;
+            fn deref(&self) -> &Self::Target {
+                &self.content
+            }
+        }
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            ::core::ops::DerefMut for $name<'i, T, RULE, INHERITED>
+        {
+            fn deref_mut(&mut self) -> &mut Self::Target {
+                &mut self.content
+            }
+        }
+        $crate::rule_body!(@display $name, [T, const RULE: ::core::primitive::usize,] [T, RULE,]);
+        impl<'i, T: ::core::default::Default, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            ::core::default::Default for $name<'i, T, RULE, INHERITED>
+        {
+            fn default() -> Self {
+                <Self as ::core::convert::From<T>>::from(::core::default::Default::default())
+            }
+        }
+        // Only implemented for the default `INHERITED`, so that `rule::MIN` can be inferred,
+        // as bounds don't depend on whether trivia is skipped.
+        impl<'i, T: $crate::BoundWrapper, const RULE: ::core::primitive::usize> $crate::BoundWrapper
+            for $name<'i, T, RULE, 1>
+        {
+            const MIN: ::core::primitive::usize = T::MIN;
+            const MAX: ::core::primitive::usize = T::MAX;
+        }
+    };
+    // Impls shared by silent bodies.
+    (@silent $name:ident, $Rule:ty, $boxed:tt) => {
+        impl<'i: 'n, 'n, T: $crate::iterators::Pairs<'i, 'n, $Rule>, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::iterators::Pairs<'i, 'n, $Rule> for $name<'i, T, RULE, INHERITED>
+        {
+            type Iter =
+                $crate::re_exported::vec::IntoIter<&'n dyn $crate::iterators::Pair<'i, 'n, $Rule>>;
+            type IntoIter = $crate::re_exported::vec::IntoIter<
+                $crate::re_exported::Box<dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n>,
+            >;
+
+            fn iter_pairs(&'n self) -> Self::Iter {
+                let i = T::iter_pairs(&self.content);
+                i.collect::<$crate::re_exported::Vec<_>>().into_iter()
+            }
+            fn into_iter_pairs(self) -> Self::IntoIter {
+                let i = T::into_iter_pairs($crate::rule_body!(@take $boxed, self.content));
+                i.collect::<$crate::re_exported::Vec<_>>().into_iter()
+            }
+        }
+        impl<'i, T: $crate::MatchedText, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::MatchedText for $name<'i, T, RULE, INHERITED>
+        {
+            fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $crate::MatchedText::fmt_matched(&self.content, f)
+            }
+        }
+    };
+    (@content_eq $name:ident) => {
+        impl<'i, T: $crate::ContentEq, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::ContentEq for $name<'i, T, RULE, INHERITED>
+        {
+            fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+                $crate::ContentEq::content_eq(&self.content, &other.content)
+            }
+            fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                $crate::ContentEq::content_hash(&self.content, state)
+            }
+            fn content_eq_ignore_case(&self, other: &Self) -> ::core::primitive::bool {
+                $crate::ContentEq::content_eq_ignore_case(&self.content, &other.content)
+            }
+        }
+    };
+    (@offset_both $name:ident) => {
+        impl<'i, T: $crate::incremental::Offset<'i>, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::incremental::Offset<'i> for $name<'i, T, RULE, INHERITED>
+        {
+            fn offset(
+                &mut self,
+                input: &'i ::core::primitive::str,
+                from: ::core::primitive::usize,
+                delta: ::core::primitive::isize,
+            ) {
+                $crate::incremental::Offset::offset(&mut self.content, input, from, delta);
+                $crate::incremental::Offset::offset(&mut self.span, input, from, delta);
+            }
+        }
+    };
+    (@spanned_content $name:ident, $new:ident) => {
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $name<'i, T, RULE, INHERITED>
+        {
+            /// Create the node from its content and span, without parsing.
+            pub fn $new(content: T, span: $crate::Span<'i>) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    span,
+                }
+            }
+        }
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            ::core::convert::From<T> for $name<'i, T, RULE, INHERITED>
+        {
+            /// Create the node from its content with an empty span.
+            fn from(content: T) -> Self {
+                Self::$new(content, ::core::default::Default::default())
+            }
+        }
+        $crate::rule_body!(@ord $name, [T, const RULE: ::core::primitive::usize,] [T, RULE,]);
+        $crate::rule_body!(@content_eq $name);
+        $crate::rule_body!(@offset_both $name);
+    };
+    ($name:ident, $($doc:literal)*, $Rule:ty, $ignored:ty, $atomic:tt, Expression, $boxed:tt, $new:ident) => {
+        $crate::rule_body!(@struct $name, [$($doc)*], $boxed, {
+            _phantom: ::core::marker::PhantomData<&'i ::core::primitive::str>,
+        });
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $name<'i, T, RULE, INHERITED>
+        {
+            /// Create the node from its content, without parsing.
+            pub fn $new(content: T) -> Self {
+                Self {
+                    content: ::core::convert::From::from(content),
+                    _phantom: ::core::marker::PhantomData,
+                }
+            }
+        }
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            ::core::convert::From<T> for $name<'i, T, RULE, INHERITED>
+        {
+            fn from(content: T) -> Self {
+                Self::$new(content)
+            }
+        }
+        impl<'i, T: $crate::TypedNode<'i, $Rule>, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::TypedNode<'i, $Rule> for $name<'i, T, RULE, INHERITED>
+        where
+            Self: $crate::RuleWrapper<$Rule> + ::core::fmt::Debug,
+        {
+            #[inline]
+            fn try_parse_with(
+                input: $crate::Position<'i>,
+                stack: &mut $crate::Stack<$crate::Span<'i>>,
+                tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
+            ) -> ::core::option::Option<($crate::Position<'i>, Self)> {
+                let (input, content) = tracker.nest_rule_during::<Self, _>(input, |tracker| {
+                    T::try_parse_with(input, stack, tracker)
+                })?;
+                Some((input, Self::$new(content)))
+            }
+        }
+        $crate::rule_body!(@content $name, $Rule, $ignored, $atomic, $boxed);
+        $crate::rule_body!(@silent $name, $Rule, $boxed);
+        $crate::rule_body!(@content_eq $name);
+        impl<'i, T: $crate::incremental::Offset<'i>, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::incremental::Offset<'i> for $name<'i, T, RULE, INHERITED>
+        {
+            fn offset(
+                &mut self,
+                input: &'i ::core::primitive::str,
+                from: ::core::primitive::usize,
+                delta: ::core::primitive::isize,
+            ) {
+                $crate::incremental::Offset::offset(&mut self.content, input, from, delta)
+            }
+        }
+    };
+    ($name:ident, $($doc:literal)*, $Rule:ty, $ignored:ty, $atomic:tt, SpannedExpression, $boxed:tt, $new:ident) => {
+        $crate::rule_body!(@struct $name, [$($doc)*], $boxed, {
+            /// Span of matched expression.
+            pub span: $crate::Span<'i>,
+        });
+        impl<'i, T: $crate::TypedNode<'i, $Rule>, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::TypedNode<'i, $Rule> for $name<'i, T, RULE, INHERITED>
+        where
+            Self: $crate::RuleWrapper<$Rule> + ::core::fmt::Debug,
+        {
+            #[inline]
+            fn try_parse_with(
+                input: $crate::Position<'i>,
+                stack: &mut $crate::Stack<$crate::Span<'i>>,
+                tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
+            ) -> ::core::option::Option<($crate::Position<'i>, Self)> {
+                let start = input;
+                let (input, content) = tracker.nest_rule_during::<Self, _>(input, |tracker| {
+                    T::try_parse_with(input, stack, tracker)
+                })?;
+                let span = start.span(&input);
+                Some((input, Self::$new(content, span)))
+            }
+        }
+        $crate::rule_body!(@spanned_content $name, $new);
+        $crate::rule_body!(@content $name, $Rule, $ignored, $atomic, $boxed);
+        $crate::rule_body!(@silent $name, $Rule, $boxed);
+        $crate::rule_body!(@spanned $name, $Rule, [T, const RULE: ::core::primitive::usize,] [T, RULE,]);
+    };
+    ($name:ident, $($doc:literal)*, $Rule:ty, $ignored:ty, $atomic:tt, Both, $boxed:tt, $new:ident) => {
+        $crate::rule_body!(@struct $name, [$($doc)*], $boxed, {
+            /// Span of matched expression.
+            pub span: $crate::Span<'i>,
+        });
+        impl<'i, T: $crate::TypedNode<'i, $Rule>, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::TypedNode<'i, $Rule> for $name<'i, T, RULE, INHERITED>
+        where
+            Self: $crate::RuleWrapper<$Rule> + ::core::fmt::Debug,
+        {
+            #[inline]
+            fn try_parse_with(
+                input: $crate::Position<'i>,
+                stack: &mut $crate::Stack<$crate::Span<'i>>,
+                tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
+            ) -> ::core::option::Option<($crate::Position<'i>, Self)> {
+                tracker.record_during(input, |tracker| {
+                    if tracker.prunes(input, <Self as $crate::RuleWrapper<$Rule>>::MIN_LEN) {
+                        return None;
+                    }
+                    let start = input;
+                    let (input, content) = T::try_parse_with(input, stack, tracker)?;
+                    let span = start.span(&input);
+                    Some((input, Self::$new(content, span)))
+                })
+            }
+        }
+        $crate::rule_body!(@spanned_content $name, $new);
+        $crate::rule_body!(@content $name, $Rule, $ignored, $atomic, $boxed);
+        $crate::rule_body!(@pairs_with_self $name, $Rule, [T, const RULE: ::core::primitive::usize,] [T, RULE,]);
+        $crate::rule_body!(@both $name, $Rule, $atomic, $boxed);
+        $crate::rule_body!(@matched_span $name, [T, const RULE: ::core::primitive::usize,] [T, RULE,]);
+    };
+    (@both $name:ident, $Rule:ty, true, $boxed:tt) => {
+        $crate::rule_body!(@pair_with_empty $name, $Rule, [T, const RULE: ::core::primitive::usize,] [T, RULE,]);
+    };
+    // `AnyPair` is implemented by rule instances,
+    // as it can't require the content to be `Pairs` for all lifetimes.
+    (@both $name:ident, $Rule:ty, false, $boxed:tt) => {
+        impl<'i, T, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::TypeWrapper for $name<'i, T, RULE, INHERITED>
+        where
+            Self: ::core::clone::Clone + ::core::cmp::PartialEq,
+        {
+            type Inner = T;
+        }
+        $crate::rule_body!(@spanned $name, $Rule, [T, const RULE: ::core::primitive::usize,] [T, RULE,]);
+        impl<'i: 'n, 'n, T: $crate::iterators::Pairs<'i, 'n, $Rule>, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::iterators::Pair<'i, 'n, $Rule> for $name<'i, T, RULE, INHERITED>
+        where
+            Self: $crate::RuleWrapper<$Rule>,
+        {
+            fn inner(
+                &'n self,
+            ) -> $crate::re_exported::vec::IntoIter<
+                &'n (dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n),
+            > {
+                let i = T::iter_pairs(&self.content);
+                i.collect::<$crate::re_exported::Vec<_>>().into_iter()
+            }
+            fn into_inner(
+                self,
+            ) -> $crate::re_exported::vec::IntoIter<
+                $crate::re_exported::Box<(dyn $crate::iterators::Pair<'i, 'n, $Rule> + 'n)>,
+            > {
+                let i = T::into_iter_pairs($crate::rule_body!(@take $boxed, self.content));
+                i.collect::<$crate::re_exported::Vec<_>>().into_iter()
+            }
+        }
+    };
+    ($name:ident, $($doc:literal)*, $Rule:ty, $ignored:ty, $atomic:tt, Span, $boxed:tt, $new:ident) => {
+        $(
+            #[doc = $doc]
+        )*
+        #[derive(Clone, Hash, PartialEq, Eq)]
+        pub struct $name<'i, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize = 1> {
+            /// Span of matched expression.
+            pub span: $crate::Span<'i>,
+        }
+        impl<'i, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $name<'i, RULE, INHERITED>
+        {
+            /// Create the node from its span, without parsing.
+            pub fn $new(span: $crate::Span<'i>) -> Self {
+                Self { span }
+            }
+        }
+        $crate::rule_body!(@ord $name, [const RULE: ::core::primitive::usize,] [RULE,]);
+        $crate::rule_body!(@parse $name, $Rule, $ignored, $atomic, [const RULE: ::core::primitive::usize] [RULE,]);
+        $crate::rule_body!(@pairs_with_self $name, $Rule, [const RULE: ::core::primitive::usize,] [RULE,]);
+        $crate::rule_body!(@pair_with_empty $name, $Rule, [const RULE: ::core::primitive::usize,] [RULE,]);
+        $crate::rule_body!(@matched_span $name, [const RULE: ::core::primitive::usize,] [RULE,]);
+        $crate::rule_body!(@display $name, [const RULE: ::core::primitive::usize,] [RULE,]);
+        impl<'i, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::ContentEq for $name<'i, RULE, INHERITED>
+        {
+            fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+                self.span.as_str() == other.span.as_str()
+            }
+            fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                ::core::hash::Hash::hash(self.span.as_str(), state)
+            }
+        }
+        impl<'i, const RULE: ::core::primitive::usize, const INHERITED: ::core::primitive::usize>
+            $crate::incremental::Offset<'i> for $name<'i, RULE, INHERITED>
+        {
+            fn offset(
+                &mut self,
+                input: &'i ::core::primitive::str,
+                from: ::core::primitive::usize,
+                delta: ::core::primitive::isize,
+            ) {
+                $crate::incremental::Offset::offset(&mut self.span, input, from, delta)
+            }
+        }
+    };
+}
+
+/// Declare a rule as an alias of a [rule body](crate::rule_body!),
+/// and implement the traits that depend on the rule itself.
+///
+/// Arguments:
+///
+/// - `$name:ident`. Name of generated alias.
+/// - `$($doc:literal)*`. A list of strings that is prepended to generated alias as document comments.
+/// - `$Rule:ty`. Rule type. Must implement [RuleType](`crate::RuleType`).
+/// - `$rule:expr`. Rule enumeration.
+/// - `$body:ty`. The rule body with the inner type, `$rule as usize` and `INHERITED` as generic arguments.
+/// - `$atomicity:tt`. `true`, `false` or `INHERITED`.
+/// - `$emission:tt`. `Span`, `Expression`, `SpannedExpression` or `Both`.
+/// - `inner = $inner:ty`. Only for `Span`. Type of inner parsing expression, which the body doesn't keep.
+/// - `min_len = $min_len:literal`. Optional. See [`RuleWrapper::MIN_LEN`](crate::RuleWrapper::MIN_LEN).
+/// - `error_label = $error_label:literal`. Optional. See [`RuleWrapper::ERROR_LABEL`](crate::RuleWrapper::ERROR_LABEL).
+/// - `recursion_limit = $recursion_limit:literal`. Optional. See [`RuleWrapper::RECURSION_LIMIT`](crate::RuleWrapper::RECURSION_LIMIT).
+/// - `counts_recursion = $counts_recursion:literal`. Optional. See [`RuleWrapper::COUNTS_RECURSION`](crate::RuleWrapper::COUNTS_RECURSION).
+///
+/// Rules whose types contain themselves can't be aliases, and are declared by [rule](crate::rule!) instead.
+#[macro_export]
+macro_rules! rule_instance {
+    (@emission $name:ident, $Rule:ty, $atomicity:tt, Span, $inner:ty) => {
+        $crate::impl_try_parse_with!($name, $Rule, $inner, $atomicity, Span);
+    };
+    (@emission $name:ident, $Rule:ty, false, Both) => {
+        $crate::impl_any_pair_with_content!($name, $Rule);
+    };
+    (@emission $name:ident, $Rule:ty, INHERITED, Both) => {
+        $crate::impl_any_pair_with_content!($name, $Rule);
+    };
+    (@emission $name:ident, $Rule:ty, $atomicity:tt, $emission:tt) => {};
+    ($name:ident, $($doc:literal)*, $Rule:ty, $rule:expr, $body:ty, $atomicity:tt, $emission:tt $(, inner = $inner:ty)? $(, min_len = $min_len:literal)? $(, error_label = $error_label:literal)? $(, recursion_limit = $recursion_limit:literal)? $(, counts_recursion = $counts_recursion:literal)?) => {
+        $(
+            #[doc = $doc]
+        )*
+        #[allow(non_camel_case_types)]
+        pub type $name<'i, const INHERITED: ::core::primitive::usize = 1> = $body;
+        $crate::impl_rule_wrapper!($name, $Rule, $rule $(, min_len = $min_len)? $(, error_label = ::core::option::Option::Some($error_label))? $(, recursion_limit = $recursion_limit)? $(, counts_recursion = $counts_recursion)?);
+        $crate::impl_debug!($name, $emission);
+        $crate::impl_serialize!($name, $emission);
+        $crate::rule_instance!(@emission $name, $Rule, $atomicity, $emission $(, $inner)?);
     };
 }
 
@@ -662,10 +1727,14 @@ macro_rules! silent_rule {
 ///
 /// - `$name:ident`. Name of generated struct.
 /// - `$Rule:ty`. Rule type. Must implement [RuleType].
+///
+/// Leading attributes are handled as in [rule](`crate::rule!`).
 #[macro_export]
 macro_rules! rule_eoi {
-    ($name:ident, $Rule:ty) => {
+    (@split [$(#[$cfg:meta])*] [$(#[$attr:meta])*] $name:ident, $Rule:ty) => {
+        $(#[$cfg])*
         $crate::declare_rule_struct!(
+            $(#[$attr])*
             $name,
             "The rule for end of input.",
             $Rule,
@@ -673,8 +1742,11 @@ macro_rules! rule_eoi {
             Both,
             false
         );
+        $(#[$cfg])*
         $crate::impl_rule_wrapper!($name, $Rule, <$Rule>::EOI);
+        $(#[$cfg])*
         $crate::impl_try_parse_with!($name, $Rule, $crate::predefined_node::EOI, INHERITED, Both);
+        $(#[$cfg])*
         impl<'i, const INHERITED: usize> $crate::ParsableTypedNode<'i, $Rule>
             for $name<'i, INHERITED>
         {
@@ -684,22 +1756,41 @@ macro_rules! rule_eoi {
                 stack: &mut $crate::Stack<$crate::Span<'i>>,
                 tracker: &mut $crate::tracker::Tracker<'i, $Rule>,
             ) -> ::core::option::Option<Self> {
-                $crate::rule::parse_without_ignore::<$Rule, Self>(
-                    input,
-                    stack,
-                    tracker,
-                    <$Rule>::EOI,
-                )
+                $crate::rule::parse_without_ignore::<$Rule, Self>(input, stack, tracker)
             }
         }
+        $(#[$cfg])*
         $crate::impl_deref!($name, $crate::predefined_node::EOI, Expression);
+        $(#[$cfg])*
         $crate::impl_pairs_with_self!($name, $Rule);
+        $(#[$cfg])*
         $crate::impl_pair_with_empty!($name, $Rule, <$Rule>::EOI);
+        $(#[$cfg])*
+        $crate::impl_display!($name, Both);
+        $(#[$cfg])*
+        $crate::impl_content_eq!($name, Both);
+        $(#[$cfg])*
+        $crate::impl_offset!($name, Both);
+        $(#[$cfg])*
+        $crate::impl_serialize!($name, Both);
+        $(#[$cfg])*
+        impl<'i, const INHERITED: ::core::primitive::usize, V: ?::core::marker::Sized>
+            $crate::visitor::Walk<V> for $name<'i, INHERITED>
+        {
+            #[inline]
+            fn walk(&self, _visitor: &mut V) {}
+        }
+    };
+    ($($tt:tt)*) => {
+        $crate::split_cfg_attrs!(rule_eoi, [] [] $($tt)*);
     };
 }
 
 /// Full parse as a non-atomic rule.
 ///
+/// Trailing ignored contents are skipped before checking the end of input,
+/// so input left after them is reported by [`Tracker::trailing_input`].
+///
 /// For [rule](crate::rule!) to implement [ParsableTypedNode](crate::ParsableTypedNode).
 pub fn parse<
     'i,
@@ -710,44 +1801,119 @@ pub fn parse<
     input: Position<'i>,
     stack: &mut Stack<Span<'i>>,
     tracker: &mut Tracker<'i, R>,
-    rule_eoi: R,
 ) -> Option<_Self> {
     let (input, res) = match _Self::try_parse_with(input, stack, tracker) {
-        Some((input, res)) => (input, res),
-        None => return None,
+        Some((input, res)) if !tracker.recursion_limit_exceeded() => (input, res),
+        _ => return None,
     };
     let (input, _) = IGNORED::parse_with(input, stack);
-    let (_, _) = match tracker.record_during_with(
-        input,
-        |tracker| EOI::try_parse_with(input, stack, tracker),
-        rule_eoi,
-    ) {
-        Some((input, res)) => (input, res),
-        None => return None,
-    };
-    Some(res)
+    match EOI::try_parse_with(input, stack, tracker) {
+        Some(_) => Some(res),
+        None => {
+            tracker.trailing_input(input);
+            None
+        }
+    }
 }
 
 /// Full parse as an atomic rule.
 ///
+/// Input left after the match is reported by [`Tracker::trailing_input`].
+///
 /// For [rule](crate::rule!) to implement [ParsableTypedNode](crate::ParsableTypedNode).
 pub fn parse_without_ignore<'i, R: RuleType + 'i, _Self: TypedNode<'i, R>>(
     input: Position<'i>,
     stack: &mut Stack<Span<'i>>,
     tracker: &mut Tracker<'i, R>,
-    rule_eoi: R,
 ) -> Option<_Self> {
     let (input, res) = match _Self::try_parse_with(input, stack, tracker) {
-        Some((input, res)) => (input, res),
-        None => return None,
-    };
-    let (_, _) = match tracker.record_during_with(
-        input,
-        |tracker| EOI::try_parse_with(input, stack, tracker),
-        rule_eoi,
-    ) {
-        Some((input, res)) => (input, res),
-        None => return None,
-    };
-    Some(res)
+        Some((input, res)) if !tracker.recursion_limit_exceeded() => (input, res),
+        _ => return None,
+    };
+    match EOI::try_parse_with(input, stack, tracker) {
+        Some(_) => Some(res),
+        None => {
+            tracker.trailing_input(input);
+            None
+        }
+    }
+}
+
+/// Full parse as a non-atomic rule `Outer` that matches `T* ~ End`, optionally after `SOI`,
+/// passing each `T` to `f` and dropping it before the next one is parsed.
+///
+/// `End` is the `EOI` rule if the rule ends with it, or [`Empty`](crate::predefined_node::Empty) otherwise.
+/// `IGNORED` is skipped between items, after `SOI` if `soi` is set and before `End`,
+/// the same as in `SOI ~ T* ~ EOI`.
+/// Attempts are tracked inside `Outer` as in a full parse of it,
+/// so a failure after some items have been passed to `f` is reported the same way.
+///
+/// Stops without error if `f` returns [`ControlFlow::Break`], leaving the rest of the input unchecked.
+///
+/// For generated `parse_iter` methods of rules.
+pub fn parse_iter<
+    'i,
+    R: RuleType + 'i,
+    Outer: RuleWrapper<R>,
+    T: TypedNode<'i, R>,
+    End: TypedNode<'i, R>,
+    IGNORED: NeverFailedTypedNode<'i, R>,
+>(
+    input: &'i str,
+    soi: bool,
+    mut f: impl FnMut(T) -> ControlFlow<()>,
+) -> ParseResult<(), R> {
+    let mut stack = Stack::new();
+    let start = Position::from_start(input);
+    let mut tracker = Tracker::new(start);
+    let res = tracker.nest_rule_during::<Outer, _>(start, |tracker| {
+        tracker.record_during_with(
+            start,
+            |tracker| {
+                let mut input = start;
+                let mut skip = soi;
+                loop {
+                    let item = restore_on_none(&mut stack, |stack| {
+                        let pos = match skip {
+                            true => IGNORED::parse_with(input, stack).0,
+                            false => input,
+                        };
+                        let (next, item) = T::try_parse_with(pos, stack, tracker)?;
+                        tracker.reach(next);
+                        Some((next, item))
+                    });
+                    let (next, item) = match item {
+                        Some(item) => item,
+                        None => break,
+                    };
+                    if tracker.recursion_limit_exceeded() {
+                        return None;
+                    }
+                    input = next;
+                    skip = true;
+                    if f(item).is_break() {
+                        return Some((input, ControlFlow::Break(())));
+                    }
+                }
+                let (pos, _) = IGNORED::parse_with(input, &mut stack);
+                let (input, _) = End::try_parse_with(pos, &mut stack, tracker)?;
+                Some((input, ControlFlow::Continue(())))
+            },
+            Outer::RULE,
+            Outer::ERROR_LABEL,
+        )
+    });
+    let end = match res {
+        Some((_, ControlFlow::Break(()))) => return Ok(()),
+        Some((end, ControlFlow::Continue(()))) if !tracker.recursion_limit_exceeded() => end,
+        _ => return Err(Box::new(tracker.collect_failure())),
+    };
+    let (end, _) = IGNORED::parse_with(end, &mut stack);
+    match EOI::try_parse_with(end, &mut stack, &mut tracker) {
+        Some(_) => Ok(()),
+        None => {
+            tracker.trailing_input(end);
+            Err(Box::new(tracker.collect_failure()))
+        }
+    }
 }
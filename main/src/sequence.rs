@@ -88,6 +88,7 @@ macro_rules! seq {
                         let skipped = ::core::array::from_fn(|_| Skip::default());
                         let (next, matched) = T0::try_parse_with(input, stack, tracker)?;
                         input = next;
+                        tracker.reach(input);
                         $pest_typed::predefined_node::Skipped { skipped, matched }
                     },
                     $(
@@ -99,6 +100,7 @@ macro_rules! seq {
                             });
                             let (next, matched) = $T::try_parse_with(input, stack, tracker)?;
                             input = next;
+                            tracker.reach(input);
                             $pest_typed::predefined_node::Skipped { skipped, matched }
                         },
                     )*
@@ -171,6 +173,12 @@ macro_rules! seq {
             pub fn into_matched(self) -> ( $T0, $($T, )* ) {
                 ( self.content.$t0.matched, $(self.content.$t.matched, )* )
             }
+            /// Skipped contents before each element in positional order.
+            ///
+            /// The first one is always [`Default`] as nothing is skipped before the first element.
+            pub fn skipped(&self) -> [&[IGNORED; SKIP]; $number] {
+                [ &self.content.$t0.skipped, $(&self.content.$t.skipped, )* ]
+            }
         }
         impl<$T0, $($T, )*> $name<T0, $($T, )*> {
             /// Convert the reference of a sequence into a tuple of references of skipped and matched elements.
@@ -211,9 +219,115 @@ macro_rules! seq {
                     .finish()
             }
         }
+        impl<$T0: $pest_typed::MatchedText, $($T: $pest_typed::MatchedText, )*>
+            $pest_typed::MatchedText for $name<T0, $($T),*>
+        {
+            fn fmt_matched(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $pest_typed::MatchedText::fmt_matched(&self.content.$t0, f)?;
+                $(
+                    $pest_typed::MatchedText::fmt_matched(&self.content.$t, f)?;
+                )*
+                ::core::result::Result::Ok(())
+            }
+        }
+        impl<$T0: $pest_typed::MatchedText, $($T: $pest_typed::MatchedText, )*>
+            ::core::fmt::Display for $name<T0, $($T),*>
+        {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                $pest_typed::MatchedText::fmt_matched(self, f)
+            }
+        }
+        impl<$T0: $pest_typed::ContentEq, $($T: $pest_typed::ContentEq, )*>
+            $pest_typed::ContentEq for $name<T0, $($T),*>
+        {
+            fn content_eq(&self, other: &Self) -> ::core::primitive::bool {
+                $pest_typed::ContentEq::content_eq(&self.content.$t0, &other.content.$t0)
+                $(
+                    && $pest_typed::ContentEq::content_eq(&self.content.$t, &other.content.$t)
+                )*
+            }
+            fn content_hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                $pest_typed::ContentEq::content_hash(&self.content.$t0, state);
+                $(
+                    $pest_typed::ContentEq::content_hash(&self.content.$t, state);
+                )*
+            }
+            fn content_eq_ignore_case(&self, other: &Self) -> ::core::primitive::bool {
+                $pest_typed::ContentEq::content_eq_ignore_case(&self.content.$t0, &other.content.$t0)
+                $(
+                    && $pest_typed::ContentEq::content_eq_ignore_case(&self.content.$t, &other.content.$t)
+                )*
+            }
+        }
+        impl<
+                V: ?::core::marker::Sized,
+                $T0: $pest_typed::visitor::Walk<V>,
+                $($T: $pest_typed::visitor::Walk<V>, )*
+            > $pest_typed::visitor::Walk<V> for $name<$T0, $($T),*>
+        {
+            fn walk(&self, visitor: &mut V) {
+                $pest_typed::visitor::Walk::walk(&self.content.$t0, visitor);
+                $(
+                    $pest_typed::visitor::Walk::walk(&self.content.$t, visitor);
+                )*
+            }
+        }
+        impl<
+                'i,
+                $T0: $pest_typed::incremental::Offset<'i>,
+                $($T: $pest_typed::incremental::Offset<'i>, )*
+            > $pest_typed::incremental::Offset<'i> for $name<$T0, $($T),*>
+        {
+            fn offset(
+                &mut self,
+                input: &'i ::core::primitive::str,
+                from: ::core::primitive::usize,
+                delta: ::core::primitive::isize,
+            ) {
+                $pest_typed::incremental::Offset::offset(&mut self.content.$t0, input, from, delta);
+                $(
+                    $pest_typed::incremental::Offset::offset(&mut self.content.$t, input, from, delta);
+                )*
+            }
+        }
+        $crate::seq_serialize!($name, $number, $T0, $t0, $( $T, $t, )*);
     };
 }
 
+#[cfg(feature = "serde")]
+#[macro_export]
+/// Implement `serde::Serialize` for sequences if feature `serde` is enabled.
+///
+/// Sequences are serialized as tuples.
+///
+/// Used by [`crate::seq`].
+macro_rules! seq_serialize {
+    ($name:ident, $number:literal, $T0:ident, $t0:tt, $( $T:ident, $t:tt, )* ) => {
+        impl<$T0: $crate::serde::Serialize, $($T: $crate::serde::Serialize, )*>
+            $crate::serde::Serialize for $name<$T0, $($T, )*>
+        {
+            fn serialize<S: $crate::serde::Serializer>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error> {
+                use $crate::serde::ser::SerializeTuple;
+                let mut tuple = serializer.serialize_tuple($number)?;
+                tuple.serialize_element(&self.content.$t0)?;
+                $(
+                    tuple.serialize_element(&self.content.$t)?;
+                )*
+                tuple.end()
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[macro_export]
+/// Implement `serde::Serialize` for sequences if feature `serde` is enabled.
+///
+/// Feature `serde` is disabled, so nothing is generated.
+macro_rules! seq_serialize {
+    ($name:ident, $number:literal, $T0:ident, $t0:tt, $( $T:ident, $t:tt, )* ) => {};
+}
+
 seq!(Seq2, crate, 2, T0, 0, T1, 1,);
 seq!(Seq3, crate, 3, T0, 0, T1, 1, T2, 2,);
 seq!(Seq4, crate, 4, T0, 0, T1, 1, T2, 2, T3, 3,);
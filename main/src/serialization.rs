@@ -0,0 +1,138 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Implementations of [`Serialize`] for spans and predefined nodes.
+//!
+//! Only compiled with feature `serde`.
+//!
+//! - Spans are serialized as `{str, start, end}`.
+//! - Terminal nodes are serialized as the string or character they matched.
+//! - Nodes that can't carry content, such as [`SOI`] and predicates, are serialized as unit.
+//! - Skipped trivia is not serialized, a [`Skipped`] is serialized as the matched node.
+
+use crate::{
+    predefined_node::{
+        AlwaysFail, AtomicRep, CharExcept, CharRange, Empty, Insens, InsensUnicode, Keyword,
+        Negative, NewLineType, PeekSlice1, PeekSlice2, Positive, Presence, Push, PushLiteral,
+        Recover, RepMin, RepMinMax, RepUntil, Skip, SkipChar, Skipped, SpanOnly, Str, StrChoice,
+        StrSpanned, ANY, DROP, EOI, NEWLINE, PEEK, PEEK_ALL, PEEK_INSENS, POP, POP_ALL, POP_INSENS,
+        SOI,
+    },
+    Span, StringArrayWrapper, StringWrapper,
+};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+impl<'i> Serialize for Span<'i> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut s = serializer.serialize_struct("Span", 3)?;
+        s.serialize_field("str", self.as_str())?;
+        s.serialize_field("start", &self.start())?;
+        s.serialize_field("end", &self.end())?;
+        s.end()
+    }
+}
+
+/// Serialize as matched string.
+macro_rules! serialize_as_str {
+    ($name:ty, ($($params:tt)*), $self:ident => $str:expr) => {
+        impl<$($params)*> Serialize for $name {
+            fn serialize<S: Serializer>(&$self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_str($str)
+            }
+        }
+    };
+}
+
+/// Serialize as unit.
+macro_rules! serialize_as_unit {
+    ($name:ty, ($($params:tt)*)) => {
+        impl<$($params)*> Serialize for $name {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                serializer.serialize_unit()
+            }
+        }
+    };
+}
+
+/// Serialize as inner content.
+macro_rules! serialize_as_content {
+    ($name:ty, ($($params:tt)*), $self:ident => $content:expr) => {
+        impl<$($params)*> Serialize for $name {
+            fn serialize<S: Serializer>(&$self, serializer: S) -> Result<S::Ok, S::Error> {
+                $content.serialize(serializer)
+            }
+        }
+    };
+}
+
+serialize_as_str!(Str<T>, (T: StringWrapper), self => T::CONTENT);
+serialize_as_str!(StrSpanned<'i, T>, ('i, T: StringWrapper), self => T::CONTENT);
+serialize_as_content!(Presence<T>, (T: StringWrapper), self => self.present().then_some(T::CONTENT));
+serialize_as_str!(Insens<'i, T>, ('i, T: StringWrapper), self => self.content);
+serialize_as_str!(InsensUnicode<'i, T>, ('i, T: StringWrapper), self => self.content);
+serialize_as_str!(Keyword<'i, T, Boundary>, ('i, T: StringWrapper, Boundary), self => self.content);
+serialize_as_str!(Skip<'i, Strings>, ('i, Strings: StringArrayWrapper), self => self.span.as_str());
+serialize_as_str!(SpanOnly<'i, T>, ('i, T), self => self.span.as_str());
+serialize_as_str!(SkipChar<'i, N>, ('i, const N: usize), self => self.span.as_str());
+serialize_as_str!(PEEK_ALL<'i>, ('i), self => self.span.as_str());
+serialize_as_str!(PEEK<'i>, ('i), self => self.span.as_str());
+serialize_as_str!(PeekSlice2<'i, START, END>, ('i, const START: i32, const END: i32), self => self.span.as_str());
+serialize_as_str!(PeekSlice1<'i, START>, ('i, const START: i32), self => self.span.as_str());
+serialize_as_str!(POP<'i>, ('i), self => self.span.as_str());
+serialize_as_str!(POP_ALL<'i>, ('i), self => self.span.as_str());
+serialize_as_str!(PEEK_INSENS<'i>, ('i), self => self.span.as_str());
+serialize_as_str!(POP_INSENS<'i>, ('i), self => self.span.as_str());
+serialize_as_str!(NEWLINE, (), self => match self.content {
+    NewLineType::CRLF => "\r\n",
+    NewLineType::LF => "\n",
+    NewLineType::CR => "\r",
+});
+
+serialize_as_content!(CharExcept<Strings>, (Strings: StringArrayWrapper), self => self.content);
+serialize_as_str!(StrChoice<Strings>, (Strings: StringArrayWrapper), self => self.get());
+serialize_as_content!(CharRange<MIN, MAX>, (const MIN: char, const MAX: char), self => self.content);
+serialize_as_content!(ANY, (), self => self.content);
+serialize_as_content!(Positive<N>, (N: Serialize), self => self.content);
+serialize_as_content!(Push<'i, T>, ('i, T: Serialize), self => self.content);
+serialize_as_content!(Skipped<T, Skip, SKIP>, (T: Serialize, Skip, const SKIP: usize), self => self.matched);
+serialize_as_content!(AtomicRep<T>, (T: Serialize), self => self.content);
+serialize_as_content!(RepUntil<'i, T, Terminator>, ('i, T: Serialize, Terminator), self => self.content);
+serialize_as_content!(
+    RepMin<T, Skip, SKIP, MIN>,
+    (T: Serialize, Skip, const SKIP: usize, const MIN: usize),
+    self => self.content
+);
+serialize_as_content!(
+    RepMinMax<T, Skip, SKIP, MIN, MAX>,
+    (T: Serialize, Skip, const SKIP: usize, const MIN: usize, const MAX: usize),
+    self => self.content
+);
+
+/// The matched expression, or `{"recovered": span}` if recovered.
+impl<'i, T: Serialize, Sync> Serialize for Recover<'i, T, Sync> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Recover::Matched(matched) => matched.serialize(serializer),
+            Recover::Recovered { span, .. } => {
+                use serde::ser::SerializeMap;
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("recovered", span)?;
+                map.end()
+            }
+        }
+    }
+}
+
+serialize_as_unit!(Negative<T>, (T));
+serialize_as_unit!(SOI, ());
+serialize_as_unit!(EOI, ());
+serialize_as_unit!(DROP, ());
+serialize_as_unit!(PushLiteral<T>, (T: StringWrapper));
+serialize_as_unit!(AlwaysFail<'i>, ('i));
+serialize_as_unit!(Empty<'i>, ('i));
@@ -10,6 +10,7 @@
 //! Copied from pest/pest/src/position.rs (commit ac0aed3eecf435fd93ba575a39704aaa88a375b7)
 //! and modified.
 
+use core::cmp::Ordering;
 use core::fmt::{self, Write};
 use core::hash::{Hash, Hasher};
 use core::ops::{Bound, RangeBounds};
@@ -237,6 +238,104 @@ impl<'i> Span<'i> {
         self.input
     }
 
+    /// Returns the sub-span of `range`, relative to the start of the `Span`.
+    ///
+    /// Like [`Span::get`], but panics if `range` is out of the `Span`
+    /// or not on character boundaries, just like slicing a `&str`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pest_typed::Span;
+    /// let input = "let α = 1;";
+    /// let span = Span::new(input, 4, input.len()).unwrap();
+    /// let alpha = span.sub_span(..2);
+    /// assert_eq!(alpha.as_str(), "α");
+    /// assert_eq!(alpha.start(), 4);
+    /// ```
+    pub fn sub_span(&self, range: impl RangeBounds<usize> + fmt::Debug) -> Span<'i> {
+        match self.get((range.start_bound(), range.end_bound())) {
+            Some(span) => span,
+            None => panic!(
+                "Range {:?} is out of span {}..{} or not on character boundaries.",
+                range, self.start, self.end
+            ),
+        }
+    }
+
+    /// Returns the `Span` without leading characters that match `pred`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pest_typed::Span;
+    /// let input = "  x = 1";
+    /// let span = Span::new(input, 0, input.len()).unwrap();
+    /// assert_eq!(span.trim_start_matches(|c| c == ' ').start(), 2);
+    /// ```
+    pub fn trim_start_matches(&self, pred: impl FnMut(char) -> bool) -> Span<'i> {
+        let trimmed = self.as_str().trim_start_matches(pred);
+        Span {
+            input: self.input,
+            start: self.end - trimmed.len(),
+            end: self.end,
+        }
+    }
+
+    /// Returns the `Span` without trailing characters that match `pred`.
+    pub fn trim_end_matches(&self, pred: impl FnMut(char) -> bool) -> Span<'i> {
+        let trimmed = self.as_str().trim_end_matches(pred);
+        Span {
+            input: self.input,
+            start: self.start,
+            end: self.start + trimmed.len(),
+        }
+    }
+
+    /// Returns the `Span` without leading and trailing whitespaces,
+    /// as defined by [`char::is_whitespace`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pest_typed::Span;
+    /// let input = "{ \u{3000}x = 1;\n }";
+    /// let span = Span::new(input, 1, input.len() - 1).unwrap();
+    /// assert_eq!(span.trim().as_str(), "x = 1;");
+    /// ```
+    pub fn trim(&self) -> Span<'i> {
+        self.trim_start_matches(char::is_whitespace)
+            .trim_end_matches(char::is_whitespace)
+    }
+
+    /// Returns the `Span` after `prefix`, or [`None`] if it doesn't start with `prefix`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use pest_typed::Span;
+    /// let input = "// comment";
+    /// let span = Span::new(input, 0, input.len()).unwrap();
+    /// assert_eq!(span.strip_prefix("//").unwrap().as_str(), " comment");
+    /// assert!(span.strip_prefix("/*").is_none());
+    /// ```
+    pub fn strip_prefix(&self, prefix: &str) -> Option<Span<'i>> {
+        self.as_str().strip_prefix(prefix).map(|rest| Span {
+            input: self.input,
+            start: self.end - rest.len(),
+            end: self.end,
+        })
+    }
+
+    /// Returns the `Span` before `suffix`, or [`None`] if it doesn't end with `suffix`.
+    pub fn strip_suffix(&self, suffix: &str) -> Option<Span<'i>> {
+        self.as_str().strip_suffix(suffix).map(|rest| Span {
+            input: self.input,
+            start: self.start,
+            end: self.start + rest.len(),
+        })
+    }
+
     /// Iterates over all lines (partially) covered by this span. Yielding a `&str` for each line.
     ///
     /// # Examples
@@ -297,6 +396,17 @@ impl<'i> fmt::Debug for Span<'i> {
     }
 }
 
+/// An empty span over an empty input, for nodes that are not parsed from any input.
+impl<'i> Default for Span<'i> {
+    fn default() -> Self {
+        Span {
+            input: "",
+            start: 0,
+            end: 0,
+        }
+    }
+}
+
 impl<'i> PartialEq for Span<'i> {
     fn eq(&self, other: &Span<'i>) -> bool {
         ptr::eq(self.input, other.input) && self.start == other.start && self.end == other.end
@@ -313,6 +423,23 @@ impl<'i> Hash for Span<'i> {
     }
 }
 
+impl<'i> PartialOrd for Span<'i> {
+    fn partial_cmp(&self, other: &Span<'i>) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Spans are ordered by start position, then by end position.
+impl<'i> Ord for Span<'i> {
+    fn cmp(&self, other: &Span<'i>) -> Ordering {
+        assert!(
+            ptr::eq(self.input, other.input),
+            "cannot compare spans from different strs"
+        );
+        (self.start, self.end).cmp(&(other.start, other.end))
+    }
+}
+
 impl<'i> Span<'i> {
     /// Format span with given option.
     pub fn display<Writer, SF, MF, NF>(
@@ -482,6 +609,77 @@ mod tests {
         assert!(span2.is_none());
     }
 
+    #[test]
+    fn sub_span() {
+        let input = "xαβγ";
+        let span = Span::new(input, 1, input.len()).unwrap();
+        assert_eq!(span.sub_span(..).as_str(), "αβγ");
+        assert_eq!(span.sub_span(2..4).as_str(), "β");
+        assert_eq!(span.sub_span(2..4).start(), 3);
+        assert_eq!(span.sub_span(2..=3).as_str(), "β");
+        assert_eq!(span.sub_span(6..).as_str(), "");
+        assert_eq!(span.sub_span(6..).start(), input.len());
+    }
+
+    #[test]
+    #[should_panic(expected = "not on character boundaries")]
+    fn sub_span_inside_char() {
+        let input = "xαβγ";
+        let span = Span::new(input, 1, input.len()).unwrap();
+        let _ = span.sub_span(1..4);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of span 1..7")]
+    fn sub_span_out_of_range() {
+        let input = "xαβγ";
+        let span = Span::new(input, 1, input.len()).unwrap();
+        let _ = span.sub_span(..7);
+    }
+
+    #[test]
+    fn trim() {
+        let input = "(\u{3000} α\tβ \n)";
+        let span = Span::new(input, 1, input.len() - 1).unwrap();
+        let trimmed = span.trim();
+        assert_eq!(trimmed.as_str(), "α\tβ");
+        assert_eq!(trimmed.start(), 5);
+        assert_eq!(trimmed.end(), input.len() - 3);
+        assert_eq!(trimmed.get_input(), input);
+        assert_eq!(trimmed.trim(), trimmed);
+
+        let start = span.trim_start_matches(char::is_whitespace);
+        assert_eq!(start.as_str(), "α\tβ \n");
+        let end = span.trim_end_matches(char::is_whitespace);
+        assert_eq!(end.as_str(), "\u{3000} α\tβ");
+        assert_eq!(end.start(), span.start());
+
+        // Multi-byte characters can be trimmed, too.
+        let alphas = Span::new("ααβα", 0, 8).unwrap();
+        assert_eq!(alphas.trim_start_matches(|c| c == 'α').start(), 4);
+        assert_eq!(alphas.trim_end_matches(|c| c == 'α').end(), 6);
+
+        // Everything is trimmed to an empty span at the end.
+        let blank = Span::new(input, 1, 5).unwrap();
+        assert_eq!(blank.trim().as_str(), "");
+        assert_eq!(blank.trim_start_matches(char::is_whitespace).start(), 5);
+        assert_eq!(blank.trim_end_matches(char::is_whitespace).end(), 1);
+    }
+
+    #[test]
+    fn strip() {
+        let input = "«αβ»";
+        let span = Span::new(input, 0, input.len()).unwrap();
+        let inner = span.strip_prefix("«").unwrap().strip_suffix("»").unwrap();
+        assert_eq!(inner.as_str(), "αβ");
+        assert_eq!((inner.start(), inner.end()), (2, 6));
+        assert!(span.strip_prefix("α").is_none());
+        assert!(span.strip_suffix("β").is_none());
+        assert_eq!(span.strip_prefix("").unwrap(), span);
+        assert_eq!(span.strip_prefix(input).unwrap().as_str(), "");
+        assert_eq!(span.strip_prefix(input).unwrap().start(), input.len());
+    }
+
     #[test]
     fn span_comp() {
         let input = "abc\ndef\nghi";
@@ -0,0 +1,79 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Reading [`Stack`], which is re-exported from **pest**, and restoring it with [`StackGuard`].
+
+use core::{
+    ops::{Deref, DerefMut},
+    slice::Iter,
+};
+use pest::Stack;
+
+/// Accessors on [`Stack`], such as for the spans left after a partial parse.
+pub trait StackExt<T: Clone> {
+    /// Iterate over elements from the bottom to the top.
+    fn iter(&self) -> Iter<'_, T>;
+    /// The `n`-th element from the top, starting with `0` for the top one.
+    fn peek_nth(&self, n: usize) -> Option<&T>;
+    /// Take a snapshot, which is restored when the returned guard is dropped,
+    /// unless [`StackGuard::commit`] is called.
+    fn snapshot_guard(&mut self) -> StackGuard<'_, T>;
+}
+
+impl<T: Clone> StackExt<T> for Stack<T> {
+    fn iter(&self) -> Iter<'_, T> {
+        self[0..self.len()].iter()
+    }
+    fn peek_nth(&self, n: usize) -> Option<&T> {
+        let index = self.len().checked_sub(n + 1)?;
+        self[index..index + 1].first()
+    }
+    fn snapshot_guard(&mut self) -> StackGuard<'_, T> {
+        self.snapshot();
+        StackGuard {
+            stack: self,
+            committed: false,
+        }
+    }
+}
+
+/// A snapshot of a [`Stack`], created by [`StackExt::snapshot_guard`].
+///
+/// Dereferences to the stack, so nodes can be parsed with `&mut guard`.
+/// When dropped, the stack is restored to the snapshot, unless [`StackGuard::commit`] has been called,
+/// so every snapshot is either restored or cleared exactly once, even if parsing returns early or panics.
+pub struct StackGuard<'s, T: Clone> {
+    stack: &'s mut Stack<T>,
+    committed: bool,
+}
+impl<'s, T: Clone> StackGuard<'s, T> {
+    /// Keep changes since the snapshot, and discard the snapshot.
+    pub fn commit(mut self) {
+        self.committed = true;
+    }
+}
+impl<'s, T: Clone> Deref for StackGuard<'s, T> {
+    type Target = Stack<T>;
+    fn deref(&self) -> &Self::Target {
+        self.stack
+    }
+}
+impl<'s, T: Clone> DerefMut for StackGuard<'s, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.stack
+    }
+}
+impl<'s, T: Clone> Drop for StackGuard<'s, T> {
+    fn drop(&mut self) {
+        match self.committed {
+            true => self.stack.clear_snapshot(),
+            false => self.stack.restore(),
+        }
+    }
+}
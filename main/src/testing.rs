@@ -0,0 +1,391 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Assertion helpers for grammar test suites.
+//!
+//! Each macro panics with the rendered parsing error or the unexpected tree on failure:
+//!
+//! - [`assert_parses!`](crate::assert_parses!) asserts that a rule matches the whole input, and returns the parsed node.
+//! - [`assert_rejects!`](crate::assert_rejects!) asserts that a rule fails on the input.
+//! - [`assert_parses_prefix!`](crate::assert_parses_prefix!) asserts how many bytes a rule consumes from the input.
+//! - [`assert_tree_snapshot!`](crate::assert_tree_snapshot!) compares the tree with a snapshot in the format of
+//!   [`PairTree::format_as_tree`], and shows a line diff if they differ.
+//!
+//! [`compare_with_pest`] compares a typed rule with the same rule parsed by [`pest::Parser`],
+//! and is used by `check_against_pest` generated with `#[emit_pest_check]`.
+//!
+//! ```rust,ignore
+//! use pest_typed::{assert_parses, assert_parses_prefix, assert_rejects, assert_tree_snapshot};
+//!
+//! #[derive(TypedParser)]
+//! #[grammar_inline = r#"
+//! list = { item ~ ("," ~ item)* }
+//! item = { ASCII_DIGIT+ }
+//! "#]
+//! struct Parser;
+//!
+//! assert_parses!(pairs::list, "1,2");
+//! assert_rejects!(pairs::list, "1,");
+//! assert_parses_prefix!(pairs::list, "1,2,", 3);
+//! assert_tree_snapshot!(pairs::list, "1,2", "list\n    item \"1\"\n    item \"2\"\n");
+//! ```
+
+use crate::{
+    iterators::{PairTree, Token},
+    ParsableTypedNode, RuleType, RuleWrapper,
+};
+use core::fmt::{self, Display, Formatter};
+use std::{format, string::String, vec, vec::Vec};
+
+/// Path of the rule struct in messages.
+///
+/// Type names aren't used, as rules sharing bodies are aliases and their type names are expanded.
+fn rule_path<R: RuleType>(rule: R) -> String {
+    format!("rules::{:?}", rule)
+}
+
+/// Parse the whole input, or describe the failure.
+pub fn parses<'i, R: RuleType, T: ParsableTypedNode<'i, R> + RuleWrapper<R>>(
+    input: &'i str,
+) -> Result<T, String> {
+    T::try_parse(input).map_err(|error| {
+        format!(
+            "`{}` failed to parse {:?}:\n{}",
+            rule_path(T::RULE),
+            input,
+            error
+        )
+    })
+}
+
+/// Fail to parse the whole input, or describe the unexpected tree.
+pub fn rejects<'i, R: RuleType, T: ParsableTypedNode<'i, R> + RuleWrapper<R>>(
+    input: &'i str,
+) -> Result<(), String> {
+    match T::try_parse(input) {
+        Ok(tree) => Err(format!(
+            "`{}` unexpectedly parsed {:?}:\n{:#?}",
+            rule_path(T::RULE),
+            input,
+            tree
+        )),
+        Err(_) => Ok(()),
+    }
+}
+
+/// Parse a prefix of the input with `expected` bytes, or describe the failure.
+pub fn parses_prefix<'i, R: RuleType, T: ParsableTypedNode<'i, R> + RuleWrapper<R>>(
+    input: &'i str,
+    expected: usize,
+) -> Result<T, String> {
+    let (pos, tree) = T::try_parse_partial(input).map_err(|error| {
+        format!(
+            "`{}` failed to parse a prefix of {:?}:\n{}",
+            rule_path(T::RULE),
+            input,
+            error
+        )
+    })?;
+    let consumed = pos.pos();
+    if consumed == expected {
+        Ok(tree)
+    } else {
+        Err(format!(
+            "`{}` consumed {} bytes {:?} of {:?}, but {} bytes {:?} are expected.",
+            rule_path(T::RULE),
+            consumed,
+            &input[..consumed],
+            input,
+            expected,
+            input.get(..expected).unwrap_or(input),
+        ))
+    }
+}
+
+/// Compare the tree with a snapshot, or describe the difference line by line.
+pub fn tree_snapshot<'i: 'n, 'n, R: RuleType + 'n, T: PairTree<'i, 'n, R>>(
+    tree: &'n T,
+    expected: &str,
+) -> Result<(), String> {
+    let actual = tree
+        .format_as_tree()
+        .map_err(|_| String::from("Failed to format the tree."))?;
+    if actual == expected {
+        Ok(())
+    } else {
+        Err(format!(
+            "Tree of `{}` differs from the snapshot (-expected +actual):\n{}",
+            rule_path(tree.rule()),
+            diff_lines(expected, &actual)
+        ))
+    }
+}
+
+/// A non-silent pair flattened from a tree, with the name of its rule.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FlatPair {
+    /// Rule name, as [`Debug`](core::fmt::Debug) of the rule.
+    pub rule: String,
+    /// Start position.
+    pub start: usize,
+    /// End position.
+    pub end: usize,
+}
+
+impl Display for FlatPair {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}..{}", self.rule, self.start, self.end)
+    }
+}
+
+/// Difference between a typed rule and the same rule parsed by [`pest::Parser`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Mismatch {
+    /// Only one of them matched a prefix of the input. `typed` tells whether the typed rule did.
+    Matched {
+        /// Whether the typed rule matched.
+        typed: bool,
+    },
+    /// Both matched a non-silent rule, but consumed different numbers of bytes.
+    Consumed {
+        /// Bytes consumed by the typed rule.
+        typed: usize,
+        /// Bytes consumed by pest.
+        pest: usize,
+    },
+    /// Non-silent pairs in pre-order differ at `index`.
+    /// One of them is [`None`] if there are fewer pairs on that side.
+    Pairs {
+        /// Index of the first differing pair.
+        index: usize,
+        /// Pair from the typed rule.
+        typed: Option<FlatPair>,
+        /// Pair from pest.
+        pest: Option<FlatPair>,
+    },
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let side = |pair: &Option<FlatPair>| match pair {
+            Some(pair) => format!("{}", pair),
+            None => String::from("nothing"),
+        };
+        match self {
+            Self::Matched { typed: true } => write!(f, "Only pest-typed matched the input."),
+            Self::Matched { typed: false } => write!(f, "Only pest matched the input."),
+            Self::Consumed { typed, pest } => write!(
+                f,
+                "pest-typed consumed {} bytes, but pest consumed {} bytes.",
+                typed, pest
+            ),
+            Self::Pairs { index, typed, pest } => write!(
+                f,
+                "Pair {} is {} in pest-typed, but {} in pest.",
+                index,
+                side(typed),
+                side(pest)
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+fn flatten_tokens<R: RuleType>(tokens: &[Token<R>], res: &mut Vec<FlatPair>) {
+    for token in tokens {
+        res.push(FlatPair {
+            rule: format!("{:?}", token.rule),
+            start: token.start,
+            end: token.end,
+        });
+        flatten_tokens(&token.children, res);
+    }
+}
+
+/// Compare a typed rule with the same rule parsed by [`pest::Parser`] on the same input.
+///
+/// - `typed` is the number of consumed bytes and the top-level pairs, if the typed rule matched a prefix.
+/// - `pest` is the result of [`pest::Parser::parse`], if it matched.
+/// - `silent` tells whether the rule is silent. Pest doesn't tell how many bytes a silent rule consumes,
+///   so only the pairs inside it are compared.
+///
+/// Rules are compared by their names, so the two rule enumerations don't need to be the same type.
+pub fn compare_with_pest<R: RuleType, P: RuleType>(
+    typed: Option<(usize, Vec<Token<R>>)>,
+    pest: Option<pest::iterators::Pairs<'_, P>>,
+    silent: bool,
+) -> Result<(), Mismatch> {
+    let ((consumed, tokens), pest) = match (typed, pest) {
+        (Some(typed), Some(pest)) => (typed, pest),
+        (None, None) => return Ok(()),
+        (typed, _) => {
+            return Err(Mismatch::Matched {
+                typed: typed.is_some(),
+            })
+        }
+    };
+    let mut typed = vec![];
+    flatten_tokens(&tokens, &mut typed);
+    let pest: Vec<FlatPair> = pest
+        .flatten()
+        .map(|pair| FlatPair {
+            rule: format!("{:?}", pair.as_rule()),
+            start: pair.as_span().start(),
+            end: pair.as_span().end(),
+        })
+        .collect();
+    if !silent {
+        if let Some(root) = pest.first() {
+            if root.end != consumed {
+                return Err(Mismatch::Consumed {
+                    typed: consumed,
+                    pest: root.end,
+                });
+            }
+        }
+    }
+    for index in 0..typed.len().max(pest.len()) {
+        let (typed, pest) = (typed.get(index), pest.get(index));
+        if typed != pest {
+            return Err(Mismatch::Pairs {
+                index,
+                typed: typed.cloned(),
+                pest: pest.cloned(),
+            });
+        }
+    }
+    Ok(())
+}
+
+/// Diff two texts by their longest common subsequence of lines.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected: Vec<&str> = expected.lines().collect();
+    let actual: Vec<&str> = actual.lines().collect();
+    let (m, n) = (expected.len(), actual.len());
+    // `lcs[i][j]` is the length of the longest common subsequence of `expected[i..]` and `actual[j..]`.
+    let mut lcs = vec![vec![0usize; n + 1]; m + 1];
+    for i in (0..m).rev() {
+        for j in (0..n).rev() {
+            lcs[i][j] = if expected[i] == actual[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+    let mut res = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < m || j < n {
+        if i < m && j < n && expected[i] == actual[j] {
+            res += &format!(" {}\n", expected[i]);
+            i += 1;
+            j += 1;
+        } else if j == n || (i < m && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            res += &format!("-{}\n", expected[i]);
+            i += 1;
+        } else {
+            res += &format!("+{}\n", actual[j]);
+            j += 1;
+        }
+    }
+    res
+}
+
+/// Assert that a rule matches the whole input, and return the parsed node.
+///
+/// See [`testing`](crate::testing).
+#[macro_export]
+macro_rules! assert_parses {
+    ($rule:ty, $input:expr $(,)?) => {
+        match $crate::testing::parses::<_, $rule>($input) {
+            ::core::result::Result::Ok(tree) => tree,
+            ::core::result::Result::Err(message) => ::core::panic!("{}", message),
+        }
+    };
+}
+
+/// Assert that a rule fails to match the whole input.
+///
+/// See [`testing`](crate::testing).
+#[macro_export]
+macro_rules! assert_rejects {
+    ($rule:ty, $input:expr $(,)?) => {
+        if let ::core::result::Result::Err(message) = $crate::testing::rejects::<_, $rule>($input) {
+            ::core::panic!("{}", message)
+        }
+    };
+}
+
+/// Assert that a rule matches exactly `$expected` bytes from the start of the input, and return the parsed node.
+///
+/// See [`testing`](crate::testing).
+#[macro_export]
+macro_rules! assert_parses_prefix {
+    ($rule:ty, $input:expr, $expected:expr $(,)?) => {
+        match $crate::testing::parses_prefix::<_, $rule>($input, $expected) {
+            ::core::result::Result::Ok(tree) => tree,
+            ::core::result::Result::Err(message) => ::core::panic!("{}", message),
+        }
+    };
+}
+
+/// Assert that a rule matches the whole input,
+/// and the tree formatted by [`PairTree::format_as_tree`](crate::iterators::PairTree::format_as_tree) equals the snapshot.
+///
+/// See [`testing`](crate::testing).
+#[macro_export]
+macro_rules! assert_tree_snapshot {
+    ($rule:ty, $input:expr, $expected:expr $(,)?) => {{
+        let tree = $crate::assert_parses!($rule, $input);
+        if let ::core::result::Result::Err(message) =
+            $crate::testing::tree_snapshot(&tree, $expected)
+        {
+            ::core::panic!("{}", message)
+        }
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{compare_with_pest, diff_lines, Mismatch};
+    use crate::iterators::Token;
+    use std::{string::ToString, vec};
+
+    #[test]
+    fn diff() {
+        assert_eq!(diff_lines("a\nb\nc\n", "a\nb\nc\n"), " a\n b\n c\n");
+        assert_eq!(diff_lines("a\nb\nc\n", "a\nx\nc\n"), " a\n-b\n+x\n c\n");
+        assert_eq!(diff_lines("a\n", "a\nb\n"), " a\n+b\n");
+        assert_eq!(diff_lines("a\nb\n", ""), "-a\n-b\n");
+    }
+
+    #[test]
+    fn compare_matched() {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+        enum R {
+            A,
+        }
+        let token = Token {
+            rule: R::A,
+            start: 0,
+            end: 1,
+            children: vec![],
+        };
+        assert_eq!(
+            compare_with_pest::<R, R>(Some((1, vec![token])), None, false),
+            Err(Mismatch::Matched { typed: true })
+        );
+        assert_eq!(compare_with_pest::<R, R>(None, None, false), Ok(()));
+        assert_eq!(
+            Mismatch::Consumed { typed: 1, pest: 2 }.to_string(),
+            "pest-typed consumed 1 bytes, but pest consumed 2 bytes."
+        );
+    }
+}
@@ -10,19 +10,17 @@
 //! Tracker for parsing failures.
 
 use crate::{
-    error::{Error, ErrorVariant},
+    error::{Error, ErrorVariant, ParseFailure},
     position::Position,
+    span::Span,
     RuleType, RuleWrapper,
 };
-use alloc::{
-    borrow::ToOwned,
-    collections::BTreeMap,
-    format,
-    string::{String, ToString},
-    vec,
-    vec::Vec,
+use alloc::{borrow::ToOwned, collections::BTreeMap, format, string::String, vec, vec::Vec};
+use core::{
+    cmp::Ordering,
+    fmt::{self, Display, Formatter},
+    mem::take,
 };
-use core::cmp::Ordering;
 
 /// Some special errors that are not matching failures.
 pub enum SpecialError {
@@ -32,23 +30,58 @@ pub enum SpecialError {
     RepeatTooManyTimes,
     /// Accessing elements in empty stack, such as Drop or Pop.
     EmptyStack,
+    /// Input left after a full parse, with at most [`TRAILING_INPUT_MAX_CHARS`] characters shown.
+    TrailingInput(String),
+    /// Rules nested deeper than the limit, see [`RuleWrapper::RECURSION_LIMIT`].
+    RecursionLimitExceeded(usize),
+    /// Text matched by the inner expression of a failed negative predicate,
+    /// with at most [`TRAILING_INPUT_MAX_CHARS`] characters shown.
+    UnexpectedMatch(String),
 }
 
-impl ToString for SpecialError {
-    fn to_string(&self) -> String {
+impl Display for SpecialError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
         match self {
             SpecialError::SliceOutOfBound(start, end) => match end {
-                Some(end) => format!("Peek slice {}..{} out of bound.", start, end),
-                None => format!("Peek slice {}.. out of bound.", start),
+                Some(end) => write!(f, "Peek slice {}..{} out of bound.", start, end),
+                None => write!(f, "Peek slice {}.. out of bound.", start),
             },
-            SpecialError::RepeatTooManyTimes => "Repeated too many times.".to_owned(),
-            SpecialError::EmptyStack => "Nothing to pop or drop.".to_owned(),
+            SpecialError::RepeatTooManyTimes => write!(f, "Repeated too many times."),
+            SpecialError::EmptyStack => write!(f, "Nothing to pop or drop."),
+            SpecialError::TrailingInput(found) => {
+                write!(f, "Expected end of input, found `{}`.", found)
+            }
+            SpecialError::RecursionLimitExceeded(limit) => {
+                write!(
+                    f,
+                    "Recursion limit exceeded ({} nested recursive rules).",
+                    limit
+                )
+            }
+            SpecialError::UnexpectedMatch(found) => write!(f, "Found unexpected `{}`.", found),
         }
     }
 }
 
 type Tracked<R> = (Vec<R>, Vec<R>, Vec<SpecialError>);
 
+/// Maximum number of rules shown in the rule path of an error.
+///
+/// Outer rules are elided if there are more.
+pub const RULE_PATH_MAX_DEPTH: usize = 8;
+
+/// Maximum number of characters of trailing input shown in an error.
+///
+/// More characters are elided.
+pub const TRAILING_INPUT_MAX_CHARS: usize = 16;
+
+/// Default maximum number of nested recursive rules, see [`RuleWrapper::RECURSION_LIMIT`].
+///
+/// Generated rules count once per level of nesting in the input, see [`RuleWrapper::COUNTS_RECURSION`].
+/// Even if each level goes through several rules, as in an expression grammar with precedence,
+/// that many levels fit in the 8 MiB stack of a main thread in debug builds.
+pub const DEFAULT_RECURSION_LIMIT: usize = 128;
+
 /// Error tracker.
 pub struct Tracker<'i, R: RuleType> {
     position: Position<'i>,
@@ -56,6 +89,33 @@ pub struct Tracker<'i, R: RuleType> {
     /// upper rule -> (positives, negatives)
     attempts: BTreeMap<Option<R>, Tracked<R>>,
     stack: Vec<(R, Position<'i>, bool)>,
+    /// Rules that the first attempt on current position is nested in.
+    ///
+    /// The buffer is reused when the position moves forward.
+    path: Vec<R>,
+    path_recorded: bool,
+    /// Errors recovered from, if error recovery is enabled.
+    recovered: Option<Vec<Error<R>>>,
+    /// Whether attempts may be pruned, that is, there is a choice branch to fall back to.
+    pruning: bool,
+    /// Position of the zero-width choice commitment being probed, if any.
+    ///
+    /// Only attempts on it are recorded.
+    probe: Option<Position<'i>>,
+    /// Farthest position that any successful match has reached.
+    farthest: Position<'i>,
+    /// Rule -> Name in error messages, see [`RuleWrapper::ERROR_LABEL`].
+    labels: BTreeMap<R, &'static str>,
+    /// Number of rules that current attempt is nested in.
+    depth: usize,
+    /// Whether the recursion limit has been exceeded.
+    ///
+    /// Nothing else is recorded after that.
+    exceeded: bool,
+    /// First span matched by a failed negative predicate on current position.
+    unexpected: Option<Span<'i>>,
+    /// Whether nothing is recorded, as the tracker is dropped without being collected.
+    discarding: bool,
 }
 impl<'i, R: RuleType> Tracker<'i, R> {
     /// Create an empty tracker for attempts.
@@ -65,12 +125,63 @@ impl<'i, R: RuleType> Tracker<'i, R> {
             positive: true,
             attempts: BTreeMap::new(),
             stack: vec![],
+            path: vec![],
+            path_recorded: false,
+            recovered: None,
+            pruning: false,
+            probe: None,
+            farthest: pos,
+            labels: BTreeMap::new(),
+            depth: 0,
+            exceeded: false,
+            unexpected: None,
+            discarding: false,
         }
     }
+    /// Create a tracker that records nothing,
+    /// for nodes that never fail and have no tracker to report to.
+    pub(crate) fn discarding(pos: Position<'i>) -> Self {
+        let mut res = Self::new(pos);
+        res.discarding = true;
+        res
+    }
+    /// Enable error recovery in [`Recover`](crate::predefined_node::Recover) nodes.
+    pub fn with_recovery(mut self) -> Self {
+        self.recovered = Some(vec![]);
+        self
+    }
+    /// Whether error recovery is enabled.
+    pub fn is_recovering(&self) -> bool {
+        self.recovered.is_some()
+    }
+    /// Record an error that has been recovered from.
+    pub fn recover(&mut self, error: Error<R>) {
+        if let Some(recovered) = &mut self.recovered {
+            recovered.push(error);
+        }
+    }
+    /// Take errors that have been recovered from, in positional order.
+    pub fn take_recovered(&mut self) -> Vec<Error<R>> {
+        self.recovered.as_mut().map(take).unwrap_or_default()
+    }
     fn clear(&mut self) {
         self.attempts.clear();
+        self.path_recorded = false;
+        self.unexpected = None;
+    }
+    /// Create an empty tracker for attempts nested in current one, such as those for error recovery.
+    pub(crate) fn nested(&self, pos: Position<'i>) -> Self {
+        let mut res = Self::new(pos);
+        res.depth = self.depth;
+        res
     }
     fn prepare(&mut self, pos: Position<'i>) -> bool {
+        if self.exceeded || self.discarding {
+            return false;
+        }
+        if matches!(self.probe, Some(probe) if probe != pos) {
+            return false;
+        }
         match pos.cmp(&self.position) {
             Ordering::Less => false,
             Ordering::Equal => true,
@@ -88,6 +199,57 @@ impl<'i, R: RuleType> Tracker<'i, R> {
         self.positive = original;
         res
     }
+    /// Allow pruning during calling `f` if `prune` is `true`.
+    ///
+    /// Used by choices for all branches but the last one,
+    /// so that errors of the last branch are reported in full.
+    pub fn pruning_during<Ret>(&mut self, prune: bool, f: impl FnOnce(&mut Self) -> Ret) -> Ret {
+        let original = self.pruning;
+        self.pruning = original || prune;
+        let res = f(self);
+        self.pruning = original;
+        res
+    }
+    /// Record attempts during calling `f` only if they are on `pos`, without recovering from errors.
+    ///
+    /// Rules attempted on `pos` are expected even if they succeed.
+    ///
+    /// Used by choices to probe the branches after one that matched nothing at `pos`,
+    /// so that their expectations are reported if parsing fails on `pos` later.
+    /// Such records are dropped as usual once an attempt goes further.
+    ///
+    /// Nothing is probed in negative state, as those branches are never taken.
+    pub fn probe_during(&mut self, pos: Position<'i>, f: impl FnOnce(&mut Self)) {
+        if !self.positive {
+            return;
+        }
+        let original = self.probe;
+        self.probe = original.or(Some(pos));
+        let recovered = self.recovered.take();
+        f(self);
+        self.recovered = recovered;
+        self.probe = original;
+    }
+    /// Note that a match has reached `pos`, even if it's abandoned later.
+    #[inline]
+    pub fn reach(&mut self, pos: Position<'i>) {
+        if pos.pos() > self.farthest.pos() {
+            self.farthest = pos;
+        }
+    }
+    /// Farthest position that any successful match has reached,
+    /// including those in branches that are abandoned later.
+    ///
+    /// It may go beyond where the committed parse ends,
+    /// and indicates where the input stops making sense.
+    pub fn farthest_reached(&self) -> Position<'i> {
+        self.farthest
+    }
+    /// Whether an attempt at `pos` that consumes at least `min_len` bytes can fail fast without being tracked.
+    #[inline]
+    pub fn prunes(&self, pos: Position<'i>, min_len: usize) -> bool {
+        self.pruning && pos.input.len() - pos.pos() < min_len
+    }
     /// Set the tracker to positive during calling `f`.
     pub fn positive_during<Ret>(&mut self, f: impl FnOnce(&mut Self) -> Ret) -> Ret {
         self.during::<Ret, true>(f)
@@ -100,6 +262,13 @@ impl<'i, R: RuleType> Tracker<'i, R> {
         &'s mut self,
         pos: &Position<'_>,
     ) -> &'s mut (Vec<R>, Vec<R>, Vec<SpecialError>) {
+        if !self.path_recorded {
+            self.path.clear();
+            self.path
+                .extend(self.stack.iter().map(|(rule, _, _)| *rule));
+            self.path.dedup();
+            self.path_recorded = true;
+        }
         // Find lowest rule with the different position.
         let mut upper = None;
         for (upper_rule, upper_pos, _) in self.stack.iter().rev() {
@@ -132,6 +301,41 @@ impl<'i, R: RuleType> Tracker<'i, R> {
             self.get_entry(&pos).2.push(SpecialError::EmptyStack);
         }
     }
+    /// Escape `text` and elide characters after the first [`TRAILING_INPUT_MAX_CHARS`].
+    fn shown(text: &str) -> String {
+        let mut found: String = text
+            .chars()
+            .take(TRAILING_INPUT_MAX_CHARS)
+            .flat_map(char::escape_debug)
+            .collect();
+        if text.chars().nth(TRAILING_INPUT_MAX_CHARS).is_some() {
+            found.push('…');
+        }
+        found
+    }
+    /// Reports input left at `pos` after a full parse.
+    pub fn trailing_input(&mut self, pos: Position<'i>) {
+        if self.prepare(pos) {
+            let found = Self::shown(&pos.input[pos.pos()..]);
+            self.get_entry(&pos)
+                .2
+                .push(SpecialError::TrailingInput(found));
+        }
+    }
+    /// Reports a negative predicate that fails because its inner expression matched `span`.
+    ///
+    /// Nothing is recorded for empty spans or in negative state,
+    /// where the failure of the predicate is what's expected.
+    pub fn unexpected(&mut self, span: Span<'i>) {
+        let pos = span.start_pos();
+        if self.positive && span.start() < span.end() && self.prepare(pos) {
+            self.unexpected.get_or_insert(span);
+            let found = Self::shown(span.as_str());
+            self.get_entry(&pos)
+                .2
+                .push(SpecialError::UnexpectedMatch(found));
+        }
+    }
     fn same_with_last(vec: &[R], rule: R) -> bool {
         match vec.last() {
             Some(last) => *last == rule,
@@ -140,7 +344,9 @@ impl<'i, R: RuleType> Tracker<'i, R> {
     }
     #[inline]
     fn record(&mut self, rule: R, pos: Position<'i>, succeeded: bool) {
-        if self.prepare(pos) && succeeded != self.positive {
+        // Probed rules are expected no matter whether they succeed, as their branches are not taken.
+        let probed = self.probe.is_some() && self.positive;
+        if self.prepare(pos) && (succeeded != self.positive || probed) {
             let positive = self.positive;
             let value = self.get_entry(&pos);
             let vec = if positive { &mut value.0 } else { &mut value.1 };
@@ -156,29 +362,126 @@ impl<'i, R: RuleType> Tracker<'i, R> {
         pos: Position<'i>,
         f: impl FnOnce(&mut Self) -> Option<(Position<'i>, T)>,
         rule: R,
+        label: Option<&'static str>,
     ) -> Option<(Position<'i>, T)> {
+        if self.discarding {
+            return f(self);
+        }
+        if let Some(label) = label {
+            self.labels.insert(rule, label);
+        }
         if let Some((_, _, has_children)) = self.stack.last_mut() {
             *has_children = true;
         }
         self.stack.push((rule, pos, false));
+        let recovered = self.recovered.as_ref().map(Vec::len);
         let res = f(self);
         let succeeded = res.is_some();
+        if let Some((end, _)) = &res {
+            self.reach(*end);
+        }
         let (_r, _pos, has_children) = self.stack.pop().unwrap();
+        // Errors recovered inside a failed rule are discarded with it,
+        // unless it's the outermost rule.
+        if let (false, false, Some(len), Some(recovered)) = (
+            succeeded,
+            self.stack.is_empty(),
+            recovered,
+            &mut self.recovered,
+        ) {
+            recovered.truncate(len);
+        }
         if !has_children {
             self.record(rule, pos, succeeded);
         }
         res
     }
     /// Record if the result doesn't match the state during calling `f`.
+    ///
+    /// Fails without calling `f` if rule `T` is nested too deep, see [`Tracker::nest_rule_during`].
     #[inline]
     pub fn record_during<T: RuleWrapper<R>>(
         &mut self,
         pos: Position<'i>,
         f: impl FnOnce(&mut Self) -> Option<(Position<'i>, T)>,
     ) -> Option<(Position<'i>, T)> {
-        self.record_during_with(pos, f, T::RULE)
+        self.nest_rule_during::<T, _>(pos, |tracker| {
+            tracker.record_during_with(pos, f, T::RULE, T::ERROR_LABEL)
+        })
+    }
+    /// Call `f` inside rule `T`,
+    /// one level deeper in the nesting of rules if it [counts](RuleWrapper::COUNTS_RECURSION).
+    ///
+    /// See [`Tracker::nest_during`].
+    #[inline]
+    pub fn nest_rule_during<T: RuleWrapper<R>, U>(
+        &mut self,
+        pos: Position<'i>,
+        f: impl FnOnce(&mut Self) -> Option<U>,
+    ) -> Option<U> {
+        match T::COUNTS_RECURSION {
+            true => self.nest_during(pos, T::RECURSION_LIMIT, f),
+            false => f(self),
+        }
+    }
+    /// Call `f` one level deeper in the nesting of rules.
+    ///
+    /// If there are already `limit` levels, or the limit has been exceeded by any attempt,
+    /// fails at `pos` without calling `f`,
+    /// and the whole parse fails with [`SpecialError::RecursionLimitExceeded`]
+    /// instead of overflowing the stack.
+    #[inline]
+    pub fn nest_during<T>(
+        &mut self,
+        pos: Position<'i>,
+        limit: usize,
+        f: impl FnOnce(&mut Self) -> Option<T>,
+    ) -> Option<T> {
+        if self.exceeded || self.depth >= limit {
+            self.exceed(pos, limit);
+            return None;
+        }
+        self.depth += 1;
+        let res = f(self);
+        self.depth -= 1;
+        res
+    }
+    #[cold]
+    fn exceed(&mut self, pos: Position<'i>, limit: usize) {
+        if self.exceeded {
+            return;
+        }
+        self.clear();
+        self.position = pos;
+        self.get_entry(&pos)
+            .2
+            .push(SpecialError::RecursionLimitExceeded(limit));
+        self.exceeded = true;
+    }
+    /// Whether any attempt has exceeded the recursion limit, see [`Tracker::nest_during`].
+    ///
+    /// A parse can't succeed after that, even if some choice falls back to a shallower branch.
+    pub fn recursion_limit_exceeded(&self) -> bool {
+        self.exceeded
+    }
+    /// Rules that attempts on current position are nested in, from outer to inner.
+    ///
+    /// Consecutive repeated rules are merged, and silent rules are not included.
+    pub fn rule_path(&self) -> &[R] {
+        if self.path_recorded {
+            &self.path
+        } else {
+            &[]
+        }
     }
-    fn collect_to_message(self) -> String {
+    fn collect_to_message(mut self) -> String {
+        let path = take(&mut self.path);
+        let path = if self.path_recorded { path } else { vec![] };
+        let labels = take(&mut self.labels);
+        let name = |rule: &R| match labels.get(rule) {
+            Some(label) => (*label).to_owned(),
+            None => format!("{:?}", rule),
+        };
         let (pos, attempts) = self.finish();
         // "{} | "
         // "{} = "
@@ -197,39 +500,85 @@ impl<'i, R: RuleType> Tracker<'i, R> {
         use core::fmt::Write;
         let mut message = String::new();
 
+        // A single rule in the path is already shown as the upper rule.
+        if path.len() > 1 {
+            let elided = path.len().saturating_sub(RULE_PATH_MAX_DEPTH);
+            let _ = message.write_str("In ");
+            if elided > 0 {
+                let _ = message.write_str("… → ");
+            }
+            for (i, rule) in path[elided..].iter().enumerate() {
+                if i > 0 {
+                    let _ = message.write_str(" → ");
+                }
+                let _ = write!(message, "`{}`", name(rule));
+            }
+            let _ = message.write_str(":");
+            let _ = message.write_str(&spacing);
+        }
         let _ = write!(message, "{}^---", line_matched);
 
         let mut write_message =
-            |(rule, (mut positives, mut negatives, special)): (Option<R>, Tracked<R>)| {
+            |(rule, (mut positives, mut negatives, mut special)): (Option<R>, Tracked<R>)| {
                 positives.sort();
                 positives.dedup();
                 negatives.sort();
                 negatives.dedup();
-                fn collect_rules<R: RuleType>(vec: Vec<R>) -> String {
-                    format!("{:?}", vec)
+                // Texts matched by failed negative predicates are shown with the unexpected rules.
+                let mut found = vec![];
+                if !negatives.is_empty() {
+                    special.retain(|special| match special {
+                        SpecialError::UnexpectedMatch(text) => {
+                            found.push(format!("`{}`", text));
+                            false
+                        }
+                        _ => true,
+                    });
+                    found.dedup();
                 }
-                let _ = message.write_str(&spacing);
-                let _ = match (positives.is_empty(), negatives.is_empty()) {
-                    (true, true) => write!(message, "Unknown error (no rule tracked)"),
-                    (false, true) => write!(message, "Expected {}", collect_rules(positives)),
-                    (true, false) => write!(message, "Unexpected {}", collect_rules(negatives),),
-                    (false, false) => write!(
-                        message,
-                        "Unexpected {}, expected {}",
-                        collect_rules(negatives),
-                        collect_rules(positives),
-                    ),
-                };
-                if let Some(upper_rule) = rule {
-                    let _ = write!(message, ", by {:?}", upper_rule);
+                let collect_rules = |vec: Vec<R>| {
+                    let names: Vec<String> = vec.iter().map(name).collect();
+                    format!("[{}]", names.join(", "))
                 };
-                let _ = write!(message, ".");
+                // Trailing input, recursion limit and unexpected matches explain themselves if no rule is tracked.
+                let explained = !special.is_empty()
+                    && special.iter().all(|special| {
+                        matches!(
+                            special,
+                            SpecialError::TrailingInput(_)
+                                | SpecialError::RecursionLimitExceeded(_)
+                                | SpecialError::UnexpectedMatch(_)
+                        )
+                    });
+                if !(positives.is_empty() && negatives.is_empty() && explained) {
+                    let _ = message.write_str(&spacing);
+                    let _ = match (positives.is_empty(), negatives.is_empty()) {
+                        (true, true) => write!(message, "Unknown error (no rule tracked)"),
+                        (false, true) => write!(message, "Expected {}", collect_rules(positives)),
+                        (true, false) => {
+                            write!(message, "Unexpected {}", collect_rules(negatives),)
+                        }
+                        (false, false) => write!(
+                            message,
+                            "Unexpected {}, expected {}",
+                            collect_rules(negatives),
+                            collect_rules(positives),
+                        ),
+                    };
+                    if !found.is_empty() {
+                        let _ = write!(message, " (found {})", found.join(", "));
+                    }
+                    if let Some(upper_rule) = rule {
+                        let _ = write!(message, ", by {}", name(&upper_rule));
+                    };
+                    let _ = write!(message, ".");
+                }
 
                 for special in special {
                     let _ = message.write_str(&spacing);
-                    let _ = write!(message, "{}", special.to_string());
+                    let _ = write!(message, "{}", special);
                     if let Some(upper_rule) = rule {
-                        let _ = write!(message, " (By {:?})", upper_rule);
+                        let _ = write!(message, " (By {})", name(&upper_rule));
                     };
                 }
             };
@@ -239,6 +588,9 @@ impl<'i, R: RuleType> Tracker<'i, R> {
         message
     }
     /// Collect attempts to [`Error<R>`]
+    ///
+    /// Rules are named by their [`RuleWrapper::ERROR_LABEL`]s if any,
+    /// and by their [`Debug`](core::fmt::Debug) representations otherwise.
     pub fn collect(self) -> Error<R> {
         let pos = self.position;
         match pest::Position::new(pos.input, pos.pos()).ok_or_else(|| {
@@ -250,12 +602,38 @@ impl<'i, R: RuleType> Tracker<'i, R> {
             )
         }) {
             Ok(pos) => {
+                // Point to the text that a negative predicate unexpectedly matched, if any.
+                let span = self
+                    .unexpected
+                    .and_then(|span| pest::Span::new(span.get_input(), span.start(), span.end()));
                 let message = self.collect_to_message();
-                Error::new_from_pos(ErrorVariant::CustomError { message }, pos)
+                let variant = ErrorVariant::CustomError { message };
+                match span {
+                    Some(span) => Error::new_from_span(variant, span),
+                    None => Error::new_from_pos(variant, pos),
+                }
             }
             Err(err) => err,
         }
     }
+    /// Collect attempts to a [`ParseFailure`],
+    /// with the error from [`Tracker::collect`], rules attempted on current position
+    /// and the farthest position reached.
+    ///
+    /// Rules attempted by all upper rules are merged, sorted and deduplicated.
+    pub fn collect_failure(self) -> ParseFailure<R> {
+        let farthest = self.farthest;
+        let (mut expected, mut unexpected) = (vec![], vec![]);
+        for (positives, negatives, _) in self.attempts.values() {
+            expected.extend_from_slice(positives);
+            unexpected.extend_from_slice(negatives);
+        }
+        expected.sort();
+        expected.dedup();
+        unexpected.sort();
+        unexpected.dedup();
+        ParseFailure::new(self.collect(), farthest).with_attempts(expected, unexpected)
+    }
     /// Finish matching and convert the tracker into recorded information.
     ///
     /// Returned value is:
@@ -272,6 +650,7 @@ impl<'i, R: RuleType> Tracker<'i, R> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[allow(dead_code, clippy::upper_case_acronyms)]
     #[derive(Clone, Copy, Debug, Hash, Eq, Ord, PartialEq, PartialOrd)]
     enum Rule {
         Program,
@@ -286,6 +665,7 @@ mod tests {
 
         macro_rules! wrap {
             ($name:ident) => {
+                #[allow(dead_code, clippy::upper_case_acronyms)]
                 #[derive(Clone, PartialEq)]
                 pub struct $name;
                 impl RuleWrapper<Rule> for $name {
@@ -301,6 +681,19 @@ mod tests {
         wrap!(EOI);
     }
     #[test]
+    fn discarding() {
+        let pos = Position::from_start("abc");
+        let mut tracker = Tracker::<'_, Rule>::discarding(pos);
+        let res = tracker.record_during(pos, |tracker| {
+            tracker.record_during::<rule_wrappers::Main>(pos, |_| None)?;
+            Some((pos, rule_wrappers::Program))
+        });
+        assert!(res.is_none());
+        tracker.empty_stack(pos);
+        let (_, attempts) = tracker.finish();
+        assert!(attempts.is_empty());
+    }
+    #[test]
     fn negative() -> Result<(), ()> {
         let pos = Position::from_start("abc\ndef\nghi");
         let mut tracker = Tracker::<'_, Rule>::new(pos);
@@ -421,9 +814,45 @@ mod tests {
 1 | αβψ
   |  ^---
   |
-  = α^---
+  = In `Program` → `Main`:
+    α^---
     Unexpected [Body], by Program."#
         );
         Ok(())
     }
+    #[test]
+    fn unexpected() {
+        let input = "while x";
+        let start = Position::from_start(input);
+        let span = Span::new(input, 0, 5).unwrap();
+        let mut tracker = Tracker::<'_, Rule>::new(start);
+        tracker.negative_during(|tracker| tracker.unexpected(span));
+        tracker.unexpected(Span::new(input, 0, 0).unwrap());
+        assert!(tracker.unexpected.is_none());
+        tracker.unexpected(span);
+        assert_eq!(tracker.unexpected, Some(span));
+        let error = tracker.collect();
+        assert_eq!(error.location, pest::error::InputLocation::Span((0, 5)));
+        assert!(format!("{}", error).ends_with("Found unexpected `while`."));
+    }
+    #[test]
+    fn rule_path() {
+        let input = "αβψ";
+        let start = Position::from_start(input);
+        let mut next = start;
+        assert!(next.match_string("α"));
+        let mut tracker = Tracker::<'_, Rule>::new(start);
+        assert_eq!(tracker.rule_path(), []);
+        let _ = tracker.record_during(start, |tracker| {
+            let _ = tracker.record_during::<rule_wrappers::Main>(start, |tracker| {
+                let _ = tracker.record_during::<rule_wrappers::Body>(start, |_| None);
+                None
+            });
+            assert_eq!(tracker.rule_path(), [Rule::Program, Rule::Main]);
+            let _ = tracker.record_during::<rule_wrappers::EOI>(next, |_| None);
+            assert_eq!(tracker.rule_path(), [Rule::Program]);
+            Some((next, rule_wrappers::Program))
+        });
+        assert_eq!(tracker.rule_path(), [Rule::Program]);
+    }
 }
@@ -8,11 +8,20 @@
 // modified, or distributed except according to those terms.
 
 use crate::{
-    error::Error, position::Position, predefined_node::restore_on_none, span::Span,
-    tracker::Tracker, RuleWrapper, Stack,
+    error::{Error, ParseFailure, ParseResult},
+    position::Position,
+    predefined_node::restore_on_none,
+    span::Span,
+    tracker::Tracker,
+    RuleWrapper, Stack,
 };
 use alloc::{boxed::Box, vec::Vec};
-use core::fmt::Debug;
+use core::{
+    fmt::{self, Debug, Formatter},
+    hash::{Hash, Hasher},
+    ops::{Deref, DerefMut},
+    str::FromStr,
+};
 use pest::RuleType;
 
 /// Node of concrete syntax tree that never fails.
@@ -39,6 +48,10 @@ where
 }
 
 /// Node of concrete syntax tree.
+///
+/// Methods that may fail return errors in a [`Box`], see [`ParseResult`],
+/// as an [`Error`] is much larger than most nodes and would enlarge every successful result.
+/// Generated `parse_<rule>` methods and `parse_rule` return errors in the same form.
 pub trait ParsableTypedNode<'i, R: RuleType>: TypedNode<'i, R> {
     /// Try to create typed node.
     fn try_parse_with_until_end(
@@ -48,26 +61,132 @@ pub trait ParsableTypedNode<'i, R: RuleType>: TypedNode<'i, R> {
     ) -> Option<Self>;
     /// Try to parse the whole input into given typed node.
     /// A rule is not atomic by default.
-    fn try_parse(input: &'i str) -> Result<Self, Box<Error<R>>> {
+    fn try_parse(input: &'i str) -> ParseResult<Self, R> {
         let mut stack = Stack::new();
-        let input = Position::from_start(input);
-        let mut tracker = Tracker::new(input);
-        match Self::try_parse_with_until_end(input, &mut stack, &mut tracker) {
+        let mut tracker = Tracker::new(Position::from_start(input));
+        match Self::try_parse_with_state(input, &mut stack, &mut tracker) {
             Some(res) => Ok(res),
-            None => Err(Box::new(tracker.collect())),
+            None => Err(Box::new(tracker.collect_failure())),
         }
     }
+    /// Try to parse the whole input into given typed node, with a caller-supplied stack and tracker.
+    ///
+    /// The stack can be seeded before parsing, such as with the indentation of the enclosing block
+    /// when parsing a fragment, and spans on it may come from other strings than `input`.
+    /// Spans pushed and not popped by the parse are left on it.
+    ///
+    /// The tracker should be created on the same input,
+    /// and if this fails, [`Tracker::collect`] reports the error.
+    fn try_parse_with_state(
+        input: &'i str,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<Self> {
+        Self::try_parse_with_until_end(Position::from_start(input), stack, tracker)
+    }
+    /// Try to parse the whole input into given typed node,
+    /// and wrap it in a [`Root`] with the span of the whole input.
+    ///
+    /// Useful for silent rules, whose structs don't store spans.
+    fn try_parse_root(input: &'i str) -> ParseResult<Root<'i, Self>, R> {
+        let content = Self::try_parse(input)?;
+        let span = Span::new(input, 0, input.len()).unwrap();
+        Ok(Root { content, span })
+    }
+    /// Try to parse the whole input into given typed node, recording attempts to a caller-supplied tracker.
+    ///
+    /// The tracker can be reused to parse the same input with several alternative nodes.
+    /// Like attempts inside a single parse, only attempts on the farthest position are kept,
+    /// and attempts on the same position are merged.
+    /// So if all of them fail, [`Tracker::collect`] reports the farthest failure,
+    /// with expectations of all nodes that fail there.
+    ///
+    /// The tracker should be created on the same input, such as with `Tracker::new(Position::from_start(input))`.
+    #[deprecated(
+        note = "use `try_parse_with_state` with `&mut Stack::new()`, which also takes a tracker"
+    )]
+    fn try_parse_with_tracker(input: &'i str, tracker: &mut Tracker<'i, R>) -> Option<Self> {
+        let mut stack = Stack::new();
+        Self::try_parse_with_state(input, &mut stack, tracker)
+    }
+    /// Parse the whole input into given typed node,
+    /// recovering from failures inside [`Recover`](crate::predefined_node::Recover) nodes.
+    ///
+    /// Returns the tree if the whole input is matched after recovery,
+    /// and errors in positional order, followed by the final error if parsing still fails.
+    fn parse_with_recovery(input: &'i str) -> (Option<Self>, Vec<Error<R>>) {
+        let mut stack = Stack::new();
+        let input = Position::from_start(input);
+        let mut tracker = Tracker::new(input).with_recovery();
+        let res = Self::try_parse_with_until_end(input, &mut stack, &mut tracker);
+        let mut errors = tracker.take_recovered();
+        if res.is_none() {
+            errors.push(tracker.collect());
+        }
+        (res, errors)
+    }
     /// Try to parse the whole input into given typed node.
     /// A rule is not atomic by default.
-    fn try_parse_partial(input: &'i str) -> Result<(Position<'i>, Self), Box<Error<R>>> {
+    fn try_parse_partial(input: &'i str) -> ParseResult<(Position<'i>, Self), R> {
         let mut stack = Stack::new();
-        let input = Position::from_start(input);
-        let mut tracker = Tracker::new(input);
-        match Self::try_parse_with(input, &mut stack, &mut tracker) {
+        let mut tracker = Tracker::new(Position::from_start(input));
+        match Self::try_parse_partial_with_state(input, &mut stack, &mut tracker) {
             Some((input, res)) => Ok((input, res)),
-            None => Err(Box::new(tracker.collect())),
+            None => Err(Box::new(tracker.collect_failure())),
+        }
+    }
+    /// Like [`ParsableTypedNode::try_parse_partial`],
+    /// but with a caller-supplied stack and tracker as in [`ParsableTypedNode::try_parse_with_state`].
+    fn try_parse_partial_with_state(
+        input: &'i str,
+        stack: &mut Stack<Span<'i>>,
+        tracker: &mut Tracker<'i, R>,
+    ) -> Option<(Position<'i>, Self)> {
+        match Self::try_parse_with(Position::from_start(input), stack, tracker) {
+            Some(res) if !tracker.recursion_limit_exceeded() => Some(res),
+            _ => None,
+        }
+    }
+    /// Like [`ParsableTypedNode::try_parse_partial`],
+    /// but also returns the stack after parsing.
+    ///
+    /// Spans pushed and not popped are left on it, such as indentations of unclosed blocks.
+    /// See [`StackExt`](crate::StackExt) for reading it.
+    #[allow(clippy::type_complexity)]
+    fn try_parse_partial_with_stack(
+        input: &'i str,
+    ) -> ParseResult<(Position<'i>, Self, Stack<Span<'i>>), R> {
+        let mut stack = Stack::new();
+        let mut tracker = Tracker::new(Position::from_start(input));
+        match Self::try_parse_partial_with_state(input, &mut stack, &mut tracker) {
+            Some((input, res)) => Ok((input, res, stack)),
+            None => Err(Box::new(tracker.collect_failure())),
         }
     }
+    /// Like [`ParsableTypedNode::try_parse_partial`],
+    /// but also returns the farthest position that any match has reached,
+    /// see [`Tracker::farthest_reached`].
+    #[allow(clippy::type_complexity)]
+    fn try_parse_partial_with_farthest(
+        input: &'i str,
+    ) -> (ParseResult<(Position<'i>, Self), R>, Position<'i>) {
+        let mut stack = Stack::new();
+        let mut tracker = Tracker::new(Position::from_start(input));
+        let res = Self::try_parse_partial_with_state(input, &mut stack, &mut tracker);
+        let farthest = tracker.farthest_reached();
+        match res {
+            Some((input, res)) => (Ok((input, res)), farthest),
+            None => (Err(Box::new(tracker.collect_failure())), farthest),
+        }
+    }
+    /// Same as [`ParsableTypedNode::try_parse_partial`],
+    /// whose [`ParseFailure`] carries the farthest position that any match has reached.
+    #[deprecated(note = "use `try_parse_partial`, whose error also carries the farthest position")]
+    fn try_parse_partial_with_failure(
+        input: &'i str,
+    ) -> Result<(Position<'i>, Self), Box<ParseFailure<R>>> {
+        Self::try_parse_partial(input)
+    }
 }
 
 /// Node of concrete syntax tree.
@@ -88,6 +207,13 @@ pub trait NeverFailedParsableTypedNode<'i, R: RuleType>: NeverFailedTypedNode<'i
         let input = Position::from_start(input);
         Self::parse_with(input, &mut stack)
     }
+    /// Like [`NeverFailedParsableTypedNode::parse_partial`], but also returns the stack after parsing.
+    fn parse_partial_with_stack(input: &'i str) -> (Position<'i>, Self, Stack<Span<'i>>) {
+        let mut stack = Stack::new();
+        let input = Position::from_start(input);
+        let (input, res) = Self::parse_with(input, &mut stack);
+        (input, res, stack)
+    }
 }
 
 pub trait RuleStorage<R: RuleType> {
@@ -103,6 +229,22 @@ impl<R: RuleType, T: RuleWrapper<R>> RuleStorage<R> for T {
 pub trait Spanned<'i, R: RuleType> {
     /// The span of a matched expression by a non-silent rule.
     fn span(&self) -> Span<'i>;
+    /// The text matched by a non-silent rule.
+    fn as_str(&self) -> &'i str {
+        self.span().as_str()
+    }
+    /// The whole input that a non-silent rule was matched in,
+    /// such as for showing lines around it in diagnostics.
+    fn input(&self) -> &'i str {
+        self.span().get_input()
+    }
+    /// Parse the matched text with [`FromStr`], such as `number.parse_to::<f64>()`.
+    fn parse_to<F: FromStr>(&self) -> Result<F, F::Err>
+    where
+        Self: Sized,
+    {
+        self.as_str().parse()
+    }
 }
 
 /// A trait for those struct that correspond to rules with inner expression.
@@ -117,6 +259,185 @@ pub trait RuleStruct<'i, R: RuleType>: RuleStorage<R> {
     fn mut_inner(&mut self) -> &mut Self::Inner;
 }
 
+/// A trait for nodes that can write the text they matched.
+///
+/// Skipped contents (by rule `WHITESPACE` and `COMMENT`) are also written,
+/// so that the written text of a rule struct is exactly what it matched.
+///
+/// Rule structs, sequences and choices implement [`Display`](core::fmt::Display) with this.
+pub trait MatchedText {
+    /// Write the matched text.
+    fn fmt_matched(&self, f: &mut Formatter<'_>) -> fmt::Result;
+}
+impl<T: MatchedText + ?Sized> MatchedText for Box<T> {
+    fn fmt_matched(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        T::fmt_matched(self, f)
+    }
+}
+impl<T: MatchedText> MatchedText for Option<T> {
+    fn fmt_matched(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Some(content) => content.fmt_matched(f),
+            None => Ok(()),
+        }
+    }
+}
+impl MatchedText for () {
+    fn fmt_matched(&self, _f: &mut Formatter<'_>) -> fmt::Result {
+        Ok(())
+    }
+}
+
+/// A trait for comparing and hashing nodes by their contents.
+///
+/// Unlike [`PartialEq`] and [`Hash`], skipped contents (by rule `WHITESPACE` and `COMMENT`) and positions are ignored,
+/// so only chosen branches, numbers of repeated elements and matched texts of terminal nodes are compared.
+///
+/// Wrap a node in [`ByContent`] to deduplicate trees with this.
+pub trait ContentEq {
+    /// Whether the contents are the same.
+    fn content_eq(&self, other: &Self) -> bool;
+    /// Hash the contents, consistently with [`ContentEq::content_eq`].
+    fn content_hash<H: Hasher>(&self, state: &mut H);
+    /// Whether the contents are the same, except that case-insensitive strings are compared ignoring case.
+    ///
+    /// Matched texts of atomic rules are still compared exactly.
+    ///
+    /// The same as [`ContentEq::content_eq`] by default,
+    /// so nodes with children must forward it to them.
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.content_eq(other)
+    }
+}
+impl<T: ContentEq + ?Sized> ContentEq for &T {
+    fn content_eq(&self, other: &Self) -> bool {
+        T::content_eq(self, other)
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        T::content_hash(self, state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        T::content_eq_ignore_case(self, other)
+    }
+}
+impl<T: ContentEq + ?Sized> ContentEq for Box<T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        T::content_eq(self, other)
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        T::content_hash(self, state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        T::content_eq_ignore_case(self, other)
+    }
+}
+impl<T: ContentEq> ContentEq for Option<T> {
+    fn content_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(lhs), Some(rhs)) => lhs.content_eq(rhs),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.is_some().hash(state);
+        if let Some(content) = self {
+            content.content_hash(state)
+        }
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(lhs), Some(rhs)) => lhs.content_eq_ignore_case(rhs),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+}
+impl<T: ContentEq> ContentEq for [T] {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(l, r)| l.content_eq(r))
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.len().hash(state);
+        self.iter().for_each(|i| i.content_hash(state))
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|(l, r)| l.content_eq_ignore_case(r))
+    }
+}
+impl<T: ContentEq, const N: usize> ContentEq for [T; N] {
+    fn content_eq(&self, other: &Self) -> bool {
+        self[..].content_eq(&other[..])
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self[..].content_hash(state)
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self[..].content_eq_ignore_case(&other[..])
+    }
+}
+impl<T1: ContentEq, T2: ContentEq> ContentEq for (T1, T2) {
+    fn content_eq(&self, other: &Self) -> bool {
+        self.0.content_eq(&other.0) && self.1.content_eq(&other.1)
+    }
+    fn content_hash<H: Hasher>(&self, state: &mut H) {
+        self.0.content_hash(state);
+        self.1.content_hash(state);
+    }
+    fn content_eq_ignore_case(&self, other: &Self) -> bool {
+        self.0.content_eq_ignore_case(&other.0) && self.1.content_eq_ignore_case(&other.1)
+    }
+}
+impl ContentEq for () {
+    fn content_eq(&self, _other: &Self) -> bool {
+        true
+    }
+    fn content_hash<H: Hasher>(&self, _state: &mut H) {}
+}
+
+/// A node parsed from the whole input, and the span of the whole input.
+///
+/// Returned by [`ParsableTypedNode::try_parse_root`].
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Root<'i, T> {
+    /// Parsed node.
+    pub content: T,
+    /// The whole input.
+    pub span: Span<'i>,
+}
+impl<'i, T> Deref for Root<'i, T> {
+    type Target = T;
+    fn deref(&self) -> &Self::Target {
+        &self.content
+    }
+}
+impl<'i, T> DerefMut for Root<'i, T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.content
+    }
+}
+
+/// Compare and hash the wrapped node by [`ContentEq`].
+///
+/// For example, trees differing only in skipped contents are the same in a `HashSet<ByContent<_>>`.
+#[derive(Clone, Copy, Debug)]
+pub struct ByContent<T>(pub T);
+impl<T: ContentEq> PartialEq for ByContent<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.content_eq(&other.0)
+    }
+}
+impl<T: ContentEq> Eq for ByContent<T> {}
+impl<T: ContentEq> Hash for ByContent<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.0.content_hash(state)
+    }
+}
+
 /// Match `[T; N]`.
 impl<'i, R: RuleType, T: TypedNode<'i, R>, const N: usize> TypedNode<'i, R> for [T; N] {
     fn try_parse_with(
@@ -128,6 +449,7 @@ impl<'i, R: RuleType, T: TypedNode<'i, R>, const N: usize> TypedNode<'i, R> for
         for _ in 0..N {
             let (next, res) = T::try_parse_with(input, stack, tracker)?;
             input = next;
+            tracker.reach(input);
             vec.push(res);
         }
         match vec.try_into() {
@@ -147,7 +469,9 @@ impl<'i, R: RuleType, T1: TypedNode<'i, R>, T2: TypedNode<'i, R>> TypedNode<'i,
         tracker: &mut Tracker<'i, R>,
     ) -> Option<(Position<'i>, Self)> {
         let (input, t1) = T1::try_parse_with(input, stack, tracker)?;
+        tracker.reach(input);
         let (input, t2) = T2::try_parse_with(input, stack, tracker)?;
+        tracker.reach(input);
         Some((input, (t1, t2)))
     }
 }
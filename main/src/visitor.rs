@@ -0,0 +1,156 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Walk through rule structs in a typed tree.
+//!
+//! Visitors themselves are generated by **pest_typed_derive** with `emit_visitor`.
+
+use crate::{
+    predefined_node::{
+        AlwaysFail, AtomicRep, CharExcept, CharRange, Empty, Insens, InsensUnicode, Keyword,
+        Negative, PeekSlice1, PeekSlice2, Positive, Presence, Push, PushLiteral, Recover, RepMin,
+        RepMinMax, RepUntil, Skip, Skipped, SpanOnly, Str, StrChoice, StrSpanned, ANY, DROP, EOI,
+        NEWLINE, PEEK, PEEK_ALL, PEEK_INSENS, POP, POP_ALL, POP_INSENS, SOI,
+    },
+    StringArrayWrapper, StringWrapper,
+};
+use alloc::boxed::Box;
+
+/// A node that can be walked through by visitor `V`.
+///
+/// Rule structs call the corresponding method of `V`,
+/// while other nodes walk through their children in order.
+pub trait Walk<V: ?Sized> {
+    /// Walk through rule structs inside this node.
+    fn walk(&self, visitor: &mut V);
+}
+
+macro_rules! impl_empty {
+    ($node:ty, $($tt:tt)*) => {
+        impl<$($tt)* V: ?Sized> Walk<V> for $node {
+            #[inline]
+            fn walk(&self, _visitor: &mut V) {}
+        }
+    };
+}
+
+impl_empty!(Str<T>, T: StringWrapper,);
+impl_empty!(StrSpanned<'i, T>, 'i, T: StringWrapper,);
+impl_empty!(SpanOnly<'i, T>, 'i, T,);
+impl_empty!(Presence<T>, T: StringWrapper,);
+impl_empty!(Insens<'i, T>, 'i, T: StringWrapper,);
+impl_empty!(InsensUnicode<'i, T>, 'i, T: StringWrapper,);
+impl_empty!(Keyword<'i, T, Boundary>, 'i, T: StringWrapper, Boundary,);
+impl_empty!(PushLiteral<T>, T: StringWrapper,);
+impl_empty!(PeekSlice2<'i, START, END>, 'i, const START: i32, const END: i32,);
+impl_empty!(PeekSlice1<'i, START>, 'i, const START: i32,);
+impl_empty!(Skip<'i, Strings>, 'i, Strings: StringArrayWrapper,);
+impl_empty!(CharExcept<Strings>, Strings: StringArrayWrapper,);
+impl_empty!(StrChoice<Strings>, Strings: StringArrayWrapper,);
+impl_empty!(CharRange<MIN, MAX>, const MIN: char, const MAX: char,);
+impl_empty!(Positive<T>, T,);
+impl_empty!(Negative<T>, T,);
+impl_empty!(ANY,);
+impl_empty!(SOI,);
+impl_empty!(EOI,);
+impl_empty!(NEWLINE,);
+impl_empty!(PEEK<'i>, 'i,);
+impl_empty!(PEEK_ALL<'i>, 'i,);
+impl_empty!(POP<'i>, 'i,);
+impl_empty!(POP_ALL<'i>, 'i,);
+impl_empty!(PEEK_INSENS<'i>, 'i,);
+impl_empty!(POP_INSENS<'i>, 'i,);
+impl_empty!(DROP,);
+impl_empty!(AlwaysFail<'i>, 'i,);
+impl_empty!(Empty<'i>, 'i,);
+
+impl<'i, V: ?Sized, T: Walk<V>> Walk<V> for Push<'i, T> {
+    fn walk(&self, visitor: &mut V) {
+        self.content.walk(visitor)
+    }
+}
+
+impl<V: ?Sized, T: Walk<V>> Walk<V> for Box<T> {
+    fn walk(&self, visitor: &mut V) {
+        T::walk(self, visitor)
+    }
+}
+
+impl<V: ?Sized, T: Walk<V>> Walk<V> for Option<T> {
+    fn walk(&self, visitor: &mut V) {
+        if let Some(inner) = self {
+            inner.walk(visitor)
+        }
+    }
+}
+
+impl<'i, V: ?Sized, T: Walk<V>, Sync: Walk<V>> Walk<V> for Recover<'i, T, Sync> {
+    fn walk(&self, visitor: &mut V) {
+        match self {
+            Self::Matched(matched) => matched.walk(visitor),
+            Self::Recovered { sync, .. } => sync.walk(visitor),
+        }
+    }
+}
+
+impl<V: ?Sized, T1: Walk<V>, T2: Walk<V>> Walk<V> for (T1, T2) {
+    fn walk(&self, visitor: &mut V) {
+        self.0.walk(visitor);
+        self.1.walk(visitor);
+    }
+}
+
+impl<V: ?Sized, T: Walk<V>, const N: usize> Walk<V> for [T; N] {
+    fn walk(&self, visitor: &mut V) {
+        self.iter().for_each(|i| i.walk(visitor))
+    }
+}
+
+impl<V: ?Sized, T: Walk<V>, Skip: Walk<V>, const SKIP: usize> Walk<V> for Skipped<T, Skip, SKIP> {
+    fn walk(&self, visitor: &mut V) {
+        self.skipped.walk(visitor);
+        self.matched.walk(visitor);
+    }
+}
+
+macro_rules! impl_with_vec {
+    ($name:ident, $(const $args:ident : $t:ty,)*) => {
+        impl<V: ?Sized, T: Walk<V>, $(const $args: $t, )*> Walk<V> for $name<T, $($args, )*> {
+            fn walk(&self, visitor: &mut V) {
+                self.content.iter().for_each(|i| i.walk(visitor))
+            }
+        }
+    };
+}
+
+impl_with_vec!(AtomicRep,);
+
+macro_rules! impl_with_skipped_vec {
+    ($name:ident, $(const $args:ident : $t:ty,)*) => {
+        impl<V: ?Sized, T: Walk<V>, Skip: Walk<V>, const SKIP: usize, $(const $args: $t, )*> Walk<V>
+            for $name<T, Skip, SKIP, $($args, )*>
+        {
+            fn walk(&self, visitor: &mut V) {
+                for (skipped, matched) in self.skipped.iter().zip(&self.content) {
+                    skipped.walk(visitor);
+                    matched.walk(visitor);
+                }
+            }
+        }
+    };
+}
+
+impl_with_skipped_vec!(RepMinMax, const MIN: usize, const MAX: usize,);
+impl_with_skipped_vec!(RepMin, const MIN: usize,);
+
+impl<'i, V: ?Sized, T: Walk<V>, Terminator> Walk<V> for RepUntil<'i, T, Terminator> {
+    fn walk(&self, visitor: &mut V) {
+        self.content.iter().for_each(|i| i.walk(visitor))
+    }
+}
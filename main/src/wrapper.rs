@@ -55,6 +55,23 @@ pub trait RuleWrapper<R: RuleType>: Clone + PartialEq {
     const RULE: R;
     /// The type of wrapped rule.
     type Rule;
+    /// Minimum number of bytes that the rule consumes.
+    ///
+    /// It's a lower bound, and `0` if unknown.
+    const MIN_LEN: usize = 0;
+    /// Name of the rule in error messages, instead of its [`Debug`](core::fmt::Debug) representation.
+    ///
+    /// See [`Tracker::collect`](crate::tracker::Tracker::collect).
+    const ERROR_LABEL: Option<&'static str> = None;
+    /// Maximum number of rules counting towards the limit that the rule can be nested in, including itself.
+    ///
+    /// See [`COUNTS_RECURSION`](Self::COUNTS_RECURSION) and [`Tracker::nest_during`](crate::tracker::Tracker::nest_during).
+    const RECURSION_LIMIT: usize = crate::tracker::DEFAULT_RECURSION_LIMIT;
+    /// Whether nesting of the rule counts towards [`RECURSION_LIMIT`](Self::RECURSION_LIMIT).
+    ///
+    /// Generated rules only set it on one rule of each recursion cycle,
+    /// so that a level of nesting in the input counts once however many rules it goes through.
+    const COUNTS_RECURSION: bool = true;
 
     /// Get wrapped rule.
     fn get_rule(&self) -> R {
@@ -10,6 +10,7 @@
 extern crate alloc;
 
 #[cfg(test)]
+#[allow(clippy::upper_case_acronyms)]
 mod tests {
     use pest_typed::{
         atomic_rule,
@@ -20,10 +21,19 @@ mod tests {
         predefined_node::*,
         rule_eoi,
         sequence::{Seq2, Seq3},
-        silent_rule, BoundWrapper, ParsableTypedNode, RuleStruct, RuleWrapper, Storage,
-        StringArrayWrapper, StringWrapper, TypeWrapper,
+        silent_rule,
+        tracker::Tracker,
+        BoundWrapper, ByContent, ContentEq, ParsableTypedNode, Position, RuleStruct, RuleType,
+        RuleWrapper, Span, Stack, StackExt, Storage, StringArrayWrapper, StringWrapper,
+        TypeWrapper, TypedNode,
+    };
+    use std::{
+        collections::HashSet,
+        fmt::Write,
+        ops::Deref,
+        panic::{catch_unwind, AssertUnwindSafe},
+        string::String,
     };
-    use std::{fmt::Write, ops::Deref, string::String};
 
     #[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
     enum Rule {
@@ -85,6 +95,18 @@ mod tests {
         )
     }
 
+    #[test]
+    fn trailing_input() {
+        EOI::<0>::try_parse("").unwrap();
+        EOI::<0>::try_parse(" ").unwrap_err();
+        assert_eq!(<EOI<'_, 0> as RuleWrapper<Rule>>::RULE, Rule::EOI);
+        let err = StrFoo::try_parse("foo\tbar").unwrap_err();
+        assert_eq!(err.location, pest::error::InputLocation::Pos(4));
+        assert!(err
+            .to_string()
+            .ends_with("Expected end of input, found `bar`."));
+    }
+
     #[test]
     fn range() {
         let whitespace = WHITESPACE::try_parse(" ").unwrap();
@@ -100,6 +122,24 @@ mod tests {
     }
     type Ignore<'i> = AtomicRep<Choice2<WHITESPACE<'i>, COMMENT<'i>>>;
 
+    #[test]
+    fn range_bounds() {
+        atomic_rule!(
+            abc,
+            "From `a` to `c`.",
+            Rule,
+            Rule::Foo,
+            CharRange::<'a', 'c'>
+        );
+        atomic_rule!(single, "Only `b`.", Rule, Rule::Foo, CharRange::<'b', 'b'>);
+        assert_eq!(abc::try_parse("a").unwrap().span.as_str(), "a");
+        assert_eq!(abc::try_parse("c").unwrap().span.as_str(), "c");
+        abc::try_parse("d").unwrap_err();
+        abc::try_parse("`").unwrap_err();
+        single::try_parse("b").unwrap();
+        single::try_parse("c").unwrap_err();
+    }
+
     #[test]
     fn ignore() {
         silent_rule!(
@@ -143,6 +183,20 @@ mod tests {
         assert_eq!(format(&rep1), format(&rep2));
         assert_eq!(format(&rep1), format(&rep3));
 
+        // Matched expressions can be reached by index, also through the rule.
+        assert_eq!(rep2.len(), 3);
+        assert!(!rep2.is_empty());
+        assert_eq!(rep2[1].span.as_str(), "foo");
+        assert_eq!(rep2[1].span.start(), 4);
+        assert_eq!(rep2.get(1), Some(&rep2[1]));
+        assert_eq!(rep2.get(3), None);
+        assert_eq!(rep2.first().unwrap().span.start(), 0);
+        assert_eq!(rep2.last().unwrap().span.start(), 8);
+        let empty = R::try_parse("").unwrap();
+        assert!(empty.is_empty());
+        assert_eq!(empty.first(), None);
+        assert_eq!(empty.last(), None);
+
         for i in rep1.clone().into_inner() {
             assert_eq!(i.rule(), Rule::Foo);
         }
@@ -157,6 +211,15 @@ mod tests {
             rep3.iter_matched().collect::<Vec<_>>()
         );
 
+        // Skipped contents and positions are ignored by content.
+        assert!(rep1.content_eq(&rep2));
+        assert!(rep1.content_eq(&rep3));
+        let set = HashSet::from([ByContent(&rep1), ByContent(&rep2), ByContent(&rep3)]);
+        assert_eq!(set.len(), 1);
+        let rep4 = R::try_parse("foo foo").unwrap();
+        assert!(!rep1.content_eq(&rep4));
+        assert!(!set.contains(&ByContent(&rep4)));
+
         assert_eq!(REP::MIN, 0);
         assert_eq!(rep1.deref().get_min_len(), 0);
         assert_eq!(rep1.deref().get_max_len(), usize::MAX);
@@ -194,6 +257,16 @@ mod tests {
         assert_eq!(collect(&rep1), collect(&rep3));
         assert_eq!(collect(&rep1), collect(&rep4));
 
+        // Case-insensitive strings keep their cases unless compared ignoring case.
+        assert!(rep1.content_eq(&rep2));
+        assert!(!rep1.content_eq(&rep3));
+        assert!(!rep1.content_eq(&rep4));
+        assert!(rep1.content_eq_ignore_case(&rep2));
+        assert!(rep1.content_eq_ignore_case(&rep3));
+        assert!(rep1.content_eq_ignore_case(&rep4));
+        let rep5 = R::try_parse("FOO FOO").unwrap();
+        assert!(!rep1.content_eq_ignore_case(&rep5));
+
         assert_eq!(rep1.clone().into_iter_pairs().count(), 1);
         assert_eq!(
             rep1.clone().into_iter_pairs().next().unwrap().rule(),
@@ -214,12 +287,83 @@ mod tests {
             );
         }
 
+        // The first matched expression always exists, also through the rule.
+        assert_eq!(rep2.first().content, "foo");
+        assert_eq!(rep2.rest().len(), 2);
+        assert_eq!(rep2.rest()[0].content, "Foo");
+        let (first, rest) = rep2.split_first();
+        assert_eq!(first, rep2.first());
+        assert_eq!(rest, rep2.rest());
+
+        let items = rep2.to_vec();
+        let rep = REP::try_from(items.clone()).unwrap();
+        assert_eq!(rep.as_slice(), items.as_slice());
+        assert_eq!(rep.skipped().len(), items.len());
+        assert_eq!(REP::try_from(vec![]), Err(vec![]));
+
         assert_eq!(REP::MIN, 1);
         assert_eq!(rep1.deref().get_min_len(), 1);
         assert_eq!(rep1.deref().get_max_len(), usize::MAX);
         assert_eq!(<R<'_, 0> as TypeWrapper>::Inner::MIN, 1);
     }
 
+    #[test]
+    fn repetition_views() {
+        type REP<'i> = Rep<Insens<'i, Foo>, Ignore<'i>, 1>;
+        non_atomic_rule!(
+            R,
+            "Repetion of [Insens].",
+            Rule,
+            Rule::RepFoo,
+            REP<'i>,
+            Ignore<'i>,
+            false
+        );
+
+        let r = R::try_parse("foo Foo\tFOO").unwrap();
+        let rep = r.deref();
+        fn contents<'i>(slice: &[Insens<'i, Foo>]) -> Vec<&'i str> {
+            slice.iter().map(|i| i.content).collect()
+        }
+        assert_eq!(contents(rep.as_slice()), ["foo", "Foo", "FOO"]);
+        assert_eq!(contents(&rep.to_vec()), ["foo", "Foo", "FOO"]);
+        assert_eq!(contents(&rep.clone().into_vec()), ["foo", "Foo", "FOO"]);
+        assert_eq!(
+            rep.as_slice().iter().collect::<Vec<_>>(),
+            rep.iter_matched().collect::<Vec<_>>()
+        );
+
+        // Skipped contents are kept aside, one group before each element.
+        assert_eq!(rep.skipped.len(), rep.as_slice().len());
+        let all = rep
+            .iter_all()
+            .map(|s| (s.skipped[0].to_string(), s.matched.content))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            all,
+            [
+                (String::new(), "foo"),
+                (String::from(" "), "Foo"),
+                (String::from("\t"), "FOO")
+            ]
+        );
+        assert_eq!(
+            rep.clone()
+                .into_iter_all()
+                .map(|s| s.matched)
+                .collect::<Vec<_>>(),
+            rep.to_vec()
+        );
+        assert_eq!(rep.to_string(), "foo Foo\tFOO");
+        assert_eq!(
+            r.format_as_tree().unwrap(),
+            "RepFoo
+    WHITESPACE \" \"
+    COMMENT \"\\t\"
+"
+        );
+    }
+
     #[test]
     fn skip() {
         #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -242,6 +386,46 @@ mod tests {
 
         let new_line = NewLine;
         assert_eq!(new_line.get_content(), NewLine::CONTENT);
+
+        let (s1, _) = s1.as_ref();
+        assert_eq!(s1.terminator, Some(0));
+        assert_eq!(s1.terminated_by(), Some("\r\n"));
+    }
+
+    #[test]
+    fn skip_terminator() {
+        #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+        pub struct CommentEnd;
+        impl StringArrayWrapper for CommentEnd {
+            const CONTENT: &'static [&'static str] = &["*/", "\n"];
+        }
+        compound_atomic_rule!(
+            Comment,
+            "Comment content.",
+            Rule,
+            Rule::String,
+            Skip<'i, CommentEnd>,
+            false
+        );
+
+        let (_, comment) = Comment::<1>::try_parse_partial("abc*/def\n").unwrap();
+        assert_eq!(comment.content.span.as_str(), "abc");
+        assert_eq!(comment.content.terminator, Some(0));
+        assert_eq!(comment.content.terminated_by(), Some("*/"));
+
+        let (_, comment) = Comment::<1>::try_parse_partial("abc\ndef*/").unwrap();
+        assert_eq!(comment.content.span.as_str(), "abc");
+        assert_eq!(comment.content.terminated_by(), Some("\n"));
+
+        let (_, comment) = Comment::<1>::try_parse_partial("*/").unwrap();
+        assert_eq!(comment.content.span.as_str(), "");
+        assert_eq!(comment.content.terminated_by(), Some("*/"));
+
+        // Nothing to stop at before the end of input.
+        Comment::<1>::try_parse_partial("abc").unwrap_err();
+        let skip = Skip::<'_, CommentEnd>::from(Span::new("abc", 1, 3).unwrap());
+        assert_eq!(skip.terminator, None);
+        assert_eq!(skip.terminated_by(), None);
     }
 
     #[test]
@@ -290,10 +474,7 @@ mod tests {
         assert_eq!(any.content, 'i');
         assert_eq!(name.iter().map(|c| c.content).collect::<String>(), "i");
         assert_eq!(
-            name.deref()
-                .into_iter()
-                .map(|c| c.content)
-                .collect::<String>(),
+            name.deref().iter().map(|c| c.content).collect::<String>(),
             "i"
         );
 
@@ -359,8 +540,8 @@ mod tests {
             Rule,
             Rule::RepFoo,
             Seq2<
-                Skipped<Push<Insens<'i, Foo>>, Ignore<'i>, 0>,
-                Skipped<RepMinMax<Skipped<PEEK<'i>, Ignore<'i>, 0>, 1, 3>, Ignore<'i>, 0>,
+                Skipped<Push<'i, Insens<'i, Foo>>, Ignore<'i>, 0>,
+                Skipped<RepMinMax<PEEK<'i>, Ignore<'i>, 0, 1, 3>, Ignore<'i>, 0>,
             >,
             false
         );
@@ -373,6 +554,11 @@ mod tests {
             content: Insens {
                 content: \"foO\",
             },
+            span: Span {
+                str: \"foO\",
+                start: 0,
+                end: 3,
+            },
         },
         RepMinMax {
             content: [
@@ -423,6 +609,95 @@ mod tests {
         }
     }
 
+    #[test]
+    fn peek_pop_insens() {
+        compound_atomic_rule!(
+            PeekPopInsens,
+            "Match a pushed expression twice case-insensitively",
+            Rule,
+            Rule::RepFoo,
+            Seq3<
+                Skipped<Push<'i, Str<Foo>>, Ignore<'i>, 0>,
+                Skipped<PEEK_INSENS<'i>, Ignore<'i>, 0>,
+                Skipped<POP_INSENS<'i>, Ignore<'i>, 0>,
+            >,
+            false
+        );
+        let r = PeekPopInsens::<1>::try_parse("fooFOOfOo").unwrap();
+        let (_, peeked, popped) = r.as_ref();
+        assert_eq!(peeked.span.as_str(), "FOO");
+        assert_eq!((peeked.span.start(), peeked.span.end()), (3, 6));
+        assert_eq!(popped.span.as_str(), "fOo");
+        assert_eq!((popped.span.start(), popped.span.end()), (6, 9));
+
+        PeekPopInsens::try_parse("foofoo").unwrap_err();
+        PeekPopInsens::try_parse("fooFOObar").unwrap_err();
+    }
+
+    #[test]
+    fn push_literal() {
+        compound_atomic_rule!(
+            PeekPopLiteral,
+            "Match a pushed literal twice",
+            Rule,
+            Rule::RepFoo,
+            Seq3<
+                Skipped<PushLiteral<Foo>, Ignore<'i>, 0>,
+                Skipped<PEEK<'i>, Ignore<'i>, 0>,
+                Skipped<POP<'i>, Ignore<'i>, 0>,
+            >,
+            false
+        );
+        let r = PeekPopLiteral::<1>::try_parse("foofoo").unwrap();
+        let (pushed, peeked, popped) = r.as_ref();
+        assert_eq!(pushed.get_content(), Foo::CONTENT);
+        assert_eq!(peeked.span.as_str(), "foo");
+        assert_eq!(peeked.span.start(), 0);
+        assert_eq!(popped.span.as_str(), "foo");
+        assert_eq!(format!("{}", pushed), "");
+        assert_eq!(r.span.as_str(), "foofoo");
+
+        PeekPopLiteral::try_parse("foo").unwrap_err();
+        PeekPopLiteral::try_parse("fooFoo").unwrap_err();
+        PeekPopLiteral::try_parse("foofoofoo").unwrap_err();
+    }
+
+    #[test]
+    fn push_span() {
+        compound_atomic_rule!(
+            PushOrPopAll,
+            "Push three times, or push once and pop all",
+            Rule,
+            Rule::RepFoo,
+            Choice2<
+                Seq3<
+                    Skipped<Push<'i, Insens<'i, Foo>>, Ignore<'i>, 0>,
+                    Skipped<Push<'i, Insens<'i, Foo>>, Ignore<'i>, 0>,
+                    Skipped<Push<'i, Insens<'i, Foo>>, Ignore<'i>, 0>,
+                >,
+                Seq2<
+                    Skipped<Push<'i, Insens<'i, Foo>>, Ignore<'i>, 0>,
+                    Skipped<POP_ALL<'i>, Ignore<'i>, 0>,
+                >,
+            >,
+            false
+        );
+        let r = PushOrPopAll::<1>::try_parse("fooFOOfOo").unwrap();
+        let (first, second, third) = r.content._0().unwrap().as_ref();
+        assert_eq!(first.span.as_str(), "foo");
+        assert_eq!(second.span.as_str(), "FOO");
+        assert_eq!((second.span.start(), second.span.end()), (3, 6));
+        assert_eq!(third.span.as_str(), third.content.content);
+
+        // The first branch pushes twice before it fails, and the stack is restored.
+        let r = PushOrPopAll::<1>::try_parse("foofoo").unwrap();
+        let (pushed, popped) = r.content._1().unwrap().as_ref();
+        assert_eq!(pushed.span.as_str(), "foo");
+        assert_eq!((pushed.span.start(), pushed.span.end()), (0, 3));
+        assert_eq!(popped.span.as_str(), "foo");
+        assert_eq!(popped.span.start(), 3);
+    }
+
     #[test]
     fn rep() {
         compound_atomic_rule!(
@@ -430,7 +705,7 @@ mod tests {
             "Repeat previously matched expression 0 to 3 times",
             Rule,
             Rule::RepFoo,
-            RepMin<Skipped<Str<Foo>, Ignore<'i>, 0>, 0>,
+            RepMin<Str<Foo>, Ignore<'i>, 0, 0>,
             false
         );
 
@@ -480,4 +755,72 @@ mod tests {
         }
         assert_eq!(set.len(), 3);
     }
+
+    #[cfg(feature = "compact_debug")]
+    #[test]
+    fn compact_debug() {
+        let content = vec!['x'; COMPACT_DEBUG_MAX_ELEMENTS + 2];
+        let rep = AtomicRep::<ANY> {
+            content: content.iter().map(|&content| ANY { content }).collect(),
+        };
+        let elements = vec!["ANY { content: 'x' }"; COMPACT_DEBUG_MAX_ELEMENTS];
+        assert_eq!(
+            format!("{:?}", rep),
+            format!(
+                "AtomicRep {{ content: [{}, … +2 elements] }}",
+                elements.join(", ")
+            )
+        );
+    }
+
+    /// Pushes the position onto the stack and then panics.
+    #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+    struct PushThenPanic;
+    impl<'i, R: RuleType> TypedNode<'i, R> for PushThenPanic {
+        fn try_parse_with(
+            input: Position<'i>,
+            stack: &mut Stack<Span<'i>>,
+            _tracker: &mut Tracker<'i, R>,
+        ) -> Option<(Position<'i>, Self)> {
+            stack.push(input.span(&input));
+            panic!("inner parse panicked");
+        }
+    }
+
+    #[test]
+    fn stack_balanced_after_panic() {
+        let input = "foo";
+        let start = Position::from_start(input);
+        type Parse = dyn Fn(&mut Stack<Span<'static>>, &mut Tracker<'static, Rule>);
+        let parsers: [&Parse; 3] = [
+            &move |stack, tracker| {
+                let _ = Positive::<PushThenPanic>::try_parse_with(start, stack, tracker);
+            },
+            &move |stack, tracker| {
+                let _ = Negative::<PushThenPanic>::try_parse_with(start, stack, tracker);
+            },
+            &move |stack, tracker| {
+                let _ = restore_on_none(stack, |stack| {
+                    TypedNode::<Rule>::try_parse_with(start, stack, tracker)
+                        .map(|(_, node): (_, PushThenPanic)| node)
+                });
+            },
+        ];
+        for parse in parsers {
+            let mut stack = Stack::new();
+            let mut tracker = Tracker::new(start);
+            stack.push(Span::new(input, 0, 1).unwrap());
+            let mut outer = stack.snapshot_guard();
+            let res = catch_unwind(AssertUnwindSafe(|| parse(&mut outer, &mut tracker)));
+            assert!(res.is_err());
+            // The inner snapshot has been restored, so the outer one is the latest.
+            assert_eq!(outer.len(), 1);
+            outer.push(Span::new(input, 1, 2).unwrap());
+            drop(outer);
+            assert_eq!(
+                stack.iter().collect::<Vec<_>>(),
+                [&Span::new(input, 0, 1).unwrap()]
+            );
+        }
+    }
 }
@@ -0,0 +1,339 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Deterministic fuzzing of parsing failures and error rendering.
+//!
+//! Random inputs are fed through hand-composed parsers,
+//! and every produced error is rendered and checked to point within the input.
+//!
+//! Run more iterations with:
+//!
+//! ```shell
+//! PEST_TYPED_FUZZ_ITERATIONS=1000000 cargo test -p pest_typed --test robustness --release
+//! ```
+//!
+//! Inputs that once broke something are kept in [`REGRESSIONS`].
+
+#![allow(clippy::upper_case_acronyms)]
+
+use pest_typed::{
+    atomic_rule,
+    choices::{Choice2, Choice3},
+    compound_atomic_rule,
+    error::{CustomError, Error, ErrorDetails, LineColLocation},
+    non_atomic_rule,
+    predefined_node::*,
+    sequence::{Seq2, Seq3},
+    ParsableTypedNode, Position, RuleWrapper, Span, StringArrayWrapper, StringWrapper,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    WHITESPACE,
+    COMMENT,
+    Keyword,
+    Quoted,
+    Lines,
+    Stack,
+    List,
+    Chars,
+    EOI,
+}
+
+macro_rules! string {
+    ($name:ident, $content:literal) => {
+        #[derive(Clone, Debug, Hash, PartialEq, Eq)]
+        pub struct $name;
+        impl StringWrapper for $name {
+            const CONTENT: &'static str = $content;
+        }
+    };
+}
+
+string!(Straße, "straße");
+string!(Quote, "\"");
+string!(Backslash, "\\");
+string!(Comma, ",");
+string!(Crlf, "\r\n");
+string!(Hash, "#");
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LineEnds;
+impl StringArrayWrapper for LineEnds {
+    const CONTENT: &'static [&'static str] = &["\r\n", "\n", "\r"];
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Stops;
+impl StringArrayWrapper for Stops {
+    const CONTENT: &'static [&'static str] = &["世", "\r\n", ""];
+}
+
+atomic_rule!(
+    WHITESPACE,
+    "White space.",
+    Rule,
+    Rule::WHITESPACE,
+    Choice2<CharRange<' ', ' '>, Str<Crlf>>
+);
+compound_atomic_rule!(
+    COMMENT,
+    "Comment till the end of line.",
+    Rule,
+    Rule::COMMENT,
+    Seq2<Skipped<Str<Hash>, Empty<'i>, 0>, Skipped<Skip<'i, LineEnds>, Empty<'i>, 0>>,
+    false
+);
+
+type Ignore<'i> = AtomicRep<Choice2<WHITESPACE<'i, 0>, COMMENT<'i, 0>>>;
+
+// `^"straße" ~ "é"?`
+atomic_rule!(
+    Keyword,
+    "Case-insensitive keyword with a multi-byte character.",
+    Rule,
+    Rule::Keyword,
+    Seq2<
+        Skipped<Insens<'i, Straße>, Empty<'i>, 0>,
+        Skipped<Option<CharRange<'é', 'é'>>, Empty<'i>, 0>,
+    >
+);
+
+// `"\"" ~ ("\\" ~ ANY | !"\"" ~ ANY)* ~ "\""`
+compound_atomic_rule!(
+    Quoted,
+    "Quoted string with escapes.",
+    Rule,
+    Rule::Quoted,
+    Seq3<
+        Skipped<Str<Quote>, Empty<'i>, 0>,
+        Skipped<
+            AtomicRep<Choice2<(Str<Backslash>, ANY), (Negative<Str<Quote>>, ANY)>>,
+            Empty<'i>,
+            0,
+        >,
+        Skipped<Str<Quote>, Empty<'i>, 0>,
+    >,
+    false
+);
+
+// `((!NEWLINE ~ ANY)* ~ NEWLINE)*`, with stops in between.
+compound_atomic_rule!(
+    Lines,
+    "Lines ending with new lines.",
+    Rule,
+    Rule::Lines,
+    AtomicRep<(Skip<'i, Stops>, Choice2<NEWLINE, SkipChar<'i, 1>>)>,
+    false
+);
+
+// `PUSH(ASCII_DIGIT{1, 2}) ~ ("-" ~ PEEK)* ~ POP ~ DROP?`
+compound_atomic_rule!(
+    Stack,
+    "Stack operations.",
+    Rule,
+    Rule::Stack,
+    Seq3<
+        Skipped<Push<'i, RepMinMax<ASCII_DIGIT, Empty<'i>, 0, 1, 2>>, Empty<'i>, 0>,
+        Skipped<AtomicRep<(CharRange<'-', '-'>, PEEK<'i>)>, Empty<'i>, 0>,
+        Skipped<(POP<'i>, Option<DROP>), Empty<'i>, 0>,
+    >,
+    false
+);
+
+// `item ~ ("," ~ item)*`, where `item` is any of the atomic rules.
+non_atomic_rule!(
+    List,
+    "Comma-separated items.",
+    Rule,
+    Rule::List,
+    Seq2<
+        Skipped<Item<'i>, Ignore<'i>, 1>,
+        Skipped<
+            Rep<
+                Seq2<Skipped<Str<Comma>, Ignore<'i>, 1>, Skipped<Item<'i>, Ignore<'i>, 1>>,
+                Ignore<'i>,
+                1,
+            >,
+            Ignore<'i>,
+            1,
+        >,
+    >,
+    Ignore<'i>,
+    false
+);
+
+type Item<'i> = Choice3<Keyword<'i, 1>, Quoted<'i, 1>, Stack<'i, 1>>;
+
+// Three characters, each of a different width.
+compound_atomic_rule!(
+    Chars,
+    "Characters of different widths.",
+    Rule,
+    Rule::Chars,
+    Seq3<
+        Skipped<CharRange<'\u{80}', '\u{7ff}'>, Empty<'i>, 0>,
+        Skipped<CharRange<'\u{800}', '\u{ffff}'>, Empty<'i>, 0>,
+        Skipped<RepMinMax<CharRange<'\u{10000}', '\u{10ffff}'>, Empty<'i>, 0, 0, 2>, Empty<'i>, 0>,
+    >,
+    false
+);
+
+/// Inputs that once caused panics or wrong positions.
+const REGRESSIONS: &[&str] = &[
+    "",
+    "\r",
+    "\n",
+    "\r\n",
+    "a\r",
+    "é",
+    "STRASSE",
+    "straß",
+    "STRAẞEé",
+    "\"",
+    "\"\\",
+    "\"é\\",
+    "\"\\世",
+    "12-1",
+    "12-12-",
+    "1-",
+    "世",
+    "世\r",
+    "é世",
+    "é世🎉🎉🎉",
+    "\u{feff}",
+    "straße,\r\n",
+    "straße, # é\r",
+    "12 ,\"\r\n\",",
+];
+
+/// Fragments that inputs are assembled from.
+const FRAGMENTS: &[&str] = &[
+    "", "a", "z", "0", "1", "9", "-", "straße", "STRASSE", "ß", "é", "É", "世", "🎉", " ", "\t",
+    "\n", "\r", "\r\n", "\"", "\\", ",", "#", "\u{0}", "\u{feff}", "\u{fffd}",
+];
+
+/// A xorshift generator, so that failures are reproducible.
+struct Random(u64);
+
+impl Random {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+    fn below(&mut self, n: usize) -> usize {
+        (self.next() % n as u64) as usize
+    }
+    fn fragments(&mut self) -> String {
+        (0..self.below(12))
+            .map(|_| FRAGMENTS[self.below(FRAGMENTS.len())])
+            .collect()
+    }
+    fn bytes(&mut self) -> String {
+        let bytes: Vec<u8> = (0..self.below(16)).map(|_| self.next() as u8).collect();
+        match String::from_utf8(bytes) {
+            Ok(string) => string,
+            Err(error) => String::from_utf8_lossy(error.as_bytes()).into_owned(),
+        }
+    }
+}
+
+fn iterations() -> usize {
+    std::env::var("PEST_TYPED_FUZZ_ITERATIONS")
+        .ok()
+        .and_then(|n| n.parse().ok())
+        .unwrap_or(1000)
+}
+
+/// Render the error and check that it points within the input.
+fn check_error(input: &str, error: &Error<Rule>) {
+    let rendered = error.to_string();
+    assert!(!rendered.is_empty(), "{:?}", input);
+    let (start, end) = (error.range().start, error.range().end);
+    assert!(
+        start <= end && end <= input.len(),
+        "{:?}: {:?}",
+        input,
+        error
+    );
+    assert!(
+        input.is_char_boundary(start) && input.is_char_boundary(end),
+        "{:?}: {:?}",
+        input,
+        error
+    );
+    let line_col = match error.line_col {
+        LineColLocation::Pos(line_col) => line_col,
+        LineColLocation::Span(line_col, _) => line_col,
+    };
+    let pos = Position::new(input, start).unwrap();
+    assert_eq!(line_col, pos.line_col(), "{:?}: {:?}", input, error);
+}
+
+fn check<'i, T: ParsableTypedNode<'i, Rule> + RuleWrapper<Rule>>(input: &'i str) {
+    match T::try_parse(input) {
+        Ok(_) => (),
+        Err(error) => check_error(input, &error),
+    }
+    match T::try_parse_partial(input) {
+        Ok((pos, _)) => assert!(input.is_char_boundary(pos.pos()), "{:?}", input),
+        Err(error) => check_error(input, &error),
+    }
+}
+
+fn check_all(input: &str) {
+    check::<Keyword<'_>>(input);
+    check::<Quoted<'_>>(input);
+    check::<Lines<'_>>(input);
+    check::<Stack<'_>>(input);
+    check::<List<'_>>(input);
+    check::<Chars<'_>>(input);
+
+    let boundaries: Vec<usize> = (0..=input.len())
+        .filter(|&i| input.is_char_boundary(i))
+        .collect();
+    for &start in &boundaries {
+        let pos = Position::new(input, start).unwrap();
+        let _ = pos.line_col();
+        assert!(pos.line_of().len() <= input.len());
+        let _ = pos.to_string();
+        check_error(input, &Error::custom_at_pos(pos, "at position"));
+        for &end in boundaries.iter().filter(|&&end| end >= start) {
+            let span = Span::new(input, start, end).unwrap();
+            let _ = span.lines().count();
+            let _ = span.to_string();
+            check_error(input, &Error::custom_at_span(span, "at span"));
+        }
+    }
+}
+
+#[test]
+fn regressions() {
+    for input in REGRESSIONS {
+        check_all(input);
+    }
+}
+
+#[test]
+fn fragments() {
+    let mut random = Random(0x9e37_79b9_7f4a_7c15);
+    for _ in 0..iterations() {
+        check_all(&random.fragments());
+    }
+}
+
+#[test]
+fn bytes() {
+    let mut random = Random(0x2545_f491_4f6c_dd1d);
+    for _ in 0..iterations() {
+        check_all(&random.bytes());
+    }
+}
@@ -0,0 +1,96 @@
+// pest-typed. A statically typed version of pest.
+// Copyright (c) 2023 黄博奕
+//
+// Licensed under the Apache License, Version 2.0
+// <LICENSE-APACHE or http://www.apache.org/licenses/LICENSE-2.0> or the MIT
+// license <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. All files in the project carrying such notice may not be copied,
+// modified, or distributed except according to those terms.
+
+//! Hand-written rules with accessers and attributes.
+
+#![allow(clippy::upper_case_acronyms)]
+
+use pest_typed::{
+    atomic_rule, non_atomic_rule, predefined_node::*, rule_eoi, sequence::Seq3, ParsableTypedNode,
+    StringWrapper,
+};
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum Rule {
+    WHITESPACE,
+    Key,
+    Entry,
+    Unused,
+    EOI,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Equal;
+impl StringWrapper for Equal {
+    const CONTENT: &'static str = "=";
+}
+
+atomic_rule!(
+    WHITESPACE,
+    "White space.",
+    Rule,
+    Rule::WHITESPACE,
+    CharRange<' ', ' '>
+);
+
+atomic_rule!(
+    /// Extra documentation.
+    #[must_use]
+    Key,
+    "A key.",
+    Rule,
+    Rule::Key,
+    AtomicRep<CharRange<'a', 'z'>>
+);
+
+type Ignore<'i> = AtomicRep<WHITESPACE<'i, 0>>;
+
+// `key ~ "=" ~ key`
+non_atomic_rule!(
+    Entry,
+    "An entry.",
+    Rule,
+    Rule::Entry,
+    Seq3<
+        Skipped<Key<'i, 1>, Ignore<'i>, 1>,
+        Skipped<Str<Equal>, Ignore<'i>, 1>,
+        Skipped<Key<'i, 1>, Ignore<'i>, 1>,
+    >,
+    Ignore<'i>,
+    false,
+    {
+        key => content.0.matched: Key<'i, 1>,
+        value => content.2.matched: Key<'i, 1>,
+    }
+);
+
+non_atomic_rule!(
+    #[cfg(any())]
+    Unused,
+    "Never compiled.",
+    Rule,
+    Rule::Unused,
+    DoesNotExist<'i>,
+    Ignore<'i>,
+    false,
+    {
+        first => content.0.matched: DoesNotExist,
+    }
+);
+
+rule_eoi!(EOI, Rule);
+
+#[test]
+fn accessers() -> Result<(), pest_typed::Box<pest_typed::error::Error<Rule>>> {
+    let entry = Entry::try_parse("name = value")?;
+    assert_eq!(entry.key().span.as_str(), "name");
+    assert_eq!(entry.value().span.as_str(), "value");
+    assert!(entry.key() < entry.value());
+    Ok(())
+}